@@ -1,9 +1,12 @@
 use crate::response_handlers::handle_engine_response;
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::project::open::project_open_request::ProjectOpenRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
 use squalr_engine_session::engine_unprivileged_state::EngineUnprivilegedState;
 use std::io;
 use std::io::Write;
+use std::path::PathBuf;
 use std::sync::{Arc, mpsc};
 use structopt::StructOpt;
 use structopt::clap::ErrorKind;
@@ -53,6 +56,39 @@ impl Cli {
         log::error!("Exiting cli.");
     }
 
+    /// Opens a project and keeps the engine running without a UI until any input is received, such that the project hotkeys can be used.
+    pub fn run_project(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        project_directory_path: PathBuf,
+    ) -> Result<()> {
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let project_open_request = ProjectOpenRequest {
+            open_file_browser: false,
+            project_directory_path: Some(project_directory_path.clone()),
+            project_name: None,
+        };
+
+        project_open_request.send(engine_unprivileged_state, move |project_open_response| {
+            let _ = response_sender.send(project_open_response.success);
+        });
+
+        let is_opened = response_receiver
+            .recv()
+            .map_err(|error| anyhow!("Failed waiting for project open response: {}", error))?;
+
+        if !is_opened {
+            bail!("Failed to open project at {}.", project_directory_path.display());
+        }
+
+        log::info!(
+            "Running project at {}. Hotkeys are active, press enter to exit.",
+            project_directory_path.display()
+        );
+        Self::stay_alive();
+
+        Ok(())
+    }
+
     /// Executes a single command and blocks until the engine response arrives.
     pub fn run_one_shot(
         engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
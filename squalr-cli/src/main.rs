@@ -4,7 +4,11 @@ mod response_handlers;
 use anyhow::{Context, Result, bail};
 use cli::Cli;
 use squalr_engine::engine_mode::EngineMode;
-use squalr_engine::squalr_engine::SqualrEngine;
+use squalr_engine::squalr_engine::{SqualrEngine, SqualrEngineOptions};
+use std::path::PathBuf;
+
+/// The command that opens a project and keeps the engine running headlessly, such that its hotkeys stay active.
+const RUN_PROJECT_COMMAND: &str = "run-project";
 
 fn main() -> Result<()> {
     let command_line_arguments: Vec<String> = std::env::args().collect();
@@ -17,9 +21,17 @@ fn main() -> Result<()> {
         EngineMode::Standalone
     };
     let one_shot_command_text = build_one_shot_command_text(&command_line_arguments);
+    let run_project_directory_path = get_run_project_directory_path(&command_line_arguments)?;
 
-    // Start Squalr engine.
-    let mut squalr_engine = SqualrEngine::new(engine_mode).context("Fatal error initializing Squalr engine.")?;
+    // Start Squalr engine. Hotkeys are only listened for when running a project, as interactive input would otherwise trigger them.
+    let mut squalr_engine = SqualrEngine::new_with_options(
+        engine_mode,
+        SqualrEngineOptions {
+            enable_project_hotkeys: run_project_directory_path.is_some(),
+            ..SqualrEngineOptions::default()
+        },
+    )
+    .context("Fatal error initializing Squalr engine.")?;
 
     // Start the log event sending now that both the CLI and engine are ready to receive log messages.
     squalr_engine.initialize();
@@ -33,7 +45,9 @@ fn main() -> Result<()> {
         // Listen for user input.
         // Note that the "Cli", when listening for input, is considered unprivileged, as it is considered the "UI".
         // Internally, these commands then get dispatched to an abstracted away privileged component.
-        if let Some(one_shot_command_text) = one_shot_command_text {
+        if let Some(run_project_directory_path) = run_project_directory_path {
+            Cli::run_project(engine_unprivileged_state, run_project_directory_path).context("Failed running project.")?;
+        } else if let Some(one_shot_command_text) = one_shot_command_text {
             Cli::run_one_shot(engine_unprivileged_state, &one_shot_command_text).context("Failed running one-shot CLI command.")?;
         } else {
             Cli::run_loop(engine_unprivileged_state);
@@ -50,6 +64,22 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+fn get_run_project_directory_path(command_line_arguments: &[String]) -> Result<Option<PathBuf>> {
+    let mut arguments = command_line_arguments
+        .iter()
+        .skip(1)
+        .filter(|argument| argument.as_str() != "--ipc-mode");
+
+    if arguments.next().map(String::as_str) != Some(RUN_PROJECT_COMMAND) {
+        return Ok(None);
+    }
+
+    match arguments.next() {
+        Some(project_directory_path) => Ok(Some(PathBuf::from(project_directory_path))),
+        None => bail!("Usage: squalr-cli {} <project directory path>", RUN_PROJECT_COMMAND),
+    }
+}
+
 fn build_one_shot_command_text(command_line_arguments: &[String]) -> Option<String> {
     let one_shot_tokens: Vec<String> = command_line_arguments
         .iter()
@@ -0,0 +1,21 @@
+use squalr_engine_api::commands::memory::build_ids::memory_build_ids_response::MemoryBuildIdsResponse;
+
+pub fn handle_memory_build_ids_response(memory_build_ids_response: MemoryBuildIdsResponse) {
+    if memory_build_ids_response.module_build_identities.is_empty() {
+        log::error!("No build identities were computed for the requested modules");
+        return;
+    }
+
+    for module_build_identity in &memory_build_ids_response.module_build_identities {
+        log::info!("{}: {}", module_build_identity.get_module_name(), module_build_identity.get_build_id());
+    }
+
+    for stale_module_build in &memory_build_ids_response.stale_module_builds {
+        log::warn!(
+            "{} is stale: expected {}, found {}",
+            stale_module_build.get_module_name(),
+            stale_module_build.get_expected_build_id(),
+            stale_module_build.get_actual_build_id()
+        );
+    }
+}
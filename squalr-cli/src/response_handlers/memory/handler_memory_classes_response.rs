@@ -0,0 +1,19 @@
+use squalr_engine_api::commands::memory::classes::memory_classes_response::MemoryClassesResponse;
+
+pub fn handle_memory_classes_response(memory_classes_response: MemoryClassesResponse) {
+    if !memory_classes_response.success {
+        log::error!("Failed to discover module classes");
+        return;
+    }
+
+    log::info!("Discovered {} classes", memory_classes_response.runtime_classes.len());
+
+    for runtime_class in &memory_classes_response.runtime_classes {
+        log::info!(
+            "{} (vtable 0x{:X}, {} rtti)",
+            runtime_class.get_class_name(),
+            runtime_class.get_vtable_address(),
+            runtime_class.get_abi()
+        );
+    }
+}
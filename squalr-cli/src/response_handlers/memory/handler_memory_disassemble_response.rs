@@ -0,0 +1,43 @@
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
+
+pub fn handle_memory_disassemble_response(memory_disassemble_response: MemoryDisassembleResponse) {
+    if !memory_disassemble_response.success {
+        log::error!("Failed to disassemble memory at 0x{:X}", memory_disassemble_response.address);
+        return;
+    }
+
+    for instruction in &memory_disassemble_response.instructions {
+        let hex_bytes = instruction
+            .get_bytes()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let location = instruction.get_location().unwrap_or_default();
+        let marker = if instruction.get_address() == memory_disassemble_response.address {
+            ">"
+        } else {
+            " "
+        };
+
+        match instruction.get_target_location() {
+            Some(target_location) => log::info!(
+                "{}{:016X} {:<24} {:<30} {} ; {}",
+                marker,
+                instruction.get_address(),
+                location,
+                hex_bytes,
+                instruction.get_text(),
+                target_location
+            ),
+            None => log::info!(
+                "{}{:016X} {:<24} {:<30} {}",
+                marker,
+                instruction.get_address(),
+                location,
+                hex_bytes,
+                instruction.get_text()
+            ),
+        }
+    }
+}
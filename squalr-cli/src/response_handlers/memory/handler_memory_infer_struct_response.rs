@@ -0,0 +1,20 @@
+use squalr_engine_api::commands::memory::infer_struct::memory_infer_struct_response::MemoryInferStructResponse;
+
+pub fn handle_memory_infer_struct_response(memory_infer_struct_response: MemoryInferStructResponse) {
+    let Some(symbolic_struct_definition) = memory_infer_struct_response.symbolic_struct_definition else {
+        log::error!("Failed to infer a struct layout");
+        return;
+    };
+
+    for inferred_field in &memory_infer_struct_response.inferred_fields {
+        log::info!(
+            "+0x{:X}: {} ({}) {}",
+            inferred_field.get_offset(),
+            inferred_field.get_field_definition(),
+            inferred_field.get_kind(),
+            inferred_field.get_description()
+        );
+    }
+
+    log::info!("Draft struct definition: {}", symbolic_struct_definition);
+}
@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::patch::memory_patch_response::MemoryPatchResponse;
+
+pub fn handle_memory_patch_response(memory_patch_response: MemoryPatchResponse) {
+    if memory_patch_response.failed_patch_target_count == 0 {
+        log::info!("Patch success.");
+    } else {
+        log::error!("Patch failed for {} targets.", memory_patch_response.failed_patch_target_count);
+    }
+}
@@ -0,0 +1,32 @@
+use squalr_engine_api::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+
+pub fn handle_memory_read_range_response(memory_read_range_response: MemoryReadRangeResponse) {
+    if !memory_read_range_response.success {
+        log::error!("Failed to read memory range");
+        return;
+    }
+
+    for (row_index, row_bytes) in memory_read_range_response.bytes.chunks(16).enumerate() {
+        let row_offset = row_index * 16;
+        let hex_bytes = row_bytes
+            .iter()
+            .enumerate()
+            .map(|(byte_index, byte)| {
+                if memory_read_range_response.is_readable(row_offset + byte_index) {
+                    format!("{:02X}", byte)
+                } else {
+                    "??".to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        log::info!(
+            "{:016X}  {}",
+            memory_read_range_response
+                .address
+                .saturating_add(row_offset as u64),
+            hex_bytes
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use squalr_engine_api::commands::memory::resolve::memory_resolve_response::MemoryResolveResponse;
+
+pub fn handle_memory_resolve_response(memory_resolve_response: MemoryResolveResponse) {
+    if !memory_resolve_response.success {
+        log::error!("Failed to resolve address expression");
+        return;
+    }
+
+    if !memory_resolve_response.symbol_label.is_empty() {
+        log::info!(
+            "Resolved address: 0x{:X} ({})",
+            memory_resolve_response.address,
+            memory_resolve_response.symbol_label
+        );
+    } else if memory_resolve_response.module_name.is_empty() {
+        log::info!("Resolved address: 0x{:X}", memory_resolve_response.address);
+    } else {
+        log::info!(
+            "Resolved address: 0x{:X} ({}+0x{:X})",
+            memory_resolve_response.address,
+            memory_resolve_response.module_name,
+            memory_resolve_response.module_offset
+        );
+    }
+}
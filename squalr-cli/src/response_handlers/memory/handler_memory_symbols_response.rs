@@ -0,0 +1,29 @@
+use squalr_engine_api::commands::memory::symbols::memory_symbols_response::MemorySymbolsResponse;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+
+pub fn handle_memory_symbols_response(memory_symbols_response: MemorySymbolsResponse) {
+    let Some(module_symbol_table) = memory_symbols_response.module_symbol_table else {
+        log::error!("Failed to look up module symbols");
+        return;
+    };
+
+    log::info!(
+        "Module {}: {} symbols, build id: {}, debug link: {}",
+        module_symbol_table.get_module_name(),
+        module_symbol_table.get_symbols().len(),
+        module_symbol_table.get_build_id().as_deref().unwrap_or("none"),
+        module_symbol_table
+            .get_debug_link()
+            .as_deref()
+            .unwrap_or("none")
+    );
+
+    for module_symbol in module_symbol_table.get_symbols() {
+        log::info!(
+            "{} = {} (size 0x{:X})",
+            AddressExpression::format_symbol_offset(module_symbol_table.get_module_name(), module_symbol.get_name(), 0),
+            AddressExpression::format_module_offset(module_symbol_table.get_module_name(), module_symbol.get_offset()),
+            module_symbol.get_size()
+        );
+    }
+}
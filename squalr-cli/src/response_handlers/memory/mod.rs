@@ -1,16 +1,40 @@
+pub mod handler_memory_build_ids_response;
+pub mod handler_memory_classes_response;
+pub mod handler_memory_disassemble_response;
 pub mod handler_memory_freeze_response;
+pub mod handler_memory_infer_struct_response;
+pub mod handler_memory_patch_response;
+pub mod handler_memory_read_range_response;
 pub mod handler_memory_read_response;
+pub mod handler_memory_resolve_response;
+pub mod handler_memory_symbols_response;
 pub mod handler_memory_write_response;
 
+use crate::response_handlers::memory::handler_memory_build_ids_response::handle_memory_build_ids_response;
+use crate::response_handlers::memory::handler_memory_classes_response::handle_memory_classes_response;
+use crate::response_handlers::memory::handler_memory_disassemble_response::handle_memory_disassemble_response;
 use crate::response_handlers::memory::handler_memory_freeze_response::handle_memory_response_freeze;
+use crate::response_handlers::memory::handler_memory_infer_struct_response::handle_memory_infer_struct_response;
+use crate::response_handlers::memory::handler_memory_patch_response::handle_memory_patch_response;
+use crate::response_handlers::memory::handler_memory_read_range_response::handle_memory_read_range_response;
 use crate::response_handlers::memory::handler_memory_read_response::handle_memory_read_response;
+use crate::response_handlers::memory::handler_memory_resolve_response::handle_memory_resolve_response;
+use crate::response_handlers::memory::handler_memory_symbols_response::handle_memory_symbols_response;
 use crate::response_handlers::memory::handler_memory_write_response::handle_memory_response_write;
 use squalr_engine_api::commands::memory::memory_response::MemoryResponse;
 
 pub fn handle_memory_response(cmd: MemoryResponse) {
     match cmd {
+        MemoryResponse::BuildIds { memory_build_ids_response } => handle_memory_build_ids_response(memory_build_ids_response),
+        MemoryResponse::Classes { memory_classes_response } => handle_memory_classes_response(memory_classes_response),
+        MemoryResponse::Disassemble { memory_disassemble_response } => handle_memory_disassemble_response(memory_disassemble_response),
         MemoryResponse::Freeze { memory_freeze_response } => handle_memory_response_freeze(memory_freeze_response),
+        MemoryResponse::InferStruct { memory_infer_struct_response } => handle_memory_infer_struct_response(memory_infer_struct_response),
+        MemoryResponse::Patch { memory_patch_response } => handle_memory_patch_response(memory_patch_response),
         MemoryResponse::Read { memory_read_response } => handle_memory_read_response(memory_read_response),
+        MemoryResponse::ReadRange { memory_read_range_response } => handle_memory_read_range_response(memory_read_range_response),
+        MemoryResponse::Resolve { memory_resolve_response } => handle_memory_resolve_response(memory_resolve_response),
+        MemoryResponse::Symbols { memory_symbols_response } => handle_memory_symbols_response(memory_symbols_response),
         MemoryResponse::Write { memory_write_response } => handle_memory_response_write(memory_write_response),
     }
 }
@@ -1,4 +1,5 @@
 mod memory;
+mod plugins;
 mod pointer_scan;
 mod process;
 mod project;
@@ -6,8 +7,11 @@ mod scan;
 mod scan_results;
 mod settings;
 mod struct_scan;
+mod value_timeline;
+mod watchpoint;
 
 use crate::response_handlers::memory::handle_memory_response;
+use crate::response_handlers::plugins::handle_plugins_response;
 use crate::response_handlers::pointer_scan::handle_pointer_scan_response;
 use crate::response_handlers::process::handle_process_response;
 use crate::response_handlers::project::handle_project_response;
@@ -15,18 +19,23 @@ use crate::response_handlers::scan::handle_scan_response;
 use crate::response_handlers::scan_results::handle_scan_results_response;
 use crate::response_handlers::settings::handle_settings_response;
 use crate::response_handlers::struct_scan::handle_struct_scan_response;
+use crate::response_handlers::value_timeline::handle_value_timeline_response;
+use crate::response_handlers::watchpoint::handle_watchpoint_response;
 use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
 
 pub fn handle_engine_response(response: PrivilegedCommandResponse) {
     match response {
         PrivilegedCommandResponse::Scan(response) => handle_scan_response(response),
         PrivilegedCommandResponse::Memory(response) => handle_memory_response(response),
+        PrivilegedCommandResponse::Plugins(response) => handle_plugins_response(response),
         PrivilegedCommandResponse::Process(response) => handle_process_response(response),
         PrivilegedCommandResponse::Results(response) => handle_scan_results_response(response),
         PrivilegedCommandResponse::Project(response) => handle_project_response(response),
         PrivilegedCommandResponse::PointerScan(response) => handle_pointer_scan_response(response),
         PrivilegedCommandResponse::StructScan(response) => handle_struct_scan_response(response),
         PrivilegedCommandResponse::Settings(response) => handle_settings_response(response),
+        PrivilegedCommandResponse::ValueTimeline(response) => handle_value_timeline_response(response),
+        PrivilegedCommandResponse::Watchpoint(response) => handle_watchpoint_response(response),
         PrivilegedCommandResponse::ProjectItems(response) => {
             log::debug!("Unhandled project items response: {:?}", response);
         }
@@ -0,0 +1,14 @@
+use squalr_engine_api::commands::plugins::plugins_response::PluginsResponse;
+
+pub fn handle_plugins_add_middleware_response(plugins_response: PluginsResponse) {
+    if let PluginsResponse::AddMiddleware {
+        plugins_add_middleware_response,
+    } = plugins_response
+    {
+        if plugins_add_middleware_response.success {
+            log::info!("Memory middleware added.");
+        } else {
+            log::error!("Failed to add memory middleware.");
+        }
+    }
+}
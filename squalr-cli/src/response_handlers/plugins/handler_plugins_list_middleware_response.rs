@@ -0,0 +1,29 @@
+use squalr_engine_api::commands::plugins::plugins_response::PluginsResponse;
+
+pub fn handle_plugins_list_middleware_response(plugins_response: PluginsResponse) {
+    if let PluginsResponse::ListMiddleware {
+        plugins_list_middleware_response,
+    } = plugins_response
+    {
+        if plugins_list_middleware_response.middleware_names.is_empty() {
+            log::info!("No memory middleware is registered.");
+            return;
+        }
+
+        for middleware_name in plugins_list_middleware_response.middleware_names {
+            log::info!("middleware: {}", middleware_name);
+        }
+
+        for address_space in plugins_list_middleware_response.address_spaces {
+            log::info!(
+                "module: {} ({}), guest: 0x{:X}, host: 0x{:X}, size: 0x{:X}, endian: {}",
+                address_space.get_module_name(),
+                address_space.get_middleware_name(),
+                address_space.get_guest_base_address(),
+                address_space.get_host_base_address(),
+                address_space.get_region_size(),
+                address_space.get_endian()
+            );
+        }
+    }
+}
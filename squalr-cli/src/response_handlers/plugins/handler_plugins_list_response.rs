@@ -0,0 +1,21 @@
+use squalr_engine_api::commands::plugins::plugins_response::PluginsResponse;
+
+pub fn handle_plugins_list_response(plugins_response: PluginsResponse) {
+    if let PluginsResponse::List { plugins_list_response } = plugins_response {
+        if plugins_list_response.data_types.is_empty() {
+            log::info!("No plugin data types are loaded.");
+            return;
+        }
+
+        for data_type in plugins_list_response.data_types {
+            log::info!(
+                "plugin: {}, data_type_id: {}, display_name: {}, size: {} bytes, path: {}",
+                data_type.get_plugin_name(),
+                data_type.get_data_type_id(),
+                data_type.get_display_name(),
+                data_type.get_unit_size_in_bytes(),
+                data_type.get_plugin_path().display()
+            );
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use squalr_engine_api::commands::plugins::plugins_response::PluginsResponse;
+
+pub fn handle_plugins_load_response(plugins_response: PluginsResponse) {
+    if let PluginsResponse::Load { plugins_load_response } = plugins_response {
+        if !plugins_load_response.success {
+            log::error!("Failed to load plugin.");
+            return;
+        }
+
+        for data_type in plugins_load_response.loaded_data_types {
+            log::info!(
+                "Registered data type: {} ({}), size: {} bytes",
+                data_type.get_data_type_id(),
+                data_type.get_display_name(),
+                data_type.get_unit_size_in_bytes()
+            );
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use squalr_engine_api::commands::plugins::plugins_response::PluginsResponse;
+
+pub fn handle_plugins_remove_middleware_response(plugins_response: PluginsResponse) {
+    if let PluginsResponse::RemoveMiddleware {
+        plugins_remove_middleware_response,
+    } = plugins_response
+    {
+        if plugins_remove_middleware_response.success {
+            log::info!("Memory middleware removed.");
+        } else {
+            log::error!("No memory middleware with that name is registered.");
+        }
+    }
+}
@@ -0,0 +1,22 @@
+pub mod handler_plugins_add_middleware_response;
+pub mod handler_plugins_list_middleware_response;
+pub mod handler_plugins_list_response;
+pub mod handler_plugins_load_response;
+pub mod handler_plugins_remove_middleware_response;
+
+use crate::response_handlers::plugins::handler_plugins_add_middleware_response::handle_plugins_add_middleware_response;
+use crate::response_handlers::plugins::handler_plugins_list_middleware_response::handle_plugins_list_middleware_response;
+use crate::response_handlers::plugins::handler_plugins_list_response::handle_plugins_list_response;
+use crate::response_handlers::plugins::handler_plugins_load_response::handle_plugins_load_response;
+use crate::response_handlers::plugins::handler_plugins_remove_middleware_response::handle_plugins_remove_middleware_response;
+use squalr_engine_api::commands::plugins::plugins_response::PluginsResponse;
+
+pub fn handle_plugins_response(response: PluginsResponse) {
+    match response {
+        PluginsResponse::Load { .. } => handle_plugins_load_response(response),
+        PluginsResponse::List { .. } => handle_plugins_list_response(response),
+        PluginsResponse::AddMiddleware { .. } => handle_plugins_add_middleware_response(response),
+        PluginsResponse::RemoveMiddleware { .. } => handle_plugins_remove_middleware_response(response),
+        PluginsResponse::ListMiddleware { .. } => handle_plugins_list_middleware_response(response),
+    }
+}
@@ -0,0 +1,17 @@
+use squalr_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_auto_attach_response(process_response: ProcessResponse) {
+    if let ProcessResponse::AutoAttach { process_auto_attach_response } = process_response {
+        if !process_auto_attach_response.is_enabled {
+            log::info!("Auto-attach disabled.");
+        } else if let Some(process_info) = process_auto_attach_response.opened_process_info {
+            log::info!(
+                "Auto-attached to process_id: {}, Name: {}",
+                process_info.get_process_id_raw(),
+                process_info.get_name()
+            );
+        } else {
+            log::info!("Auto-attach enabled, waiting for process to launch.");
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use squalr_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_close_secondary_response(process_response: ProcessResponse) {
+    if let ProcessResponse::CloseSecondary {
+        process_close_secondary_response,
+    } = process_response
+    {
+        if let Some(process_info) = process_close_secondary_response.process_info {
+            log::info!(
+                "Closed secondary process_id: {}, Name: {}",
+                process_info.get_process_id_raw(),
+                process_info.get_name()
+            );
+        } else {
+            log::info!("Failed to close secondary process");
+        }
+    }
+}
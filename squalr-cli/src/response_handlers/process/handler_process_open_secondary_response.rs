@@ -0,0 +1,18 @@
+use squalr_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_open_secondary_response(process_response: ProcessResponse) {
+    if let ProcessResponse::OpenSecondary {
+        process_open_secondary_response,
+    } = process_response
+    {
+        if let Some(process_info) = process_open_secondary_response.opened_process_info {
+            log::info!(
+                "Opened secondary process_id: {}, Name: {}",
+                process_info.get_process_id_raw(),
+                process_info.get_name()
+            );
+        } else {
+            log::error!("Failed to open secondary process");
+        }
+    }
+}
@@ -1,10 +1,16 @@
+pub mod handler_process_auto_attach_response;
 pub mod handler_process_close_response;
+pub mod handler_process_close_secondary_response;
 pub mod handler_process_list_response;
 pub mod handler_process_open_response;
+pub mod handler_process_open_secondary_response;
 
+use crate::response_handlers::process::handler_process_auto_attach_response::handle_process_auto_attach_response;
 use crate::response_handlers::process::handler_process_close_response::handle_process_close_response;
+use crate::response_handlers::process::handler_process_close_secondary_response::handle_process_close_secondary_response;
 use crate::response_handlers::process::handler_process_list_response::handle_process_list_response;
 use crate::response_handlers::process::handler_process_open_response::handle_process_open_response;
+use crate::response_handlers::process::handler_process_open_secondary_response::handle_process_open_secondary_response;
 use squalr_engine_api::commands::process::process_response::ProcessResponse;
 
 pub fn handle_process_response(response: ProcessResponse) {
@@ -12,5 +18,8 @@ pub fn handle_process_response(response: ProcessResponse) {
         ProcessResponse::List { .. } => handle_process_list_response(response),
         ProcessResponse::Close { .. } => handle_process_close_response(response),
         ProcessResponse::Open { .. } => handle_process_open_response(response),
+        ProcessResponse::OpenSecondary { .. } => handle_process_open_secondary_response(response),
+        ProcessResponse::CloseSecondary { .. } => handle_process_close_secondary_response(response),
+        ProcessResponse::AutoAttach { .. } => handle_process_auto_attach_response(response),
     }
 }
@@ -27,5 +27,12 @@ pub fn handle_project_response(cmd: ProjectResponse) {
         ProjectResponse::Export { project_export_response } => {
             log::debug!("Unhandled project export response: {:?}", project_export_response);
         }
+        ProjectResponse::AddStruct { project_add_struct_response } => {
+            if project_add_struct_response.success {
+                log::info!("Added struct definition to project.");
+            } else {
+                log::error!("Failed to add struct definition to project.");
+            }
+        }
     }
 }
@@ -0,0 +1,15 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_class_instances_response(scan_response: ScanResponse) {
+    if let ScanResponse::ClassInstances { scan_class_instances_response } = scan_response {
+        match scan_class_instances_response.runtime_class {
+            Some(runtime_class) => log::info!(
+                "Found {} instances of {} (vtable 0x{:X}).",
+                scan_class_instances_response.scan_results_metadata.result_count,
+                runtime_class.get_class_name(),
+                runtime_class.get_vtable_address()
+            ),
+            None => log::error!("Failed to scan for class instances."),
+        }
+    }
+}
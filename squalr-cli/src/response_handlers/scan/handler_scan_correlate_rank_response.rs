@@ -0,0 +1,31 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_correlate_rank_response(scan_response: ScanResponse) {
+    if let ScanResponse::CorrelateRank { scan_correlate_rank_response } = scan_response {
+        if !scan_correlate_rank_response.success {
+            log::error!("Failed to rank correlation candidates.");
+            return;
+        }
+
+        log::info!(
+            "Ranked {} remaining results over {} samples.",
+            scan_correlate_rank_response.scan_results_metadata.result_count,
+            scan_correlate_rank_response.sample_count
+        );
+
+        for ranked_candidate in &scan_correlate_rank_response.ranked_candidates {
+            let latest_value = ranked_candidate
+                .latest_value
+                .map(|latest_value| latest_value.to_string())
+                .unwrap_or_else(|| "??".to_string());
+
+            log::info!(
+                "0x{:X} ({}) score {:.3}, latest value {}",
+                ranked_candidate.address,
+                ranked_candidate.data_type_ref,
+                ranked_candidate.score,
+                latest_value
+            );
+        }
+    }
+}
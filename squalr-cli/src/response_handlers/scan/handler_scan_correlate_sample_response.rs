@@ -0,0 +1,18 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_correlate_sample_response(scan_response: ScanResponse) {
+    if let ScanResponse::CorrelateSample {
+        scan_correlate_sample_response,
+    } = scan_response
+    {
+        if scan_correlate_sample_response.success {
+            log::info!(
+                "Recorded correlation sample {} across {} candidates.",
+                scan_correlate_sample_response.sample_count,
+                scan_correlate_sample_response.candidate_count
+            );
+        } else {
+            log::error!("Failed to record correlation sample.");
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_differential_response(scan_response: ScanResponse) {
+    if let ScanResponse::Differential { scan_differential_response } = scan_response {
+        if scan_differential_response.success {
+            log::info!(
+                "Differential scan kept {} results, {} could not be located in the secondary process.",
+                scan_differential_response.scan_results_metadata.result_count,
+                scan_differential_response.unmatched_result_count
+            );
+        } else {
+            log::error!("Failed to perform differential scan.");
+        }
+    }
+}
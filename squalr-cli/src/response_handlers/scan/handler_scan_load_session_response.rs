@@ -0,0 +1,19 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_load_session_response(scan_response: ScanResponse) {
+    if let ScanResponse::LoadSession { scan_load_session_response } = scan_response {
+        if scan_load_session_response.success {
+            log::info!(
+                "Restored scan session with {} results.",
+                scan_load_session_response.scan_results_metadata.result_count
+            );
+            log::info!(
+                "Session was saved with memory alignment {:?} and read mode {:?}. Current scan settings are unchanged.",
+                scan_load_session_response.scan_settings.memory_alignment,
+                scan_load_session_response.scan_settings.memory_read_mode
+            );
+        } else {
+            log::error!("Failed to load scan session.");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_refresh_regions_response(scan_response: ScanResponse) {
+    if let ScanResponse::RefreshRegions { scan_refresh_regions_response } = scan_response {
+        if scan_refresh_regions_response.success {
+            log::info!(
+                "Refreshed scan regions: {} dropped, {} split, {} added.",
+                scan_refresh_regions_response.dropped_region_count,
+                scan_refresh_regions_response.split_region_count,
+                scan_refresh_regions_response.added_region_count
+            );
+        } else {
+            log::error!("Failed to refresh scan regions.");
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_save_session_response(scan_response: ScanResponse) {
+    match scan_response {
+        ScanResponse::SaveSession { scan_save_session_response } if !scan_save_session_response.success => log::error!("Failed to save scan session."),
+        _ => {}
+    }
+}
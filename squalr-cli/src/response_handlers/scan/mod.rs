@@ -1,19 +1,40 @@
+pub mod handler_scan_class_instances_response;
 pub mod handler_scan_collect_values_response;
+pub mod handler_scan_correlate_rank_response;
+pub mod handler_scan_correlate_sample_response;
+pub mod handler_scan_differential_response;
 pub mod handler_scan_executor_response;
+pub mod handler_scan_load_session_response;
 pub mod handler_scan_new_response;
+pub mod handler_scan_refresh_regions_response;
 pub mod handler_scan_reset_response;
+pub mod handler_scan_save_session_response;
 
+use crate::response_handlers::scan::handler_scan_class_instances_response::handle_scan_class_instances_response;
 use crate::response_handlers::scan::handler_scan_collect_values_response::handle_scan_collect_values_response;
+use crate::response_handlers::scan::handler_scan_correlate_rank_response::handle_scan_correlate_rank_response;
+use crate::response_handlers::scan::handler_scan_correlate_sample_response::handle_scan_correlate_sample_response;
+use crate::response_handlers::scan::handler_scan_differential_response::handle_scan_differential_response;
 use crate::response_handlers::scan::handler_scan_executor_response::handle_scan_execute_response;
+use crate::response_handlers::scan::handler_scan_load_session_response::handle_scan_load_session_response;
 use crate::response_handlers::scan::handler_scan_new_response::handle_scan_new_response;
+use crate::response_handlers::scan::handler_scan_refresh_regions_response::handle_scan_refresh_regions_response;
 use crate::response_handlers::scan::handler_scan_reset_response::handle_scan_reset_response;
+use crate::response_handlers::scan::handler_scan_save_session_response::handle_scan_save_session_response;
 use squalr_engine_api::commands::scan::scan_response::ScanResponse;
 
 pub fn handle_scan_response(cmd: ScanResponse) {
     match cmd {
         ScanResponse::Reset { .. } => handle_scan_reset_response(cmd),
         ScanResponse::New { .. } => handle_scan_new_response(cmd),
+        ScanResponse::RefreshRegions { .. } => handle_scan_refresh_regions_response(cmd),
         ScanResponse::CollectValues { .. } => handle_scan_collect_values_response(cmd),
         ScanResponse::ElementScan { .. } => handle_scan_execute_response(cmd),
+        ScanResponse::ClassInstances { .. } => handle_scan_class_instances_response(cmd),
+        ScanResponse::CorrelateSample { .. } => handle_scan_correlate_sample_response(cmd),
+        ScanResponse::CorrelateRank { .. } => handle_scan_correlate_rank_response(cmd),
+        ScanResponse::Differential { .. } => handle_scan_differential_response(cmd),
+        ScanResponse::SaveSession { .. } => handle_scan_save_session_response(cmd),
+        ScanResponse::LoadSession { .. } => handle_scan_load_session_response(cmd),
     }
 }
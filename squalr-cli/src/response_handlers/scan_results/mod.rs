@@ -23,5 +23,11 @@ pub fn handle_scan_results_response(cmd: ScanResultsResponse) {
         ScanResultsResponse::Delete { scan_results_delete_response } => {
             log::debug!("Unhandled scan results delete response: {:?}", scan_results_delete_response);
         }
+        ScanResultsResponse::Export { scan_results_export_response } => {
+            log::debug!("Unhandled scan results export response: {:?}", scan_results_export_response);
+        }
+        ScanResultsResponse::Import { scan_results_import_response } => {
+            log::debug!("Unhandled scan results import response: {:?}", scan_results_import_response);
+        }
     }
 }
@@ -0,0 +1,29 @@
+use squalr_engine_api::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+
+pub fn handle_value_timeline_list_response(value_timeline_response: ValueTimelineResponse) {
+    if let ValueTimelineResponse::List { value_timeline_list_response } = value_timeline_response {
+        if value_timeline_list_response.value_timelines.is_empty() {
+            log::info!("No value timelines are being recorded.");
+        }
+
+        for value_timeline_info in value_timeline_list_response.value_timelines {
+            log::info!(
+                "Timeline {}: {} ({}) every {} ms, {}/{} samples, latest {}",
+                value_timeline_info.timeline_id,
+                value_timeline_info.target.get_display_name(),
+                value_timeline_info
+                    .target
+                    .get_data_type_ref()
+                    .get_data_type_id(),
+                value_timeline_info.interval_ms,
+                value_timeline_info.sample_count,
+                value_timeline_info.capacity,
+                value_timeline_info
+                    .latest_sample
+                    .as_ref()
+                    .and_then(|sample| sample.get_value())
+                    .unwrap_or("??")
+            );
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use squalr_engine_api::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+
+pub fn handle_value_timeline_query_response(value_timeline_response: ValueTimelineResponse) {
+    if let ValueTimelineResponse::Query { value_timeline_query_response } = value_timeline_response {
+        let Some(value_timeline_info) = value_timeline_query_response.value_timeline_info else {
+            log::error!("Value timeline not found.");
+            return;
+        };
+
+        log::info!(
+            "Timeline {}: {} ({} samples)",
+            value_timeline_info.timeline_id,
+            value_timeline_info.target.get_display_name(),
+            value_timeline_query_response.samples.len()
+        );
+
+        for sample in value_timeline_query_response.samples {
+            log::info!("{} {}", sample.get_timestamp_ms(), sample.get_value().unwrap_or("??"));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use squalr_engine_api::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+
+pub fn handle_value_timeline_remove_response(value_timeline_response: ValueTimelineResponse) {
+    match value_timeline_response {
+        ValueTimelineResponse::Remove {
+            value_timeline_remove_response,
+        } if !value_timeline_remove_response.success => {
+            log::error!("Failed to remove value timeline.")
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,22 @@
+pub mod handler_value_timeline_export_response;
+pub mod handler_value_timeline_list_response;
+pub mod handler_value_timeline_query_response;
+pub mod handler_value_timeline_record_response;
+pub mod handler_value_timeline_remove_response;
+
+use crate::response_handlers::value_timeline::handler_value_timeline_export_response::handle_value_timeline_export_response;
+use crate::response_handlers::value_timeline::handler_value_timeline_list_response::handle_value_timeline_list_response;
+use crate::response_handlers::value_timeline::handler_value_timeline_query_response::handle_value_timeline_query_response;
+use crate::response_handlers::value_timeline::handler_value_timeline_record_response::handle_value_timeline_record_response;
+use crate::response_handlers::value_timeline::handler_value_timeline_remove_response::handle_value_timeline_remove_response;
+use squalr_engine_api::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+
+pub fn handle_value_timeline_response(cmd: ValueTimelineResponse) {
+    match cmd {
+        ValueTimelineResponse::Record { .. } => handle_value_timeline_record_response(cmd),
+        ValueTimelineResponse::Remove { .. } => handle_value_timeline_remove_response(cmd),
+        ValueTimelineResponse::List { .. } => handle_value_timeline_list_response(cmd),
+        ValueTimelineResponse::Query { .. } => handle_value_timeline_query_response(cmd),
+        ValueTimelineResponse::Export { .. } => handle_value_timeline_export_response(cmd),
+    }
+}
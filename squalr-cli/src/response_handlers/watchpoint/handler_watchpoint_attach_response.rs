@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::watchpoint::watchpoint_response::WatchpointResponse;
+
+pub fn handle_watchpoint_attach_response(watchpoint_response: WatchpointResponse) {
+    if let WatchpointResponse::Attach { watchpoint_attach_response } = watchpoint_response {
+        if watchpoint_attach_response.success {
+            log::info!("Debugger attached.");
+        } else {
+            log::error!("Failed to attach debugger.");
+        }
+    }
+}
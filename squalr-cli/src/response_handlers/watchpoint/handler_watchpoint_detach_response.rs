@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::watchpoint::watchpoint_response::WatchpointResponse;
+
+pub fn handle_watchpoint_detach_response(watchpoint_response: WatchpointResponse) {
+    if let WatchpointResponse::Detach { watchpoint_detach_response } = watchpoint_response {
+        if watchpoint_detach_response.success {
+            log::info!("Debugger detached.");
+        } else {
+            log::error!("Failed to detach debugger.");
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use squalr_engine_api::commands::watchpoint::watchpoint_response::WatchpointResponse;
+
+pub fn handle_watchpoint_hits_response(watchpoint_response: WatchpointResponse) {
+    if let WatchpointResponse::Hits { watchpoint_hits_response } = watchpoint_response {
+        if watchpoint_hits_response.watchpoint_hits.is_empty() {
+            log::info!("No watchpoint hits.");
+        }
+
+        for watchpoint_hit in watchpoint_hits_response.watchpoint_hits {
+            let registers = watchpoint_hit
+                .get_registers()
+                .get_registers()
+                .iter()
+                .map(|(register_name, value)| format!("{}=0x{:X}", register_name, value))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            log::info!(
+                "Watchpoint {}: 0x{:X} hit {} times (thread {}) {}",
+                watchpoint_hit.get_watchpoint_id(),
+                watchpoint_hit.get_instruction_pointer(),
+                watchpoint_hit.get_hit_count(),
+                watchpoint_hit.get_thread_id(),
+                registers
+            );
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::watchpoint::watchpoint_response::WatchpointResponse;
+
+pub fn handle_watchpoint_remove_response(watchpoint_response: WatchpointResponse) {
+    if let WatchpointResponse::Remove { watchpoint_remove_response } = watchpoint_response {
+        if watchpoint_remove_response.success {
+            log::info!("Watchpoint removed.");
+        } else {
+            log::error!("Failed to remove watchpoint.");
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use squalr_engine_api::commands::watchpoint::watchpoint_response::WatchpointResponse;
+
+pub fn handle_watchpoint_set_response(watchpoint_response: WatchpointResponse) {
+    if let WatchpointResponse::Set { watchpoint_set_response } = watchpoint_response {
+        match watchpoint_set_response.watchpoint_id {
+            Some(watchpoint_id) => log::info!("Watchpoint set with id: {}", watchpoint_id),
+            None => log::error!("Failed to set watchpoint."),
+        }
+    }
+}
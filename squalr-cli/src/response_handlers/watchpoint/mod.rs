@@ -0,0 +1,22 @@
+pub mod handler_watchpoint_attach_response;
+pub mod handler_watchpoint_detach_response;
+pub mod handler_watchpoint_hits_response;
+pub mod handler_watchpoint_remove_response;
+pub mod handler_watchpoint_set_response;
+
+use crate::response_handlers::watchpoint::handler_watchpoint_attach_response::handle_watchpoint_attach_response;
+use crate::response_handlers::watchpoint::handler_watchpoint_detach_response::handle_watchpoint_detach_response;
+use crate::response_handlers::watchpoint::handler_watchpoint_hits_response::handle_watchpoint_hits_response;
+use crate::response_handlers::watchpoint::handler_watchpoint_remove_response::handle_watchpoint_remove_response;
+use crate::response_handlers::watchpoint::handler_watchpoint_set_response::handle_watchpoint_set_response;
+use squalr_engine_api::commands::watchpoint::watchpoint_response::WatchpointResponse;
+
+pub fn handle_watchpoint_response(cmd: WatchpointResponse) {
+    match cmd {
+        WatchpointResponse::Attach { .. } => handle_watchpoint_attach_response(cmd),
+        WatchpointResponse::Set { .. } => handle_watchpoint_set_response(cmd),
+        WatchpointResponse::Remove { .. } => handle_watchpoint_remove_response(cmd),
+        WatchpointResponse::Hits { .. } => handle_watchpoint_hits_response(cmd),
+        WatchpointResponse::Detach { .. } => handle_watchpoint_detach_response(cmd),
+    }
+}
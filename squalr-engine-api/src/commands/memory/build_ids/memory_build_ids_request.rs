@@ -0,0 +1,32 @@
+use crate::commands::memory::build_ids::memory_build_ids_response::MemoryBuildIdsResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::memory::module_build_identity::ModuleBuildIdentity;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryBuildIdsRequest {
+    #[structopt(short = "m", long)]
+    pub module_names: Vec<String>,
+    #[structopt(skip)]
+    pub expected_build_identities: Vec<ModuleBuildIdentity>,
+}
+
+impl PrivilegedCommandRequest for MemoryBuildIdsRequest {
+    type ResponseType = MemoryBuildIdsResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::BuildIds {
+            memory_build_ids_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryBuildIdsResponse> for MemoryResponse {
+    fn from(memory_build_ids_response: MemoryBuildIdsResponse) -> Self {
+        MemoryResponse::BuildIds { memory_build_ids_response }
+    }
+}
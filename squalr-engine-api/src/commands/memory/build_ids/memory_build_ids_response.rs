@@ -0,0 +1,30 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::module_build_identity::ModuleBuildIdentity;
+use crate::structures::memory::module_build_mismatch::ModuleBuildMismatch;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryBuildIdsResponse {
+    /// The build identities of the requested and expected modules that are loaded in the opened process.
+    pub module_build_identities: Vec<ModuleBuildIdentity>,
+    /// The expected module builds that differ from the loaded module builds.
+    pub stale_module_builds: Vec<ModuleBuildMismatch>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryBuildIdsResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::BuildIds {
+            memory_build_ids_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::BuildIds { memory_build_ids_response }) = response {
+            Ok(memory_build_ids_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_build_ids_request;
+pub mod memory_build_ids_response;
@@ -0,0 +1,31 @@
+use crate::commands::memory::classes::memory_classes_response::MemoryClassesResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryClassesRequest {
+    #[structopt(short = "m", long)]
+    pub module_name: String,
+    #[structopt(short = "f", long)]
+    pub filter: Option<String>,
+}
+
+impl PrivilegedCommandRequest for MemoryClassesRequest {
+    type ResponseType = MemoryClassesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Classes {
+            memory_classes_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryClassesResponse> for MemoryResponse {
+    fn from(memory_classes_response: MemoryClassesResponse) -> Self {
+        MemoryResponse::Classes { memory_classes_response }
+    }
+}
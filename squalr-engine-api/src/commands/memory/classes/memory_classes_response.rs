@@ -0,0 +1,29 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::runtime_classes::runtime_class::RuntimeClass;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryClassesResponse {
+    /// The classes discovered in the requested module that match the filter, ordered by vtable address.
+    pub runtime_classes: Vec<RuntimeClass>,
+    /// Whether the requested module is loaded and was scanned for classes.
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryClassesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Classes {
+            memory_classes_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Classes { memory_classes_response }) = response {
+            Ok(memory_classes_response)
+        } else {
+            Err(response)
+        }
+    }
+}
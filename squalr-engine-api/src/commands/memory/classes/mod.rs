@@ -0,0 +1,2 @@
+pub mod memory_classes_request;
+pub mod memory_classes_response;
@@ -0,0 +1,47 @@
+use crate::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::disassembly::instruction_set::InstructionSet;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryDisassembleRequest {
+    #[structopt(short = "a", long, default_value = "0", parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+
+    #[structopt(short = "e", long, default_value = "")]
+    #[serde(default)]
+    pub address_expression: String,
+
+    #[structopt(short = "c", long, default_value = "32")]
+    pub instruction_count: u32,
+
+    #[structopt(short = "b", long, default_value = "0")]
+    pub instructions_before: u32,
+
+    #[structopt(short = "i", long)]
+    pub instruction_set: Option<InstructionSet>,
+}
+
+impl PrivilegedCommandRequest for MemoryDisassembleRequest {
+    type ResponseType = MemoryDisassembleResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Disassemble {
+            memory_disassemble_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryDisassembleResponse> for MemoryResponse {
+    fn from(memory_disassemble_response: MemoryDisassembleResponse) -> Self {
+        MemoryResponse::Disassemble { memory_disassemble_response }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+use crate::structures::disassembly::instruction_set::InstructionSet;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryDisassembleResponse {
+    /// The absolute address that was disassembled, after resolving any module or address expression.
+    pub address: u64,
+    /// The instruction set used to decode the code bytes.
+    pub instruction_set: Option<InstructionSet>,
+    /// The decoded instructions in address order, including any requested instructions preceding `address`.
+    pub instructions: Vec<DisassembledInstruction>,
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryDisassembleResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Disassemble {
+            memory_disassemble_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Disassemble { memory_disassemble_response }) = response {
+            Ok(memory_disassemble_response)
+        } else {
+            Err(response)
+        }
+    }
+}
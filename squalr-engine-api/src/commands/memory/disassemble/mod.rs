@@ -0,0 +1,2 @@
+pub mod memory_disassemble_request;
+pub mod memory_disassemble_response;
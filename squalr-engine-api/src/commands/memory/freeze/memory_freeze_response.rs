@@ -6,6 +6,23 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct MemoryFreezeResponse {
     pub failed_freeze_target_count: u64,
+    /// The indices of the requested freeze targets that failed.
+    pub failed_freeze_target_indices: Vec<u64>,
+}
+
+impl MemoryFreezeResponse {
+    /// Creates a response from the indices of the freeze targets that failed.
+    pub fn from_failed_target_indices(failed_freeze_target_indices: Vec<u64>) -> Self {
+        Self {
+            failed_freeze_target_count: failed_freeze_target_indices.len() as u64,
+            failed_freeze_target_indices,
+        }
+    }
+
+    /// Creates a response in which every one of the requested freeze targets failed.
+    pub fn all_failed(freeze_target_count: usize) -> Self {
+        Self::from_failed_target_indices((0..freeze_target_count as u64).collect())
+    }
 }
 
 impl TypedPrivilegedCommandResponse for MemoryFreezeResponse {
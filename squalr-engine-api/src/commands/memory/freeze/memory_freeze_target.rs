@@ -1,8 +1,28 @@
+use crate::structures::freezing::freeze_policy::FreezePolicy;
+use crate::structures::memory::pointer::Pointer;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct MemoryFreezeTarget {
     pub address: u64,
     pub module_name: String,
+    /// Address expression locating the target, which takes precedence over the address and module when non-empty.
+    #[serde(default)]
+    pub address_expression: String,
     pub data_type_id: String,
+    #[serde(default)]
+    pub freeze_policy: FreezePolicy,
+}
+
+impl MemoryFreezeTarget {
+    /// Gets the freeze list key for this target.
+    pub fn to_pointer(&self) -> Pointer {
+        let address_expression = self.address_expression.trim();
+
+        if address_expression.is_empty() {
+            Pointer::new(self.address, vec![], self.module_name.clone())
+        } else {
+            Pointer::new_from_address_expression(address_expression.to_string())
+        }
+    }
 }
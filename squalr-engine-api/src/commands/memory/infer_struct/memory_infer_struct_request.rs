@@ -0,0 +1,34 @@
+use crate::commands::memory::infer_struct::memory_infer_struct_response::MemoryInferStructResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryInferStructRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+    #[structopt(short = "s", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub size: u64,
+    #[structopt(short = "n", long, default_value = "")]
+    pub struct_name: String,
+}
+
+impl PrivilegedCommandRequest for MemoryInferStructRequest {
+    type ResponseType = MemoryInferStructResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::InferStruct {
+            memory_infer_struct_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryInferStructResponse> for MemoryResponse {
+    fn from(memory_infer_struct_response: MemoryInferStructResponse) -> Self {
+        MemoryResponse::InferStruct { memory_infer_struct_response }
+    }
+}
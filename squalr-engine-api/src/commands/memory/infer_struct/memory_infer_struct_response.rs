@@ -0,0 +1,30 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::struct_inference::inferred_struct_field::InferredStructField;
+use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryInferStructResponse {
+    /// The classification of each field of the analyzed range, ordered by offset.
+    pub inferred_fields: Vec<InferredStructField>,
+    /// The draft struct definition proposed from the inferred fields, or `None` if the range could not be read.
+    pub symbolic_struct_definition: Option<SymbolicStructDefinition>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryInferStructResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::InferStruct {
+            memory_infer_struct_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::InferStruct { memory_infer_struct_response }) = response {
+            Ok(memory_infer_struct_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_infer_struct_request;
+pub mod memory_infer_struct_response;
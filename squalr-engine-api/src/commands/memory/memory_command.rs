@@ -1,19 +1,59 @@
+use crate::commands::memory::build_ids::memory_build_ids_request::MemoryBuildIdsRequest;
+use crate::commands::memory::classes::memory_classes_request::MemoryClassesRequest;
+use crate::commands::memory::disassemble::memory_disassemble_request::MemoryDisassembleRequest;
 use crate::commands::memory::freeze::memory_freeze_request::MemoryFreezeRequest;
+use crate::commands::memory::infer_struct::memory_infer_struct_request::MemoryInferStructRequest;
+use crate::commands::memory::patch::memory_patch_request::MemoryPatchRequest;
 use crate::commands::memory::read::memory_read_request::MemoryReadRequest;
+use crate::commands::memory::read_range::memory_read_range_request::MemoryReadRangeRequest;
+use crate::commands::memory::resolve::memory_resolve_request::MemoryResolveRequest;
+use crate::commands::memory::symbols::memory_symbols_request::MemorySymbolsRequest;
 use crate::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
 pub enum MemoryCommand {
+    BuildIds {
+        #[structopt(flatten)]
+        memory_build_ids_request: MemoryBuildIdsRequest,
+    },
+    Classes {
+        #[structopt(flatten)]
+        memory_classes_request: MemoryClassesRequest,
+    },
+    Disassemble {
+        #[structopt(flatten)]
+        memory_disassemble_request: MemoryDisassembleRequest,
+    },
     Freeze {
         #[structopt(flatten)]
         memory_freeze_request: MemoryFreezeRequest,
     },
+    InferStruct {
+        #[structopt(flatten)]
+        memory_infer_struct_request: MemoryInferStructRequest,
+    },
+    Patch {
+        #[structopt(flatten)]
+        memory_patch_request: MemoryPatchRequest,
+    },
     Read {
         #[structopt(flatten)]
         memory_read_request: MemoryReadRequest,
     },
+    ReadRange {
+        #[structopt(flatten)]
+        memory_read_range_request: MemoryReadRangeRequest,
+    },
+    Resolve {
+        #[structopt(flatten)]
+        memory_resolve_request: MemoryResolveRequest,
+    },
+    Symbols {
+        #[structopt(flatten)]
+        memory_symbols_request: MemorySymbolsRequest,
+    },
     Write {
         #[structopt(flatten)]
         memory_write_request: MemoryWriteRequest,
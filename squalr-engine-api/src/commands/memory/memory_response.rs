@@ -1,11 +1,27 @@
+use crate::commands::memory::build_ids::memory_build_ids_response::MemoryBuildIdsResponse;
+use crate::commands::memory::classes::memory_classes_response::MemoryClassesResponse;
+use crate::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
 use crate::commands::memory::freeze::memory_freeze_response::MemoryFreezeResponse;
+use crate::commands::memory::infer_struct::memory_infer_struct_response::MemoryInferStructResponse;
+use crate::commands::memory::patch::memory_patch_response::MemoryPatchResponse;
 use crate::commands::memory::read::memory_read_response::MemoryReadResponse;
+use crate::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+use crate::commands::memory::resolve::memory_resolve_response::MemoryResolveResponse;
+use crate::commands::memory::symbols::memory_symbols_response::MemorySymbolsResponse;
 use crate::commands::memory::write::memory_write_response::MemoryWriteResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MemoryResponse {
+    BuildIds { memory_build_ids_response: MemoryBuildIdsResponse },
+    Classes { memory_classes_response: MemoryClassesResponse },
+    Disassemble { memory_disassemble_response: MemoryDisassembleResponse },
     Freeze { memory_freeze_response: MemoryFreezeResponse },
+    InferStruct { memory_infer_struct_response: MemoryInferStructResponse },
+    Patch { memory_patch_response: MemoryPatchResponse },
     Read { memory_read_response: MemoryReadResponse },
+    ReadRange { memory_read_range_response: MemoryReadRangeResponse },
+    Resolve { memory_resolve_response: MemoryResolveResponse },
+    Symbols { memory_symbols_response: MemorySymbolsResponse },
     Write { memory_write_response: MemoryWriteResponse },
 }
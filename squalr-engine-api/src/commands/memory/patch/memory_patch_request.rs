@@ -0,0 +1,32 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::patch::memory_patch_response::MemoryPatchResponse;
+use crate::commands::memory::patch::memory_patch_target::MemoryPatchTarget;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryPatchRequest {
+    #[structopt(skip)]
+    pub patch_targets: Vec<MemoryPatchTarget>,
+    #[structopt(short = "p", long = "patched")]
+    pub is_patched: bool,
+}
+
+impl PrivilegedCommandRequest for MemoryPatchRequest {
+    type ResponseType = MemoryPatchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Patch {
+            memory_patch_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryPatchResponse> for MemoryResponse {
+    fn from(memory_patch_response: MemoryPatchResponse) -> Self {
+        MemoryResponse::Patch { memory_patch_response }
+    }
+}
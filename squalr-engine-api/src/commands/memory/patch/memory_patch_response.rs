@@ -0,0 +1,42 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryPatchResponse {
+    pub failed_patch_target_count: u64,
+    /// The indices of the requested patch targets that failed.
+    pub failed_patch_target_indices: Vec<u64>,
+}
+
+impl MemoryPatchResponse {
+    /// Creates a response from the indices of the patch targets that failed.
+    pub fn from_failed_target_indices(failed_patch_target_indices: Vec<u64>) -> Self {
+        Self {
+            failed_patch_target_count: failed_patch_target_indices.len() as u64,
+            failed_patch_target_indices,
+        }
+    }
+
+    /// Creates a response in which every one of the requested patch targets failed.
+    pub fn all_failed(patch_target_count: usize) -> Self {
+        Self::from_failed_target_indices((0..patch_target_count as u64).collect())
+    }
+}
+
+impl TypedPrivilegedCommandResponse for MemoryPatchResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Patch {
+            memory_patch_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Patch { memory_patch_response }) = response {
+            Ok(memory_patch_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryPatchTarget {
+    pub address: u64,
+    pub module_name: String,
+    pub original_bytes: Vec<u8>,
+    pub replacement: CodePatchReplacement,
+}
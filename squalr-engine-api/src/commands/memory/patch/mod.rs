@@ -0,0 +1,3 @@
+pub mod memory_patch_request;
+pub mod memory_patch_response;
+pub mod memory_patch_target;
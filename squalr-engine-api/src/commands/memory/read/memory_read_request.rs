@@ -11,13 +11,16 @@ use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
 pub struct MemoryReadRequest {
-    // JIRA: Should probably bias hex and fall back on int? Maybe this can be made more explicit.
-    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    #[structopt(short = "a", long, default_value = "0", parse(try_from_str = Conversions::parse_hex_or_int))]
     pub address: u64,
 
-    #[structopt(short = "m")]
+    #[structopt(short = "m", long, default_value = "")]
     pub module_name: String,
 
+    #[structopt(short = "e", long, default_value = "")]
+    #[serde(default)]
+    pub address_expression: String,
+
     #[structopt(short = "v")]
     pub symbolic_struct_definition: SymbolicStructDefinition,
 
@@ -0,0 +1,40 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryReadRangeRequest {
+    #[structopt(short = "a", long, default_value = "0", parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+
+    #[structopt(short = "e", long, default_value = "")]
+    #[serde(default)]
+    pub address_expression: String,
+
+    #[structopt(short = "l", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub length: u64,
+}
+
+impl PrivilegedCommandRequest for MemoryReadRangeRequest {
+    type ResponseType = MemoryReadRangeResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::ReadRange {
+            memory_read_range_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryReadRangeResponse> for MemoryResponse {
+    fn from(memory_read_range_response: MemoryReadRangeResponse) -> Self {
+        MemoryResponse::ReadRange { memory_read_range_response }
+    }
+}
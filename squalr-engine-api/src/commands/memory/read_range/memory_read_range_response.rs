@@ -0,0 +1,40 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryReadRangeResponse {
+    /// The absolute address of the first byte read, after resolving any module.
+    pub address: u64,
+    /// The bytes read. Bytes on unreadable pages are zeroed.
+    pub bytes: Vec<u8>,
+    /// Whether each byte in `bytes` was successfully read.
+    pub readable: Vec<bool>,
+    pub success: bool,
+}
+
+impl MemoryReadRangeResponse {
+    pub fn is_readable(
+        &self,
+        offset: usize,
+    ) -> bool {
+        self.readable.get(offset).copied().unwrap_or(false)
+    }
+}
+
+impl TypedPrivilegedCommandResponse for MemoryReadRangeResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::ReadRange {
+            memory_read_range_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::ReadRange { memory_read_range_response }) = response {
+            Ok(memory_read_range_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_read_range_request;
+pub mod memory_read_range_response;
@@ -0,0 +1,29 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::resolve::memory_resolve_response::MemoryResolveResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryResolveRequest {
+    #[structopt(short = "e", long)]
+    pub address_expression: String,
+}
+
+impl PrivilegedCommandRequest for MemoryResolveRequest {
+    type ResponseType = MemoryResolveResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Resolve {
+            memory_resolve_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryResolveResponse> for MemoryResponse {
+    fn from(memory_resolve_response: MemoryResolveResponse) -> Self {
+        MemoryResponse::Resolve { memory_resolve_response }
+    }
+}
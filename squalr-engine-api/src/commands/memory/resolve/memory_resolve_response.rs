@@ -0,0 +1,33 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryResolveResponse {
+    /// The absolute address that the expression evaluated to.
+    pub address: u64,
+    /// The module containing the resolved address, or empty if the address is not within a module.
+    pub module_name: String,
+    /// The offset of the resolved address into `module_name`, or the absolute address if there is no module.
+    pub module_offset: u64,
+    /// The `module!symbol+offset` label of the resolved address, or empty if no module symbol covers it.
+    pub symbol_label: String,
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryResolveResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Resolve {
+            memory_resolve_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Resolve { memory_resolve_response }) = response {
+            Ok(memory_resolve_response)
+        } else {
+            Err(response)
+        }
+    }
+}
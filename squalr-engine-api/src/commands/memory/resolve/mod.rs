@@ -0,0 +1,2 @@
+pub mod memory_resolve_request;
+pub mod memory_resolve_response;
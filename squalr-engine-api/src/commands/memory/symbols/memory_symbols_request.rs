@@ -0,0 +1,31 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::symbols::memory_symbols_response::MemorySymbolsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemorySymbolsRequest {
+    #[structopt(short = "m", long)]
+    pub module_name: String,
+    #[structopt(short = "f", long)]
+    pub filter: Option<String>,
+}
+
+impl PrivilegedCommandRequest for MemorySymbolsRequest {
+    type ResponseType = MemorySymbolsResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Symbols {
+            memory_symbols_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemorySymbolsResponse> for MemoryResponse {
+    fn from(memory_symbols_response: MemorySymbolsResponse) -> Self {
+        MemoryResponse::Symbols { memory_symbols_response }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::module_symbol_table::ModuleSymbolTable;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemorySymbolsResponse {
+    /// The symbols of the requested module that match the filter, or `None` if the module symbols could not be loaded.
+    pub module_symbol_table: Option<ModuleSymbolTable>,
+}
+
+impl TypedPrivilegedCommandResponse for MemorySymbolsResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Symbols {
+            memory_symbols_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Symbols { memory_symbols_response }) = response {
+            Ok(memory_symbols_response)
+        } else {
+            Err(response)
+        }
+    }
+}
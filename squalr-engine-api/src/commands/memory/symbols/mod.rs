@@ -0,0 +1,2 @@
+pub mod memory_symbols_request;
+pub mod memory_symbols_response;
@@ -9,14 +9,17 @@ use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
 pub struct MemoryWriteRequest {
-    // JIRA: Seems sus to just have generic int or hex parser.
-    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    #[structopt(short = "a", long, default_value = "0", parse(try_from_str = Conversions::parse_hex_or_int))]
     pub address: u64,
 
-    #[structopt(short = "m")]
+    #[structopt(short = "m", long, default_value = "")]
     pub module_name: String,
 
-    #[structopt(short = "v")]
+    #[structopt(short = "e", long, default_value = "")]
+    #[serde(default)]
+    pub address_expression: String,
+
+    #[structopt(short = "v", required = true)]
     pub value: Vec<u8>,
 }
 
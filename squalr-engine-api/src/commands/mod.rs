@@ -1,4 +1,5 @@
 pub mod memory;
+pub mod plugins;
 pub mod pointer_scan;
 pub mod privileged_command;
 pub mod privileged_command_request;
@@ -14,3 +15,5 @@ pub mod trackable_tasks;
 pub mod unprivileged_command;
 pub mod unprivileged_command_request;
 pub mod unprivileged_command_response;
+pub mod value_timeline;
+pub mod watchpoint;
@@ -0,0 +1,2 @@
+pub mod plugins_add_middleware_request;
+pub mod plugins_add_middleware_response;
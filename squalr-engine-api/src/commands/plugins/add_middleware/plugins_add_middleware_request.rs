@@ -0,0 +1,73 @@
+use crate::commands::plugins::add_middleware::plugins_add_middleware_response::PluginsAddMiddlewareResponse;
+use crate::commands::plugins::plugins_command::PluginsCommand;
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::memory::endian::Endian;
+use crate::structures::memory::memory_middleware_definition::MemoryMiddlewareDefinition;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PluginsAddMiddlewareRequest {
+    #[structopt(short = "n", long)]
+    pub middleware_name: String,
+
+    #[structopt(short = "m", long)]
+    pub module_name: String,
+
+    /// Space separated hex bytes, where `??` matches any byte.
+    #[structopt(short = "s", long)]
+    pub signature: String,
+
+    #[structopt(short = "o", long, default_value = "0", allow_hyphen_values = true)]
+    pub signature_offset: i64,
+
+    #[structopt(short = "g", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub guest_base_address: u64,
+
+    #[structopt(short = "z", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub region_size: u64,
+
+    #[structopt(short = "b", long)]
+    pub big_endian: bool,
+
+    /// Whether guest pointers are 8 bytes. Most emulated systems are 32-bit, regardless of the bitness of the emulator.
+    #[structopt(long)]
+    pub guest_64_bit: bool,
+}
+
+impl PluginsAddMiddlewareRequest {
+    pub fn to_definition(&self) -> MemoryMiddlewareDefinition {
+        MemoryMiddlewareDefinition::new(
+            self.middleware_name.clone(),
+            self.module_name.clone(),
+            self.signature.clone(),
+            self.signature_offset,
+            self.guest_base_address,
+            self.region_size,
+            if self.big_endian { Endian::Big } else { Endian::Little },
+            if self.guest_64_bit { Bitness::Bit64 } else { Bitness::Bit32 },
+        )
+    }
+}
+
+impl PrivilegedCommandRequest for PluginsAddMiddlewareRequest {
+    type ResponseType = PluginsAddMiddlewareResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Plugins(PluginsCommand::AddMiddleware {
+            plugins_add_middleware_request: self.clone(),
+        })
+    }
+}
+
+impl From<PluginsAddMiddlewareResponse> for PluginsResponse {
+    fn from(plugins_add_middleware_response: PluginsAddMiddlewareResponse) -> Self {
+        PluginsResponse::AddMiddleware {
+            plugins_add_middleware_response,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PluginsAddMiddlewareResponse {
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for PluginsAddMiddlewareResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Plugins(PluginsResponse::AddMiddleware {
+            plugins_add_middleware_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Plugins(PluginsResponse::AddMiddleware {
+            plugins_add_middleware_response,
+        }) = response
+        {
+            Ok(plugins_add_middleware_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod plugins_list_request;
+pub mod plugins_list_response;
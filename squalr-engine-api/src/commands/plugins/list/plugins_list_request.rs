@@ -0,0 +1,26 @@
+use crate::commands::plugins::list::plugins_list_response::PluginsListResponse;
+use crate::commands::plugins::plugins_command::PluginsCommand;
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PluginsListRequest {}
+
+impl PrivilegedCommandRequest for PluginsListRequest {
+    type ResponseType = PluginsListResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Plugins(PluginsCommand::List {
+            plugins_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<PluginsListResponse> for PluginsResponse {
+    fn from(plugins_list_response: PluginsListResponse) -> Self {
+        PluginsResponse::List { plugins_list_response }
+    }
+}
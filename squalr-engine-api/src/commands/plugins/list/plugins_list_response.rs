@@ -0,0 +1,26 @@
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::data_types::plugin_types::data_type_plugin_metadata::DataTypePluginMetadata;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PluginsListResponse {
+    pub data_types: Vec<DataTypePluginMetadata>,
+}
+
+impl TypedPrivilegedCommandResponse for PluginsListResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Plugins(PluginsResponse::List {
+            plugins_list_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Plugins(PluginsResponse::List { plugins_list_response }) = response {
+            Ok(plugins_list_response)
+        } else {
+            Err(response)
+        }
+    }
+}
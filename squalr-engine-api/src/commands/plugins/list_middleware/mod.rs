@@ -0,0 +1,2 @@
+pub mod plugins_list_middleware_request;
+pub mod plugins_list_middleware_response;
@@ -0,0 +1,32 @@
+use crate::commands::plugins::list_middleware::plugins_list_middleware_response::PluginsListMiddlewareResponse;
+use crate::commands::plugins::plugins_command::PluginsCommand;
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PluginsListMiddlewareRequest {
+    /// Discards cached guest address spaces and locates them again, ie after the emulator has booted a game.
+    #[structopt(short = "r", long)]
+    pub refresh: bool,
+}
+
+impl PrivilegedCommandRequest for PluginsListMiddlewareRequest {
+    type ResponseType = PluginsListMiddlewareResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Plugins(PluginsCommand::ListMiddleware {
+            plugins_list_middleware_request: self.clone(),
+        })
+    }
+}
+
+impl From<PluginsListMiddlewareResponse> for PluginsResponse {
+    fn from(plugins_list_middleware_response: PluginsListMiddlewareResponse) -> Self {
+        PluginsResponse::ListMiddleware {
+            plugins_list_middleware_response,
+        }
+    }
+}
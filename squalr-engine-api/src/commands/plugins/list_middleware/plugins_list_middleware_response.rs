@@ -0,0 +1,30 @@
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::virtual_address_space::VirtualAddressSpace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PluginsListMiddlewareResponse {
+    pub middleware_names: Vec<String>,
+    pub address_spaces: Vec<VirtualAddressSpace>,
+}
+
+impl TypedPrivilegedCommandResponse for PluginsListMiddlewareResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Plugins(PluginsResponse::ListMiddleware {
+            plugins_list_middleware_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Plugins(PluginsResponse::ListMiddleware {
+            plugins_list_middleware_response,
+        }) = response
+        {
+            Ok(plugins_list_middleware_response)
+        } else {
+            Err(response)
+        }
+    }
+}
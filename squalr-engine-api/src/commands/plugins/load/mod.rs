@@ -0,0 +1,2 @@
+pub mod plugins_load_request;
+pub mod plugins_load_response;
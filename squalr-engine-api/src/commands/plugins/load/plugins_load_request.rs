@@ -0,0 +1,30 @@
+use crate::commands::plugins::load::plugins_load_response::PluginsLoadResponse;
+use crate::commands::plugins::plugins_command::PluginsCommand;
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PluginsLoadRequest {
+    #[structopt(short = "p", long)]
+    pub plugin_path: PathBuf,
+}
+
+impl PrivilegedCommandRequest for PluginsLoadRequest {
+    type ResponseType = PluginsLoadResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Plugins(PluginsCommand::Load {
+            plugins_load_request: self.clone(),
+        })
+    }
+}
+
+impl From<PluginsLoadResponse> for PluginsResponse {
+    fn from(plugins_load_response: PluginsLoadResponse) -> Self {
+        PluginsResponse::Load { plugins_load_response }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::data_types::plugin_types::data_type_plugin_metadata::DataTypePluginMetadata;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PluginsLoadResponse {
+    pub success: bool,
+    pub loaded_data_types: Vec<DataTypePluginMetadata>,
+}
+
+impl TypedPrivilegedCommandResponse for PluginsLoadResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Plugins(PluginsResponse::Load {
+            plugins_load_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Plugins(PluginsResponse::Load { plugins_load_response }) = response {
+            Ok(plugins_load_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod add_middleware;
+pub mod list;
+pub mod list_middleware;
+pub mod load;
+pub mod plugins_command;
+pub mod plugins_response;
+pub mod remove_middleware;
@@ -0,0 +1,31 @@
+use crate::commands::plugins::add_middleware::plugins_add_middleware_request::PluginsAddMiddlewareRequest;
+use crate::commands::plugins::list::plugins_list_request::PluginsListRequest;
+use crate::commands::plugins::list_middleware::plugins_list_middleware_request::PluginsListMiddlewareRequest;
+use crate::commands::plugins::load::plugins_load_request::PluginsLoadRequest;
+use crate::commands::plugins::remove_middleware::plugins_remove_middleware_request::PluginsRemoveMiddlewareRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum PluginsCommand {
+    Load {
+        #[structopt(flatten)]
+        plugins_load_request: PluginsLoadRequest,
+    },
+    List {
+        #[structopt(flatten)]
+        plugins_list_request: PluginsListRequest,
+    },
+    AddMiddleware {
+        #[structopt(flatten)]
+        plugins_add_middleware_request: PluginsAddMiddlewareRequest,
+    },
+    RemoveMiddleware {
+        #[structopt(flatten)]
+        plugins_remove_middleware_request: PluginsRemoveMiddlewareRequest,
+    },
+    ListMiddleware {
+        #[structopt(flatten)]
+        plugins_list_middleware_request: PluginsListMiddlewareRequest,
+    },
+}
@@ -0,0 +1,25 @@
+use crate::commands::plugins::add_middleware::plugins_add_middleware_response::PluginsAddMiddlewareResponse;
+use crate::commands::plugins::list::plugins_list_response::PluginsListResponse;
+use crate::commands::plugins::list_middleware::plugins_list_middleware_response::PluginsListMiddlewareResponse;
+use crate::commands::plugins::load::plugins_load_response::PluginsLoadResponse;
+use crate::commands::plugins::remove_middleware::plugins_remove_middleware_response::PluginsRemoveMiddlewareResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum PluginsResponse {
+    Load {
+        plugins_load_response: PluginsLoadResponse,
+    },
+    List {
+        plugins_list_response: PluginsListResponse,
+    },
+    AddMiddleware {
+        plugins_add_middleware_response: PluginsAddMiddlewareResponse,
+    },
+    RemoveMiddleware {
+        plugins_remove_middleware_response: PluginsRemoveMiddlewareResponse,
+    },
+    ListMiddleware {
+        plugins_list_middleware_response: PluginsListMiddlewareResponse,
+    },
+}
@@ -0,0 +1,2 @@
+pub mod plugins_remove_middleware_request;
+pub mod plugins_remove_middleware_response;
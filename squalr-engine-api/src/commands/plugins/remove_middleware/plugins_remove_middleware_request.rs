@@ -0,0 +1,31 @@
+use crate::commands::plugins::plugins_command::PluginsCommand;
+use crate::commands::plugins::plugins_response::PluginsResponse;
+use crate::commands::plugins::remove_middleware::plugins_remove_middleware_response::PluginsRemoveMiddlewareResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PluginsRemoveMiddlewareRequest {
+    #[structopt(short = "n", long)]
+    pub middleware_name: String,
+}
+
+impl PrivilegedCommandRequest for PluginsRemoveMiddlewareRequest {
+    type ResponseType = PluginsRemoveMiddlewareResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Plugins(PluginsCommand::RemoveMiddleware {
+            plugins_remove_middleware_request: self.clone(),
+        })
+    }
+}
+
+impl From<PluginsRemoveMiddlewareResponse> for PluginsResponse {
+    fn from(plugins_remove_middleware_response: PluginsRemoveMiddlewareResponse) -> Self {
+        PluginsResponse::RemoveMiddleware {
+            plugins_remove_middleware_response,
+        }
+    }
+}
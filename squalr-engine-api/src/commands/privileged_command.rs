@@ -1,4 +1,5 @@
 use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::plugins::plugins_command::PluginsCommand;
 use crate::commands::pointer_scan::pointer_scan_command::PointerScanCommand;
 use crate::commands::process::process_command::ProcessCommand;
 use crate::commands::scan::scan_command::ScanCommand;
@@ -6,6 +7,8 @@ use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
 use crate::commands::settings::settings_command::SettingsCommand;
 use crate::commands::struct_scan::struct_scan_command::StructScanCommand;
 use crate::commands::trackable_tasks::trackable_tasks_command::TrackableTasksCommand;
+use crate::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use crate::commands::watchpoint::watchpoint_command::WatchpointCommand;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -14,6 +17,9 @@ pub enum PrivilegedCommand {
     #[structopt(alias = "mem", alias = "m")]
     Memory(MemoryCommand),
 
+    #[structopt(alias = "plug", alias = "pl")]
+    Plugins(PluginsCommand),
+
     #[structopt(alias = "proc", alias = "pr")]
     Process(ProcessCommand),
 
@@ -34,4 +40,10 @@ pub enum PrivilegedCommand {
 
     #[structopt(alias = "tasks", alias = "tt")]
     TrackableTasks(TrackableTasksCommand),
+
+    #[structopt(alias = "timeline", alias = "vt")]
+    ValueTimeline(ValueTimelineCommand),
+
+    #[structopt(alias = "wp")]
+    Watchpoint(WatchpointCommand),
 }
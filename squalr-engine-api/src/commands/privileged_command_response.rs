@@ -1,4 +1,5 @@
 use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::plugins::plugins_response::PluginsResponse;
 use crate::commands::pointer_scan::pointer_scan_response::PointerScanResponse;
 use crate::commands::process::process_response::ProcessResponse;
 use crate::commands::project::project_response::ProjectResponse;
@@ -8,11 +9,14 @@ use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
 use crate::commands::settings::settings_response::SettingsResponse;
 use crate::commands::struct_scan::struct_scan_response::StructScanResponse;
 use crate::commands::trackable_tasks::trackable_tasks_response::TrackableTasksResponse;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PrivilegedCommandResponse {
     Memory(MemoryResponse),
+    Plugins(PluginsResponse),
     Process(ProcessResponse),
     Results(ScanResultsResponse),
     Project(ProjectResponse),
@@ -22,6 +26,8 @@ pub enum PrivilegedCommandResponse {
     StructScan(StructScanResponse),
     Settings(SettingsResponse),
     TrackableTasks(TrackableTasksResponse),
+    ValueTimeline(ValueTimelineResponse),
+    Watchpoint(WatchpointResponse),
 }
 
 pub trait TypedPrivilegedCommandResponse: Sized {
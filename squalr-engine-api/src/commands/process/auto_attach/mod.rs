@@ -0,0 +1,2 @@
+pub mod process_auto_attach_request;
+pub mod process_auto_attach_response;
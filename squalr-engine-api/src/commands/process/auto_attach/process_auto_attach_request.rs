@@ -0,0 +1,31 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::process::auto_attach::process_auto_attach_response::ProcessAutoAttachResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessAutoAttachRequest {
+    #[structopt(short = "n", long)]
+    pub process_name: Option<String>,
+    #[structopt(short = "m", long)]
+    pub match_case: bool,
+}
+
+impl PrivilegedCommandRequest for ProcessAutoAttachRequest {
+    type ResponseType = ProcessAutoAttachResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Process(ProcessCommand::AutoAttach {
+            process_auto_attach_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessAutoAttachResponse> for ProcessResponse {
+    fn from(process_auto_attach_response: ProcessAutoAttachResponse) -> Self {
+        ProcessResponse::AutoAttach { process_auto_attach_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod process_close_secondary_request;
+pub mod process_close_secondary_response;
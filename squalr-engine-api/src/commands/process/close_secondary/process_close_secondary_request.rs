@@ -0,0 +1,28 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::process::close_secondary::process_close_secondary_response::ProcessCloseSecondaryResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessCloseSecondaryRequest {}
+
+impl PrivilegedCommandRequest for ProcessCloseSecondaryRequest {
+    type ResponseType = ProcessCloseSecondaryResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Process(ProcessCommand::CloseSecondary {
+            process_close_secondary_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessCloseSecondaryResponse> for ProcessResponse {
+    fn from(process_close_secondary_response: ProcessCloseSecondaryResponse) -> Self {
+        ProcessResponse::CloseSecondary {
+            process_close_secondary_response,
+        }
+    }
+}
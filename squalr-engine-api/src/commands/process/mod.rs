@@ -1,5 +1,8 @@
+pub mod auto_attach;
 pub mod close;
+pub mod close_secondary;
 pub mod list;
 pub mod open;
+pub mod open_secondary;
 pub mod process_command;
 pub mod process_response;
@@ -0,0 +1,2 @@
+pub mod process_open_secondary_request;
+pub mod process_open_secondary_response;
@@ -0,0 +1,35 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::process::open_secondary::process_open_secondary_response::ProcessOpenSecondaryResponse;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessOpenSecondaryRequest {
+    #[structopt(short = "p", long)]
+    pub process_id: Option<u32>,
+    #[structopt(short = "n", long)]
+    pub search_name: Option<String>,
+    #[structopt(short = "m", long)]
+    pub match_case: bool,
+}
+
+impl PrivilegedCommandRequest for ProcessOpenSecondaryRequest {
+    type ResponseType = ProcessOpenSecondaryResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Process(ProcessCommand::OpenSecondary {
+            process_open_secondary_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessOpenSecondaryResponse> for ProcessResponse {
+    fn from(process_open_secondary_response: ProcessOpenSecondaryResponse) -> Self {
+        ProcessResponse::OpenSecondary {
+            process_open_secondary_response,
+        }
+    }
+}
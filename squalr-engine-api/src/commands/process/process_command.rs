@@ -1,6 +1,9 @@
+use crate::commands::process::auto_attach::process_auto_attach_request::ProcessAutoAttachRequest;
 use crate::commands::process::close::process_close_request::ProcessCloseRequest;
+use crate::commands::process::close_secondary::process_close_secondary_request::ProcessCloseSecondaryRequest;
 use crate::commands::process::list::process_list_request::ProcessListRequest;
 use crate::commands::process::open::process_open_request::ProcessOpenRequest;
+use crate::commands::process::open_secondary::process_open_secondary_request::ProcessOpenSecondaryRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -18,4 +21,16 @@ pub enum ProcessCommand {
         #[structopt(flatten)]
         process_close_request: ProcessCloseRequest,
     },
+    OpenSecondary {
+        #[structopt(flatten)]
+        process_open_secondary_request: ProcessOpenSecondaryRequest,
+    },
+    CloseSecondary {
+        #[structopt(flatten)]
+        process_close_secondary_request: ProcessCloseSecondaryRequest,
+    },
+    AutoAttach {
+        #[structopt(flatten)]
+        process_auto_attach_request: ProcessAutoAttachRequest,
+    },
 }
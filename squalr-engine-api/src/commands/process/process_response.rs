@@ -1,11 +1,29 @@
+use crate::commands::process::auto_attach::process_auto_attach_response::ProcessAutoAttachResponse;
 use crate::commands::process::close::process_close_response::ProcessCloseResponse;
+use crate::commands::process::close_secondary::process_close_secondary_response::ProcessCloseSecondaryResponse;
 use crate::commands::process::list::process_list_response::ProcessListResponse;
 use crate::commands::process::open::process_open_response::ProcessOpenResponse;
+use crate::commands::process::open_secondary::process_open_secondary_response::ProcessOpenSecondaryResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ProcessResponse {
-    List { process_list_response: ProcessListResponse },
-    Close { process_close_response: ProcessCloseResponse },
-    Open { process_open_response: ProcessOpenResponse },
+    List {
+        process_list_response: ProcessListResponse,
+    },
+    Close {
+        process_close_response: ProcessCloseResponse,
+    },
+    Open {
+        process_open_response: ProcessOpenResponse,
+    },
+    OpenSecondary {
+        process_open_secondary_response: ProcessOpenSecondaryResponse,
+    },
+    CloseSecondary {
+        process_close_secondary_response: ProcessCloseSecondaryResponse,
+    },
+    AutoAttach {
+        process_auto_attach_response: ProcessAutoAttachResponse,
+    },
 }
@@ -0,0 +1,2 @@
+pub mod project_add_struct_request;
+pub mod project_add_struct_response;
@@ -0,0 +1,26 @@
+use crate::commands::{
+    project::project_response::ProjectResponse,
+    unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectAddStructResponse {
+    pub success: bool,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectAddStructResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::Project(ProjectResponse::AddStruct {
+            project_add_struct_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::Project(ProjectResponse::AddStruct { project_add_struct_response }) = response {
+            Ok(project_add_struct_response)
+        } else {
+            Err(response)
+        }
+    }
+}
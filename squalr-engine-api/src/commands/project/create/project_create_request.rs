@@ -13,6 +13,10 @@ pub struct ProjectCreateRequest {
 
     #[structopt(short = "n", long)]
     pub project_name: Option<String>,
+
+    #[structopt(short = "a", long)]
+    #[serde(default)]
+    pub auto_attach_process_name: Option<String>,
 }
 
 impl UnprivilegedCommandRequest for ProjectCreateRequest {
@@ -1,3 +1,4 @@
+pub mod add_struct;
 pub mod close;
 pub mod create;
 pub mod delete;
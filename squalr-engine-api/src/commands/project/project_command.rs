@@ -1,3 +1,4 @@
+use crate::commands::project::add_struct::project_add_struct_request::ProjectAddStructRequest;
 use crate::commands::project::create::project_create_request::ProjectCreateRequest;
 use crate::commands::project::export::project_export_request::ProjectExportRequest;
 use crate::commands::project::list::project_list_request::ProjectListRequest;
@@ -45,6 +46,11 @@ pub enum ProjectCommand {
         #[structopt(flatten)]
         project_export_request: ProjectExportRequest,
     },
+    /// Add a struct definition to the opened project, replacing any existing definition with the same name.
+    AddStruct {
+        #[structopt(flatten)]
+        project_add_struct_request: ProjectAddStructRequest,
+    },
     /// List all projects.
     List {
         #[structopt(flatten)]
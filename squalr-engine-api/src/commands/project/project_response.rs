@@ -1,3 +1,4 @@
+use crate::commands::project::add_struct::project_add_struct_response::ProjectAddStructResponse;
 use crate::commands::project::create::project_create_response::ProjectCreateResponse;
 use crate::commands::project::export::project_export_response::ProjectExportResponse;
 use crate::commands::project::list::project_list_response::ProjectListResponse;
@@ -16,5 +17,6 @@ pub enum ProjectResponse {
     Rename { project_rename_response: ProjectRenameResponse },
     Save { project_save_response: ProjectSaveResponse },
     Export { project_export_response: ProjectExportResponse },
+    AddStruct { project_add_struct_response: ProjectAddStructResponse },
     List { project_list_response: ProjectListResponse },
 }
@@ -3,6 +3,8 @@ use crate::commands::project_items::project_items_command::ProjectItemsCommand;
 use crate::commands::project_items::project_items_response::ProjectItemsResponse;
 use crate::commands::unprivileged_command::UnprivilegedCommand;
 use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::code_patches::code_patch_replacement::CodePatchReplacement;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use structopt::StructOpt;
@@ -17,6 +19,24 @@ pub struct ProjectItemsCreateRequest {
 
     #[structopt(short = "t", long, default_value = "directory")]
     pub project_item_type: String,
+
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: Option<u64>,
+
+    #[structopt(short = "m", long)]
+    pub module_name: Option<String>,
+
+    #[structopt(short = "e", long)]
+    pub address_expression: Option<String>,
+
+    #[structopt(short = "d", long)]
+    pub data_type_id: Option<String>,
+
+    #[structopt(short = "o", long)]
+    pub original_bytes: Option<String>,
+
+    #[structopt(short = "r", long)]
+    pub replacement: Option<CodePatchReplacement>,
 }
 
 impl UnprivilegedCommandRequest for ProjectItemsCreateRequest {
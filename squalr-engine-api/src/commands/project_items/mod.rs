@@ -8,3 +8,6 @@ pub mod project_items_command;
 pub mod project_items_response;
 pub mod rename;
 pub mod reorder;
+pub mod set_freeze_policy;
+pub mod set_hotkeys;
+pub mod trigger_hotkey;
@@ -3,6 +3,9 @@ use crate::commands::project_items::{
     create::project_items_create_request::ProjectItemsCreateRequest, delete::project_items_delete_request::ProjectItemsDeleteRequest,
     list::project_items_list_request::ProjectItemsListRequest, move_item::project_items_move_request::ProjectItemsMoveRequest,
     rename::project_items_rename_request::ProjectItemsRenameRequest, reorder::project_items_reorder_request::ProjectItemsReorderRequest,
+    set_freeze_policy::project_items_set_freeze_policy_request::ProjectItemsSetFreezePolicyRequest,
+    set_hotkeys::project_items_set_hotkeys_request::ProjectItemsSetHotkeysRequest,
+    trigger_hotkey::project_items_trigger_hotkey_request::ProjectItemsTriggerHotkeyRequest,
 };
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
@@ -49,4 +52,19 @@ pub enum ProjectItemsCommand {
         #[structopt(flatten)]
         project_items_reorder_request: ProjectItemsReorderRequest,
     },
+    /// Sets how address project items are frozen when activated.
+    SetFreezePolicy {
+        #[structopt(flatten)]
+        project_items_set_freeze_policy_request: ProjectItemsSetFreezePolicyRequest,
+    },
+    /// Sets the hotkeys bound to project items, replacing any existing bindings.
+    SetHotkeys {
+        #[structopt(flatten)]
+        project_items_set_hotkeys_request: ProjectItemsSetHotkeysRequest,
+    },
+    /// Performs the actions of every project item bound to a key chord, as if that chord were pressed.
+    TriggerHotkey {
+        #[structopt(flatten)]
+        project_items_trigger_hotkey_request: ProjectItemsTriggerHotkeyRequest,
+    },
 }
@@ -3,6 +3,9 @@ use crate::commands::project_items::{
     create::project_items_create_response::ProjectItemsCreateResponse, delete::project_items_delete_response::ProjectItemsDeleteResponse,
     list::project_items_list_response::ProjectItemsListResponse, move_item::project_items_move_response::ProjectItemsMoveResponse,
     rename::project_items_rename_response::ProjectItemsRenameResponse, reorder::project_items_reorder_response::ProjectItemsReorderResponse,
+    set_freeze_policy::project_items_set_freeze_policy_response::ProjectItemsSetFreezePolicyResponse,
+    set_hotkeys::project_items_set_hotkeys_response::ProjectItemsSetHotkeysResponse,
+    trigger_hotkey::project_items_trigger_hotkey_response::ProjectItemsTriggerHotkeyResponse,
 };
 use serde::{Deserialize, Serialize};
 
@@ -32,4 +35,13 @@ pub enum ProjectItemsResponse {
     Reorder {
         project_items_reorder_response: ProjectItemsReorderResponse,
     },
+    SetFreezePolicy {
+        project_items_set_freeze_policy_response: ProjectItemsSetFreezePolicyResponse,
+    },
+    SetHotkeys {
+        project_items_set_hotkeys_response: ProjectItemsSetHotkeysResponse,
+    },
+    TriggerHotkey {
+        project_items_trigger_hotkey_response: ProjectItemsTriggerHotkeyResponse,
+    },
 }
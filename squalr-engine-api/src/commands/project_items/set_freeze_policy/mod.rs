@@ -0,0 +1,2 @@
+pub mod project_items_set_freeze_policy_request;
+pub mod project_items_set_freeze_policy_response;
@@ -0,0 +1,35 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::project_items::set_freeze_policy::project_items_set_freeze_policy_response::ProjectItemsSetFreezePolicyResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use crate::structures::freezing::freeze_policy::FreezePolicy;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsSetFreezePolicyRequest {
+    #[structopt(short = "p", long)]
+    pub project_item_paths: Vec<String>,
+    #[structopt(flatten)]
+    #[serde(default)]
+    pub freeze_policy: FreezePolicy,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsSetFreezePolicyRequest {
+    type ResponseType = ProjectItemsSetFreezePolicyResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::SetFreezePolicy {
+            project_items_set_freeze_policy_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsSetFreezePolicyResponse> for ProjectItemsResponse {
+    fn from(project_items_set_freeze_policy_response: ProjectItemsSetFreezePolicyResponse) -> Self {
+        ProjectItemsResponse::SetFreezePolicy {
+            project_items_set_freeze_policy_response,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectItemsSetFreezePolicyResponse {
+    pub success: bool,
+    pub updated_project_item_count: u64,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectItemsSetFreezePolicyResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::SetFreezePolicy {
+            project_items_set_freeze_policy_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::SetFreezePolicy {
+            project_items_set_freeze_policy_response,
+        }) = response
+        {
+            Ok(project_items_set_freeze_policy_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::project_items::set_hotkeys::project_items_set_hotkeys_response::ProjectItemsSetHotkeysResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use crate::structures::hotkeys::hotkey_binding::HotkeyBinding;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsSetHotkeysRequest {
+    #[structopt(short = "p", long)]
+    pub project_item_paths: Vec<String>,
+    #[structopt(short = "k", long)]
+    pub hotkey_bindings: Vec<HotkeyBinding>,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsSetHotkeysRequest {
+    type ResponseType = ProjectItemsSetHotkeysResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::SetHotkeys {
+            project_items_set_hotkeys_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsSetHotkeysResponse> for ProjectItemsResponse {
+    fn from(project_items_set_hotkeys_response: ProjectItemsSetHotkeysResponse) -> Self {
+        ProjectItemsResponse::SetHotkeys {
+            project_items_set_hotkeys_response,
+        }
+    }
+}
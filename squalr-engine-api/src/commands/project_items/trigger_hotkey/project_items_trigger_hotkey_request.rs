@@ -0,0 +1,32 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::project_items::trigger_hotkey::project_items_trigger_hotkey_response::ProjectItemsTriggerHotkeyResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use crate::structures::hotkeys::key_chord::KeyChord;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsTriggerHotkeyRequest {
+    #[structopt(short = "k", long)]
+    pub key_chord: KeyChord,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsTriggerHotkeyRequest {
+    type ResponseType = ProjectItemsTriggerHotkeyResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::TriggerHotkey {
+            project_items_trigger_hotkey_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsTriggerHotkeyResponse> for ProjectItemsResponse {
+    fn from(project_items_trigger_hotkey_response: ProjectItemsTriggerHotkeyResponse) -> Self {
+        ProjectItemsResponse::TriggerHotkey {
+            project_items_trigger_hotkey_response,
+        }
+    }
+}
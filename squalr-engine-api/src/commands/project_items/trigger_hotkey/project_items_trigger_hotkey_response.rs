@@ -0,0 +1,29 @@
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::unprivileged_command_response::{TypedUnprivilegedCommandResponse, UnprivilegedCommandResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectItemsTriggerHotkeyResponse {
+    pub success: bool,
+    pub triggered_action_count: u64,
+    pub failed_action_count: u64,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectItemsTriggerHotkeyResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::TriggerHotkey {
+            project_items_trigger_hotkey_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::TriggerHotkey {
+            project_items_trigger_hotkey_response,
+        }) = response
+        {
+            Ok(project_items_trigger_hotkey_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod scan_class_instances_request;
+pub mod scan_class_instances_response;
@@ -0,0 +1,31 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::class_instances::scan_class_instances_response::ScanClassInstancesResponse;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanClassInstancesRequest {
+    #[structopt(short = "c", long)]
+    pub class_name: String,
+    #[structopt(short = "m", long)]
+    pub module_name: String,
+}
+
+impl PrivilegedCommandRequest for ScanClassInstancesRequest {
+    type ResponseType = ScanClassInstancesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::ClassInstances {
+            scan_class_instances_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanClassInstancesResponse> for ScanResponse {
+    fn from(scan_class_instances_response: ScanClassInstancesResponse) -> Self {
+        ScanResponse::ClassInstances { scan_class_instances_response }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::runtime_classes::runtime_class::RuntimeClass;
+use crate::structures::scan_results::scan_results_metadata::ScanResultsMetadata;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanClassInstancesResponse {
+    pub scan_results_metadata: ScanResultsMetadata,
+    /// The class whose instances were scanned for, or `None` if no such class was discovered in the module.
+    pub runtime_class: Option<RuntimeClass>,
+}
+
+impl TypedPrivilegedCommandResponse for ScanClassInstancesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::ClassInstances {
+            scan_class_instances_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::ClassInstances { scan_class_instances_response }) = response {
+            Ok(scan_class_instances_response)
+        } else {
+            Err(response)
+        }
+    }
+}
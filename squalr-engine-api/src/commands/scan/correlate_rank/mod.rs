@@ -0,0 +1,2 @@
+pub mod scan_correlate_rank_request;
+pub mod scan_correlate_rank_response;
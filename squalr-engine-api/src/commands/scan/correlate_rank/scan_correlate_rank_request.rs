@@ -0,0 +1,35 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::correlate_rank::scan_correlate_rank_response::ScanCorrelateRankResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use crate::structures::scanning::correlation::scan_correlation_mode::ScanCorrelationMode;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanCorrelateRankRequest {
+    #[structopt(short = "m", long, default_value = "exact")]
+    pub correlation_mode: ScanCorrelationMode,
+    #[structopt(short = "s", long, default_value = "0")]
+    pub minimum_score: f64,
+    #[structopt(short = "t", long, default_value = "0")]
+    pub tolerance: f64,
+    #[structopt(short = "l", long, default_value = "20")]
+    pub limit: u64,
+}
+
+impl PrivilegedCommandRequest for ScanCorrelateRankRequest {
+    type ResponseType = ScanCorrelateRankResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::CorrelateRank {
+            scan_correlate_rank_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanCorrelateRankResponse> for ScanResponse {
+    fn from(scan_correlate_rank_response: ScanCorrelateRankResponse) -> Self {
+        ScanResponse::CorrelateRank { scan_correlate_rank_response }
+    }
+}
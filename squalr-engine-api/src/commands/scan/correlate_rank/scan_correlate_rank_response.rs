@@ -0,0 +1,30 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::scan_results::scan_results_metadata::ScanResultsMetadata;
+use crate::structures::scanning::correlation::scan_correlation_ranking::ScanCorrelationRanking;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanCorrelateRankResponse {
+    pub success: bool,
+    pub sample_count: u64,
+    pub ranked_candidates: Vec<ScanCorrelationRanking>,
+    pub scan_results_metadata: ScanResultsMetadata,
+}
+
+impl TypedPrivilegedCommandResponse for ScanCorrelateRankResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::CorrelateRank {
+            scan_correlate_rank_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::CorrelateRank { scan_correlate_rank_response }) = response {
+            Ok(scan_correlate_rank_response)
+        } else {
+            Err(response)
+        }
+    }
+}
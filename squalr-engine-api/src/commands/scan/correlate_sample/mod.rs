@@ -0,0 +1,2 @@
+pub mod scan_correlate_sample_request;
+pub mod scan_correlate_sample_response;
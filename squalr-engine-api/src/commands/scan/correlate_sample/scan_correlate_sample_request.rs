@@ -0,0 +1,32 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::correlate_sample::scan_correlate_sample_response::ScanCorrelateSampleResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanCorrelateSampleRequest {
+    #[structopt(short = "o", long, allow_hyphen_values = true)]
+    pub observation: f64,
+    #[structopt(short = "r", long)]
+    pub restart: bool,
+}
+
+impl PrivilegedCommandRequest for ScanCorrelateSampleRequest {
+    type ResponseType = ScanCorrelateSampleResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::CorrelateSample {
+            scan_correlate_sample_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanCorrelateSampleResponse> for ScanResponse {
+    fn from(scan_correlate_sample_response: ScanCorrelateSampleResponse) -> Self {
+        ScanResponse::CorrelateSample {
+            scan_correlate_sample_response,
+        }
+    }
+}
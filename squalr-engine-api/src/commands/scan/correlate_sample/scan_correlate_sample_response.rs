@@ -0,0 +1,30 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanCorrelateSampleResponse {
+    pub success: bool,
+    pub sample_count: u64,
+    pub candidate_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ScanCorrelateSampleResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::CorrelateSample {
+            scan_correlate_sample_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::CorrelateSample {
+            scan_correlate_sample_response,
+        }) = response
+        {
+            Ok(scan_correlate_sample_response)
+        } else {
+            Err(response)
+        }
+    }
+}
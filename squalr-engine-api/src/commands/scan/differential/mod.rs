@@ -0,0 +1,2 @@
+pub mod scan_differential_request;
+pub mod scan_differential_response;
@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::differential::scan_differential_response::ScanDifferentialResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use crate::structures::scanning::differential::scan_differential_compare_type::ScanDifferentialCompareType;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanDifferentialRequest {
+    #[structopt(short = "c", long, default_value = "different")]
+    pub compare_type: ScanDifferentialCompareType,
+}
+
+impl PrivilegedCommandRequest for ScanDifferentialRequest {
+    type ResponseType = ScanDifferentialResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::Differential {
+            scan_differential_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanDifferentialResponse> for ScanResponse {
+    fn from(scan_differential_response: ScanDifferentialResponse) -> Self {
+        ScanResponse::Differential { scan_differential_response }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::scan_results::scan_results_metadata::ScanResultsMetadata;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanDifferentialResponse {
+    pub success: bool,
+    pub scan_results_metadata: ScanResultsMetadata,
+    pub secondary_scan_results_metadata: ScanResultsMetadata,
+    pub unmatched_result_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ScanDifferentialResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::Differential {
+            scan_differential_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::Differential { scan_differential_response }) = response {
+            Ok(scan_differential_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod scan_load_session_request;
+pub mod scan_load_session_response;
@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::load_session::scan_load_session_response::ScanLoadSessionResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanLoadSessionRequest {
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+}
+
+impl PrivilegedCommandRequest for ScanLoadSessionRequest {
+    type ResponseType = ScanLoadSessionResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::LoadSession {
+            scan_load_session_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanLoadSessionResponse> for ScanResponse {
+    fn from(scan_load_session_response: ScanLoadSessionResponse) -> Self {
+        ScanResponse::LoadSession { scan_load_session_response }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::scan_results::scan_results_metadata::ScanResultsMetadata;
+use crate::structures::settings::scan_settings::ScanSettings;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanLoadSessionResponse {
+    pub success: bool,
+    pub scan_results_metadata: ScanResultsMetadata,
+    /// The scan settings in effect when the session was saved. These are not applied, such that loading a session never rewrites
+    /// the persisted configuration.
+    pub scan_settings: ScanSettings,
+}
+
+impl TypedPrivilegedCommandResponse for ScanLoadSessionResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::LoadSession {
+            scan_load_session_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::LoadSession { scan_load_session_response }) = response {
+            Ok(scan_load_session_response)
+        } else {
+            Err(response)
+        }
+    }
+}
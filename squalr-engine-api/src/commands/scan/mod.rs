@@ -1,6 +1,13 @@
+pub mod class_instances;
 pub mod collect_values;
+pub mod correlate_rank;
+pub mod correlate_sample;
+pub mod differential;
 pub mod element_scan;
+pub mod load_session;
 pub mod new;
+pub mod refresh_regions;
 pub mod reset;
+pub mod save_session;
 pub mod scan_command;
 pub mod scan_response;
@@ -0,0 +1,2 @@
+pub mod scan_refresh_regions_request;
+pub mod scan_refresh_regions_response;
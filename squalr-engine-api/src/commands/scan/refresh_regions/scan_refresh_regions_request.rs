@@ -0,0 +1,29 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::refresh_regions::scan_refresh_regions_response::ScanRefreshRegionsResponse;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanRefreshRegionsRequest {
+    #[structopt(short = "s", long)]
+    pub seed_new_regions: bool,
+}
+
+impl PrivilegedCommandRequest for ScanRefreshRegionsRequest {
+    type ResponseType = ScanRefreshRegionsResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::RefreshRegions {
+            scan_refresh_regions_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanRefreshRegionsResponse> for ScanResponse {
+    fn from(scan_refresh_regions_response: ScanRefreshRegionsResponse) -> Self {
+        ScanResponse::RefreshRegions { scan_refresh_regions_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanRefreshRegionsResponse {
+    pub success: bool,
+    pub dropped_region_count: u64,
+    pub split_region_count: u64,
+    pub added_region_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ScanRefreshRegionsResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::RefreshRegions {
+            scan_refresh_regions_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::RefreshRegions { scan_refresh_regions_response }) = response {
+            Ok(scan_refresh_regions_response)
+        } else {
+            Err(response)
+        }
+    }
+}
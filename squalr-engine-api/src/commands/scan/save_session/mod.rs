@@ -0,0 +1,2 @@
+pub mod scan_save_session_request;
+pub mod scan_save_session_response;
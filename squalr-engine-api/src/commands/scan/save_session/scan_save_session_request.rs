@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::save_session::scan_save_session_response::ScanSaveSessionResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanSaveSessionRequest {
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+}
+
+impl PrivilegedCommandRequest for ScanSaveSessionRequest {
+    type ResponseType = ScanSaveSessionResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::SaveSession {
+            scan_save_session_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanSaveSessionResponse> for ScanResponse {
+    fn from(scan_save_session_response: ScanSaveSessionResponse) -> Self {
+        ScanResponse::SaveSession { scan_save_session_response }
+    }
+}
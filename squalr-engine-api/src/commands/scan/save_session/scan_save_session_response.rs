@@ -0,0 +1,25 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanSaveSessionResponse {
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for ScanSaveSessionResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::SaveSession {
+            scan_save_session_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::SaveSession { scan_save_session_response }) = response {
+            Ok(scan_save_session_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -1,7 +1,14 @@
+use crate::commands::scan::class_instances::scan_class_instances_request::ScanClassInstancesRequest;
 use crate::commands::scan::collect_values::scan_collect_values_request::ScanCollectValuesRequest;
+use crate::commands::scan::correlate_rank::scan_correlate_rank_request::ScanCorrelateRankRequest;
+use crate::commands::scan::correlate_sample::scan_correlate_sample_request::ScanCorrelateSampleRequest;
+use crate::commands::scan::differential::scan_differential_request::ScanDifferentialRequest;
 use crate::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use crate::commands::scan::load_session::scan_load_session_request::ScanLoadSessionRequest;
 use crate::commands::scan::new::scan_new_request::ScanNewRequest;
+use crate::commands::scan::refresh_regions::scan_refresh_regions_request::ScanRefreshRegionsRequest;
 use crate::commands::scan::reset::scan_reset_request::ScanResetRequest;
+use crate::commands::scan::save_session::scan_save_session_request::ScanSaveSessionRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -18,6 +25,12 @@ pub enum ScanCommand {
         #[structopt(flatten)]
         scan_new_request: ScanNewRequest,
     },
+    /// Reconciles the current scan with the latest virtual memory pages, dropping freed regions and trimming resized ones.
+    /// Newly allocated regions are added to the scan, matching all values if existing results should be preserved.
+    RefreshRegions {
+        #[structopt(flatten)]
+        scan_refresh_regions_request: ScanRefreshRegionsRequest,
+    },
     /// Collect values for the current scan if one exist, otherwise collect initial values.
     CollectValues {
         #[structopt(flatten)]
@@ -28,4 +41,36 @@ pub enum ScanCommand {
         #[structopt(flatten)]
         element_scan_request: ElementScanRequest,
     },
+    /// Starts a new scan for objects whose first pointer is the vtable of a class discovered from module run-time type information.
+    ClassInstances {
+        #[structopt(flatten)]
+        scan_class_instances_request: ScanClassInstancesRequest,
+    },
+    /// Records an observed value of the signal being searched for, sampling the value of every remaining scan result alongside it.
+    /// The first sample, or a restarted one, begins a new correlation over the current scan results.
+    CorrelateSample {
+        #[structopt(flatten)]
+        scan_correlate_sample_request: ScanCorrelateSampleRequest,
+    },
+    /// Ranks sampled scan results by how well their history tracks the observations, narrowing away poorly correlated results.
+    CorrelateRank {
+        #[structopt(flatten)]
+        scan_correlate_rank_request: ScanCorrelateRankRequest,
+    },
+    /// Compares the value of each scan result against the same module-relative address in the secondary process,
+    /// keeping results whose values differ, or match, between the two processes.
+    Differential {
+        #[structopt(flatten)]
+        scan_differential_request: ScanDifferentialRequest,
+    },
+    /// Saves the current scan, including its regions, values, filters, and scan settings, to a compressed session file.
+    SaveSession {
+        #[structopt(flatten)]
+        scan_save_session_request: ScanSaveSessionRequest,
+    },
+    /// Replaces the current scan with one restored from a session file, such that narrowing can resume where it left off.
+    LoadSession {
+        #[structopt(flatten)]
+        scan_load_session_request: ScanLoadSessionRequest,
+    },
 }
@@ -1,13 +1,49 @@
+use crate::commands::scan::class_instances::scan_class_instances_response::ScanClassInstancesResponse;
 use crate::commands::scan::collect_values::scan_collect_values_response::ScanCollectValuesResponse;
+use crate::commands::scan::correlate_rank::scan_correlate_rank_response::ScanCorrelateRankResponse;
+use crate::commands::scan::correlate_sample::scan_correlate_sample_response::ScanCorrelateSampleResponse;
+use crate::commands::scan::differential::scan_differential_response::ScanDifferentialResponse;
 use crate::commands::scan::element_scan::element_scan_response::ElementScanResponse;
+use crate::commands::scan::load_session::scan_load_session_response::ScanLoadSessionResponse;
 use crate::commands::scan::new::scan_new_response::ScanNewResponse;
+use crate::commands::scan::refresh_regions::scan_refresh_regions_response::ScanRefreshRegionsResponse;
 use crate::commands::scan::reset::scan_reset_response::ScanResetResponse;
+use crate::commands::scan::save_session::scan_save_session_response::ScanSaveSessionResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ScanResponse {
-    New { scan_new_response: ScanNewResponse },
-    Reset { scan_reset_response: ScanResetResponse },
-    CollectValues { scan_value_collector_response: ScanCollectValuesResponse },
-    ElementScan { element_scan_response: ElementScanResponse },
+    New {
+        scan_new_response: ScanNewResponse,
+    },
+    Reset {
+        scan_reset_response: ScanResetResponse,
+    },
+    RefreshRegions {
+        scan_refresh_regions_response: ScanRefreshRegionsResponse,
+    },
+    CollectValues {
+        scan_value_collector_response: ScanCollectValuesResponse,
+    },
+    ElementScan {
+        element_scan_response: ElementScanResponse,
+    },
+    ClassInstances {
+        scan_class_instances_response: ScanClassInstancesResponse,
+    },
+    CorrelateSample {
+        scan_correlate_sample_response: ScanCorrelateSampleResponse,
+    },
+    CorrelateRank {
+        scan_correlate_rank_response: ScanCorrelateRankResponse,
+    },
+    Differential {
+        scan_differential_response: ScanDifferentialResponse,
+    },
+    SaveSession {
+        scan_save_session_response: ScanSaveSessionResponse,
+    },
+    LoadSession {
+        scan_load_session_response: ScanLoadSessionResponse,
+    },
 }
@@ -0,0 +1,2 @@
+pub mod scan_results_export_request;
+pub mod scan_results_export_response;
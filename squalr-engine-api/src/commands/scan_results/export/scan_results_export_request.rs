@@ -0,0 +1,34 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan_results::export::scan_results_export_response::ScanResultsExportResponse;
+use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::structures::scan_results::scan_results_export_format::ScanResultsExportFormat;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanResultsExportRequest {
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+
+    #[structopt(long)]
+    pub format: Option<ScanResultsExportFormat>,
+}
+
+impl PrivilegedCommandRequest for ScanResultsExportRequest {
+    type ResponseType = ScanResultsExportResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Results(ScanResultsCommand::Export {
+            results_export_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanResultsExportResponse> for ScanResultsResponse {
+    fn from(scan_results_export_response: ScanResultsExportResponse) -> Self {
+        ScanResultsResponse::Export { scan_results_export_response }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanResultsExportResponse {
+    pub success: bool,
+    pub exported_result_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ScanResultsExportResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Results(ScanResultsResponse::Export {
+            scan_results_export_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Results(ScanResultsResponse::Export { scan_results_export_response }) = response {
+            Ok(scan_results_export_response)
+        } else {
+            Err(response)
+        }
+    }
+}
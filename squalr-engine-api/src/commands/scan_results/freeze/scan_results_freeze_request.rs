@@ -3,6 +3,7 @@ use crate::commands::privileged_command_request::PrivilegedCommandRequest;
 use crate::commands::scan_results::freeze::scan_results_freeze_response::ScanResultsFreezeResponse;
 use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
 use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::structures::freezing::freeze_policy::FreezePolicy;
 use crate::structures::scan_results::scan_result_ref::ScanResultRef;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
@@ -13,6 +14,9 @@ pub struct ScanResultsFreezeRequest {
     pub scan_result_refs: Vec<ScanResultRef>,
     #[structopt(short = "f", long)]
     pub is_frozen: bool,
+    #[structopt(flatten)]
+    #[serde(default)]
+    pub freeze_policy: FreezePolicy,
 }
 
 impl PrivilegedCommandRequest for ScanResultsFreezeRequest {
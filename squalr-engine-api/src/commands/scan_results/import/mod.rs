@@ -0,0 +1,2 @@
+pub mod scan_results_import_request;
+pub mod scan_results_import_response;
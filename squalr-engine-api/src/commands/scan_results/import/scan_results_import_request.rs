@@ -0,0 +1,34 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan_results::import::scan_results_import_response::ScanResultsImportResponse;
+use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::structures::scan_results::scan_results_export_format::ScanResultsExportFormat;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanResultsImportRequest {
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+
+    #[structopt(long)]
+    pub format: Option<ScanResultsExportFormat>,
+}
+
+impl PrivilegedCommandRequest for ScanResultsImportRequest {
+    type ResponseType = ScanResultsImportResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Results(ScanResultsCommand::Import {
+            results_import_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanResultsImportResponse> for ScanResultsResponse {
+    fn from(scan_results_import_response: ScanResultsImportResponse) -> Self {
+        ScanResultsResponse::Import { scan_results_import_response }
+    }
+}
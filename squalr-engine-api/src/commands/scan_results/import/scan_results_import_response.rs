@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanResultsImportResponse {
+    pub success: bool,
+    pub imported_result_count: u64,
+    /// The number of results that could not be placed, ie because they duplicate another result, are of an unknown data type,
+    /// are in a module that is not loaded, or lie outside of allocated memory.
+    pub unresolved_result_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ScanResultsImportResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Results(ScanResultsResponse::Import {
+            scan_results_import_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Results(ScanResultsResponse::Import { scan_results_import_response }) = response {
+            Ok(scan_results_import_response)
+        } else {
+            Err(response)
+        }
+    }
+}
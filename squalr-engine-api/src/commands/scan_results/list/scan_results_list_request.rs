@@ -3,6 +3,7 @@ use crate::commands::privileged_command_request::PrivilegedCommandRequest;
 use crate::commands::scan_results::list::scan_results_list_response::ScanResultsListResponse;
 use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
 use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -10,6 +11,9 @@ use structopt::StructOpt;
 pub struct ScanResultsListRequest {
     #[structopt(short = "p", long)]
     pub page_index: u64,
+    #[structopt(flatten)]
+    #[serde(default)]
+    pub query_options: ScanResultsQueryOptions,
 }
 
 impl PrivilegedCommandRequest for ScanResultsListRequest {
@@ -1,5 +1,7 @@
 pub mod delete;
+pub mod export;
 pub mod freeze;
+pub mod import;
 pub mod list;
 pub mod query;
 pub mod refresh;
@@ -3,6 +3,7 @@ use crate::commands::privileged_command_request::PrivilegedCommandRequest;
 use crate::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
 use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
 use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -12,6 +13,9 @@ use structopt::StructOpt;
 pub struct ScanResultsQueryRequest {
     #[structopt(short = "p", long)]
     pub page_index: u64,
+    #[structopt(flatten)]
+    #[serde(default)]
+    pub query_options: ScanResultsQueryOptions,
 }
 
 impl PrivilegedCommandRequest for ScanResultsQueryRequest {
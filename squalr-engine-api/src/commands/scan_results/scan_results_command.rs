@@ -1,5 +1,7 @@
 use crate::commands::scan_results::delete::scan_results_delete_request::ScanResultsDeleteRequest;
+use crate::commands::scan_results::export::scan_results_export_request::ScanResultsExportRequest;
 use crate::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
+use crate::commands::scan_results::import::scan_results_import_request::ScanResultsImportRequest;
 use crate::commands::scan_results::list::scan_results_list_request::ScanResultsListRequest;
 use crate::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
 use crate::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
@@ -39,4 +41,14 @@ pub enum ScanResultsCommand {
         #[structopt(flatten)]
         results_delete_request: ScanResultsDeleteRequest,
     },
+    /// Exports all scan results to a CSV or JSON file.
+    Export {
+        #[structopt(flatten)]
+        results_export_request: ScanResultsExportRequest,
+    },
+    /// Replaces the current scan results with results imported from a CSV or JSON file.
+    Import {
+        #[structopt(flatten)]
+        results_import_request: ScanResultsImportRequest,
+    },
 }
@@ -1,5 +1,7 @@
 use crate::commands::scan_results::delete::scan_results_delete_response::ScanResultsDeleteResponse;
+use crate::commands::scan_results::export::scan_results_export_response::ScanResultsExportResponse;
 use crate::commands::scan_results::freeze::scan_results_freeze_response::ScanResultsFreezeResponse;
+use crate::commands::scan_results::import::scan_results_import_response::ScanResultsImportResponse;
 use crate::commands::scan_results::list::scan_results_list_response::ScanResultsListResponse;
 use crate::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
 use crate::commands::scan_results::refresh::scan_results_refresh_response::ScanResultsRefreshResponse;
@@ -26,4 +28,10 @@ pub enum ScanResultsResponse {
     Delete {
         scan_results_delete_response: ScanResultsDeleteResponse,
     },
+    Export {
+        scan_results_export_response: ScanResultsExportResponse,
+    },
+    Import {
+        scan_results_import_response: ScanResultsImportResponse,
+    },
 }
@@ -0,0 +1,2 @@
+pub mod value_timeline_export_request;
+pub mod value_timeline_export_response;
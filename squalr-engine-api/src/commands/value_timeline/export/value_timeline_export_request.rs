@@ -0,0 +1,35 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::value_timeline::export::value_timeline_export_response::ValueTimelineExportResponse;
+use crate::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ValueTimelineExportRequest {
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+
+    #[structopt(short = "t", long)]
+    pub timeline_ids: Vec<u32>,
+}
+
+impl PrivilegedCommandRequest for ValueTimelineExportRequest {
+    type ResponseType = ValueTimelineExportResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::ValueTimeline(ValueTimelineCommand::Export {
+            value_timeline_export_request: self.clone(),
+        })
+    }
+}
+
+impl From<ValueTimelineExportResponse> for ValueTimelineResponse {
+    fn from(value_timeline_export_response: ValueTimelineExportResponse) -> Self {
+        ValueTimelineResponse::Export {
+            value_timeline_export_response,
+        }
+    }
+}
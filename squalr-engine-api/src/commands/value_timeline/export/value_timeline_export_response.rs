@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ValueTimelineExportResponse {
+    pub success: bool,
+    pub exported_sample_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ValueTimelineExportResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Export {
+            value_timeline_export_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Export {
+            value_timeline_export_response,
+        }) = response
+        {
+            Ok(value_timeline_export_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod value_timeline_list_request;
+pub mod value_timeline_list_response;
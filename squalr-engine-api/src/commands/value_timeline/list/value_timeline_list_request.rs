@@ -0,0 +1,26 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::value_timeline::list::value_timeline_list_response::ValueTimelineListResponse;
+use crate::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ValueTimelineListRequest {}
+
+impl PrivilegedCommandRequest for ValueTimelineListRequest {
+    type ResponseType = ValueTimelineListResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::ValueTimeline(ValueTimelineCommand::List {
+            value_timeline_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<ValueTimelineListResponse> for ValueTimelineResponse {
+    fn from(value_timeline_list_response: ValueTimelineListResponse) -> Self {
+        ValueTimelineResponse::List { value_timeline_list_response }
+    }
+}
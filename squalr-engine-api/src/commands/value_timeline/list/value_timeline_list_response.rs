@@ -0,0 +1,26 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use crate::structures::value_timelines::value_timeline_info::ValueTimelineInfo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ValueTimelineListResponse {
+    pub value_timelines: Vec<ValueTimelineInfo>,
+}
+
+impl TypedPrivilegedCommandResponse for ValueTimelineListResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::List {
+            value_timeline_list_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::List { value_timeline_list_response }) = response {
+            Ok(value_timeline_list_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod export;
+pub mod list;
+pub mod query;
+pub mod record;
+pub mod remove;
+pub mod value_timeline_command;
+pub mod value_timeline_response;
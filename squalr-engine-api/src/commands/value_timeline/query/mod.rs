@@ -0,0 +1,2 @@
+pub mod value_timeline_query_request;
+pub mod value_timeline_query_response;
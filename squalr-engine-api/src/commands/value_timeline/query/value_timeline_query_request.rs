@@ -0,0 +1,32 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::value_timeline::query::value_timeline_query_response::ValueTimelineQueryResponse;
+use crate::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ValueTimelineQueryRequest {
+    #[structopt(short = "t", long)]
+    pub timeline_id: u32,
+
+    #[structopt(short = "s", long)]
+    pub since_timestamp_ms: Option<u64>,
+}
+
+impl PrivilegedCommandRequest for ValueTimelineQueryRequest {
+    type ResponseType = ValueTimelineQueryResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::ValueTimeline(ValueTimelineCommand::Query {
+            value_timeline_query_request: self.clone(),
+        })
+    }
+}
+
+impl From<ValueTimelineQueryResponse> for ValueTimelineResponse {
+    fn from(value_timeline_query_response: ValueTimelineQueryResponse) -> Self {
+        ValueTimelineResponse::Query { value_timeline_query_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use crate::structures::value_timelines::value_timeline_info::ValueTimelineInfo;
+use crate::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ValueTimelineQueryResponse {
+    pub value_timeline_info: Option<ValueTimelineInfo>,
+    pub samples: Vec<ValueTimelineSample>,
+}
+
+impl TypedPrivilegedCommandResponse for ValueTimelineQueryResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Query {
+            value_timeline_query_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Query { value_timeline_query_response }) = response {
+            Ok(value_timeline_query_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod value_timeline_record_request;
+pub mod value_timeline_record_response;
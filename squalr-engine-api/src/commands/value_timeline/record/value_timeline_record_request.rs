@@ -0,0 +1,48 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::value_timeline::record::value_timeline_record_response::ValueTimelineRecordResponse;
+use crate::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ValueTimelineRecordRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+
+    #[structopt(short = "d", long)]
+    pub data_type_ref: DataTypeRef,
+
+    #[structopt(short = "l", long, default_value = "")]
+    pub label: String,
+
+    #[structopt(short = "i", long, default_value = "100")]
+    pub interval_ms: u64,
+
+    #[structopt(short = "c", long, default_value = "10000")]
+    pub capacity: u64,
+}
+
+impl PrivilegedCommandRequest for ValueTimelineRecordRequest {
+    type ResponseType = ValueTimelineRecordResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::ValueTimeline(ValueTimelineCommand::Record {
+            value_timeline_record_request: self.clone(),
+        })
+    }
+}
+
+impl From<ValueTimelineRecordResponse> for ValueTimelineResponse {
+    fn from(value_timeline_record_response: ValueTimelineRecordResponse) -> Self {
+        ValueTimelineResponse::Record {
+            value_timeline_record_response,
+        }
+    }
+}
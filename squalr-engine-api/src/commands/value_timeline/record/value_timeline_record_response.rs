@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ValueTimelineRecordResponse {
+    pub success: bool,
+    pub timeline_id: u32,
+}
+
+impl TypedPrivilegedCommandResponse for ValueTimelineRecordResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Record {
+            value_timeline_record_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Record {
+            value_timeline_record_response,
+        }) = response
+        {
+            Ok(value_timeline_record_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod value_timeline_remove_request;
+pub mod value_timeline_remove_response;
@@ -0,0 +1,31 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::value_timeline::remove::value_timeline_remove_response::ValueTimelineRemoveResponse;
+use crate::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ValueTimelineRemoveRequest {
+    #[structopt(short = "t", long)]
+    pub timeline_id: u32,
+}
+
+impl PrivilegedCommandRequest for ValueTimelineRemoveRequest {
+    type ResponseType = ValueTimelineRemoveResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::ValueTimeline(ValueTimelineCommand::Remove {
+            value_timeline_remove_request: self.clone(),
+        })
+    }
+}
+
+impl From<ValueTimelineRemoveResponse> for ValueTimelineResponse {
+    fn from(value_timeline_remove_response: ValueTimelineRemoveResponse) -> Self {
+        ValueTimelineResponse::Remove {
+            value_timeline_remove_response,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::value_timeline::value_timeline_response::ValueTimelineResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ValueTimelineRemoveResponse {
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for ValueTimelineRemoveResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Remove {
+            value_timeline_remove_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::ValueTimeline(ValueTimelineResponse::Remove {
+            value_timeline_remove_response,
+        }) = response
+        {
+            Ok(value_timeline_remove_response)
+        } else {
+            Err(response)
+        }
+    }
+}
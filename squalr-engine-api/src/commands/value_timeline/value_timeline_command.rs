@@ -0,0 +1,36 @@
+use crate::commands::value_timeline::export::value_timeline_export_request::ValueTimelineExportRequest;
+use crate::commands::value_timeline::list::value_timeline_list_request::ValueTimelineListRequest;
+use crate::commands::value_timeline::query::value_timeline_query_request::ValueTimelineQueryRequest;
+use crate::commands::value_timeline::record::value_timeline_record_request::ValueTimelineRecordRequest;
+use crate::commands::value_timeline::remove::value_timeline_remove_request::ValueTimelineRemoveRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum ValueTimelineCommand {
+    /// Starts sampling the value of an address or struct field at a fixed interval into a ring buffer.
+    Record {
+        #[structopt(flatten)]
+        value_timeline_record_request: ValueTimelineRecordRequest,
+    },
+    /// Stops recording a value timeline and discards its samples.
+    Remove {
+        #[structopt(flatten)]
+        value_timeline_remove_request: ValueTimelineRemoveRequest,
+    },
+    /// Lists all value timelines being recorded, along with their most recent sample.
+    List {
+        #[structopt(flatten)]
+        value_timeline_list_request: ValueTimelineListRequest,
+    },
+    /// Gets the recorded samples of a value timeline, optionally only those taken after a given timestamp.
+    Query {
+        #[structopt(flatten)]
+        value_timeline_query_request: ValueTimelineQueryRequest,
+    },
+    /// Exports the samples of the given value timelines, or of all timelines if none are given, to a CSV file.
+    Export {
+        #[structopt(flatten)]
+        value_timeline_export_request: ValueTimelineExportRequest,
+    },
+}
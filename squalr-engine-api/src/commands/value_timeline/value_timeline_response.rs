@@ -0,0 +1,25 @@
+use crate::commands::value_timeline::export::value_timeline_export_response::ValueTimelineExportResponse;
+use crate::commands::value_timeline::list::value_timeline_list_response::ValueTimelineListResponse;
+use crate::commands::value_timeline::query::value_timeline_query_response::ValueTimelineQueryResponse;
+use crate::commands::value_timeline::record::value_timeline_record_response::ValueTimelineRecordResponse;
+use crate::commands::value_timeline::remove::value_timeline_remove_response::ValueTimelineRemoveResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ValueTimelineResponse {
+    Record {
+        value_timeline_record_response: ValueTimelineRecordResponse,
+    },
+    Remove {
+        value_timeline_remove_response: ValueTimelineRemoveResponse,
+    },
+    List {
+        value_timeline_list_response: ValueTimelineListResponse,
+    },
+    Query {
+        value_timeline_query_response: ValueTimelineQueryResponse,
+    },
+    Export {
+        value_timeline_export_response: ValueTimelineExportResponse,
+    },
+}
@@ -0,0 +1,2 @@
+pub mod watchpoint_attach_request;
+pub mod watchpoint_attach_response;
@@ -0,0 +1,26 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::watchpoint::attach::watchpoint_attach_response::WatchpointAttachResponse;
+use crate::commands::watchpoint::watchpoint_command::WatchpointCommand;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct WatchpointAttachRequest {}
+
+impl PrivilegedCommandRequest for WatchpointAttachRequest {
+    type ResponseType = WatchpointAttachResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Attach {
+            watchpoint_attach_request: self.clone(),
+        })
+    }
+}
+
+impl From<WatchpointAttachResponse> for WatchpointResponse {
+    fn from(watchpoint_attach_response: WatchpointAttachResponse) -> Self {
+        WatchpointResponse::Attach { watchpoint_attach_response }
+    }
+}
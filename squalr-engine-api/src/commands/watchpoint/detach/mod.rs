@@ -0,0 +1,2 @@
+pub mod watchpoint_detach_request;
+pub mod watchpoint_detach_response;
@@ -0,0 +1,26 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::watchpoint::detach::watchpoint_detach_response::WatchpointDetachResponse;
+use crate::commands::watchpoint::watchpoint_command::WatchpointCommand;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct WatchpointDetachRequest {}
+
+impl PrivilegedCommandRequest for WatchpointDetachRequest {
+    type ResponseType = WatchpointDetachResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Detach {
+            watchpoint_detach_request: self.clone(),
+        })
+    }
+}
+
+impl From<WatchpointDetachResponse> for WatchpointResponse {
+    fn from(watchpoint_detach_response: WatchpointDetachResponse) -> Self {
+        WatchpointResponse::Detach { watchpoint_detach_response }
+    }
+}
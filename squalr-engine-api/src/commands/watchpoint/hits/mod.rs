@@ -0,0 +1,2 @@
+pub mod watchpoint_hits_request;
+pub mod watchpoint_hits_response;
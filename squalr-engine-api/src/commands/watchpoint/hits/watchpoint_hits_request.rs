@@ -0,0 +1,29 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::watchpoint::hits::watchpoint_hits_response::WatchpointHitsResponse;
+use crate::commands::watchpoint::watchpoint_command::WatchpointCommand;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct WatchpointHitsRequest {
+    #[structopt(short = "c", long)]
+    pub clear: bool,
+}
+
+impl PrivilegedCommandRequest for WatchpointHitsRequest {
+    type ResponseType = WatchpointHitsResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Hits {
+            watchpoint_hits_request: self.clone(),
+        })
+    }
+}
+
+impl From<WatchpointHitsResponse> for WatchpointResponse {
+    fn from(watchpoint_hits_response: WatchpointHitsResponse) -> Self {
+        WatchpointResponse::Hits { watchpoint_hits_response }
+    }
+}
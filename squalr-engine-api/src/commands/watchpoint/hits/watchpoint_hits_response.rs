@@ -0,0 +1,26 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
+use crate::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WatchpointHitsResponse {
+    pub watchpoint_hits: Vec<WatchpointHit>,
+}
+
+impl TypedPrivilegedCommandResponse for WatchpointHitsResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Watchpoint(WatchpointResponse::Hits {
+            watchpoint_hits_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Watchpoint(WatchpointResponse::Hits { watchpoint_hits_response }) = response {
+            Ok(watchpoint_hits_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod attach;
+pub mod detach;
+pub mod hits;
+pub mod remove;
+pub mod set;
+pub mod watchpoint_command;
+pub mod watchpoint_response;
@@ -0,0 +1,2 @@
+pub mod watchpoint_remove_request;
+pub mod watchpoint_remove_response;
@@ -0,0 +1,29 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::watchpoint::remove::watchpoint_remove_response::WatchpointRemoveResponse;
+use crate::commands::watchpoint::watchpoint_command::WatchpointCommand;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct WatchpointRemoveRequest {
+    #[structopt(short = "i", long)]
+    pub watchpoint_id: u32,
+}
+
+impl PrivilegedCommandRequest for WatchpointRemoveRequest {
+    type ResponseType = WatchpointRemoveResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Remove {
+            watchpoint_remove_request: self.clone(),
+        })
+    }
+}
+
+impl From<WatchpointRemoveResponse> for WatchpointResponse {
+    fn from(watchpoint_remove_response: WatchpointRemoveResponse) -> Self {
+        WatchpointResponse::Remove { watchpoint_remove_response }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WatchpointRemoveResponse {
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for WatchpointRemoveResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Watchpoint(WatchpointResponse::Remove {
+            watchpoint_remove_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Watchpoint(WatchpointResponse::Remove { watchpoint_remove_response }) = response {
+            Ok(watchpoint_remove_response)
+        } else {
+            Err(response)
+        }
+    }
+}
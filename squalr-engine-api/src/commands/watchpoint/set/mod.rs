@@ -0,0 +1,2 @@
+pub mod watchpoint_set_request;
+pub mod watchpoint_set_response;
@@ -0,0 +1,37 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::watchpoint::set::watchpoint_set_response::WatchpointSetResponse;
+use crate::commands::watchpoint::watchpoint_command::WatchpointCommand;
+use crate::commands::watchpoint::watchpoint_response::WatchpointResponse;
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::watchpoints::watchpoint_kind::WatchpointKind;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct WatchpointSetRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "s", long, default_value = "4")]
+    pub size: u64,
+
+    #[structopt(short = "k", long, default_value = "write")]
+    pub watchpoint_kind: WatchpointKind,
+}
+
+impl PrivilegedCommandRequest for WatchpointSetRequest {
+    type ResponseType = WatchpointSetResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Set {
+            watchpoint_set_request: self.clone(),
+        })
+    }
+}
+
+impl From<WatchpointSetResponse> for WatchpointResponse {
+    fn from(watchpoint_set_response: WatchpointSetResponse) -> Self {
+        WatchpointResponse::Set { watchpoint_set_response }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::commands::watchpoint::attach::watchpoint_attach_request::WatchpointAttachRequest;
+use crate::commands::watchpoint::detach::watchpoint_detach_request::WatchpointDetachRequest;
+use crate::commands::watchpoint::hits::watchpoint_hits_request::WatchpointHitsRequest;
+use crate::commands::watchpoint::remove::watchpoint_remove_request::WatchpointRemoveRequest;
+use crate::commands::watchpoint::set::watchpoint_set_request::WatchpointSetRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum WatchpointCommand {
+    /// Attaches a debugger to the opened process, such that hardware watchpoints can be set.
+    Attach {
+        #[structopt(flatten)]
+        watchpoint_attach_request: WatchpointAttachRequest,
+    },
+    /// Watches an address for writes, or for reads and writes, recording each instruction that accesses it.
+    Set {
+        #[structopt(flatten)]
+        watchpoint_set_request: WatchpointSetRequest,
+    },
+    /// Removes a previously set watchpoint.
+    Remove {
+        #[structopt(flatten)]
+        watchpoint_remove_request: WatchpointRemoveRequest,
+    },
+    /// Lists each unique instruction that triggered a watchpoint, with hit counts and the most recent registers.
+    Hits {
+        #[structopt(flatten)]
+        watchpoint_hits_request: WatchpointHitsRequest,
+    },
+    /// Removes all watchpoints and detaches the debugger, leaving the process running.
+    Detach {
+        #[structopt(flatten)]
+        watchpoint_detach_request: WatchpointDetachRequest,
+    },
+}
@@ -0,0 +1,15 @@
+use crate::commands::watchpoint::attach::watchpoint_attach_response::WatchpointAttachResponse;
+use crate::commands::watchpoint::detach::watchpoint_detach_response::WatchpointDetachResponse;
+use crate::commands::watchpoint::hits::watchpoint_hits_response::WatchpointHitsResponse;
+use crate::commands::watchpoint::remove::watchpoint_remove_response::WatchpointRemoveResponse;
+use crate::commands::watchpoint::set::watchpoint_set_response::WatchpointSetResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum WatchpointResponse {
+    Attach { watchpoint_attach_response: WatchpointAttachResponse },
+    Set { watchpoint_set_response: WatchpointSetResponse },
+    Remove { watchpoint_remove_response: WatchpointRemoveResponse },
+    Hits { watchpoint_hits_response: WatchpointHitsResponse },
+    Detach { watchpoint_detach_response: WatchpointDetachResponse },
+}
@@ -1,3 +1,5 @@
+use crate::events::memory::memory_event::MemoryEvent;
+use crate::events::plugins::plugins_event::PluginsEvent;
 use crate::events::process::process_event::ProcessEvent;
 use crate::events::project::project_event::ProjectEvent;
 use crate::events::project_items::project_items_event::ProjectItemsEvent;
@@ -7,6 +9,8 @@ use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum EngineEvent {
+    Memory(MemoryEvent),
+    Plugins(PluginsEvent),
     Process(ProcessEvent),
     Project(ProjectEvent),
     ProjectItems(ProjectItemsEvent),
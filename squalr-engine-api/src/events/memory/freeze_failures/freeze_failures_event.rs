@@ -0,0 +1,23 @@
+use crate::{
+    events::{
+        engine_event::{EngineEvent, EngineEventRequest},
+        memory::memory_event::MemoryEvent,
+    },
+    structures::freezing::freeze_metrics::FreezeMetrics,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired when the freezer fails to read or write frozen addresses, for example because the memory was unmapped or protected.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FreezeFailuresEvent {
+    pub freeze_metrics: FreezeMetrics,
+    pub failed_addresses: Vec<u64>,
+}
+
+impl EngineEventRequest for FreezeFailuresEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Memory(MemoryEvent::FreezeFailures {
+            freeze_failures_event: self.clone(),
+        })
+    }
+}
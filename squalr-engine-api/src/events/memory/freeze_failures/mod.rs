@@ -0,0 +1 @@
+pub mod freeze_failures_event;
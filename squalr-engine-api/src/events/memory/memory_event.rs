@@ -0,0 +1,13 @@
+use crate::events::memory::freeze_failures::freeze_failures_event::FreezeFailuresEvent;
+use crate::events::memory::modules_changed::modules_changed_event::ModulesChangedEvent;
+use crate::events::memory::regions_changed::regions_changed_event::RegionsChangedEvent;
+use crate::events::memory::stale_module_builds::stale_module_builds_event::StaleModuleBuildsEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MemoryEvent {
+    FreezeFailures { freeze_failures_event: FreezeFailuresEvent },
+    ModulesChanged { modules_changed_event: ModulesChangedEvent },
+    RegionsChanged { regions_changed_event: RegionsChangedEvent },
+    StaleModuleBuilds { stale_module_builds_event: StaleModuleBuildsEvent },
+}
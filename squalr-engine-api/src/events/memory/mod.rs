@@ -0,0 +1,5 @@
+pub mod freeze_failures;
+pub mod memory_event;
+pub mod modules_changed;
+pub mod regions_changed;
+pub mod stale_module_builds;
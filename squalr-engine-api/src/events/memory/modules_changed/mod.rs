@@ -0,0 +1 @@
+pub mod modules_changed_event;
@@ -0,0 +1,23 @@
+use crate::{
+    events::{
+        engine_event::{EngineEvent, EngineEventRequest},
+        memory::memory_event::MemoryEvent,
+    },
+    structures::memory::normalized_module::NormalizedModule,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired when modules are loaded into or unloaded from the opened process.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ModulesChangedEvent {
+    pub loaded_modules: Vec<NormalizedModule>,
+    pub unloaded_modules: Vec<NormalizedModule>,
+}
+
+impl EngineEventRequest for ModulesChangedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Memory(MemoryEvent::ModulesChanged {
+            modules_changed_event: self.clone(),
+        })
+    }
+}
@@ -0,0 +1 @@
+pub mod regions_changed_event;
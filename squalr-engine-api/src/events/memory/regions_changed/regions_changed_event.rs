@@ -0,0 +1,24 @@
+use crate::{
+    events::{
+        engine_event::{EngineEvent, EngineEventRequest},
+        memory::memory_event::MemoryEvent,
+    },
+    structures::memory::normalized_region::NormalizedRegion,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired when memory regions are mapped into or unmapped from the opened process.
+/// A region that is resized or moved is reported as both unmapped (old bounds) and mapped (new bounds).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RegionsChangedEvent {
+    pub mapped_regions: Vec<NormalizedRegion>,
+    pub unmapped_regions: Vec<NormalizedRegion>,
+}
+
+impl EngineEventRequest for RegionsChangedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Memory(MemoryEvent::RegionsChanged {
+            regions_changed_event: self.clone(),
+        })
+    }
+}
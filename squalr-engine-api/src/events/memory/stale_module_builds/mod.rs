@@ -0,0 +1 @@
+pub mod stale_module_builds_event;
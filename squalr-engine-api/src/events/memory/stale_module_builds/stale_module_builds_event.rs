@@ -0,0 +1,22 @@
+use crate::{
+    events::{
+        engine_event::{EngineEvent, EngineEventRequest},
+        memory::memory_event::MemoryEvent,
+    },
+    structures::memory::module_build_mismatch::ModuleBuildMismatch,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired when loaded modules are a different build than the one that addresses were recorded against, such that those addresses may need re-finding.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StaleModuleBuildsEvent {
+    pub stale_module_builds: Vec<ModuleBuildMismatch>,
+}
+
+impl EngineEventRequest for StaleModuleBuildsEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Memory(MemoryEvent::StaleModuleBuilds {
+            stale_module_builds_event: self.clone(),
+        })
+    }
+}
@@ -1,4 +1,6 @@
 pub mod engine_event;
+pub mod memory;
+pub mod plugins;
 pub mod process;
 pub mod project;
 pub mod project_items;
@@ -0,0 +1,22 @@
+use crate::{
+    events::{
+        engine_event::{EngineEvent, EngineEventRequest},
+        plugins::plugins_event::PluginsEvent,
+    },
+    structures::data_types::plugin_types::data_type_plugin_metadata::DataTypePluginMetadata,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired when plugins register new data types. Contains the full list of plugin-defined data types.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DataTypesChangedEvent {
+    pub data_types: Vec<DataTypePluginMetadata>,
+}
+
+impl EngineEventRequest for DataTypesChangedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Plugins(PluginsEvent::DataTypesChanged {
+            data_types_changed_event: self.clone(),
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod data_types_changed;
+pub mod plugins_event;
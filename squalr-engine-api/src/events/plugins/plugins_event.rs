@@ -0,0 +1,7 @@
+use crate::events::plugins::data_types_changed::data_types_changed_event::DataTypesChangedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum PluginsEvent {
+    DataTypesChanged { data_types_changed_event: DataTypesChangedEvent },
+}
@@ -0,0 +1,57 @@
+use crate::structures::code_patches::applied_code_patch::AppliedCodePatch;
+use crate::structures::memory::pointer::Pointer;
+use std::collections::HashMap;
+
+/// Contains all code patches currently written to the opened process, keyed by their module-relative address,
+/// such that the original bytes can be restored when a patch is deactivated or the engine shuts down.
+pub struct CodePatchRegistry {
+    applied_patches: HashMap<Pointer, AppliedCodePatch>,
+}
+
+impl CodePatchRegistry {
+    pub fn new() -> Self {
+        Self {
+            applied_patches: HashMap::new(),
+        }
+    }
+
+    pub fn get_applied_patches(&self) -> &HashMap<Pointer, AppliedCodePatch> {
+        &self.applied_patches
+    }
+
+    pub fn get_applied_patch(
+        &self,
+        pointer: &Pointer,
+    ) -> Option<&AppliedCodePatch> {
+        self.applied_patches.get(pointer)
+    }
+
+    pub fn set_patch_applied(
+        &mut self,
+        pointer: Pointer,
+        applied_code_patch: AppliedCodePatch,
+    ) {
+        self.applied_patches.insert(pointer, applied_code_patch);
+    }
+
+    pub fn remove_applied_patch(
+        &mut self,
+        pointer: &Pointer,
+    ) -> Option<AppliedCodePatch> {
+        self.applied_patches.remove(pointer)
+    }
+
+    /// Removes and returns all applied patches.
+    pub fn take_applied_patches(&mut self) -> Vec<AppliedCodePatch> {
+        self.applied_patches
+            .drain()
+            .map(|(_pointer, applied_code_patch)| applied_code_patch)
+            .collect()
+    }
+}
+
+impl Default for CodePatchRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,443 @@
+use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use crate::structures::data_values::container_type::ContainerType;
+use crate::structures::data_values::data_value::DataValue;
+use crate::structures::freezing::freeze_mode::FreezeMode;
+use crate::structures::freezing::freeze_policy::FreezePolicy;
+use crate::structures::freezing::freeze_policy_error::FreezePolicyError;
+use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use crate::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
+
+/// Adds two integers of the given primitive stored as bytes, yielding `None` if either is too short or the sum overflows.
+macro_rules! checked_add_bytes {
+    ($primitive:ty, $from_bytes:ident, $to_bytes:ident, $value_bytes:expr, $step_bytes:expr) => {
+        <$primitive>::$from_bytes($value_bytes.get(..size_of::<$primitive>())?.try_into().ok()?)
+            .checked_add(<$primitive>::$from_bytes($step_bytes.get(..size_of::<$primitive>())?.try_into().ok()?))
+            .map(|sum| sum.$to_bytes().to_vec())
+    };
+}
+
+/// Adds two floats of the given primitive stored as bytes, yielding `None` if either is too short or the sum is not finite.
+macro_rules! finite_add_bytes {
+    ($primitive:ty, $from_bytes:ident, $to_bytes:ident, $value_bytes:expr, $step_bytes:expr) => {
+        Some(
+            <$primitive>::$from_bytes($value_bytes.get(..size_of::<$primitive>())?.try_into().ok()?)
+                + <$primitive>::$from_bytes($step_bytes.get(..size_of::<$primitive>())?.try_into().ok()?),
+        )
+        .filter(|sum| sum.is_finite())
+        .map(|sum| sum.$to_bytes().to_vec())
+    };
+}
+
+/// A frozen address, along with the policy that determines what the freezer writes to it.
+#[derive(Clone, Debug)]
+pub struct FreezeListEntry {
+    freeze_mode: FreezeMode,
+    frozen_value: DataValue,
+    minimum_value: Option<DataValue>,
+    maximum_value: Option<DataValue>,
+    step_value: Option<DataValue>,
+    interval_ms: Option<u64>,
+    is_low_latency: bool,
+    is_applied: bool,
+}
+
+impl FreezeListEntry {
+    /// Creates an entry that repeatedly writes the given value.
+    pub fn new(frozen_value: DataValue) -> Self {
+        Self {
+            freeze_mode: FreezeMode::Constant,
+            frozen_value,
+            minimum_value: None,
+            maximum_value: None,
+            step_value: None,
+            interval_ms: None,
+            is_low_latency: false,
+            is_applied: false,
+        }
+    }
+
+    /// Creates an entry from a freeze policy, interpreting its bounds and step using the data type of the frozen value.
+    pub fn from_policy(
+        frozen_value: DataValue,
+        freeze_policy: &FreezePolicy,
+    ) -> Result<Self, FreezePolicyError> {
+        let data_type_ref = frozen_value.get_data_type_ref().clone();
+        let minimum_value = Self::parse_parameter(&data_type_ref, "minimum", &freeze_policy.freeze_minimum)?;
+        let maximum_value = Self::parse_parameter(&data_type_ref, "maximum", &freeze_policy.freeze_maximum)?;
+        let step_value = Self::parse_parameter(&data_type_ref, "step", &freeze_policy.freeze_step)?;
+
+        if step_value
+            .as_ref()
+            .is_some_and(|step_value| Self::to_decimal_string(step_value).is_none_or(|step_string| step_string.parse::<f64>().is_err()))
+        {
+            return Err(FreezePolicyError::invalid_parameter(
+                "step",
+                freeze_policy.freeze_step.clone().unwrap_or_default(),
+                data_type_ref.get_data_type_id(),
+            ));
+        }
+
+        match freeze_policy.freeze_mode {
+            FreezeMode::Clamp if minimum_value.is_none() && maximum_value.is_none() => {
+                return Err(FreezePolicyError::missing_parameter(freeze_policy.freeze_mode, "minimum or maximum"));
+            }
+            FreezeMode::Increment if step_value.is_none() => {
+                return Err(FreezePolicyError::missing_parameter(freeze_policy.freeze_mode, "step"));
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            freeze_mode: freeze_policy.freeze_mode,
+            frozen_value,
+            minimum_value,
+            maximum_value,
+            step_value,
+            interval_ms: freeze_policy.freeze_interval_ms,
+            is_low_latency: freeze_policy.freeze_low_latency,
+            is_applied: false,
+        })
+    }
+
+    pub fn get_freeze_mode(&self) -> FreezeMode {
+        self.freeze_mode
+    }
+
+    /// Gets the interval at which this entry is frozen, or `None` to use the configured freeze interval.
+    pub fn get_interval_ms(&self) -> Option<u64> {
+        self.interval_ms
+    }
+
+    /// Gets whether this entry is frozen precisely at its deadline, for values that are overwritten every frame.
+    pub fn is_low_latency(&self) -> bool {
+        self.is_low_latency
+    }
+
+    pub fn get_frozen_value(&self) -> &DataValue {
+        &self.frozen_value
+    }
+
+    /// Gets the number of bytes that the freezer reads and writes for this entry.
+    pub fn get_size_in_bytes(&self) -> u64 {
+        self.frozen_value.get_size_in_bytes()
+    }
+
+    /// Records that the resolved value was written, such that set-once entries stop writing.
+    pub fn mark_applied(&mut self) {
+        self.is_applied = true;
+    }
+
+    /// Determines the bytes to write given the current value of the frozen address, or `None` if nothing should be written.
+    /// Set-once entries return their value until marked applied, and never-decrease or never-increase entries adopt the current value
+    /// whenever it moves in the allowed direction.
+    pub fn resolve_write_bytes(
+        &mut self,
+        current_value_bytes: Option<&[u8]>,
+    ) -> Option<Vec<u8>> {
+        match self.freeze_mode {
+            FreezeMode::Constant => return Some(self.frozen_value.get_value_bytes().clone()),
+            FreezeMode::SetOnce if self.is_applied => return None,
+            FreezeMode::SetOnce => return Some(self.frozen_value.get_value_bytes().clone()),
+            _ => {}
+        }
+
+        // Guard the comparison functions, which read a full value from the pointer they are given.
+        let current_value_bytes = current_value_bytes.filter(|current_value_bytes| current_value_bytes.len() as u64 >= self.get_size_in_bytes())?;
+
+        match self.freeze_mode {
+            FreezeMode::Clamp => match (self.minimum_value.as_ref(), self.maximum_value.as_ref()) {
+                (Some(minimum_value), _) if Self::compare(ScanCompareTypeImmediate::LessThan, current_value_bytes, minimum_value) => {
+                    Some(minimum_value.get_value_bytes().clone())
+                }
+                (_, Some(maximum_value)) if Self::compare(ScanCompareTypeImmediate::GreaterThan, current_value_bytes, maximum_value) => {
+                    Some(maximum_value.get_value_bytes().clone())
+                }
+                _ => None,
+            },
+            FreezeMode::NeverDecrease => self.resolve_monotonic_write_bytes(ScanCompareTypeImmediate::LessThan, current_value_bytes),
+            FreezeMode::NeverIncrease => self.resolve_monotonic_write_bytes(ScanCompareTypeImmediate::GreaterThan, current_value_bytes),
+            FreezeMode::Increment => self.resolve_incremented_write_bytes(current_value_bytes),
+            FreezeMode::Constant | FreezeMode::SetOnce => None,
+        }
+    }
+
+    /// Restores the frozen value if the current value moved in the disallowed direction, otherwise adopts the current value.
+    fn resolve_monotonic_write_bytes(
+        &mut self,
+        disallowed_compare_type: ScanCompareTypeImmediate,
+        current_value_bytes: &[u8],
+    ) -> Option<Vec<u8>> {
+        if Self::compare(disallowed_compare_type, current_value_bytes, &self.frozen_value) {
+            return Some(self.frozen_value.get_value_bytes().clone());
+        }
+
+        let size_in_bytes = self.get_size_in_bytes() as usize;
+
+        self.frozen_value
+            .copy_from_bytes(current_value_bytes.get(..size_in_bytes)?);
+
+        None
+    }
+
+    /// Adds the step to the current value. Results that the data type cannot represent, such as overflows, are not written.
+    fn resolve_incremented_write_bytes(
+        &self,
+        current_value_bytes: &[u8],
+    ) -> Option<Vec<u8>> {
+        let step_value = self.step_value.as_ref()?;
+        let size_in_bytes = self.get_size_in_bytes() as usize;
+        let current_value_bytes = current_value_bytes.get(..size_in_bytes)?;
+        let step_bytes = step_value.get_value_bytes();
+
+        // Built-in integers use checked arithmetic on their primitives, such that values near the limits of the type stay exact.
+        match self.frozen_value.get_data_type_ref().get_data_type_id() {
+            "u8" => checked_add_bytes!(u8, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "i8" => checked_add_bytes!(i8, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "u16" => checked_add_bytes!(u16, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "i16" => checked_add_bytes!(i16, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "u32" => checked_add_bytes!(u32, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "i32" => checked_add_bytes!(i32, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "u64" => checked_add_bytes!(u64, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "i64" => checked_add_bytes!(i64, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "f32" => finite_add_bytes!(f32, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "f64" => finite_add_bytes!(f64, from_le_bytes, to_le_bytes, current_value_bytes, step_bytes),
+            "u16be" => checked_add_bytes!(u16, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            "i16be" => checked_add_bytes!(i16, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            "u32be" => checked_add_bytes!(u32, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            "i32be" => checked_add_bytes!(i32, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            "u64be" => checked_add_bytes!(u64, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            "i64be" => checked_add_bytes!(i64, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            "f32be" => finite_add_bytes!(f32, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            "f64be" => finite_add_bytes!(f64, from_be_bytes, to_be_bytes, current_value_bytes, step_bytes),
+            _ => self.add_decimal_values(current_value_bytes, step_value),
+        }
+    }
+
+    /// Adds the step to the current value by way of their decimal representations, for data types that are not built in. Values that
+    /// are both integers are added as integers, such that large values are not rounded.
+    fn add_decimal_values(
+        &self,
+        current_value_bytes: &[u8],
+        step_value: &DataValue,
+    ) -> Option<Vec<u8>> {
+        let mut current_value = self.frozen_value.clone();
+
+        current_value.copy_from_bytes(current_value_bytes);
+
+        let current_value_string = Self::to_decimal_string(&current_value)?;
+        let step_value_string = Self::to_decimal_string(step_value)?;
+        let incremented_value_string = match (current_value_string.parse::<i128>(), step_value_string.parse::<i128>()) {
+            (Ok(current_value), Ok(step_value)) => current_value.checked_add(step_value)?.to_string(),
+            _ => (current_value_string.parse::<f64>().ok()? + step_value_string.parse::<f64>().ok()?).to_string(),
+        };
+        let incremented_value_string = AnonymousValueString::new(incremented_value_string, AnonymousValueStringFormat::Decimal, ContainerType::None);
+
+        SymbolRegistry::get_instance()
+            .deanonymize_value_string(self.frozen_value.get_data_type_ref(), &incremented_value_string)
+            .ok()
+            .map(|incremented_value| incremented_value.get_value_bytes().clone())
+    }
+
+    /// Compares the current value against an immediate using the comparison functions of the data type.
+    fn compare(
+        scan_compare_type_immediate: ScanCompareTypeImmediate,
+        current_value_bytes: &[u8],
+        immediate_value: &DataValue,
+    ) -> bool {
+        let scan_constraint = ScanConstraint::new(
+            ScanCompareType::Immediate(scan_compare_type_immediate),
+            immediate_value.clone(),
+            FloatingPointTolerance::default(),
+        );
+
+        match SymbolRegistry::get_instance().get_scalar_compare_func_immediate(&scan_compare_type_immediate, &scan_constraint) {
+            Some(compare_func) => compare_func(current_value_bytes.as_ptr()),
+            None => false,
+        }
+    }
+
+    fn parse_parameter(
+        data_type_ref: &DataTypeRef,
+        parameter_name: &str,
+        parameter_value: &Option<String>,
+    ) -> Result<Option<DataValue>, FreezePolicyError> {
+        let parameter_value = match parameter_value.as_deref().map(str::trim) {
+            Some(parameter_value) if !parameter_value.is_empty() => parameter_value,
+            _ => return Ok(None),
+        };
+        let anonymous_value_string = AnonymousValueString::new(parameter_value.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None);
+
+        SymbolRegistry::get_instance()
+            .deanonymize_value_string(data_type_ref, &anonymous_value_string)
+            .map(Some)
+            .map_err(|_error| FreezePolicyError::invalid_parameter(parameter_name, parameter_value, data_type_ref.get_data_type_id()))
+    }
+
+    fn to_decimal_string(data_value: &DataValue) -> Option<String> {
+        SymbolRegistry::get_instance()
+            .anonymize_value(data_value, AnonymousValueStringFormat::Decimal)
+            .ok()
+            .map(|anonymous_value_string| {
+                anonymous_value_string
+                    .get_anonymous_value_string()
+                    .trim()
+                    .to_string()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FreezeListEntry;
+    use crate::structures::data_types::built_in_types::{
+        f32::data_type_f32::DataTypeF32, i32::data_type_i32::DataTypeI32, i64::data_type_i64::DataTypeI64, u8::data_type_u8::DataTypeU8,
+        u64::data_type_u64::DataTypeU64,
+    };
+    use crate::structures::freezing::{freeze_mode::FreezeMode, freeze_policy::FreezePolicy, freeze_policy_error::FreezePolicyError};
+
+    fn create_freeze_policy(
+        freeze_mode: FreezeMode,
+        freeze_minimum: Option<&str>,
+        freeze_maximum: Option<&str>,
+        freeze_step: Option<&str>,
+    ) -> FreezePolicy {
+        FreezePolicy {
+            freeze_mode,
+            freeze_minimum: freeze_minimum.map(str::to_string),
+            freeze_maximum: freeze_maximum.map(str::to_string),
+            freeze_step: freeze_step.map(str::to_string),
+            ..FreezePolicy::default()
+        }
+    }
+
+    #[test]
+    fn set_once_writes_the_frozen_value_until_a_write_is_applied() {
+        let freeze_policy = create_freeze_policy(FreezeMode::SetOnce, None, None, None);
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeI32::get_value_from_primitive(100), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(freeze_list_entry.resolve_write_bytes(None), Some(100i32.to_le_bytes().to_vec()));
+
+        // A failed write leaves the entry unapplied, such that it is retried.
+        assert_eq!(freeze_list_entry.resolve_write_bytes(None), Some(100i32.to_le_bytes().to_vec()));
+
+        freeze_list_entry.mark_applied();
+
+        assert_eq!(freeze_list_entry.resolve_write_bytes(None), None);
+    }
+
+    #[test]
+    fn clamp_writes_the_nearest_bound_only_when_out_of_range() {
+        let freeze_policy = create_freeze_policy(FreezeMode::Clamp, Some("-10"), Some("50"), None);
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeI32::get_value_from_primitive(0), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&(-25i32).to_le_bytes())),
+            Some((-10i32).to_le_bytes().to_vec())
+        );
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&20i32.to_le_bytes())), None);
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&75i32.to_le_bytes())),
+            Some(50i32.to_le_bytes().to_vec())
+        );
+        assert_eq!(freeze_list_entry.resolve_write_bytes(None), None);
+    }
+
+    #[test]
+    fn never_decrease_restores_the_highest_value_seen() {
+        let freeze_policy = create_freeze_policy(FreezeMode::NeverDecrease, None, None, None);
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeI32::get_value_from_primitive(100), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&90i32.to_le_bytes())),
+            Some(100i32.to_le_bytes().to_vec())
+        );
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&150i32.to_le_bytes())), None);
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&120i32.to_le_bytes())),
+            Some(150i32.to_le_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn never_increase_restores_the_lowest_value_seen() {
+        let freeze_policy = create_freeze_policy(FreezeMode::NeverIncrease, None, None, None);
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeI32::get_value_from_primitive(10), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&4i32.to_le_bytes())), None);
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&9i32.to_le_bytes())),
+            Some(4i32.to_le_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn increment_adds_the_step_until_the_data_type_overflows() {
+        let freeze_policy = create_freeze_policy(FreezeMode::Increment, None, None, Some("5"));
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeU8::get_value_from_primitive(0), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&[10])), Some(vec![15]));
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&[253])), None);
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&[250])), Some(vec![255]));
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&[])), None);
+    }
+
+    #[test]
+    fn increment_is_exact_for_large_unsigned_integers() {
+        let freeze_policy = create_freeze_policy(FreezeMode::Increment, None, None, Some("1"));
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeU64::get_value_from_primitive(0), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&(u64::MAX - 1).to_le_bytes())),
+            Some(u64::MAX.to_le_bytes().to_vec())
+        );
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&u64::MAX.to_le_bytes())), None);
+    }
+
+    #[test]
+    fn increment_applies_negative_steps_to_signed_integers() {
+        let freeze_policy = create_freeze_policy(FreezeMode::Increment, None, None, Some("-3"));
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeI64::get_value_from_primitive(0), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&(-9_007_199_254_740_993i64).to_le_bytes())),
+            Some((-9_007_199_254_740_996i64).to_le_bytes().to_vec())
+        );
+        assert_eq!(freeze_list_entry.resolve_write_bytes(Some(&(i64::MIN + 2).to_le_bytes())), None);
+    }
+
+    #[test]
+    fn increment_adds_fractional_steps_to_floats() {
+        let freeze_policy = create_freeze_policy(FreezeMode::Increment, None, None, Some("0.5"));
+        let mut freeze_list_entry = FreezeListEntry::from_policy(DataTypeF32::get_value_from_primitive(0.0), &freeze_policy).expect("policy should be valid");
+
+        assert_eq!(
+            freeze_list_entry.resolve_write_bytes(Some(&1.25f32.to_le_bytes())),
+            Some(1.75f32.to_le_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn from_policy_rejects_missing_and_invalid_parameters() {
+        let clamp_policy = create_freeze_policy(FreezeMode::Clamp, None, None, None);
+        let increment_policy = create_freeze_policy(FreezeMode::Increment, None, None, None);
+        let invalid_policy = create_freeze_policy(FreezeMode::Clamp, Some("300"), None, None);
+
+        assert!(matches!(
+            FreezeListEntry::from_policy(DataTypeU8::get_value_from_primitive(0), &clamp_policy),
+            Err(FreezePolicyError::MissingParameter { .. })
+        ));
+        assert!(matches!(
+            FreezeListEntry::from_policy(DataTypeU8::get_value_from_primitive(0), &increment_policy),
+            Err(FreezePolicyError::MissingParameter { .. })
+        ));
+        assert!(matches!(
+            FreezeListEntry::from_policy(DataTypeU8::get_value_from_primitive(0), &invalid_policy),
+            Err(FreezePolicyError::InvalidParameter { .. })
+        ));
+    }
+}
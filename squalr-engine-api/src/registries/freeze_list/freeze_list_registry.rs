@@ -1,8 +1,11 @@
+use crate::registries::freeze_list::freeze_list_entry::FreezeListEntry;
+use crate::structures::freezing::freeze_metrics::FreezeMetrics;
 use crate::structures::memory::pointer::Pointer;
 use std::collections::HashMap;
 
 pub struct FreezeListRegistry {
-    frozen_pointers: HashMap<Pointer, Vec<u8>>,
+    frozen_pointers: HashMap<Pointer, FreezeListEntry>,
+    freeze_metrics: FreezeMetrics,
 }
 
 /// Contains all indicies that the user has marked as frozen in the scan results list.
@@ -11,13 +14,27 @@ impl FreezeListRegistry {
     pub fn new() -> Self {
         Self {
             frozen_pointers: HashMap::new(),
+            freeze_metrics: FreezeMetrics::default(),
         }
     }
 
-    pub fn get_frozen_pointers(&self) -> &HashMap<Pointer, Vec<u8>> {
+    pub fn get_frozen_pointers(&self) -> &HashMap<Pointer, FreezeListEntry> {
         &self.frozen_pointers
     }
 
+    pub fn get_frozen_pointers_mut(&mut self) -> &mut HashMap<Pointer, FreezeListEntry> {
+        &mut self.frozen_pointers
+    }
+
+    /// Gets the running totals of freezer reads and writes.
+    pub fn get_freeze_metrics(&self) -> &FreezeMetrics {
+        &self.freeze_metrics
+    }
+
+    pub fn get_freeze_metrics_mut(&mut self) -> &mut FreezeMetrics {
+        &mut self.freeze_metrics
+    }
+
     pub fn is_address_frozen(
         &self,
         pointer: &Pointer,
@@ -29,19 +46,26 @@ impl FreezeListRegistry {
         &self,
         pointer: &Pointer,
     ) -> Option<&Vec<u8>> {
-        if let Some(data_value) = self.frozen_pointers.get(pointer) {
-            Some(data_value)
+        if let Some(freeze_list_entry) = self.frozen_pointers.get(pointer) {
+            Some(freeze_list_entry.get_frozen_value().get_value_bytes())
         } else {
             None
         }
     }
 
+    pub fn get_address_freeze_entry(
+        &self,
+        pointer: &Pointer,
+    ) -> Option<&FreezeListEntry> {
+        self.frozen_pointers.get(pointer)
+    }
+
     pub fn set_address_frozen(
         &mut self,
         pointer: Pointer,
-        data_value: Vec<u8>,
+        freeze_list_entry: FreezeListEntry,
     ) {
-        self.frozen_pointers.insert(pointer, data_value);
+        self.frozen_pointers.insert(pointer, freeze_list_entry);
     }
 
     pub fn set_address_unfrozen(
@@ -51,6 +75,13 @@ impl FreezeListRegistry {
         self.frozen_pointers.remove(pointer);
     }
 
+    /// Removes every freeze located by an absolute address, which is meaningless in any other process. Module-relative and address
+    /// expression freezes are kept, as they are resolved against the modules of whichever process is opened.
+    pub fn clear_absolute_freezes(&mut self) {
+        self.frozen_pointers
+            .retain(|pointer, _freeze_list_entry| !pointer.get_module_name().is_empty() || !pointer.get_address_expression().is_empty());
+    }
+
     // JIRA: This function need sto be able to clear by source. We need to be be able to register by source.
     // We need to be able to also freeze complex types like pointers.
     pub fn clear(&mut self) {
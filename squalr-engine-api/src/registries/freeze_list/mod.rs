@@ -1 +1,2 @@
+pub mod freeze_list_entry;
 pub mod freeze_list_registry;
@@ -1,7 +1,7 @@
 use crate::structures::projects::project_items::{
     built_in_types::{
-        project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory,
-        project_item_type_pointer::ProjectItemTypePointer,
+        project_item_type_address::ProjectItemTypeAddress, project_item_type_code_patch::ProjectItemTypeCodePatch,
+        project_item_type_directory::ProjectItemTypeDirectory, project_item_type_pointer::ProjectItemTypePointer,
     },
     project_item_type::ProjectItemType,
 };
@@ -35,6 +35,7 @@ impl ProjectItemTypeRegistry {
         let built_in_project_item_types: Vec<Arc<dyn ProjectItemType>> = vec![
             Arc::new(ProjectItemTypeDirectory {}),
             Arc::new(ProjectItemTypeAddress {}),
+            Arc::new(ProjectItemTypeCodePatch {}),
             Arc::new(ProjectItemTypePointer {}),
         ];
 
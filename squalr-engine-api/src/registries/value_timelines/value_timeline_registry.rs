@@ -0,0 +1,69 @@
+use crate::structures::value_timelines::value_timeline::ValueTimeline;
+use crate::structures::value_timelines::value_timeline_target::ValueTimelineTarget;
+use std::collections::BTreeMap;
+
+/// Contains all value timelines being recorded, keyed by their id.
+pub struct ValueTimelineRegistry {
+    timelines: BTreeMap<u32, ValueTimeline>,
+    next_timeline_id: u32,
+}
+
+impl ValueTimelineRegistry {
+    pub fn new() -> Self {
+        Self {
+            timelines: BTreeMap::new(),
+            next_timeline_id: 1,
+        }
+    }
+
+    /// Starts recording the given target, returning the id of the new timeline.
+    pub fn add_timeline(
+        &mut self,
+        target: ValueTimelineTarget,
+        interval_ms: u64,
+        capacity: u64,
+    ) -> u32 {
+        let timeline_id = self.next_timeline_id;
+
+        self.next_timeline_id = self.next_timeline_id.wrapping_add(1).max(1);
+        self.timelines
+            .insert(timeline_id, ValueTimeline::new(timeline_id, target, interval_ms, capacity));
+
+        timeline_id
+    }
+
+    pub fn remove_timeline(
+        &mut self,
+        timeline_id: u32,
+    ) -> Option<ValueTimeline> {
+        self.timelines.remove(&timeline_id)
+    }
+
+    pub fn get_timelines(&self) -> &BTreeMap<u32, ValueTimeline> {
+        &self.timelines
+    }
+
+    pub fn get_timeline(
+        &self,
+        timeline_id: u32,
+    ) -> Option<&ValueTimeline> {
+        self.timelines.get(&timeline_id)
+    }
+
+    pub fn get_timeline_mut(
+        &mut self,
+        timeline_id: u32,
+    ) -> Option<&mut ValueTimeline> {
+        self.timelines.get_mut(&timeline_id)
+    }
+
+    pub fn clear(&mut self) {
+        self.timelines.clear();
+    }
+}
+
+impl Default for ValueTimelineRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
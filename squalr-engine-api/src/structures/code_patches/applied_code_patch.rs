@@ -0,0 +1,41 @@
+/// A code patch that has been written to an opened process, retaining the bytes that it replaced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppliedCodePatch {
+    process_id: u32,
+    address: u64,
+    original_bytes: Vec<u8>,
+    patch_bytes: Vec<u8>,
+}
+
+impl AppliedCodePatch {
+    pub fn new(
+        process_id: u32,
+        address: u64,
+        original_bytes: Vec<u8>,
+        patch_bytes: Vec<u8>,
+    ) -> Self {
+        Self {
+            process_id,
+            address,
+            original_bytes,
+            patch_bytes,
+        }
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    /// Gets the absolute address at which the patch was written.
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_original_bytes(&self) -> &[u8] {
+        &self.original_bytes
+    }
+
+    pub fn get_patch_bytes(&self) -> &[u8] {
+        &self.patch_bytes
+    }
+}
@@ -0,0 +1,89 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum CodePatchError {
+    #[error("Code patch has no original bytes to verify.")]
+    MissingOriginalBytes,
+    #[error("Module '{module_name}' is not loaded.")]
+    ModuleNotLoaded { module_name: String },
+    #[error("Failed to read {size} bytes at address 0x{address:X}.")]
+    UnreadableMemory { address: u64, size: usize },
+    #[error("Bytes at address 0x{address:X} do not match the original bytes of the patch.")]
+    OriginalBytesMismatch { address: u64 },
+    #[error("Bytes at address 0x{address:X} no longer match the applied patch.")]
+    PatchBytesMismatch { address: u64 },
+    #[error("Patch is {patch_size} bytes, which exceeds the {original_size} original bytes.")]
+    PatchExceedsOriginalBytes { patch_size: usize, original_size: usize },
+    #[error("Patch of {size} bytes is not a multiple of the {alignment} byte instruction size.")]
+    MisalignedPatch { size: usize, alignment: usize },
+    #[error("Unknown replacement kind '{kind}', expected nop, bytes, or asm.")]
+    UnknownReplacementKind { kind: String },
+    #[error("Invalid byte string '{text}'.")]
+    InvalidBytes { text: String },
+    #[error("Failed to assemble '{instruction}': {reason}.")]
+    InvalidInstruction { instruction: String, reason: String },
+    #[error("Failed to write {size} bytes at address 0x{address:X}.")]
+    WriteFailed { address: u64, size: usize },
+}
+
+impl CodePatchError {
+    pub fn module_not_loaded(module_name: impl Into<String>) -> Self {
+        Self::ModuleNotLoaded {
+            module_name: module_name.into(),
+        }
+    }
+
+    pub fn unreadable_memory(
+        address: u64,
+        size: usize,
+    ) -> Self {
+        Self::UnreadableMemory { address, size }
+    }
+
+    pub fn original_bytes_mismatch(address: u64) -> Self {
+        Self::OriginalBytesMismatch { address }
+    }
+
+    pub fn patch_bytes_mismatch(address: u64) -> Self {
+        Self::PatchBytesMismatch { address }
+    }
+
+    pub fn patch_exceeds_original_bytes(
+        patch_size: usize,
+        original_size: usize,
+    ) -> Self {
+        Self::PatchExceedsOriginalBytes { patch_size, original_size }
+    }
+
+    pub fn misaligned_patch(
+        size: usize,
+        alignment: usize,
+    ) -> Self {
+        Self::MisalignedPatch { size, alignment }
+    }
+
+    pub fn unknown_replacement_kind(kind: impl Into<String>) -> Self {
+        Self::UnknownReplacementKind { kind: kind.into() }
+    }
+
+    pub fn invalid_bytes(text: impl Into<String>) -> Self {
+        Self::InvalidBytes { text: text.into() }
+    }
+
+    pub fn invalid_instruction(
+        instruction: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::InvalidInstruction {
+            instruction: instruction.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn write_failed(
+        address: u64,
+        size: usize,
+    ) -> Self {
+        Self::WriteFailed { address, size }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::structures::code_patches::code_patch_error::CodePatchError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The bytes written over the original code when a code patch is applied.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CodePatchReplacement {
+    /// Replaces all original bytes with no-op instructions.
+    Nop,
+    /// Replaces the original bytes with raw bytes, padding any remainder with no-op instructions.
+    Bytes(Vec<u8>),
+    /// Replaces the original bytes with instructions assembled at the patch address, padding any remainder with no-op instructions.
+    Assembly(String),
+}
+
+impl CodePatchReplacement {
+    pub const KIND_NOP: &str = "nop";
+    pub const KIND_BYTES: &str = "bytes";
+    pub const KIND_ASSEMBLY: &str = "asm";
+
+    /// Creates a replacement from a kind identifier and its value, as stored in project item properties.
+    pub fn from_kind_and_value(
+        kind: &str,
+        value: &str,
+    ) -> Result<Self, CodePatchError> {
+        match kind.trim().to_ascii_lowercase().as_str() {
+            Self::KIND_NOP => Ok(CodePatchReplacement::Nop),
+            Self::KIND_BYTES => Ok(CodePatchReplacement::Bytes(Self::parse_bytes(value)?)),
+            Self::KIND_ASSEMBLY => Ok(CodePatchReplacement::Assembly(value.trim().to_string())),
+            _ => Err(CodePatchError::unknown_replacement_kind(kind.trim())),
+        }
+    }
+
+    pub fn get_kind(&self) -> &'static str {
+        match self {
+            CodePatchReplacement::Nop => Self::KIND_NOP,
+            CodePatchReplacement::Bytes(_) => Self::KIND_BYTES,
+            CodePatchReplacement::Assembly(_) => Self::KIND_ASSEMBLY,
+        }
+    }
+
+    pub fn get_value(&self) -> String {
+        match self {
+            CodePatchReplacement::Nop => String::new(),
+            CodePatchReplacement::Bytes(bytes) => Self::format_bytes(bytes),
+            CodePatchReplacement::Assembly(assembly) => assembly.clone(),
+        }
+    }
+
+    /// Parses bytes written as space or comma separated hex pairs (ie `90 90`, `0x90,0x90`) or as a contiguous hex string (ie `9090`).
+    pub fn parse_bytes(text: &str) -> Result<Vec<u8>, CodePatchError> {
+        let mut bytes = Vec::new();
+
+        for token in text.split(|character: char| character.is_whitespace() || character == ',') {
+            let token = token.trim();
+            let token = token
+                .strip_prefix("0x")
+                .or_else(|| token.strip_prefix("0X"))
+                .unwrap_or(token);
+
+            if token.is_empty() {
+                continue;
+            }
+
+            if token.len() % 2 != 0 || !token.chars().all(|character| character.is_ascii_hexdigit()) {
+                return Err(CodePatchError::invalid_bytes(text));
+            }
+
+            for index in (0..token.len()).step_by(2) {
+                bytes.push(u8::from_str_radix(&token[index..index + 2], 16).map_err(|_| CodePatchError::invalid_bytes(text))?);
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    /// Formats bytes as space separated hex pairs, such that they can be parsed by `parse_bytes`.
+    pub fn format_bytes(bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl FromStr for CodePatchReplacement {
+    type Err = String;
+
+    /// Parses a replacement in the form `nop`, `bytes:<hex bytes>`, or `asm:<instructions>`.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = string.split_once(':').unwrap_or((string, ""));
+
+        Self::from_kind_and_value(kind, value).map_err(|error| format!("Invalid code patch replacement '{}': {}", string, error))
+    }
+}
+
+impl fmt::Display for CodePatchReplacement {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            CodePatchReplacement::Nop => write!(formatter, "{}", Self::KIND_NOP),
+            _ => write!(formatter, "{}:{}", self.get_kind(), self.get_value()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodePatchReplacement;
+    use std::str::FromStr;
+
+    #[test]
+    fn parse_bytes_accepts_separated_and_contiguous_hex() {
+        assert_eq!(CodePatchReplacement::parse_bytes("90 0x90,EB").unwrap(), vec![0x90, 0x90, 0xEB]);
+        assert_eq!(CodePatchReplacement::parse_bytes("9090eb").unwrap(), vec![0x90, 0x90, 0xEB]);
+        assert!(CodePatchReplacement::parse_bytes("909").is_err());
+        assert!(CodePatchReplacement::parse_bytes("zz").is_err());
+    }
+
+    #[test]
+    fn replacement_round_trips_through_display() {
+        for replacement in [
+            CodePatchReplacement::Nop,
+            CodePatchReplacement::Bytes(vec![0xEB, 0x05]),
+            CodePatchReplacement::Assembly("jmp 0x401000".to_string()),
+        ] {
+            assert_eq!(CodePatchReplacement::from_str(&replacement.to_string()).unwrap(), replacement);
+        }
+    }
+}
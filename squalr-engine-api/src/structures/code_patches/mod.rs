@@ -0,0 +1,3 @@
+pub mod applied_code_patch;
+pub mod code_patch_error;
+pub mod code_patch_replacement;
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+/// A single decoded instruction, annotated with the module locations of itself and of any address it references.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisassembledInstruction {
+    address: u64,
+    bytes: Vec<u8>,
+    mnemonic: String,
+    operands: String,
+    is_valid: bool,
+    target_address: Option<u64>,
+    location: Option<String>,
+    target_location: Option<String>,
+}
+
+impl DisassembledInstruction {
+    pub fn new(
+        address: u64,
+        bytes: Vec<u8>,
+        mnemonic: String,
+        operands: String,
+        is_valid: bool,
+        target_address: Option<u64>,
+    ) -> Self {
+        Self {
+            address,
+            bytes,
+            mnemonic,
+            operands,
+            is_valid,
+            target_address,
+            location: None,
+            target_location: None,
+        }
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+
+    pub fn get_mnemonic(&self) -> &str {
+        &self.mnemonic
+    }
+
+    pub fn get_operands(&self) -> &str {
+        &self.operands
+    }
+
+    /// Gets the mnemonic and operands as a single line of assembly.
+    pub fn get_text(&self) -> String {
+        if self.operands.is_empty() {
+            self.mnemonic.clone()
+        } else {
+            format!("{} {}", self.mnemonic, self.operands)
+        }
+    }
+
+    /// Gets whether the bytes decoded to a known instruction, rather than being emitted as raw data.
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    /// Gets the branch target or memory address referenced by this instruction, if it can be computed statically.
+    pub fn get_target_address(&self) -> Option<u64> {
+        self.target_address
+    }
+
+    /// Gets the module and offset of this instruction, formatted as `module+0xOFFSET`.
+    pub fn get_location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    pub fn set_location(
+        &mut self,
+        location: Option<String>,
+    ) {
+        self.location = location;
+    }
+
+    /// Gets the module and offset of the target address, formatted as `module+0xOFFSET`.
+    pub fn get_target_location(&self) -> Option<&str> {
+        self.target_location.as_deref()
+    }
+
+    pub fn set_target_location(
+        &mut self,
+        target_location: Option<String>,
+    ) {
+        self.target_location = target_location;
+    }
+}
@@ -0,0 +1,49 @@
+use crate::structures::memory::bitness::Bitness;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The instruction set used to decode code bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InstructionSet {
+    X86,
+    X64,
+    AArch64,
+}
+
+impl InstructionSet {
+    /// Gets the instruction set most likely used by a process of the given bitness on the host architecture.
+    pub fn from_bitness(bitness: Bitness) -> Self {
+        match bitness {
+            Bitness::Bit32 => InstructionSet::X86,
+            Bitness::Bit64 if cfg!(target_arch = "aarch64") => InstructionSet::AArch64,
+            Bitness::Bit64 => InstructionSet::X64,
+        }
+    }
+}
+
+impl FromStr for InstructionSet {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "x86" | "i386" | "x86-32" => Ok(InstructionSet::X86),
+            "x64" | "x86-64" | "x86_64" | "amd64" => Ok(InstructionSet::X64),
+            "aarch64" | "arm64" => Ok(InstructionSet::AArch64),
+            _ => Err(format!("Invalid instruction set: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for InstructionSet {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            InstructionSet::X86 => write!(formatter, "x86"),
+            InstructionSet::X64 => write!(formatter, "x64"),
+            InstructionSet::AArch64 => write!(formatter, "aarch64"),
+        }
+    }
+}
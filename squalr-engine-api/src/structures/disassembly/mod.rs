@@ -0,0 +1,2 @@
+pub mod disassembled_instruction;
+pub mod instruction_set;
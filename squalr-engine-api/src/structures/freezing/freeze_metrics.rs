@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Running totals of the reads and writes performed by the freezer, used to surface addresses that can no longer be frozen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FreezeMetrics {
+    write_count: u64,
+    failed_write_count: u64,
+    failed_read_count: u64,
+}
+
+impl FreezeMetrics {
+    pub fn get_write_count(&self) -> u64 {
+        self.write_count
+    }
+
+    pub fn get_failed_write_count(&self) -> u64 {
+        self.failed_write_count
+    }
+
+    pub fn get_failed_read_count(&self) -> u64 {
+        self.failed_read_count
+    }
+
+    /// Gets the fraction of attempted writes that succeeded, which is 1 if no writes have been attempted.
+    pub fn get_write_success_ratio(&self) -> f32 {
+        if self.write_count == 0 {
+            1.0
+        } else {
+            (self.write_count - self.failed_write_count) as f32 / self.write_count as f32
+        }
+    }
+
+    /// Records a batched write covering the given number of frozen entries.
+    pub fn record_write(
+        &mut self,
+        entry_count: u64,
+        is_success: bool,
+    ) {
+        self.write_count = self.write_count.saturating_add(entry_count);
+
+        if !is_success {
+            self.failed_write_count = self.failed_write_count.saturating_add(entry_count);
+        }
+    }
+
+    /// Records a failed batched read of the current values of the given number of frozen entries.
+    pub fn record_failed_read(
+        &mut self,
+        entry_count: u64,
+    ) {
+        self.failed_read_count = self.failed_read_count.saturating_add(entry_count);
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Determines how a frozen address is kept in check each time the freezer runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FreezeMode {
+    /// Repeatedly writes the frozen value.
+    #[default]
+    Constant,
+    /// Writes the frozen value a single time, after which the game is free to change it.
+    SetOnce,
+    /// Writes the nearest bound whenever the value leaves the minimum to maximum range.
+    Clamp,
+    /// Restores the highest value seen whenever the value decreases.
+    NeverDecrease,
+    /// Restores the lowest value seen whenever the value increases.
+    NeverIncrease,
+    /// Adds the step to the current value each time the freezer runs.
+    Increment,
+}
+
+impl FreezeMode {
+    /// Determines whether this mode must read the current value before deciding what to write.
+    pub fn requires_current_value(&self) -> bool {
+        !matches!(self, FreezeMode::Constant | FreezeMode::SetOnce)
+    }
+}
+
+impl FromStr for FreezeMode {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "c" | "constant" => Ok(FreezeMode::Constant),
+            "o" | "once" | "set-once" | "set_once" => Ok(FreezeMode::SetOnce),
+            "r" | "clamp" | "range" => Ok(FreezeMode::Clamp),
+            "min" | "never-decrease" | "never_decrease" => Ok(FreezeMode::NeverDecrease),
+            "max" | "never-increase" | "never_increase" => Ok(FreezeMode::NeverIncrease),
+            "i" | "increment" | "add" => Ok(FreezeMode::Increment),
+            _ => Err(format!("Invalid freeze mode: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for FreezeMode {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            FreezeMode::Constant => write!(formatter, "constant"),
+            FreezeMode::SetOnce => write!(formatter, "set-once"),
+            FreezeMode::Clamp => write!(formatter, "clamp"),
+            FreezeMode::NeverDecrease => write!(formatter, "never-decrease"),
+            FreezeMode::NeverIncrease => write!(formatter, "never-increase"),
+            FreezeMode::Increment => write!(formatter, "increment"),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::structures::freezing::freeze_mode::FreezeMode;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Describes how an address is frozen. Bounds and steps are decimal value strings, interpreted using the data type of the
+/// frozen address, such that the policy can be configured and persisted before the data type is known. Entries without an
+/// interval are frozen at the configured freeze interval, and low latency entries busy-wait for their deadline rather than sleeping.
+#[derive(Clone, StructOpt, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FreezePolicy {
+    #[structopt(long, default_value)]
+    #[serde(default)]
+    pub freeze_mode: FreezeMode,
+    #[structopt(long, allow_hyphen_values = true)]
+    #[serde(default)]
+    pub freeze_minimum: Option<String>,
+    #[structopt(long, allow_hyphen_values = true)]
+    #[serde(default)]
+    pub freeze_maximum: Option<String>,
+    #[structopt(long, allow_hyphen_values = true)]
+    #[serde(default)]
+    pub freeze_step: Option<String>,
+    #[structopt(long)]
+    #[serde(default)]
+    pub freeze_interval_ms: Option<u64>,
+    #[structopt(long)]
+    #[serde(default)]
+    pub freeze_low_latency: bool,
+}
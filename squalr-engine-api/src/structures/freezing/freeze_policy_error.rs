@@ -0,0 +1,38 @@
+use crate::structures::freezing::freeze_mode::FreezeMode;
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum FreezePolicyError {
+    #[error("Freeze mode '{freeze_mode}' requires a {parameter_name} value.")]
+    MissingParameter { freeze_mode: FreezeMode, parameter_name: String },
+    #[error("Invalid {parameter_name} value '{value}' for data type '{data_type_id}'.")]
+    InvalidParameter {
+        parameter_name: String,
+        value: String,
+        data_type_id: String,
+    },
+}
+
+impl FreezePolicyError {
+    pub fn missing_parameter(
+        freeze_mode: FreezeMode,
+        parameter_name: impl Into<String>,
+    ) -> Self {
+        Self::MissingParameter {
+            freeze_mode,
+            parameter_name: parameter_name.into(),
+        }
+    }
+
+    pub fn invalid_parameter(
+        parameter_name: impl Into<String>,
+        value: impl Into<String>,
+        data_type_id: impl Into<String>,
+    ) -> Self {
+        Self::InvalidParameter {
+            parameter_name: parameter_name.into(),
+            value: value.into(),
+            data_type_id: data_type_id.into(),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod freeze_metrics;
+pub mod freeze_mode;
+pub mod freeze_policy;
+pub mod freeze_policy_error;
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The action performed on a project item when one of its hotkeys is pressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HotkeyAction {
+    /// Activates the item if it is inactive, otherwise deactivates it.
+    #[default]
+    ToggleActivation,
+    /// Writes the binding value to an address item.
+    SetValue,
+    /// Adds the binding value to the current value of an address item.
+    Increment,
+    /// Subtracts the binding value from the current value of an address item.
+    Decrement,
+    /// Applies a code patch item, leaving it applied if it already is.
+    RunScript,
+}
+
+impl HotkeyAction {
+    /// Determines whether this action requires a value on its binding.
+    pub fn requires_value(&self) -> bool {
+        matches!(self, HotkeyAction::SetValue | HotkeyAction::Increment | HotkeyAction::Decrement)
+    }
+}
+
+impl FromStr for HotkeyAction {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "t" | "toggle" | "toggle-activation" | "toggle_activation" => Ok(HotkeyAction::ToggleActivation),
+            "s" | "set" | "set-value" | "set_value" => Ok(HotkeyAction::SetValue),
+            "i" | "inc" | "increment" => Ok(HotkeyAction::Increment),
+            "d" | "dec" | "decrement" => Ok(HotkeyAction::Decrement),
+            "r" | "run" | "script" | "run-script" | "run_script" => Ok(HotkeyAction::RunScript),
+            _ => Err(format!("Invalid hotkey action: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for HotkeyAction {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            HotkeyAction::ToggleActivation => write!(formatter, "toggle"),
+            HotkeyAction::SetValue => write!(formatter, "set"),
+            HotkeyAction::Increment => write!(formatter, "increment"),
+            HotkeyAction::Decrement => write!(formatter, "decrement"),
+            HotkeyAction::RunScript => write!(formatter, "run-script"),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::structures::hotkeys::hotkey_action::HotkeyAction;
+use crate::structures::hotkeys::key_chord::KeyChord;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Binds a key chord to an action on a project item, written as `<chord>=<action>[:<value>]`, for example `ctrl+f1=toggle`
+/// or `f2=set:100`. Values are decimal value strings, interpreted using the data type of the bound address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HotkeyBinding {
+    key_chord: KeyChord,
+    hotkey_action: HotkeyAction,
+    value: Option<String>,
+}
+
+impl HotkeyBinding {
+    /// The separator between bindings when several are stored in a single project item property.
+    pub const LIST_SEPARATOR: char = ';';
+
+    pub fn new(
+        key_chord: KeyChord,
+        hotkey_action: HotkeyAction,
+        value: Option<String>,
+    ) -> Self {
+        Self {
+            key_chord,
+            hotkey_action,
+            value,
+        }
+    }
+
+    pub fn get_key_chord(&self) -> &KeyChord {
+        &self.key_chord
+    }
+
+    pub fn get_hotkey_action(&self) -> HotkeyAction {
+        self.hotkey_action
+    }
+
+    pub fn get_value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl FromStr for HotkeyBinding {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (key_chord, action) = string
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid hotkey binding: '{}'", string))?;
+        let (hotkey_action, value) = match action.split_once(':') {
+            Some((hotkey_action, value)) => (hotkey_action, Some(value.trim().to_string()).filter(|value| !value.is_empty())),
+            None => (action, None),
+        };
+        let hotkey_binding = HotkeyBinding {
+            key_chord: key_chord.trim().parse()?,
+            hotkey_action: hotkey_action.trim().parse()?,
+            value,
+        };
+
+        if hotkey_binding.hotkey_action.requires_value() && hotkey_binding.value.is_none() {
+            return Err(format!("Hotkey action '{}' requires a value: '{}'", hotkey_binding.hotkey_action, string));
+        }
+
+        Ok(hotkey_binding)
+    }
+}
+
+impl fmt::Display for HotkeyBinding {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(formatter, "{}={}:{}", self.key_chord, self.hotkey_action, value),
+            None => write!(formatter, "{}={}", self.key_chord, self.hotkey_action),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HotkeyBinding;
+    use crate::structures::hotkeys::{hotkey_action::HotkeyAction, key_chord::KeyChord};
+
+    #[test]
+    fn hotkey_binding_parses_chord_action_and_value() {
+        let hotkey_binding = "Control + Shift + F2 = set:-100"
+            .parse::<HotkeyBinding>()
+            .expect("binding should parse");
+
+        assert_eq!(hotkey_binding.get_key_chord(), &KeyChord::new("f2", true, false, true, false));
+        assert_eq!(hotkey_binding.get_hotkey_action(), HotkeyAction::SetValue);
+        assert_eq!(hotkey_binding.get_value(), Some("-100"));
+        assert_eq!(hotkey_binding.to_string(), "ctrl+shift+f2=set:-100");
+    }
+
+    #[test]
+    fn hotkey_binding_rejects_missing_values_and_malformed_chords() {
+        assert!("f3=increment".parse::<HotkeyBinding>().is_err());
+        assert!("ctrl=toggle".parse::<HotkeyBinding>().is_err());
+        assert!("a+b=toggle".parse::<HotkeyBinding>().is_err());
+        assert!("f1".parse::<HotkeyBinding>().is_err());
+        assert!("alt+f4=toggle".parse::<HotkeyBinding>().is_ok());
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A key pressed while holding a set of modifiers, such as `ctrl+shift+f1`. Keys are named in lowercase, for example `a`, `5`,
+/// `f12`, `space`, `up` or `numpad8`, and left and right modifiers are not distinguished.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyChord {
+    key_name: String,
+    is_control: bool,
+    is_alt: bool,
+    is_shift: bool,
+    is_super: bool,
+}
+
+impl KeyChord {
+    pub const MODIFIER_CONTROL: &str = "ctrl";
+    pub const MODIFIER_ALT: &str = "alt";
+    pub const MODIFIER_SHIFT: &str = "shift";
+    pub const MODIFIER_SUPER: &str = "super";
+
+    pub fn new(
+        key_name: &str,
+        is_control: bool,
+        is_alt: bool,
+        is_shift: bool,
+        is_super: bool,
+    ) -> Self {
+        Self {
+            key_name: key_name.to_ascii_lowercase(),
+            is_control,
+            is_alt,
+            is_shift,
+            is_super,
+        }
+    }
+
+    pub fn get_key_name(&self) -> &str {
+        &self.key_name
+    }
+
+    pub fn is_control(&self) -> bool {
+        self.is_control
+    }
+
+    pub fn is_alt(&self) -> bool {
+        self.is_alt
+    }
+
+    pub fn is_shift(&self) -> bool {
+        self.is_shift
+    }
+
+    pub fn is_super(&self) -> bool {
+        self.is_super
+    }
+
+    /// Gets the canonical name of a modifier key, accepting common aliases, or `None` if the key is not a modifier.
+    pub fn get_modifier_name(key_name: &str) -> Option<&'static str> {
+        match key_name.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => Some(Self::MODIFIER_CONTROL),
+            "alt" | "option" => Some(Self::MODIFIER_ALT),
+            "shift" => Some(Self::MODIFIER_SHIFT),
+            "super" | "meta" | "win" | "cmd" => Some(Self::MODIFIER_SUPER),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut key_chord = KeyChord::default();
+
+        for key_name in string.split('+').map(str::trim) {
+            match Self::get_modifier_name(key_name) {
+                Some(Self::MODIFIER_CONTROL) => key_chord.is_control = true,
+                Some(Self::MODIFIER_ALT) => key_chord.is_alt = true,
+                Some(Self::MODIFIER_SHIFT) => key_chord.is_shift = true,
+                Some(_) => key_chord.is_super = true,
+                None if key_name.is_empty() || !key_chord.key_name.is_empty() => return Err(format!("Invalid key chord: '{}'", string)),
+                None => key_chord.key_name = key_name.to_ascii_lowercase(),
+            }
+        }
+
+        if key_chord.key_name.is_empty() {
+            return Err(format!("Invalid key chord: '{}'", string));
+        }
+
+        Ok(key_chord)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let modifiers = [
+            (self.is_control, Self::MODIFIER_CONTROL),
+            (self.is_alt, Self::MODIFIER_ALT),
+            (self.is_shift, Self::MODIFIER_SHIFT),
+            (self.is_super, Self::MODIFIER_SUPER),
+        ];
+
+        for (_is_held, modifier_name) in modifiers.iter().filter(|(is_held, _modifier_name)| *is_held) {
+            write!(formatter, "{}+", modifier_name)?;
+        }
+
+        write!(formatter, "{}", self.key_name)
+    }
+}
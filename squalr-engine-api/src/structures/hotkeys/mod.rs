@@ -0,0 +1,3 @@
+pub mod hotkey_action;
+pub mod hotkey_binding;
+pub mod key_chord;
@@ -0,0 +1,571 @@
+use crate::structures::memory::address_expression_context::AddressExpressionContext;
+use crate::structures::memory::address_expression_error::AddressExpressionError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed address expression, such as `game.exe+0x1234`, `[[base+10]+8]+4`, or `"my-lib.so"+#64*2`.
+///
+/// Numbers are hexadecimal by default (with or without a `0x` prefix), and decimal when prefixed with `#`.
+/// Names resolve to modules or symbols, and may be quoted when they contain operators or look like hex numbers.
+/// Symbols within a module are named as `module!symbol`, such as `libc.so.6!malloc+10`.
+/// Square brackets dereference a pointer, reading a pointer-sized value at the enclosed address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressExpression {
+    Literal(u64),
+    Symbol(String),
+    Dereference(Box<AddressExpression>),
+    Negate(Box<AddressExpression>),
+    Add(Box<AddressExpression>, Box<AddressExpression>),
+    Subtract(Box<AddressExpression>, Box<AddressExpression>),
+    Multiply(Box<AddressExpression>, Box<AddressExpression>),
+}
+
+impl AddressExpression {
+    /// Separates a module name from a symbol name within that module, as in `libc.so.6!malloc`.
+    pub const MODULE_SYMBOL_SEPARATOR: char = '!';
+
+    pub fn parse(text: &str) -> Result<Self, AddressExpressionError> {
+        let tokens = tokenize(text)?;
+
+        if tokens.is_empty() {
+            return Err(AddressExpressionError::Empty);
+        }
+
+        let mut parser = Parser { tokens, position: 0 };
+        let expression = parser.parse_sum()?;
+
+        match parser.peek() {
+            Some(token) => Err(AddressExpressionError::unexpected_token(token.kind.to_string(), token.position)),
+            None => Ok(expression),
+        }
+    }
+
+    /// Evaluates this expression to an absolute address, resolving names and dereferencing pointers through the context.
+    /// Arithmetic wraps on overflow, mirroring pointer arithmetic in the target process.
+    pub fn evaluate(
+        &self,
+        context: &dyn AddressExpressionContext,
+    ) -> Result<u64, AddressExpressionError> {
+        match self {
+            AddressExpression::Literal(value) => Ok(*value),
+            AddressExpression::Symbol(name) => context
+                .resolve_symbol(name)
+                .ok_or_else(|| AddressExpressionError::unknown_symbol(name.clone())),
+            AddressExpression::Dereference(inner) => {
+                let pointer_address = inner.evaluate(context)?;
+
+                context
+                    .read_pointer(pointer_address)
+                    .ok_or(AddressExpressionError::unreadable_pointer(pointer_address))
+            }
+            AddressExpression::Negate(inner) => Ok(inner.evaluate(context)?.wrapping_neg()),
+            AddressExpression::Add(left, right) => Ok(left.evaluate(context)?.wrapping_add(right.evaluate(context)?)),
+            AddressExpression::Subtract(left, right) => Ok(left.evaluate(context)?.wrapping_sub(right.evaluate(context)?)),
+            AddressExpression::Multiply(left, right) => Ok(left.evaluate(context)?.wrapping_mul(right.evaluate(context)?)),
+        }
+    }
+
+    /// Gets whether this expression can be evaluated without reading process memory.
+    pub fn is_static(&self) -> bool {
+        match self {
+            AddressExpression::Literal(_) | AddressExpression::Symbol(_) => true,
+            AddressExpression::Dereference(_) => false,
+            AddressExpression::Negate(inner) => inner.is_static(),
+            AddressExpression::Add(left, right) | AddressExpression::Subtract(left, right) | AddressExpression::Multiply(left, right) => {
+                left.is_static() && right.is_static()
+            }
+        }
+    }
+
+    /// Reduces this expression to a module name and offset, if it is a constant optionally offset from a single module.
+    /// Absolute addresses produce an empty module name.
+    pub fn to_module_offset(&self) -> Option<(String, u64)> {
+        match self {
+            AddressExpression::Literal(value) => Some((String::new(), *value)),
+            AddressExpression::Symbol(name) => Some((name.clone(), 0)),
+            AddressExpression::Dereference(_) => None,
+            AddressExpression::Negate(inner) => match inner.to_module_offset()? {
+                (module_name, value) if module_name.is_empty() => Some((module_name, value.wrapping_neg())),
+                _ => None,
+            },
+            AddressExpression::Add(left, right) => match (left.to_module_offset()?, right.to_module_offset()?) {
+                ((left_module, left_value), (right_module, right_value)) if left_module.is_empty() || right_module.is_empty() => {
+                    Some((left_module + &right_module, left_value.wrapping_add(right_value)))
+                }
+                _ => None,
+            },
+            AddressExpression::Subtract(left, right) => match (left.to_module_offset()?, right.to_module_offset()?) {
+                ((left_module, left_value), (right_module, right_value)) if right_module.is_empty() => {
+                    Some((left_module, left_value.wrapping_sub(right_value)))
+                }
+                _ => None,
+            },
+            AddressExpression::Multiply(left, right) => match (left.to_module_offset()?, right.to_module_offset()?) {
+                ((left_module, left_value), (right_module, right_value)) if left_module.is_empty() && right_module.is_empty() => {
+                    Some((String::new(), left_value.wrapping_mul(right_value)))
+                }
+                _ => None,
+            },
+        }
+    }
+
+    /// Formats a module name and offset as an expression string, such as `game.exe+0x1234` or `0x7FF00000`.
+    pub fn format_module_offset(
+        module_name: &str,
+        offset: u64,
+    ) -> String {
+        if module_name.is_empty() {
+            AddressExpression::Literal(offset).to_string()
+        } else {
+            AddressExpression::Add(
+                Box::new(AddressExpression::Symbol(module_name.to_string())),
+                Box::new(AddressExpression::Literal(offset)),
+            )
+            .to_string()
+        }
+    }
+
+    /// Formats a module symbol and displacement as an expression string, such as `libc.so.6!malloc+0x10`.
+    pub fn format_symbol_offset(
+        module_name: &str,
+        symbol_name: &str,
+        displacement: u64,
+    ) -> String {
+        let symbol = AddressExpression::Symbol(format!("{}{}{}", module_name, Self::MODULE_SYMBOL_SEPARATOR, symbol_name));
+
+        if displacement == 0 {
+            symbol.to_string()
+        } else {
+            AddressExpression::Add(Box::new(symbol), Box::new(AddressExpression::Literal(displacement))).to_string()
+        }
+    }
+
+    /// Splits a name of the form `module!symbol` into its module and symbol names.
+    pub fn split_module_symbol(name: &str) -> Option<(&str, &str)> {
+        name.split_once(Self::MODULE_SYMBOL_SEPARATOR)
+            .filter(|(module_name, symbol_name)| !module_name.is_empty() && !symbol_name.is_empty())
+    }
+
+    fn is_sum(&self) -> bool {
+        matches!(self, AddressExpression::Add(_, _) | AddressExpression::Subtract(_, _))
+    }
+}
+
+impl FromStr for AddressExpression {
+    type Err = AddressExpressionError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl fmt::Display for AddressExpression {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            AddressExpression::Literal(value) => write!(formatter, "0x{:X}", value),
+            AddressExpression::Symbol(name) => {
+                if is_bare_symbol(name) {
+                    write!(formatter, "{}", name)
+                } else {
+                    write!(formatter, "\"{}\"", name)
+                }
+            }
+            AddressExpression::Dereference(inner) => write!(formatter, "[{}]", inner),
+            AddressExpression::Negate(inner) => match inner.as_ref() {
+                AddressExpression::Literal(_) | AddressExpression::Symbol(_) | AddressExpression::Dereference(_) => write!(formatter, "-{}", inner),
+                _ => write!(formatter, "-({})", inner),
+            },
+            AddressExpression::Add(left, right) => write!(formatter, "{}+{}", left, right),
+            AddressExpression::Subtract(left, right) => {
+                if right.is_sum() {
+                    write!(formatter, "{}-({})", left, right)
+                } else {
+                    write!(formatter, "{}-{}", left, right)
+                }
+            }
+            AddressExpression::Multiply(left, right) => {
+                let format_operand = |operand: &AddressExpression| {
+                    if operand.is_sum() { format!("({})", operand) } else { operand.to_string() }
+                };
+
+                write!(formatter, "{}*{}", format_operand(left), format_operand(right))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum TokenKind {
+    Number(u64),
+    Symbol(String),
+    Plus,
+    Minus,
+    Star,
+    OpenParenthesis,
+    CloseParenthesis,
+    OpenBracket,
+    CloseBracket,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            TokenKind::Number(value) => write!(formatter, "0x{:X}", value),
+            TokenKind::Symbol(name) => write!(formatter, "{}", name),
+            TokenKind::Plus => write!(formatter, "+"),
+            TokenKind::Minus => write!(formatter, "-"),
+            TokenKind::Star => write!(formatter, "*"),
+            TokenKind::OpenParenthesis => write!(formatter, "("),
+            TokenKind::CloseParenthesis => write!(formatter, ")"),
+            TokenKind::OpenBracket => write!(formatter, "["),
+            TokenKind::CloseBracket => write!(formatter, "]"),
+        }
+    }
+}
+
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn is_symbol_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || matches!(character, '_' | '.' | '$' | '@' | '!' | '?')
+}
+
+fn is_bare_symbol(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(is_symbol_character) && parse_hex_word(name).is_none()
+}
+
+/// Parses a bare word as a hexadecimal number, if it consists entirely of hex digits (with an optional `0x` prefix).
+fn parse_hex_word(word: &str) -> Option<Result<u64, AddressExpressionError>> {
+    let digits = word
+        .strip_prefix("0x")
+        .or_else(|| word.strip_prefix("0X"))
+        .unwrap_or(word);
+
+    if digits.is_empty() || !digits.chars().all(|character| character.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(u64::from_str_radix(digits, 16).map_err(|_| AddressExpressionError::invalid_number(word)))
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, AddressExpressionError> {
+    let characters = text.char_indices().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < characters.len() {
+        let (position, character) = characters[index];
+        let single_character_kind = match character {
+            '+' => Some(TokenKind::Plus),
+            '-' => Some(TokenKind::Minus),
+            '*' => Some(TokenKind::Star),
+            '(' => Some(TokenKind::OpenParenthesis),
+            ')' => Some(TokenKind::CloseParenthesis),
+            '[' => Some(TokenKind::OpenBracket),
+            ']' => Some(TokenKind::CloseBracket),
+            _ => None,
+        };
+
+        if character.is_whitespace() {
+            index += 1;
+        } else if let Some(kind) = single_character_kind {
+            tokens.push(Token { kind, position });
+            index += 1;
+        } else if character == '"' {
+            let name_start = index + 1;
+            let name_end = (name_start..characters.len())
+                .find(|name_index| characters[*name_index].1 == '"')
+                .ok_or(AddressExpressionError::unexpected_end("closing '\"'"))?;
+            let name = characters[name_start..name_end]
+                .iter()
+                .map(|(_, name_character)| *name_character)
+                .collect::<String>();
+
+            if name.is_empty() {
+                return Err(AddressExpressionError::unexpected_token("\"\"", position));
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Symbol(name),
+                position,
+            });
+            index = name_end + 1;
+        } else if character == '#' {
+            let digits_start = index + 1;
+            let digits_end = (digits_start..characters.len())
+                .find(|digit_index| !characters[*digit_index].1.is_ascii_digit())
+                .unwrap_or(characters.len());
+            let digits = characters[digits_start..digits_end]
+                .iter()
+                .map(|(_, digit)| *digit)
+                .collect::<String>();
+            let value = digits
+                .parse::<u64>()
+                .map_err(|_| AddressExpressionError::invalid_number(format!("#{}", digits)))?;
+
+            tokens.push(Token {
+                kind: TokenKind::Number(value),
+                position,
+            });
+            index = digits_end;
+        } else if is_symbol_character(character) {
+            let word_end = (index..characters.len())
+                .find(|word_index| !is_symbol_character(characters[*word_index].1))
+                .unwrap_or(characters.len());
+            let word = characters[index..word_end]
+                .iter()
+                .map(|(_, word_character)| *word_character)
+                .collect::<String>();
+            let kind = match parse_hex_word(&word) {
+                Some(value) => TokenKind::Number(value?),
+                None => TokenKind::Symbol(word),
+            };
+
+            tokens.push(Token { kind, position });
+            index = word_end;
+        } else {
+            return Err(AddressExpressionError::unexpected_character(character, position));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent parser over the grammar:
+/// `sum := product (('+' | '-') product)*`, `product := unary ('*' unary)*`,
+/// `unary := '-' unary | primary`, and `primary := number | name | '(' sum ')' | '[' sum ']'`.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.position);
+
+        self.position += 1;
+
+        token
+    }
+
+    fn parse_sum(&mut self) -> Result<AddressExpression, AddressExpressionError> {
+        let mut expression = self.parse_product()?;
+
+        loop {
+            match self.peek().map(|token| &token.kind) {
+                Some(TokenKind::Plus) => {
+                    self.position += 1;
+                    expression = AddressExpression::Add(Box::new(expression), Box::new(self.parse_product()?));
+                }
+                Some(TokenKind::Minus) => {
+                    self.position += 1;
+                    expression = AddressExpression::Subtract(Box::new(expression), Box::new(self.parse_product()?));
+                }
+                _ => return Ok(expression),
+            }
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<AddressExpression, AddressExpressionError> {
+        let mut expression = self.parse_unary()?;
+
+        while let Some(TokenKind::Star) = self.peek().map(|token| &token.kind) {
+            self.position += 1;
+            expression = AddressExpression::Multiply(Box::new(expression), Box::new(self.parse_unary()?));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<AddressExpression, AddressExpressionError> {
+        if let Some(TokenKind::Minus) = self.peek().map(|token| &token.kind) {
+            self.position += 1;
+
+            return Ok(AddressExpression::Negate(Box::new(self.parse_unary()?)));
+        }
+
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<AddressExpression, AddressExpressionError> {
+        let (kind, position) = match self.next() {
+            Some(token) => (token.kind.clone(), token.position),
+            None => return Err(AddressExpressionError::unexpected_end("a number, name, '(' or '['")),
+        };
+
+        match kind {
+            TokenKind::Number(value) => Ok(AddressExpression::Literal(value)),
+            TokenKind::Symbol(name) => Ok(AddressExpression::Symbol(name)),
+            TokenKind::OpenParenthesis => {
+                let inner = self.parse_sum()?;
+
+                self.expect(TokenKind::CloseParenthesis, "')'")?;
+
+                Ok(inner)
+            }
+            TokenKind::OpenBracket => {
+                let inner = self.parse_sum()?;
+
+                self.expect(TokenKind::CloseBracket, "']'")?;
+
+                Ok(AddressExpression::Dereference(Box::new(inner)))
+            }
+            kind => Err(AddressExpressionError::unexpected_token(kind.to_string(), position)),
+        }
+    }
+
+    fn expect(
+        &mut self,
+        expected_kind: TokenKind,
+        expected_description: &'static str,
+    ) -> Result<(), AddressExpressionError> {
+        match self.next() {
+            Some(token) if token.kind == expected_kind => Ok(()),
+            Some(token) => Err(AddressExpressionError::unexpected_token(token.kind.to_string(), token.position)),
+            None => Err(AddressExpressionError::unexpected_end(expected_description)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddressExpression;
+    use crate::structures::memory::address_expression_context::AddressExpressionContext;
+    use crate::structures::memory::address_expression_error::AddressExpressionError;
+
+    struct TestContext;
+
+    impl AddressExpressionContext for TestContext {
+        fn resolve_symbol(
+            &self,
+            name: &str,
+        ) -> Option<u64> {
+            match name {
+                "game.exe" => Some(0x40_0000),
+                "my-lib.so" => Some(0x7000),
+                _ => None,
+            }
+        }
+
+        fn read_pointer(
+            &self,
+            address: u64,
+        ) -> Option<u64> {
+            match address {
+                0x40_0010 => Some(0x1000),
+                0x1008 => Some(0x2000),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn parse_treats_bare_words_as_hex_and_hash_prefix_as_decimal() {
+        assert_eq!(AddressExpression::parse("1F0"), Ok(AddressExpression::Literal(0x1F0)));
+        assert_eq!(AddressExpression::parse("0x1F0"), Ok(AddressExpression::Literal(0x1F0)));
+        assert_eq!(AddressExpression::parse("#100"), Ok(AddressExpression::Literal(100)));
+        assert_eq!(AddressExpression::parse("game.exe"), Ok(AddressExpression::Symbol("game.exe".to_string())));
+    }
+
+    #[test]
+    fn evaluate_follows_nested_pointer_chains() {
+        let expression = AddressExpression::parse("[[game.exe+10]+8]+4").expect("expression should parse");
+
+        assert!(!expression.is_static());
+        assert_eq!(expression.evaluate(&TestContext), Ok(0x2004));
+    }
+
+    #[test]
+    fn evaluate_respects_operator_precedence_and_quoted_names() {
+        let expression = AddressExpression::parse("\"my-lib.so\" + #4 * (2 + 2) - -1").expect("expression should parse");
+
+        assert_eq!(expression.evaluate(&TestContext), Ok(0x7000 + 16 + 1));
+    }
+
+    #[test]
+    fn evaluate_reports_unknown_symbols_and_unreadable_pointers() {
+        assert_eq!(
+            AddressExpression::parse("missing.dll+4")
+                .expect("expression should parse")
+                .evaluate(&TestContext),
+            Err(AddressExpressionError::unknown_symbol("missing.dll"))
+        );
+        assert_eq!(
+            AddressExpression::parse("[game.exe]")
+                .expect("expression should parse")
+                .evaluate(&TestContext),
+            Err(AddressExpressionError::unreadable_pointer(0x40_0000))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_expressions() {
+        assert_eq!(AddressExpression::parse("  "), Err(AddressExpressionError::Empty));
+        assert!(matches!(
+            AddressExpression::parse("[game.exe+10"),
+            Err(AddressExpressionError::UnexpectedEnd { .. })
+        ));
+        assert!(matches!(
+            AddressExpression::parse("game.exe+"),
+            Err(AddressExpressionError::UnexpectedEnd { .. })
+        ));
+        assert!(matches!(AddressExpression::parse("10 20"), Err(AddressExpressionError::UnexpectedToken { .. })));
+        assert!(matches!(
+            AddressExpression::parse("10 % 20"),
+            Err(AddressExpressionError::UnexpectedCharacter { .. })
+        ));
+    }
+
+    #[test]
+    fn to_module_offset_folds_static_module_offsets() {
+        let module_offset = |text: &str| {
+            AddressExpression::parse(text)
+                .expect("expression should parse")
+                .to_module_offset()
+        };
+
+        assert_eq!(module_offset("game.exe+10+20"), Some(("game.exe".to_string(), 0x30)));
+        assert_eq!(module_offset("7FF00000"), Some((String::new(), 0x7FF0_0000)));
+        assert_eq!(module_offset("game.exe+other.dll"), None);
+        assert_eq!(module_offset("[game.exe]+4"), None);
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for text in [
+            "[[game.exe+0x10]+0x8]+0x4",
+            "\"my-lib.so\"-(0x4+0x2)",
+            "(0x1+0x2)*-0x3",
+            "\"beef\"+0x1",
+        ] {
+            let expression = AddressExpression::parse(text).expect("expression should parse");
+
+            assert_eq!(expression.to_string(), text);
+            assert_eq!(AddressExpression::parse(&expression.to_string()), Ok(expression));
+        }
+    }
+
+    #[test]
+    fn format_symbol_offset_produces_parseable_module_symbol_names() {
+        let label = AddressExpression::format_symbol_offset("libc.so.6", "malloc", 0x10);
+        let expression = AddressExpression::parse(&label).expect("expression should parse");
+
+        assert_eq!(label, "libc.so.6!malloc+0x10");
+        assert_eq!(expression.to_module_offset(), Some(("libc.so.6!malloc".to_string(), 0x10)));
+        assert_eq!(AddressExpression::split_module_symbol("libc.so.6!malloc"), Some(("libc.so.6", "malloc")));
+        assert_eq!(AddressExpression::split_module_symbol("game.exe"), None);
+        assert_eq!(AddressExpression::format_symbol_offset("my-lib.so", "init", 0), "\"my-lib.so!init\"");
+    }
+}
@@ -0,0 +1,14 @@
+/// Supplies the process-specific lookups needed to evaluate an address expression.
+pub trait AddressExpressionContext {
+    /// Resolves a module or symbol name to its address, if it exists.
+    fn resolve_symbol(
+        &self,
+        name: &str,
+    ) -> Option<u64>;
+
+    /// Reads a pointer-sized value at the given address, if it is readable.
+    fn read_pointer(
+        &self,
+        address: u64,
+    ) -> Option<u64>;
+}
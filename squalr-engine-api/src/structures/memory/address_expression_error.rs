@@ -0,0 +1,51 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum AddressExpressionError {
+    #[error("Address expression is empty.")]
+    Empty,
+    #[error("Unexpected character '{character}' at position {position}.")]
+    UnexpectedCharacter { character: char, position: usize },
+    #[error("Unexpected '{token}' at position {position}.")]
+    UnexpectedToken { token: String, position: usize },
+    #[error("Unexpected end of address expression, expected {expected}.")]
+    UnexpectedEnd { expected: &'static str },
+    #[error("Invalid number '{text}'.")]
+    InvalidNumber { text: String },
+    #[error("Unknown module or symbol '{name}'.")]
+    UnknownSymbol { name: String },
+    #[error("Failed to read pointer at address 0x{address:X}.")]
+    UnreadablePointer { address: u64 },
+}
+
+impl AddressExpressionError {
+    pub fn unexpected_character(
+        character: char,
+        position: usize,
+    ) -> Self {
+        Self::UnexpectedCharacter { character, position }
+    }
+
+    pub fn unexpected_token(
+        token: impl Into<String>,
+        position: usize,
+    ) -> Self {
+        Self::UnexpectedToken { token: token.into(), position }
+    }
+
+    pub fn unexpected_end(expected: &'static str) -> Self {
+        Self::UnexpectedEnd { expected }
+    }
+
+    pub fn invalid_number(text: impl Into<String>) -> Self {
+        Self::InvalidNumber { text: text.into() }
+    }
+
+    pub fn unknown_symbol(name: impl Into<String>) -> Self {
+        Self::UnknownSymbol { name: name.into() }
+    }
+
+    pub fn unreadable_pointer(address: u64) -> Self {
+        Self::UnreadablePointer { address }
+    }
+}
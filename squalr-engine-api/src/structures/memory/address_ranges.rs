@@ -0,0 +1,52 @@
+use crate::structures::memory::normalized_region::NormalizedRegion;
+
+/// Operations over sorted, non-overlapping `(base, end)` address ranges.
+pub struct AddressRanges;
+
+impl AddressRanges {
+    /// Merges sorted memory pages into contiguous (base, end) address ranges.
+    pub fn merge(memory_pages: &[NormalizedRegion]) -> Vec<(u64, u64)> {
+        let mut ranges: Vec<(u64, u64)> = vec![];
+
+        for memory_page in memory_pages {
+            match ranges.last_mut() {
+                Some((_, end_address)) if *end_address >= memory_page.get_base_address() => {
+                    *end_address = (*end_address).max(memory_page.get_end_address());
+                }
+                _ => ranges.push((memory_page.get_base_address(), memory_page.get_end_address())),
+            }
+        }
+
+        ranges
+    }
+
+    /// Removes all addresses in the subtracted ranges from the given ranges. Both inputs must be sorted and non-overlapping.
+    pub fn subtract(
+        ranges: &[(u64, u64)],
+        subtracted_ranges: &[(u64, u64)],
+    ) -> Vec<(u64, u64)> {
+        let mut remaining_ranges = vec![];
+
+        for &(base_address, end_address) in ranges {
+            let mut current_address = base_address;
+
+            for &(subtracted_base_address, subtracted_end_address) in subtracted_ranges {
+                if subtracted_end_address <= current_address || subtracted_base_address >= end_address {
+                    continue;
+                }
+
+                if subtracted_base_address > current_address {
+                    remaining_ranges.push((current_address, subtracted_base_address));
+                }
+
+                current_address = current_address.max(subtracted_end_address);
+            }
+
+            if current_address < end_address {
+                remaining_ranges.push((current_address, end_address));
+            }
+        }
+
+        remaining_ranges
+    }
+}
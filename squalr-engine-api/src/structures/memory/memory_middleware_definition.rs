@@ -0,0 +1,95 @@
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::memory::endian::Endian;
+use serde::{Deserialize, Serialize};
+
+/// Describes a memory middleware that locates a guest memory block in the host process by byte signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryMiddlewareDefinition {
+    middleware_name: String,
+    module_name: String,
+    signature: String,
+    signature_offset: i64,
+    guest_base_address: u64,
+    region_size: u64,
+    endian: Endian,
+    guest_bitness: Bitness,
+}
+
+impl MemoryMiddlewareDefinition {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        middleware_name: String,
+        module_name: String,
+        signature: String,
+        signature_offset: i64,
+        guest_base_address: u64,
+        region_size: u64,
+        endian: Endian,
+        guest_bitness: Bitness,
+    ) -> Self {
+        Self {
+            middleware_name,
+            module_name,
+            signature,
+            signature_offset,
+            guest_base_address,
+            region_size,
+            endian,
+            guest_bitness,
+        }
+    }
+
+    pub fn get_middleware_name(&self) -> &str {
+        &self.middleware_name
+    }
+
+    /// Gets the name of the virtual module that the guest memory block is exposed as.
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    /// Gets the signature string, as space separated hex bytes where `??` matches any byte.
+    pub fn get_signature(&self) -> &str {
+        &self.signature
+    }
+
+    /// Gets the offset from the start of a signature match to the start of the guest memory block.
+    pub fn get_signature_offset(&self) -> i64 {
+        self.signature_offset
+    }
+
+    pub fn get_guest_base_address(&self) -> u64 {
+        self.guest_base_address
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        self.region_size
+    }
+
+    pub fn get_endian(&self) -> &Endian {
+        &self.endian
+    }
+
+    /// Gets the bitness of the guest system, which determines its pointer size independently of the host emulator.
+    pub fn get_guest_bitness(&self) -> Bitness {
+        self.guest_bitness
+    }
+
+    /// Parses the signature into a byte pattern, where `None` entries are wildcards.
+    pub fn parse_signature(&self) -> Option<Vec<Option<u8>>> {
+        let pattern = self
+            .signature
+            .split_whitespace()
+            .map(|token| match token {
+                "?" | "??" => Some(None),
+                token => u8::from_str_radix(token, 16).ok().map(Some),
+            })
+            .collect::<Option<Vec<Option<u8>>>>()?;
+
+        if pattern.iter().all(|byte| byte.is_none()) {
+            return None;
+        }
+
+        Some(pattern)
+    }
+}
@@ -1,6 +1,17 @@
+pub mod address_expression;
+pub mod address_expression_context;
+pub mod address_expression_error;
+pub mod address_ranges;
 pub mod bitness;
 pub mod endian;
 pub mod memory_alignment;
+pub mod memory_middleware_definition;
+pub mod module_build_identity;
+pub mod module_build_mismatch;
+pub mod module_symbol;
+pub mod module_symbol_error;
+pub mod module_symbol_table;
 pub mod normalized_module;
 pub mod normalized_region;
 pub mod pointer;
+pub mod virtual_address_space;
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Identifies the exact build of a module, such that addresses recorded against one build can be recognized as stale in another.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleBuildIdentity {
+    module_name: String,
+    build_id: String,
+}
+
+impl ModuleBuildIdentity {
+    pub fn new(
+        module_name: String,
+        build_id: String,
+    ) -> Self {
+        Self { module_name, build_id }
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    /// Gets the build id, prefixed by the kind of identity it was derived from (ie `pe:`, `build-id:` or `header-hash:`).
+    pub fn get_build_id(&self) -> &str {
+        &self.build_id
+    }
+}
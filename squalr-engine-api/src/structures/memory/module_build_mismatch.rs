@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// A module whose loaded build differs from the build that a set of addresses was recorded against.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleBuildMismatch {
+    module_name: String,
+    expected_build_id: String,
+    actual_build_id: String,
+}
+
+impl ModuleBuildMismatch {
+    pub fn new(
+        module_name: String,
+        expected_build_id: String,
+        actual_build_id: String,
+    ) -> Self {
+        Self {
+            module_name,
+            expected_build_id,
+            actual_build_id,
+        }
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    pub fn get_expected_build_id(&self) -> &str {
+        &self.expected_build_id
+    }
+
+    pub fn get_actual_build_id(&self) -> &str {
+        &self.actual_build_id
+    }
+}
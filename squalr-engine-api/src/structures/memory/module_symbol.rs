@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// A named symbol exported or defined by a module, located relative to the module base.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleSymbol {
+    name: String,
+    offset: u64,
+    size: u64,
+}
+
+impl ModuleSymbol {
+    pub fn new(
+        name: String,
+        offset: u64,
+        size: u64,
+    ) -> Self {
+        Self { name, offset, size }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the offset of this symbol from the base address of its module.
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Gets the size of this symbol in bytes, or zero if the symbol table does not record one.
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+}
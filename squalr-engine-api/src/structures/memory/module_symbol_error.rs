@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ModuleSymbolError {
+    #[error("Failed to read module file '{module_path}': {reason}.")]
+    UnreadableFile { module_path: String, reason: String },
+    #[error("Failed to read {size} bytes at module offset 0x{offset:X}.")]
+    UnreadableImage { offset: u64, size: usize },
+    #[error("Module image is not in a supported format.")]
+    UnsupportedFormat,
+    #[error("Malformed module image: {reason}.")]
+    MalformedImage { reason: String },
+}
+
+impl ModuleSymbolError {
+    pub fn unreadable_file(
+        module_path: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::UnreadableFile {
+            module_path: module_path.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn unreadable_image(
+        offset: u64,
+        size: usize,
+    ) -> Self {
+        Self::UnreadableImage { offset, size }
+    }
+
+    pub fn malformed_image(reason: impl Into<String>) -> Self {
+        Self::MalformedImage { reason: reason.into() }
+    }
+}
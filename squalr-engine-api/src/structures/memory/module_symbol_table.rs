@@ -0,0 +1,183 @@
+use crate::structures::memory::address_expression::AddressExpression;
+use crate::structures::memory::module_symbol::ModuleSymbol;
+use serde::{Deserialize, Serialize};
+
+/// The symbols parsed from a module's export and symbol tables, along with the identifiers of its debug information.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleSymbolTable {
+    module_name: String,
+    build_id: Option<String>,
+    debug_link: Option<String>,
+    symbols: Vec<ModuleSymbol>,
+}
+
+impl ModuleSymbolTable {
+    /// The maximum distance past a symbol without a recorded size at which an address is still labeled by that symbol.
+    pub const MAX_UNSIZED_SYMBOL_DISPLACEMENT: u64 = 0x1000;
+
+    /// Creates a symbol table, sorting the symbols by offset and discarding unnamed and duplicate entries.
+    pub fn new(
+        module_name: String,
+        build_id: Option<String>,
+        debug_link: Option<String>,
+        mut symbols: Vec<ModuleSymbol>,
+    ) -> Self {
+        symbols.retain(|symbol| !symbol.get_name().is_empty());
+        symbols.sort_by(|left, right| {
+            left.get_name()
+                .cmp(right.get_name())
+                .then(right.get_size().cmp(&left.get_size()))
+        });
+        symbols.dedup_by(|symbol, previous_symbol| symbol.get_name() == previous_symbol.get_name());
+        symbols.sort_by(|left, right| {
+            left.get_offset()
+                .cmp(&right.get_offset())
+                .then(right.get_size().cmp(&left.get_size()))
+                .then(left.get_name().cmp(right.get_name()))
+        });
+
+        Self {
+            module_name,
+            build_id,
+            debug_link,
+            symbols,
+        }
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    /// Gets the build identifier of the module as a hex string, such as an ELF build-id, Mach-O UUID, or PDB GUID and age.
+    pub fn get_build_id(&self) -> &Option<String> {
+        &self.build_id
+    }
+
+    /// Gets the name of the separate debug information file for the module, such as a `.gnu_debuglink` or PDB path.
+    pub fn get_debug_link(&self) -> &Option<String> {
+        &self.debug_link
+    }
+
+    /// Gets all symbols, ordered by offset.
+    pub fn get_symbols(&self) -> &Vec<ModuleSymbol> {
+        &self.symbols
+    }
+
+    /// Finds a symbol by its exact name.
+    pub fn find_symbol_by_name(
+        &self,
+        symbol_name: &str,
+    ) -> Option<&ModuleSymbol> {
+        self.symbols
+            .iter()
+            .find(|symbol| symbol.get_name() == symbol_name)
+    }
+
+    /// Finds the symbol containing the given module offset, along with the displacement of the offset into that symbol.
+    pub fn find_symbol_by_offset(
+        &self,
+        offset: u64,
+    ) -> Option<(&ModuleSymbol, u64)> {
+        let symbol_index = self
+            .symbols
+            .partition_point(|symbol| symbol.get_offset() <= offset)
+            .checked_sub(1)?;
+        let symbol = &self.symbols[symbol_index];
+        let displacement = offset - symbol.get_offset();
+        let is_within_symbol = if symbol.get_size() > 0 {
+            displacement < symbol.get_size()
+        } else {
+            displacement < Self::MAX_UNSIZED_SYMBOL_DISPLACEMENT
+        };
+
+        if is_within_symbol { Some((symbol, displacement)) } else { None }
+    }
+
+    /// Formats a label for the given module offset, such as `libc.so.6!malloc+0x10`, if a symbol contains it.
+    pub fn format_symbol_label(
+        &self,
+        offset: u64,
+    ) -> Option<String> {
+        let (symbol, displacement) = self.find_symbol_by_offset(offset)?;
+
+        Some(AddressExpression::format_symbol_offset(&self.module_name, symbol.get_name(), displacement))
+    }
+
+    /// Gets the symbols whose names contain the given filter, ignoring case.
+    pub fn filter_symbols(
+        &self,
+        filter: &str,
+    ) -> Vec<ModuleSymbol> {
+        let filter = filter.to_ascii_lowercase();
+
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.get_name().to_ascii_lowercase().contains(&filter))
+            .cloned()
+            .collect()
+    }
+
+    /// Creates a copy of this table that only keeps the given symbols.
+    pub fn with_symbols(
+        &self,
+        symbols: Vec<ModuleSymbol>,
+    ) -> Self {
+        Self {
+            module_name: self.module_name.clone(),
+            build_id: self.build_id.clone(),
+            debug_link: self.debug_link.clone(),
+            symbols,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModuleSymbolTable;
+    use crate::structures::memory::module_symbol::ModuleSymbol;
+
+    fn create_symbol_table() -> ModuleSymbolTable {
+        ModuleSymbolTable::new(
+            "libgame.so".to_string(),
+            Some("ABCD".to_string()),
+            None,
+            vec![
+                ModuleSymbol::new("update_player".to_string(), 0x2000, 0x80),
+                ModuleSymbol::new("init".to_string(), 0x1000, 0x40),
+                ModuleSymbol::new("init".to_string(), 0x1000, 0),
+                ModuleSymbol::new("exported_table".to_string(), 0x4000, 0),
+                ModuleSymbol::new(String::new(), 0x3000, 0x10),
+            ],
+        )
+    }
+
+    #[test]
+    fn new_sorts_by_offset_and_discards_duplicate_and_unnamed_symbols() {
+        let symbol_table = create_symbol_table();
+        let symbol_names = symbol_table
+            .get_symbols()
+            .iter()
+            .map(|symbol| symbol.get_name())
+            .collect::<Vec<_>>();
+
+        assert_eq!(symbol_names, vec!["init", "update_player", "exported_table"]);
+        assert_eq!(
+            symbol_table
+                .find_symbol_by_name("init")
+                .map(|symbol| symbol.get_size()),
+            Some(0x40)
+        );
+    }
+
+    #[test]
+    fn format_symbol_label_respects_symbol_sizes() {
+        let symbol_table = create_symbol_table();
+
+        assert_eq!(symbol_table.format_symbol_label(0x1000), Some("libgame.so!init".to_string()));
+        assert_eq!(symbol_table.format_symbol_label(0x2010), Some("libgame.so!update_player+0x10".to_string()));
+        assert_eq!(symbol_table.format_symbol_label(0x2080), None);
+        assert_eq!(symbol_table.format_symbol_label(0x4FFF), Some("libgame.so!exported_table+0xFFF".to_string()));
+        assert_eq!(symbol_table.format_symbol_label(0x5000), None);
+        assert_eq!(symbol_table.format_symbol_label(0x10), None);
+    }
+}
@@ -1,9 +1,13 @@
 use crate::structures::memory::normalized_region::NormalizedRegion;
+use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct NormalizedModule {
     base_region: NormalizedRegion,
     module_name: String,
+    #[serde(default)]
+    module_path: String,
 }
 
 impl NormalizedModule {
@@ -15,6 +19,7 @@ impl NormalizedModule {
         Self {
             base_region: NormalizedRegion::new(base_address, size),
             module_name: module_name.to_string(),
+            module_path: String::new(),
         }
     }
 
@@ -25,6 +30,7 @@ impl NormalizedModule {
         Self {
             base_region: normalized_region,
             module_name: module_name.to_string(),
+            module_path: String::new(),
         }
     }
 
@@ -36,6 +42,18 @@ impl NormalizedModule {
         &self.module_name
     }
 
+    /// Gets the path of the file backing this module on disk, or an empty string if it is not known.
+    pub fn get_module_path(&self) -> &str {
+        &self.module_path
+    }
+
+    pub fn set_module_path(
+        &mut self,
+        module_path: &str,
+    ) {
+        self.module_path = module_path.to_string();
+    }
+
     pub fn get_base_address(&self) -> u64 {
         self.base_region.get_base_address()
     }
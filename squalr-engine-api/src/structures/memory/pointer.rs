@@ -3,6 +3,7 @@ pub struct Pointer {
     address: u64,
     offsets: Vec<u8>,
     module_name: String,
+    address_expression: String,
 }
 
 impl Pointer {
@@ -11,7 +12,22 @@ impl Pointer {
         offsets: Vec<u8>,
         module_name: String,
     ) -> Self {
-        Self { address, offsets, module_name }
+        Self {
+            address,
+            offsets,
+            module_name,
+            address_expression: String::new(),
+        }
+    }
+
+    /// Creates a pointer that is located by evaluating an address expression, rather than by a fixed module offset.
+    pub fn new_from_address_expression(address_expression: String) -> Self {
+        Self {
+            address: 0,
+            offsets: vec![],
+            module_name: String::new(),
+            address_expression,
+        }
     }
 
     pub fn get_address(&self) -> u64 {
@@ -46,4 +62,9 @@ impl Pointer {
     ) {
         self.module_name = module_name;
     }
+
+    /// Gets the address expression locating this pointer, which is empty for module-relative pointers.
+    pub fn get_address_expression(&self) -> &str {
+        &self.address_expression
+    }
 }
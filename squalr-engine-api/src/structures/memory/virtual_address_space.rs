@@ -0,0 +1,99 @@
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::memory::endian::Endian;
+use serde::{Deserialize, Serialize};
+
+/// Describes a guest address space that has been mapped onto a block of host memory by a memory middleware.
+/// Guest addresses are stable across emulator sessions, whereas the host block may be allocated anywhere on the heap.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VirtualAddressSpace {
+    middleware_name: String,
+    module_name: String,
+    guest_base_address: u64,
+    host_base_address: u64,
+    region_size: u64,
+    endian: Endian,
+    guest_bitness: Bitness,
+}
+
+impl VirtualAddressSpace {
+    pub fn new(
+        middleware_name: String,
+        module_name: String,
+        guest_base_address: u64,
+        host_base_address: u64,
+        region_size: u64,
+        endian: Endian,
+        guest_bitness: Bitness,
+    ) -> Self {
+        Self {
+            middleware_name,
+            module_name,
+            guest_base_address,
+            host_base_address,
+            region_size,
+            endian,
+            guest_bitness,
+        }
+    }
+
+    /// Gets the name of the middleware that produced this address space.
+    pub fn get_middleware_name(&self) -> &str {
+        &self.middleware_name
+    }
+
+    /// Gets the name of the virtual module that this address space is exposed as.
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    pub fn get_guest_base_address(&self) -> u64 {
+        self.guest_base_address
+    }
+
+    pub fn get_host_base_address(&self) -> u64 {
+        self.host_base_address
+    }
+
+    pub fn get_region_size(&self) -> u64 {
+        self.region_size
+    }
+
+    /// Gets the native endianness of the guest system.
+    pub fn get_endian(&self) -> &Endian {
+        &self.endian
+    }
+
+    /// Gets the bitness of the guest system, which determines the size of pointers stored in this address space.
+    pub fn get_guest_bitness(&self) -> Bitness {
+        self.guest_bitness
+    }
+
+    /// Translates a guest address range to the host address backing it, provided the entire range lies within this address space.
+    pub fn guest_to_host(
+        &self,
+        guest_address: u64,
+        length: u64,
+    ) -> Option<u64> {
+        let offset = guest_address.checked_sub(self.guest_base_address)?;
+
+        if offset.checked_add(length)? > self.region_size {
+            return None;
+        }
+
+        self.host_base_address.checked_add(offset)
+    }
+
+    /// Translates a host address to its corresponding guest address, if it lies within this address space.
+    pub fn host_to_guest(
+        &self,
+        host_address: u64,
+    ) -> Option<u64> {
+        let offset = host_address.checked_sub(self.host_base_address)?;
+
+        if offset >= self.region_size {
+            return None;
+        }
+
+        self.guest_base_address.checked_add(offset)
+    }
+}
@@ -1,13 +1,21 @@
+pub mod code_patches;
 pub mod data_types;
 pub mod data_values;
+pub mod disassembly;
+pub mod freezing;
+pub mod hotkeys;
 pub mod logging;
 pub mod memory;
 pub mod processes;
 pub mod projects;
 pub mod results;
+pub mod runtime_classes;
 pub mod scan_results;
 pub mod scanning;
 pub mod settings;
 pub mod snapshots;
+pub mod struct_inference;
 pub mod structs;
 pub mod tasks;
+pub mod value_timelines;
+pub mod watchpoints;
@@ -1,3 +1,4 @@
 pub mod project_item_type_address;
+pub mod project_item_type_code_patch;
 pub mod project_item_type_directory;
 pub mod project_item_type_pointer;
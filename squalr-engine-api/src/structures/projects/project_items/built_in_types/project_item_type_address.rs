@@ -1,5 +1,7 @@
 use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
 use crate::registries::registry_context::RegistryContext;
+use crate::structures::freezing::freeze_mode::FreezeMode;
+use crate::structures::freezing::freeze_policy::FreezePolicy;
 use crate::structures::processes::opened_process_info::OpenedProcessInfo;
 use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
 use crate::structures::structs::symbolic_struct_ref::SymbolicStructRef;
@@ -88,8 +90,16 @@ impl ProjectItemTypeAddress {
     pub const DEFAULT_PROJECT_ITEM_NAME: &str = "New Address";
     pub const PROPERTY_ADDRESS: &str = "address";
     pub const PROPERTY_MODULE: &str = "module";
+    pub const PROPERTY_MODULE_BUILD_ID: &str = "module_build_id";
+    pub const PROPERTY_ADDRESS_EXPRESSION: &str = "address_expression";
     pub const PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE: &str = "symbolic_struct_definition_reference";
     pub const PROPERTY_FREEZE_DISPLAY_VALUE: &str = "freeze_data_value_interpreter";
+    pub const PROPERTY_FREEZE_MODE: &str = "freeze_mode";
+    pub const PROPERTY_FREEZE_MINIMUM: &str = "freeze_minimum";
+    pub const PROPERTY_FREEZE_MAXIMUM: &str = "freeze_maximum";
+    pub const PROPERTY_FREEZE_STEP: &str = "freeze_step";
+    pub const PROPERTY_FREEZE_INTERVAL_MS: &str = "freeze_interval_ms";
+    pub const PROPERTY_FREEZE_LOW_LATENCY: &str = "freeze_low_latency";
 
     pub fn new_project_item(
         name: &str,
@@ -104,9 +114,12 @@ impl ProjectItemTypeAddress {
 
         project_item.set_field_description(description);
         Self::set_field_module(&mut project_item, module);
+        Self::set_field_module_build_id(&mut project_item, "");
         Self::set_field_address(&mut project_item, address);
+        Self::set_field_address_expression(&mut project_item, "");
         // Default to unknown until project-item refresh logic reads live memory.
         Self::set_field_freeze_data_value_interpreter(&mut project_item, "");
+        Self::set_field_freeze_policy(&mut project_item, &FreezePolicy::default());
         Self::set_field_symbolic_struct_definition_reference(&mut project_item, freeze_value.get_data_type_id());
 
         project_item
@@ -162,6 +175,40 @@ impl ProjectItemTypeAddress {
             .set_field_data(Self::PROPERTY_MODULE, field_data, false);
     }
 
+    /// Gets the build identity of the module that the address was recorded against, or an empty string if it is unknown.
+    pub fn get_field_module_build_id(project_item: &ProjectItem) -> String {
+        Self::read_string_field(project_item, Self::PROPERTY_MODULE_BUILD_ID)
+    }
+
+    pub fn set_field_module_build_id(
+        project_item: &mut ProjectItem,
+        module_build_id: &str,
+    ) {
+        let module_build_id_data_value = DataTypeStringUtf8::get_value_from_primitive_string(module_build_id);
+        let field_data = ValuedStructFieldData::Value(module_build_id_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_data(Self::PROPERTY_MODULE_BUILD_ID, field_data, false);
+    }
+
+    /// Gets the address expression for this item. When non-empty, this takes precedence over the address and module fields.
+    pub fn get_field_address_expression(project_item: &mut ProjectItem) -> String {
+        Self::read_string_field(project_item, Self::PROPERTY_ADDRESS_EXPRESSION)
+    }
+
+    pub fn set_field_address_expression(
+        project_item: &mut ProjectItem,
+        address_expression: &str,
+    ) {
+        let address_expression_data_value = DataTypeStringUtf8::get_value_from_primitive_string(address_expression);
+        let field_data = ValuedStructFieldData::Value(address_expression_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_data(Self::PROPERTY_ADDRESS_EXPRESSION, field_data, false);
+    }
+
     pub fn get_field_freeze_data_value_interpreter(project_item: &mut ProjectItem) -> String {
         Self::read_string_field(project_item, Self::PROPERTY_FREEZE_DISPLAY_VALUE)
     }
@@ -178,6 +225,49 @@ impl ProjectItemTypeAddress {
             .set_field_data(Self::PROPERTY_FREEZE_DISPLAY_VALUE, field_data, true);
     }
 
+    /// Gets the policy applied when this item is frozen. Items saved before freeze policies existed freeze to a constant value.
+    pub fn get_field_freeze_policy(project_item: &ProjectItem) -> FreezePolicy {
+        let freeze_mode = Self::read_string_field(project_item, Self::PROPERTY_FREEZE_MODE);
+        let read_optional_string_field = |field_name: &str| Some(Self::read_string_field(project_item, field_name)).filter(|value| !value.trim().is_empty());
+
+        FreezePolicy {
+            freeze_mode: freeze_mode.parse::<FreezeMode>().unwrap_or_default(),
+            freeze_minimum: read_optional_string_field(Self::PROPERTY_FREEZE_MINIMUM),
+            freeze_maximum: read_optional_string_field(Self::PROPERTY_FREEZE_MAXIMUM),
+            freeze_step: read_optional_string_field(Self::PROPERTY_FREEZE_STEP),
+            freeze_interval_ms: read_optional_string_field(Self::PROPERTY_FREEZE_INTERVAL_MS).and_then(|value| value.trim().parse::<u64>().ok()),
+            freeze_low_latency: Self::read_string_field(project_item, Self::PROPERTY_FREEZE_LOW_LATENCY) == "true",
+        }
+    }
+
+    pub fn set_field_freeze_policy(
+        project_item: &mut ProjectItem,
+        freeze_policy: &FreezePolicy,
+    ) {
+        let freeze_policy_fields = [
+            (Self::PROPERTY_FREEZE_MODE, freeze_policy.freeze_mode.to_string()),
+            (Self::PROPERTY_FREEZE_MINIMUM, freeze_policy.freeze_minimum.clone().unwrap_or_default()),
+            (Self::PROPERTY_FREEZE_MAXIMUM, freeze_policy.freeze_maximum.clone().unwrap_or_default()),
+            (Self::PROPERTY_FREEZE_STEP, freeze_policy.freeze_step.clone().unwrap_or_default()),
+            (
+                Self::PROPERTY_FREEZE_INTERVAL_MS,
+                freeze_policy
+                    .freeze_interval_ms
+                    .map(|freeze_interval_ms| freeze_interval_ms.to_string())
+                    .unwrap_or_default(),
+            ),
+            (Self::PROPERTY_FREEZE_LOW_LATENCY, freeze_policy.freeze_low_latency.to_string()),
+        ];
+
+        for (field_name, field_value) in freeze_policy_fields {
+            let field_data = ValuedStructFieldData::Value(DataTypeStringUtf8::get_value_from_primitive_string(&field_value));
+
+            project_item
+                .get_properties_mut()
+                .set_field_data(field_name, field_data, false);
+        }
+    }
+
     pub fn get_field_symbolic_struct_definition_reference(project_item: &mut ProjectItem) -> Option<SymbolicStructRef> {
         let symbolic_struct_definition_reference = Self::read_string_field(project_item, Self::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE);
 
@@ -221,6 +311,7 @@ impl ProjectItemTypeAddress {
 mod tests {
     use super::ProjectItemTypeAddress;
     use crate::structures::data_types::built_in_types::{u8::data_type_u8::DataTypeU8, u32::data_type_u32::DataTypeU32};
+    use crate::structures::freezing::{freeze_mode::FreezeMode, freeze_policy::FreezePolicy};
     use crate::structures::structs::valued_struct_field::ValuedStructFieldData;
 
     #[test]
@@ -244,6 +335,25 @@ mod tests {
         assert_eq!(ProjectItemTypeAddress::get_field_freeze_data_value_interpreter(&mut project_item), "");
     }
 
+    #[test]
+    fn freeze_policy_round_trips_through_project_item_fields() {
+        let mut project_item = ProjectItemTypeAddress::new_project_item("Health", 0x1234, "module", "", DataTypeU8::get_value_from_primitive(7));
+        let freeze_policy = FreezePolicy {
+            freeze_mode: FreezeMode::Clamp,
+            freeze_minimum: Some("10".to_string()),
+            freeze_maximum: None,
+            freeze_step: None,
+            freeze_interval_ms: Some(16),
+            freeze_low_latency: true,
+        };
+
+        assert_eq!(ProjectItemTypeAddress::get_field_freeze_policy(&project_item), FreezePolicy::default());
+
+        ProjectItemTypeAddress::set_field_freeze_policy(&mut project_item, &freeze_policy);
+
+        assert_eq!(ProjectItemTypeAddress::get_field_freeze_policy(&project_item), freeze_policy);
+    }
+
     #[test]
     fn get_field_address_reads_u32_bytes() {
         let mut project_item = ProjectItemTypeAddress::new_project_item("Health", 0, "module", "", DataTypeU8::get_value_from_primitive(7));
@@ -0,0 +1,190 @@
+use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
+use crate::registries::registry_context::RegistryContext;
+use crate::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+use crate::structures::processes::opened_process_info::OpenedProcessInfo;
+use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use crate::structures::{
+    data_types::built_in_types::{string::utf8::data_type_string_utf8::DataTypeStringUtf8, u64::data_type_u64::DataTypeU64},
+    projects::project_items::{project_item::ProjectItem, project_item_type::ProjectItemType, project_item_type_ref::ProjectItemTypeRef},
+    structs::valued_struct_field::ValuedStructFieldData,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+
+/// A project item that replaces code at a module-relative address while activated, restoring the original bytes when deactivated.
+#[derive(Serialize, Deserialize)]
+pub struct ProjectItemTypeCodePatch {}
+
+impl ProjectItemType for ProjectItemTypeCodePatch {
+    fn get_project_item_type_id(&self) -> &str {
+        Self::PROJECT_ITEM_TYPE_ID
+    }
+
+    fn on_activated_changed(
+        &self,
+        _engine_bindings: &Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
+        _registry_context: &dyn RegistryContext,
+        _project_item_ref: &ProjectItemRef,
+    ) {
+    }
+
+    fn tick(
+        &self,
+        _engine_bindings: &dyn EngineApiPrivilegedBindings,
+        _opened_process: &Option<OpenedProcessInfo>,
+        _registry_context: &dyn RegistryContext,
+        _project_item_ref: &ProjectItemRef,
+    ) {
+    }
+}
+
+impl ProjectItemTypeCodePatch {
+    pub const PROJECT_ITEM_TYPE_ID: &str = "code_patch";
+    pub const DEFAULT_PROJECT_ITEM_NAME: &str = "New Code Patch";
+    pub const PROPERTY_ADDRESS: &str = "address";
+    pub const PROPERTY_MODULE: &str = "module";
+    pub const PROPERTY_MODULE_BUILD_ID: &str = "module_build_id";
+    pub const PROPERTY_ORIGINAL_BYTES: &str = "original_bytes";
+    pub const PROPERTY_REPLACEMENT_KIND: &str = "replacement_kind";
+    pub const PROPERTY_REPLACEMENT_VALUE: &str = "replacement_value";
+
+    pub fn new_project_item(
+        name: &str,
+        address: u64,
+        module: &str,
+        description: &str,
+        original_bytes: &[u8],
+        replacement: &CodePatchReplacement,
+    ) -> ProjectItem {
+        let project_item_type_ref = ProjectItemTypeRef::new(Self::PROJECT_ITEM_TYPE_ID.to_string());
+        let project_item_name = if name.trim().is_empty() { Self::DEFAULT_PROJECT_ITEM_NAME } else { name };
+        let mut project_item = ProjectItem::new(project_item_type_ref, project_item_name);
+
+        project_item.set_field_description(description);
+        Self::set_field_module(&mut project_item, module);
+        Self::set_field_module_build_id(&mut project_item, "");
+        Self::set_field_address(&mut project_item, address);
+        Self::set_field_original_bytes(&mut project_item, original_bytes);
+        Self::set_field_replacement(&mut project_item, replacement);
+
+        project_item
+    }
+
+    pub fn get_field_address(project_item: &ProjectItem) -> u64 {
+        project_item
+            .get_properties()
+            .get_field(Self::PROPERTY_ADDRESS)
+            .and_then(|field| field.get_data_value())
+            .and_then(|data_value| data_value.get_value_bytes().as_slice().try_into().ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or(0)
+    }
+
+    pub fn set_field_address(
+        project_item: &mut ProjectItem,
+        address: u64,
+    ) {
+        let field_data = ValuedStructFieldData::Value(DataTypeU64::get_value_from_primitive(address));
+
+        project_item
+            .get_properties_mut()
+            .set_field_data(Self::PROPERTY_ADDRESS, field_data, false);
+    }
+
+    pub fn get_field_module(project_item: &ProjectItem) -> String {
+        Self::read_string_field(project_item, Self::PROPERTY_MODULE)
+    }
+
+    pub fn set_field_module(
+        project_item: &mut ProjectItem,
+        module: &str,
+    ) {
+        Self::write_string_field(project_item, Self::PROPERTY_MODULE, module);
+    }
+
+    /// Gets the build identity of the module that the patch was recorded against, or an empty string if it is unknown.
+    pub fn get_field_module_build_id(project_item: &ProjectItem) -> String {
+        Self::read_string_field(project_item, Self::PROPERTY_MODULE_BUILD_ID)
+    }
+
+    pub fn set_field_module_build_id(
+        project_item: &mut ProjectItem,
+        module_build_id: &str,
+    ) {
+        Self::write_string_field(project_item, Self::PROPERTY_MODULE_BUILD_ID, module_build_id);
+    }
+
+    /// Gets the bytes that must be present at the address before the patch is applied, and that are restored afterwards.
+    pub fn get_field_original_bytes(project_item: &ProjectItem) -> Vec<u8> {
+        CodePatchReplacement::parse_bytes(&Self::read_string_field(project_item, Self::PROPERTY_ORIGINAL_BYTES)).unwrap_or_default()
+    }
+
+    pub fn set_field_original_bytes(
+        project_item: &mut ProjectItem,
+        original_bytes: &[u8],
+    ) {
+        Self::write_string_field(project_item, Self::PROPERTY_ORIGINAL_BYTES, &CodePatchReplacement::format_bytes(original_bytes));
+    }
+
+    /// Gets the code written over the original bytes, or `None` if the stored replacement is malformed.
+    pub fn get_field_replacement(project_item: &ProjectItem) -> Option<CodePatchReplacement> {
+        let replacement_kind = Self::read_string_field(project_item, Self::PROPERTY_REPLACEMENT_KIND);
+        let replacement_value = Self::read_string_field(project_item, Self::PROPERTY_REPLACEMENT_VALUE);
+
+        CodePatchReplacement::from_kind_and_value(&replacement_kind, &replacement_value).ok()
+    }
+
+    pub fn set_field_replacement(
+        project_item: &mut ProjectItem,
+        replacement: &CodePatchReplacement,
+    ) {
+        Self::write_string_field(project_item, Self::PROPERTY_REPLACEMENT_KIND, replacement.get_kind());
+        Self::write_string_field(project_item, Self::PROPERTY_REPLACEMENT_VALUE, &replacement.get_value());
+    }
+
+    fn read_string_field(
+        project_item: &ProjectItem,
+        field_name: &str,
+    ) -> String {
+        let data_value = match project_item
+            .get_properties()
+            .get_field(field_name)
+            .and_then(|field| field.get_data_value())
+        {
+            Some(data_value) => data_value,
+            None => return String::new(),
+        };
+
+        String::from_utf8(data_value.get_value_bytes().clone()).unwrap_or_default()
+    }
+
+    fn write_string_field(
+        project_item: &mut ProjectItem,
+        field_name: &str,
+        value: &str,
+    ) {
+        let field_data = ValuedStructFieldData::Value(DataTypeStringUtf8::get_value_from_primitive_string(value));
+
+        project_item
+            .get_properties_mut()
+            .set_field_data(field_name, field_data, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectItemTypeCodePatch;
+    use crate::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+
+    #[test]
+    fn new_project_item_round_trips_patch_fields() {
+        let replacement = CodePatchReplacement::Assembly("jmp 0x401020".to_string());
+        let project_item = ProjectItemTypeCodePatch::new_project_item("", 0x1234, "game.exe", "", &[0x74, 0x05], &replacement);
+
+        assert_eq!(project_item.get_field_name(), ProjectItemTypeCodePatch::DEFAULT_PROJECT_ITEM_NAME);
+        assert_eq!(ProjectItemTypeCodePatch::get_field_address(&project_item), 0x1234);
+        assert_eq!(ProjectItemTypeCodePatch::get_field_module(&project_item), "game.exe");
+        assert_eq!(ProjectItemTypeCodePatch::get_field_original_bytes(&project_item), vec![0x74, 0x05]);
+        assert_eq!(ProjectItemTypeCodePatch::get_field_replacement(&project_item), Some(replacement));
+    }
+}
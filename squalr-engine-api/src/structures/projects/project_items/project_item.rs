@@ -1,5 +1,6 @@
 use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
 use crate::registries::registry_context::RegistryContext;
+use crate::structures::hotkeys::hotkey_binding::HotkeyBinding;
 use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
 use crate::structures::{
     data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8,
@@ -34,6 +35,7 @@ impl ProjectItem {
     pub const PROPERTY_NAME: &str = "name";
     pub const PROPERTY_ICON_ID: &str = "icon_id";
     pub const PROPERTY_DESCRIPTION: &str = "description";
+    pub const PROPERTY_HOTKEYS: &str = "hotkeys";
 
     pub fn new(
         item_type: ProjectItemTypeRef,
@@ -148,6 +150,37 @@ impl ProjectItem {
             .set_field_data(Self::PROPERTY_DESCRIPTION, field_data, false);
     }
 
+    /// Gets the hotkeys bound to this item. Malformed bindings, for example from a hand-edited project file, are skipped.
+    pub fn get_field_hotkey_bindings(&self) -> Vec<HotkeyBinding> {
+        Self::read_string_field(self, Self::PROPERTY_HOTKEYS)
+            .split(HotkeyBinding::LIST_SEPARATOR)
+            .filter(|hotkey_binding| !hotkey_binding.trim().is_empty())
+            .filter_map(|hotkey_binding| match hotkey_binding.parse::<HotkeyBinding>() {
+                Ok(hotkey_binding) => Some(hotkey_binding),
+                Err(error) => {
+                    log::warn!("Skipping hotkey on project item '{}': {}", self.get_field_name(), error);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn set_field_hotkey_bindings(
+        &mut self,
+        hotkey_bindings: &[HotkeyBinding],
+    ) {
+        let hotkey_bindings = hotkey_bindings
+            .iter()
+            .map(|hotkey_binding| hotkey_binding.to_string())
+            .collect::<Vec<_>>()
+            .join(&HotkeyBinding::LIST_SEPARATOR.to_string());
+        let hotkey_bindings_data_value = DataTypeStringUtf8::get_value_from_primitive_string(&hotkey_bindings);
+        let field_data = ValuedStructFieldData::Value(hotkey_bindings_data_value);
+
+        self.get_properties_mut()
+            .set_field_data(Self::PROPERTY_HOTKEYS, field_data, false);
+    }
+
     fn read_string_field(
         project_item: &ProjectItem,
         field_name: &str,
@@ -1,3 +1,4 @@
+use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -5,11 +6,21 @@ use std::path::PathBuf;
 pub struct ProjectManifest {
     #[serde(rename = "sort_order")]
     project_item_sort_order: Vec<PathBuf>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auto_attach_process_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    struct_definitions: Vec<SymbolicStructDefinition>,
 }
 
 impl ProjectManifest {
     pub fn new(project_item_sort_order: Vec<PathBuf>) -> Self {
-        Self { project_item_sort_order }
+        Self {
+            project_item_sort_order,
+            auto_attach_process_name: None,
+            struct_definitions: Vec::new(),
+        }
     }
 
     pub fn get_project_item_sort_order(&self) -> &Vec<PathBuf> {
@@ -22,4 +33,36 @@ impl ProjectManifest {
     ) {
         self.project_item_sort_order = project_item_sort_order;
     }
+
+    /// Gets the executable that should be automatically attached to while this project is open, if any.
+    pub fn get_auto_attach_process_name(&self) -> Option<&str> {
+        self.auto_attach_process_name.as_deref()
+    }
+
+    pub fn set_auto_attach_process_name(
+        &mut self,
+        auto_attach_process_name: Option<String>,
+    ) {
+        self.auto_attach_process_name = auto_attach_process_name;
+    }
+
+    /// Gets the struct definitions that were accepted into this project, such as drafts proposed by struct inference.
+    pub fn get_struct_definitions(&self) -> &Vec<SymbolicStructDefinition> {
+        &self.struct_definitions
+    }
+
+    /// Adds a struct definition to this project, replacing any existing definition with the same namespace.
+    pub fn set_struct_definition(
+        &mut self,
+        struct_definition: SymbolicStructDefinition,
+    ) {
+        match self
+            .struct_definitions
+            .iter_mut()
+            .find(|existing_struct_definition| existing_struct_definition.get_symbol_namespace() == struct_definition.get_symbol_namespace())
+        {
+            Some(existing_struct_definition) => *existing_struct_definition = struct_definition,
+            None => self.struct_definitions.push(struct_definition),
+        }
+    }
 }
@@ -0,0 +1,2 @@
+pub mod runtime_class;
+pub mod runtime_class_abi;
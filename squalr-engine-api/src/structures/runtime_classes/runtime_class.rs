@@ -0,0 +1,46 @@
+use crate::structures::runtime_classes::runtime_class_abi::RuntimeClassAbi;
+use serde::{Deserialize, Serialize};
+
+/// A C++ class recovered from run-time type information, identified by the vtable that its objects point to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimeClass {
+    class_name: String,
+    module_name: String,
+    vtable_address: u64,
+    abi: RuntimeClassAbi,
+}
+
+impl RuntimeClass {
+    pub fn new(
+        class_name: String,
+        module_name: String,
+        vtable_address: u64,
+        abi: RuntimeClassAbi,
+    ) -> Self {
+        Self {
+            class_name,
+            module_name,
+            vtable_address,
+            abi,
+        }
+    }
+
+    /// Gets the demangled class name, or the mangled name if it could not be demangled.
+    pub fn get_class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// Gets the name of the module containing the vtable of this class.
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    /// Gets the address of the primary vtable of this class, which is the first pointer of every instance of it.
+    pub fn get_vtable_address(&self) -> u64 {
+        self.vtable_address
+    }
+
+    pub fn get_abi(&self) -> RuntimeClassAbi {
+        self.abi
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The C++ ABI whose run-time type information describes a class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RuntimeClassAbi {
+    /// The Itanium ABI used by GCC and Clang, where the vtable is preceded by the offset-to-top and a `type_info` pointer.
+    Itanium,
+    /// The MSVC ABI, where the vftable is preceded by a pointer to an `RTTICompleteObjectLocator`.
+    Msvc,
+}
+
+impl RuntimeClassAbi {
+    const MAX_NAME_DEPTH: usize = 32;
+
+    /// Demangles an Itanium `type_info` name such as `N4game6PlayerE` into `game::Player`. Template arguments are not
+    /// supported, and are rejected such that callers can fall back to the mangled name.
+    pub fn demangle_itanium_type_name(mangled_name: &str) -> Option<String> {
+        let (nested_name, is_nested) = match mangled_name.strip_prefix('N') {
+            Some(nested_name) => (nested_name.strip_suffix('E')?, true),
+            None => (mangled_name, false),
+        };
+        let mut remaining_name = nested_name;
+        let mut name_components = Vec::new();
+
+        while !remaining_name.is_empty() && name_components.len() < Self::MAX_NAME_DEPTH {
+            let length_digit_count = remaining_name.bytes().take_while(u8::is_ascii_digit).count();
+            let component_length = remaining_name[..length_digit_count].parse::<usize>().ok()?;
+            let component = remaining_name.get(length_digit_count..length_digit_count + component_length)?;
+
+            if component.is_empty()
+                || !component
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+            {
+                return None;
+            }
+
+            name_components.push(component);
+            remaining_name = &remaining_name[length_digit_count + component_length..];
+        }
+
+        if !remaining_name.is_empty() || name_components.is_empty() || (!is_nested && name_components.len() > 1) {
+            return None;
+        }
+
+        Some(name_components.join("::"))
+    }
+
+    /// Demangles an MSVC type descriptor name such as `.?AVPlayer@game@@` into `game::Player`. Template names are not
+    /// supported, and are rejected such that callers can fall back to the decorated name.
+    pub fn demangle_msvc_type_name(decorated_name: &str) -> Option<String> {
+        let qualified_name = decorated_name
+            .strip_prefix(".?AV")
+            .or_else(|| decorated_name.strip_prefix(".?AU"))?
+            .strip_suffix("@@")?;
+        let name_components = qualified_name.split('@').rev().collect::<Vec<_>>();
+
+        if name_components.is_empty()
+            || name_components.len() > Self::MAX_NAME_DEPTH
+            || name_components.iter().any(|name_component| {
+                name_component.is_empty()
+                    || !name_component
+                        .bytes()
+                        .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+            })
+        {
+            return None;
+        }
+
+        Some(name_components.join("::"))
+    }
+}
+
+impl fmt::Display for RuntimeClassAbi {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            RuntimeClassAbi::Itanium => write!(formatter, "itanium"),
+            RuntimeClassAbi::Msvc => write!(formatter, "msvc"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuntimeClassAbi;
+
+    #[test]
+    fn demangle_itanium_type_name_supports_plain_and_nested_names() {
+        assert_eq!(RuntimeClassAbi::demangle_itanium_type_name("6Player").as_deref(), Some("Player"));
+        assert_eq!(
+            RuntimeClassAbi::demangle_itanium_type_name("N4game6actors6PlayerE").as_deref(),
+            Some("game::actors::Player")
+        );
+        assert!(RuntimeClassAbi::demangle_itanium_type_name("N4game6VectorIiEE").is_none());
+        assert!(RuntimeClassAbi::demangle_itanium_type_name("7Player").is_none());
+        assert!(RuntimeClassAbi::demangle_itanium_type_name("").is_none());
+    }
+
+    #[test]
+    fn demangle_msvc_type_name_reverses_scopes() {
+        assert_eq!(RuntimeClassAbi::demangle_msvc_type_name(".?AVPlayer@@").as_deref(), Some("Player"));
+        assert_eq!(
+            RuntimeClassAbi::demangle_msvc_type_name(".?AUPlayer@actors@game@@").as_deref(),
+            Some("game::actors::Player")
+        );
+        assert!(RuntimeClassAbi::demangle_msvc_type_name(".?AV?$vector@H@std@@").is_none());
+        assert!(RuntimeClassAbi::demangle_msvc_type_name("Player").is_none());
+    }
+}
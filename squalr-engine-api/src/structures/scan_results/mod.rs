@@ -2,4 +2,10 @@ pub mod scan_result;
 pub mod scan_result_base;
 pub mod scan_result_ref;
 pub mod scan_result_valued;
+pub mod scan_results_export_error;
+pub mod scan_results_export_format;
+pub mod scan_results_export_record;
 pub mod scan_results_metadata;
+pub mod scan_results_query_cache;
+pub mod scan_results_query_options;
+pub mod scan_results_sort_key;
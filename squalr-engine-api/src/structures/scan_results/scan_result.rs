@@ -16,6 +16,10 @@ pub struct ScanResult {
     valued_result: ScanResultValued,
     module: String,
     module_offset: u64,
+    #[serde(default)]
+    symbol_label: String,
+    #[serde(default)]
+    class_name: String,
     recently_read_value: Option<DataValue>,
     recently_read_display_values: Vec<AnonymousValueString>,
     is_frozen: bool,
@@ -27,6 +31,7 @@ impl ScanResult {
     pub const PROPERTY_NAME_ADDRESS: &str = "address";
     pub const PROPERTY_NAME_MODULE: &str = "module";
     pub const PROPERTY_NAME_MODULE_OFFSET: &str = "module_offset";
+    pub const PROPERTY_NAME_CLASS: &str = "class";
 
     pub fn new(
         valued_result: ScanResultValued,
@@ -40,6 +45,8 @@ impl ScanResult {
             valued_result,
             module,
             module_offset,
+            symbol_label: String::new(),
+            class_name: String::new(),
             recently_read_value,
             recently_read_display_values,
             is_frozen,
@@ -67,13 +74,23 @@ impl ScanResult {
         let field_module_offset =
             DataTypeU64::get_value_from_primitive(self.module_offset).to_named_valued_struct_field(Self::PROPERTY_NAME_MODULE_OFFSET.to_string(), true);
 
-        ValuedStruct::new_anonymous(vec![
+        let mut fields = vec![
             field_value,
             field_is_frozen,
             field_address,
             field_module,
             field_module_offset,
-        ])
+        ];
+
+        // Objects whose vtable belongs to a discovered class are hinted with their class name.
+        if !self.class_name.is_empty() {
+            fields.push(
+                DataTypeStringUtf8::get_value_from_primitive_array(self.class_name.as_bytes().to_vec())
+                    .to_named_valued_struct_field(Self::PROPERTY_NAME_CLASS.to_string(), true),
+            );
+        }
+
+        ValuedStruct::new_anonymous(fields)
     }
 
     pub fn get_valued_result(&self) -> &ScanResultValued {
@@ -108,6 +125,30 @@ impl ScanResult {
         self.module_offset
     }
 
+    /// Gets the `module!symbol+offset` label of this result, or an empty string if no module symbol covers it.
+    pub fn get_symbol_label(&self) -> &str {
+        &self.symbol_label
+    }
+
+    pub fn set_symbol_label(
+        &mut self,
+        symbol_label: String,
+    ) {
+        self.symbol_label = symbol_label;
+    }
+
+    /// Gets the class name of the object at this result, or an empty string if it does not point to a known vtable.
+    pub fn get_class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    pub fn set_class_name(
+        &mut self,
+        class_name: String,
+    ) {
+        self.class_name = class_name;
+    }
+
     pub fn get_recently_read_value(&self) -> &Option<DataValue> {
         &self.recently_read_value
     }
@@ -267,6 +308,27 @@ mod tests {
         assert!(module_offset_field.get_is_read_only());
     }
 
+    #[test]
+    fn as_valued_struct_includes_read_only_class_field_only_when_class_is_known() {
+        let mut scan_result = create_scan_result();
+
+        assert!(
+            scan_result
+                .as_valued_struct()
+                .get_field(ScanResult::PROPERTY_NAME_CLASS)
+                .is_none()
+        );
+
+        scan_result.set_class_name(String::from("game::Player"));
+
+        let valued_struct = scan_result.as_valued_struct();
+        let class_field = valued_struct
+            .get_field(ScanResult::PROPERTY_NAME_CLASS)
+            .expect("Expected class field.");
+
+        assert!(class_field.get_is_read_only());
+    }
+
     #[test]
     fn as_valued_struct_prefers_recently_read_value_for_value_field() {
         let scan_result_valued = ScanResultValued::new(
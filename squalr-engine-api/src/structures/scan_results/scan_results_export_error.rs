@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ScanResultsExportError {
+    #[error("Unable to infer a scan results format for '{file_path}'. Use a .csv or .json file, or specify the format.")]
+    UnknownFormat { file_path: String },
+    #[error("Failed to access scan results file '{file_path}': {reason}.")]
+    Io { file_path: String, reason: String },
+    #[error("Malformed scan results CSV at line {line_number}: {reason}.")]
+    MalformedCsvRow { line_number: usize, reason: String },
+    #[error("Malformed scan results JSON: {reason}.")]
+    MalformedJson { reason: String },
+}
+
+impl ScanResultsExportError {
+    pub fn unknown_format(file_path: impl Into<String>) -> Self {
+        Self::UnknownFormat { file_path: file_path.into() }
+    }
+
+    pub fn io(
+        file_path: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::Io {
+            file_path: file_path.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn malformed_csv_row(
+        line_number: usize,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::MalformedCsvRow {
+            line_number,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn malformed_json(reason: impl Into<String>) -> Self {
+        Self::MalformedJson { reason: reason.into() }
+    }
+}
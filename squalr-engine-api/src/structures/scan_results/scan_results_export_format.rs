@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The file format used when exporting or importing scan results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScanResultsExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ScanResultsExportFormat {
+    /// Infers the format from the extension of the given file path.
+    pub fn from_file_path(file_path: &Path) -> Option<Self> {
+        file_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| Self::from_str(extension).ok())
+    }
+}
+
+impl FromStr for ScanResultsExportFormat {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "csv" => Ok(ScanResultsExportFormat::Csv),
+            "json" => Ok(ScanResultsExportFormat::Json),
+            _ => Err(format!("Invalid scan results export format: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for ScanResultsExportFormat {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ScanResultsExportFormat::Csv => write!(formatter, "csv"),
+            ScanResultsExportFormat::Json => write!(formatter, "json"),
+        }
+    }
+}
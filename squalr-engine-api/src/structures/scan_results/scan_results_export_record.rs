@@ -0,0 +1,115 @@
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::scan_results::scan_results_export_error::ScanResultsExportError;
+use serde::{Deserialize, Serialize};
+
+/// A single scan result as written to, or read from, an exported scan results file.
+/// Module relative addresses are preferred on import, such that results survive the process being restarted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanResultsExportRecord {
+    pub address: u64,
+    #[serde(default)]
+    pub module: String,
+    #[serde(default)]
+    pub module_offset: u64,
+    pub data_type_id: String,
+    #[serde(default)]
+    pub current_value: Option<String>,
+    #[serde(default)]
+    pub previous_value: Option<String>,
+}
+
+impl ScanResultsExportRecord {
+    pub const CSV_HEADER: &'static str = "address,module,module_offset,data_type,current_value,previous_value";
+    const CSV_COLUMN_COUNT: usize = 6;
+
+    pub fn to_csv_row(&self) -> String {
+        [
+            format!("0x{:X}", self.address),
+            Self::escape_csv_field(&self.module),
+            format!("0x{:X}", self.module_offset),
+            Self::escape_csv_field(&self.data_type_id),
+            Self::escape_csv_field(self.current_value.as_deref().unwrap_or_default()),
+            Self::escape_csv_field(self.previous_value.as_deref().unwrap_or_default()),
+        ]
+        .join(",")
+    }
+
+    /// Parses a CSV row in the format described by `CSV_HEADER`. Only the address and data type columns are required.
+    pub fn from_csv_row(
+        row: &str,
+        line_number: usize,
+    ) -> Result<Self, ScanResultsExportError> {
+        let fields = Self::split_csv_fields(row).ok_or_else(|| ScanResultsExportError::malformed_csv_row(line_number, "unterminated quoted field"))?;
+
+        if fields.len() < 4 || fields.len() > Self::CSV_COLUMN_COUNT {
+            return Err(ScanResultsExportError::malformed_csv_row(
+                line_number,
+                format!("expected between 4 and {} columns, found {}", Self::CSV_COLUMN_COUNT, fields.len()),
+            ));
+        }
+
+        let parse_address = |field: &str, column_name: &str| {
+            Conversions::parse_hex_or_int(field.trim())
+                .map_err(|error| ScanResultsExportError::malformed_csv_row(line_number, format!("invalid {} '{}': {}", column_name, field, error)))
+        };
+        let optional_field = |index: usize| fields.get(index).filter(|field| !field.is_empty()).cloned();
+        let module = fields[1].trim().to_string();
+        let module_offset = if module.is_empty() && fields[2].trim().is_empty() {
+            0
+        } else {
+            parse_address(&fields[2], "module offset")?
+        };
+        let data_type_id = fields[3].trim().to_string();
+
+        if data_type_id.is_empty() {
+            return Err(ScanResultsExportError::malformed_csv_row(line_number, "missing data type"));
+        }
+
+        Ok(Self {
+            address: parse_address(&fields[0], "address")?,
+            module,
+            module_offset,
+            data_type_id,
+            current_value: optional_field(4),
+            previous_value: optional_field(5),
+        })
+    }
+
+    /// Quotes a field if it contains a delimiter, quote or line break (ie array values such as `1, 2, 3`).
+    fn escape_csv_field(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    /// Splits a CSV row into fields, unescaping quoted fields. Returns `None` if a quoted field is never closed.
+    fn split_csv_fields(row: &str) -> Option<Vec<String>> {
+        let mut fields = vec![];
+        let mut field = String::new();
+        let mut is_quoted = false;
+        let mut characters = row.trim_end_matches(['\r', '\n']).chars().peekable();
+
+        while let Some(character) = characters.next() {
+            match (character, is_quoted) {
+                ('"', true) if characters.peek() == Some(&'"') => {
+                    field.push('"');
+                    characters.next();
+                }
+                ('"', true) => is_quoted = false,
+                ('"', false) if field.is_empty() => is_quoted = true,
+                (',', false) => fields.push(std::mem::take(&mut field)),
+                _ => field.push(character),
+            }
+        }
+
+        if is_quoted {
+            return None;
+        }
+
+        fields.push(field);
+
+        Some(fields)
+    }
+}
@@ -0,0 +1,117 @@
+use crate::structures::memory::normalized_module::NormalizedModule;
+use crate::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// A query evaluated against a specific snapshot generation and module list.
+struct CachedScanResultsQuery {
+    snapshot_generation: u64,
+    query_options: ScanResultsQueryOptions,
+    modules_hash: u64,
+    scan_result_indices: Arc<Vec<u64>>,
+}
+
+/// Remembers the scan result indices matching the most recent query, such that paging through the results of a sorted or filtered
+/// query does not evaluate every scan result again.
+#[derive(Default)]
+pub struct ScanResultsQueryCache {
+    cached_query: Option<CachedScanResultsQuery>,
+}
+
+impl ScanResultsQueryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hashes a module list by the name and bounds of each module, such that queries are keyed by modules without storing them.
+    pub fn hash_modules(modules: &[NormalizedModule]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        modules.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Gets the cached indices if they were evaluated for the same snapshot generation, query and module list hash.
+    pub fn get(
+        &self,
+        snapshot_generation: u64,
+        query_options: &ScanResultsQueryOptions,
+        modules_hash: u64,
+    ) -> Option<Arc<Vec<u64>>> {
+        self.cached_query
+            .as_ref()
+            .filter(|cached_query| {
+                cached_query.snapshot_generation == snapshot_generation
+                    && cached_query.modules_hash == modules_hash
+                    && cached_query.query_options == *query_options
+            })
+            .map(|cached_query| cached_query.scan_result_indices.clone())
+    }
+
+    /// Replaces the cached query.
+    pub fn set(
+        &mut self,
+        snapshot_generation: u64,
+        query_options: ScanResultsQueryOptions,
+        modules_hash: u64,
+        scan_result_indices: Arc<Vec<u64>>,
+    ) {
+        self.cached_query = Some(CachedScanResultsQuery {
+            snapshot_generation,
+            query_options,
+            modules_hash,
+            scan_result_indices,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResultsQueryCache;
+    use crate::structures::memory::normalized_module::NormalizedModule;
+    use crate::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
+    use crate::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
+    use std::sync::Arc;
+
+    #[test]
+    fn get_only_returns_indices_for_the_same_generation_query_and_modules() {
+        let query_options = ScanResultsQueryOptions {
+            sort_key: ScanResultsSortKey::Address,
+            ..ScanResultsQueryOptions::default()
+        };
+        let modules_hash = ScanResultsQueryCache::hash_modules(&[NormalizedModule::new("game.exe", 0x1000, 0x100)]);
+        let moved_modules_hash = ScanResultsQueryCache::hash_modules(&[NormalizedModule::new("game.exe", 0x2000, 0x100)]);
+        let mut scan_results_query_cache = ScanResultsQueryCache::new();
+
+        scan_results_query_cache.set(7, query_options.clone(), modules_hash, Arc::new(vec![2, 0, 1]));
+
+        assert_eq!(
+            scan_results_query_cache
+                .get(7, &query_options, modules_hash)
+                .as_deref(),
+            Some(&vec![2, 0, 1])
+        );
+        assert!(
+            scan_results_query_cache
+                .get(8, &query_options, modules_hash)
+                .is_none()
+        );
+        assert!(
+            scan_results_query_cache
+                .get(7, &ScanResultsQueryOptions::default(), modules_hash)
+                .is_none()
+        );
+        assert!(
+            scan_results_query_cache
+                .get(7, &query_options, moved_modules_hash)
+                .is_none()
+        );
+        assert!(
+            scan_results_query_cache
+                .get(7, &query_options, ScanResultsQueryCache::hash_modules(&[]))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
+use crate::structures::scanning::constraints::scan_constraint_expression::AnonymousScanConstraintExpression;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Sorting, filtering and search parameters applied to scan results by the engine before a page of results is returned.
+#[derive(Clone, StructOpt, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScanResultsQueryOptions {
+    #[structopt(long, default_value)]
+    #[serde(default)]
+    pub sort_key: ScanResultsSortKey,
+    #[structopt(long)]
+    #[serde(default)]
+    pub sort_descending: bool,
+    #[structopt(long)]
+    #[serde(default)]
+    pub value_filter: Option<AnonymousScanConstraintExpression>,
+    #[structopt(long)]
+    #[serde(default)]
+    pub module_filter: Option<String>,
+    #[structopt(long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    #[serde(default)]
+    pub region_start_address: Option<u64>,
+    #[structopt(long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    #[serde(default)]
+    pub region_end_address: Option<u64>,
+    #[structopt(long)]
+    #[serde(default)]
+    pub address_search: Option<String>,
+}
+
+impl ScanResultsQueryOptions {
+    /// Determines whether these options keep every scan result in snapshot order, in which case no evaluation is required.
+    pub fn is_unfiltered_and_unsorted(&self) -> bool {
+        self.sort_key == ScanResultsSortKey::Index
+            && !self.sort_descending
+            && self.value_filter.is_none()
+            && self.module_filter.is_none()
+            && self.region_start_address.is_none()
+            && self.region_end_address.is_none()
+            && self
+                .address_search
+                .as_ref()
+                .is_none_or(|address_search| address_search.trim().is_empty())
+    }
+}
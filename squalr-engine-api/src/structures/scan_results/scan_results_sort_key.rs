@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The key by which scan results are ordered when queried.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScanResultsSortKey {
+    /// Orders results as they are stored in the snapshot.
+    #[default]
+    Index,
+    Address,
+    /// Orders results by module name, then by module offset. Results outside of modules are ordered last.
+    Module,
+    CurrentValue,
+    /// Orders results by the difference between the current and previous value.
+    Delta,
+}
+
+impl FromStr for ScanResultsSortKey {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "index" => Ok(ScanResultsSortKey::Index),
+            "address" => Ok(ScanResultsSortKey::Address),
+            "module" => Ok(ScanResultsSortKey::Module),
+            "value" | "current-value" => Ok(ScanResultsSortKey::CurrentValue),
+            "delta" => Ok(ScanResultsSortKey::Delta),
+            _ => Err(format!("Invalid scan results sort key: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for ScanResultsSortKey {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ScanResultsSortKey::Index => write!(formatter, "index"),
+            ScanResultsSortKey::Address => write!(formatter, "address"),
+            ScanResultsSortKey::Module => write!(formatter, "module"),
+            ScanResultsSortKey::CurrentValue => write!(formatter, "current-value"),
+            ScanResultsSortKey::Delta => write!(formatter, "delta"),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod scan_correlation;
+pub mod scan_correlation_candidate;
+pub mod scan_correlation_mode;
+pub mod scan_correlation_ranking;
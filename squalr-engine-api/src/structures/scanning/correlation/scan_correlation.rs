@@ -0,0 +1,132 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::scanning::correlation::scan_correlation_candidate::ScanCorrelationCandidate;
+use crate::structures::scanning::correlation::scan_correlation_mode::ScanCorrelationMode;
+use crate::structures::scanning::correlation::scan_correlation_ranking::ScanCorrelationRanking;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Tracks a sequence of observations made by the user, and the value of each candidate address sampled alongside them.
+/// Rather than requiring a strict comparison per step, candidates are ranked by how well their history tracks the observations.
+#[derive(Clone, Debug, Default)]
+pub struct ScanCorrelation {
+    observations: Vec<f64>,
+    candidates: Vec<ScanCorrelationCandidate>,
+    candidate_indices: HashMap<(u64, DataTypeRef), usize>,
+}
+
+impl ScanCorrelation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Discards all observations and candidates.
+    pub fn clear(&mut self) {
+        self.observations.clear();
+        self.candidates.clear();
+        self.candidate_indices.clear();
+    }
+
+    /// Replaces the candidates, discarding all prior observations.
+    pub fn begin(
+        &mut self,
+        candidates: Vec<ScanCorrelationCandidate>,
+    ) {
+        self.observations.clear();
+        self.candidates = candidates;
+        self.rebuild_candidate_indices();
+    }
+
+    pub fn get_observations(&self) -> &[f64] {
+        &self.observations
+    }
+
+    pub fn get_sample_count(&self) -> usize {
+        self.observations.len()
+    }
+
+    pub fn get_candidates(&self) -> &[ScanCorrelationCandidate] {
+        &self.candidates
+    }
+
+    pub fn get_candidate_count(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// Records a new observation. Each candidate starts with an unread value for it, to be filled in by `set_latest_value`.
+    pub fn push_observation(
+        &mut self,
+        observation: f64,
+    ) {
+        self.observations.push(observation);
+
+        for candidate in &mut self.candidates {
+            candidate.push_value(None);
+        }
+    }
+
+    /// Sets the value sampled for the latest observation of a candidate. Values for unknown candidates are ignored.
+    pub fn set_latest_value(
+        &mut self,
+        address: u64,
+        data_type_ref: &DataTypeRef,
+        value: Option<f64>,
+    ) {
+        if let Some(candidate_index) = self.candidate_indices.get(&(address, data_type_ref.clone())) {
+            self.candidates[*candidate_index].set_latest_value(value);
+        }
+    }
+
+    /// Scores every candidate, returning rankings ordered from the best score to the worst, then by address.
+    pub fn rank(
+        &self,
+        correlation_mode: ScanCorrelationMode,
+        tolerance: f64,
+    ) -> Vec<ScanCorrelationRanking> {
+        let mut rankings: Vec<ScanCorrelationRanking> = self
+            .candidates
+            .iter()
+            .map(|candidate| ScanCorrelationRanking {
+                address: candidate.get_address(),
+                data_type_ref: candidate.get_data_type_ref().clone(),
+                score: candidate.score(correlation_mode, &self.observations, tolerance),
+                latest_value: candidate.get_latest_value(),
+            })
+            .collect();
+
+        rankings.sort_by(|left, right| {
+            right
+                .score
+                .partial_cmp(&left.score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| left.address.cmp(&right.address))
+        });
+
+        rankings
+    }
+
+    /// Keeps only the candidates scoring at least the minimum score, returning the number of candidates removed.
+    pub fn retain_minimum_score(
+        &mut self,
+        correlation_mode: ScanCorrelationMode,
+        tolerance: f64,
+        minimum_score: f64,
+    ) -> usize {
+        let candidate_count = self.candidates.len();
+        let observations = &self.observations;
+
+        self.candidates
+            .retain(|candidate| candidate.score(correlation_mode, observations, tolerance) >= minimum_score);
+        self.rebuild_candidate_indices();
+
+        candidate_count - self.candidates.len()
+    }
+
+    fn rebuild_candidate_indices(&mut self) {
+        self.candidate_indices = self
+            .candidates
+            .iter()
+            .enumerate()
+            .map(|(candidate_index, candidate)| ((candidate.get_address(), candidate.get_data_type_ref().clone()), candidate_index))
+            .collect();
+    }
+}
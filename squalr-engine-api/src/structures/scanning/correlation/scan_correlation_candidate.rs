@@ -0,0 +1,172 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::scanning::correlation::scan_correlation_mode::ScanCorrelationMode;
+
+/// An address under consideration by a correlation scan, along with the value sampled alongside each observation.
+/// Values that could not be read are recorded as `None`, and always count against the candidate.
+#[derive(Clone, Debug)]
+pub struct ScanCorrelationCandidate {
+    address: u64,
+    data_type_ref: DataTypeRef,
+    memory_alignment: MemoryAlignment,
+    values: Vec<Option<f64>>,
+}
+
+impl ScanCorrelationCandidate {
+    pub fn new(
+        address: u64,
+        data_type_ref: DataTypeRef,
+        memory_alignment: MemoryAlignment,
+    ) -> Self {
+        Self {
+            address,
+            data_type_ref,
+            memory_alignment,
+            values: vec![],
+        }
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_data_type_ref(&self) -> &DataTypeRef {
+        &self.data_type_ref
+    }
+
+    pub fn get_memory_alignment(&self) -> MemoryAlignment {
+        self.memory_alignment
+    }
+
+    pub fn get_values(&self) -> &[Option<f64>] {
+        &self.values
+    }
+
+    /// Gets the most recently sampled value, if it could be read.
+    pub fn get_latest_value(&self) -> Option<f64> {
+        self.values.last().copied().flatten()
+    }
+
+    pub fn push_value(
+        &mut self,
+        value: Option<f64>,
+    ) {
+        self.values.push(value);
+    }
+
+    /// Replaces the most recently sampled value.
+    pub fn set_latest_value(
+        &mut self,
+        value: Option<f64>,
+    ) {
+        if let Some(latest_value) = self.values.last_mut() {
+            *latest_value = value;
+        }
+    }
+
+    /// Scores how well the sampled values track the observations, from 0 (no relation) to 1 (perfect match).
+    pub fn score(
+        &self,
+        correlation_mode: ScanCorrelationMode,
+        observations: &[f64],
+        tolerance: f64,
+    ) -> f64 {
+        let samples: Vec<(Option<f64>, f64)> = self
+            .values
+            .iter()
+            .copied()
+            .zip(observations.iter().copied())
+            .collect();
+
+        if samples.is_empty() {
+            return 0.0;
+        }
+
+        match correlation_mode {
+            ScanCorrelationMode::Exact => Self::score_exact(&samples, tolerance),
+            ScanCorrelationMode::Monotonic => Self::score_monotonic(&samples, tolerance),
+            ScanCorrelationMode::Proportional => Self::score_proportional(&samples, tolerance),
+        }
+    }
+
+    fn score_exact(
+        samples: &[(Option<f64>, f64)],
+        tolerance: f64,
+    ) -> f64 {
+        let matching_sample_count = samples
+            .iter()
+            .filter(|(value, observation)| value.is_some_and(|value| (value - observation).abs() <= tolerance))
+            .count();
+
+        matching_sample_count as f64 / samples.len() as f64
+    }
+
+    /// Compares the direction of change between consecutive samples. A single sample has no direction, so it scores as exact.
+    fn score_monotonic(
+        samples: &[(Option<f64>, f64)],
+        tolerance: f64,
+    ) -> f64 {
+        if samples.len() < 2 {
+            return Self::score_exact(samples, tolerance);
+        }
+
+        let direction = |delta: f64| if delta.abs() <= tolerance { 0 } else { delta.signum() as i32 };
+        let matching_step_count = samples
+            .windows(2)
+            .filter(|step| match (step[0], step[1]) {
+                ((Some(previous_value), previous_observation), (Some(value), observation)) => {
+                    direction(value - previous_value) == direction(observation - previous_observation)
+                }
+                _ => false,
+            })
+            .count();
+
+        matching_step_count as f64 / (samples.len() - 1) as f64
+    }
+
+    /// Computes the Pearson correlation of values against observations, treating negative correlation as no correlation.
+    /// Constant series only correlate with each other. Any unreadable sample disqualifies the candidate.
+    fn score_proportional(
+        samples: &[(Option<f64>, f64)],
+        tolerance: f64,
+    ) -> f64 {
+        if samples.len() < 2 {
+            return Self::score_exact(samples, tolerance);
+        }
+
+        let mut values = Vec::with_capacity(samples.len());
+
+        for (value, _observation) in samples {
+            match value {
+                Some(value) if value.is_finite() => values.push(*value),
+                _ => return 0.0,
+            }
+        }
+
+        let sample_count = samples.len() as f64;
+        let value_mean = values.iter().sum::<f64>() / sample_count;
+        let observation_mean = samples
+            .iter()
+            .map(|(_value, observation)| observation)
+            .sum::<f64>()
+            / sample_count;
+        let mut covariance = 0.0;
+        let mut value_variance = 0.0;
+        let mut observation_variance = 0.0;
+
+        for (value, (_value, observation)) in values.iter().zip(samples) {
+            let value_deviation = value - value_mean;
+            let observation_deviation = observation - observation_mean;
+
+            covariance += value_deviation * observation_deviation;
+            value_variance += value_deviation * value_deviation;
+            observation_variance += observation_deviation * observation_deviation;
+        }
+
+        match (value_variance > 0.0, observation_variance > 0.0) {
+            (true, true) => (covariance / (value_variance * observation_variance).sqrt()).clamp(0.0, 1.0),
+            (false, false) => 1.0,
+            _ => 0.0,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Determines how the sampled history of a candidate is compared against the observed signal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScanCorrelationMode {
+    /// Scores the fraction of samples where the value equals the observation.
+    #[default]
+    Exact,
+    /// Scores the fraction of consecutive samples where the value moves in the same direction as the observation.
+    Monotonic,
+    /// Scores the linear correlation between values and observations, such that scaled or offset values still match.
+    Proportional,
+}
+
+impl FromStr for ScanCorrelationMode {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "e" | "exact" => Ok(ScanCorrelationMode::Exact),
+            "m" | "monotonic" => Ok(ScanCorrelationMode::Monotonic),
+            "p" | "proportional" | "linear" => Ok(ScanCorrelationMode::Proportional),
+            _ => Err(format!("Invalid correlation mode: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for ScanCorrelationMode {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ScanCorrelationMode::Exact => write!(formatter, "exact"),
+            ScanCorrelationMode::Monotonic => write!(formatter, "monotonic"),
+            ScanCorrelationMode::Proportional => write!(formatter, "proportional"),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use serde::{Deserialize, Serialize};
+
+/// A candidate address scored by how well its sampled history tracks the observed signal.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScanCorrelationRanking {
+    pub address: u64,
+    pub data_type_ref: DataTypeRef,
+    pub score: f64,
+    pub latest_value: Option<f64>,
+}
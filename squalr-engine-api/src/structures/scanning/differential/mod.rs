@@ -0,0 +1 @@
+pub mod scan_differential_compare_type;
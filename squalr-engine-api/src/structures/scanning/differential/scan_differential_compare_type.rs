@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Determines which results a differential scan keeps when comparing the same module-relative address across two processes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScanDifferentialCompareType {
+    /// Keeps results whose value differs between the processes.
+    #[default]
+    Different,
+    /// Keeps results whose value is the same in both processes.
+    Equal,
+}
+
+impl FromStr for ScanDifferentialCompareType {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "!=" | "differ" | "different" => Ok(ScanDifferentialCompareType::Different),
+            "==" | "match" | "equal" | "same" => Ok(ScanDifferentialCompareType::Equal),
+            _ => Err(format!("Invalid differential compare type: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for ScanDifferentialCompareType {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ScanDifferentialCompareType::Different => write!(formatter, "different"),
+            ScanDifferentialCompareType::Equal => write!(formatter, "equal"),
+        }
+    }
+}
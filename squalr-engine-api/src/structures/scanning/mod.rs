@@ -1,5 +1,7 @@
 pub mod comparisons;
 pub mod constraints;
+pub mod correlation;
+pub mod differential;
 pub mod filters;
 pub mod memory_read_mode;
 pub mod plans;
@@ -49,6 +49,14 @@ impl ElementScanFilterRule for RuleMapScanType {
             return;
         }
 
+        // Types without SIMD comparisons (ie plugin-defined types) must always be scanned element by element. This also rules out
+        // byte array scans, since plugin equality is not guaranteed to be bytewise equality.
+        if !SymbolRegistry::get_instance().supports_vector_comparisons(snapshot_filter_element_scan_plan.get_data_type_ref()) {
+            snapshot_filter_element_scan_plan.set_planned_scan_type(PlannedScanType::Scalar(PlannedScanTypeScalar::ScalarIterative));
+
+            return;
+        }
+
         let region_size = snapshot_region_filter.get_region_size();
 
         // Early check as to whether we are smaller than the smallest possible vector.
@@ -41,6 +41,11 @@ impl ElementScanParametersRule for RuleMapToPrimitiveType {
                 return;
             }
 
+            // Plugin-defined types own their comparison semantics, so reinterpreting them as a built-in primitive would be incorrect.
+            if !symbol_registry.supports_vector_comparisons(data_type_ref) {
+                return;
+            }
+
             let data_type_size = data_value.get_size_in_bytes();
             let data_type_default_size = symbol_registry.get_unit_size_in_bytes(data_type_ref);
 
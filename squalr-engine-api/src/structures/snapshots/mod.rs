@@ -1,2 +1,5 @@
 pub mod snapshot;
+pub mod snapshot_refresh_result;
 pub mod snapshot_region;
+pub mod snapshot_session;
+pub mod snapshot_session_error;
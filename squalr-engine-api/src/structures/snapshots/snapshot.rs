@@ -1,9 +1,27 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::address_ranges::AddressRanges;
+use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
 use crate::structures::scan_results::scan_result_valued::ScanResultValued;
+use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use crate::structures::snapshots::snapshot_refresh_result::SnapshotRefreshResult;
 use crate::structures::snapshots::snapshot_region::SnapshotRegion;
 use std::cmp;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The source of snapshot generations, shared by all snapshots such that a replaced snapshot never repeats a generation.
+static NEXT_SNAPSHOT_GENERATION: AtomicU64 = AtomicU64::new(0);
 
 pub struct Snapshot {
     snapshot_regions: Vec<SnapshotRegion>,
+
+    /// Identifies the current contents of this snapshot, changing whenever its regions, results or values may have changed.
+    generation: u64,
+
+    /// The memory pages known to this snapshot, used to distinguish newly allocated memory from memory eliminated by scans.
+    captured_memory_pages: Vec<NormalizedRegion>,
 }
 
 /// Represents a snapshot of memory in an external process that contains current and previous values of memory pages.
@@ -11,7 +29,160 @@ impl Snapshot {
     /// Creates a new snapshot from the given collection of snapshot regions.
     /// This will automatically sort and remove invalid regions.
     pub fn new() -> Self {
-        Self { snapshot_regions: vec![] }
+        Self {
+            snapshot_regions: vec![],
+            generation: Self::next_generation(),
+            captured_memory_pages: vec![],
+        }
+    }
+
+    /// Gets the generation of this snapshot, which changes whenever its contents may have changed, such that derived data can be cached.
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn next_generation() -> u64 {
+        NEXT_SNAPSHOT_GENERATION.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Replaces all snapshot regions with regions covering the given memory pages, remembering these pages for later refreshes.
+    pub fn capture_memory_pages(
+        &mut self,
+        memory_pages: Vec<NormalizedRegion>,
+    ) {
+        self.captured_memory_pages = memory_pages.clone();
+        self.set_snapshot_regions(Self::create_snapshot_regions(memory_pages));
+    }
+
+    /// Gets the memory pages known to this snapshot as of the last capture or refresh.
+    pub fn get_captured_memory_pages(&self) -> &Vec<NormalizedRegion> {
+        &self.captured_memory_pages
+    }
+
+    /// Creates snapshot regions from memory pages sorted by address, merging adjacent pages into a single region.
+    pub fn create_snapshot_regions(memory_pages: Vec<NormalizedRegion>) -> Vec<SnapshotRegion> {
+        // Attempt to merge any adjacent regions. This drastically simplifies the scanning process by eliminating edge case handling.
+        // Additionally, we must track the page boundaries at which the merge took place.
+        // Doing this allows us to ensure that we do not try to read memory across a page boundary later when collecting values.
+        // This prevents issues where one page may deallocate, which would otherwise cause the read for an adjacent page to fail!
+        let mut merged_snapshot_regions = vec![];
+        let mut page_boundaries = vec![];
+        let mut iter = memory_pages.into_iter();
+
+        if let Some(mut current_region) = iter.next() {
+            for region in iter {
+                if current_region.get_end_address() == region.get_base_address() {
+                    current_region.set_end_address(region.get_end_address());
+                    page_boundaries.push(region.get_base_address());
+                } else {
+                    merged_snapshot_regions.push(SnapshotRegion::new(current_region, std::mem::take(&mut page_boundaries)));
+                    current_region = region;
+                }
+            }
+
+            // Push the last region.
+            merged_snapshot_regions.push(SnapshotRegion::new(current_region, page_boundaries));
+        }
+
+        merged_snapshot_regions
+    }
+
+    /// Reconciles this snapshot against the current memory pages of the process, preserving existing scan results.
+    /// Regions whose memory was deallocated are dropped, and regions that were partially deallocated are split or trimmed.
+    /// Newly allocated memory is added if no scan has been performed yet, or if `seed_new_regions` is set, in which case the
+    /// new regions are seeded with filters that match every element for each data type and alignment currently being scanned.
+    pub fn refresh_memory_pages(
+        &mut self,
+        mut memory_pages: Vec<NormalizedRegion>,
+        seed_new_regions: bool,
+    ) -> SnapshotRefreshResult {
+        let mut refresh_result = SnapshotRefreshResult::default();
+
+        self.generation = Self::next_generation();
+        memory_pages.sort();
+
+        let fresh_ranges = AddressRanges::merge(&memory_pages);
+        let scan_layouts = self.collect_scan_layouts();
+        let mut refreshed_snapshot_regions = vec![];
+
+        for snapshot_region in std::mem::take(&mut self.snapshot_regions) {
+            let overlapping_ranges = fresh_ranges
+                .iter()
+                .filter(|(base_address, end_address)| *base_address < snapshot_region.get_end_address() && snapshot_region.get_base_address() < *end_address)
+                .collect::<Vec<_>>();
+
+            let is_fully_mapped = matches!(
+                overlapping_ranges.as_slice(),
+                [(base_address, end_address)] if *base_address <= snapshot_region.get_base_address() && snapshot_region.get_end_address() <= *end_address
+            );
+
+            if is_fully_mapped {
+                let mut snapshot_region = snapshot_region;
+
+                snapshot_region.set_is_stale(false);
+                refreshed_snapshot_regions.push(snapshot_region);
+                continue;
+            }
+
+            let clipped_snapshot_regions = overlapping_ranges
+                .iter()
+                .filter_map(|(base_address, end_address)| snapshot_region.clip(*base_address, *end_address))
+                .collect::<Vec<_>>();
+
+            if clipped_snapshot_regions.is_empty() {
+                refresh_result.dropped_region_count += 1;
+            } else {
+                refresh_result.split_region_count += 1;
+                refreshed_snapshot_regions.extend(clipped_snapshot_regions);
+            }
+        }
+
+        let new_ranges = AddressRanges::subtract(&fresh_ranges, &AddressRanges::merge(&self.captured_memory_pages));
+        let should_add_new_ranges = scan_layouts.is_empty() || seed_new_regions;
+
+        if should_add_new_ranges {
+            for (base_address, end_address) in &new_ranges {
+                let page_boundaries = memory_pages
+                    .iter()
+                    .map(|memory_page| memory_page.get_base_address())
+                    .filter(|page_boundary| page_boundary > base_address && page_boundary < end_address)
+                    .collect();
+                let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(*base_address, end_address - base_address), page_boundaries);
+
+                if !scan_layouts.is_empty() {
+                    let snapshot_region_filter_collections = scan_layouts
+                        .iter()
+                        .map(|(data_type_ref, memory_alignment)| {
+                            SnapshotRegionFilterCollection::new(
+                                vec![vec![SnapshotRegionFilter::new(
+                                    *base_address,
+                                    end_address - base_address,
+                                )]],
+                                data_type_ref.clone(),
+                                *memory_alignment,
+                            )
+                        })
+                        .collect();
+
+                    snapshot_region.set_scan_results(SnapshotRegionScanResults::new(snapshot_region_filter_collections));
+                }
+
+                refreshed_snapshot_regions.push(snapshot_region);
+                refresh_result.added_region_count += 1;
+            }
+
+            self.captured_memory_pages = memory_pages;
+        } else {
+            // Only remember memory that was previously known, such that a later seeded refresh can still add the new memory.
+            self.captured_memory_pages = AddressRanges::subtract(&fresh_ranges, &new_ranges)
+                .into_iter()
+                .map(|(base_address, end_address)| NormalizedRegion::new(base_address, end_address - base_address))
+                .collect();
+        }
+
+        self.set_snapshot_regions(refreshed_snapshot_regions);
+
+        refresh_result
     }
 
     /// Assigns new snapshot regions to this snapshot.
@@ -19,6 +190,7 @@ impl Snapshot {
         &mut self,
         snapshot_regions: Vec<SnapshotRegion>,
     ) {
+        self.generation = Self::next_generation();
         self.snapshot_regions = snapshot_regions;
         self.discard_empty_regions();
         self.sort_regions();
@@ -31,23 +203,28 @@ impl Snapshot {
 
     /// Gets a mutable reference to the snapshot regions contained by this snapshot.
     pub fn get_snapshot_regions_mut(&mut self) -> &mut Vec<SnapshotRegion> {
+        self.generation = Self::next_generation();
+
         &mut self.snapshot_regions
     }
 
     /// Discards all snapshot regions with a size of zero.
     pub fn discard_empty_regions(&mut self) {
+        self.generation = Self::next_generation();
         self.snapshot_regions
             .retain(|region| region.get_region_size() > 0);
     }
 
     /// Sorts all snapshot regions by base address ascending.
     pub fn sort_regions_for_read(&mut self) {
+        self.generation = Self::next_generation();
         self.snapshot_regions
             .sort_by_key(|region| cmp::Reverse(region.get_region_size()));
     }
 
     /// Sorts all snapshot regions by base address ascending.
     pub fn sort_regions(&mut self) {
+        self.generation = Self::next_generation();
         self.snapshot_regions
             .sort_by_key(|region| region.get_base_address());
     }
@@ -65,6 +242,38 @@ impl Snapshot {
             .sum()
     }
 
+    /// Marks all snapshot regions overlapping the given unmapped regions as stale, returning the number of newly stale regions.
+    pub fn mark_stale_regions(
+        &mut self,
+        unmapped_regions: &[NormalizedRegion],
+    ) -> u64 {
+        let mut newly_stale_region_count = 0;
+
+        for snapshot_region in &mut self.snapshot_regions {
+            if snapshot_region.get_is_stale() {
+                continue;
+            }
+
+            if unmapped_regions
+                .iter()
+                .any(|unmapped_region| snapshot_region.overlaps(unmapped_region))
+            {
+                snapshot_region.set_is_stale(true);
+                newly_stale_region_count += 1;
+            }
+        }
+
+        newly_stale_region_count
+    }
+
+    /// Gets the number of snapshot regions whose backing memory was unmapped or remapped since they were captured.
+    pub fn get_stale_region_count(&self) -> u64 {
+        self.snapshot_regions
+            .iter()
+            .filter(|snapshot_region| snapshot_region.get_is_stale())
+            .count() as u64
+    }
+
     /// Seeks to the scan result at the specified index. First this performs a linear scan to locate the snapshot region
     /// containing the index, followed by a binary search to find the exact filter, and finally the scan result.
     pub fn get_scan_result(
@@ -87,6 +296,26 @@ impl Snapshot {
         None
     }
 
+    /// Gets each distinct data type and alignment pairing currently being scanned across all snapshot regions.
+    fn collect_scan_layouts(&self) -> Vec<(DataTypeRef, MemoryAlignment)> {
+        let mut scan_layouts: Vec<(DataTypeRef, MemoryAlignment)> = vec![];
+
+        for snapshot_region in &self.snapshot_regions {
+            for snapshot_region_filter_collection in snapshot_region.get_scan_results().get_filter_collections() {
+                let scan_layout = (
+                    snapshot_region_filter_collection.get_data_type_ref().clone(),
+                    snapshot_region_filter_collection.get_memory_alignment(),
+                );
+
+                if !scan_layouts.contains(&scan_layout) {
+                    scan_layouts.push(scan_layout);
+                }
+            }
+        }
+
+        scan_layouts
+    }
+
     /// Gets the number of scan results contained in this snapshot.
     pub fn get_number_of_results(&self) -> u64 {
         self.snapshot_regions
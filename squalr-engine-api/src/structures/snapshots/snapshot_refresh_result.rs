@@ -0,0 +1,10 @@
+/// Summarizes how a snapshot changed when reconciled against the current memory pages of a process.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotRefreshResult {
+    /// The number of snapshot regions whose memory was entirely deallocated.
+    pub dropped_region_count: u64,
+    /// The number of snapshot regions that were split or trimmed because part of their memory was deallocated.
+    pub split_region_count: u64,
+    /// The number of snapshot regions added for newly allocated memory.
+    pub added_region_count: u64,
+}
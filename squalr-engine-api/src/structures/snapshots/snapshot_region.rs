@@ -26,6 +26,9 @@ pub struct SnapshotRegion {
     /// Pages where a read has failed due to a deallocated page or other read issue.
     pub page_boundary_tombstones: HashSet<u64>,
 
+    /// Whether the memory backing this region was unmapped or remapped after this region was captured.
+    is_stale: bool,
+
     /// The current scan results on this snapshot region.
     scan_results: SnapshotRegionScanResults,
 }
@@ -41,6 +44,7 @@ impl SnapshotRegion {
             previous_values: vec![],
             page_boundaries,
             page_boundary_tombstones: HashSet::new(),
+            is_stale: false,
             scan_results: SnapshotRegionScanResults::new(vec![]),
         }
     }
@@ -145,6 +149,112 @@ impl SnapshotRegion {
         self.normalized_region.get_region_size()
     }
 
+    /// Gets whether the memory backing this region was unmapped or remapped after this region was captured.
+    pub fn get_is_stale(&self) -> bool {
+        self.is_stale
+    }
+
+    pub fn set_is_stale(
+        &mut self,
+        is_stale: bool,
+    ) {
+        self.is_stale = is_stale;
+    }
+
+    /// Determines whether this region shares any addresses with the given region.
+    pub fn overlaps(
+        &self,
+        normalized_region: &NormalizedRegion,
+    ) -> bool {
+        self.get_base_address() < normalized_region.get_end_address() && normalized_region.get_base_address() < self.get_end_address()
+    }
+
+    /// Creates a copy of this region clipped to the given address range, retaining any values, page boundaries, and scan results
+    /// within that range. Returns `None` if the range does not overlap this region, or if no scan results remain after clipping.
+    pub fn clip(
+        &self,
+        base_address: u64,
+        end_address: u64,
+    ) -> Option<SnapshotRegion> {
+        let clipped_base_address = base_address.max(self.get_base_address());
+        let clipped_end_address = end_address.min(self.get_end_address());
+
+        if clipped_base_address >= clipped_end_address {
+            return None;
+        }
+
+        let start_offset = clipped_base_address.saturating_sub(self.get_base_address()) as usize;
+        let end_offset = clipped_end_address.saturating_sub(self.get_base_address()) as usize;
+        let clip_values = |values: &Vec<u8>| {
+            values
+                .get(start_offset..end_offset.min(values.len()))
+                .map(|values| values.to_vec())
+                .unwrap_or_default()
+        };
+        let page_boundaries = self
+            .page_boundaries
+            .iter()
+            .copied()
+            .filter(|&page_boundary| page_boundary > clipped_base_address && page_boundary < clipped_end_address)
+            .collect();
+        let mut clipped_region = SnapshotRegion::new(
+            NormalizedRegion::new(clipped_base_address, clipped_end_address - clipped_base_address),
+            page_boundaries,
+        );
+
+        clipped_region.current_values = clip_values(&self.current_values);
+        clipped_region.previous_values = clip_values(&self.previous_values);
+        clipped_region.page_boundary_tombstones = self
+            .page_boundary_tombstones
+            .iter()
+            .copied()
+            .filter(|&tombstone| tombstone >= clipped_base_address && tombstone < clipped_end_address)
+            .collect();
+
+        // A region that has not been scanned yet implicitly matches everything, so there are no filters to preserve.
+        if self.scan_results.get_filter_collections().is_empty() {
+            return Some(clipped_region);
+        }
+
+        let snapshot_region_filter_collections = self
+            .scan_results
+            .get_filter_collections()
+            .iter()
+            .filter_map(|snapshot_region_filter_collection| {
+                let snapshot_region_filters = snapshot_region_filter_collection
+                    .iter()
+                    .filter_map(|snapshot_region_filter| {
+                        let filter_base_address = snapshot_region_filter
+                            .get_base_address()
+                            .max(clipped_base_address);
+                        let filter_end_address = snapshot_region_filter
+                            .get_end_address()
+                            .min(clipped_end_address);
+
+                        (filter_base_address < filter_end_address)
+                            .then(|| SnapshotRegionFilter::new(filter_base_address, filter_end_address - filter_base_address))
+                    })
+                    .collect::<Vec<_>>();
+
+                (!snapshot_region_filters.is_empty()).then(|| {
+                    SnapshotRegionFilterCollection::new(
+                        vec![snapshot_region_filters],
+                        snapshot_region_filter_collection.get_data_type_ref().clone(),
+                        snapshot_region_filter_collection.get_memory_alignment(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if snapshot_region_filter_collections.is_empty() {
+            return None;
+        }
+
+        clipped_region.set_scan_results(SnapshotRegionScanResults::new(snapshot_region_filter_collections));
+
+        Some(clipped_region)
+    }
+
     pub fn has_current_values(&self) -> bool {
         !self.current_values.is_empty()
     }
@@ -0,0 +1,372 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use crate::structures::settings::scan_settings::ScanSettings;
+use crate::structures::snapshots::snapshot::Snapshot;
+use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+use crate::structures::snapshots::snapshot_session_error::SnapshotSessionError;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// A checkpoint of a scan in progress, containing everything needed to rebuild the snapshot and continue narrowing results later.
+/// Sessions are written through a `SnapshotSessionView`, and read back as this type.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SnapshotSession {
+    pub version: u32,
+    pub process_id: u32,
+    pub process_name: String,
+    pub scan_settings: ScanSettings,
+    pub captured_memory_pages: Vec<NormalizedRegion>,
+    pub snapshot_regions: Vec<SnapshotSessionRegion>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SnapshotSessionRegion {
+    pub normalized_region: NormalizedRegion,
+    pub current_values: Vec<u8>,
+    pub previous_values: Vec<u8>,
+    pub page_boundaries: Vec<u64>,
+    pub page_boundary_tombstones: Vec<u64>,
+    pub is_stale: bool,
+    pub filter_collections: Vec<SnapshotSessionFilterCollection>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SnapshotSessionFilterCollection {
+    pub data_type_ref: DataTypeRef,
+    pub memory_alignment: MemoryAlignment,
+    /// The filters of this collection, as (base address, size in bytes) pairs.
+    pub filters: Vec<(u64, u64)>,
+}
+
+impl SnapshotSession {
+    /// The current session file version. Sessions written by other versions are rejected rather than misinterpreted.
+    pub const VERSION: u32 = 1;
+
+    /// Replaces the contents of the given snapshot with the regions, values, and filters of this session. The snapshot is left
+    /// untouched if any region is inconsistent, such as values or filters that do not fit within it.
+    pub fn restore(
+        self,
+        snapshot: &mut Snapshot,
+    ) -> Result<(), SnapshotSessionError> {
+        for snapshot_session_region in &self.snapshot_regions {
+            snapshot_session_region.validate()?;
+        }
+
+        let snapshot_regions = self
+            .snapshot_regions
+            .into_iter()
+            .map(|snapshot_session_region| {
+                let mut snapshot_region = SnapshotRegion::new(snapshot_session_region.normalized_region, snapshot_session_region.page_boundaries);
+                let snapshot_region_filter_collections = snapshot_session_region
+                    .filter_collections
+                    .into_iter()
+                    .map(|snapshot_session_filter_collection| {
+                        let filters = snapshot_session_filter_collection
+                            .filters
+                            .into_iter()
+                            .map(|(base_address, size_in_bytes)| SnapshotRegionFilter::new(base_address, size_in_bytes))
+                            .collect();
+
+                        SnapshotRegionFilterCollection::new(
+                            vec![filters],
+                            snapshot_session_filter_collection.data_type_ref,
+                            snapshot_session_filter_collection.memory_alignment,
+                        )
+                    })
+                    .collect();
+
+                snapshot_region.current_values = snapshot_session_region.current_values;
+                snapshot_region.previous_values = snapshot_session_region.previous_values;
+                snapshot_region.page_boundary_tombstones = snapshot_session_region
+                    .page_boundary_tombstones
+                    .into_iter()
+                    .collect();
+                snapshot_region.set_is_stale(snapshot_session_region.is_stale);
+                snapshot_region.set_scan_results(SnapshotRegionScanResults::new(snapshot_region_filter_collections));
+
+                snapshot_region
+            })
+            .collect();
+
+        snapshot.capture_memory_pages(self.captured_memory_pages);
+        snapshot.set_snapshot_regions(snapshot_regions);
+
+        Ok(())
+    }
+}
+
+/// A borrowed view of a snapshot that serializes identically to a `SnapshotSession`, such that a session is written without
+/// copying snapshot values and filters, which can span gigabytes.
+pub struct SnapshotSessionView<'a> {
+    snapshot: &'a Snapshot,
+    process_id: u32,
+    process_name: &'a str,
+    scan_settings: &'a ScanSettings,
+}
+
+impl<'a> SnapshotSessionView<'a> {
+    pub fn new(
+        snapshot: &'a Snapshot,
+        process_id: u32,
+        process_name: &'a str,
+        scan_settings: &'a ScanSettings,
+    ) -> Self {
+        Self {
+            snapshot,
+            process_id,
+            process_name,
+            scan_settings,
+        }
+    }
+}
+
+impl Serialize for SnapshotSessionView<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut snapshot_session = serializer.serialize_struct("SnapshotSession", 6)?;
+
+        snapshot_session.serialize_field("version", &SnapshotSession::VERSION)?;
+        snapshot_session.serialize_field("process_id", &self.process_id)?;
+        snapshot_session.serialize_field("process_name", self.process_name)?;
+        snapshot_session.serialize_field("scan_settings", self.scan_settings)?;
+        snapshot_session.serialize_field("captured_memory_pages", self.snapshot.get_captured_memory_pages())?;
+        snapshot_session.serialize_field("snapshot_regions", &SnapshotRegionsView(self.snapshot.get_snapshot_regions()))?;
+        snapshot_session.end()
+    }
+}
+
+/// Serializes snapshot regions as a sequence of `SnapshotSessionRegion`.
+struct SnapshotRegionsView<'a>(&'a [SnapshotRegion]);
+
+impl Serialize for SnapshotRegionsView<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(SnapshotRegionView))
+    }
+}
+
+/// Serializes a snapshot region as a `SnapshotSessionRegion`.
+struct SnapshotRegionView<'a>(&'a SnapshotRegion);
+
+impl Serialize for SnapshotRegionView<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let snapshot_region = self.0;
+        let mut page_boundary_tombstones: Vec<u64> = snapshot_region
+            .page_boundary_tombstones
+            .iter()
+            .copied()
+            .collect();
+
+        page_boundary_tombstones.sort_unstable();
+
+        let mut snapshot_session_region = serializer.serialize_struct("SnapshotSessionRegion", 7)?;
+
+        snapshot_session_region.serialize_field(
+            "normalized_region",
+            &NormalizedRegion::new(snapshot_region.get_base_address(), snapshot_region.get_region_size()),
+        )?;
+        snapshot_session_region.serialize_field("current_values", &snapshot_region.current_values)?;
+        snapshot_session_region.serialize_field("previous_values", &snapshot_region.previous_values)?;
+        snapshot_session_region.serialize_field("page_boundaries", &snapshot_region.page_boundaries)?;
+        snapshot_session_region.serialize_field("page_boundary_tombstones", &page_boundary_tombstones)?;
+        snapshot_session_region.serialize_field("is_stale", &snapshot_region.get_is_stale())?;
+        snapshot_session_region.serialize_field(
+            "filter_collections",
+            &SnapshotRegionFilterCollectionsView(snapshot_region.get_scan_results().get_filter_collections()),
+        )?;
+        snapshot_session_region.end()
+    }
+}
+
+/// Serializes filter collections as a sequence of `SnapshotSessionFilterCollection`.
+struct SnapshotRegionFilterCollectionsView<'a>(&'a [SnapshotRegionFilterCollection]);
+
+impl Serialize for SnapshotRegionFilterCollectionsView<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(SnapshotRegionFilterCollectionView))
+    }
+}
+
+/// Serializes a filter collection as a `SnapshotSessionFilterCollection`, with its filters as (base address, size in bytes) pairs.
+struct SnapshotRegionFilterCollectionView<'a>(&'a SnapshotRegionFilterCollection);
+
+impl Serialize for SnapshotRegionFilterCollectionView<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut snapshot_session_filter_collection = serializer.serialize_struct("SnapshotSessionFilterCollection", 3)?;
+
+        snapshot_session_filter_collection.serialize_field("data_type_ref", self.0.get_data_type_ref())?;
+        snapshot_session_filter_collection.serialize_field("memory_alignment", &self.0.get_memory_alignment())?;
+        snapshot_session_filter_collection.serialize_field("filters", &SnapshotRegionFiltersView(self.0))?;
+        snapshot_session_filter_collection.end()
+    }
+}
+
+/// Serializes the filters of a collection as (base address, size in bytes) pairs.
+struct SnapshotRegionFiltersView<'a>(&'a SnapshotRegionFilterCollection);
+
+impl Serialize for SnapshotRegionFiltersView<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // The filters are flattened from chunks, so the length is counted up front for formats that require it.
+        let mut filters = serializer.serialize_seq(Some(self.0.iter().count()))?;
+
+        for snapshot_region_filter in self.0.iter() {
+            filters.serialize_element(&(snapshot_region_filter.get_base_address(), snapshot_region_filter.get_region_size()))?;
+        }
+
+        filters.end()
+    }
+}
+
+impl SnapshotSessionRegion {
+    /// Checks that the values, page boundaries, and filters of this region lie within it, as scans index into the values directly.
+    fn validate(&self) -> Result<(), SnapshotSessionError> {
+        let base_address = self.normalized_region.get_base_address();
+        let region_size = self.normalized_region.get_region_size();
+        let Some(end_address) = base_address.checked_add(region_size) else {
+            return Err(SnapshotSessionError::inconsistent_region(
+                base_address,
+                "region extends past the end of the address space",
+            ));
+        };
+        let is_within_region = |address: &u64| *address > base_address && *address < end_address;
+
+        for (values_name, values) in [
+            ("current", &self.current_values),
+            ("previous", &self.previous_values),
+        ] {
+            if !values.is_empty() && values.len() as u64 != region_size {
+                return Err(SnapshotSessionError::inconsistent_region(
+                    base_address,
+                    format!("{} values span {} bytes, but the region spans {} bytes", values_name, values.len(), region_size),
+                ));
+            }
+        }
+
+        if !self.page_boundaries.iter().all(is_within_region) || !self.page_boundaries.is_sorted() {
+            return Err(SnapshotSessionError::inconsistent_region(
+                base_address,
+                "page boundaries are unordered or outside of the region",
+            ));
+        }
+
+        if !self.page_boundary_tombstones.iter().all(is_within_region) {
+            return Err(SnapshotSessionError::inconsistent_region(
+                base_address,
+                "page boundary tombstones are outside of the region",
+            ));
+        }
+
+        for snapshot_session_filter_collection in &self.filter_collections {
+            for (filter_base_address, filter_size) in &snapshot_session_filter_collection.filters {
+                let is_filter_within_region = *filter_base_address >= base_address
+                    && filter_base_address
+                        .checked_add(*filter_size)
+                        .is_some_and(|filter_end_address| filter_end_address <= end_address);
+
+                if !is_filter_within_region {
+                    return Err(SnapshotSessionError::inconsistent_region(
+                        base_address,
+                        format!("filter 0x{:X} of {} bytes is outside of the region", filter_base_address, filter_size),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SnapshotSession, SnapshotSessionFilterCollection, SnapshotSessionRegion};
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::memory::memory_alignment::MemoryAlignment;
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+    use crate::structures::settings::scan_settings::ScanSettings;
+    use crate::structures::snapshots::snapshot::Snapshot;
+    use crate::structures::snapshots::snapshot_session_error::SnapshotSessionError;
+
+    fn create_snapshot_session(snapshot_session_region: SnapshotSessionRegion) -> SnapshotSession {
+        SnapshotSession {
+            version: SnapshotSession::VERSION,
+            process_id: 1234,
+            process_name: "game.exe".to_string(),
+            scan_settings: ScanSettings::default(),
+            captured_memory_pages: vec![NormalizedRegion::new(0x1000, 0x2000)],
+            snapshot_regions: vec![snapshot_session_region],
+        }
+    }
+
+    fn create_snapshot_session_region(filters: Vec<(u64, u64)>) -> SnapshotSessionRegion {
+        SnapshotSessionRegion {
+            normalized_region: NormalizedRegion::new(0x1000, 0x2000),
+            current_values: vec![0; 0x2000],
+            previous_values: vec![],
+            page_boundaries: vec![0x2000],
+            page_boundary_tombstones: vec![],
+            is_stale: false,
+            filter_collections: vec![SnapshotSessionFilterCollection {
+                data_type_ref: DataTypeRef::new("u8"),
+                memory_alignment: MemoryAlignment::Alignment1,
+                filters,
+            }],
+        }
+    }
+
+    #[test]
+    fn restore_accepts_values_and_filters_within_each_region() {
+        let mut snapshot = Snapshot::new();
+
+        create_snapshot_session(create_snapshot_session_region(vec![(0x1000, 0x10), (0x2FF0, 0x10)]))
+            .restore(&mut snapshot)
+            .expect("session should be restored");
+
+        assert_eq!(snapshot.get_snapshot_regions().len(), 1);
+    }
+
+    #[test]
+    fn restore_rejects_inconsistent_regions_without_modifying_the_snapshot() {
+        let mut short_values_region = create_snapshot_session_region(vec![]);
+        let mut outside_page_boundary_region = create_snapshot_session_region(vec![]);
+        let outside_filter_region = create_snapshot_session_region(vec![(0x2FF0, 0x11)]);
+        let overflowing_filter_region = create_snapshot_session_region(vec![(0x2000, u64::MAX)]);
+
+        short_values_region.previous_values = vec![0; 0x10];
+        outside_page_boundary_region.page_boundaries = vec![0x3000];
+
+        for snapshot_session_region in [
+            short_values_region,
+            outside_page_boundary_region,
+            outside_filter_region,
+            overflowing_filter_region,
+        ] {
+            let mut snapshot = Snapshot::new();
+
+            assert!(matches!(
+                create_snapshot_session(snapshot_session_region).restore(&mut snapshot),
+                Err(SnapshotSessionError::InconsistentRegion { base_address: 0x1000, .. })
+            ));
+            assert!(snapshot.get_snapshot_regions().is_empty());
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum SnapshotSessionError {
+    #[error("Failed to access scan session file '{file_path}': {reason}.")]
+    Io { file_path: String, reason: String },
+    #[error("Malformed scan session file '{file_path}': {reason}.")]
+    Malformed { file_path: String, reason: String },
+    #[error("Unsupported scan session version {version}, expected version {expected_version}.")]
+    UnsupportedVersion { version: u32, expected_version: u32 },
+    #[error("Inconsistent scan session region at 0x{base_address:X}: {reason}.")]
+    InconsistentRegion { base_address: u64, reason: String },
+}
+
+impl SnapshotSessionError {
+    pub fn io(
+        file_path: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::Io {
+            file_path: file_path.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn inconsistent_region(
+        base_address: u64,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::InconsistentRegion {
+            base_address,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn malformed(
+        file_path: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::Malformed {
+            file_path: file_path.into(),
+            reason: reason.into(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The heuristic classification of a slot of unknown memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InferredFieldKind {
+    /// A pointer to the vtable of a discovered class, typically the first field of an object.
+    Vtable,
+    /// A pointer into a loaded module, such as to static data or a function.
+    ModulePointer,
+    /// A pointer into a non-module memory region, such as to another heap object.
+    HeapPointer,
+    /// A null-terminated run of printable characters.
+    String,
+    /// A 32-bit float within a plausible range of magnitudes.
+    Float,
+    /// A 64-bit float within a plausible range of magnitudes.
+    Double,
+    /// A signed integer with a small magnitude, such as a count, flag or identifier.
+    SmallInteger,
+    /// A zeroed slot, which could be padding or an unset field of any type.
+    Zero,
+    /// A value that matched no other classification.
+    Unknown,
+}
+
+impl fmt::Display for InferredFieldKind {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let kind_str = match self {
+            InferredFieldKind::Vtable => "vtable",
+            InferredFieldKind::ModulePointer => "module pointer",
+            InferredFieldKind::HeapPointer => "heap pointer",
+            InferredFieldKind::String => "string",
+            InferredFieldKind::Float => "float",
+            InferredFieldKind::Double => "double",
+            InferredFieldKind::SmallInteger => "small integer",
+            InferredFieldKind::Zero => "zero",
+            InferredFieldKind::Unknown => "unknown",
+        };
+
+        write!(formatter, "{}", kind_str)
+    }
+}
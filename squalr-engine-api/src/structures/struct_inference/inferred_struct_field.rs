@@ -0,0 +1,46 @@
+use crate::structures::struct_inference::inferred_field_kind::InferredFieldKind;
+use crate::structures::structs::symbolic_field_definition::SymbolicFieldDefinition;
+use serde::{Deserialize, Serialize};
+
+/// A field of a draft struct definition, inferred from the value found at its offset.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InferredStructField {
+    offset: u64,
+    kind: InferredFieldKind,
+    field_definition: SymbolicFieldDefinition,
+    description: String,
+}
+
+impl InferredStructField {
+    pub fn new(
+        offset: u64,
+        kind: InferredFieldKind,
+        field_definition: SymbolicFieldDefinition,
+        description: String,
+    ) -> Self {
+        Self {
+            offset,
+            kind,
+            field_definition,
+            description,
+        }
+    }
+
+    /// Gets the offset of this field from the start of the analyzed range.
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn get_kind(&self) -> InferredFieldKind {
+        self.kind
+    }
+
+    pub fn get_field_definition(&self) -> &SymbolicFieldDefinition {
+        &self.field_definition
+    }
+
+    /// Gets a short human readable rendering of the value that led to this classification, such as a pointer target or a string.
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+}
@@ -0,0 +1,2 @@
+pub mod inferred_field_kind;
+pub mod inferred_struct_field;
@@ -0,0 +1,4 @@
+pub mod value_timeline;
+pub mod value_timeline_info;
+pub mod value_timeline_sample;
+pub mod value_timeline_target;
@@ -0,0 +1,121 @@
+use crate::structures::value_timelines::value_timeline_info::ValueTimelineInfo;
+use crate::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use crate::structures::value_timelines::value_timeline_target::ValueTimelineTarget;
+use std::collections::VecDeque;
+
+/// Records the values of a target at a fixed interval into a ring buffer, discarding the oldest samples once full.
+pub struct ValueTimeline {
+    timeline_id: u32,
+    target: ValueTimelineTarget,
+    interval_ms: u64,
+    capacity: u64,
+    samples: VecDeque<ValueTimelineSample>,
+}
+
+impl ValueTimeline {
+    pub const CSV_HEADER: &'static str = "timestamp_ms,timeline_id,label,module,address,data_type,value";
+
+    pub fn new(
+        timeline_id: u32,
+        target: ValueTimelineTarget,
+        interval_ms: u64,
+        capacity: u64,
+    ) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            timeline_id,
+            target,
+            interval_ms: interval_ms.max(1),
+            capacity,
+            samples: VecDeque::with_capacity(capacity.min(u16::MAX as u64) as usize),
+        }
+    }
+
+    pub fn get_timeline_id(&self) -> u32 {
+        self.timeline_id
+    }
+
+    pub fn get_target(&self) -> &ValueTimelineTarget {
+        &self.target
+    }
+
+    pub fn get_interval_ms(&self) -> u64 {
+        self.interval_ms
+    }
+
+    pub fn get_capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    pub fn get_samples(&self) -> &VecDeque<ValueTimelineSample> {
+        &self.samples
+    }
+
+    /// Gets all samples taken strictly after the given timestamp, such that callers can poll for new samples.
+    pub fn get_samples_since(
+        &self,
+        timestamp_ms: u64,
+    ) -> Vec<ValueTimelineSample> {
+        let first_sample_index = self
+            .samples
+            .partition_point(|sample| sample.get_timestamp_ms() <= timestamp_ms);
+
+        self.samples.range(first_sample_index..).cloned().collect()
+    }
+
+    pub fn push_sample(
+        &mut self,
+        sample: ValueTimelineSample,
+    ) {
+        while self.samples.len() as u64 >= self.capacity {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back(sample);
+    }
+
+    pub fn clear_samples(&mut self) {
+        self.samples.clear();
+    }
+
+    pub fn get_info(&self) -> ValueTimelineInfo {
+        ValueTimelineInfo {
+            timeline_id: self.timeline_id,
+            target: self.target.clone(),
+            interval_ms: self.interval_ms,
+            capacity: self.capacity,
+            sample_count: self.samples.len() as u64,
+            latest_sample: self.samples.back().cloned(),
+        }
+    }
+
+    /// Formats each sample as a CSV row in the format described by `CSV_HEADER`.
+    pub fn to_csv_rows(&self) -> impl Iterator<Item = String> + '_ {
+        let label = escape_csv_field(self.target.get_label());
+        let module_name = escape_csv_field(self.target.get_module_name());
+        let data_type_id = escape_csv_field(self.target.get_data_type_ref().get_data_type_id());
+
+        self.samples.iter().map(move |sample| {
+            format!(
+                "{},{},{},{},0x{:X},{},{}",
+                sample.get_timestamp_ms(),
+                self.timeline_id,
+                label,
+                module_name,
+                self.target.get_address(),
+                data_type_id,
+                escape_csv_field(sample.get_value().unwrap_or_default())
+            )
+        })
+    }
+}
+
+/// Quotes a field if it contains a delimiter, quote or line break (ie array values such as `1, 2, 3`).
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,14 @@
+use crate::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use crate::structures::value_timelines::value_timeline_target::ValueTimelineTarget;
+use serde::{Deserialize, Serialize};
+
+/// Describes a value timeline recorder, without its samples.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValueTimelineInfo {
+    pub timeline_id: u32,
+    pub target: ValueTimelineTarget,
+    pub interval_ms: u64,
+    pub capacity: u64,
+    pub sample_count: u64,
+    pub latest_sample: Option<ValueTimelineSample>,
+}
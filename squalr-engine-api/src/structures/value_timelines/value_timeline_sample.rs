@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// A single value read from a recorded target.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValueTimelineSample {
+    timestamp_ms: u64,
+    value: Option<String>,
+    numeric_value: Option<f64>,
+}
+
+impl ValueTimelineSample {
+    pub fn new(
+        timestamp_ms: u64,
+        value: Option<String>,
+    ) -> Self {
+        let numeric_value = value
+            .as_deref()
+            .and_then(|value| value.trim().parse::<f64>().ok());
+
+        Self {
+            timestamp_ms,
+            value,
+            numeric_value,
+        }
+    }
+
+    /// Gets the time of this sample, in milliseconds since the Unix epoch.
+    pub fn get_timestamp_ms(&self) -> u64 {
+        self.timestamp_ms
+    }
+
+    /// Gets the decimal representation of the sampled value, or `None` if the value could not be read.
+    pub fn get_value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Gets the sampled value as a number for plotting, or `None` if the value is unreadable or non-numeric.
+    pub fn get_numeric_value(&self) -> Option<f64> {
+        self.numeric_value
+    }
+}
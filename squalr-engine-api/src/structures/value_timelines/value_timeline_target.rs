@@ -0,0 +1,55 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use serde::{Deserialize, Serialize};
+
+/// An address, or struct field, whose value is sampled over time. Module relative targets are resolved on every sample,
+/// such that recording continues if the module is reloaded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueTimelineTarget {
+    address: u64,
+    module_name: String,
+    data_type_ref: DataTypeRef,
+    label: String,
+}
+
+impl ValueTimelineTarget {
+    pub fn new(
+        address: u64,
+        module_name: String,
+        data_type_ref: DataTypeRef,
+        label: String,
+    ) -> Self {
+        Self {
+            address,
+            module_name,
+            data_type_ref,
+            label,
+        }
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    pub fn get_data_type_ref(&self) -> &DataTypeRef {
+        &self.data_type_ref
+    }
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets a display name for this target, preferring the label over the address.
+    pub fn get_display_name(&self) -> String {
+        if !self.label.is_empty() {
+            self.label.clone()
+        } else if self.module_name.is_empty() {
+            format!("0x{:X}", self.address)
+        } else {
+            format!("{}+0x{:X}", self.module_name, self.address)
+        }
+    }
+}
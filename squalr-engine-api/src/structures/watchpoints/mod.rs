@@ -0,0 +1,3 @@
+pub mod register_snapshot;
+pub mod watchpoint_hit;
+pub mod watchpoint_kind;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// The general purpose registers of a thread, captured at the moment it was stopped.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterSnapshot {
+    registers: Vec<(String, u64)>,
+}
+
+impl RegisterSnapshot {
+    pub fn new(registers: Vec<(String, u64)>) -> Self {
+        Self { registers }
+    }
+
+    /// Gets all captured registers as name and value pairs, in architecture order.
+    pub fn get_registers(&self) -> &Vec<(String, u64)> {
+        &self.registers
+    }
+
+    /// Gets the value of a register by name, ignoring case.
+    pub fn get_register(
+        &self,
+        register_name: &str,
+    ) -> Option<u64> {
+        self.registers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(register_name))
+            .map(|(_, value)| *value)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::structures::watchpoints::register_snapshot::RegisterSnapshot;
+use serde::{Deserialize, Serialize};
+
+/// A unique instruction that triggered a watchpoint, along with how often it did so.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchpointHit {
+    watchpoint_id: u32,
+    thread_id: u32,
+    instruction_pointer: u64,
+    hit_count: u64,
+    registers: RegisterSnapshot,
+}
+
+impl WatchpointHit {
+    pub fn new(
+        watchpoint_id: u32,
+        thread_id: u32,
+        instruction_pointer: u64,
+        registers: RegisterSnapshot,
+    ) -> Self {
+        Self {
+            watchpoint_id,
+            thread_id,
+            instruction_pointer,
+            hit_count: 1,
+            registers,
+        }
+    }
+
+    pub fn get_watchpoint_id(&self) -> u32 {
+        self.watchpoint_id
+    }
+
+    /// Gets the thread that most recently triggered the watchpoint from this instruction.
+    pub fn get_thread_id(&self) -> u32 {
+        self.thread_id
+    }
+
+    /// Gets the instruction pointer at the time of the hit. On x86-64, data watchpoints trap after the accessing
+    /// instruction completes, so this is the address of the instruction following the one that accessed memory.
+    pub fn get_instruction_pointer(&self) -> u64 {
+        self.instruction_pointer
+    }
+
+    pub fn get_hit_count(&self) -> u64 {
+        self.hit_count
+    }
+
+    /// Gets the registers captured during the most recent hit.
+    pub fn get_registers(&self) -> &RegisterSnapshot {
+        &self.registers
+    }
+
+    /// Records another hit from the same instruction, keeping the most recent thread and registers.
+    pub fn record_hit(
+        &mut self,
+        thread_id: u32,
+        registers: RegisterSnapshot,
+    ) {
+        self.hit_count = self.hit_count.saturating_add(1);
+        self.thread_id = thread_id;
+        self.registers = registers;
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The type of memory access that triggers a watchpoint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WatchpointKind {
+    /// Triggers when the watched memory is written.
+    #[default]
+    Write,
+    /// Triggers when the watched memory is read or written. Hardware does not support watching reads alone.
+    ReadWrite,
+}
+
+impl FromStr for WatchpointKind {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_ascii_lowercase().as_str() {
+            "w" | "write" => Ok(WatchpointKind::Write),
+            "rw" | "read-write" | "readwrite" => Ok(WatchpointKind::ReadWrite),
+            _ => Err(format!("Invalid watchpoint kind: '{}'", string)),
+        }
+    }
+}
+
+impl fmt::Display for WatchpointKind {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            WatchpointKind::Write => write!(formatter, "write"),
+            WatchpointKind::ReadWrite => write!(formatter, "read-write"),
+        }
+    }
+}
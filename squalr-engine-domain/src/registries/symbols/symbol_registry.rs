@@ -29,14 +29,15 @@ use crate::structures::{
         scan_function_scalar::{ScalarCompareFnImmediate, ScalarCompareFnRelative},
     },
 };
-use std::sync::Once;
+use std::sync::{Once, RwLock};
 use std::{collections::HashMap, sync::Arc};
 
 /// Manages a symbolic struct registry and a data type registry. All registered data types are also registered into the symbolic struct
 /// registry, since each data type is considered to be a symbol. The struct contains a single anonymous field for the corresponding type.
+/// Both registries are guarded internally, allowing plugins to register additional data types after construction.
 pub struct SymbolRegistry {
-    symbolic_struct_registry: HashMap<String, Arc<SymbolicStructDefinition>>,
-    data_type_registry: HashMap<String, Arc<dyn DataType>>,
+    symbolic_struct_registry: RwLock<HashMap<String, Arc<SymbolicStructDefinition>>>,
+    data_type_registry: RwLock<HashMap<String, Arc<dyn DataType>>>,
 }
 
 impl SymbolRegistry {
@@ -60,41 +61,124 @@ impl SymbolRegistry {
         let (symbolic_struct_registry, data_type_registry) = Self::create_built_in_registries();
 
         Self {
-            symbolic_struct_registry,
-            data_type_registry,
+            symbolic_struct_registry: RwLock::new(symbolic_struct_registry),
+            data_type_registry: RwLock::new(data_type_registry),
         }
     }
 
-    pub fn get_registry(&self) -> &HashMap<String, Arc<SymbolicStructDefinition>> {
-        &self.symbolic_struct_registry
+    /// Gets a snapshot of all registered symbolic structs.
+    pub fn get_registry(&self) -> HashMap<String, Arc<SymbolicStructDefinition>> {
+        match self.symbolic_struct_registry.read() {
+            Ok(symbolic_struct_registry) => symbolic_struct_registry.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire symbolic struct registry read lock: {}", error);
+                HashMap::new()
+            }
+        }
     }
 
     pub fn get(
         &self,
         symbolic_struct_ref_id: &str,
     ) -> Option<Arc<SymbolicStructDefinition>> {
-        if let Some(symbolic_struct_definition) = self.symbolic_struct_registry.get(symbolic_struct_ref_id.trim()) {
-            Some(symbolic_struct_definition.clone())
-        } else {
+        let symbolic_struct_definition = match self.symbolic_struct_registry.read() {
+            Ok(symbolic_struct_registry) => symbolic_struct_registry
+                .get(symbolic_struct_ref_id.trim())
+                .cloned(),
+            Err(error) => {
+                log::error!("Failed to acquire symbolic struct registry read lock: {}", error);
+                None
+            }
+        };
+
+        if symbolic_struct_definition.is_none() {
             log::warn!("Failed to find symbolic struct in registry: {}", symbolic_struct_ref_id);
-            None
         }
+
+        symbolic_struct_definition
     }
 
-    pub fn get_data_type_registry(&self) -> &HashMap<String, Arc<dyn DataType>> {
-        &self.data_type_registry
+    /// Gets a snapshot of all registered data types.
+    pub fn get_data_type_registry(&self) -> HashMap<String, Arc<dyn DataType>> {
+        match self.data_type_registry.read() {
+            Ok(data_type_registry) => data_type_registry.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire data type registry read lock: {}", error);
+                HashMap::new()
+            }
+        }
     }
 
     pub fn get_data_type(
         &self,
         data_type_id: &str,
     ) -> Option<Arc<dyn DataType>> {
-        if let Some(data_type) = self.data_type_registry.get(data_type_id.trim()) {
-            Some(data_type.clone())
-        } else {
+        let data_type = match self.data_type_registry.read() {
+            Ok(data_type_registry) => data_type_registry.get(data_type_id.trim()).cloned(),
+            Err(error) => {
+                log::error!("Failed to acquire data type registry read lock: {}", error);
+                None
+            }
+        };
+
+        if data_type.is_none() {
             log::warn!("Failed to find data type in registry: {}", data_type_id);
-            None
         }
+
+        data_type
+    }
+
+    /// Registers a data type at runtime, along with its single field symbolic struct. Fails if the identifier is already taken.
+    pub fn register_data_type(
+        &self,
+        data_type: Arc<dyn DataType>,
+    ) -> Result<(), SymbolRegistryError> {
+        let data_type_id = data_type.get_data_type_id().trim().to_string();
+
+        if data_type_id.is_empty() {
+            return Err(SymbolRegistryError::invalid_data_type_id("register data type", data_type_id));
+        }
+
+        let mut data_type_registry = self
+            .data_type_registry
+            .write()
+            .map_err(|error| SymbolRegistryError::lock_failure("register data type", error.to_string()))?;
+        let mut symbolic_struct_registry = self
+            .symbolic_struct_registry
+            .write()
+            .map_err(|error| SymbolRegistryError::lock_failure("register data type", error.to_string()))?;
+
+        if data_type_registry.contains_key(&data_type_id) || symbolic_struct_registry.contains_key(&data_type_id) {
+            return Err(SymbolRegistryError::data_type_already_registered("register data type", data_type_id));
+        }
+
+        symbolic_struct_registry.insert(data_type_id.clone(), Self::create_data_type_symbolic_struct(&data_type_id));
+        data_type_registry.insert(data_type_id, data_type);
+
+        Ok(())
+    }
+
+    /// Removes a runtime registered data type, returning it if it was registered.
+    pub fn unregister_data_type(
+        &self,
+        data_type_id: &str,
+    ) -> Result<Option<Arc<dyn DataType>>, SymbolRegistryError> {
+        let data_type_id = data_type_id.trim();
+        let mut data_type_registry = self
+            .data_type_registry
+            .write()
+            .map_err(|error| SymbolRegistryError::lock_failure("unregister data type", error.to_string()))?;
+        let mut symbolic_struct_registry = self
+            .symbolic_struct_registry
+            .write()
+            .map_err(|error| SymbolRegistryError::lock_failure("unregister data type", error.to_string()))?;
+        let removed_data_type = data_type_registry.remove(data_type_id);
+
+        if removed_data_type.is_some() {
+            symbolic_struct_registry.remove(data_type_id);
+        }
+
+        Ok(removed_data_type)
     }
 
     /// Determines if the `DataType` this struct represents is currently registered and available.
@@ -222,6 +306,17 @@ impl SymbolRegistry {
         }
     }
 
+    /// Gets a value indicating whether this data type provides SIMD comparisons, or must be scanned with scalar comparisons.
+    pub fn supports_vector_comparisons(
+        &self,
+        data_type_ref: &DataTypeRef,
+    ) -> bool {
+        match self.get_data_type(data_type_ref.get_data_type_id()) {
+            Some(data_type) => data_type.supports_vector_comparisons(),
+            None => false,
+        }
+    }
+
     /// Gets a value indicating whether this value is discrete, ie non-floating point.
     pub fn is_floating_point(
         &self,
@@ -378,18 +473,19 @@ impl SymbolRegistry {
             let data_type_id = built_in_data_type.get_data_type_id().to_string();
 
             // Create a single field symbolic struct for every registered data type.
-            symbolic_struct_registry.insert(
-                data_type_id.clone(),
-                Arc::new(SymbolicStructDefinition::new_anonymous(vec![SymbolicFieldDefinition::new(
-                    DataTypeRef::new(&data_type_id),
-                    ContainerType::None,
-                )])),
-            );
+            symbolic_struct_registry.insert(data_type_id.clone(), Self::create_data_type_symbolic_struct(&data_type_id));
             data_type_registry.insert(data_type_id, built_in_data_type);
         }
 
         (symbolic_struct_registry, data_type_registry)
     }
+
+    fn create_data_type_symbolic_struct(data_type_id: &str) -> Arc<SymbolicStructDefinition> {
+        Arc::new(SymbolicStructDefinition::new_anonymous(vec![SymbolicFieldDefinition::new(
+            DataTypeRef::new(data_type_id),
+            ContainerType::None,
+        )]))
+    }
 }
 
 impl SymbolResolver for SymbolRegistry {
@@ -5,6 +5,12 @@ use thiserror::Error;
 pub enum SymbolRegistryError {
     #[error("Cannot {operation_context}: data type `{data_type_id}` is not registered.")]
     DataTypeNotRegistered { operation_context: &'static str, data_type_id: String },
+    #[error("Cannot {operation_context}: data type `{data_type_id}` is already registered.")]
+    DataTypeAlreadyRegistered { operation_context: &'static str, data_type_id: String },
+    #[error("Cannot {operation_context}: `{data_type_id}` is not a valid data type identifier.")]
+    InvalidDataTypeId { operation_context: &'static str, data_type_id: String },
+    #[error("Failed to acquire symbol registry lock while {operation_context}: {error}.")]
+    LockFailure { operation_context: &'static str, error: String },
     #[error("Failed while {operation_context}: {source}.")]
     DataTypeOperationFailed {
         operation_context: &'static str,
@@ -24,6 +30,36 @@ impl SymbolRegistryError {
         }
     }
 
+    pub fn data_type_already_registered(
+        operation_context: &'static str,
+        data_type_id: impl Into<String>,
+    ) -> Self {
+        Self::DataTypeAlreadyRegistered {
+            operation_context,
+            data_type_id: data_type_id.into(),
+        }
+    }
+
+    pub fn invalid_data_type_id(
+        operation_context: &'static str,
+        data_type_id: impl Into<String>,
+    ) -> Self {
+        Self::InvalidDataTypeId {
+            operation_context,
+            data_type_id: data_type_id.into(),
+        }
+    }
+
+    pub fn lock_failure(
+        operation_context: &'static str,
+        error: impl Into<String>,
+    ) -> Self {
+        Self::LockFailure {
+            operation_context,
+            error: error.into(),
+        }
+    }
+
     pub fn data_type_operation_failed(
         operation_context: &'static str,
         source: DataTypeError,
@@ -54,6 +54,12 @@ pub trait DataType: Debug + Send + Sync + ScalarComparable + VectorComparable {
     /// Gets a value indicating whether this value is unsigned.
     fn is_signed(&self) -> bool;
 
+    /// Gets a value indicating whether this type provides SIMD comparisons. Types that do not (ie plugin-defined types) are always
+    /// scanned with their scalar comparisons, and are never remapped to other primitive types.
+    fn supports_vector_comparisons(&self) -> bool {
+        true
+    }
+
     fn get_default_value(
         &self,
         data_type_ref: DataTypeRef,
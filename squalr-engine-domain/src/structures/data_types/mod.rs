@@ -6,3 +6,4 @@ pub mod data_type_ref;
 pub mod data_type_sizing_data;
 pub mod floating_point_tolerance;
 pub mod generics;
+pub mod plugin_types;
@@ -0,0 +1,496 @@
+use crate::structures::data_types::data_type::DataType;
+use crate::structures::data_types::data_type_error::DataTypeError;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_types::plugin_types::data_type_plugin_abi::{
+    DATA_TYPE_PLUGIN_FLAG_BIG_ENDIAN, DATA_TYPE_PLUGIN_FLAG_FLOATING_POINT, DATA_TYPE_PLUGIN_FLAG_SIGNED, DATA_TYPE_PLUGIN_STATUS_BUFFER_TOO_SMALL,
+    DATA_TYPE_PLUGIN_STATUS_OK, DataTypePluginCompareFn, DataTypePluginCompareOperation, DataTypePluginDescriptor, DataTypePluginFormatFn,
+    DataTypePluginParseFn, DataTypePluginValueFormat,
+};
+use crate::structures::data_types::plugin_types::data_type_plugin_error::DataTypePluginError;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use crate::structures::data_values::container_type::ContainerType;
+use crate::structures::data_values::data_value::DataValue;
+use crate::structures::memory::endian::Endian;
+use std::any::Any;
+use std::ffi::{CStr, c_char};
+use std::fmt;
+use std::sync::Arc;
+
+/// The initial buffer size used when asking a plugin to format a single value.
+const FORMAT_BUFFER_SIZE: usize = 64;
+
+/// Adapts a data type exported by a plugin over the C ABI to the `DataType` trait.
+pub struct DataTypePlugin {
+    data_type_id: String,
+    icon_id: String,
+    display_name: String,
+    unit_size_in_bytes: u64,
+    flags: u32,
+    supported_value_formats: u32,
+    default_value_format: DataTypePluginValueFormat,
+    supported_compare_operations: u32,
+    parse_value: DataTypePluginParseFn,
+    format_value: DataTypePluginFormatFn,
+    compare: DataTypePluginCompareFn,
+    /// Keeps the library that owns the callbacks loaded for as long as this data type exists.
+    _library_handle: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl DataTypePlugin {
+    /// Creates a data type from a plugin descriptor, copying all metadata out of plugin owned memory.
+    ///
+    /// # Safety
+    /// All string pointers in the descriptor must be null or point to valid NUL terminated strings, and the callbacks
+    /// must remain valid for as long as `library_handle` is alive.
+    pub unsafe fn from_descriptor(
+        plugin_path: &str,
+        descriptor_index: usize,
+        descriptor: &DataTypePluginDescriptor,
+        library_handle: Option<Arc<dyn Any + Send + Sync>>,
+    ) -> Result<Self, DataTypePluginError> {
+        let data_type_id = unsafe { Self::read_c_string(descriptor.data_type_id) }
+            .ok_or_else(|| DataTypePluginError::invalid_descriptor(plugin_path, descriptor_index, "the data type id is missing or is not UTF-8"))?;
+
+        if data_type_id.trim().is_empty() {
+            return Err(DataTypePluginError::invalid_descriptor(
+                plugin_path,
+                descriptor_index,
+                "the data type id is empty",
+            ));
+        }
+
+        if descriptor.unit_size_in_bytes == 0 {
+            return Err(DataTypePluginError::invalid_descriptor(
+                plugin_path,
+                descriptor_index,
+                "the unit size must be greater than zero",
+            ));
+        }
+
+        let default_value_format = DataTypePluginValueFormat::from_raw(descriptor.default_value_format)
+            .filter(|value_format| descriptor.supported_value_formats & (1 << (*value_format as u32)) != 0)
+            .ok_or_else(|| DataTypePluginError::invalid_descriptor(plugin_path, descriptor_index, "the default value format is not supported"))?;
+        let icon_id = unsafe { Self::read_c_string(descriptor.icon_id) }.unwrap_or_else(|| data_type_id.clone());
+        let display_name = unsafe { Self::read_c_string(descriptor.display_name) }.unwrap_or_else(|| data_type_id.clone());
+
+        Ok(Self {
+            data_type_id,
+            icon_id,
+            display_name,
+            unit_size_in_bytes: descriptor.unit_size_in_bytes,
+            flags: descriptor.flags,
+            supported_value_formats: descriptor.supported_value_formats,
+            default_value_format,
+            supported_compare_operations: descriptor.supported_compare_operations,
+            parse_value: descriptor.parse_value,
+            format_value: descriptor.format_value,
+            compare: descriptor.compare,
+            _library_handle: library_handle,
+        })
+    }
+
+    /// Gets the human readable name for this data type, as shown in data type selectors.
+    pub fn get_display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn supports_compare_operation(
+        &self,
+        compare_operation: DataTypePluginCompareOperation,
+    ) -> bool {
+        self.supported_compare_operations & compare_operation.get_mask_bit() != 0
+    }
+
+    pub(crate) fn get_unit_size_in_bytes_internal(&self) -> u64 {
+        self.unit_size_in_bytes
+    }
+
+    pub(crate) fn get_compare_fn(&self) -> DataTypePluginCompareFn {
+        self.compare
+    }
+
+    fn supports_value_format(
+        &self,
+        value_format: DataTypePluginValueFormat,
+    ) -> bool {
+        self.supported_value_formats & (1 << (value_format as u32)) != 0
+    }
+
+    fn get_value_format(
+        &self,
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> Result<DataTypePluginValueFormat, DataTypeError> {
+        match DataTypePluginValueFormat::from_anonymous_value_string_format(anonymous_value_string_format) {
+            Some(value_format) if self.supports_value_format(value_format) => Ok(value_format),
+            _ => Err(DataTypeError::UnsupportedDisplayType),
+        }
+    }
+
+    fn parse_element(
+        &self,
+        value_string: &str,
+        value_format: DataTypePluginValueFormat,
+    ) -> Result<Vec<u8>, DataTypeError> {
+        let mut value_bytes = vec![0u8; self.unit_size_in_bytes as usize];
+        let status = unsafe { (self.parse_value)(value_string.as_ptr(), value_string.len(), value_format as u32, value_bytes.as_mut_ptr()) };
+
+        if status != DATA_TYPE_PLUGIN_STATUS_OK {
+            return Err(DataTypeError::ParseError(format!(
+                "Plugin data type `{}` failed to parse value '{}' (status {}).",
+                self.data_type_id, value_string, status
+            )));
+        }
+
+        Ok(value_bytes)
+    }
+
+    fn format_element(
+        &self,
+        element_bytes: &[u8],
+        value_format: DataTypePluginValueFormat,
+    ) -> Result<String, DataTypeError> {
+        let mut buffer = vec![0u8; FORMAT_BUFFER_SIZE];
+
+        loop {
+            let mut length = 0usize;
+            let status = unsafe { (self.format_value)(element_bytes.as_ptr(), value_format as u32, buffer.as_mut_ptr(), buffer.len(), &mut length) };
+
+            match status {
+                DATA_TYPE_PLUGIN_STATUS_OK if length <= buffer.len() => {
+                    buffer.truncate(length);
+
+                    return String::from_utf8(buffer).map_err(|error| DataTypeError::DecodingError { error: error.to_string() });
+                }
+                DATA_TYPE_PLUGIN_STATUS_BUFFER_TOO_SMALL if length > buffer.len() => buffer.resize(length, 0),
+                _ => {
+                    return Err(DataTypeError::DecodingError {
+                        error: format!("Plugin data type `{}` failed to format value (status {}).", self.data_type_id, status),
+                    });
+                }
+            }
+        }
+    }
+
+    unsafe fn read_c_string(c_string: *const c_char) -> Option<String> {
+        if c_string.is_null() {
+            return None;
+        }
+
+        unsafe { CStr::from_ptr(c_string) }
+            .to_str()
+            .ok()
+            .map(str::to_string)
+    }
+}
+
+impl fmt::Debug for DataTypePlugin {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        formatter
+            .debug_struct("DataTypePlugin")
+            .field("data_type_id", &self.data_type_id)
+            .field("display_name", &self.display_name)
+            .field("unit_size_in_bytes", &self.unit_size_in_bytes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DataType for DataTypePlugin {
+    fn get_data_type_id(&self) -> &str {
+        &self.data_type_id
+    }
+
+    fn get_icon_id(&self) -> &str {
+        &self.icon_id
+    }
+
+    fn get_unit_size_in_bytes(&self) -> u64 {
+        self.unit_size_in_bytes
+    }
+
+    fn validate_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> bool {
+        self.deanonymize_value_string(anonymous_value_string).is_ok()
+    }
+
+    fn deanonymize_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Result<DataValue, DataTypeError> {
+        let value_format = self.get_value_format(anonymous_value_string.get_anonymous_value_string_format())?;
+        let value_string = anonymous_value_string.get_anonymous_value_string();
+        let value_bytes = match anonymous_value_string.get_container_type() {
+            ContainerType::None => self.parse_element(value_string.trim(), value_format)?,
+            ContainerType::Array | ContainerType::ArrayFixed(_) => {
+                let mut value_bytes = vec![];
+
+                for element_string in value_string.split(',') {
+                    value_bytes.extend(self.parse_element(element_string.trim(), value_format)?);
+                }
+
+                value_bytes
+            }
+            container_type => return Err(DataTypeError::UnsupportedContainerType { container_type }),
+        };
+
+        Ok(DataValue::new(self.get_ref(), value_bytes))
+    }
+
+    fn anonymize_value_bytes(
+        &self,
+        value_bytes: &[u8],
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> Result<AnonymousValueString, DataTypeError> {
+        let value_format = self.get_value_format(anonymous_value_string_format)?;
+        let unit_size_in_bytes = self.unit_size_in_bytes as usize;
+
+        if value_bytes.is_empty() {
+            return Err(DataTypeError::NoBytes);
+        }
+
+        if !value_bytes.len().is_multiple_of(unit_size_in_bytes) {
+            return Err(DataTypeError::InvalidByteCount {
+                expected: self.unit_size_in_bytes,
+                actual: value_bytes.len() as u64,
+            });
+        }
+
+        let mut result_strings = vec![];
+
+        for element_bytes in value_bytes.chunks_exact(unit_size_in_bytes) {
+            result_strings.push(self.format_element(element_bytes, value_format)?);
+        }
+
+        let container_type = if result_strings.len() > 1 {
+            ContainerType::ArrayFixed(result_strings.len() as u64)
+        } else {
+            ContainerType::None
+        };
+
+        Ok(AnonymousValueString::new(
+            result_strings.join(", "),
+            anonymous_value_string_format,
+            container_type,
+        ))
+    }
+
+    fn get_supported_anonymous_value_string_formats(&self) -> Vec<AnonymousValueStringFormat> {
+        DataTypePluginValueFormat::ALL
+            .into_iter()
+            .filter(|value_format| self.supports_value_format(*value_format))
+            .map(|value_format| value_format.to_anonymous_value_string_format())
+            .collect()
+    }
+
+    fn get_default_anonymous_value_string_format(&self) -> AnonymousValueStringFormat {
+        self.default_value_format.to_anonymous_value_string_format()
+    }
+
+    fn get_endian(&self) -> Endian {
+        if self.flags & DATA_TYPE_PLUGIN_FLAG_BIG_ENDIAN != 0 {
+            Endian::Big
+        } else {
+            Endian::Little
+        }
+    }
+
+    fn is_floating_point(&self) -> bool {
+        self.flags & DATA_TYPE_PLUGIN_FLAG_FLOATING_POINT != 0
+    }
+
+    fn is_signed(&self) -> bool {
+        self.flags & DATA_TYPE_PLUGIN_FLAG_SIGNED != 0
+    }
+
+    fn supports_vector_comparisons(&self) -> bool {
+        false
+    }
+
+    fn get_default_value(
+        &self,
+        data_type_ref: DataTypeRef,
+    ) -> DataValue {
+        DataValue::new(data_type_ref, vec![0u8; self.unit_size_in_bytes as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DataTypePlugin;
+    use crate::structures::data_types::comparisons::scalar_comparable::ScalarComparable;
+    use crate::structures::data_types::data_type::DataType;
+    use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+    use crate::structures::data_types::plugin_types::data_type_plugin_abi::{
+        DATA_TYPE_PLUGIN_FLAG_SIGNED, DATA_TYPE_PLUGIN_STATUS_BUFFER_TOO_SMALL, DATA_TYPE_PLUGIN_STATUS_INVALID_VALUE, DATA_TYPE_PLUGIN_STATUS_OK,
+        DataTypePluginCompareOperation, DataTypePluginDescriptor, DataTypePluginValueFormat,
+    };
+    use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use crate::structures::data_values::container_type::ContainerType;
+    use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+    use crate::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+    use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
+    use std::ffi::c_char;
+    use std::ptr;
+
+    /// A 16.16 signed fixed point type, as a plugin would implement it.
+    unsafe extern "C" fn parse_fixed(
+        value_string: *const u8,
+        value_string_length: usize,
+        _value_format: u32,
+        out_value_bytes: *mut u8,
+    ) -> i32 {
+        let value_string = unsafe { std::slice::from_raw_parts(value_string, value_string_length) };
+
+        match std::str::from_utf8(value_string)
+            .ok()
+            .and_then(|value| value.parse::<f64>().ok())
+        {
+            Some(value) => {
+                let raw_value = ((value * 65536.0) as i32).to_le_bytes();
+                unsafe { ptr::copy_nonoverlapping(raw_value.as_ptr(), out_value_bytes, raw_value.len()) };
+                DATA_TYPE_PLUGIN_STATUS_OK
+            }
+            None => DATA_TYPE_PLUGIN_STATUS_INVALID_VALUE,
+        }
+    }
+
+    unsafe extern "C" fn format_fixed(
+        value_bytes: *const u8,
+        _value_format: u32,
+        out_string: *mut u8,
+        out_capacity: usize,
+        out_length: *mut usize,
+    ) -> i32 {
+        let raw_value = unsafe { ptr::read_unaligned(value_bytes as *const i32) };
+        let value_string = format!("{}", raw_value as f64 / 65536.0);
+
+        unsafe { *out_length = value_string.len() };
+
+        if value_string.len() > out_capacity {
+            return DATA_TYPE_PLUGIN_STATUS_BUFFER_TOO_SMALL;
+        }
+
+        unsafe { ptr::copy_nonoverlapping(value_string.as_ptr(), out_string, value_string.len()) };
+        DATA_TYPE_PLUGIN_STATUS_OK
+    }
+
+    unsafe extern "C" fn compare_fixed(
+        compare_operation: u32,
+        current: *const u8,
+        _previous: *const u8,
+        operand: *const u8,
+    ) -> u8 {
+        let current = unsafe { ptr::read_unaligned(current as *const i32) };
+        let operand = unsafe { ptr::read_unaligned(operand as *const i32) };
+
+        match compare_operation {
+            operation if operation == DataTypePluginCompareOperation::Equal as u32 => (current == operand) as u8,
+            operation if operation == DataTypePluginCompareOperation::GreaterThan as u32 => (current > operand) as u8,
+            _ => 0,
+        }
+    }
+
+    fn create_descriptor(data_type_id: *const c_char) -> DataTypePluginDescriptor {
+        DataTypePluginDescriptor {
+            data_type_id,
+            icon_id: ptr::null(),
+            display_name: c"Fixed 16.16".as_ptr(),
+            unit_size_in_bytes: 4,
+            flags: DATA_TYPE_PLUGIN_FLAG_SIGNED,
+            supported_value_formats: 1 << (DataTypePluginValueFormat::Decimal as u32),
+            default_value_format: DataTypePluginValueFormat::Decimal as u32,
+            supported_compare_operations: DataTypePluginCompareOperation::Equal.get_mask_bit() | DataTypePluginCompareOperation::GreaterThan.get_mask_bit(),
+            parse_value: parse_fixed,
+            format_value: format_fixed,
+            compare: compare_fixed,
+        }
+    }
+
+    fn create_data_type() -> DataTypePlugin {
+        let descriptor = create_descriptor(c"fixed16_16".as_ptr());
+
+        unsafe { DataTypePlugin::from_descriptor("test_plugin", 0, &descriptor, None) }.expect("Descriptor should be valid.")
+    }
+
+    #[test]
+    fn from_descriptor_copies_metadata_and_defaults_icon_to_data_type_id() {
+        let data_type = create_data_type();
+
+        assert_eq!(data_type.get_data_type_id(), "fixed16_16");
+        assert_eq!(data_type.get_icon_id(), "fixed16_16");
+        assert_eq!(data_type.get_display_name(), "Fixed 16.16");
+        assert_eq!(data_type.get_unit_size_in_bytes(), 4);
+        assert!(data_type.is_signed());
+        assert!(!data_type.supports_vector_comparisons());
+        assert_eq!(
+            data_type.get_supported_anonymous_value_string_formats(),
+            vec![AnonymousValueStringFormat::Decimal]
+        );
+    }
+
+    #[test]
+    fn from_descriptor_rejects_missing_data_type_id() {
+        let descriptor = create_descriptor(ptr::null());
+
+        assert!(unsafe { DataTypePlugin::from_descriptor("test_plugin", 3, &descriptor, None) }.is_err());
+    }
+
+    #[test]
+    fn values_round_trip_through_plugin_callbacks() {
+        let data_type = create_data_type();
+        let data_value = data_type
+            .deanonymize_value_string(&AnonymousValueString::new(
+                "1.5, -2".to_string(),
+                AnonymousValueStringFormat::Decimal,
+                ContainerType::ArrayFixed(2),
+            ))
+            .expect("Value should parse.");
+        let anonymous_value_string = data_type
+            .anonymize_value_bytes(data_value.get_value_bytes(), AnonymousValueStringFormat::Decimal)
+            .expect("Value should format.");
+
+        assert_eq!(data_value.get_value_bytes().len(), 8);
+        assert_eq!(anonymous_value_string.get_anonymous_value_string(), "1.5, -2");
+        assert_eq!(anonymous_value_string.get_container_type(), ContainerType::ArrayFixed(2));
+        assert!(
+            data_type
+                .anonymize_value_bytes(data_value.get_value_bytes(), AnonymousValueStringFormat::Hexadecimal)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn scalar_comparisons_call_into_plugin_and_respect_supported_operations() {
+        let data_type = create_data_type();
+        let data_value = data_type
+            .deanonymize_value_string(&AnonymousValueString::new(
+                "1.5".to_string(),
+                AnonymousValueStringFormat::Decimal,
+                ContainerType::None,
+            ))
+            .expect("Value should parse.");
+        let scan_constraint = ScanConstraint::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            data_value.clone(),
+            FloatingPointTolerance::default(),
+        );
+        let compare_equal = data_type
+            .get_compare_equal(&scan_constraint)
+            .expect("Equal should be supported.");
+        let compare_greater_than = data_type
+            .get_compare_greater_than(&scan_constraint)
+            .expect("Greater than should be supported.");
+        let larger_value = (2i32 * 65536).to_le_bytes();
+
+        assert!(compare_equal(data_value.as_ptr()));
+        assert!(!compare_equal(larger_value.as_ptr()));
+        assert!(compare_greater_than(larger_value.as_ptr()));
+        assert!(data_type.get_compare_less_than(&scan_constraint).is_none());
+    }
+}
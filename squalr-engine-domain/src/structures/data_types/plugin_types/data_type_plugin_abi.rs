@@ -0,0 +1,198 @@
+use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use crate::structures::scanning::comparisons::scan_compare_type_delta::ScanCompareTypeDelta;
+use crate::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use crate::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
+use std::ffi::c_char;
+
+/// The C ABI version that data type plugins are built against. This must be bumped whenever the layout or semantics
+/// of any `#[repr(C)]` type or function signature in this file changes. Plugins reporting a different version are rejected.
+pub const DATA_TYPE_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// The NUL terminated name of the symbol that every data type plugin library must export, with the signature `DataTypePluginEntryFn`.
+pub const DATA_TYPE_PLUGIN_ENTRY_SYMBOL: &[u8] = b"squalr_data_type_plugin_entry\0";
+
+/// Status code returned by plugin callbacks on success.
+pub const DATA_TYPE_PLUGIN_STATUS_OK: i32 = 0;
+
+/// Status code returned by plugin callbacks when a value could not be parsed or formatted.
+pub const DATA_TYPE_PLUGIN_STATUS_INVALID_VALUE: i32 = 1;
+
+/// Status code returned by the format callback when the output buffer is too small. The required length must be written to `out_length`.
+pub const DATA_TYPE_PLUGIN_STATUS_BUFFER_TOO_SMALL: i32 = 2;
+
+/// Descriptor flag indicating that the data type is signed.
+pub const DATA_TYPE_PLUGIN_FLAG_SIGNED: u32 = 1 << 0;
+
+/// Descriptor flag indicating that the data type is floating point.
+pub const DATA_TYPE_PLUGIN_FLAG_FLOATING_POINT: u32 = 1 << 1;
+
+/// Descriptor flag indicating that the data type is big endian.
+pub const DATA_TYPE_PLUGIN_FLAG_BIG_ENDIAN: u32 = 1 << 2;
+
+/// The entry point exported by a plugin library. The returned manifest, and all memory it points to, must remain valid until the library is unloaded.
+pub type DataTypePluginEntryFn = unsafe extern "C" fn() -> *const DataTypePluginManifest;
+
+/// Parses `value_string_length` bytes of UTF-8 (not NUL terminated) in the given `DataTypePluginValueFormat` into `out_value_bytes`,
+/// which is exactly `unit_size_in_bytes` long. Returns a `DATA_TYPE_PLUGIN_STATUS_*` code.
+pub type DataTypePluginParseFn = unsafe extern "C" fn(value_string: *const u8, value_string_length: usize, value_format: u32, out_value_bytes: *mut u8) -> i32;
+
+/// Formats `unit_size_in_bytes` bytes at `value_bytes` as UTF-8 into `out_string` using the given `DataTypePluginValueFormat`.
+/// Writes the formatted length to `out_length`, returning `DATA_TYPE_PLUGIN_STATUS_BUFFER_TOO_SMALL` if it exceeds `out_capacity`.
+pub type DataTypePluginFormatFn =
+    unsafe extern "C" fn(value_bytes: *const u8, value_format: u32, out_string: *mut u8, out_capacity: usize, out_length: *mut usize) -> i32;
+
+/// Performs a `DataTypePluginCompareOperation` over values of `unit_size_in_bytes` bytes each. Immediate comparisons receive the scan value
+/// as `operand` and a null `previous`. Relative comparisons receive a null `operand`. Delta comparisons receive all three. Returns nonzero on match.
+pub type DataTypePluginCompareFn = unsafe extern "C" fn(compare_operation: u32, current: *const u8, previous: *const u8, operand: *const u8) -> u8;
+
+/// Describes a plugin library and the data types that it provides.
+#[repr(C)]
+pub struct DataTypePluginManifest {
+    /// Must equal `DATA_TYPE_PLUGIN_ABI_VERSION`.
+    pub abi_version: u32,
+    /// NUL terminated UTF-8 name of the plugin.
+    pub plugin_name: *const c_char,
+    /// Pointer to `data_type_count` contiguous data type descriptors.
+    pub data_types: *const DataTypePluginDescriptor,
+    pub data_type_count: usize,
+}
+
+/// Describes a single plugin-defined data type, including its metadata and the callbacks that implement it.
+#[repr(C)]
+pub struct DataTypePluginDescriptor {
+    /// NUL terminated UTF-8 unique identifier for the data type, ie `fixed16_16`.
+    pub data_type_id: *const c_char,
+    /// NUL terminated UTF-8 icon identifier. May be null, in which case the data type identifier is used.
+    pub icon_id: *const c_char,
+    /// NUL terminated UTF-8 name shown in data type selectors. May be null, in which case the data type identifier is used.
+    pub display_name: *const c_char,
+    pub unit_size_in_bytes: u64,
+    /// Combination of `DATA_TYPE_PLUGIN_FLAG_*` values.
+    pub flags: u32,
+    /// Bitmask of supported formats, where bit N corresponds to `DataTypePluginValueFormat` N.
+    pub supported_value_formats: u32,
+    /// The `DataTypePluginValueFormat` used when displaying values by default.
+    pub default_value_format: u32,
+    /// Bitmask of supported comparisons, where bit N corresponds to `DataTypePluginCompareOperation` N.
+    pub supported_compare_operations: u32,
+    pub parse_value: DataTypePluginParseFn,
+    pub format_value: DataTypePluginFormatFn,
+    pub compare: DataTypePluginCompareFn,
+}
+
+/// The value formats that may cross the plugin ABI. Discriminants are part of the ABI and must never be reordered.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataTypePluginValueFormat {
+    Bool = 0,
+    String = 1,
+    Binary = 2,
+    Decimal = 3,
+    Hexadecimal = 4,
+    Address = 5,
+}
+
+impl DataTypePluginValueFormat {
+    pub const ALL: [DataTypePluginValueFormat; 6] = [
+        DataTypePluginValueFormat::Bool,
+        DataTypePluginValueFormat::String,
+        DataTypePluginValueFormat::Binary,
+        DataTypePluginValueFormat::Decimal,
+        DataTypePluginValueFormat::Hexadecimal,
+        DataTypePluginValueFormat::Address,
+    ];
+
+    pub fn from_raw(raw_value_format: u32) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|value_format| *value_format as u32 == raw_value_format)
+    }
+
+    pub fn from_anonymous_value_string_format(anonymous_value_string_format: AnonymousValueStringFormat) -> Option<Self> {
+        match anonymous_value_string_format {
+            AnonymousValueStringFormat::Bool => Some(DataTypePluginValueFormat::Bool),
+            AnonymousValueStringFormat::String => Some(DataTypePluginValueFormat::String),
+            AnonymousValueStringFormat::Binary => Some(DataTypePluginValueFormat::Binary),
+            AnonymousValueStringFormat::Decimal => Some(DataTypePluginValueFormat::Decimal),
+            AnonymousValueStringFormat::Hexadecimal => Some(DataTypePluginValueFormat::Hexadecimal),
+            AnonymousValueStringFormat::Address => Some(DataTypePluginValueFormat::Address),
+            AnonymousValueStringFormat::DataTypeRef | AnonymousValueStringFormat::Enumeration => None,
+        }
+    }
+
+    pub fn to_anonymous_value_string_format(&self) -> AnonymousValueStringFormat {
+        match self {
+            DataTypePluginValueFormat::Bool => AnonymousValueStringFormat::Bool,
+            DataTypePluginValueFormat::String => AnonymousValueStringFormat::String,
+            DataTypePluginValueFormat::Binary => AnonymousValueStringFormat::Binary,
+            DataTypePluginValueFormat::Decimal => AnonymousValueStringFormat::Decimal,
+            DataTypePluginValueFormat::Hexadecimal => AnonymousValueStringFormat::Hexadecimal,
+            DataTypePluginValueFormat::Address => AnonymousValueStringFormat::Address,
+        }
+    }
+}
+
+/// The comparisons that may cross the plugin ABI. Discriminants are part of the ABI and must never be reordered.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataTypePluginCompareOperation {
+    Equal = 0,
+    NotEqual = 1,
+    GreaterThan = 2,
+    GreaterThanOrEqual = 3,
+    LessThan = 4,
+    LessThanOrEqual = 5,
+    Changed = 6,
+    Unchanged = 7,
+    Increased = 8,
+    Decreased = 9,
+    IncreasedBy = 10,
+    DecreasedBy = 11,
+    MultipliedBy = 12,
+    DividedBy = 13,
+    ModuloBy = 14,
+    ShiftLeftBy = 15,
+    ShiftRightBy = 16,
+    LogicalAndBy = 17,
+    LogicalOrBy = 18,
+    LogicalXorBy = 19,
+}
+
+impl DataTypePluginCompareOperation {
+    pub fn from_scan_compare_type(scan_compare_type: ScanCompareType) -> Self {
+        match scan_compare_type {
+            ScanCompareType::Immediate(scan_compare_type_immediate) => match scan_compare_type_immediate {
+                ScanCompareTypeImmediate::Equal => DataTypePluginCompareOperation::Equal,
+                ScanCompareTypeImmediate::NotEqual => DataTypePluginCompareOperation::NotEqual,
+                ScanCompareTypeImmediate::GreaterThan => DataTypePluginCompareOperation::GreaterThan,
+                ScanCompareTypeImmediate::GreaterThanOrEqual => DataTypePluginCompareOperation::GreaterThanOrEqual,
+                ScanCompareTypeImmediate::LessThan => DataTypePluginCompareOperation::LessThan,
+                ScanCompareTypeImmediate::LessThanOrEqual => DataTypePluginCompareOperation::LessThanOrEqual,
+            },
+            ScanCompareType::Relative(scan_compare_type_relative) => match scan_compare_type_relative {
+                ScanCompareTypeRelative::Changed => DataTypePluginCompareOperation::Changed,
+                ScanCompareTypeRelative::Unchanged => DataTypePluginCompareOperation::Unchanged,
+                ScanCompareTypeRelative::Increased => DataTypePluginCompareOperation::Increased,
+                ScanCompareTypeRelative::Decreased => DataTypePluginCompareOperation::Decreased,
+            },
+            ScanCompareType::Delta(scan_compare_type_delta) => match scan_compare_type_delta {
+                ScanCompareTypeDelta::IncreasedByX => DataTypePluginCompareOperation::IncreasedBy,
+                ScanCompareTypeDelta::DecreasedByX => DataTypePluginCompareOperation::DecreasedBy,
+                ScanCompareTypeDelta::MultipliedByX => DataTypePluginCompareOperation::MultipliedBy,
+                ScanCompareTypeDelta::DividedByX => DataTypePluginCompareOperation::DividedBy,
+                ScanCompareTypeDelta::ModuloByX => DataTypePluginCompareOperation::ModuloBy,
+                ScanCompareTypeDelta::ShiftLeftByX => DataTypePluginCompareOperation::ShiftLeftBy,
+                ScanCompareTypeDelta::ShiftRightByX => DataTypePluginCompareOperation::ShiftRightBy,
+                ScanCompareTypeDelta::LogicalAndByX => DataTypePluginCompareOperation::LogicalAndBy,
+                ScanCompareTypeDelta::LogicalOrByX => DataTypePluginCompareOperation::LogicalOrBy,
+                ScanCompareTypeDelta::LogicalXorByX => DataTypePluginCompareOperation::LogicalXorBy,
+            },
+        }
+    }
+
+    /// Gets the bit for this operation within `DataTypePluginDescriptor::supported_compare_operations`.
+    pub fn get_mask_bit(&self) -> u32 {
+        1 << (*self as u32)
+    }
+}
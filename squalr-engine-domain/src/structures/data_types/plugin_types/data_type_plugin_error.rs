@@ -0,0 +1,46 @@
+use crate::registries::symbols::symbol_registry_error::SymbolRegistryError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DataTypePluginError {
+    #[error("Failed to load data type plugin `{plugin_path}`: {error}.")]
+    LibraryLoadFailed { plugin_path: String, error: String },
+    #[error("Data type plugin `{plugin_path}` does not export the entry point: {error}.")]
+    EntryPointMissing { plugin_path: String, error: String },
+    #[error("Data type plugin `{plugin_path}` returned a null manifest.")]
+    NullManifest { plugin_path: String },
+    #[error("Data type plugin `{plugin_path}` targets ABI version {actual_abi_version}, but version {expected_abi_version} is required.")]
+    AbiVersionMismatch {
+        plugin_path: String,
+        expected_abi_version: u32,
+        actual_abi_version: u32,
+    },
+    #[error("Data type plugin `{plugin_path}` has an invalid descriptor at index {descriptor_index}: {reason}.")]
+    InvalidDescriptor {
+        plugin_path: String,
+        descriptor_index: usize,
+        reason: String,
+    },
+    #[error("Data type plugin `{plugin_path}` is already loaded.")]
+    AlreadyLoaded { plugin_path: String },
+    #[error("Failed to register data types from plugin `{plugin_path}`: {source}")]
+    RegistrationFailed {
+        plugin_path: String,
+        #[source]
+        source: SymbolRegistryError,
+    },
+}
+
+impl DataTypePluginError {
+    pub fn invalid_descriptor(
+        plugin_path: impl Into<String>,
+        descriptor_index: usize,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::InvalidDescriptor {
+            plugin_path: plugin_path.into(),
+            descriptor_index,
+            reason: reason.into(),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Describes a plugin-defined data type without its callbacks, such that it can be sent across process boundaries for display.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataTypePluginMetadata {
+    plugin_name: String,
+    plugin_path: PathBuf,
+    data_type_id: String,
+    icon_id: String,
+    display_name: String,
+    unit_size_in_bytes: u64,
+}
+
+impl DataTypePluginMetadata {
+    pub fn new(
+        plugin_name: String,
+        plugin_path: PathBuf,
+        data_type_id: String,
+        icon_id: String,
+        display_name: String,
+        unit_size_in_bytes: u64,
+    ) -> Self {
+        Self {
+            plugin_name,
+            plugin_path,
+            data_type_id,
+            icon_id,
+            display_name,
+            unit_size_in_bytes,
+        }
+    }
+
+    pub fn get_plugin_name(&self) -> &str {
+        &self.plugin_name
+    }
+
+    pub fn get_plugin_path(&self) -> &PathBuf {
+        &self.plugin_path
+    }
+
+    pub fn get_data_type_id(&self) -> &str {
+        &self.data_type_id
+    }
+
+    pub fn get_icon_id(&self) -> &str {
+        &self.icon_id
+    }
+
+    pub fn get_display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn get_unit_size_in_bytes(&self) -> u64 {
+        self.unit_size_in_bytes
+    }
+}
@@ -0,0 +1,214 @@
+use crate::structures::data_types::comparisons::scalar_comparable::ScalarComparable;
+use crate::structures::data_types::plugin_types::data_type_plugin::DataTypePlugin;
+use crate::structures::data_types::plugin_types::data_type_plugin_abi::DataTypePluginCompareOperation;
+use crate::structures::scanning::comparisons::scan_function_scalar::{ScalarCompareFnDelta, ScalarCompareFnImmediate, ScalarCompareFnRelative};
+use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
+use std::ptr;
+use std::sync::Arc;
+
+impl DataTypePlugin {
+    /// Gets the scan constraint value as an owned operand, provided the plugin supports the comparison and the value is large enough.
+    fn get_compare_operand(
+        &self,
+        compare_operation: DataTypePluginCompareOperation,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<Arc<Vec<u8>>> {
+        if !self.supports_compare_operation(compare_operation) {
+            return None;
+        }
+
+        let operand = scan_constraint.get_data_value().get_value_bytes();
+
+        if (operand.len() as u64) < self.get_unit_size_in_bytes_internal() {
+            return None;
+        }
+
+        Some(Arc::new(operand.clone()))
+    }
+
+    fn get_compare_immediate(
+        &self,
+        compare_operation: DataTypePluginCompareOperation,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        let operand = self.get_compare_operand(compare_operation, scan_constraint)?;
+        let compare = self.get_compare_fn();
+        let compare_operation = compare_operation as u32;
+
+        Some(Arc::new(move |current_value_ptr| unsafe {
+            compare(compare_operation, current_value_ptr, ptr::null(), operand.as_ptr()) != 0
+        }))
+    }
+
+    fn get_compare_relative(
+        &self,
+        compare_operation: DataTypePluginCompareOperation,
+    ) -> Option<ScalarCompareFnRelative> {
+        if !self.supports_compare_operation(compare_operation) {
+            return None;
+        }
+
+        let compare = self.get_compare_fn();
+        let compare_operation = compare_operation as u32;
+
+        Some(Arc::new(move |current_value_ptr, previous_value_ptr| unsafe {
+            compare(compare_operation, current_value_ptr, previous_value_ptr, ptr::null()) != 0
+        }))
+    }
+
+    fn get_compare_delta(
+        &self,
+        compare_operation: DataTypePluginCompareOperation,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        let operand = self.get_compare_operand(compare_operation, scan_constraint)?;
+        let compare = self.get_compare_fn();
+        let compare_operation = compare_operation as u32;
+
+        Some(Arc::new(move |current_value_ptr, previous_value_ptr| unsafe {
+            compare(compare_operation, current_value_ptr, previous_value_ptr, operand.as_ptr()) != 0
+        }))
+    }
+}
+
+impl ScalarComparable for DataTypePlugin {
+    fn get_compare_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_immediate(DataTypePluginCompareOperation::Equal, scan_constraint)
+    }
+
+    fn get_compare_not_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_immediate(DataTypePluginCompareOperation::NotEqual, scan_constraint)
+    }
+
+    fn get_compare_greater_than(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_immediate(DataTypePluginCompareOperation::GreaterThan, scan_constraint)
+    }
+
+    fn get_compare_greater_than_or_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_immediate(DataTypePluginCompareOperation::GreaterThanOrEqual, scan_constraint)
+    }
+
+    fn get_compare_less_than(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_immediate(DataTypePluginCompareOperation::LessThan, scan_constraint)
+    }
+
+    fn get_compare_less_than_or_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_immediate(DataTypePluginCompareOperation::LessThanOrEqual, scan_constraint)
+    }
+
+    fn get_compare_changed(
+        &self,
+        _scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        self.get_compare_relative(DataTypePluginCompareOperation::Changed)
+    }
+
+    fn get_compare_unchanged(
+        &self,
+        _scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        self.get_compare_relative(DataTypePluginCompareOperation::Unchanged)
+    }
+
+    fn get_compare_increased(
+        &self,
+        _scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        self.get_compare_relative(DataTypePluginCompareOperation::Increased)
+    }
+
+    fn get_compare_decreased(
+        &self,
+        _scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        self.get_compare_relative(DataTypePluginCompareOperation::Decreased)
+    }
+
+    fn get_compare_increased_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::IncreasedBy, scan_constraint)
+    }
+
+    fn get_compare_decreased_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::DecreasedBy, scan_constraint)
+    }
+
+    fn get_compare_multiplied_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::MultipliedBy, scan_constraint)
+    }
+
+    fn get_compare_divided_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::DividedBy, scan_constraint)
+    }
+
+    fn get_compare_modulo_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::ModuloBy, scan_constraint)
+    }
+
+    fn get_compare_shift_left_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::ShiftLeftBy, scan_constraint)
+    }
+
+    fn get_compare_shift_right_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::ShiftRightBy, scan_constraint)
+    }
+
+    fn get_compare_logical_and_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::LogicalAndBy, scan_constraint)
+    }
+
+    fn get_compare_logical_or_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::LogicalOrBy, scan_constraint)
+    }
+
+    fn get_compare_logical_xor_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_delta(DataTypePluginCompareOperation::LogicalXorBy, scan_constraint)
+    }
+}
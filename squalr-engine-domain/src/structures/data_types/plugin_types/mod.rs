@@ -0,0 +1,6 @@
+pub mod data_type_plugin;
+pub mod data_type_plugin_abi;
+pub mod data_type_plugin_error;
+pub mod data_type_plugin_metadata;
+pub mod data_type_plugin_scalar_comparisons;
+pub mod data_type_plugin_vector_comparisons;
@@ -12,13 +12,15 @@ use std::fmt;
 use std::str::FromStr;
 
 /// Represents a scan constraint containing a compare type and an anonymous value.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AnonymousScanConstraint {
     scan_compare_type: ScanCompareType,
     anonymous_value_string: Option<AnonymousValueString>,
 }
 
 impl AnonymousScanConstraint {
+    const PREVIOUS_VALUE_KEYWORD: &'static str = "previous";
+
     pub fn new(
         scan_compare_type: ScanCompareType,
         anonymous_value_string: Option<AnonymousValueString>,
@@ -49,6 +51,11 @@ impl AnonymousScanConstraint {
                 Ok(data_value) => return Some(ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance)),
                 Err(error) => log::error!("Unable to parse value in anonymous constraint: {}", error),
             }
+        } else if let ScanCompareType::Relative(_) = self.scan_compare_type {
+            // Relative scans compare against previous values, so the value only serves to carry the data type.
+            return symbol_registry
+                .get_default_value(data_type_ref)
+                .map(|data_value| ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance));
         }
 
         None
@@ -82,7 +89,10 @@ impl FromStr for AnonymousScanConstraint {
 
         for (prefix, scan_compare_type, needs_value) in prefixes {
             if string.starts_with(prefix) {
-                let rest = &string[prefix.len()..].trim();
+                let rest = string[prefix.len()..].trim();
+
+                // Relative scans may explicitly name the value they compare against, ie `!= previous`.
+                let rest = if !needs_value && rest == Self::PREVIOUS_VALUE_KEYWORD { "" } else { rest };
 
                 if !needs_value && !rest.is_empty() {
                     continue; // Skip to next prefix instead of err, but actually in logic, we err only if no match at end.
@@ -91,6 +101,9 @@ impl FromStr for AnonymousScanConstraint {
                 let anonymous_value_string = if rest.is_empty() {
                     None
                 } else {
+                    // Bare values (ie `> 100`) are shorthand for decimal values without a container.
+                    let rest = if rest.contains(';') { rest.to_string() } else { format!("{};dec;", rest) };
+
                     match rest.parse::<AnonymousValueString>() {
                         Ok(anonymous_value_string) => Some(anonymous_value_string),
                         Err(error) => {
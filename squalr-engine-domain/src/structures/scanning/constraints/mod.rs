@@ -1,3 +1,4 @@
 pub mod anonymous_scan_constraint;
 pub mod scan_constraint;
+pub mod scan_constraint_expression;
 pub mod scan_constraint_finalized;
@@ -0,0 +1,233 @@
+use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use crate::structures::scanning::constraints::anonymous_scan_constraint::{AnonymousScanConstraint, ParseScanConstraintError};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Represents a boolean expression over scan constraints, ie `(> 100 && < 200) || == 0`.
+/// Each constraint is scanned independently, and the resulting filters are combined based on the operators in the tree.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ScanConstraintExpression<Constraint> {
+    Constraint(Constraint),
+    And(Vec<ScanConstraintExpression<Constraint>>),
+    Or(Vec<ScanConstraintExpression<Constraint>>),
+    Not(Box<ScanConstraintExpression<Constraint>>),
+}
+
+/// A scan constraint expression whose values have not yet been bound to a data type.
+pub type AnonymousScanConstraintExpression = ScanConstraintExpression<AnonymousScanConstraint>;
+
+impl<Constraint> ScanConstraintExpression<Constraint> {
+    /// Combines the given expressions such that all of them must pass. Returns `None` if no expressions are provided.
+    pub fn all(mut expressions: Vec<Self>) -> Option<Self> {
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(Self::And(expressions)),
+        }
+    }
+
+    /// Combines the given expressions such that any of them may pass. Returns `None` if no expressions are provided.
+    pub fn any(mut expressions: Vec<Self>) -> Option<Self> {
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(Self::Or(expressions)),
+        }
+    }
+
+    /// Maps every constraint in this expression to a new constraint type. Constraints that fail to map are dropped,
+    /// as are any operators left without operands. Returns `None` if nothing remains of the expression.
+    pub fn map_constraints<MappedConstraint, Mapper>(
+        self,
+        mapper: &mut Mapper,
+    ) -> Option<ScanConstraintExpression<MappedConstraint>>
+    where
+        Mapper: FnMut(Constraint) -> Option<MappedConstraint>,
+    {
+        match self {
+            Self::Constraint(constraint) => mapper(constraint).map(ScanConstraintExpression::Constraint),
+            Self::And(expressions) => ScanConstraintExpression::all(Self::map_operands(expressions, mapper)),
+            Self::Or(expressions) => ScanConstraintExpression::any(Self::map_operands(expressions, mapper)),
+            Self::Not(expression) => expression
+                .map_constraints(mapper)
+                .map(|expression| ScanConstraintExpression::Not(Box::new(expression))),
+        }
+    }
+
+    /// Gets the first constraint in this expression, visiting operands from left to right.
+    pub fn get_first_constraint(&self) -> Option<&Constraint> {
+        match self {
+            Self::Constraint(constraint) => Some(constraint),
+            Self::And(expressions) | Self::Or(expressions) => expressions
+                .iter()
+                .find_map(|expression| expression.get_first_constraint()),
+            Self::Not(expression) => expression.get_first_constraint(),
+        }
+    }
+
+    fn map_operands<MappedConstraint, Mapper>(
+        expressions: Vec<Self>,
+        mapper: &mut Mapper,
+    ) -> Vec<ScanConstraintExpression<MappedConstraint>>
+    where
+        Mapper: FnMut(Constraint) -> Option<MappedConstraint>,
+    {
+        expressions
+            .into_iter()
+            .filter_map(|expression| expression.map_constraints(mapper))
+            .collect()
+    }
+}
+
+impl<Constraint> From<Constraint> for ScanConstraintExpression<Constraint> {
+    fn from(constraint: Constraint) -> Self {
+        Self::Constraint(constraint)
+    }
+}
+
+/// Parses expressions such as `(> 100 && < 200) || == 0` and `!= previous && in {1, 2, 4, 8}`.
+/// Supports `&&`, `||`, `!` and parentheses, as well as `in {a, b, ..}` sets and inclusive `in [min, max]` ranges.
+/// Individual constraints use the syntax accepted by `AnonymousScanConstraint::from_str`.
+impl FromStr for ScanConstraintExpression<AnonymousScanConstraint> {
+    type Err = ParseScanConstraintError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut parser = ScanConstraintExpressionParser { remaining: string };
+        let expression = parser.parse_or()?;
+
+        if parser.remaining.trim().is_empty() {
+            Ok(expression)
+        } else {
+            Err(ParseScanConstraintError)
+        }
+    }
+}
+
+struct ScanConstraintExpressionParser<'lifetime> {
+    remaining: &'lifetime str,
+}
+
+impl<'lifetime> ScanConstraintExpressionParser<'lifetime> {
+    fn parse_or(&mut self) -> Result<AnonymousScanConstraintExpression, ParseScanConstraintError> {
+        let mut operands = vec![self.parse_and()?];
+
+        while self.consume("||") {
+            operands.push(self.parse_and()?);
+        }
+
+        ScanConstraintExpression::any(operands).ok_or(ParseScanConstraintError)
+    }
+
+    fn parse_and(&mut self) -> Result<AnonymousScanConstraintExpression, ParseScanConstraintError> {
+        let mut operands = vec![self.parse_unary()?];
+
+        while self.consume("&&") {
+            operands.push(self.parse_unary()?);
+        }
+
+        ScanConstraintExpression::all(operands).ok_or(ParseScanConstraintError)
+    }
+
+    fn parse_unary(&mut self) -> Result<AnonymousScanConstraintExpression, ParseScanConstraintError> {
+        self.remaining = self.remaining.trim_start();
+
+        // A leading `!` is a negation, unless it is the start of a `!=` comparison.
+        if self.remaining.starts_with('!') && !self.remaining.starts_with("!=") {
+            self.remaining = &self.remaining[1..];
+
+            return Ok(ScanConstraintExpression::Not(Box::new(self.parse_unary()?)));
+        }
+
+        if self.consume("(") {
+            let expression = self.parse_or()?;
+
+            return if self.consume(")") { Ok(expression) } else { Err(ParseScanConstraintError) };
+        }
+
+        if let Some(membership) = self.remaining.strip_prefix("in") {
+            let membership = membership.trim_start();
+
+            if membership.starts_with('{') || membership.starts_with('[') {
+                self.remaining = membership;
+
+                return self.parse_membership();
+            }
+        }
+
+        self.parse_constraint()
+    }
+
+    /// Parses `{a, b, ..}` into an equality check against any of the values, or `[min, max]` into an inclusive range check.
+    fn parse_membership(&mut self) -> Result<AnonymousScanConstraintExpression, ParseScanConstraintError> {
+        let is_set = self.remaining.starts_with('{');
+        let closing_delimiter = if is_set { '}' } else { ']' };
+        let end_index = self
+            .remaining
+            .find(closing_delimiter)
+            .ok_or(ParseScanConstraintError)?;
+        let values: Vec<&str> = self.remaining[1..end_index]
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .collect();
+
+        self.remaining = &self.remaining[end_index + 1..];
+
+        if is_set {
+            let operands = values
+                .iter()
+                .map(|value| Self::create_immediate_constraint("==", value))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            ScanConstraintExpression::any(operands).ok_or(ParseScanConstraintError)
+        } else {
+            match values.as_slice() {
+                [minimum, maximum] => Ok(ScanConstraintExpression::And(vec![
+                    Self::create_immediate_constraint(">=", minimum)?,
+                    Self::create_immediate_constraint("<=", maximum)?,
+                ])),
+                _ => Err(ParseScanConstraintError),
+            }
+        }
+    }
+
+    fn parse_constraint(&mut self) -> Result<AnonymousScanConstraintExpression, ParseScanConstraintError> {
+        let end_index = ["&&", "||", ")"]
+            .iter()
+            .filter_map(|delimiter| self.remaining.find(delimiter))
+            .min()
+            .unwrap_or(self.remaining.len());
+        let constraint_string = &self.remaining[..end_index];
+
+        self.remaining = &self.remaining[end_index..];
+
+        Ok(ScanConstraintExpression::Constraint(AnonymousScanConstraint::from_str(constraint_string)?))
+    }
+
+    fn create_immediate_constraint(
+        comparison_prefix: &str,
+        value: &str,
+    ) -> Result<AnonymousScanConstraintExpression, ParseScanConstraintError> {
+        let anonymous_scan_constraint = AnonymousScanConstraint::from_str(&format!("{}{}", comparison_prefix, value))?;
+
+        match anonymous_scan_constraint.get_scan_compare_type() {
+            ScanCompareType::Immediate(_) => Ok(ScanConstraintExpression::Constraint(anonymous_scan_constraint)),
+            _ => Err(ParseScanConstraintError),
+        }
+    }
+
+    fn consume(
+        &mut self,
+        token: &str,
+    ) -> bool {
+        self.remaining = self.remaining.trim_start();
+
+        match self.remaining.strip_prefix(token) {
+            Some(remaining) => {
+                self.remaining = remaining;
+                true
+            }
+            None => false,
+        }
+    }
+}
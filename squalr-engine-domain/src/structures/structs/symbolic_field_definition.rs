@@ -8,6 +8,7 @@ use crate::structures::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
+    fmt,
     str::FromStr,
     sync::{Arc, RwLock},
 };
@@ -104,6 +105,15 @@ impl SymbolicFieldDefinition {
     }
 }
 
+impl fmt::Display for SymbolicFieldDefinition {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "{}{}", self.data_type_ref, self.container_type)
+    }
+}
+
 impl FromStr for SymbolicFieldDefinition {
     type Err = String;
 
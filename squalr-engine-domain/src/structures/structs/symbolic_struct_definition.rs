@@ -3,6 +3,7 @@ use crate::structures::structs::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
+    fmt,
     str::FromStr,
     sync::{Arc, RwLock},
 };
@@ -32,6 +33,10 @@ impl SymbolicStructDefinition {
         &self.symbol_namespace
     }
 
+    pub fn get_fields(&self) -> &[SymbolicFieldDefinition] {
+        &self.fields
+    }
+
     pub fn add_field(
         &mut self,
         symbolic_struct_field: SymbolicFieldDefinition,
@@ -62,6 +67,24 @@ impl SymbolicStructDefinition {
     }
 }
 
+/// Formats the fields in the same `;` separated form that is accepted by `from_str`, such as `u8*(64);f32;i32`.
+impl fmt::Display for SymbolicStructDefinition {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for (field_index, field) in self.fields.iter().enumerate() {
+            if field_index > 0 {
+                write!(formatter, ";")?;
+            }
+
+            write!(formatter, "{}", field)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl FromStr for SymbolicStructDefinition {
     type Err = String;
 
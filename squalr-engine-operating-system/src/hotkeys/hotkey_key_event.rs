@@ -0,0 +1,26 @@
+/// A key being pressed or released, named as in key chords, for example `f1`, `ctrl` or `numpad8`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HotkeyKeyEvent {
+    key_name: String,
+    is_pressed: bool,
+}
+
+impl HotkeyKeyEvent {
+    pub fn new(
+        key_name: &str,
+        is_pressed: bool,
+    ) -> Self {
+        Self {
+            key_name: key_name.to_string(),
+            is_pressed,
+        }
+    }
+
+    pub fn get_key_name(&self) -> &str {
+        &self.key_name
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+}
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HotkeyListenerError {
+    #[error("Global hotkeys are not supported on `{platform}`.")]
+    NotSupported { platform: &'static str },
+    #[error("No readable keyboards were found. Reading input devices may require membership in the `input` group.")]
+    NoKeyboards,
+    #[error("The `{backend}` hotkey backend is unavailable: {details}.")]
+    BackendUnavailable { backend: &'static str, details: String },
+}
+
+impl HotkeyListenerError {
+    pub fn not_supported(platform: &'static str) -> Self {
+        Self::NotSupported { platform }
+    }
+
+    pub fn backend_unavailable(
+        backend: &'static str,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::BackendUnavailable {
+            backend,
+            details: details.into(),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use crate::hotkeys::hotkey_key_event::HotkeyKeyEvent;
+
+pub trait HotkeyListenerTrait: Send {
+    /// Gets the keys pressed or released since the previous poll, in the order that they occurred. Key repeats are not reported.
+    fn poll_key_events(&mut self) -> Vec<HotkeyKeyEvent>;
+}
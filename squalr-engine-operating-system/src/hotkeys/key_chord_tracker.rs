@@ -0,0 +1,71 @@
+use crate::hotkeys::hotkey_key_event::HotkeyKeyEvent;
+use squalr_engine_api::structures::hotkeys::key_chord::KeyChord;
+use std::collections::HashSet;
+
+/// Tracks held keys to turn key events into key chords. A chord is completed each time a non-modifier key is pressed, using the
+/// modifiers held at that moment, such that holding a key does not repeat its chord.
+#[derive(Default)]
+pub struct KeyChordTracker {
+    pressed_key_names: HashSet<String>,
+}
+
+impl KeyChordTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a key event, returning the chord that it completes, if any.
+    pub fn process_key_event(
+        &mut self,
+        hotkey_key_event: &HotkeyKeyEvent,
+    ) -> Option<KeyChord> {
+        let modifier_name = KeyChord::get_modifier_name(hotkey_key_event.get_key_name());
+        let key_name = match modifier_name {
+            Some(modifier_name) => modifier_name.to_string(),
+            None => hotkey_key_event.get_key_name().to_ascii_lowercase(),
+        };
+
+        if !hotkey_key_event.is_pressed() {
+            self.pressed_key_names.remove(&key_name);
+
+            return None;
+        }
+
+        if !self.pressed_key_names.insert(key_name.clone()) || modifier_name.is_some() {
+            return None;
+        }
+
+        Some(KeyChord::new(
+            &key_name,
+            self.pressed_key_names.contains(KeyChord::MODIFIER_CONTROL),
+            self.pressed_key_names.contains(KeyChord::MODIFIER_ALT),
+            self.pressed_key_names.contains(KeyChord::MODIFIER_SHIFT),
+            self.pressed_key_names.contains(KeyChord::MODIFIER_SUPER),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyChordTracker;
+    use crate::hotkeys::hotkey_key_event::HotkeyKeyEvent;
+    use squalr_engine_api::structures::hotkeys::key_chord::KeyChord;
+
+    #[test]
+    fn key_chord_tracker_completes_chords_with_held_modifiers() {
+        let mut key_chord_tracker = KeyChordTracker::new();
+
+        assert_eq!(key_chord_tracker.process_key_event(&HotkeyKeyEvent::new("ctrl", true)), None);
+        assert_eq!(
+            key_chord_tracker.process_key_event(&HotkeyKeyEvent::new("f1", true)),
+            Some(KeyChord::new("f1", true, false, false, false))
+        );
+        assert_eq!(key_chord_tracker.process_key_event(&HotkeyKeyEvent::new("f1", true)), None);
+        assert_eq!(key_chord_tracker.process_key_event(&HotkeyKeyEvent::new("f1", false)), None);
+        assert_eq!(key_chord_tracker.process_key_event(&HotkeyKeyEvent::new("ctrl", false)), None);
+        assert_eq!(
+            key_chord_tracker.process_key_event(&HotkeyKeyEvent::new("f1", true)),
+            Some(KeyChord::new("f1", false, false, false, false))
+        );
+    }
+}
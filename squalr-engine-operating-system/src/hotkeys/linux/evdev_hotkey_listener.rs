@@ -0,0 +1,102 @@
+use crate::hotkeys::hotkey_key_event::HotkeyKeyEvent;
+use crate::hotkeys::hotkey_listener_error::HotkeyListenerError;
+use crate::hotkeys::hotkey_listener_trait::HotkeyListenerTrait;
+use crate::hotkeys::linux::linux_key_codes::get_key_name;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Read};
+use std::mem::{offset_of, size_of};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+
+const INPUT_DEVICE_DIRECTORY: &str = "/dev/input";
+const EVENT_TYPE_KEY: u16 = 0x01;
+const EVENT_TYPE_REPEAT: u64 = 0x14;
+const KEY_VALUE_RELEASED: i32 = 0;
+const KEY_VALUE_PRESSED: i32 = 1;
+
+/// Hotkey listener that reads key events directly from keyboard input devices, regardless of which window has focus.
+pub struct EvdevHotkeyListener {
+    keyboard_devices: Vec<File>,
+}
+
+impl EvdevHotkeyListener {
+    pub fn new() -> Result<Self, HotkeyListenerError> {
+        let device_entries = fs::read_dir(INPUT_DEVICE_DIRECTORY).map_err(|error| HotkeyListenerError::backend_unavailable("evdev", error.to_string()))?;
+        let keyboard_devices: Vec<File> = device_entries
+            .filter_map(Result::ok)
+            .filter(|device_entry| {
+                let device_name = device_entry.file_name();
+
+                device_name.to_string_lossy().starts_with("event") && Self::is_keyboard(&device_name.to_string_lossy())
+            })
+            .filter_map(|device_entry| {
+                OpenOptions::new()
+                    .read(true)
+                    .custom_flags(libc::O_NONBLOCK)
+                    .open(device_entry.path())
+                    .ok()
+            })
+            .collect();
+
+        if keyboard_devices.is_empty() {
+            return Err(HotkeyListenerError::NoKeyboards);
+        }
+
+        Ok(Self { keyboard_devices })
+    }
+
+    /// Determines whether an input device is a keyboard, taken to be any device that reports both keys and key repeats.
+    fn is_keyboard(device_name: &str) -> bool {
+        let capabilities_path = Path::new("/sys/class/input")
+            .join(device_name)
+            .join("device/capabilities/ev");
+
+        fs::read_to_string(capabilities_path)
+            .ok()
+            .and_then(|event_types| u64::from_str_radix(event_types.trim(), 16).ok())
+            .is_some_and(|event_types| event_types & (1 << EVENT_TYPE_KEY) != 0 && event_types & (1 << EVENT_TYPE_REPEAT) != 0)
+    }
+
+    /// Decodes a key event from the raw bytes of a `struct input_event`, ignoring other event types and key repeats.
+    fn decode_key_event(input_event_bytes: &[u8]) -> Option<HotkeyKeyEvent> {
+        let read_u16 = |offset: usize| u16::from_ne_bytes([input_event_bytes[offset], input_event_bytes[offset + 1]]);
+        let event_type = read_u16(offset_of!(libc::input_event, type_));
+        let key_code = read_u16(offset_of!(libc::input_event, code));
+        let value_offset = offset_of!(libc::input_event, value);
+        let value = i32::from_ne_bytes(
+            input_event_bytes[value_offset..value_offset + 4]
+                .try_into()
+                .ok()?,
+        );
+
+        match (event_type, value) {
+            (EVENT_TYPE_KEY, KEY_VALUE_PRESSED | KEY_VALUE_RELEASED) => Some(HotkeyKeyEvent::new(get_key_name(key_code)?, value == KEY_VALUE_PRESSED)),
+            _ => None,
+        }
+    }
+}
+
+impl HotkeyListenerTrait for EvdevHotkeyListener {
+    fn poll_key_events(&mut self) -> Vec<HotkeyKeyEvent> {
+        let mut hotkey_key_events = Vec::new();
+        let mut input_event_buffer = vec![0u8; size_of::<libc::input_event>() * 64];
+
+        for keyboard_device in &mut self.keyboard_devices {
+            loop {
+                match keyboard_device.read(&mut input_event_buffer) {
+                    Ok(0) => break,
+                    Ok(read_size) => hotkey_key_events.extend(
+                        input_event_buffer[..read_size]
+                            .chunks_exact(size_of::<libc::input_event>())
+                            .filter_map(Self::decode_key_event),
+                    ),
+                    Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                    // Would-block means no further events are queued. Other errors, such as an unplugged keyboard, are treated alike.
+                    Err(_error) => break,
+                }
+            }
+        }
+
+        hotkey_key_events
+    }
+}
@@ -0,0 +1,116 @@
+/// Gets the key chord name of a Linux input event key code, as defined in `linux/input-event-codes.h`. X11 key codes on evdev based
+/// servers are these codes offset by 8. Left and right modifiers share a name.
+pub fn get_key_name(key_code: u16) -> Option<&'static str> {
+    const LETTER_ROWS: [(u16, &str); 3] = [(16, "qwertyuiop"), (30, "asdfghjkl"), (44, "zxcvbnm")];
+    const LETTER_NAMES: [&str; 26] = [
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
+    ];
+
+    for (first_key_code, letters) in LETTER_ROWS {
+        if let Some(letter) = key_code
+            .checked_sub(first_key_code)
+            .and_then(|letter_index| letters.as_bytes().get(letter_index as usize))
+        {
+            return Some(LETTER_NAMES[(letter - b'a') as usize]);
+        }
+    }
+
+    let key_name = match key_code {
+        1 => "escape",
+        2 => "1",
+        3 => "2",
+        4 => "3",
+        5 => "4",
+        6 => "5",
+        7 => "6",
+        8 => "7",
+        9 => "8",
+        10 => "9",
+        11 => "0",
+        12 => "minus",
+        13 => "equal",
+        14 => "backspace",
+        15 => "tab",
+        26 => "leftbracket",
+        27 => "rightbracket",
+        28 => "enter",
+        29 | 97 => "ctrl",
+        39 => "semicolon",
+        40 => "apostrophe",
+        41 => "grave",
+        42 | 54 => "shift",
+        43 => "backslash",
+        51 => "comma",
+        52 => "period",
+        53 => "slash",
+        55 => "numpadmultiply",
+        56 | 100 => "alt",
+        57 => "space",
+        58 => "capslock",
+        59 => "f1",
+        60 => "f2",
+        61 => "f3",
+        62 => "f4",
+        63 => "f5",
+        64 => "f6",
+        65 => "f7",
+        66 => "f8",
+        67 => "f9",
+        68 => "f10",
+        71 => "numpad7",
+        72 => "numpad8",
+        73 => "numpad9",
+        74 => "numpadsubtract",
+        75 => "numpad4",
+        76 => "numpad5",
+        77 => "numpad6",
+        78 => "numpadadd",
+        79 => "numpad1",
+        80 => "numpad2",
+        81 => "numpad3",
+        82 => "numpad0",
+        83 => "numpaddecimal",
+        87 => "f11",
+        88 => "f12",
+        96 => "numpadenter",
+        98 => "numpaddivide",
+        102 => "home",
+        103 => "up",
+        104 => "pageup",
+        105 => "left",
+        106 => "right",
+        107 => "end",
+        108 => "down",
+        109 => "pagedown",
+        110 => "insert",
+        111 => "delete",
+        119 => "pause",
+        125 | 126 => "super",
+        183..=194 => {
+            return Some(
+                [
+                    "f13", "f14", "f15", "f16", "f17", "f18", "f19", "f20", "f21", "f22", "f23", "f24",
+                ][(key_code - 183) as usize],
+            );
+        }
+        _ => return None,
+    };
+
+    Some(key_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_key_name;
+
+    #[test]
+    fn get_key_name_maps_letters_function_keys_and_modifiers() {
+        assert_eq!(get_key_name(16), Some("q"));
+        assert_eq!(get_key_name(38), Some("l"));
+        assert_eq!(get_key_name(50), Some("m"));
+        assert_eq!(get_key_name(59), Some("f1"));
+        assert_eq!(get_key_name(97), Some("ctrl"));
+        assert_eq!(get_key_name(194), Some("f24"));
+        assert_eq!(get_key_name(0), None);
+    }
+}
@@ -0,0 +1,21 @@
+pub mod evdev_hotkey_listener;
+pub mod linux_key_codes;
+pub mod x11_hotkey_listener;
+
+use crate::hotkeys::hotkey_listener_error::HotkeyListenerError;
+use crate::hotkeys::hotkey_listener_trait::HotkeyListenerTrait;
+use crate::hotkeys::linux::evdev_hotkey_listener::EvdevHotkeyListener;
+use crate::hotkeys::linux::x11_hotkey_listener::X11HotkeyListener;
+
+/// Prefers X11, which needs no special permissions, falling back to reading keyboards directly through evdev, which also works
+/// without a display server but typically requires membership in the `input` group.
+pub fn create_hotkey_listener() -> Result<Box<dyn HotkeyListenerTrait>, HotkeyListenerError> {
+    if std::env::var_os("DISPLAY").is_some() {
+        match X11HotkeyListener::new() {
+            Ok(x11_hotkey_listener) => return Ok(Box::new(x11_hotkey_listener)),
+            Err(error) => log::warn!("Falling back to evdev hotkeys: {}", error),
+        }
+    }
+
+    Ok(Box::new(EvdevHotkeyListener::new()?))
+}
@@ -0,0 +1,78 @@
+use crate::hotkeys::hotkey_key_event::HotkeyKeyEvent;
+use crate::hotkeys::hotkey_listener_error::HotkeyListenerError;
+use crate::hotkeys::hotkey_listener_trait::HotkeyListenerTrait;
+use crate::hotkeys::linux::linux_key_codes::get_key_name;
+use std::os::raw::c_char;
+use std::ptr::null;
+use x11_dl::xlib::{Display, Xlib};
+
+/// X11 key codes on evdev based servers are Linux input key codes offset by this amount.
+const X11_KEY_CODE_OFFSET: usize = 8;
+
+/// Hotkey listener that polls the X11 keyboard state, which reflects keys pressed in any window. Xlib is loaded at runtime, such
+/// that systems without X11 fall back to another backend rather than failing to start.
+pub struct X11HotkeyListener {
+    xlib: Xlib,
+    display: *mut Display,
+    previous_keymap: [c_char; 32],
+}
+
+// The display connection is only used by the thread that owns the listener.
+unsafe impl Send for X11HotkeyListener {}
+
+impl X11HotkeyListener {
+    pub fn new() -> Result<Self, HotkeyListenerError> {
+        let xlib = Xlib::open().map_err(|error| HotkeyListenerError::backend_unavailable("x11", error.to_string()))?;
+        let display = unsafe { (xlib.XOpenDisplay)(null()) };
+
+        if display.is_null() {
+            return Err(HotkeyListenerError::backend_unavailable("x11", "failed to open the display"));
+        }
+
+        Ok(Self {
+            xlib,
+            display,
+            previous_keymap: [0; 32],
+        })
+    }
+}
+
+impl HotkeyListenerTrait for X11HotkeyListener {
+    fn poll_key_events(&mut self) -> Vec<HotkeyKeyEvent> {
+        let mut keymap: [c_char; 32] = [0; 32];
+
+        unsafe {
+            (self.xlib.XQueryKeymap)(self.display, keymap.as_mut_ptr());
+        }
+
+        let mut hotkey_key_events = Vec::new();
+
+        for (byte_index, (key_bits, previous_key_bits)) in keymap.iter().zip(self.previous_keymap.iter()).enumerate() {
+            let changed_key_bits = (*key_bits ^ *previous_key_bits) as u8;
+
+            for bit_index in (0..8).filter(|bit_index| changed_key_bits & (1 << bit_index) != 0) {
+                let x11_key_code = byte_index * 8 + bit_index;
+                let Some(key_name) = x11_key_code
+                    .checked_sub(X11_KEY_CODE_OFFSET)
+                    .and_then(|key_code| get_key_name(key_code as u16))
+                else {
+                    continue;
+                };
+
+                hotkey_key_events.push(HotkeyKeyEvent::new(key_name, (*key_bits as u8) & (1 << bit_index) != 0));
+            }
+        }
+
+        self.previous_keymap = keymap;
+
+        hotkey_key_events
+    }
+}
+
+impl Drop for X11HotkeyListener {
+    fn drop(&mut self) {
+        unsafe {
+            (self.xlib.XCloseDisplay)(self.display);
+        }
+    }
+}
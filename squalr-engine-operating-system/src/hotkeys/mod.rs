@@ -0,0 +1,30 @@
+pub mod hotkey_key_event;
+pub mod hotkey_listener_error;
+pub mod hotkey_listener_trait;
+pub mod key_chord_tracker;
+
+use crate::hotkeys::hotkey_listener_error::HotkeyListenerError;
+use crate::hotkeys::hotkey_listener_trait::HotkeyListenerTrait;
+
+#[cfg(target_os = "linux")]
+mod linux;
+
+#[cfg(not(target_os = "linux"))]
+mod unsupported;
+
+pub struct HotkeyListener;
+
+impl HotkeyListener {
+    /// Creates a listener for key presses made while any application has focus, using the best backend available on this platform.
+    pub fn create() -> Result<Box<dyn HotkeyListenerTrait>, HotkeyListenerError> {
+        #[cfg(target_os = "linux")]
+        {
+            linux::create_hotkey_listener()
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            unsupported::create_hotkey_listener()
+        }
+    }
+}
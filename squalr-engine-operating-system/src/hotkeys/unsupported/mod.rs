@@ -0,0 +1,6 @@
+use crate::hotkeys::hotkey_listener_error::HotkeyListenerError;
+use crate::hotkeys::hotkey_listener_trait::HotkeyListenerTrait;
+
+pub fn create_hotkey_listener() -> Result<Box<dyn HotkeyListenerTrait>, HotkeyListenerError> {
+    Err(HotkeyListenerError::not_supported(std::env::consts::OS))
+}
@@ -1,6 +1,8 @@
 pub mod config;
+pub mod hotkeys;
 pub mod memory_queryer;
 pub mod memory_reader;
 pub mod memory_writer;
 pub mod process;
 pub mod process_query;
+pub mod watchpoints;
@@ -7,11 +7,25 @@ use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use std::collections::HashMap;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-pub struct LinuxMemoryQueryer;
+pub struct LinuxMemoryQueryer {
+    thread_stack_caches: Mutex<HashMap<u32, ThreadStackCache>>,
+}
+
+/// The thread stacks of a process, along with the index assigned to each thread. Indices are assigned once per thread and never
+/// reused, such that `THREADSTACKn` refers to the same thread for as long as that thread lives.
+#[derive(Default)]
+struct ThreadStackCache {
+    thread_indices: HashMap<u32, usize>,
+    next_thread_index: usize,
+    thread_stack_modules: HashMap<u32, NormalizedModule>,
+    refreshed_at: Option<Instant>,
+}
 
 struct ProcMapsRegion {
     start_address: u64,
@@ -21,8 +35,23 @@ struct ProcMapsRegion {
 }
 
 impl LinuxMemoryQueryer {
+    /// The prefix for virtual modules that expose thread stacks, numbered by thread creation order (ie `THREADSTACK0` is the main thread).
+    pub const THREAD_STACK_MODULE_PREFIX: &'static str = "THREADSTACK";
+
+    /// The size of the window below each stack top that is exposed as a thread stack module. Offsets are measured from the bottom of this
+    /// window, such that they remain stable even as the main thread stack mapping grows downward.
+    pub const THREAD_STACK_MODULE_SIZE: u64 = 0x10_0000;
+
+    /// How long thread stacks are reused before `/proc/pid/task` is read again.
+    const THREAD_STACK_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// The zero-based index of the `kstkesp` field within `/proc/pid/task/tid/stat`, counting from the field after the command name.
+    const STAT_STACK_POINTER_FIELD_INDEX: usize = 26;
+
     pub fn new() -> Self {
-        LinuxMemoryQueryer
+        LinuxMemoryQueryer {
+            thread_stack_caches: Mutex::new(HashMap::new()),
+        }
     }
 
     fn parse_proc_maps(process_id: u32) -> std::io::Result<Vec<ProcMapsRegion>> {
@@ -200,6 +229,177 @@ impl LinuxMemoryQueryer {
         region.permissions.chars().nth(2) == Some('x') && region.pathname.starts_with('/')
     }
 
+    /// Gets virtual modules for each thread stack, refreshing them from `/proc/pid/task` at most once per refresh interval.
+    /// With glibc, each non-main thread's TLS block and thread descriptor sit at the top of its stack mapping, and are covered as well.
+    fn get_thread_stack_modules(
+        &self,
+        process_id: u32,
+        parsed_regions: &[ProcMapsRegion],
+    ) -> Vec<NormalizedModule> {
+        let mut thread_stack_caches = match self.thread_stack_caches.lock() {
+            Ok(thread_stack_caches) => thread_stack_caches,
+            Err(error) => {
+                log::error!("Failed to acquire thread stack cache lock: {}", error);
+                return Vec::new();
+            }
+        };
+        let is_stale = thread_stack_caches
+            .get(&process_id)
+            .and_then(|thread_stack_cache| thread_stack_cache.refreshed_at)
+            .is_none_or(|refreshed_at| refreshed_at.elapsed() >= Self::THREAD_STACK_REFRESH_INTERVAL);
+
+        if is_stale {
+            // Caches of exited processes are discarded, such that a reused process id starts numbering afresh.
+            thread_stack_caches.retain(|cached_process_id, _| Path::new(&format!("/proc/{cached_process_id}")).exists());
+
+            let thread_ids = match fs::read_dir(format!("/proc/{process_id}/task")) {
+                Ok(task_entries) => task_entries
+                    .filter_map(|task_entry| task_entry.ok())
+                    .filter_map(|task_entry| task_entry.file_name().to_str()?.parse::<u32>().ok())
+                    .collect(),
+                Err(_) => return Vec::new(),
+            };
+            let thread_stack_cache = thread_stack_caches.entry(process_id).or_default();
+
+            Self::assign_thread_indices(thread_stack_cache, process_id, thread_ids);
+
+            for (thread_id, thread_index) in &thread_stack_cache.thread_indices {
+                let stack_pointer = if *thread_id == process_id {
+                    None
+                } else {
+                    Self::read_thread_stack_pointer(process_id, *thread_id)
+                };
+
+                // A thread whose stack cannot be read keeps its last known stack, or is omitted without renumbering other threads.
+                if let Some(thread_stack_module) = Self::build_thread_stack_module(*thread_index, stack_pointer, parsed_regions) {
+                    thread_stack_cache
+                        .thread_stack_modules
+                        .insert(*thread_id, thread_stack_module);
+                }
+            }
+
+            thread_stack_cache.refreshed_at = Some(Instant::now());
+        }
+
+        let mut thread_stack_modules: Vec<(usize, NormalizedModule)> = thread_stack_caches
+            .get(&process_id)
+            .map(|thread_stack_cache| {
+                thread_stack_cache
+                    .thread_stack_modules
+                    .iter()
+                    .filter_map(|(thread_id, thread_stack_module)| {
+                        let thread_index = *thread_stack_cache.thread_indices.get(thread_id)?;
+
+                        Some((thread_index, thread_stack_module.clone()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        thread_stack_modules.sort_by_key(|(thread_index, _)| *thread_index);
+        thread_stack_modules
+            .into_iter()
+            .map(|(_, thread_stack_module)| thread_stack_module)
+            .collect()
+    }
+
+    /// Assigns an index to each newly seen thread in thread id order, which approximates creation order, with the main thread first.
+    /// Exited threads are forgotten, but their indices are not reused.
+    fn assign_thread_indices(
+        thread_stack_cache: &mut ThreadStackCache,
+        process_id: u32,
+        mut thread_ids: Vec<u32>,
+    ) {
+        thread_ids.sort_unstable_by_key(|thread_id| (*thread_id != process_id, *thread_id));
+        thread_stack_cache
+            .thread_indices
+            .retain(|thread_id, _| thread_ids.contains(thread_id));
+        thread_stack_cache
+            .thread_stack_modules
+            .retain(|thread_id, _| thread_ids.contains(thread_id));
+
+        for thread_id in thread_ids {
+            if !thread_stack_cache.thread_indices.contains_key(&thread_id) {
+                thread_stack_cache
+                    .thread_indices
+                    .insert(thread_id, thread_stack_cache.next_thread_index);
+                thread_stack_cache.next_thread_index += 1;
+            }
+        }
+    }
+
+    /// Reads the stack pointer of a thread, preferring `stat`, and falling back on `syscall` for kernels that zero `kstkesp`.
+    fn read_thread_stack_pointer(
+        process_id: u32,
+        thread_id: u32,
+    ) -> Option<u64> {
+        let task_path = format!("/proc/{process_id}/task/{thread_id}");
+
+        fs::read_to_string(format!("{task_path}/stat"))
+            .ok()
+            .and_then(|stat| Self::parse_stat_stack_pointer(&stat))
+            .or_else(|| {
+                fs::read_to_string(format!("{task_path}/syscall"))
+                    .ok()
+                    .and_then(|syscall| Self::parse_syscall_stack_pointer(&syscall))
+            })
+    }
+
+    fn parse_stat_stack_pointer(stat: &str) -> Option<u64> {
+        // The command name is parenthesized and may itself contain spaces or parentheses, so parse from the last closing parenthesis.
+        let (_, stat_fields) = stat.rsplit_once(')')?;
+        let stack_pointer = stat_fields
+            .split_whitespace()
+            .nth(Self::STAT_STACK_POINTER_FIELD_INDEX)?
+            .parse::<u64>()
+            .ok()?;
+
+        if stack_pointer == 0 { None } else { Some(stack_pointer) }
+    }
+
+    fn parse_syscall_stack_pointer(syscall: &str) -> Option<u64> {
+        // Blocked threads report the syscall number and arguments, followed by the stack pointer and program counter.
+        let syscall_tokens: Vec<&str> = syscall.split_whitespace().collect();
+
+        if syscall_tokens.len() < 3 {
+            return None;
+        }
+
+        let stack_pointer_token = syscall_tokens[syscall_tokens.len() - 2];
+        let stack_pointer = u64::from_str_radix(stack_pointer_token.trim_start_matches("0x"), 16).ok()?;
+
+        if stack_pointer == 0 { None } else { Some(stack_pointer) }
+    }
+
+    /// Builds a thread stack module. The main thread is identified by having no stack pointer, and uses the `[stack]` mapping.
+    fn build_thread_stack_module(
+        thread_index: usize,
+        stack_pointer: Option<u64>,
+        parsed_regions: &[ProcMapsRegion],
+    ) -> Option<NormalizedModule> {
+        let (stack_top, module_size) = match stack_pointer {
+            None => {
+                let stack_region = parsed_regions
+                    .iter()
+                    .find(|parsed_region| parsed_region.pathname == "[stack]")?;
+
+                // The main stack grows downward into unmapped space, so the window is not clamped to the current mapping.
+                (stack_region.end_address, Self::THREAD_STACK_MODULE_SIZE)
+            }
+            Some(stack_pointer) => {
+                let stack_region = parsed_regions
+                    .iter()
+                    .find(|parsed_region| stack_pointer >= parsed_region.start_address && stack_pointer < parsed_region.end_address)?;
+                let stack_region_size = stack_region.end_address - stack_region.start_address;
+
+                (stack_region.end_address, Self::THREAD_STACK_MODULE_SIZE.min(stack_region_size))
+            }
+        };
+        let module_name = format!("{}{}", Self::THREAD_STACK_MODULE_PREFIX, thread_index);
+
+        Some(NormalizedModule::new(&module_name, stack_top.saturating_sub(module_size), module_size))
+    }
+
     fn module_name_from_path(module_path: &str) -> String {
         Path::new(module_path)
             .file_name()
@@ -319,8 +519,8 @@ impl MemoryQueryerTrait for LinuxMemoryQueryer {
 
         let mut module_ranges: HashMap<String, (u64, u64)> = HashMap::new();
 
-        for parsed_region in parsed_regions {
-            if !Self::is_module_region(&parsed_region) {
+        for parsed_region in &parsed_regions {
+            if !Self::is_module_region(parsed_region) {
                 continue;
             }
 
@@ -342,12 +542,16 @@ impl MemoryQueryerTrait for LinuxMemoryQueryer {
                 }
 
                 let module_name = Self::module_name_from_path(module_path);
+                let mut module = NormalizedModule::new(&module_name, *module_start_address, module_region_size);
+
+                module.set_module_path(module_path);
 
-                Some(NormalizedModule::new(&module_name, *module_start_address, module_region_size))
+                Some(module)
             })
             .collect();
 
         modules.sort_by_key(|module| module.get_base_address());
+        modules.extend(self.get_thread_stack_modules(process_info.get_process_id_raw(), &parsed_regions));
 
         modules
     }
@@ -392,12 +596,14 @@ impl MemoryQueryerTrait for LinuxMemoryQueryer {
 
 #[cfg(test)]
 mod tests {
-    use super::{LinuxMemoryQueryer, ProcMapsRegion};
+    use super::{LinuxMemoryQueryer, ProcMapsRegion, ThreadStackCache};
     use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
     use crate::memory_queryer::memory_queryer_trait::MemoryQueryerTrait;
     use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
     use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
     use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 
     #[test]
     fn parse_maps_line_parses_well_formed_rows() {
@@ -479,4 +685,85 @@ mod tests {
         assert_eq!(module_name, "target.so");
         assert_eq!(module_offset, 0xABC);
     }
+
+    #[test]
+    fn parse_stat_stack_pointer_handles_command_names_with_spaces_and_parentheses() {
+        let mut stat_fields = vec!["0"; 50];
+        stat_fields[26] = "140737488346112";
+        let stat = format!("4242 (game (main) thread) {}", stat_fields.join(" "));
+
+        assert_eq!(LinuxMemoryQueryer::parse_stat_stack_pointer(&stat), Some(140737488346112));
+        assert_eq!(
+            LinuxMemoryQueryer::parse_stat_stack_pointer(&format!("4242 (game) {}", vec!["0"; 50].join(" "))),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_syscall_stack_pointer_reads_second_to_last_token() {
+        let syscall = "202 0x7f0000001000 0x80 0x0 0x0 0x0 0x0 0x7f00000ffe40 0x7f1234567890";
+
+        assert_eq!(LinuxMemoryQueryer::parse_syscall_stack_pointer(syscall), Some(0x7f00000ffe40));
+        assert_eq!(LinuxMemoryQueryer::parse_syscall_stack_pointer("running"), None);
+    }
+
+    #[test]
+    fn build_thread_stack_module_anchors_windows_to_stack_tops() {
+        let parsed_regions = vec![
+            ProcMapsRegion {
+                start_address: 0x7f00_0000_0000,
+                end_address: 0x7f00_0080_0000,
+                permissions: "rw-p".to_string(),
+                pathname: String::new(),
+            },
+            ProcMapsRegion {
+                start_address: 0x7fff_fffd_e000,
+                end_address: 0x7fff_ffff_f000,
+                permissions: "rw-p".to_string(),
+                pathname: "[stack]".to_string(),
+            },
+        ];
+
+        let main_thread_module = LinuxMemoryQueryer::build_thread_stack_module(0, None, &parsed_regions).expect("main thread stack module should be built.");
+        let worker_thread_module =
+            LinuxMemoryQueryer::build_thread_stack_module(1, Some(0x7f00_007f_fe00), &parsed_regions).expect("worker thread stack module should be built.");
+
+        assert_eq!(main_thread_module.get_module_name(), "THREADSTACK0");
+        assert_eq!(
+            main_thread_module.get_base_address(),
+            0x7fff_ffff_f000 - LinuxMemoryQueryer::THREAD_STACK_MODULE_SIZE
+        );
+        assert_eq!(worker_thread_module.get_module_name(), "THREADSTACK1");
+        assert_eq!(
+            worker_thread_module.get_base_address() + worker_thread_module.get_region_size(),
+            0x7f00_0080_0000
+        );
+        assert!(LinuxMemoryQueryer::build_thread_stack_module(2, Some(0x1234), &parsed_regions).is_none());
+    }
+
+    #[test]
+    fn assign_thread_indices_keeps_indices_stable_as_threads_exit_and_start() {
+        let mut thread_stack_cache = ThreadStackCache::default();
+
+        LinuxMemoryQueryer::assign_thread_indices(&mut thread_stack_cache, 100, vec![102, 101, 100]);
+        LinuxMemoryQueryer::assign_thread_indices(&mut thread_stack_cache, 100, vec![100, 102, 103]);
+
+        assert_eq!(thread_stack_cache.thread_indices.get(&100), Some(&0));
+        assert_eq!(thread_stack_cache.thread_indices.get(&101), None);
+        assert_eq!(thread_stack_cache.thread_indices.get(&102), Some(&2));
+        assert_eq!(thread_stack_cache.thread_indices.get(&103), Some(&3));
+    }
+
+    #[test]
+    fn get_modules_exposes_main_thread_stack_for_current_process() {
+        let queryer = LinuxMemoryQueryer::new();
+        let process_info = OpenedProcessInfo::new(std::process::id(), "self".to_string(), 0, Bitness::Bit64, None);
+        let modules = queryer.get_modules(&process_info);
+
+        assert!(
+            modules
+                .iter()
+                .any(|module| module.get_module_name() == "THREADSTACK0")
+        );
+    }
 }
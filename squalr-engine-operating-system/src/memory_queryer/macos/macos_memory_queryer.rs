@@ -366,11 +366,11 @@ impl MemoryQueryerTrait for MacOsMemoryQueryer {
                     return None;
                 }
 
-                Some(NormalizedModule::new(
-                    &Self::module_name_from_path(module_path),
-                    *module_start_address,
-                    module_region_size,
-                ))
+                let mut module = NormalizedModule::new(&Self::module_name_from_path(module_path), *module_start_address, module_region_size);
+
+                module.set_module_path(module_path);
+
+                Some(module)
             })
             .collect();
 
@@ -281,11 +281,10 @@ impl MemoryQueryerTrait for WindowsMemoryQueryer {
                 continue;
             }
 
-            modules.push(NormalizedModule::new(
-                &module_name,
-                module_info.lpBaseOfDll as u64,
-                module_info.SizeOfImage as u64,
-            ));
+            let mut module = NormalizedModule::new(&module_name, module_info.lpBaseOfDll as u64, module_info.SizeOfImage as u64);
+
+            module.set_module_path(&module_path);
+            modules.push(module);
         }
 
         modules
@@ -1,6 +1,8 @@
 use crate::memory_writer::memory_writer_trait::MemoryWriterTrait;
 use libc::{c_void, iovec, pid_t, process_vm_writev};
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::fs::OpenOptions;
+use std::os::unix::fs::FileExt;
 
 pub struct LinuxMemoryWriter;
 
@@ -30,7 +32,24 @@ impl LinuxMemoryWriter {
 
         let bytes_written = unsafe { process_vm_writev(process_id as pid_t, &local_iovec, 1, &remote_iovec, 1, 0) };
 
-        bytes_written == source_bytes.len() as isize
+        bytes_written == source_bytes.len() as isize || Self::write_protected_process_memory(process_id, destination_address, source_bytes)
+    }
+
+    /// Writes through the memory file of the process, which, unlike `process_vm_writev`, can write to read-only and executable pages.
+    fn write_protected_process_memory(
+        process_id: u32,
+        destination_address: u64,
+        source_bytes: &[u8],
+    ) -> bool {
+        match OpenOptions::new()
+            .write(true)
+            .open(format!("/proc/{}/mem", process_id))
+        {
+            Ok(memory_file) => memory_file
+                .write_all_at(source_bytes, destination_address)
+                .is_ok(),
+            Err(_error) => false,
+        }
     }
 }
 
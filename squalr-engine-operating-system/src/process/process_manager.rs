@@ -15,6 +15,7 @@ use sysinfo::Pid;
 
 pub struct ProcessManager {
     opened_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+    secondary_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
     event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
 }
 
@@ -22,6 +23,7 @@ impl ProcessManager {
     pub fn new(event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>) -> Self {
         let instance = Self {
             opened_process: Arc::new(RwLock::new(None)),
+            secondary_process: Arc::new(RwLock::new(None)),
             event_emitter: event_emitter.clone(),
         };
 
@@ -75,6 +77,37 @@ impl ProcessManager {
         self.opened_process.clone()
     }
 
+    /// Sets a second process to compare against the opened process, such as another instance of the same game.
+    pub fn set_secondary_process(
+        &self,
+        process_info: OpenedProcessInfo,
+    ) {
+        if let Ok(mut process) = self.secondary_process.write() {
+            log::info!("Opened secondary process: {}, pid: {}", process_info.get_name(), process_info.get_process_id());
+            *process = Some(process_info);
+        }
+    }
+
+    /// Clears the secondary process.
+    pub fn clear_secondary_process(&self) {
+        if let Ok(mut process) = self.secondary_process.write() {
+            *process = None;
+
+            log::info!("Secondary process closed.");
+        }
+    }
+
+    /// Gets the secondary process compared against the opened process, if any.
+    pub fn get_secondary_process(&self) -> Option<OpenedProcessInfo> {
+        match self.secondary_process.read() {
+            Ok(secondary_process) => secondary_process.clone(),
+            Err(error) => {
+                log::error!("Failed to access secondary process: {}", error);
+                None
+            }
+        }
+    }
+
     /// Listens for the death of the currently opened process by polling for it repeatedly.
     fn listen_for_open_process_death(
         event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
@@ -0,0 +1,708 @@
+use crate::watchpoints::linux::x86_64_debug_registers::{
+    DEBUG_CONTROL_REGISTER_INDEX, DEBUG_REGISTER_SLOT_COUNT, DEBUG_STATUS_REGISTER_INDEX, DebugRegisterWatchpoint, encode_debug_control_register,
+    get_triggered_slots,
+};
+use crate::watchpoints::watchpoint_debugger_trait::WatchpointDebuggerTrait;
+use crate::watchpoints::watchpoint_error::WatchpointError;
+use libc::{c_int, c_uint, c_void, pid_t};
+use squalr_engine_api::structures::watchpoints::register_snapshot::RegisterSnapshot;
+use squalr_engine_api::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+use std::collections::BTreeSet;
+use std::io;
+use std::mem::offset_of;
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How long the tracer thread sleeps when no tracee has reported a stop.
+const TRACER_IDLE_SLEEP_MS: u64 = 1;
+
+/// How long callers wait for the tracer thread to service a request before giving up.
+const TRACER_REPLY_TIMEOUT_MS: u64 = 5000;
+
+enum TracerRequest {
+    SetWatchpoint {
+        watchpoint: DebugRegisterWatchpoint,
+        reply_sender: Sender<Result<u32, WatchpointError>>,
+    },
+    RemoveWatchpoint {
+        watchpoint_id: u32,
+        reply_sender: Sender<Result<(), WatchpointError>>,
+    },
+    Detach {
+        reply_sender: Sender<Result<(), WatchpointError>>,
+    },
+}
+
+struct DebugSession {
+    process_id: u32,
+    request_sender: Sender<TracerRequest>,
+    is_running: Arc<AtomicBool>,
+    tracer_thread: JoinHandle<()>,
+}
+
+/// Hardware watchpoint debugger built on ptrace and the x86-64 debug registers.
+/// Ptrace requests must be issued from the thread that attached, so all tracing is performed by a dedicated tracer thread.
+pub struct LinuxWatchpointDebugger {
+    session: Mutex<Option<DebugSession>>,
+    watchpoint_hits: Arc<Mutex<Vec<WatchpointHit>>>,
+}
+
+impl LinuxWatchpointDebugger {
+    pub fn new() -> Self {
+        Self {
+            session: Mutex::new(None),
+            watchpoint_hits: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    /// Sends a request to the tracer thread of the active session, waiting for its reply.
+    fn send_request<T>(
+        &self,
+        create_request: impl FnOnce(Sender<Result<T, WatchpointError>>) -> TracerRequest,
+    ) -> Result<T, WatchpointError> {
+        let session_guard = self
+            .session
+            .lock()
+            .map_err(|error| WatchpointError::internal("send_request", error.to_string()))?;
+        let session = session_guard
+            .as_ref()
+            .filter(|session| session.is_running.load(Ordering::Acquire))
+            .ok_or(WatchpointError::NotAttached)?;
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        session
+            .request_sender
+            .send(create_request(reply_sender))
+            .map_err(|_| WatchpointError::NotAttached)?;
+
+        match reply_receiver.recv_timeout(Duration::from_millis(TRACER_REPLY_TIMEOUT_MS)) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(WatchpointError::internal("send_request", "timed out waiting for the tracer thread")),
+            Err(RecvTimeoutError::Disconnected) => Err(WatchpointError::NotAttached),
+        }
+    }
+}
+
+impl Default for LinuxWatchpointDebugger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WatchpointDebuggerTrait for LinuxWatchpointDebugger {
+    fn attach(
+        &self,
+        process_id: u32,
+    ) -> Result<(), WatchpointError> {
+        let mut session_guard = self
+            .session
+            .lock()
+            .map_err(|error| WatchpointError::internal("attach", error.to_string()))?;
+
+        if let Some(session) = session_guard.take() {
+            if session.is_running.load(Ordering::Acquire) {
+                let attached_process_id = session.process_id;
+
+                *session_guard = Some(session);
+
+                return Err(WatchpointError::AlreadyAttached {
+                    process_id: attached_process_id,
+                });
+            }
+
+            // The previous tracee exited, so its tracer thread has already finished.
+            let _ = session.tracer_thread.join();
+        }
+
+        if let Ok(mut watchpoint_hits) = self.watchpoint_hits.lock() {
+            watchpoint_hits.clear();
+        }
+
+        let (request_sender, request_receiver) = mpsc::channel();
+        let (attach_reply_sender, attach_reply_receiver) = mpsc::channel();
+        let is_running = Arc::new(AtomicBool::new(true));
+        let is_running_clone = is_running.clone();
+        let watchpoint_hits = self.watchpoint_hits.clone();
+
+        let tracer_thread = thread::spawn(move || {
+            let mut tracer = Tracer::new(process_id, watchpoint_hits);
+            let attach_result = tracer.attach();
+            let is_attached = attach_result.is_ok();
+
+            let _ = attach_reply_sender.send(attach_result);
+
+            if is_attached {
+                tracer.run(request_receiver);
+            }
+
+            is_running_clone.store(false, Ordering::Release);
+        });
+
+        let attach_result = attach_reply_receiver
+            .recv()
+            .unwrap_or_else(|_| Err(WatchpointError::attach_failed(process_id, "the tracer thread exited unexpectedly")));
+
+        match attach_result {
+            Ok(()) => {
+                log::info!("Debugger attached to process {}.", process_id);
+
+                *session_guard = Some(DebugSession {
+                    process_id,
+                    request_sender,
+                    is_running,
+                    tracer_thread,
+                });
+
+                Ok(())
+            }
+            Err(error) => {
+                let _ = tracer_thread.join();
+
+                Err(error)
+            }
+        }
+    }
+
+    fn detach(&self) -> Result<(), WatchpointError> {
+        let session = self
+            .session
+            .lock()
+            .map_err(|error| WatchpointError::internal("detach", error.to_string()))?
+            .take()
+            .ok_or(WatchpointError::NotAttached)?;
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        // If the tracer already stopped because the process exited, there is nothing left to detach from.
+        let detach_result = match session
+            .request_sender
+            .send(TracerRequest::Detach { reply_sender })
+        {
+            Ok(()) => reply_receiver.recv().unwrap_or(Ok(())),
+            Err(_) => Ok(()),
+        };
+
+        let _ = session.tracer_thread.join();
+        log::info!("Debugger detached from process {}.", session.process_id);
+
+        detach_result
+    }
+
+    fn get_attached_process_id(&self) -> Option<u32> {
+        self.session
+            .lock()
+            .ok()?
+            .as_ref()
+            .filter(|session| session.is_running.load(Ordering::Acquire))
+            .map(|session| session.process_id)
+    }
+
+    fn set_watchpoint(
+        &self,
+        address: u64,
+        size: u64,
+        watchpoint_kind: WatchpointKind,
+    ) -> Result<u32, WatchpointError> {
+        let watchpoint = DebugRegisterWatchpoint::new(address, size, watchpoint_kind)?;
+
+        self.send_request(|reply_sender| TracerRequest::SetWatchpoint { watchpoint, reply_sender })
+    }
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u32,
+    ) -> Result<(), WatchpointError> {
+        self.send_request(|reply_sender| TracerRequest::RemoveWatchpoint { watchpoint_id, reply_sender })
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        clear: bool,
+    ) -> Vec<WatchpointHit> {
+        match self.watchpoint_hits.lock() {
+            Ok(mut watchpoint_hits) if clear => std::mem::take(&mut *watchpoint_hits),
+            Ok(watchpoint_hits) => watchpoint_hits.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire watchpoint hits lock: {}", error);
+                vec![]
+            }
+        }
+    }
+}
+
+/// How a thread that was stopped by the tracer should be resumed.
+#[derive(Clone, Copy)]
+enum ThreadResumeMode {
+    /// Continue execution, delivering the given signal (or none if zero).
+    Continue(c_int),
+    /// Leave the thread in its group-stop, while still reporting future events.
+    Listen,
+}
+
+/// Owns the ptrace session for a single process. Only usable from the thread that created it.
+struct Tracer {
+    process_id: u32,
+    thread_ids: BTreeSet<pid_t>,
+    slots: [Option<DebugRegisterWatchpoint>; DEBUG_REGISTER_SLOT_COUNT],
+    watchpoint_hits: Arc<Mutex<Vec<WatchpointHit>>>,
+}
+
+impl Tracer {
+    fn new(
+        process_id: u32,
+        watchpoint_hits: Arc<Mutex<Vec<WatchpointHit>>>,
+    ) -> Self {
+        Self {
+            process_id,
+            thread_ids: BTreeSet::new(),
+            slots: [None; DEBUG_REGISTER_SLOT_COUNT],
+            watchpoint_hits,
+        }
+    }
+
+    /// Seizes every thread of the process. Threads may be created while attaching, so this repeats until no new threads are found.
+    fn attach(&mut self) -> Result<(), WatchpointError> {
+        loop {
+            let task_ids = Self::get_task_ids(self.process_id).map_err(|error| WatchpointError::attach_failed(self.process_id, error.to_string()))?;
+            let new_task_ids = task_ids
+                .into_iter()
+                .filter(|task_id| !self.thread_ids.contains(task_id))
+                .collect::<Vec<_>>();
+
+            if new_task_ids.is_empty() {
+                break;
+            }
+
+            for task_id in new_task_ids {
+                match Self::ptrace(libc::PTRACE_SEIZE, task_id, null_mut(), libc::PTRACE_O_TRACECLONE as usize as *mut c_void) {
+                    Ok(_) => {
+                        self.thread_ids.insert(task_id);
+                    }
+                    // The thread exited between listing and seizing it.
+                    Err(error) if error.raw_os_error() == Some(libc::ESRCH) && task_id as u32 != self.process_id => {}
+                    Err(error) => {
+                        self.detach_all();
+
+                        return Err(WatchpointError::attach_failed(self.process_id, error.to_string()));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Services requests and tracee stops until detached, or until every thread of the process has exited.
+    fn run(
+        &mut self,
+        request_receiver: Receiver<TracerRequest>,
+    ) {
+        loop {
+            match request_receiver.try_recv() {
+                Ok(TracerRequest::SetWatchpoint { watchpoint, reply_sender }) => {
+                    let _ = reply_sender.send(self.set_watchpoint(watchpoint));
+                }
+                Ok(TracerRequest::RemoveWatchpoint { watchpoint_id, reply_sender }) => {
+                    let _ = reply_sender.send(self.remove_watchpoint(watchpoint_id));
+                }
+                Ok(TracerRequest::Detach { reply_sender }) => {
+                    self.detach_all();
+                    let _ = reply_sender.send(Ok(()));
+
+                    return;
+                }
+                Err(TryRecvError::Disconnected) => {
+                    self.detach_all();
+
+                    return;
+                }
+                Err(TryRecvError::Empty) => {}
+            }
+
+            let mut has_reported_stop = false;
+
+            for thread_id in self.thread_ids.iter().copied().collect::<Vec<_>>() {
+                if let Some(status) = Self::wait_for_thread(thread_id, libc::WNOHANG) {
+                    has_reported_stop = true;
+
+                    if let Some(resume_mode) = self.handle_status(thread_id, status) {
+                        Self::resume_thread(thread_id, resume_mode);
+                    }
+                }
+            }
+
+            if self.thread_ids.is_empty() {
+                log::info!("Debugged process {} exited.", self.process_id);
+
+                return;
+            }
+
+            if !has_reported_stop {
+                thread::sleep(Duration::from_millis(TRACER_IDLE_SLEEP_MS));
+            }
+        }
+    }
+
+    fn set_watchpoint(
+        &mut self,
+        watchpoint: DebugRegisterWatchpoint,
+    ) -> Result<u32, WatchpointError> {
+        let slot_index = self
+            .slots
+            .iter()
+            .position(|slot| slot.is_none())
+            .ok_or(WatchpointError::NoFreeSlots {
+                slot_count: DEBUG_REGISTER_SLOT_COUNT,
+            })?;
+
+        self.slots[slot_index] = Some(watchpoint);
+
+        if let Err(error) = self.apply_debug_registers() {
+            self.slots[slot_index] = None;
+            let _ = self.apply_debug_registers();
+
+            return Err(error);
+        }
+
+        Ok(slot_index as u32)
+    }
+
+    fn remove_watchpoint(
+        &mut self,
+        watchpoint_id: u32,
+    ) -> Result<(), WatchpointError> {
+        match self.slots.get_mut(watchpoint_id as usize) {
+            Some(slot) if slot.is_some() => *slot = None,
+            _ => return Err(WatchpointError::UnknownWatchpoint { watchpoint_id }),
+        }
+
+        self.apply_debug_registers()
+    }
+
+    /// Writes the current watchpoints to the debug registers of every thread, briefly stopping each thread to do so.
+    fn apply_debug_registers(&mut self) -> Result<(), WatchpointError> {
+        for thread_id in self.thread_ids.iter().copied().collect::<Vec<_>>() {
+            let Some(resume_mode) = self.stop_thread(thread_id) else {
+                continue;
+            };
+            let write_result = self.write_debug_registers(thread_id);
+
+            Self::resume_thread(thread_id, resume_mode);
+            write_result?;
+        }
+
+        Ok(())
+    }
+
+    fn write_debug_registers(
+        &self,
+        thread_id: pid_t,
+    ) -> Result<(), WatchpointError> {
+        let write_debug_register = |register_index: usize, value: u64| {
+            Self::ptrace(
+                libc::PTRACE_POKEUSER,
+                thread_id,
+                Self::get_debug_register_offset(register_index) as *mut c_void,
+                value as usize as *mut c_void,
+            )
+            .map(|_| ())
+            .map_err(|error| WatchpointError::internal("write_debug_registers", format!("thread {}: {}", thread_id, error)))
+        };
+
+        // Disable all slots first, since the kernel validates each address against the currently enabled length.
+        write_debug_register(DEBUG_CONTROL_REGISTER_INDEX, 0)?;
+
+        for (slot_index, slot) in self.slots.iter().enumerate() {
+            if let Some(watchpoint) = slot {
+                write_debug_register(slot_index, watchpoint.address)?;
+            }
+        }
+
+        write_debug_register(DEBUG_CONTROL_REGISTER_INDEX, encode_debug_control_register(&self.slots))
+    }
+
+    /// Handles a reported status for a thread, returning how it should be resumed, or `None` if it is no longer stopped.
+    fn handle_status(
+        &mut self,
+        thread_id: pid_t,
+        status: c_int,
+    ) -> Option<ThreadResumeMode> {
+        if libc::WIFEXITED(status) || libc::WIFSIGNALED(status) {
+            self.thread_ids.remove(&thread_id);
+
+            return None;
+        }
+
+        if !libc::WIFSTOPPED(status) {
+            return None;
+        }
+
+        let signal = libc::WSTOPSIG(status);
+
+        match status >> 16 {
+            libc::PTRACE_EVENT_CLONE => {
+                let mut new_thread_id: libc::c_ulong = 0;
+
+                if Self::ptrace(
+                    libc::PTRACE_GETEVENTMSG,
+                    thread_id,
+                    null_mut(),
+                    &mut new_thread_id as *mut libc::c_ulong as *mut c_void,
+                )
+                .is_ok()
+                {
+                    // The new thread starts stopped, and receives the debug registers when that stop is reported.
+                    self.thread_ids.insert(new_thread_id as pid_t);
+                }
+
+                Some(ThreadResumeMode::Continue(0))
+            }
+            libc::PTRACE_EVENT_STOP => {
+                // Debug registers are not inherited by new threads, so write them whenever a thread reports a stop.
+                if let Err(error) = self.write_debug_registers(thread_id) {
+                    log::warn!("Failed to write debug registers: {}", error);
+                }
+
+                if matches!(signal, libc::SIGSTOP | libc::SIGTSTP | libc::SIGTTIN | libc::SIGTTOU) {
+                    Some(ThreadResumeMode::Listen)
+                } else {
+                    Some(ThreadResumeMode::Continue(0))
+                }
+            }
+            0 if signal == libc::SIGTRAP && self.record_hit(thread_id) => Some(ThreadResumeMode::Continue(0)),
+            0 => Some(ThreadResumeMode::Continue(signal)),
+            _ => Some(ThreadResumeMode::Continue(0)),
+        }
+    }
+
+    /// Interrupts a running thread and waits for it to stop, handling any stops that were reported first.
+    /// Returns how the thread should be resumed, or `None` if the thread exited.
+    fn stop_thread(
+        &mut self,
+        thread_id: pid_t,
+    ) -> Option<ThreadResumeMode> {
+        if Self::ptrace(libc::PTRACE_INTERRUPT, thread_id, null_mut(), null_mut()).is_err() {
+            self.thread_ids.remove(&thread_id);
+
+            return None;
+        }
+
+        let status = match Self::wait_for_thread(thread_id, 0) {
+            Some(status) => status,
+            None => {
+                self.thread_ids.remove(&thread_id);
+
+                return None;
+            }
+        };
+
+        self.handle_status(thread_id, status)
+    }
+
+    fn resume_thread(
+        thread_id: pid_t,
+        resume_mode: ThreadResumeMode,
+    ) {
+        let resume_result = match resume_mode {
+            ThreadResumeMode::Continue(signal) => Self::ptrace(libc::PTRACE_CONT, thread_id, null_mut(), signal as usize as *mut c_void),
+            ThreadResumeMode::Listen => Self::ptrace(libc::PTRACE_LISTEN, thread_id, null_mut(), null_mut()),
+        };
+
+        if let Err(error) = resume_result {
+            log::warn!("Failed to resume thread {}: {}", thread_id, error);
+        }
+    }
+
+    /// Clears the debug registers of every thread and detaches from it, leaving the process running.
+    fn detach_all(&mut self) {
+        self.slots = [None; DEBUG_REGISTER_SLOT_COUNT];
+
+        for thread_id in self.thread_ids.iter().copied().collect::<Vec<_>>() {
+            let Some(resume_mode) = self.stop_thread(thread_id) else {
+                continue;
+            };
+            let signal = match resume_mode {
+                ThreadResumeMode::Continue(signal) => signal,
+                ThreadResumeMode::Listen => 0,
+            };
+
+            let _ = self.write_debug_registers(thread_id);
+
+            if let Err(error) = Self::ptrace(libc::PTRACE_DETACH, thread_id, null_mut(), signal as usize as *mut c_void) {
+                log::warn!("Failed to detach from thread {}: {}", thread_id, error);
+            }
+        }
+
+        self.thread_ids.clear();
+    }
+
+    /// Records a hit if the trap was raised by a watchpoint, returning false if the trap came from something else.
+    fn record_hit(
+        &self,
+        thread_id: pid_t,
+    ) -> bool {
+        let debug_status_register = match Self::ptrace(
+            libc::PTRACE_PEEKUSER,
+            thread_id,
+            Self::get_debug_register_offset(DEBUG_STATUS_REGISTER_INDEX) as *mut c_void,
+            null_mut(),
+        ) {
+            Ok(debug_status_register) => debug_status_register as u64,
+            Err(_) => return false,
+        };
+        let triggered_slots = get_triggered_slots(debug_status_register);
+
+        if triggered_slots.is_empty() {
+            return false;
+        }
+
+        // A trap may still be pending for a watchpoint that was just removed. It must be swallowed rather than
+        // delivered, since the process has no handler for it, but it is no longer recorded.
+        let triggered_slots = triggered_slots
+            .into_iter()
+            .filter(|slot_index| self.slots[*slot_index].is_some())
+            .collect::<Vec<_>>();
+
+        // The status register is sticky, so it must be cleared for later hits to be distinguishable.
+        let _ = Self::ptrace(
+            libc::PTRACE_POKEUSER,
+            thread_id,
+            Self::get_debug_register_offset(DEBUG_STATUS_REGISTER_INDEX) as *mut c_void,
+            null_mut(),
+        );
+
+        let Some(registers) = Self::read_registers(thread_id) else {
+            return true;
+        };
+        let instruction_pointer = registers.rip;
+        let register_snapshot = Self::create_register_snapshot(&registers);
+
+        match self.watchpoint_hits.lock() {
+            Ok(mut watchpoint_hits) => {
+                for slot_index in triggered_slots {
+                    let watchpoint_id = slot_index as u32;
+                    let existing_hit = watchpoint_hits.iter_mut().find(|watchpoint_hit| {
+                        watchpoint_hit.get_watchpoint_id() == watchpoint_id && watchpoint_hit.get_instruction_pointer() == instruction_pointer
+                    });
+
+                    match existing_hit {
+                        Some(watchpoint_hit) => watchpoint_hit.record_hit(thread_id as u32, register_snapshot.clone()),
+                        None => watchpoint_hits.push(WatchpointHit::new(
+                            watchpoint_id,
+                            thread_id as u32,
+                            instruction_pointer,
+                            register_snapshot.clone(),
+                        )),
+                    }
+                }
+            }
+            Err(error) => log::error!("Failed to acquire watchpoint hits lock: {}", error),
+        }
+
+        true
+    }
+
+    fn read_registers(thread_id: pid_t) -> Option<libc::user_regs_struct> {
+        // SAFETY: user_regs_struct is plain integer data, for which all-zeroes is a valid value.
+        let mut registers: libc::user_regs_struct = unsafe { std::mem::zeroed() };
+
+        Self::ptrace(
+            libc::PTRACE_GETREGS,
+            thread_id,
+            null_mut(),
+            &mut registers as *mut libc::user_regs_struct as *mut c_void,
+        )
+        .ok()
+        .map(|_| registers)
+    }
+
+    fn create_register_snapshot(registers: &libc::user_regs_struct) -> RegisterSnapshot {
+        RegisterSnapshot::new(
+            [
+                ("rax", registers.rax),
+                ("rbx", registers.rbx),
+                ("rcx", registers.rcx),
+                ("rdx", registers.rdx),
+                ("rsi", registers.rsi),
+                ("rdi", registers.rdi),
+                ("rbp", registers.rbp),
+                ("rsp", registers.rsp),
+                ("r8", registers.r8),
+                ("r9", registers.r9),
+                ("r10", registers.r10),
+                ("r11", registers.r11),
+                ("r12", registers.r12),
+                ("r13", registers.r13),
+                ("r14", registers.r14),
+                ("r15", registers.r15),
+                ("rip", registers.rip),
+                ("rflags", registers.eflags),
+            ]
+            .into_iter()
+            .map(|(register_name, value)| (register_name.to_string(), value))
+            .collect(),
+        )
+    }
+
+    fn get_task_ids(process_id: u32) -> io::Result<Vec<pid_t>> {
+        let mut task_ids = std::fs::read_dir(format!("/proc/{}/task", process_id))?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.file_name().to_str()?.parse::<pid_t>().ok())
+            .collect::<Vec<_>>();
+
+        task_ids.sort_unstable();
+
+        Ok(task_ids)
+    }
+
+    fn get_debug_register_offset(register_index: usize) -> usize {
+        offset_of!(libc::user, u_debugreg) + register_index * size_of::<u64>()
+    }
+
+    /// Waits for a status change on a single traced thread. Returns `None` if nothing was reported or the thread no longer exists.
+    fn wait_for_thread(
+        thread_id: pid_t,
+        options: c_int,
+    ) -> Option<c_int> {
+        let mut status: c_int = 0;
+
+        loop {
+            let result = unsafe { libc::waitpid(thread_id, &mut status, options | libc::__WALL) };
+
+            match result {
+                0 => return None,
+                -1 if io::Error::last_os_error().raw_os_error() == Some(libc::EINTR) => continue,
+                -1 => return None,
+                _ => return Some(status),
+            }
+        }
+    }
+
+    fn ptrace(
+        request: c_uint,
+        thread_id: pid_t,
+        address: *mut c_void,
+        data: *mut c_void,
+    ) -> io::Result<libc::c_long> {
+        // PTRACE_PEEKUSER may legitimately return -1, so errno must be cleared to distinguish failures.
+        unsafe {
+            *libc::__errno_location() = 0;
+        }
+
+        let result = unsafe { libc::ptrace(request, thread_id, address, data) };
+
+        if result == -1 {
+            let error = io::Error::last_os_error();
+
+            if error.raw_os_error() != Some(0) {
+                return Err(error);
+            }
+        }
+
+        Ok(result)
+    }
+}
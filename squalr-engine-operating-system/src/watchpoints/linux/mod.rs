@@ -0,0 +1,2 @@
+pub mod linux_watchpoint_debugger;
+mod x86_64_debug_registers;
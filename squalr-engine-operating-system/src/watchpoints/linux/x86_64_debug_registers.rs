@@ -0,0 +1,105 @@
+use crate::watchpoints::watchpoint_error::WatchpointError;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+
+/// The number of address debug registers (DR0-DR3), and thus the maximum number of simultaneous watchpoints.
+pub const DEBUG_REGISTER_SLOT_COUNT: usize = 4;
+
+/// The index of the debug status register, which reports the slots that triggered a trap.
+pub const DEBUG_STATUS_REGISTER_INDEX: usize = 6;
+
+/// The index of the debug control register, which enables slots and configures their access type and length.
+pub const DEBUG_CONTROL_REGISTER_INDEX: usize = 7;
+
+/// A watchpoint assigned to one of the address debug registers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DebugRegisterWatchpoint {
+    pub address: u64,
+    pub size: u64,
+    pub watchpoint_kind: WatchpointKind,
+}
+
+impl DebugRegisterWatchpoint {
+    /// Creates a watchpoint, validating that the hardware is able to watch the given range.
+    pub fn new(
+        address: u64,
+        size: u64,
+        watchpoint_kind: WatchpointKind,
+    ) -> Result<Self, WatchpointError> {
+        if !matches!(size, 1 | 2 | 4 | 8) {
+            return Err(WatchpointError::invalid_watchpoint(address, size, "size must be 1, 2, 4, or 8 bytes"));
+        }
+
+        if !address.is_multiple_of(size) {
+            return Err(WatchpointError::invalid_watchpoint(
+                address,
+                size,
+                "address must be aligned to the watchpoint size",
+            ));
+        }
+
+        Ok(Self {
+            address,
+            size,
+            watchpoint_kind,
+        })
+    }
+}
+
+/// Encodes the debug control register (DR7) value that enables the given slots as local breakpoints.
+pub fn encode_debug_control_register(slots: &[Option<DebugRegisterWatchpoint>; DEBUG_REGISTER_SLOT_COUNT]) -> u64 {
+    slots
+        .iter()
+        .enumerate()
+        .filter_map(|(slot_index, slot)| slot.map(|watchpoint| (slot_index, watchpoint)))
+        .fold(0u64, |debug_control_register, (slot_index, watchpoint)| {
+            let access_bits: u64 = match watchpoint.watchpoint_kind {
+                WatchpointKind::Write => 0b01,
+                WatchpointKind::ReadWrite => 0b11,
+            };
+            let length_bits: u64 = match watchpoint.size {
+                1 => 0b00,
+                2 => 0b01,
+                8 => 0b10,
+                _ => 0b11,
+            };
+            let local_enable_bit = 1u64 << (slot_index * 2);
+            let condition_bits = (access_bits | (length_bits << 2)) << (16 + slot_index * 4);
+
+            debug_control_register | local_enable_bit | condition_bits
+        })
+}
+
+/// Gets the slots reported as triggered by the debug status register (DR6).
+pub fn get_triggered_slots(debug_status_register: u64) -> Vec<usize> {
+    (0..DEBUG_REGISTER_SLOT_COUNT)
+        .filter(|slot_index| debug_status_register & (1 << slot_index) != 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DebugRegisterWatchpoint, encode_debug_control_register, get_triggered_slots};
+    use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+
+    #[test]
+    fn debug_control_register_encodes_enable_access_and_length_bits_per_slot() {
+        let write_watchpoint = DebugRegisterWatchpoint::new(0x1000, 4, WatchpointKind::Write).ok();
+        let read_write_watchpoint = DebugRegisterWatchpoint::new(0x2000, 8, WatchpointKind::ReadWrite).ok();
+
+        assert_eq!(encode_debug_control_register(&[write_watchpoint, None, None, None]), 0x000D_0001);
+        assert_eq!(encode_debug_control_register(&[None, read_write_watchpoint, None, None]), 0x00B0_0004);
+        assert_eq!(encode_debug_control_register(&[None; 4]), 0);
+    }
+
+    #[test]
+    fn watchpoint_requires_supported_size_and_alignment() {
+        assert!(DebugRegisterWatchpoint::new(0x1000, 3, WatchpointKind::Write).is_err());
+        assert!(DebugRegisterWatchpoint::new(0x1002, 4, WatchpointKind::Write).is_err());
+        assert!(DebugRegisterWatchpoint::new(0x1002, 2, WatchpointKind::Write).is_ok());
+    }
+
+    #[test]
+    fn triggered_slots_are_read_from_low_status_bits() {
+        assert_eq!(get_triggered_slots(0xFFFF_0FF5), vec![0, 2]);
+    }
+}
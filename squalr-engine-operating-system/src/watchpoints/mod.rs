@@ -0,0 +1,26 @@
+pub mod watchpoint_debugger_trait;
+pub mod watchpoint_error;
+
+use std::sync::OnceLock;
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod linux;
+
+#[cfg(not(all(target_os = "linux", target_arch = "x86_64")))]
+mod unsupported;
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+pub use crate::watchpoints::linux::linux_watchpoint_debugger::LinuxWatchpointDebugger as WatchpointDebuggerImpl;
+
+#[cfg(not(all(target_os = "linux", target_arch = "x86_64")))]
+pub use crate::watchpoints::unsupported::unsupported_watchpoint_debugger::UnsupportedWatchpointDebugger as WatchpointDebuggerImpl;
+
+pub struct WatchpointDebugger;
+
+impl WatchpointDebugger {
+    pub fn get_instance() -> &'static WatchpointDebuggerImpl {
+        static INSTANCE: OnceLock<WatchpointDebuggerImpl> = OnceLock::new();
+
+        INSTANCE.get_or_init(WatchpointDebuggerImpl::new)
+    }
+}
@@ -0,0 +1 @@
+pub mod unsupported_watchpoint_debugger;
@@ -0,0 +1,59 @@
+use crate::watchpoints::watchpoint_debugger_trait::WatchpointDebuggerTrait;
+use crate::watchpoints::watchpoint_error::WatchpointError;
+use squalr_engine_api::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+
+/// Placeholder debugger for platforms without a hardware watchpoint implementation.
+pub struct UnsupportedWatchpointDebugger;
+
+impl UnsupportedWatchpointDebugger {
+    pub fn new() -> Self {
+        UnsupportedWatchpointDebugger
+    }
+}
+
+impl Default for UnsupportedWatchpointDebugger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WatchpointDebuggerTrait for UnsupportedWatchpointDebugger {
+    fn attach(
+        &self,
+        _process_id: u32,
+    ) -> Result<(), WatchpointError> {
+        Err(WatchpointError::not_supported(std::env::consts::OS))
+    }
+
+    fn detach(&self) -> Result<(), WatchpointError> {
+        Err(WatchpointError::NotAttached)
+    }
+
+    fn get_attached_process_id(&self) -> Option<u32> {
+        None
+    }
+
+    fn set_watchpoint(
+        &self,
+        _address: u64,
+        _size: u64,
+        _watchpoint_kind: WatchpointKind,
+    ) -> Result<u32, WatchpointError> {
+        Err(WatchpointError::NotAttached)
+    }
+
+    fn remove_watchpoint(
+        &self,
+        _watchpoint_id: u32,
+    ) -> Result<(), WatchpointError> {
+        Err(WatchpointError::NotAttached)
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        _clear: bool,
+    ) -> Vec<WatchpointHit> {
+        vec![]
+    }
+}
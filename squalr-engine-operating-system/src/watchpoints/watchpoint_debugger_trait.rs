@@ -0,0 +1,37 @@
+use crate::watchpoints::watchpoint_error::WatchpointError;
+use squalr_engine_api::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+
+pub trait WatchpointDebuggerTrait {
+    /// Attaches a debugger to every thread of the given process, without otherwise interrupting its execution.
+    fn attach(
+        &self,
+        process_id: u32,
+    ) -> Result<(), WatchpointError>;
+
+    /// Removes all watchpoints and detaches from the debugged process, leaving it running.
+    fn detach(&self) -> Result<(), WatchpointError>;
+
+    /// Gets the process currently being debugged, if any.
+    fn get_attached_process_id(&self) -> Option<u32>;
+
+    /// Watches the given address range for accesses, returning the id of the new watchpoint.
+    fn set_watchpoint(
+        &self,
+        address: u64,
+        size: u64,
+        watchpoint_kind: WatchpointKind,
+    ) -> Result<u32, WatchpointError>;
+
+    /// Stops watching memory for the given watchpoint.
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u32,
+    ) -> Result<(), WatchpointError>;
+
+    /// Gets each unique instruction that has triggered a watchpoint, optionally clearing the collected hits.
+    fn get_watchpoint_hits(
+        &self,
+        clear: bool,
+    ) -> Vec<WatchpointHit>;
+}
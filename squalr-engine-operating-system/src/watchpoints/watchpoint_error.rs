@@ -0,0 +1,70 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WatchpointError {
+    #[error("Watchpoints are not supported on `{platform}`.")]
+    NotSupported { platform: &'static str },
+    #[error("Already debugging process with id `{process_id}`.")]
+    AlreadyAttached { process_id: u32 },
+    #[error("No process is being debugged.")]
+    NotAttached,
+    #[error("Failed to attach to process with id `{process_id}`: {details}.")]
+    AttachFailed { process_id: u32, details: String },
+    #[error("Invalid watchpoint at `0x{address:X}` with size `{size}`: {details}.")]
+    InvalidWatchpoint { address: u64, size: u64, details: &'static str },
+    #[error("All {slot_count} hardware watchpoint slots are in use.")]
+    NoFreeSlots { slot_count: usize },
+    #[error("No watchpoint with id `{watchpoint_id}` exists.")]
+    UnknownWatchpoint { watchpoint_id: u32 },
+    #[error("Debugger operation `{operation}` failed: {details}.")]
+    Internal { operation: &'static str, details: String },
+}
+
+impl WatchpointError {
+    pub fn not_supported(platform: &'static str) -> Self {
+        Self::NotSupported { platform }
+    }
+
+    pub fn attach_failed(
+        process_id: u32,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::AttachFailed {
+            process_id,
+            details: details.into(),
+        }
+    }
+
+    pub fn invalid_watchpoint(
+        address: u64,
+        size: u64,
+        details: &'static str,
+    ) -> Self {
+        Self::InvalidWatchpoint { address, size, details }
+    }
+
+    pub fn internal(
+        operation: &'static str,
+        details: impl Into<String>,
+    ) -> Self {
+        Self::Internal {
+            operation,
+            details: details.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WatchpointError;
+
+    #[test]
+    fn invalid_watchpoint_error_contains_address_size_and_details() {
+        let error = WatchpointError::invalid_watchpoint(0x1001, 4, "address must be aligned to the watchpoint size");
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid watchpoint at `0x1001` with size `4`: address must be aligned to the watchpoint size."
+        );
+    }
+}
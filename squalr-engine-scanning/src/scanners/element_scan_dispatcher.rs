@@ -3,11 +3,13 @@ use crate::scanners::scalar::scanner_scalar_iterative::ScannerScalarIterative;
 use crate::scanners::scalar::scanner_scalar_single_element::ScannerScalarSingleElement;
 use crate::scanners::scanner_null::ScannerNull;
 use crate::scanners::snapshot_scanner::Scanner;
+use crate::scanners::structures::snapshot_region_filter_set_operations::SnapshotRegionFilterSetOperations;
 use crate::scanners::vector::scanner_vector_aligned::ScannerVectorAligned;
 use crate::scanners::vector::scanner_vector_overlapping::ScannerVectorOverlapping;
 use crate::scanners::vector::scanner_vector_sparse::ScannerVectorSparse;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::ScanConstraintExpression;
 use squalr_engine_api::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
 use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
 use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
@@ -66,26 +68,111 @@ impl ElementScanDispatcher {
         )
     }
 
-    // This method orchestrates multiple scan parameters to combine when scanning a single snapshot region.
-    // Currently, this assumes only AND operations are supported, ie value >= 2000 && value <= 5000.
-    // This works by simply running constraints sequentially to produce filters.
+    // This method orchestrates a scan constraint expression when scanning a single snapshot region, ie (value >= 2000 && value <= 5000) || value == 0.
+    // Each constraint is still mapped to the best scanner individually, and the resulting filters are combined based on the expression operators.
     fn dispatch_scan_for_snapshot_filter_collection(
         snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
         snapshot_region_filter: &SnapshotRegionFilter,
         snapshot_region: &SnapshotRegion,
         element_scan_plan: &ElementScanPlan,
     ) -> Option<Vec<SnapshotRegionFilter>> {
-        let scan_constraints = match element_scan_plan
+        let scan_constraint_expression = match element_scan_plan
             .get_scan_constraints_by_data_type()
             .get(snapshot_region_filter_collection.get_data_type_ref())
         {
-            Some(scan_constraints) => scan_constraints,
+            Some(scan_constraint_expression) => scan_constraint_expression,
             None => return None,
         };
-        let mut scan_result_filters = vec![snapshot_region_filter.clone()];
+        let scan_result_filters = Self::dispatch_scan_for_expression(
+            vec![snapshot_region_filter.clone()],
+            scan_constraint_expression,
+            snapshot_region_filter_collection,
+            snapshot_region,
+            element_scan_plan,
+        );
 
+        if scan_result_filters.is_empty() { None } else { Some(scan_result_filters) }
+    }
+
+    /// Evaluates a scan constraint expression over the given filters. AND operations are performed by running operands sequentially
+    /// over the current result filters, OR operations take the union of each operand's results, and NOT operations take the difference.
+    fn dispatch_scan_for_expression(
+        scan_result_filters: Vec<SnapshotRegionFilter>,
+        scan_constraint_expression: &ScanConstraintExpression<ScanConstraintFinalized>,
+        snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
+        snapshot_region: &SnapshotRegion,
+        element_scan_plan: &ElementScanPlan,
+    ) -> Vec<SnapshotRegionFilter> {
+        if scan_result_filters.is_empty() {
+            return scan_result_filters;
+        }
+
+        match scan_constraint_expression {
+            ScanConstraintExpression::Constraint(scan_constraint_finalized) => Self::dispatch_scan_for_constraint(
+                &scan_result_filters,
+                scan_constraint_finalized,
+                snapshot_region_filter_collection,
+                snapshot_region,
+                element_scan_plan,
+            ),
+            ScanConstraintExpression::And(scan_constraint_expressions) => {
+                scan_constraint_expressions
+                    .iter()
+                    .fold(scan_result_filters, |scan_result_filters, scan_constraint_expression| {
+                        Self::dispatch_scan_for_expression(
+                            scan_result_filters,
+                            scan_constraint_expression,
+                            snapshot_region_filter_collection,
+                            snapshot_region,
+                            element_scan_plan,
+                        )
+                    })
+            }
+            ScanConstraintExpression::Or(scan_constraint_expressions) => {
+                let operand_result_filters: Vec<SnapshotRegionFilter> = scan_constraint_expressions
+                    .iter()
+                    .flat_map(|scan_constraint_expression| {
+                        Self::dispatch_scan_for_expression(
+                            scan_result_filters.clone(),
+                            scan_constraint_expression,
+                            snapshot_region_filter_collection,
+                            snapshot_region,
+                            element_scan_plan,
+                        )
+                    })
+                    .collect();
+
+                match Self::create_set_operations(scan_constraint_expression, element_scan_plan) {
+                    Some(set_operations) => set_operations.union(&operand_result_filters),
+                    None => vec![],
+                }
+            }
+            ScanConstraintExpression::Not(scan_constraint_expression) => {
+                let excluded_filters = Self::dispatch_scan_for_expression(
+                    scan_result_filters.clone(),
+                    scan_constraint_expression,
+                    snapshot_region_filter_collection,
+                    snapshot_region,
+                    element_scan_plan,
+                );
+
+                match Self::create_set_operations(scan_constraint_expression, element_scan_plan) {
+                    Some(set_operations) => set_operations.difference(&scan_result_filters, &excluded_filters),
+                    None => vec![],
+                }
+            }
+        }
+    }
+
+    fn dispatch_scan_for_constraint(
+        scan_result_filters: &[SnapshotRegionFilter],
+        scan_constraint_finalized: &ScanConstraintFinalized,
+        snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
+        snapshot_region: &SnapshotRegion,
+        element_scan_plan: &ElementScanPlan,
+    ) -> Vec<SnapshotRegionFilter> {
         // Helper function to map the given element scan parameters to optimized mapped parameters for the given filter.
-        let process_constraint = |snapshot_region_filter: &SnapshotRegionFilter, scan_constraint_finalized: &ScanConstraintFinalized| {
+        let process_constraint = |snapshot_region_filter: &SnapshotRegionFilter| {
             let mut snapshot_filter_element_scan_plan = SnapshotFilterElementScanPlan::new(
                 scan_constraint_finalized,
                 element_scan_plan.get_memory_alignment(),
@@ -109,25 +196,29 @@ impl ElementScanDispatcher {
             Self::dispatch_scan_for_snapshot_filter(snapshot_region_filter, &snapshot_filter_element_scan_plan, snapshot_region, element_scan_plan)
         };
 
-        // Perform each scan sequentially over the current result filters.
-        for scan_constraint in scan_constraints {
-            scan_result_filters = if element_scan_plan.get_is_single_thread_scan() {
-                scan_result_filters
-                    .iter()
-                    .flat_map(|snapshot_region_filter| process_constraint(snapshot_region_filter, scan_constraint))
-                    .collect()
-            } else {
-                scan_result_filters
-                    .par_iter()
-                    .flat_map_iter(|snapshot_region_filter| process_constraint(snapshot_region_filter, scan_constraint))
-                    .collect()
-            };
-
-            if scan_result_filters.is_empty() {
-                return None;
-            }
+        if element_scan_plan.get_is_single_thread_scan() {
+            scan_result_filters
+                .iter()
+                .flat_map(process_constraint)
+                .collect()
+        } else {
+            scan_result_filters
+                .par_iter()
+                .flat_map_iter(process_constraint)
+                .collect()
         }
-        if scan_result_filters.is_empty() { None } else { Some(scan_result_filters) }
+    }
+
+    /// Creates set operations for combining the filters of an expression, based on the element size of the constraints in that expression.
+    fn create_set_operations(
+        scan_constraint_expression: &ScanConstraintExpression<ScanConstraintFinalized>,
+        element_scan_plan: &ElementScanPlan,
+    ) -> Option<SnapshotRegionFilterSetOperations> {
+        scan_constraint_expression
+            .get_first_constraint()
+            .map(|scan_constraint_finalized| {
+                SnapshotRegionFilterSetOperations::new(scan_constraint_finalized.get_unit_size_in_bytes(), element_scan_plan.get_memory_alignment())
+            })
     }
 
     fn dispatch_scan_for_snapshot_filter(
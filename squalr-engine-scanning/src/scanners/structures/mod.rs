@@ -1,2 +1,3 @@
 pub mod boyer_moore_table;
 pub mod snapshot_region_filter_run_length_encoder;
+pub mod snapshot_region_filter_set_operations;
@@ -0,0 +1,126 @@
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use std::cmp::max;
+
+/// Implements set operations over region filters produced by scans, which is how the results of boolean scan expressions are combined.
+/// Filters are padded to fit the last element in the range, so each operation first reduces filters to the ranges of element starting
+/// addresses, combines these ranges, and then re-applies the padding for the data type.
+pub struct SnapshotRegionFilterSetOperations {
+    data_type_unit_size_bytes: u64,
+    memory_alignment: MemoryAlignment,
+}
+
+impl SnapshotRegionFilterSetOperations {
+    pub fn new(
+        data_type_unit_size_bytes: u64,
+        memory_alignment: MemoryAlignment,
+    ) -> Self {
+        Self {
+            data_type_unit_size_bytes,
+            memory_alignment,
+        }
+    }
+
+    /// Produces filters containing every element found in any of the given filters.
+    pub fn union(
+        &self,
+        snapshot_region_filters: &[SnapshotRegionFilter],
+    ) -> Vec<SnapshotRegionFilter> {
+        let element_ranges = self.merge_element_ranges(self.to_element_ranges(snapshot_region_filters));
+
+        self.to_filters(element_ranges)
+    }
+
+    /// Produces filters containing every element of the source filters that is not contained by the excluded filters.
+    pub fn difference(
+        &self,
+        source_filters: &[SnapshotRegionFilter],
+        excluded_filters: &[SnapshotRegionFilter],
+    ) -> Vec<SnapshotRegionFilter> {
+        let source_ranges = self.merge_element_ranges(self.to_element_ranges(source_filters));
+        let excluded_ranges = self.merge_element_ranges(self.to_element_ranges(excluded_filters));
+        let mut result_ranges = vec![];
+        let mut excluded_index = 0;
+
+        for (source_start, source_end) in source_ranges {
+            let mut current_start = source_start;
+
+            // Skip exclusions that end before this source range. Both lists are sorted, so these can never overlap later ranges either.
+            while excluded_index < excluded_ranges.len() && excluded_ranges[excluded_index].1 <= current_start {
+                excluded_index += 1;
+            }
+
+            let mut next_index = excluded_index;
+
+            while next_index < excluded_ranges.len() && excluded_ranges[next_index].0 < source_end {
+                let (excluded_start, excluded_end) = excluded_ranges[next_index];
+
+                if excluded_start > current_start {
+                    result_ranges.push((current_start, excluded_start));
+                }
+
+                current_start = max(current_start, excluded_end);
+                next_index += 1;
+            }
+
+            if current_start < source_end {
+                result_ranges.push((current_start, source_end));
+            }
+        }
+
+        self.to_filters(result_ranges)
+    }
+
+    /// Converts filters to sorted half-open ranges of element starting addresses.
+    fn to_element_ranges(
+        &self,
+        snapshot_region_filters: &[SnapshotRegionFilter],
+    ) -> Vec<(u64, u64)> {
+        let memory_alignment_size = max(self.memory_alignment as u64, 1);
+        let mut element_ranges: Vec<(u64, u64)> = snapshot_region_filters
+            .iter()
+            .filter(|snapshot_region_filter| snapshot_region_filter.get_region_size() >= self.data_type_unit_size_bytes)
+            .map(|snapshot_region_filter| {
+                let element_count = snapshot_region_filter.get_element_count(self.data_type_unit_size_bytes, self.memory_alignment);
+                let base_address = snapshot_region_filter.get_base_address();
+
+                (base_address, base_address + element_count * memory_alignment_size)
+            })
+            .filter(|(start_address, end_address)| start_address < end_address)
+            .collect();
+
+        element_ranges.sort_unstable();
+        element_ranges
+    }
+
+    /// Merges sorted element ranges that overlap or touch.
+    fn merge_element_ranges(
+        &self,
+        element_ranges: Vec<(u64, u64)>,
+    ) -> Vec<(u64, u64)> {
+        let mut merged_ranges: Vec<(u64, u64)> = Vec::with_capacity(element_ranges.len());
+
+        for (start_address, end_address) in element_ranges {
+            match merged_ranges.last_mut() {
+                Some(last_range) if start_address <= last_range.1 => last_range.1 = max(last_range.1, end_address),
+                _ => merged_ranges.push((start_address, end_address)),
+            }
+        }
+
+        merged_ranges
+    }
+
+    fn to_filters(
+        &self,
+        element_ranges: Vec<(u64, u64)>,
+    ) -> Vec<SnapshotRegionFilter> {
+        let padding = self
+            .data_type_unit_size_bytes
+            .saturating_sub(max(self.memory_alignment as u64, 1));
+
+        element_ranges
+            .into_iter()
+            .map(|(start_address, end_address)| SnapshotRegionFilter::new(start_address, end_address - start_address + padding))
+            .collect()
+    }
+}
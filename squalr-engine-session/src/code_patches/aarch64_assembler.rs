@@ -0,0 +1,122 @@
+use squalr_engine_api::conversions::conversions_from_primitives::Conversions;
+use squalr_engine_api::structures::code_patches::code_patch_error::CodePatchError;
+
+/// The AArch64 no-op instruction.
+pub const AARCH64_NOP: u32 = 0xD503201F;
+
+const CONDITION_NAMES: [&str; 15] = [
+    "eq", "ne", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al",
+];
+
+/// Assembles the subset of AArch64 instructions commonly used to patch code: `nop`, `ret`, `brk`,
+/// `b`, `bl` and `b.cond` to absolute targets, and raw `.inst` words.
+pub struct AArch64Assembler;
+
+impl AArch64Assembler {
+    /// Assembles a single instruction located at the given address.
+    pub fn assemble_instruction(
+        instruction: &str,
+        address: u64,
+    ) -> Result<Vec<u8>, CodePatchError> {
+        let (mnemonic, operands) = instruction
+            .split_once(char::is_whitespace)
+            .map(|(mnemonic, operands)| (mnemonic, operands.trim()))
+            .unwrap_or((instruction, ""));
+        let mnemonic = mnemonic.to_ascii_lowercase();
+        let word = match (mnemonic.as_str(), operands.is_empty()) {
+            ("nop", true) => AARCH64_NOP,
+            ("ret", true) => 0xD65F03C0,
+            ("brk", false) => {
+                let immediate = Self::parse_immediate(instruction, operands)?;
+
+                if immediate > u16::MAX as u64 {
+                    return Err(CodePatchError::invalid_instruction(instruction, "immediate exceeds 16 bits"));
+                }
+
+                0xD4200000 | ((immediate as u32) << 5)
+            }
+            (".inst", false) => {
+                let word = Self::parse_immediate(instruction, operands)?;
+
+                u32::try_from(word).map_err(|_| CodePatchError::invalid_instruction(instruction, "word exceeds 32 bits"))?
+            }
+            ("b", false) => 0x14000000 | Self::encode_offset(instruction, operands, address, 26)?,
+            ("bl", false) => 0x94000000 | Self::encode_offset(instruction, operands, address, 26)?,
+            _ => match mnemonic.strip_prefix("b.").and_then(Self::get_condition_code) {
+                Some(condition_code) if !operands.is_empty() => 0x54000000 | (Self::encode_offset(instruction, operands, address, 19)? << 5) | condition_code,
+                _ => return Err(CodePatchError::invalid_instruction(instruction, "unsupported instruction")),
+            },
+        };
+
+        Ok(word.to_le_bytes().to_vec())
+    }
+
+    /// Encodes the word offset from the instruction to the target as a signed immediate of the given width.
+    fn encode_offset(
+        instruction: &str,
+        operands: &str,
+        address: u64,
+        bit_count: u32,
+    ) -> Result<u32, CodePatchError> {
+        let target_address = Self::parse_immediate(instruction, operands)?;
+        let byte_offset = target_address.wrapping_sub(address) as i64;
+        let word_offset = byte_offset >> 2;
+        let limit = 1i64 << (bit_count - 1);
+
+        if byte_offset % 4 != 0 {
+            return Err(CodePatchError::invalid_instruction(instruction, "target is not 4 byte aligned"));
+        }
+
+        if word_offset < -limit || word_offset >= limit {
+            return Err(CodePatchError::invalid_instruction(instruction, "target is out of range"));
+        }
+
+        Ok((word_offset as u32) & ((1u32 << bit_count) - 1))
+    }
+
+    fn get_condition_code(condition_name: &str) -> Option<u32> {
+        let condition_name = match condition_name {
+            "cs" => "hs",
+            "cc" => "lo",
+            condition_name => condition_name,
+        };
+
+        CONDITION_NAMES
+            .iter()
+            .position(|name| *name == condition_name)
+            .map(|condition_code| condition_code as u32)
+    }
+
+    fn parse_immediate(
+        instruction: &str,
+        operand: &str,
+    ) -> Result<u64, CodePatchError> {
+        let operand = operand.trim();
+        let operand = operand.strip_prefix('#').unwrap_or(operand);
+
+        Conversions::parse_hex_or_int(operand).map_err(|_| CodePatchError::invalid_instruction(instruction, format!("invalid operand '{}'", operand)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AArch64Assembler;
+
+    #[test]
+    fn assemble_instruction_encodes_branches_relative_to_the_instruction() {
+        assert_eq!(
+            AArch64Assembler::assemble_instruction("b 0x1010", 0x1000).unwrap(),
+            0x14000004u32.to_le_bytes().to_vec()
+        );
+        assert_eq!(
+            AArch64Assembler::assemble_instruction("bl 0xFFC", 0x1000).unwrap(),
+            0x97FFFFFFu32.to_le_bytes().to_vec()
+        );
+        assert_eq!(
+            AArch64Assembler::assemble_instruction("b.ne 0x1008", 0x1000).unwrap(),
+            0x54000041u32.to_le_bytes().to_vec()
+        );
+        assert!(AArch64Assembler::assemble_instruction("b 0x1002", 0x1000).is_err());
+        assert!(AArch64Assembler::assemble_instruction("add x0, x0, #1", 0x1000).is_err());
+    }
+}
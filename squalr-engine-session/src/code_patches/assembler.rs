@@ -0,0 +1,56 @@
+use crate::code_patches::aarch64_assembler::{AARCH64_NOP, AArch64Assembler};
+use crate::code_patches::x86_assembler::{X86_NOP, X86Assembler};
+use squalr_engine_api::structures::code_patches::code_patch_error::CodePatchError;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+
+/// Assembles short instruction sequences for code patches.
+pub struct Assembler;
+
+impl Assembler {
+    /// Assembles instructions separated by newlines or semicolons, where the first instruction is located at the given address.
+    pub fn assemble(
+        instruction_set: InstructionSet,
+        text: &str,
+        address: u64,
+    ) -> Result<Vec<u8>, CodePatchError> {
+        let mut bytes = Vec::new();
+
+        for instruction in text
+            .split(['\n', ';'])
+            .map(str::trim)
+            .filter(|instruction| !instruction.is_empty())
+        {
+            let instruction_address = address.wrapping_add(bytes.len() as u64);
+            let instruction_bytes = match instruction_set {
+                InstructionSet::X86 => X86Assembler::new(false).assemble_instruction(instruction, instruction_address)?,
+                InstructionSet::X64 => X86Assembler::new(true).assemble_instruction(instruction, instruction_address)?,
+                InstructionSet::AArch64 => AArch64Assembler::assemble_instruction(instruction, instruction_address)?,
+            };
+
+            bytes.extend(instruction_bytes);
+        }
+
+        Ok(bytes)
+    }
+
+    /// Gets the encoding of a single no-op instruction.
+    pub fn get_nop_bytes(instruction_set: InstructionSet) -> Vec<u8> {
+        match instruction_set {
+            InstructionSet::X86 | InstructionSet::X64 => vec![X86_NOP],
+            InstructionSet::AArch64 => AARCH64_NOP.to_le_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Assembler;
+    use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+
+    #[test]
+    fn assemble_places_each_instruction_after_the_previous_one() {
+        let bytes = Assembler::assemble(InstructionSet::X64, "nop; jmp 0x1000\nint3", 0x1000).unwrap();
+
+        assert_eq!(bytes, vec![0x90, 0xE9, 0xFA, 0xFF, 0xFF, 0xFF, 0xCC]);
+    }
+}
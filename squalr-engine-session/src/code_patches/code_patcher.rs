@@ -0,0 +1,202 @@
+use crate::code_patches::assembler::Assembler;
+use crate::os::engine_os_provider::EngineOsProviders;
+use squalr_engine_api::structures::code_patches::applied_code_patch::AppliedCodePatch;
+use squalr_engine_api::structures::code_patches::code_patch_error::CodePatchError;
+use squalr_engine_api::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Writes code patches to an opened process, only ever overwriting bytes that match the expected original code.
+pub struct CodePatcher;
+
+impl CodePatcher {
+    /// Verifies that the original bytes are present at the address, then overwrites them with the replacement.
+    pub fn apply(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        original_bytes: &[u8],
+        replacement: &CodePatchReplacement,
+    ) -> Result<AppliedCodePatch, CodePatchError> {
+        let instruction_set = InstructionSet::from_bitness(process_info.get_bitness());
+        let patch_bytes = Self::build_patch_bytes(instruction_set, replacement, address, original_bytes.len())?;
+
+        Self::verify_bytes(
+            os_providers,
+            process_info,
+            address,
+            original_bytes,
+            CodePatchError::original_bytes_mismatch(address),
+        )?;
+        Self::write_bytes(os_providers, process_info, address, &patch_bytes)?;
+
+        Ok(AppliedCodePatch::new(
+            process_info.get_process_id_raw(),
+            address,
+            original_bytes.to_vec(),
+            patch_bytes,
+        ))
+    }
+
+    /// Restores the original bytes of an applied patch, provided the patch bytes have not since been overwritten.
+    pub fn restore(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        applied_code_patch: &AppliedCodePatch,
+    ) -> Result<(), CodePatchError> {
+        let address = applied_code_patch.get_address();
+
+        Self::verify_bytes(
+            os_providers,
+            process_info,
+            address,
+            applied_code_patch.get_patch_bytes(),
+            CodePatchError::patch_bytes_mismatch(address),
+        )?;
+        Self::write_bytes(os_providers, process_info, address, applied_code_patch.get_original_bytes())
+    }
+
+    /// Builds the bytes written for a replacement, padded with no-op instructions to the size of the original bytes.
+    pub fn build_patch_bytes(
+        instruction_set: InstructionSet,
+        replacement: &CodePatchReplacement,
+        address: u64,
+        original_size: usize,
+    ) -> Result<Vec<u8>, CodePatchError> {
+        if original_size == 0 {
+            return Err(CodePatchError::MissingOriginalBytes);
+        }
+
+        let nop_bytes = Assembler::get_nop_bytes(instruction_set);
+        let mut patch_bytes = match replacement {
+            CodePatchReplacement::Nop => Vec::new(),
+            CodePatchReplacement::Bytes(bytes) => bytes.clone(),
+            CodePatchReplacement::Assembly(assembly) => Assembler::assemble(instruction_set, assembly, address)?,
+        };
+
+        if patch_bytes.len() > original_size {
+            return Err(CodePatchError::patch_exceeds_original_bytes(patch_bytes.len(), original_size));
+        }
+
+        let padding_size = original_size - patch_bytes.len();
+
+        if !padding_size.is_multiple_of(nop_bytes.len()) {
+            return Err(CodePatchError::misaligned_patch(original_size, nop_bytes.len()));
+        }
+
+        for _ in 0..padding_size / nop_bytes.len() {
+            patch_bytes.extend_from_slice(&nop_bytes);
+        }
+
+        Ok(patch_bytes)
+    }
+
+    fn verify_bytes(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        expected_bytes: &[u8],
+        mismatch_error: CodePatchError,
+    ) -> Result<(), CodePatchError> {
+        let mut current_bytes = vec![0u8; expected_bytes.len()];
+
+        if !os_providers
+            .memory_read
+            .read_bytes(process_info, address, &mut current_bytes)
+        {
+            return Err(CodePatchError::unreadable_memory(address, expected_bytes.len()));
+        }
+
+        if current_bytes != expected_bytes {
+            return Err(mismatch_error);
+        }
+
+        Ok(())
+    }
+
+    fn write_bytes(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        bytes: &[u8],
+    ) -> Result<(), CodePatchError> {
+        if os_providers
+            .memory_write
+            .write_bytes(process_info, address, bytes)
+        {
+            Ok(())
+        } else {
+            Err(CodePatchError::write_failed(address, bytes.len()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodePatcher;
+    use squalr_engine_api::structures::code_patches::code_patch_error::CodePatchError;
+    use squalr_engine_api::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+    use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+
+    #[test]
+    fn build_patch_bytes_pads_with_nops_to_the_original_size() {
+        let replacement = CodePatchReplacement::Bytes(vec![0xEB, 0x03]);
+
+        assert_eq!(
+            CodePatcher::build_patch_bytes(InstructionSet::X64, &replacement, 0x1000, 5).unwrap(),
+            vec![0xEB, 0x03, 0x90, 0x90, 0x90]
+        );
+        assert_eq!(
+            CodePatcher::build_patch_bytes(InstructionSet::AArch64, &CodePatchReplacement::Nop, 0x1000, 8).unwrap(),
+            vec![0x1F, 0x20, 0x03, 0xD5, 0x1F, 0x20, 0x03, 0xD5]
+        );
+    }
+
+    #[test]
+    fn build_patch_bytes_rejects_oversized_and_misaligned_patches() {
+        let replacement = CodePatchReplacement::Bytes(vec![0x90, 0x90, 0x90]);
+
+        assert_eq!(
+            CodePatcher::build_patch_bytes(InstructionSet::X64, &replacement, 0x1000, 2),
+            Err(CodePatchError::patch_exceeds_original_bytes(3, 2))
+        );
+        assert_eq!(
+            CodePatcher::build_patch_bytes(InstructionSet::AArch64, &CodePatchReplacement::Nop, 0x1000, 6),
+            Err(CodePatchError::misaligned_patch(6, 4))
+        );
+        assert_eq!(
+            CodePatcher::build_patch_bytes(InstructionSet::X64, &CodePatchReplacement::Nop, 0x1000, 0),
+            Err(CodePatchError::MissingOriginalBytes)
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[inline(never)]
+    extern "C" fn patch_target() -> u64 {
+        std::hint::black_box(0x1234_5678)
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn apply_and_restore_write_to_executable_pages() {
+        use crate::os::engine_os_provider::EngineOsProviders;
+        use squalr_engine_api::structures::memory::bitness::Bitness;
+        use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+        let os_providers = EngineOsProviders::default();
+        let process_info = OpenedProcessInfo::new(std::process::id(), "squalr-engine-session".to_string(), 0, Bitness::Bit64, None);
+        let address = patch_target as *const () as u64;
+        let read_code = || unsafe { std::ptr::read_volatile(address as *const [u8; 4]) };
+        let original_bytes = read_code();
+
+        let applied_code_patch = CodePatcher::apply(&os_providers, &process_info, address, &original_bytes, &CodePatchReplacement::Nop)
+            .expect("patch should be written to the executable page");
+
+        assert_eq!(read_code(), [0x90, 0x90, 0x90, 0x90]);
+
+        CodePatcher::restore(&os_providers, &process_info, &applied_code_patch).expect("patch should be restored");
+
+        assert_eq!(read_code(), original_bytes);
+        assert_eq!(patch_target(), 0x1234_5678);
+    }
+}
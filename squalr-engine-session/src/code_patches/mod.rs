@@ -0,0 +1,4 @@
+pub mod aarch64_assembler;
+pub mod assembler;
+pub mod code_patcher;
+pub mod x86_assembler;
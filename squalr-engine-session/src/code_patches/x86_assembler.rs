@@ -0,0 +1,165 @@
+use iced_x86::{Code, Encoder, Instruction};
+use squalr_engine_api::conversions::conversions_from_primitives::Conversions;
+use squalr_engine_api::structures::code_patches::code_patch_error::CodePatchError;
+use squalr_engine_api::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+
+/// The single byte x86 no-op instruction.
+pub const X86_NOP: u8 = 0x90;
+
+/// Conditional jump suffixes, and their near branch encodings in 64-bit and 32-bit code.
+const CONDITIONAL_JUMPS: [(&str, Code, Code); 30] = [
+    ("o", Code::Jo_rel32_64, Code::Jo_rel32_32),
+    ("no", Code::Jno_rel32_64, Code::Jno_rel32_32),
+    ("b", Code::Jb_rel32_64, Code::Jb_rel32_32),
+    ("c", Code::Jb_rel32_64, Code::Jb_rel32_32),
+    ("nae", Code::Jb_rel32_64, Code::Jb_rel32_32),
+    ("ae", Code::Jae_rel32_64, Code::Jae_rel32_32),
+    ("nb", Code::Jae_rel32_64, Code::Jae_rel32_32),
+    ("nc", Code::Jae_rel32_64, Code::Jae_rel32_32),
+    ("e", Code::Je_rel32_64, Code::Je_rel32_32),
+    ("z", Code::Je_rel32_64, Code::Je_rel32_32),
+    ("ne", Code::Jne_rel32_64, Code::Jne_rel32_32),
+    ("nz", Code::Jne_rel32_64, Code::Jne_rel32_32),
+    ("be", Code::Jbe_rel32_64, Code::Jbe_rel32_32),
+    ("na", Code::Jbe_rel32_64, Code::Jbe_rel32_32),
+    ("a", Code::Ja_rel32_64, Code::Ja_rel32_32),
+    ("nbe", Code::Ja_rel32_64, Code::Ja_rel32_32),
+    ("s", Code::Js_rel32_64, Code::Js_rel32_32),
+    ("ns", Code::Jns_rel32_64, Code::Jns_rel32_32),
+    ("p", Code::Jp_rel32_64, Code::Jp_rel32_32),
+    ("pe", Code::Jp_rel32_64, Code::Jp_rel32_32),
+    ("np", Code::Jnp_rel32_64, Code::Jnp_rel32_32),
+    ("po", Code::Jnp_rel32_64, Code::Jnp_rel32_32),
+    ("l", Code::Jl_rel32_64, Code::Jl_rel32_32),
+    ("nge", Code::Jl_rel32_64, Code::Jl_rel32_32),
+    ("ge", Code::Jge_rel32_64, Code::Jge_rel32_32),
+    ("nl", Code::Jge_rel32_64, Code::Jge_rel32_32),
+    ("le", Code::Jle_rel32_64, Code::Jle_rel32_32),
+    ("ng", Code::Jle_rel32_64, Code::Jle_rel32_32),
+    ("g", Code::Jg_rel32_64, Code::Jg_rel32_32),
+    ("nle", Code::Jg_rel32_64, Code::Jg_rel32_32),
+];
+
+/// Assembles the subset of x86 and x64 instructions commonly used to patch code: `nop`, `int3`, `ret`, `ret imm16`,
+/// `jmp`, `call` and conditional jumps to absolute targets, and raw `db` bytes. Instructions are encoded with iced-x86, and branches
+/// always use 32-bit displacements such that patch sizes do not depend on the distance to their targets.
+pub struct X86Assembler {
+    is_64_bit: bool,
+}
+
+impl X86Assembler {
+    pub fn new(is_64_bit: bool) -> Self {
+        Self { is_64_bit }
+    }
+
+    /// Assembles a single instruction located at the given address.
+    pub fn assemble_instruction(
+        &self,
+        instruction: &str,
+        address: u64,
+    ) -> Result<Vec<u8>, CodePatchError> {
+        let (mnemonic, operands) = instruction
+            .split_once(char::is_whitespace)
+            .map(|(mnemonic, operands)| (mnemonic, operands.trim()))
+            .unwrap_or((instruction, ""));
+        let mnemonic = mnemonic.to_ascii_lowercase();
+        let code_instruction = match (mnemonic.as_str(), operands.is_empty()) {
+            ("db", false) => return CodePatchReplacement::parse_bytes(operands),
+            ("nop", true) => Instruction::with(Code::Nopd),
+            ("int3", true) => Instruction::with(Code::Int3),
+            ("ret", true) => Instruction::with(self.select_code(Code::Retnq, Code::Retnd)),
+            ("ret", false) => {
+                let pop_size = Self::parse_immediate(instruction, operands)?;
+                let pop_size = u16::try_from(pop_size).map_err(|_| CodePatchError::invalid_instruction(instruction, "immediate exceeds 16 bits"))?;
+
+                Instruction::with1(self.select_code(Code::Retnq_imm16, Code::Retnd_imm16), pop_size as u32)
+                    .map_err(|error| CodePatchError::invalid_instruction(instruction, error.to_string()))?
+            }
+            ("jmp", false) => self.create_branch(instruction, self.select_code(Code::Jmp_rel32_64, Code::Jmp_rel32_32), operands)?,
+            ("call", false) => self.create_branch(instruction, self.select_code(Code::Call_rel32_64, Code::Call_rel32_32), operands)?,
+            _ => match Self::get_conditional_jump_codes(&mnemonic) {
+                Some((code_64, code_32)) if !operands.is_empty() => self.create_branch(instruction, self.select_code(code_64, code_32), operands)?,
+                _ => return Err(CodePatchError::invalid_instruction(instruction, "unsupported instruction")),
+            },
+        };
+        let mut encoder = Encoder::new(if self.is_64_bit { 64 } else { 32 });
+
+        encoder
+            .encode(&code_instruction, address)
+            .map_err(|error| CodePatchError::invalid_instruction(instruction, error.to_string()))?;
+
+        Ok(encoder.take_buffer())
+    }
+
+    /// Creates a branch to the absolute target given by the operand.
+    fn create_branch(
+        &self,
+        instruction: &str,
+        code: Code,
+        operands: &str,
+    ) -> Result<Instruction, CodePatchError> {
+        let target_address = Self::parse_immediate(instruction, operands)?;
+        let target_address = if self.is_64_bit { target_address } else { target_address as u32 as u64 };
+
+        Instruction::with_branch(code, target_address).map_err(|error| CodePatchError::invalid_instruction(instruction, error.to_string()))
+    }
+
+    fn select_code(
+        &self,
+        code_64: Code,
+        code_32: Code,
+    ) -> Code {
+        if self.is_64_bit { code_64 } else { code_32 }
+    }
+
+    fn get_conditional_jump_codes(mnemonic: &str) -> Option<(Code, Code)> {
+        let condition_name = mnemonic.strip_prefix('j')?;
+
+        CONDITIONAL_JUMPS
+            .iter()
+            .find(|(name, _code_64, _code_32)| *name == condition_name)
+            .map(|(_name, code_64, code_32)| (*code_64, *code_32))
+    }
+
+    fn parse_immediate(
+        instruction: &str,
+        operand: &str,
+    ) -> Result<u64, CodePatchError> {
+        Conversions::parse_hex_or_int(operand.trim()).map_err(|_| CodePatchError::invalid_instruction(instruction, format!("invalid operand '{}'", operand)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::X86Assembler;
+
+    #[test]
+    fn assemble_instruction_encodes_relative_branches_from_instruction_end() {
+        let assembler = X86Assembler::new(true);
+
+        assert_eq!(assembler.assemble_instruction("jmp 0x1010", 0x1000).unwrap(), vec![0xE9, 0x0B, 0, 0, 0]);
+        assert_eq!(
+            assembler.assemble_instruction("call 0x1000", 0x1000).unwrap(),
+            vec![0xE8, 0xFB, 0xFF, 0xFF, 0xFF]
+        );
+        assert_eq!(assembler.assemble_instruction("jne 0x1010", 0x1000).unwrap(), vec![0x0F, 0x85, 0x0A, 0, 0, 0]);
+        assert!(
+            assembler
+                .assemble_instruction("jmp 0x200000000", 0x1000)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn assemble_instruction_encodes_simple_instructions_and_rejects_unknown_ones() {
+        let assembler = X86Assembler::new(false);
+
+        assert_eq!(assembler.assemble_instruction("ret 8", 0).unwrap(), vec![0xC2, 0x08, 0x00]);
+        assert_eq!(
+            assembler.assemble_instruction("jmp 0x1000", 0x2000).unwrap(),
+            vec![0xE9, 0xFB, 0xEF, 0xFF, 0xFF]
+        );
+        assert_eq!(assembler.assemble_instruction("db EB FE", 0).unwrap(), vec![0xEB, 0xFE]);
+        assert!(assembler.assemble_instruction("mov eax, 1", 0).is_err());
+    }
+}
@@ -0,0 +1,539 @@
+use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+
+/// The size of every AArch64 instruction, in bytes.
+pub const AARCH64_INSTRUCTION_LENGTH: usize = 4;
+
+const CONDITION_NAMES: [&str; 16] = [
+    "eq", "ne", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al", "nv",
+];
+const SHIFT_NAMES: [&str; 4] = ["lsl", "lsr", "asr", "ror"];
+const LOGICAL_MNEMONICS: [&str; 8] = ["and", "bic", "orr", "orn", "eor", "eon", "ands", "bics"];
+
+/// The fields of a successfully decoded instruction.
+struct DecodedFields {
+    mnemonic: String,
+    operands: String,
+    target_address: Option<u64>,
+}
+
+impl DecodedFields {
+    fn new(
+        mnemonic: &str,
+        operands: String,
+    ) -> Self {
+        Self {
+            mnemonic: mnemonic.to_string(),
+            operands,
+            target_address: None,
+        }
+    }
+
+    fn with_target(
+        mnemonic: &str,
+        operands: String,
+        target_address: u64,
+    ) -> Self {
+        Self {
+            mnemonic: mnemonic.to_string(),
+            operands,
+            target_address: Some(target_address),
+        }
+    }
+}
+
+/// Decodes the commonly used subset of AArch64 machine code: branches, integer loads and stores, moves, and integer
+/// arithmetic and logic. Anything else, including floating point and SIMD instructions, is emitted as a raw `.inst` word.
+pub struct AArch64Decoder;
+
+impl AArch64Decoder {
+    /// Decodes instructions from the start of the given bytes until they are exhausted or the instruction limit is reached.
+    pub fn decode(
+        bytes: &[u8],
+        address: u64,
+        max_instructions: usize,
+    ) -> Vec<DisassembledInstruction> {
+        bytes
+            .chunks_exact(AARCH64_INSTRUCTION_LENGTH)
+            .take(max_instructions)
+            .enumerate()
+            .map(|(index, instruction_bytes)| {
+                let instruction_address = address.wrapping_add((index * AARCH64_INSTRUCTION_LENGTH) as u64);
+                let word = u32::from_le_bytes([
+                    instruction_bytes[0],
+                    instruction_bytes[1],
+                    instruction_bytes[2],
+                    instruction_bytes[3],
+                ]);
+
+                match Self::decode_word(word, instruction_address) {
+                    Some(decoded_fields) => DisassembledInstruction::new(
+                        instruction_address,
+                        instruction_bytes.to_vec(),
+                        decoded_fields.mnemonic,
+                        decoded_fields.operands,
+                        true,
+                        decoded_fields.target_address,
+                    ),
+                    None => DisassembledInstruction::new(
+                        instruction_address,
+                        instruction_bytes.to_vec(),
+                        ".inst".to_string(),
+                        format!("0x{:08X}", word),
+                        false,
+                        None,
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn decode_word(
+        word: u32,
+        address: u64,
+    ) -> Option<DecodedFields> {
+        if word == 0xD503201F {
+            Some(DecodedFields::new("nop", String::new()))
+        } else if word & 0xFFFFF01F == 0xD503201F {
+            Some(DecodedFields::new("hint", format!("#{}", Self::bits(word, 5, 7))))
+        } else if word & 0xFF9FFC1F == 0xD61F0000 {
+            Self::decode_branch_register(word)
+        } else if word & 0x7C000000 == 0x14000000 {
+            let target_address = Self::offset_address(address, Self::sign_extend(Self::bits(word, 0, 26), 26) << 2);
+            let mnemonic = if Self::bits(word, 31, 1) == 1 { "bl" } else { "b" };
+
+            Some(DecodedFields::with_target(mnemonic, Self::address(target_address), target_address))
+        } else if word & 0xFF000010 == 0x54000000 {
+            let target_address = Self::offset_address(address, Self::sign_extend(Self::bits(word, 5, 19), 19) << 2);
+            let mnemonic = format!("b.{}", CONDITION_NAMES[Self::bits(word, 0, 4) as usize]);
+
+            Some(DecodedFields::with_target(&mnemonic, Self::address(target_address), target_address))
+        } else if word & 0x7E000000 == 0x34000000 {
+            let target_address = Self::offset_address(address, Self::sign_extend(Self::bits(word, 5, 19), 19) << 2);
+            let mnemonic = if Self::bits(word, 24, 1) == 1 { "cbnz" } else { "cbz" };
+            let register = Self::register(Self::bits(word, 0, 5), Self::bits(word, 31, 1) == 1, false);
+
+            Some(DecodedFields::with_target(
+                mnemonic,
+                format!("{}, {}", register, Self::address(target_address)),
+                target_address,
+            ))
+        } else if word & 0x7E000000 == 0x36000000 {
+            let target_address = Self::offset_address(address, Self::sign_extend(Self::bits(word, 5, 14), 14) << 2);
+            let mnemonic = if Self::bits(word, 24, 1) == 1 { "tbnz" } else { "tbz" };
+            let bit_number = (Self::bits(word, 31, 1) << 5) | Self::bits(word, 19, 5);
+            let register = Self::register(Self::bits(word, 0, 5), bit_number >= 32, false);
+
+            Some(DecodedFields::with_target(
+                mnemonic,
+                format!("{}, #{}, {}", register, bit_number, Self::address(target_address)),
+                target_address,
+            ))
+        } else if word & 0x1F000000 == 0x10000000 {
+            Self::decode_pc_relative_address(word, address)
+        } else if word & 0x1F800000 == 0x11000000 {
+            Self::decode_add_subtract_immediate(word)
+        } else if word & 0x1F800000 == 0x12800000 {
+            Self::decode_move_wide(word)
+        } else if word & 0x1F000000 == 0x0A000000 {
+            Self::decode_logical_shifted_register(word)
+        } else if word & 0x1F200000 == 0x0B000000 {
+            Self::decode_add_subtract_shifted_register(word)
+        } else if word & 0x3F000000 == 0x39000000 {
+            Self::decode_load_store_unsigned_offset(word)
+        } else if word & 0x3F000000 == 0x18000000 {
+            Self::decode_load_literal(word, address)
+        } else if word & 0x3C000000 == 0x28000000 {
+            Self::decode_load_store_pair(word)
+        } else if word & 0xFFE0001F == 0xD4000001 {
+            Some(DecodedFields::new("svc", Self::immediate(Self::bits(word, 5, 16) as i64)))
+        } else if word & 0xFFE0001F == 0xD4200000 {
+            Some(DecodedFields::new("brk", Self::immediate(Self::bits(word, 5, 16) as i64)))
+        } else {
+            None
+        }
+    }
+
+    fn decode_branch_register(word: u32) -> Option<DecodedFields> {
+        let register_index = Self::bits(word, 5, 5);
+
+        match Self::bits(word, 21, 2) {
+            0b00 => Some(DecodedFields::new("br", Self::register(register_index, true, false))),
+            0b01 => Some(DecodedFields::new("blr", Self::register(register_index, true, false))),
+            0b10 if register_index == 30 => Some(DecodedFields::new("ret", String::new())),
+            0b10 => Some(DecodedFields::new("ret", Self::register(register_index, true, false))),
+            _ => None,
+        }
+    }
+
+    fn decode_pc_relative_address(
+        word: u32,
+        address: u64,
+    ) -> Option<DecodedFields> {
+        let immediate = Self::sign_extend((Self::bits(word, 5, 19) << 2) | Self::bits(word, 29, 2), 21);
+        let register = Self::register(Self::bits(word, 0, 5), true, false);
+
+        if Self::bits(word, 31, 1) == 1 {
+            let target_address = Self::offset_address(address & !0xFFF, immediate << 12);
+
+            Some(DecodedFields::with_target(
+                "adrp",
+                format!("{}, {}", register, Self::address(target_address)),
+                target_address,
+            ))
+        } else {
+            let target_address = Self::offset_address(address, immediate);
+
+            Some(DecodedFields::with_target(
+                "adr",
+                format!("{}, {}", register, Self::address(target_address)),
+                target_address,
+            ))
+        }
+    }
+
+    fn decode_add_subtract_immediate(word: u32) -> Option<DecodedFields> {
+        let is_64_bit = Self::bits(word, 31, 1) == 1;
+        let is_subtract = Self::bits(word, 30, 1) == 1;
+        let sets_flags = Self::bits(word, 29, 1) == 1;
+        let is_shifted = Self::bits(word, 22, 1) == 1;
+        let immediate = Self::bits(word, 10, 12) as i64;
+        let source_index = Self::bits(word, 5, 5);
+        let destination_index = Self::bits(word, 0, 5);
+        let source = Self::register(source_index, is_64_bit, true);
+        let destination = Self::register(destination_index, is_64_bit, !sets_flags);
+        let immediate_operand = if is_shifted {
+            format!("{}, lsl #12", Self::immediate(immediate))
+        } else {
+            Self::immediate(immediate)
+        };
+
+        if !is_subtract && !sets_flags && !is_shifted && immediate == 0 && (source_index == 31 || destination_index == 31) {
+            Some(DecodedFields::new("mov", format!("{}, {}", destination, source)))
+        } else if sets_flags && destination_index == 31 {
+            let mnemonic = if is_subtract { "cmp" } else { "cmn" };
+
+            Some(DecodedFields::new(mnemonic, format!("{}, {}", source, immediate_operand)))
+        } else {
+            let mnemonic = match (is_subtract, sets_flags) {
+                (false, false) => "add",
+                (false, true) => "adds",
+                (true, false) => "sub",
+                (true, true) => "subs",
+            };
+
+            Some(DecodedFields::new(mnemonic, format!("{}, {}, {}", destination, source, immediate_operand)))
+        }
+    }
+
+    fn decode_move_wide(word: u32) -> Option<DecodedFields> {
+        let is_64_bit = Self::bits(word, 31, 1) == 1;
+        let shift = Self::bits(word, 21, 2) * 16;
+        let immediate = Self::bits(word, 5, 16) as u64;
+        let destination = Self::register(Self::bits(word, 0, 5), is_64_bit, false);
+
+        if !is_64_bit && shift >= 32 {
+            return None;
+        }
+
+        let shifted_operand = if shift == 0 {
+            Self::immediate(immediate as i64)
+        } else {
+            format!("{}, lsl #{}", Self::immediate(immediate as i64), shift)
+        };
+
+        match Self::bits(word, 29, 2) {
+            0b00 => Some(DecodedFields::new("movn", format!("{}, {}", destination, shifted_operand))),
+            0b10 => Some(DecodedFields::new(
+                "mov",
+                format!("{}, {}", destination, Self::unsigned_immediate(immediate << shift)),
+            )),
+            0b11 => Some(DecodedFields::new("movk", format!("{}, {}", destination, shifted_operand))),
+            _ => None,
+        }
+    }
+
+    fn decode_logical_shifted_register(word: u32) -> Option<DecodedFields> {
+        let is_64_bit = Self::bits(word, 31, 1) == 1;
+        let opcode = Self::bits(word, 29, 2);
+        let shift_type = Self::bits(word, 22, 2);
+        let is_negated = Self::bits(word, 21, 1);
+        let shift_amount = Self::bits(word, 10, 6);
+        let first_source_index = Self::bits(word, 5, 5);
+        let destination_index = Self::bits(word, 0, 5);
+
+        if !is_64_bit && shift_amount >= 32 {
+            return None;
+        }
+
+        let second_source = Self::register(Self::bits(word, 16, 5), is_64_bit, false);
+        let first_source = Self::register(first_source_index, is_64_bit, false);
+        let destination = Self::register(destination_index, is_64_bit, false);
+        let shifted_operand = Self::shifted_register(second_source.clone(), shift_type, shift_amount);
+        let mnemonic = LOGICAL_MNEMONICS[(opcode * 2 + is_negated) as usize];
+
+        if mnemonic == "orr" && first_source_index == 31 && shift_amount == 0 {
+            Some(DecodedFields::new("mov", format!("{}, {}", destination, second_source)))
+        } else if mnemonic == "ands" && destination_index == 31 {
+            Some(DecodedFields::new("tst", format!("{}, {}", first_source, shifted_operand)))
+        } else {
+            Some(DecodedFields::new(mnemonic, format!("{}, {}, {}", destination, first_source, shifted_operand)))
+        }
+    }
+
+    fn decode_add_subtract_shifted_register(word: u32) -> Option<DecodedFields> {
+        let is_64_bit = Self::bits(word, 31, 1) == 1;
+        let is_subtract = Self::bits(word, 30, 1) == 1;
+        let sets_flags = Self::bits(word, 29, 1) == 1;
+        let shift_type = Self::bits(word, 22, 2);
+        let shift_amount = Self::bits(word, 10, 6);
+        let destination_index = Self::bits(word, 0, 5);
+
+        if shift_type == 0b11 || (!is_64_bit && shift_amount >= 32) {
+            return None;
+        }
+
+        let second_source = Self::register(Self::bits(word, 16, 5), is_64_bit, false);
+        let first_source = Self::register(Self::bits(word, 5, 5), is_64_bit, false);
+        let destination = Self::register(destination_index, is_64_bit, false);
+        let shifted_operand = Self::shifted_register(second_source, shift_type, shift_amount);
+
+        if sets_flags && destination_index == 31 {
+            let mnemonic = if is_subtract { "cmp" } else { "cmn" };
+
+            Some(DecodedFields::new(mnemonic, format!("{}, {}", first_source, shifted_operand)))
+        } else {
+            let mnemonic = match (is_subtract, sets_flags) {
+                (false, false) => "add",
+                (false, true) => "adds",
+                (true, false) => "sub",
+                (true, true) => "subs",
+            };
+
+            Some(DecodedFields::new(mnemonic, format!("{}, {}, {}", destination, first_source, shifted_operand)))
+        }
+    }
+
+    fn decode_load_store_unsigned_offset(word: u32) -> Option<DecodedFields> {
+        let size = Self::bits(word, 30, 2);
+        let opcode = Self::bits(word, 22, 2);
+        let offset = (Self::bits(word, 10, 12) as i64) << size;
+        let base = Self::register(Self::bits(word, 5, 5), true, true);
+        let transfer_index = Self::bits(word, 0, 5);
+        let size_suffix = match size {
+            0 => "b",
+            1 => "h",
+            _ => "",
+        };
+        let (mnemonic, is_64_bit) = match (opcode, size) {
+            (0b00, _) => (format!("str{}", size_suffix), size == 3),
+            (0b01, _) => (format!("ldr{}", size_suffix), size == 3),
+            (0b10, 3) => {
+                return Some(DecodedFields::new(
+                    "prfm",
+                    format!("#{}, {}", transfer_index, Self::memory_operand(&base, offset)),
+                ));
+            }
+            (0b10, 2) => ("ldrsw".to_string(), true),
+            (0b10, _) => (format!("ldrs{}", size_suffix), true),
+            (0b11, 0) | (0b11, 1) => (format!("ldrs{}", size_suffix), false),
+            _ => return None,
+        };
+        let transfer = Self::register(transfer_index, is_64_bit, false);
+
+        Some(DecodedFields::new(&mnemonic, format!("{}, {}", transfer, Self::memory_operand(&base, offset))))
+    }
+
+    fn decode_load_literal(
+        word: u32,
+        address: u64,
+    ) -> Option<DecodedFields> {
+        let target_address = Self::offset_address(address, Self::sign_extend(Self::bits(word, 5, 19), 19) << 2);
+        let transfer_index = Self::bits(word, 0, 5);
+        let (mnemonic, transfer) = match Self::bits(word, 30, 2) {
+            0b00 => ("ldr", Self::register(transfer_index, false, false)),
+            0b01 => ("ldr", Self::register(transfer_index, true, false)),
+            0b10 => ("ldrsw", Self::register(transfer_index, true, false)),
+            _ => ("prfm", format!("#{}", transfer_index)),
+        };
+
+        Some(DecodedFields::with_target(
+            mnemonic,
+            format!("{}, {}", transfer, Self::address(target_address)),
+            target_address,
+        ))
+    }
+
+    fn decode_load_store_pair(word: u32) -> Option<DecodedFields> {
+        let is_load = Self::bits(word, 22, 1) == 1;
+        let (mnemonic, is_64_bit, scale) = match (Self::bits(word, 30, 2), is_load) {
+            (0b00, false) => ("stp", false, 2),
+            (0b00, true) => ("ldp", false, 2),
+            (0b01, true) => ("ldpsw", true, 2),
+            (0b10, false) => ("stp", true, 3),
+            (0b10, true) => ("ldp", true, 3),
+            _ => return None,
+        };
+        let offset = Self::sign_extend(Self::bits(word, 15, 7), 7) << scale;
+        let second_transfer = Self::register(Self::bits(word, 10, 5), is_64_bit, false);
+        let base = Self::register(Self::bits(word, 5, 5), true, true);
+        let first_transfer = Self::register(Self::bits(word, 0, 5), is_64_bit, false);
+        let (mnemonic, memory_operand) = match Self::bits(word, 23, 2) {
+            0b00 if mnemonic == "ldpsw" => return None,
+            0b00 => (mnemonic.replace('p', "np"), Self::memory_operand(&base, offset)),
+            0b01 => (mnemonic.to_string(), format!("[{}], {}", base, Self::immediate(offset))),
+            0b10 => (mnemonic.to_string(), Self::memory_operand(&base, offset)),
+            _ => (mnemonic.to_string(), format!("[{}, {}]!", base, Self::immediate(offset))),
+        };
+
+        Some(DecodedFields::new(
+            &mnemonic,
+            format!("{}, {}, {}", first_transfer, second_transfer, memory_operand),
+        ))
+    }
+
+    fn bits(
+        word: u32,
+        start: u32,
+        length: u32,
+    ) -> u32 {
+        (word >> start) & ((1u32 << length) - 1)
+    }
+
+    fn sign_extend(
+        value: u32,
+        length: u32,
+    ) -> i64 {
+        let shift = 64 - length;
+
+        ((value as i64) << shift) >> shift
+    }
+
+    fn offset_address(
+        address: u64,
+        offset: i64,
+    ) -> u64 {
+        address.wrapping_add(offset as u64)
+    }
+
+    /// Formats a general purpose register, where register 31 is either the stack pointer or zero register depending on context.
+    fn register(
+        index: u32,
+        is_64_bit: bool,
+        is_stack_pointer: bool,
+    ) -> String {
+        match (index, is_64_bit, is_stack_pointer) {
+            (31, true, true) => "sp".to_string(),
+            (31, false, true) => "wsp".to_string(),
+            (31, true, false) => "xzr".to_string(),
+            (31, false, false) => "wzr".to_string(),
+            (_, true, _) => format!("x{}", index),
+            (_, false, _) => format!("w{}", index),
+        }
+    }
+
+    fn shifted_register(
+        register: String,
+        shift_type: u32,
+        shift_amount: u32,
+    ) -> String {
+        if shift_amount == 0 {
+            register
+        } else {
+            format!("{}, {} #{}", register, SHIFT_NAMES[shift_type as usize], shift_amount)
+        }
+    }
+
+    fn memory_operand(
+        base: &str,
+        offset: i64,
+    ) -> String {
+        if offset == 0 {
+            format!("[{}]", base)
+        } else {
+            format!("[{}, {}]", base, Self::immediate(offset))
+        }
+    }
+
+    fn immediate(value: i64) -> String {
+        match value {
+            -9..=9 => format!("#{}", value),
+            _ if value < 0 => format!("#-0x{:X}", value.unsigned_abs()),
+            _ => format!("#0x{:X}", value),
+        }
+    }
+
+    fn unsigned_immediate(value: u64) -> String {
+        if value <= 9 { format!("#{}", value) } else { format!("#0x{:X}", value) }
+    }
+
+    fn address(address: u64) -> String {
+        format!("0x{:X}", address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AArch64Decoder;
+
+    fn decode_text(
+        word: u32,
+        address: u64,
+    ) -> String {
+        AArch64Decoder::decode(&word.to_le_bytes(), address, 1)[0].get_text()
+    }
+
+    #[test]
+    fn decode_formats_common_instructions() {
+        assert_eq!(decode_text(0xD503201F, 0), "nop");
+        assert_eq!(decode_text(0xD65F03C0, 0), "ret");
+        assert_eq!(decode_text(0xD63F0100, 0), "blr x8");
+        assert_eq!(decode_text(0xA9BF7BFD, 0), "stp x29, x30, [sp, #-0x10]!");
+        assert_eq!(decode_text(0xA8C17BFD, 0), "ldp x29, x30, [sp], #0x10");
+        assert_eq!(decode_text(0x910003FD, 0), "mov x29, sp");
+        assert_eq!(decode_text(0xAA0103E0, 0), "mov x0, x1");
+        assert_eq!(decode_text(0x52800540, 0), "mov w0, #0x2A");
+        assert_eq!(decode_text(0xF9400420, 0), "ldr x0, [x1, #8]");
+        assert_eq!(decode_text(0xB9400020, 0), "ldr w0, [x1]");
+        assert_eq!(decode_text(0xF100041F, 0), "cmp x0, #1");
+        assert_eq!(decode_text(0xD1004000, 0), "sub x0, x0, #0x10");
+        assert_eq!(decode_text(0xD4000001, 0), "svc #0");
+    }
+
+    #[test]
+    fn decode_computes_pc_relative_targets() {
+        let instructions = AArch64Decoder::decode(
+            &[0x94000002u32, 0x17FFFFFF, 0x54000041, 0xB4000040, 0xB0000000]
+                .iter()
+                .flat_map(|word| word.to_le_bytes())
+                .collect::<Vec<_>>(),
+            0x12000,
+            usize::MAX,
+        );
+        let texts = instructions
+            .iter()
+            .map(|instruction| instruction.get_text())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            texts,
+            vec![
+                "bl 0x12008",
+                "b 0x12000",
+                "b.ne 0x12010",
+                "cbz x0, 0x12014",
+                "adrp x0, 0x13000"
+            ]
+        );
+        assert_eq!(instructions[0].get_target_address(), Some(0x12008));
+        assert_eq!(instructions[4].get_target_address(), Some(0x13000));
+    }
+
+    #[test]
+    fn decode_emits_unknown_words_and_ignores_partial_words() {
+        let instructions = AArch64Decoder::decode(&[0x00, 0x00, 0x00, 0x00, 0x1F, 0x20], 0x1000, usize::MAX);
+
+        assert_eq!(instructions.len(), 1);
+        assert!(!instructions[0].is_valid());
+        assert_eq!(instructions[0].get_text(), ".inst 0x00000000");
+    }
+}
@@ -0,0 +1,235 @@
+use crate::disassembly::aarch64_decoder::{AARCH64_INSTRUCTION_LENGTH, AArch64Decoder};
+use crate::disassembly::x86_decoder::{X86_MAX_INSTRUCTION_LENGTH, X86Decoder};
+use crate::os::engine_os_provider::EngineOsProviders;
+use crate::os::process_address_expression_context::ProcessAddressExpressionContext;
+use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// The granularity at which code is read, such that listings stop at the first unreadable page rather than failing outright.
+const DISASSEMBLY_PAGE_SIZE: u64 = 0x1000;
+
+/// Produces readable instruction listings around an address of an opened process.
+pub struct Disassembler;
+
+impl Disassembler {
+    /// Disassembles the given number of instructions before and starting at an address, annotating each instruction and any
+    /// address it references with its module and offset. Listings are truncated where memory is unreadable.
+    pub fn disassemble(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        instruction_set: InstructionSet,
+        instructions_before: usize,
+        instruction_count: usize,
+    ) -> Vec<DisassembledInstruction> {
+        let max_instruction_length = Self::get_max_instruction_length(instruction_set) as u64;
+        let before_start_address = address.saturating_sub(instructions_before as u64 * max_instruction_length);
+        let after_end_address = address.saturating_add(instruction_count as u64 * max_instruction_length);
+        let before_bytes = Self::read_readable_suffix(os_providers, process_info, before_start_address, address);
+        let after_bytes = Self::read_readable_prefix(os_providers, process_info, address, after_end_address);
+        let mut disassembled_instructions = Self::decode_before(instruction_set, &before_bytes, address, instructions_before);
+
+        disassembled_instructions.extend(Self::decode(instruction_set, &after_bytes, address, instruction_count));
+
+        let address_expression_context = ProcessAddressExpressionContext::new(os_providers, process_info);
+
+        for disassembled_instruction in &mut disassembled_instructions {
+            disassembled_instruction.set_location(Self::format_location(&address_expression_context, disassembled_instruction.get_address()));
+
+            if let Some(target_address) = disassembled_instruction.get_target_address() {
+                disassembled_instruction.set_target_location(Self::format_location(&address_expression_context, target_address));
+            }
+        }
+
+        disassembled_instructions
+    }
+
+    /// Decodes instructions from the start of the given bytes.
+    pub fn decode(
+        instruction_set: InstructionSet,
+        bytes: &[u8],
+        address: u64,
+        max_instructions: usize,
+    ) -> Vec<DisassembledInstruction> {
+        match instruction_set {
+            InstructionSet::X86 => X86Decoder::new(32).decode(bytes, address, max_instructions),
+            InstructionSet::X64 => X86Decoder::new(64).decode(bytes, address, max_instructions),
+            InstructionSet::AArch64 => AArch64Decoder::decode(bytes, address, max_instructions),
+        }
+    }
+
+    /// Decodes up to the given number of instructions that immediately precede an address, given the bytes preceding it.
+    /// Variable length instruction sets cannot be decoded backwards, so every start offset is tried, preferring the earliest
+    /// offset that decodes to valid instructions ending exactly at the address.
+    pub fn decode_before(
+        instruction_set: InstructionSet,
+        bytes: &[u8],
+        end_address: u64,
+        instruction_count: usize,
+    ) -> Vec<DisassembledInstruction> {
+        if instruction_count == 0 || bytes.is_empty() {
+            return Vec::new();
+        }
+
+        let start_address = end_address.wrapping_sub(bytes.len() as u64);
+        let mut fallback_instructions = None;
+
+        if instruction_set == InstructionSet::AArch64 {
+            let start_offset = bytes.len() % AARCH64_INSTRUCTION_LENGTH;
+
+            return Self::take_last(
+                Self::decode(instruction_set, &bytes[start_offset..], start_address + start_offset as u64, usize::MAX),
+                instruction_count,
+            );
+        }
+
+        for start_offset in 0..bytes.len() {
+            let candidate_instructions = Self::decode(instruction_set, &bytes[start_offset..], start_address + start_offset as u64, usize::MAX);
+            let decoded_length = candidate_instructions
+                .iter()
+                .map(|instruction| instruction.get_size() as usize)
+                .sum::<usize>();
+
+            if decoded_length != bytes.len() - start_offset {
+                continue;
+            }
+
+            if candidate_instructions
+                .iter()
+                .all(|instruction| instruction.is_valid())
+            {
+                return Self::take_last(candidate_instructions, instruction_count);
+            }
+
+            if fallback_instructions.is_none() {
+                fallback_instructions = Some(candidate_instructions);
+            }
+        }
+
+        Self::take_last(fallback_instructions.unwrap_or_default(), instruction_count)
+    }
+
+    fn get_max_instruction_length(instruction_set: InstructionSet) -> usize {
+        match instruction_set {
+            InstructionSet::X86 | InstructionSet::X64 => X86_MAX_INSTRUCTION_LENGTH,
+            InstructionSet::AArch64 => AARCH64_INSTRUCTION_LENGTH,
+        }
+    }
+
+    fn take_last(
+        mut disassembled_instructions: Vec<DisassembledInstruction>,
+        instruction_count: usize,
+    ) -> Vec<DisassembledInstruction> {
+        let skip_count = disassembled_instructions
+            .len()
+            .saturating_sub(instruction_count);
+
+        disassembled_instructions.drain(..skip_count);
+        disassembled_instructions
+    }
+
+    fn format_location(
+        address_expression_context: &ProcessAddressExpressionContext,
+        address: u64,
+    ) -> Option<String> {
+        address_expression_context
+            .address_to_symbol_label(address)
+            .or_else(|| {
+                address_expression_context
+                    .address_to_module(address)
+                    .map(|(module_name, offset)| format!("{}+0x{:X}", module_name, offset))
+            })
+    }
+
+    /// Reads the pages overlapping a range, returning each page-aligned chunk along with whether it could be read.
+    fn read_chunks(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<Option<Vec<u8>>> {
+        let mut chunks = Vec::new();
+        let mut chunk_address = start_address;
+
+        while chunk_address < end_address {
+            let page_end_address = (chunk_address / DISASSEMBLY_PAGE_SIZE)
+                .saturating_add(1)
+                .saturating_mul(DISASSEMBLY_PAGE_SIZE);
+            let chunk_end_address = page_end_address.min(end_address);
+            let mut chunk = vec![0u8; (chunk_end_address - chunk_address) as usize];
+
+            if os_providers
+                .memory_read
+                .read_bytes(process_info, chunk_address, &mut chunk)
+            {
+                chunks.push(Some(chunk));
+            } else {
+                chunks.push(None);
+            }
+
+            chunk_address = chunk_end_address;
+        }
+
+        chunks
+    }
+
+    fn read_readable_prefix(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<u8> {
+        Self::read_chunks(os_providers, process_info, start_address, end_address)
+            .into_iter()
+            .map_while(|chunk| chunk)
+            .flatten()
+            .collect()
+    }
+
+    fn read_readable_suffix(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<u8> {
+        let mut chunks = Self::read_chunks(os_providers, process_info, start_address, end_address)
+            .into_iter()
+            .rev()
+            .map_while(|chunk| chunk)
+            .collect::<Vec<_>>();
+
+        chunks.reverse();
+        chunks.into_iter().flatten().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Disassembler;
+    use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+
+    #[test]
+    fn decode_before_synchronizes_with_the_end_address() {
+        // A `mov eax, 0x90909090` followed by `nop`, where decoding from the middle of the immediate would also be valid.
+        let bytes = [0xB8, 0x90, 0x90, 0x90, 0x90, 0x90];
+        let instructions = Disassembler::decode_before(InstructionSet::X86, &bytes, 0x1006, 2);
+        let texts = instructions
+            .iter()
+            .map(|instruction| instruction.get_text())
+            .collect::<Vec<_>>();
+
+        assert_eq!(texts, vec!["mov eax, 0x90909090", "nop"]);
+        assert_eq!(instructions[0].get_address(), 0x1000);
+    }
+
+    #[test]
+    fn decode_before_aligns_aarch64_instructions() {
+        let bytes = [0xFF, 0x1F, 0x20, 0x03, 0xD5, 0xC0, 0x03, 0x5F, 0xD6];
+        let instructions = Disassembler::decode_before(InstructionSet::AArch64, &bytes, 0x2000, 1);
+
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].get_text(), "ret");
+        assert_eq!(instructions[0].get_address(), 0x1FFC);
+    }
+}
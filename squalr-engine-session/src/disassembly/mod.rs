@@ -0,0 +1,3 @@
+pub mod aarch64_decoder;
+pub mod disassembler;
+pub mod x86_decoder;
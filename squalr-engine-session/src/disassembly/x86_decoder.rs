@@ -0,0 +1,155 @@
+use iced_x86::{Decoder, DecoderOptions, Formatter, Instruction, IntelFormatter, OpKind, Register};
+use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+
+/// The longest possible x86 instruction, in bytes.
+pub const X86_MAX_INSTRUCTION_LENGTH: usize = 15;
+
+/// Decodes x86 and x86-64 machine code into Intel syntax assembly.
+pub struct X86Decoder {
+    bitness: u32,
+    formatter: IntelFormatter,
+}
+
+impl X86Decoder {
+    /// Creates a decoder for 32-bit or 64-bit code.
+    pub fn new(bitness: u32) -> Self {
+        let mut formatter = IntelFormatter::new();
+        let options = formatter.options_mut();
+
+        options.set_hex_prefix("0x");
+        options.set_hex_suffix("");
+        options.set_space_after_operand_separator(true);
+        options.set_branch_leading_zeros(false);
+        options.set_show_branch_size(false);
+        options.set_rip_relative_addresses(false);
+
+        Self { bitness, formatter }
+    }
+
+    /// Decodes instructions from the start of the given bytes until they are exhausted or the instruction limit is reached.
+    /// Trailing bytes that could form the start of an instruction, but are too short to decode, are not emitted.
+    pub fn decode(
+        &mut self,
+        bytes: &[u8],
+        address: u64,
+        max_instructions: usize,
+    ) -> Vec<DisassembledInstruction> {
+        let mut decoder = Decoder::with_ip(self.bitness, bytes, address, DecoderOptions::NONE);
+        let mut instruction = Instruction::default();
+        let mut disassembled_instructions = Vec::new();
+
+        while decoder.can_decode() && disassembled_instructions.len() < max_instructions {
+            let offset = decoder.position();
+            decoder.decode_out(&mut instruction);
+
+            if instruction.is_invalid() {
+                if offset + X86_MAX_INSTRUCTION_LENGTH > bytes.len() {
+                    break;
+                }
+
+                // Emit a single byte of data and resynchronize on the next byte, as an invalid encoding has no meaningful length.
+                let instruction_address = address.wrapping_add(offset as u64);
+
+                disassembled_instructions.push(Self::create_data_instruction(instruction_address, bytes[offset]));
+                decoder.set_ip(instruction_address.wrapping_add(1));
+                let _ = decoder.set_position(offset + 1);
+
+                continue;
+            }
+
+            disassembled_instructions.push(self.format_instruction(&instruction, &bytes[offset..offset + instruction.len()]));
+        }
+
+        disassembled_instructions
+    }
+
+    fn format_instruction(
+        &mut self,
+        instruction: &Instruction,
+        instruction_bytes: &[u8],
+    ) -> DisassembledInstruction {
+        let mut mnemonic = String::new();
+        let mut operands = String::new();
+
+        self.formatter.format_mnemonic(instruction, &mut mnemonic);
+        self.formatter.format_all_operands(instruction, &mut operands);
+
+        DisassembledInstruction::new(
+            instruction.ip(),
+            instruction_bytes.to_vec(),
+            mnemonic,
+            operands,
+            true,
+            Self::get_target_address(instruction),
+        )
+    }
+
+    fn create_data_instruction(
+        address: u64,
+        byte: u8,
+    ) -> DisassembledInstruction {
+        DisassembledInstruction::new(address, vec![byte], "db".to_string(), format!("0x{:02X}", byte), false, None)
+    }
+
+    /// Gets the near branch target, RIP-relative address, or absolute memory address referenced by an instruction.
+    fn get_target_address(instruction: &Instruction) -> Option<u64> {
+        let has_memory_operand = (0..instruction.op_count()).any(|operand| instruction.op_kind(operand) == OpKind::Memory);
+
+        if (0..instruction.op_count()).any(|operand| matches!(instruction.op_kind(operand), OpKind::NearBranch16 | OpKind::NearBranch32 | OpKind::NearBranch64))
+        {
+            Some(instruction.near_branch_target())
+        } else if has_memory_operand && instruction.is_ip_rel_memory_operand() {
+            Some(instruction.ip_rel_memory_address())
+        } else if has_memory_operand && instruction.memory_base() == Register::None && instruction.memory_index() == Register::None {
+            Some(instruction.memory_displacement64())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::X86Decoder;
+
+    #[test]
+    fn decode_formats_x64_instructions_and_branch_targets() {
+        let bytes = [
+            0x55, 0x48, 0x89, 0xE5, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x48, 0x8B, 0x05, 0x10, 0x00, 0x00, 0x00, 0xC3,
+        ];
+        let instructions = X86Decoder::new(64).decode(&bytes, 0x1000, usize::MAX);
+        let texts = instructions
+            .iter()
+            .map(|instruction| instruction.get_text())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            texts,
+            vec![
+                "push rbp",
+                "mov rbp, rsp",
+                "call 0x1009",
+                "mov rax, [0x1020]",
+                "ret"
+            ]
+        );
+        assert_eq!(instructions[2].get_target_address(), Some(0x1009));
+        assert_eq!(instructions[3].get_target_address(), Some(0x1020));
+        assert_eq!(instructions[4].get_address(), 0x1010);
+        assert_eq!(instructions[0].get_target_address(), None);
+    }
+
+    #[test]
+    fn decode_emits_invalid_bytes_as_data_and_stops_at_truncated_instructions() {
+        let invalid_instructions = X86Decoder::new(64).decode(&[0x06; 16], 0, 1);
+
+        assert_eq!(invalid_instructions.len(), 1);
+        assert!(!invalid_instructions[0].is_valid());
+        assert_eq!(invalid_instructions[0].get_text(), "db 0x06");
+
+        let truncated_instructions = X86Decoder::new(32).decode(&[0x90, 0xE8, 0x00], 0, usize::MAX);
+
+        assert_eq!(truncated_instructions.len(), 1);
+        assert_eq!(truncated_instructions[0].get_text(), "nop");
+    }
+}
@@ -1,40 +1,82 @@
-use crate::os::ProcessManager;
+use crate::code_patches::code_patcher::CodePatcher;
 use crate::os::engine_os_provider::EngineOsProviders;
+use crate::os::memory_middleware::memory_middleware_providers::create_memory_middleware_providers;
+use crate::os::memory_middleware::memory_middleware_registry::MemoryMiddlewareRegistry;
+use crate::os::process_auto_attacher::ProcessAutoAttacher;
+use crate::os::{Pid, ProcessManager};
+use crate::plugins::data_type_plugin_loader::DataTypePluginLoader;
 use crate::registries::registries::Registries;
+use crate::tasks::memory_map_watch_task::MemoryMapWatchTask;
 use crate::tasks::snapshot_scan_result_freeze_task::SnapshotScanResultFreezeTask;
 use crate::tasks::trackable_task_manager::TrackableTaskManager;
+use crate::tasks::value_timeline_record_task::ValueTimelineRecordTask;
 use crossbeam_channel::Receiver;
 use squalr_engine_api::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
 use squalr_engine_api::engine::engine_binding_error::EngineBindingError;
 use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use squalr_engine_api::registries::code_patches::code_patch_registry::CodePatchRegistry;
 use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
 use squalr_engine_api::registries::project_item_types::project_item_type_registry::ProjectItemTypeRegistry;
 use squalr_engine_api::registries::registry_context::RegistryContext;
 use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::registries::value_timelines::value_timeline_registry::ValueTimelineRegistry;
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_error::DataTypePluginError;
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_metadata::DataTypePluginMetadata;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::scan_results::scan_results_query_cache::ScanResultsQueryCache;
+use squalr_engine_api::structures::scanning::correlation::scan_correlation::ScanCorrelation;
 use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
 use squalr_engine_operating_system::process_query::process_query_error::ProcessQueryError;
-use std::sync::{Arc, RwLock};
+use squalr_engine_operating_system::process_query::process_query_options::ProcessQueryOptions;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock, Weak};
+use std::thread;
+use std::time::Duration;
+
+const PROCESS_POLL_INTERVAL_MS: u64 = 500;
 
 /// Tracks critical privileged engine session state for command execution and event dispatch.
 pub struct EnginePrivilegedState {
     /// The manager for the process to which Squalr is attached, and detecting if that process dies.
     process_manager: ProcessManager,
 
+    /// Attaches to a named target process when it launches, and re-attaches after it restarts.
+    process_auto_attacher: ProcessAutoAttacher,
+
     /// The manager that tracks all running engine tasks.
     task_manager: TrackableTaskManager,
 
     /// The current snapshot of process memory, including any scan results.
     snapshot: Arc<RwLock<Snapshot>>,
 
+    /// Snapshots of processes attached alongside the opened process, such as a secondary process for differential scans.
+    process_snapshots: RwLock<HashMap<u32, Arc<RwLock<Snapshot>>>>,
+
+    /// The observations and sampled value history of an in-progress correlation scan over the snapshot scan results.
+    scan_correlation: Arc<RwLock<ScanCorrelation>>,
+
+    /// The scan result indices matching the most recent scan results query, reused while paging through its results.
+    scan_results_query_cache: Arc<RwLock<ScanResultsQueryCache>>,
+
     /// Defines functionality that can be invoked by the engine for the GUI or CLI to handle.
     engine_bindings: Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
 
     /// The collection of all engine registries.
     registries: Arc<Registries>,
 
-    /// OS access providers for process and memory operations.
+    /// OS access providers for process and memory operations, filtered through any active memory middleware.
     os_providers: EngineOsProviders,
+
+    /// Unfiltered OS access providers, which always operate on host addresses.
+    host_os_providers: EngineOsProviders,
+
+    /// The registry of memory middlewares that remap guest address spaces (ie emulator RAM) over host memory.
+    memory_middleware_registry: Arc<MemoryMiddlewareRegistry>,
+
+    /// The loader for plugins that register additional data types at runtime.
+    data_type_plugin_loader: DataTypePluginLoader,
 }
 
 impl EnginePrivilegedState {
@@ -47,20 +89,39 @@ impl EnginePrivilegedState {
         let task_manager = TrackableTaskManager::new();
         let snapshot = Arc::new(RwLock::new(Snapshot::new()));
         let registries = Arc::new(Registries::new());
+        let memory_middleware_registry = Arc::new(MemoryMiddlewareRegistry::new());
+        let host_os_providers = os_providers;
+        let os_providers = create_memory_middleware_providers(host_os_providers.clone(), memory_middleware_registry.clone());
 
         SnapshotScanResultFreezeTask::start_task(
             process_manager.get_opened_process_ref(),
             registries.get_freeze_list_registry().clone(),
             os_providers.clone(),
+            event_emitter.clone(),
         );
 
+        ValueTimelineRecordTask::start_task(
+            process_manager.get_opened_process_ref(),
+            registries.get_value_timeline_registry().clone(),
+            os_providers.clone(),
+        );
+
+        MemoryMapWatchTask::start_task(process_manager.get_opened_process_ref(), snapshot.clone(), os_providers.clone(), event_emitter);
+
         let engine_privileged_state = Arc::new(EnginePrivilegedState {
             process_manager,
+            process_auto_attacher: ProcessAutoAttacher::new(),
             task_manager,
             snapshot,
+            process_snapshots: RwLock::new(HashMap::new()),
+            scan_correlation: Arc::new(RwLock::new(ScanCorrelation::new())),
+            scan_results_query_cache: Arc::new(RwLock::new(ScanResultsQueryCache::new())),
             engine_bindings,
             registries,
             os_providers,
+            host_os_providers,
+            memory_middleware_registry,
+            data_type_plugin_loader: DataTypePluginLoader::new(),
         });
 
         engine_privileged_state
@@ -68,6 +129,8 @@ impl EnginePrivilegedState {
             .process_query
             .start_monitoring()?;
 
+        Self::start_process_polling(Arc::downgrade(&engine_privileged_state));
+
         Ok(engine_privileged_state)
     }
 
@@ -76,6 +139,17 @@ impl EnginePrivilegedState {
         &self.process_manager
     }
 
+    /// Gets the auto-attacher, which determines the process to attach to when it launches or restarts.
+    pub fn get_process_auto_attacher(&self) -> &ProcessAutoAttacher {
+        &self.process_auto_attacher
+    }
+
+    /// Attaches to the auto-attach target if it is running and no process is opened, returning the newly opened process.
+    pub fn poll_auto_attach(&self) -> Option<OpenedProcessInfo> {
+        self.process_auto_attacher
+            .try_attach(&self.os_providers, &self.process_manager)
+    }
+
     pub fn get_trackable_task_manager(&self) -> &TrackableTaskManager {
         &self.task_manager
     }
@@ -85,6 +159,109 @@ impl EnginePrivilegedState {
         self.snapshot.clone()
     }
 
+    /// Gets the snapshot of an attached process. The opened process always uses the main snapshot, while other processes each have their own.
+    pub fn get_process_snapshot(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Arc<RwLock<Snapshot>> {
+        let is_opened_process = self
+            .process_manager
+            .get_opened_process()
+            .is_some_and(|opened_process_info| opened_process_info.get_process_id_raw() == process_info.get_process_id_raw());
+
+        if is_opened_process {
+            return self.snapshot.clone();
+        }
+
+        match self.process_snapshots.write() {
+            Ok(mut process_snapshots) => process_snapshots
+                .entry(process_info.get_process_id_raw())
+                .or_insert_with(|| Arc::new(RwLock::new(Snapshot::new())))
+                .clone(),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on process snapshots: {}", error);
+
+                Arc::new(RwLock::new(Snapshot::new()))
+            }
+        }
+    }
+
+    /// Closes the secondary process, if any, discarding its snapshot. Returns the process that was closed.
+    pub fn close_secondary_process(&self) -> Option<OpenedProcessInfo> {
+        let process_info = self.process_manager.get_secondary_process()?;
+
+        log::info!(
+            "Closing secondary process {} with handle {}",
+            process_info.get_process_id_raw(),
+            process_info.get_handle()
+        );
+
+        if let Err(error) = self
+            .os_providers
+            .process_query
+            .close_process(process_info.get_handle())
+        {
+            log::error!("Failed to close process handle {}: {}", process_info.get_handle(), error);
+        }
+
+        self.process_manager.clear_secondary_process();
+        self.remove_process_snapshot(process_info.get_process_id_raw());
+
+        Some(process_info)
+    }
+
+    /// Closes the secondary process if it has exited, returning the process that was closed.
+    pub fn poll_secondary_process_exit(&self) -> Option<OpenedProcessInfo> {
+        let secondary_process_id = self
+            .process_manager
+            .get_secondary_process()?
+            .get_process_id_raw();
+        let is_running = self
+            .os_providers
+            .process_query
+            .get_processes(ProcessQueryOptions {
+                search_name: None,
+                required_process_id: Some(Pid::from_u32(secondary_process_id)),
+                require_windowed: false,
+                match_case: false,
+                fetch_icons: false,
+                limit: Some(1),
+            })
+            .iter()
+            .any(|process_info| process_info.get_process_id_raw() == secondary_process_id);
+
+        if is_running {
+            return None;
+        }
+
+        log::info!("Secondary process {} no longer running, detaching.", secondary_process_id);
+
+        self.close_secondary_process()
+    }
+
+    /// Discards the snapshot of a process that is no longer attached.
+    pub fn remove_process_snapshot(
+        &self,
+        process_id: u32,
+    ) {
+        match self.process_snapshots.write() {
+            Ok(mut process_snapshots) => {
+                process_snapshots.remove(&process_id);
+            }
+            Err(error) => log::error!("Failed to acquire write lock on process snapshots: {}", error),
+        }
+    }
+
+    /// Gets the in-progress correlation scan, which ranks scan results by how well they track observed values.
+    pub fn get_scan_correlation(&self) -> Arc<RwLock<ScanCorrelation>> {
+        self.scan_correlation.clone()
+    }
+
+    /// Gets the cache of the most recent scan results query.
+    pub fn get_scan_results_query_cache(&self) -> Arc<RwLock<ScanResultsQueryCache>> {
+        self.scan_results_query_cache.clone()
+    }
+
     /// Gets all engine registries.
     pub fn get_registries(&self) -> Arc<Registries> {
         self.registries.clone()
@@ -95,21 +272,87 @@ impl EnginePrivilegedState {
         &self.os_providers
     }
 
+    /// Gets OS providers that bypass memory middleware, operating on host addresses.
+    pub fn get_host_os_providers(&self) -> &EngineOsProviders {
+        &self.host_os_providers
+    }
+
+    /// Gets the registry of memory middlewares that expose guest address spaces through the OS providers.
+    pub fn get_memory_middleware_registry(&self) -> &Arc<MemoryMiddlewareRegistry> {
+        &self.memory_middleware_registry
+    }
+
     /// Gets the registry for the list of addresses that have been marked as frozen.
     pub fn get_freeze_list_registry(&self) -> Arc<RwLock<FreezeListRegistry>> {
         self.registries.get_freeze_list_registry()
     }
 
+    /// Gets the registry of code patches written to the opened process.
+    pub fn get_code_patch_registry(&self) -> Arc<RwLock<CodePatchRegistry>> {
+        self.registries.get_code_patch_registry()
+    }
+
+    /// Restores the original bytes of all applied code patches, such that the opened process is left unmodified.
+    pub fn restore_code_patches(&self) {
+        let applied_code_patches = match self.get_code_patch_registry().write() {
+            Ok(mut code_patch_registry) => code_patch_registry.take_applied_patches(),
+            Err(error) => {
+                log::error!("Failed to acquire code patch registry write lock for restoring patches: {}", error);
+                return;
+            }
+        };
+        let Some(process_info) = self.process_manager.get_opened_process() else {
+            return;
+        };
+
+        for applied_code_patch in applied_code_patches {
+            if applied_code_patch.get_process_id() != process_info.get_process_id_raw() {
+                continue;
+            }
+
+            if let Err(error) = CodePatcher::restore(&self.os_providers, &process_info, &applied_code_patch) {
+                log::error!("Failed to restore code patch at 0x{:X}: {}", applied_code_patch.get_address(), error);
+            }
+        }
+    }
+
     /// Gets the registry for symbols.
     pub fn get_symbol_registry(&self) -> Arc<RwLock<SymbolRegistry>> {
         self.registries.get_symbol_registry()
     }
 
+    /// Gets the loader for data type plugins.
+    pub fn get_data_type_plugin_loader(&self) -> &DataTypePluginLoader {
+        &self.data_type_plugin_loader
+    }
+
+    /// Loads a data type plugin, registering its data types into both the session symbol registry and the shared registry used by scanners.
+    pub fn load_data_type_plugin(
+        &self,
+        plugin_path: &Path,
+    ) -> Result<Vec<DataTypePluginMetadata>, DataTypePluginError> {
+        let symbol_registry = self.get_symbol_registry();
+        let symbol_registry = symbol_registry
+            .read()
+            .map_err(|error| DataTypePluginError::LibraryLoadFailed {
+                plugin_path: plugin_path.to_string_lossy().to_string(),
+                error: format!("Failed to acquire symbol registry read lock: {}", error),
+            })?;
+
+        self.data_type_plugin_loader
+            .load_plugin(plugin_path, &[&symbol_registry, SymbolRegistry::get_instance()])
+    }
+
     /// Gets the registry for project item types.
     pub fn get_project_item_type_registry(&self) -> Arc<RwLock<ProjectItemTypeRegistry>> {
         self.registries.get_project_item_type_registry()
     }
 
+    /// Gets the registry of value timelines being recorded.
+    pub fn get_value_timeline_registry(&self) -> Arc<RwLock<ValueTimelineRegistry>> {
+        self.registries.get_value_timeline_registry()
+    }
+
     /// Gets the registry for element scan rules.
     pub fn get_element_scan_rule_registry(&self) -> Arc<RwLock<ElementScanRuleRegistry>> {
         self.registries.get_element_scan_rule_registry()
@@ -149,6 +392,23 @@ impl EnginePrivilegedState {
         }
     }
 
+    /// Polls for the auto-attach target until the session is dropped.
+    fn start_process_polling(engine_privileged_state: Weak<Self>) {
+        thread::spawn(move || {
+            loop {
+                thread::sleep(Duration::from_millis(PROCESS_POLL_INTERVAL_MS));
+
+                match engine_privileged_state.upgrade() {
+                    Some(engine_privileged_state) => {
+                        engine_privileged_state.poll_auto_attach();
+                        engine_privileged_state.poll_secondary_process_exit();
+                    }
+                    None => return,
+                }
+            }
+        });
+    }
+
     fn create_event_emitter(engine_bindings: Arc<RwLock<dyn EngineApiPrivilegedBindings>>) -> Arc<dyn Fn(EngineEvent) + Send + Sync> {
         let engine_bindings = engine_bindings.clone();
         Arc::new(move |event: EngineEvent| {
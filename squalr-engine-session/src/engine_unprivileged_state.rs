@@ -1,17 +1,23 @@
 use crate::logging::log_dispatcher::{LogDispatcher, LogDispatcherOptions};
+use crate::plugins::data_type_plugin_loader::DataTypePluginLoader;
 use squalr_engine_api::commands::{privileged_command::PrivilegedCommand, privileged_command_response::PrivilegedCommandResponse};
 use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
 use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use squalr_engine_api::events::memory::memory_event::MemoryEvent;
+use squalr_engine_api::events::plugins::data_types_changed::data_types_changed_event::DataTypesChangedEvent;
+use squalr_engine_api::events::plugins::plugins_event::PluginsEvent;
 use squalr_engine_api::events::process::process_event::ProcessEvent;
 use squalr_engine_api::events::project::project_event::ProjectEvent;
 use squalr_engine_api::events::project_items::project_items_event::ProjectItemsEvent;
 use squalr_engine_api::events::scan_results::scan_results_event::ScanResultsEvent;
 use squalr_engine_api::events::trackable_task::trackable_task_event::TrackableTaskEvent;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_metadata::DataTypePluginMetadata;
 use squalr_engine_api::structures::projects::project_manager::ProjectManager;
 use std::{
     any::{Any, TypeId},
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{Arc, RwLock},
 };
 
@@ -25,6 +31,10 @@ pub struct EngineUnprivilegedState {
     file_system_logger: Arc<LogDispatcher>,
     /// Project manager for organizing and manipulating projects.
     project_manager: Arc<ProjectManager>,
+    /// Metadata for all plugin-defined data types registered by the engine, mirrored for data type selectors.
+    plugin_data_types: Arc<RwLock<Vec<DataTypePluginMetadata>>>,
+    /// Loads plugins locally when the engine runs in a separate process, such that plugin-defined values can be displayed.
+    data_type_plugin_loader: Arc<DataTypePluginLoader>,
 }
 
 #[derive(Clone, Copy)]
@@ -66,13 +76,27 @@ impl EngineUnprivilegedState {
                 enable_console_output: options.enable_console_logging,
             })),
             project_manager,
+            plugin_data_types: Arc::new(RwLock::new(vec![])),
+            data_type_plugin_loader: Arc::new(DataTypePluginLoader::new()),
         })
     }
 
     pub fn initialize(&self) {
+        self.listen_for_plugin_data_types();
         self.start_event_dispatcher();
     }
 
+    /// Gets the metadata for all plugin-defined data types that the engine has registered.
+    pub fn get_plugin_data_types(&self) -> Vec<DataTypePluginMetadata> {
+        match self.plugin_data_types.read() {
+            Ok(plugin_data_types) => plugin_data_types.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire plugin data types read lock: {}", error);
+                vec![]
+            }
+        }
+    }
+
     /// Gets the file system logger that routes log events to the log file.
     pub fn get_logger(&self) -> &Arc<LogDispatcher> {
         &self.file_system_logger
@@ -120,6 +144,34 @@ impl EngineUnprivilegedState {
         }
     }
 
+    /// Mirrors plugin data type metadata as the engine registers new types. If the engine is in another process, the plugin
+    /// is also loaded locally so that values of plugin-defined types can be parsed and displayed on this side.
+    fn listen_for_plugin_data_types(&self) {
+        let plugin_data_types = self.plugin_data_types.clone();
+        let data_type_plugin_loader = self.data_type_plugin_loader.clone();
+
+        self.listen_for_engine_event::<DataTypesChangedEvent>(move |data_types_changed_event| {
+            let symbol_registry = SymbolRegistry::get_instance();
+            let registered_data_types = symbol_registry.get_data_type_registry();
+            let mut attempted_plugin_paths = HashSet::new();
+
+            for data_type in &data_types_changed_event.data_types {
+                if registered_data_types.contains_key(data_type.get_data_type_id()) || !attempted_plugin_paths.insert(data_type.get_plugin_path()) {
+                    continue;
+                }
+
+                if let Err(error) = data_type_plugin_loader.load_plugin(data_type.get_plugin_path(), &[symbol_registry]) {
+                    log::error!("Failed to mirror data type plugin: {}", error);
+                }
+            }
+
+            match plugin_data_types.write() {
+                Ok(mut plugin_data_types) => *plugin_data_types = data_types_changed_event.data_types.clone(),
+                Err(error) => log::error!("Failed to acquire plugin data types write lock: {}", error),
+            }
+        });
+    }
+
     /// Starts listening for all engine events and routes specific events to listeners for that event type.
     fn start_event_dispatcher(&self) {
         let event_receiver = match self.engine_api_unprivileged_bindings.read() {
@@ -156,6 +208,25 @@ impl EngineUnprivilegedState {
         engine_event: EngineEvent,
     ) {
         match engine_event {
+            EngineEvent::Memory(memory_event) => match memory_event {
+                MemoryEvent::FreezeFailures { freeze_failures_event } => {
+                    Self::dispatch_engine_event(event_listeners, freeze_failures_event);
+                }
+                MemoryEvent::ModulesChanged { modules_changed_event } => {
+                    Self::dispatch_engine_event(event_listeners, modules_changed_event);
+                }
+                MemoryEvent::RegionsChanged { regions_changed_event } => {
+                    Self::dispatch_engine_event(event_listeners, regions_changed_event);
+                }
+                MemoryEvent::StaleModuleBuilds { stale_module_builds_event } => {
+                    Self::dispatch_engine_event(event_listeners, stale_module_builds_event);
+                }
+            },
+            EngineEvent::Plugins(plugins_event) => match plugins_event {
+                PluginsEvent::DataTypesChanged { data_types_changed_event } => {
+                    Self::dispatch_engine_event(event_listeners, data_types_changed_event);
+                }
+            },
             EngineEvent::Process(process_event) => match process_event {
                 ProcessEvent::ProcessChanged { process_changed_event } => {
                     Self::dispatch_engine_event(event_listeners, process_changed_event);
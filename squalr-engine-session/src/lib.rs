@@ -1,6 +1,12 @@
+pub mod code_patches;
+pub mod disassembly;
 pub mod engine_privileged_state;
 pub mod engine_unprivileged_state;
 mod logging;
+pub mod module_symbols;
 pub mod os;
+pub mod plugins;
 pub mod registries;
+pub mod runtime_classes;
+pub mod struct_inference;
 pub mod tasks;
@@ -0,0 +1,5 @@
+pub mod module_build_identity_resolver;
+pub mod module_file_parser;
+pub mod module_image_parser;
+pub mod module_symbol_cache;
+pub mod module_symbol_resolver;
@@ -0,0 +1,120 @@
+use crate::os::engine_os_provider::EngineOsProviders;
+use squalr_engine_api::structures::memory::module_build_identity::ModuleBuildIdentity;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Computes build identities for loaded modules, preferring identities recorded by the linker over hashes of the module headers.
+pub struct ModuleBuildIdentityResolver;
+
+impl ModuleBuildIdentityResolver {
+    const HEADER_SIZE: usize = 0x1000;
+    const PE_HEADER_OFFSET_FIELD: usize = 0x3C;
+    const PE_TIMESTAMP_OFFSET: usize = 8;
+    const PE_OPTIONAL_HEADER_OFFSET: usize = 24;
+    const PE_CHECKSUM_OFFSET: usize = 64;
+    const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+    /// Computes the build identity of a loaded module. PE modules are identified by their link timestamp and checksum, ELF and
+    /// Mach-O modules by their build id or uuid, and anything else by a hash of the header page.
+    pub fn compute_build_identity(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+    ) -> Option<ModuleBuildIdentity> {
+        let mut header_bytes = vec![0u8; Self::HEADER_SIZE.min(module.get_region_size().max(1) as usize)];
+
+        if !os_providers
+            .memory_read
+            .read_bytes(process_info, module.get_base_address(), &mut header_bytes)
+        {
+            log::debug!(
+                "Failed to read the headers of module '{}' to compute its build identity.",
+                module.get_module_name()
+            );
+            return None;
+        }
+
+        let build_id = match Self::format_pe_build_id(&header_bytes) {
+            Some(build_id) => build_id,
+            None => match os_providers
+                .module_symbols
+                .get_module_symbol_table(process_info, module)
+                .and_then(|module_symbol_table| module_symbol_table.get_build_id().clone())
+            {
+                Some(build_id) => format!("build-id:{}", build_id),
+                None => format!("header-hash:{:016x}", Self::hash_bytes(&header_bytes)),
+            },
+        };
+
+        Some(ModuleBuildIdentity::new(module.get_module_name().to_string(), build_id))
+    }
+
+    /// Formats the link timestamp and checksum of a PE image header, if the bytes start with one.
+    pub fn format_pe_build_id(header_bytes: &[u8]) -> Option<String> {
+        if !header_bytes.starts_with(b"MZ") {
+            return None;
+        }
+
+        let nt_header_offset = Self::read_u32(header_bytes, Self::PE_HEADER_OFFSET_FIELD)? as usize;
+
+        if header_bytes.get(nt_header_offset..nt_header_offset.checked_add(4)?)? != b"PE\0\0" {
+            return None;
+        }
+
+        let timestamp = Self::read_u32(header_bytes, nt_header_offset + Self::PE_TIMESTAMP_OFFSET)?;
+        let checksum = Self::read_u32(header_bytes, nt_header_offset + Self::PE_OPTIONAL_HEADER_OFFSET + Self::PE_CHECKSUM_OFFSET)?;
+
+        Some(format!("pe:{:08x}-{:08x}", timestamp, checksum))
+    }
+
+    /// Hashes bytes with 64-bit FNV-1a, which is stable across runs and platforms unlike the standard library hasher.
+    pub fn hash_bytes(bytes: &[u8]) -> u64 {
+        bytes
+            .iter()
+            .fold(Self::FNV_OFFSET_BASIS, |hash, byte| (hash ^ *byte as u64).wrapping_mul(Self::FNV_PRIME))
+    }
+
+    fn read_u32(
+        bytes: &[u8],
+        offset: usize,
+    ) -> Option<u32> {
+        let value_bytes = bytes.get(offset..offset.checked_add(4)?)?;
+
+        Some(u32::from_le_bytes(value_bytes.try_into().ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModuleBuildIdentityResolver;
+
+    #[test]
+    fn format_pe_build_id_reads_timestamp_and_checksum() {
+        let mut header_bytes = vec![0u8; 0x200];
+
+        header_bytes[0..2].copy_from_slice(b"MZ");
+        header_bytes[0x3C..0x40].copy_from_slice(&0x80u32.to_le_bytes());
+        header_bytes[0x80..0x84].copy_from_slice(b"PE\0\0");
+        header_bytes[0x88..0x8C].copy_from_slice(&0x5F3A_1B2Cu32.to_le_bytes());
+        header_bytes[0x98 + 64..0x98 + 68].copy_from_slice(&0x0001_A2B3u32.to_le_bytes());
+
+        assert_eq!(
+            ModuleBuildIdentityResolver::format_pe_build_id(&header_bytes).as_deref(),
+            Some("pe:5f3a1b2c-0001a2b3")
+        );
+    }
+
+    #[test]
+    fn format_pe_build_id_rejects_non_pe_headers() {
+        let mut header_bytes = vec![0u8; 0x200];
+
+        header_bytes[0..4].copy_from_slice(b"\x7FELF");
+
+        assert!(ModuleBuildIdentityResolver::format_pe_build_id(&header_bytes).is_none());
+        assert_ne!(
+            ModuleBuildIdentityResolver::hash_bytes(&header_bytes),
+            ModuleBuildIdentityResolver::hash_bytes(&header_bytes[1..])
+        );
+    }
+}
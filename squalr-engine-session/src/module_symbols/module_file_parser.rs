@@ -0,0 +1,205 @@
+use object::{BinaryFormat, Object, ObjectSegment, ObjectSymbol, SegmentFlags, SymbolKind};
+use squalr_engine_api::structures::memory::module_symbol::ModuleSymbol;
+use squalr_engine_api::structures::memory::module_symbol_error::ModuleSymbolError;
+use squalr_engine_api::structures::memory::module_symbol_table::ModuleSymbolTable;
+use std::ops::Range;
+
+/// The page size used to align the executable segment that module base addresses are reported from.
+const MODULE_PAGE_SIZE: u64 = 0x1000;
+
+/// Parses the symbol, dynamic symbol, and export tables of ELF, PE, and Mach-O module files as they are laid out on disk.
+pub struct ModuleFileParser;
+
+impl ModuleFileParser {
+    /// Parses a module file, producing symbols relative to the base address that the module is reported at once loaded.
+    pub fn parse(
+        module_name: &str,
+        file_bytes: &[u8],
+    ) -> Result<ModuleSymbolTable, ModuleSymbolError> {
+        let object_file = object::File::parse(file_bytes).map_err(|_| ModuleSymbolError::UnsupportedFormat)?;
+        let binary_format = object_file.format();
+        let module_base_address = Self::get_module_base_address(&object_file);
+        let mut symbols = Vec::new();
+
+        for symbol in object_file.symbols().chain(object_file.dynamic_symbols()) {
+            if !symbol.is_definition() || !matches!(symbol.kind(), SymbolKind::Text | SymbolKind::Data | SymbolKind::Unknown) {
+                continue;
+            }
+
+            let (Ok(symbol_name), Some(offset)) = (symbol.name(), symbol.address().checked_sub(module_base_address)) else {
+                continue;
+            };
+
+            symbols.push(ModuleSymbol::new(
+                Self::normalize_symbol_name(binary_format, symbol_name),
+                offset,
+                symbol.size(),
+            ));
+        }
+
+        for export in object_file.exports().unwrap_or_default() {
+            let (Ok(symbol_name), Some(offset)) = (std::str::from_utf8(export.name()), export.address().checked_sub(module_base_address)) else {
+                continue;
+            };
+
+            symbols.push(ModuleSymbol::new(Self::normalize_symbol_name(binary_format, symbol_name), offset, 0));
+        }
+
+        let (build_id, debug_link) = Self::get_debug_identifiers(&object_file);
+
+        Ok(ModuleSymbolTable::new(module_name.to_string(), build_id, debug_link, symbols))
+    }
+
+    /// Parses the address ranges that the segments of a module file occupy once loaded at the given module base address.
+    pub fn parse_image_ranges(
+        file_bytes: &[u8],
+        module_base_address: u64,
+    ) -> Result<Vec<Range<u64>>, ModuleSymbolError> {
+        let object_file = object::File::parse(file_bytes).map_err(|_| ModuleSymbolError::UnsupportedFormat)?;
+        let load_bias = module_base_address.wrapping_sub(Self::get_module_base_address(&object_file));
+
+        Ok(object_file
+            .segments()
+            .filter(|segment| segment.size() > 0 && Self::is_readable_segment(segment.flags()))
+            .map(|segment| {
+                let segment_start_address = segment.address().wrapping_add(load_bias);
+
+                segment_start_address..segment_start_address.saturating_add(segment.size())
+            })
+            .collect())
+    }
+
+    /// Formats raw build identifier bytes as an uppercase hex string.
+    pub fn format_build_id(build_id_bytes: &[u8]) -> String {
+        build_id_bytes
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect()
+    }
+
+    /// Formats a PDB identity as its GUID bytes followed by its age, matching the layout used by symbol servers.
+    pub fn format_pdb_build_id(
+        guid: &[u8],
+        age: u32,
+    ) -> String {
+        format!("{}{:X}", Self::format_build_id(guid), age)
+    }
+
+    /// Strips the leading underscore that Mach-O prepends to C symbol names.
+    pub fn normalize_symbol_name(
+        binary_format: BinaryFormat,
+        symbol_name: &str,
+    ) -> String {
+        match binary_format {
+            BinaryFormat::MachO => symbol_name.strip_prefix('_').unwrap_or(symbol_name).to_string(),
+            _ => symbol_name.to_string(),
+        }
+    }
+
+    /// Gets the virtual address that corresponds to the module base address. PE modules are reported from their image base,
+    /// whereas ELF and Mach-O modules are reported from the start of their first executable segment.
+    fn get_module_base_address(object_file: &object::File) -> u64 {
+        if object_file.format() == BinaryFormat::Pe {
+            return object_file.relative_address_base();
+        }
+
+        object_file
+            .segments()
+            .filter(|segment| Self::is_executable_segment(segment.flags()))
+            .map(|segment| segment.address())
+            .min()
+            .unwrap_or(0)
+            & !(MODULE_PAGE_SIZE - 1)
+    }
+
+    fn is_executable_segment(segment_flags: SegmentFlags) -> bool {
+        match segment_flags {
+            SegmentFlags::Elf { p_flags } => p_flags & object::elf::PF_X != 0,
+            SegmentFlags::MachO { initprot, .. } => initprot & object::macho::VM_PROT_EXECUTE != 0,
+            _ => false,
+        }
+    }
+
+    /// Checks whether a segment is mapped readable, which excludes guard segments such as the Mach-O `__PAGEZERO`.
+    fn is_readable_segment(segment_flags: SegmentFlags) -> bool {
+        match segment_flags {
+            SegmentFlags::Elf { p_flags } => p_flags & object::elf::PF_R != 0,
+            SegmentFlags::MachO { initprot, .. } => initprot & object::macho::VM_PROT_READ != 0,
+            _ => true,
+        }
+    }
+
+    fn get_debug_identifiers(object_file: &object::File) -> (Option<String>, Option<String>) {
+        match object_file.format() {
+            BinaryFormat::Elf => (
+                object_file.build_id().ok().flatten().map(Self::format_build_id),
+                object_file
+                    .gnu_debuglink()
+                    .ok()
+                    .flatten()
+                    .map(|(debug_link, _crc)| String::from_utf8_lossy(debug_link).to_string()),
+            ),
+            BinaryFormat::MachO => (
+                object_file
+                    .mach_uuid()
+                    .ok()
+                    .flatten()
+                    .map(|uuid| Self::format_build_id(&uuid)),
+                None,
+            ),
+            BinaryFormat::Pe => match object_file.pdb_info() {
+                Ok(Some(code_view)) => (
+                    Some(Self::format_pdb_build_id(&code_view.guid(), code_view.age())),
+                    Some(String::from_utf8_lossy(code_view.path()).to_string()),
+                ),
+                _ => (None, None),
+            },
+            _ => (None, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModuleFileParser;
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn squalr_module_file_parser_test_symbol() -> u32 {
+        0x5175
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn parse_finds_symbols_in_the_current_executable() {
+        let executable_path = std::env::current_exe().expect("current executable path should be known");
+        let executable_bytes = std::fs::read(&executable_path).expect("current executable should be readable");
+        let symbol_table = ModuleFileParser::parse("test", &executable_bytes).expect("current executable should parse");
+
+        assert_eq!(squalr_module_file_parser_test_symbol(), 0x5175);
+        assert!(
+            symbol_table
+                .find_symbol_by_name("squalr_module_file_parser_test_symbol")
+                .is_some()
+        );
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn parse_image_ranges_places_the_executable_segment_at_the_module_base() {
+        let executable_path = std::env::current_exe().expect("current executable path should be known");
+        let executable_bytes = std::fs::read(&executable_path).expect("current executable should be readable");
+        let image_ranges = ModuleFileParser::parse_image_ranges(&executable_bytes, 0x40_0000).expect("current executable should parse");
+
+        assert!(image_ranges.len() > 1);
+        assert!(
+            image_ranges
+                .iter()
+                .any(|image_range| image_range.start >= 0x40_0000 && image_range.start < 0x40_1000)
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_file_formats() {
+        assert!(ModuleFileParser::parse("test", b"not a module").is_err());
+    }
+}
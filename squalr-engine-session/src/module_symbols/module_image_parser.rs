@@ -0,0 +1,514 @@
+use crate::module_symbols::module_file_parser::ModuleFileParser;
+use object::BinaryFormat;
+use squalr_engine_api::structures::memory::module_symbol::ModuleSymbol;
+use squalr_engine_api::structures::memory::module_symbol_error::ModuleSymbolError;
+use squalr_engine_api::structures::memory::module_symbol_table::ModuleSymbolTable;
+
+const PE_DOS_MAGIC: u16 = 0x5A4D;
+const PE_SIGNATURE: u32 = 0x0000_4550;
+const PE_OPTIONAL_HEADER_OFFSET: usize = 24;
+const PE_OPTIONAL_HEADER_MAGIC_32: u16 = 0x010B;
+const PE_OPTIONAL_HEADER_MAGIC_64: u16 = 0x020B;
+const PE_DIRECTORY_ENTRY_EXPORT: usize = 0;
+const PE_DIRECTORY_ENTRY_DEBUG: usize = 6;
+const PE_DEBUG_DIRECTORY_SIZE: usize = 28;
+const PE_DEBUG_TYPE_CODEVIEW: u32 = 2;
+const PE_CODEVIEW_RSDS_SIGNATURE: u32 = 0x5344_5352;
+const MACHO_MAGIC_32: u32 = 0xFEED_FACE;
+const MACHO_MAGIC_64: u32 = 0xFEED_FACF;
+const MACHO_LC_SEGMENT: u32 = 0x1;
+const MACHO_LC_SYMTAB: u32 = 0x2;
+const MACHO_LC_SEGMENT_64: u32 = 0x19;
+const MACHO_LC_UUID: u32 = 0x1B;
+const MACHO_N_STAB: u8 = 0xE0;
+const MACHO_N_TYPE: u8 = 0x0E;
+const MACHO_N_SECT: u8 = 0x0E;
+const MAX_SYMBOL_NAME_LENGTH: usize = 0x100;
+const MAX_TABLE_SIZE: usize = 0x400_0000;
+
+/// Parses the export tables of PE and Mach-O modules directly from their loaded images in process memory.
+/// This covers modules whose files are unavailable, such as libraries that only exist within the dyld shared cache.
+pub struct ModuleImageParser;
+
+#[derive(Default)]
+struct MachOSegment {
+    vm_address: u64,
+    file_offset: u64,
+}
+
+#[derive(Default)]
+struct MachOSymbolTableCommand {
+    symbol_offset: u32,
+    symbol_count: u32,
+    string_offset: u32,
+    string_size: u32,
+}
+
+impl ModuleImageParser {
+    /// Parses a loaded module image, reading bytes at offsets relative to the module base through the given reader.
+    pub fn parse(
+        module_name: &str,
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+    ) -> Result<ModuleSymbolTable, ModuleSymbolError> {
+        let magic_bytes = Self::read_image(read_bytes, 0, 4)?;
+        let magic = Self::read_u32(&magic_bytes, 0)?;
+
+        if magic as u16 == PE_DOS_MAGIC {
+            Self::parse_pe(module_name, read_bytes)
+        } else if magic == MACHO_MAGIC_32 || magic == MACHO_MAGIC_64 {
+            Self::parse_macho(module_name, read_bytes, magic == MACHO_MAGIC_64)
+        } else {
+            Err(ModuleSymbolError::UnsupportedFormat)
+        }
+    }
+
+    fn parse_pe(
+        module_name: &str,
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+    ) -> Result<ModuleSymbolTable, ModuleSymbolError> {
+        let dos_header = Self::read_image(read_bytes, 0, 0x40)?;
+        let nt_headers_offset = Self::read_u32(&dos_header, 0x3C)? as u64;
+        let nt_headers = Self::read_image(read_bytes, nt_headers_offset, 0x108)?;
+
+        if Self::read_u32(&nt_headers, 0)? != PE_SIGNATURE {
+            return Err(ModuleSymbolError::malformed_image("missing PE signature"));
+        }
+
+        let data_directories_offset = PE_OPTIONAL_HEADER_OFFSET
+            + match Self::read_u16(&nt_headers, PE_OPTIONAL_HEADER_OFFSET)? {
+                PE_OPTIONAL_HEADER_MAGIC_32 => 96,
+                PE_OPTIONAL_HEADER_MAGIC_64 => 112,
+                optional_header_magic => {
+                    return Err(ModuleSymbolError::malformed_image(format!(
+                        "unknown optional header magic 0x{:X}",
+                        optional_header_magic
+                    )));
+                }
+            };
+        let read_directory = |directory_index: usize| -> Result<(u32, u32), ModuleSymbolError> {
+            let directory_offset = data_directories_offset + directory_index * 8;
+
+            Ok((
+                Self::read_u32(&nt_headers, directory_offset)?,
+                Self::read_u32(&nt_headers, directory_offset + 4)?,
+            ))
+        };
+        let (export_rva, export_size) = read_directory(PE_DIRECTORY_ENTRY_EXPORT)?;
+        let symbols = Self::parse_pe_exports(read_bytes, export_rva, export_size)?;
+        let (debug_rva, debug_size) = read_directory(PE_DIRECTORY_ENTRY_DEBUG)?;
+        let (build_id, debug_link) = Self::parse_pe_codeview(read_bytes, debug_rva, debug_size).unwrap_or_default();
+
+        Ok(ModuleSymbolTable::new(module_name.to_string(), build_id, debug_link, symbols))
+    }
+
+    fn parse_pe_exports(
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        export_rva: u32,
+        export_size: u32,
+    ) -> Result<Vec<ModuleSymbol>, ModuleSymbolError> {
+        if export_rva == 0 || export_size < 40 {
+            return Ok(Vec::new());
+        }
+
+        let export_directory = Self::read_image(read_bytes, export_rva as u64, 40)?;
+        let function_count = Self::read_u32(&export_directory, 0x14)? as usize;
+        let name_count = Self::read_u32(&export_directory, 0x18)? as usize;
+        let function_addresses = Self::read_image(read_bytes, Self::read_u32(&export_directory, 0x1C)? as u64, function_count.saturating_mul(4))?;
+        let name_addresses = Self::read_image(read_bytes, Self::read_u32(&export_directory, 0x20)? as u64, name_count.saturating_mul(4))?;
+        let name_ordinals = Self::read_image(read_bytes, Self::read_u32(&export_directory, 0x24)? as u64, name_count.saturating_mul(2))?;
+        let export_range = export_rva..export_rva.saturating_add(export_size);
+        let mut symbols = Vec::with_capacity(name_count);
+
+        for name_index in 0..name_count {
+            let name_rva = Self::read_u32(&name_addresses, name_index * 4)?;
+            let ordinal = Self::read_u16(&name_ordinals, name_index * 2)? as usize;
+            let function_rva = Self::read_u32(&function_addresses, ordinal * 4)?;
+
+            // Forwarded exports point at a string naming another module's export, rather than at code or data in this module.
+            if export_range.contains(&function_rva) {
+                continue;
+            }
+
+            if let Some(symbol_name) = Self::read_c_string(read_bytes, name_rva as u64) {
+                symbols.push(ModuleSymbol::new(symbol_name, function_rva as u64, 0));
+            }
+        }
+
+        Ok(symbols)
+    }
+
+    fn parse_pe_codeview(
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        debug_rva: u32,
+        debug_size: u32,
+    ) -> Option<(Option<String>, Option<String>)> {
+        if debug_rva == 0 {
+            return None;
+        }
+
+        let debug_directories = Self::read_image(read_bytes, debug_rva as u64, debug_size as usize).ok()?;
+
+        debug_directories
+            .chunks_exact(PE_DEBUG_DIRECTORY_SIZE)
+            .filter(|debug_directory| Self::read_u32(debug_directory, 0x0C).ok() == Some(PE_DEBUG_TYPE_CODEVIEW))
+            .find_map(|debug_directory| {
+                let codeview_rva = Self::read_u32(debug_directory, 0x14).ok()? as u64;
+                let codeview_header = Self::read_image(read_bytes, codeview_rva, 24).ok()?;
+
+                if Self::read_u32(&codeview_header, 0).ok()? != PE_CODEVIEW_RSDS_SIGNATURE {
+                    return None;
+                }
+
+                let build_id = ModuleFileParser::format_pdb_build_id(&codeview_header[4..20], Self::read_u32(&codeview_header, 20).ok()?);
+
+                Some((Some(build_id), Self::read_c_string(read_bytes, codeview_rva + 24)))
+            })
+    }
+
+    fn parse_macho(
+        module_name: &str,
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        is_64_bit: bool,
+    ) -> Result<ModuleSymbolTable, ModuleSymbolError> {
+        let header_size = if is_64_bit { 32 } else { 28 };
+        let header = Self::read_image(read_bytes, 0, header_size)?;
+        let load_commands = Self::read_image(read_bytes, header_size as u64, Self::read_u32(&header, 20)? as usize)?;
+        let mut text_segment = None;
+        let mut linkedit_segment = None;
+        let mut symbol_table_command = None;
+        let mut build_id = None;
+        let mut command_offset = 0;
+
+        while command_offset + 8 <= load_commands.len() {
+            let command = Self::read_u32(&load_commands, command_offset)?;
+            let command_size = Self::read_u32(&load_commands, command_offset + 4)? as usize;
+            let command_bytes = load_commands
+                .get(command_offset..command_offset.saturating_add(command_size))
+                .filter(|_| command_size >= 8)
+                .ok_or_else(|| ModuleSymbolError::malformed_image("truncated load command"))?;
+
+            match command {
+                MACHO_LC_SEGMENT | MACHO_LC_SEGMENT_64 => {
+                    let segment_name = Self::read_fixed_string(command_bytes, 8, 16)?;
+                    let segment = if command == MACHO_LC_SEGMENT_64 {
+                        MachOSegment {
+                            vm_address: Self::read_u64(command_bytes, 24)?,
+                            file_offset: Self::read_u64(command_bytes, 40)?,
+                        }
+                    } else {
+                        MachOSegment {
+                            vm_address: Self::read_u32(command_bytes, 24)? as u64,
+                            file_offset: Self::read_u32(command_bytes, 32)? as u64,
+                        }
+                    };
+
+                    match segment_name.as_str() {
+                        "__TEXT" => text_segment = Some(segment),
+                        "__LINKEDIT" => linkedit_segment = Some(segment),
+                        _ => {}
+                    }
+                }
+                MACHO_LC_SYMTAB => {
+                    symbol_table_command = Some(MachOSymbolTableCommand {
+                        symbol_offset: Self::read_u32(command_bytes, 8)?,
+                        symbol_count: Self::read_u32(command_bytes, 12)?,
+                        string_offset: Self::read_u32(command_bytes, 16)?,
+                        string_size: Self::read_u32(command_bytes, 20)?,
+                    });
+                }
+                MACHO_LC_UUID => {
+                    build_id = command_bytes.get(8..24).map(ModuleFileParser::format_build_id);
+                }
+                _ => {}
+            }
+
+            command_offset += command_size;
+        }
+
+        let text_segment = text_segment.ok_or_else(|| ModuleSymbolError::malformed_image("missing __TEXT segment"))?;
+        let symbols = match (symbol_table_command, linkedit_segment) {
+            (Some(symbol_table_command), Some(linkedit_segment)) => {
+                Self::parse_macho_symbols(read_bytes, is_64_bit, &text_segment, &linkedit_segment, &symbol_table_command)?
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(ModuleSymbolTable::new(module_name.to_string(), build_id, None, symbols))
+    }
+
+    fn parse_macho_symbols(
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        is_64_bit: bool,
+        text_segment: &MachOSegment,
+        linkedit_segment: &MachOSegment,
+        symbol_table_command: &MachOSymbolTableCommand,
+    ) -> Result<Vec<ModuleSymbol>, ModuleSymbolError> {
+        // Symbol and string table locations are file offsets into __LINKEDIT, which is mapped relative to __TEXT.
+        let linkedit_offset = linkedit_segment
+            .vm_address
+            .wrapping_sub(text_segment.vm_address)
+            .wrapping_sub(linkedit_segment.file_offset);
+        let symbol_entry_size = if is_64_bit { 16 } else { 12 };
+        let symbol_entries = Self::read_image(
+            read_bytes,
+            linkedit_offset.wrapping_add(symbol_table_command.symbol_offset as u64),
+            (symbol_table_command.symbol_count as usize).saturating_mul(symbol_entry_size),
+        )?;
+        let strings = Self::read_image(
+            read_bytes,
+            linkedit_offset.wrapping_add(symbol_table_command.string_offset as u64),
+            symbol_table_command.string_size as usize,
+        )?;
+        let mut symbols = Vec::new();
+
+        for symbol_entry in symbol_entries.chunks_exact(symbol_entry_size) {
+            let symbol_type = symbol_entry[4];
+
+            if symbol_type & MACHO_N_STAB != 0 || symbol_type & MACHO_N_TYPE != MACHO_N_SECT {
+                continue;
+            }
+
+            let symbol_address = if is_64_bit {
+                Self::read_u64(symbol_entry, 8)?
+            } else {
+                Self::read_u32(symbol_entry, 8)? as u64
+            };
+            let symbol_name = strings
+                .get(Self::read_u32(symbol_entry, 0)? as usize..)
+                .and_then(|name_bytes| name_bytes.split(|byte| *byte == 0).next())
+                .and_then(|name_bytes| std::str::from_utf8(name_bytes).ok());
+
+            if let (Some(symbol_name), Some(offset)) = (symbol_name, symbol_address.checked_sub(text_segment.vm_address)) {
+                symbols.push(ModuleSymbol::new(
+                    ModuleFileParser::normalize_symbol_name(BinaryFormat::MachO, symbol_name),
+                    offset,
+                    0,
+                ));
+            }
+        }
+
+        Ok(symbols)
+    }
+
+    fn read_image(
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        offset: u64,
+        size: usize,
+    ) -> Result<Vec<u8>, ModuleSymbolError> {
+        if size > MAX_TABLE_SIZE {
+            return Err(ModuleSymbolError::malformed_image(format!("table of {} bytes exceeds the size limit", size)));
+        }
+
+        let mut bytes = vec![0u8; size];
+
+        if size == 0 || read_bytes(offset, &mut bytes) {
+            Ok(bytes)
+        } else {
+            Err(ModuleSymbolError::unreadable_image(offset, size))
+        }
+    }
+
+    /// Reads a null-terminated string, shrinking the read until it no longer crosses into unreadable memory.
+    fn read_c_string(
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        offset: u64,
+    ) -> Option<String> {
+        let mut read_size = MAX_SYMBOL_NAME_LENGTH;
+
+        while read_size > 0 {
+            let mut bytes = vec![0u8; read_size];
+
+            if read_bytes(offset, &mut bytes) {
+                let string_length = bytes.iter().position(|byte| *byte == 0)?;
+
+                return std::str::from_utf8(&bytes[..string_length])
+                    .ok()
+                    .map(str::to_string);
+            }
+
+            read_size /= 2;
+        }
+
+        None
+    }
+
+    fn read_fixed_string(
+        bytes: &[u8],
+        offset: usize,
+        length: usize,
+    ) -> Result<String, ModuleSymbolError> {
+        let string_bytes = bytes
+            .get(offset..offset + length)
+            .ok_or_else(|| ModuleSymbolError::malformed_image("truncated name"))?;
+        let string_length = string_bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(length);
+
+        Ok(String::from_utf8_lossy(&string_bytes[..string_length]).to_string())
+    }
+
+    fn read_u16(
+        bytes: &[u8],
+        offset: usize,
+    ) -> Result<u16, ModuleSymbolError> {
+        bytes
+            .get(offset..offset + 2)
+            .and_then(|field_bytes| field_bytes.try_into().ok())
+            .map(u16::from_le_bytes)
+            .ok_or_else(|| ModuleSymbolError::malformed_image(format!("truncated field at 0x{:X}", offset)))
+    }
+
+    fn read_u32(
+        bytes: &[u8],
+        offset: usize,
+    ) -> Result<u32, ModuleSymbolError> {
+        bytes
+            .get(offset..offset + 4)
+            .and_then(|field_bytes| field_bytes.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or_else(|| ModuleSymbolError::malformed_image(format!("truncated field at 0x{:X}", offset)))
+    }
+
+    fn read_u64(
+        bytes: &[u8],
+        offset: usize,
+    ) -> Result<u64, ModuleSymbolError> {
+        bytes
+            .get(offset..offset + 8)
+            .and_then(|field_bytes| field_bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or_else(|| ModuleSymbolError::malformed_image(format!("truncated field at 0x{:X}", offset)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModuleImageParser;
+    use squalr_engine_api::structures::memory::module_symbol::ModuleSymbol;
+
+    fn write_bytes(
+        image: &mut [u8],
+        offset: usize,
+        bytes: &[u8],
+    ) {
+        image[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    fn write_u32(
+        image: &mut [u8],
+        offset: usize,
+        value: u32,
+    ) {
+        write_bytes(image, offset, &value.to_le_bytes());
+    }
+
+    fn write_u64(
+        image: &mut [u8],
+        offset: usize,
+        value: u64,
+    ) {
+        write_bytes(image, offset, &value.to_le_bytes());
+    }
+
+    fn create_reader(image: &[u8]) -> impl Fn(u64, &mut [u8]) -> bool + '_ {
+        move |offset, bytes| match image.get(offset as usize..offset as usize + bytes.len()) {
+            Some(image_bytes) => {
+                bytes.copy_from_slice(image_bytes);
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn parse_reads_pe_exports_and_codeview_identity_from_a_loaded_image() {
+        let mut image = vec![0u8; 0x1400];
+
+        write_bytes(&mut image, 0, b"MZ");
+        write_u32(&mut image, 0x3C, 0x80);
+        write_bytes(&mut image, 0x80, b"PE\0\0");
+        write_bytes(&mut image, 0x98, &0x020Bu16.to_le_bytes());
+        write_u32(&mut image, 0x108, 0x1000);
+        write_u32(&mut image, 0x10C, 0x100);
+        write_u32(&mut image, 0x138, 0x1200);
+        write_u32(&mut image, 0x13C, 28);
+
+        // Export directory with one export and one export forwarded to another module.
+        write_u32(&mut image, 0x1014, 2);
+        write_u32(&mut image, 0x1018, 2);
+        write_u32(&mut image, 0x101C, 0x1040);
+        write_u32(&mut image, 0x1020, 0x1050);
+        write_u32(&mut image, 0x1024, 0x1060);
+        write_u32(&mut image, 0x1040, 0x1500);
+        write_u32(&mut image, 0x1044, 0x1090);
+        write_u32(&mut image, 0x1050, 0x1070);
+        write_u32(&mut image, 0x1054, 0x1080);
+        write_bytes(&mut image, 0x1060, &[0, 0, 1, 0]);
+        write_bytes(&mut image, 0x1070, b"update_player\0");
+        write_bytes(&mut image, 0x1080, b"forwarded\0");
+        write_bytes(&mut image, 0x1090, b"other.dll.export\0");
+
+        // CodeView debug directory.
+        write_u32(&mut image, 0x120C, 2);
+        write_u32(&mut image, 0x1214, 0x1300);
+        write_bytes(&mut image, 0x1300, b"RSDS");
+        write_bytes(&mut image, 0x1304, &[0x11; 16]);
+        write_u32(&mut image, 0x1314, 3);
+        write_bytes(&mut image, 0x1318, b"game.pdb\0");
+
+        let symbol_table = ModuleImageParser::parse("game.exe", &create_reader(&image)).expect("PE image should parse");
+
+        assert_eq!(symbol_table.get_symbols(), &vec![ModuleSymbol::new("update_player".to_string(), 0x1500, 0)]);
+        assert_eq!(symbol_table.get_build_id(), &Some(format!("{}3", "11".repeat(16))));
+        assert_eq!(symbol_table.get_debug_link(), &Some("game.pdb".to_string()));
+    }
+
+    #[test]
+    fn parse_reads_macho_symbols_relative_to_the_text_segment() {
+        let mut image = vec![0u8; 0x4200];
+
+        write_u32(&mut image, 0, 0xFEED_FACF);
+        write_u32(&mut image, 20, 72 * 2 + 24 * 2);
+
+        // __TEXT, followed by a __LINKEDIT segment that maps file offset 0x3000 at image offset 0x4000.
+        write_u32(&mut image, 32, 0x19);
+        write_u32(&mut image, 36, 72);
+        write_bytes(&mut image, 40, b"__TEXT");
+        write_u64(&mut image, 56, 0x1_0000_0000);
+        write_u32(&mut image, 104, 0x19);
+        write_u32(&mut image, 108, 72);
+        write_bytes(&mut image, 112, b"__LINKEDIT");
+        write_u64(&mut image, 128, 0x1_0000_4000);
+        write_u64(&mut image, 144, 0x3000);
+        write_u32(&mut image, 176, 0x2);
+        write_u32(&mut image, 180, 24);
+        write_u32(&mut image, 184, 0x3000);
+        write_u32(&mut image, 188, 2);
+        write_u32(&mut image, 192, 0x3100);
+        write_u32(&mut image, 196, 0x10);
+        write_u32(&mut image, 200, 0x1B);
+        write_u32(&mut image, 204, 24);
+        write_bytes(&mut image, 208, &[0xAA; 16]);
+
+        // One defined symbol, and one undefined import that should be skipped.
+        write_u32(&mut image, 0x4000, 1);
+        image[0x4004] = 0x0F;
+        write_u64(&mut image, 0x4008, 0x1_0000_0800);
+        write_u32(&mut image, 0x4010, 7);
+        image[0x4014] = 0x01;
+        write_bytes(&mut image, 0x4100, b"\0_tick\0_ext\0");
+
+        let symbol_table = ModuleImageParser::parse("libgame.dylib", &create_reader(&image)).expect("Mach-O image should parse");
+
+        assert_eq!(symbol_table.get_symbols(), &vec![ModuleSymbol::new("tick".to_string(), 0x800, 0)]);
+        assert_eq!(symbol_table.get_build_id(), &Some("AA".repeat(16)));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_image_formats() {
+        let image = vec![0x7Fu8, b'E', b'L', b'F'];
+
+        assert!(ModuleImageParser::parse("libgame.so", &create_reader(&image)).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::module_symbols::module_file_parser::ModuleFileParser;
+use crate::module_symbols::module_image_parser::ModuleImageParser;
+use crate::os::engine_os_provider::{MemoryReadProvider, ModuleSymbolProvider};
+use squalr_engine_api::structures::memory::module_symbol_error::ModuleSymbolError;
+use squalr_engine_api::structures::memory::module_symbol_table::ModuleSymbolTable;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ModuleSymbolCacheKey {
+    process_id: u32,
+    module_name: String,
+    base_address: u64,
+    region_size: u64,
+}
+
+/// Loads module symbol tables on first use and caches them per loaded module instance, including modules that failed to parse.
+/// Module files are preferred, falling back to parsing the loaded image from memory when the file is unavailable or unsupported.
+pub struct ModuleSymbolCache {
+    memory_read: Arc<dyn MemoryReadProvider>,
+    symbol_tables: RwLock<HashMap<ModuleSymbolCacheKey, Option<Arc<ModuleSymbolTable>>>>,
+}
+
+impl ModuleSymbolCache {
+    pub fn new(memory_read: Arc<dyn MemoryReadProvider>) -> Self {
+        Self {
+            memory_read,
+            symbol_tables: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Parses the symbol table of a module without caching it.
+    pub fn load_module_symbol_table(
+        memory_read: &dyn MemoryReadProvider,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+    ) -> Result<ModuleSymbolTable, ModuleSymbolError> {
+        let module_name = module.get_module_name();
+        let module_path = module.get_module_path();
+
+        if !module_path.is_empty() {
+            let file_symbol_table = fs::read(module_path)
+                .map_err(|error| ModuleSymbolError::unreadable_file(module_path, error.to_string()))
+                .and_then(|file_bytes| ModuleFileParser::parse(module_name, &file_bytes));
+
+            match file_symbol_table {
+                Ok(symbol_table) => return Ok(symbol_table),
+                Err(error) => log::debug!("Falling back to parsing module '{}' from memory: {}", module_name, error),
+            }
+        }
+
+        let base_address = module.get_base_address();
+
+        ModuleImageParser::parse(module_name, &|offset, bytes| {
+            memory_read.read_bytes(process_info, base_address.wrapping_add(offset), bytes)
+        })
+    }
+}
+
+impl ModuleSymbolProvider for ModuleSymbolCache {
+    fn get_module_symbol_table(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+    ) -> Option<Arc<ModuleSymbolTable>> {
+        let cache_key = ModuleSymbolCacheKey {
+            process_id: process_info.get_process_id_raw(),
+            module_name: module.get_module_name().to_string(),
+            base_address: module.get_base_address(),
+            region_size: module.get_region_size(),
+        };
+
+        if let Some(symbol_table) = self
+            .symbol_tables
+            .read()
+            .ok()
+            .and_then(|symbol_tables| symbol_tables.get(&cache_key).cloned())
+        {
+            return symbol_table;
+        }
+
+        let symbol_table = match Self::load_module_symbol_table(self.memory_read.as_ref(), process_info, module) {
+            Ok(symbol_table) => Some(Arc::new(symbol_table)),
+            Err(error) => {
+                log::debug!("No symbols loaded for module '{}': {}", module.get_module_name(), error);
+                None
+            }
+        };
+
+        match self.symbol_tables.write() {
+            Ok(mut symbol_tables) => {
+                symbol_tables.insert(cache_key, symbol_table.clone());
+            }
+            Err(error) => log::error!("Failed to acquire module symbol cache write lock: {}", error),
+        }
+
+        symbol_table
+    }
+}
@@ -0,0 +1,46 @@
+use crate::os::engine_os_provider::EngineOsProviders;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Translates between absolute addresses and `module!symbol` names using the symbol tables of loaded modules.
+pub struct ModuleSymbolResolver;
+
+impl ModuleSymbolResolver {
+    /// Resolves a name of the form `module!symbol` to the absolute address of that symbol.
+    pub fn resolve_symbol_address(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        modules: &[NormalizedModule],
+        name: &str,
+    ) -> Option<u64> {
+        let (module_name, symbol_name) = AddressExpression::split_module_symbol(name)?;
+        let module = modules.iter().find(|module| {
+            module
+                .get_module_name()
+                .trim()
+                .eq_ignore_ascii_case(module_name.trim())
+        })?;
+        let symbol_table = os_providers
+            .module_symbols
+            .get_module_symbol_table(process_info, module)?;
+        let symbol = symbol_table.find_symbol_by_name(symbol_name)?;
+
+        Some(module.get_base_address().wrapping_add(symbol.get_offset()))
+    }
+
+    /// Formats a `module!symbol+offset` label for an absolute address, if a symbol of the containing module covers it.
+    pub fn format_symbol_label(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        modules: &[NormalizedModule],
+        address: u64,
+    ) -> Option<String> {
+        let module = modules.iter().find(|module| module.contains_address(address))?;
+        let symbol_table = os_providers
+            .module_symbols
+            .get_module_symbol_table(process_info, module)?;
+
+        symbol_table.format_symbol_label(address - module.get_base_address())
+    }
+}
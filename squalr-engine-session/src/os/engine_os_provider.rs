@@ -1,9 +1,17 @@
+use crate::module_symbols::module_symbol_cache::ModuleSymbolCache;
+use crate::runtime_classes::runtime_class_cache::RuntimeClassCache;
 use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::endian::Endian;
+use squalr_engine_api::structures::memory::module_symbol_table::ModuleSymbolTable;
 use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
+use squalr_engine_api::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
 use squalr_engine_operating_system::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_operating_system::memory_queryer::memory_queryer_trait::MemoryQueryerTrait;
 use squalr_engine_operating_system::memory_queryer::page_retrieval_mode::PageRetrievalMode;
@@ -14,6 +22,9 @@ use squalr_engine_operating_system::memory_writer::memory_writer_trait::MemoryWr
 use squalr_engine_operating_system::process_query::process_query_error::ProcessQueryError;
 use squalr_engine_operating_system::process_query::process_query_options::ProcessQueryOptions;
 use squalr_engine_operating_system::process_query::process_queryer::ProcessQuery;
+use squalr_engine_operating_system::watchpoints::WatchpointDebugger;
+use squalr_engine_operating_system::watchpoints::watchpoint_debugger_trait::WatchpointDebuggerTrait;
+use squalr_engine_operating_system::watchpoints::watchpoint_error::WatchpointError;
 use std::sync::Arc;
 
 pub trait ProcessQueryProvider: Send + Sync {
@@ -73,6 +84,24 @@ pub trait MemoryReadProvider: Send + Sync {
         address: u64,
         values: &mut [u8],
     ) -> bool;
+
+    /// Gets the byte order of values stored at the given address, which differs from the host for big endian guest memory.
+    fn get_endian(
+        &self,
+        _process_info: &OpenedProcessInfo,
+        _address: u64,
+    ) -> Endian {
+        Endian::Little
+    }
+
+    /// Gets the bitness of pointers stored at the given address, which differs from the host for guest memory of an emulated system.
+    fn get_bitness(
+        &self,
+        process_info: &OpenedProcessInfo,
+        _address: u64,
+    ) -> Bitness {
+        process_info.get_bitness()
+    }
 }
 
 pub trait MemoryWriteProvider: Send + Sync {
@@ -84,12 +113,60 @@ pub trait MemoryWriteProvider: Send + Sync {
     ) -> bool;
 }
 
+pub trait WatchpointProvider: Send + Sync {
+    fn attach(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), WatchpointError>;
+    fn detach(&self) -> Result<(), WatchpointError>;
+    fn get_attached_process_id(&self) -> Option<u32>;
+    fn set_watchpoint(
+        &self,
+        address: u64,
+        size: u64,
+        watchpoint_kind: WatchpointKind,
+    ) -> Result<u32, WatchpointError>;
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u32,
+    ) -> Result<(), WatchpointError>;
+    fn get_watchpoint_hits(
+        &self,
+        clear: bool,
+    ) -> Vec<WatchpointHit>;
+}
+
+pub trait ModuleSymbolProvider: Send + Sync {
+    fn get_module_symbol_table(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+    ) -> Option<Arc<ModuleSymbolTable>>;
+}
+
+pub trait RuntimeClassProvider: Send + Sync {
+    fn get_runtime_classes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+        modules: &[NormalizedModule],
+    ) -> Arc<Vec<RuntimeClass>>;
+    fn find_runtime_class_by_vtable(
+        &self,
+        process_info: &OpenedProcessInfo,
+        vtable_address: u64,
+    ) -> Option<RuntimeClass>;
+}
+
 #[derive(Clone)]
 pub struct EngineOsProviders {
     pub process_query: Arc<dyn ProcessQueryProvider>,
     pub memory_query: Arc<dyn MemoryQueryProvider>,
     pub memory_read: Arc<dyn MemoryReadProvider>,
     pub memory_write: Arc<dyn MemoryWriteProvider>,
+    pub watchpoints: Arc<dyn WatchpointProvider>,
+    pub module_symbols: Arc<dyn ModuleSymbolProvider>,
+    pub runtime_classes: Arc<dyn RuntimeClassProvider>,
 }
 
 impl EngineOsProviders {
@@ -98,23 +175,34 @@ impl EngineOsProviders {
         memory_query: Arc<dyn MemoryQueryProvider>,
         memory_read: Arc<dyn MemoryReadProvider>,
         memory_write: Arc<dyn MemoryWriteProvider>,
+        watchpoints: Arc<dyn WatchpointProvider>,
+        module_symbols: Arc<dyn ModuleSymbolProvider>,
+        runtime_classes: Arc<dyn RuntimeClassProvider>,
     ) -> Self {
         Self {
             process_query,
             memory_query,
             memory_read,
             memory_write,
+            watchpoints,
+            module_symbols,
+            runtime_classes,
         }
     }
 }
 
 impl Default for EngineOsProviders {
     fn default() -> Self {
+        let memory_read: Arc<dyn MemoryReadProvider> = Arc::new(DefaultMemoryReadProvider {});
+
         Self {
             process_query: Arc::new(DefaultProcessQueryProvider {}),
             memory_query: Arc::new(DefaultMemoryQueryProvider {}),
-            memory_read: Arc::new(DefaultMemoryReadProvider {}),
+            memory_read: memory_read.clone(),
             memory_write: Arc::new(DefaultMemoryWriteProvider {}),
+            watchpoints: Arc::new(DefaultWatchpointProvider {}),
+            module_symbols: Arc::new(ModuleSymbolCache::new(memory_read.clone())),
+            runtime_classes: Arc::new(RuntimeClassCache::new(memory_read)),
         }
     }
 }
@@ -226,3 +314,45 @@ impl MemoryWriteProvider for DefaultMemoryWriteProvider {
         MemoryWriter::get_instance().write_bytes(process_info, address, values)
     }
 }
+
+struct DefaultWatchpointProvider;
+
+impl WatchpointProvider for DefaultWatchpointProvider {
+    fn attach(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), WatchpointError> {
+        WatchpointDebugger::get_instance().attach(process_info.get_process_id_raw())
+    }
+
+    fn detach(&self) -> Result<(), WatchpointError> {
+        WatchpointDebugger::get_instance().detach()
+    }
+
+    fn get_attached_process_id(&self) -> Option<u32> {
+        WatchpointDebugger::get_instance().get_attached_process_id()
+    }
+
+    fn set_watchpoint(
+        &self,
+        address: u64,
+        size: u64,
+        watchpoint_kind: WatchpointKind,
+    ) -> Result<u32, WatchpointError> {
+        WatchpointDebugger::get_instance().set_watchpoint(address, size, watchpoint_kind)
+    }
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u32,
+    ) -> Result<(), WatchpointError> {
+        WatchpointDebugger::get_instance().remove_watchpoint(watchpoint_id)
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        clear: bool,
+    ) -> Vec<WatchpointHit> {
+        WatchpointDebugger::get_instance().get_watchpoint_hits(clear)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::os::engine_os_provider::EngineOsProviders;
+use squalr_engine_api::events::memory::modules_changed::modules_changed_event::ModulesChangedEvent;
+use squalr_engine_api::events::memory::regions_changed::regions_changed_event::RegionsChangedEvent;
+use squalr_engine_api::structures::memory::address_ranges::AddressRanges;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_operating_system::memory_queryer::page_retrieval_mode::PageRetrievalMode;
+use std::collections::HashSet;
+
+/// Detects module loads/unloads and memory region map/unmap operations by diffing successive queries of an opened process.
+/// Regions are diffed by the addresses they cover, such that regions being split, merged or reprotected are not reported.
+pub struct MemoryMapWatcher {
+    process_id: Option<u32>,
+    modules: HashSet<NormalizedModule>,
+    mapped_ranges: Vec<(u64, u64)>,
+}
+
+impl MemoryMapWatcher {
+    pub fn new() -> Self {
+        Self {
+            process_id: None,
+            modules: HashSet::new(),
+            mapped_ranges: Vec::new(),
+        }
+    }
+
+    /// Queries the current modules and regions of the opened process, returning any changes since the previous poll.
+    /// The first poll after attaching to a process only captures a baseline, since the attach itself is reported separately.
+    pub fn poll(
+        &mut self,
+        process_info: Option<&OpenedProcessInfo>,
+        os_providers: &EngineOsProviders,
+    ) -> (Option<ModulesChangedEvent>, Option<RegionsChangedEvent>) {
+        let process_info = match process_info {
+            Some(process_info) => process_info,
+            None => {
+                self.reset(None);
+                return (None, None);
+            }
+        };
+        let modules = os_providers
+            .memory_query
+            .get_modules(process_info)
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let mut regions = os_providers
+            .memory_query
+            .get_memory_page_bounds(process_info, PageRetrievalMode::FromSettings);
+
+        regions.sort();
+
+        let mapped_ranges = AddressRanges::merge(&regions);
+
+        if self.process_id != Some(process_info.get_process_id_raw()) {
+            self.reset(Some(process_info.get_process_id_raw()));
+            self.modules = modules;
+            self.mapped_ranges = mapped_ranges;
+
+            return (None, None);
+        }
+
+        let (loaded_modules, unloaded_modules) = Self::diff(&self.modules, &modules);
+        let mapped_regions = Self::to_regions(AddressRanges::subtract(&mapped_ranges, &self.mapped_ranges));
+        let unmapped_regions = Self::to_regions(AddressRanges::subtract(&self.mapped_ranges, &mapped_ranges));
+
+        self.modules = modules;
+        self.mapped_ranges = mapped_ranges;
+
+        let modules_changed_event = if loaded_modules.is_empty() && unloaded_modules.is_empty() {
+            None
+        } else {
+            Some(ModulesChangedEvent {
+                loaded_modules,
+                unloaded_modules,
+            })
+        };
+        let regions_changed_event = if mapped_regions.is_empty() && unmapped_regions.is_empty() {
+            None
+        } else {
+            Some(RegionsChangedEvent {
+                mapped_regions,
+                unmapped_regions,
+            })
+        };
+
+        (modules_changed_event, regions_changed_event)
+    }
+
+    fn reset(
+        &mut self,
+        process_id: Option<u32>,
+    ) {
+        self.process_id = process_id;
+        self.modules.clear();
+        self.mapped_ranges.clear();
+    }
+
+    fn to_regions(ranges: Vec<(u64, u64)>) -> Vec<NormalizedRegion> {
+        ranges
+            .into_iter()
+            .map(|(base_address, end_address)| NormalizedRegion::new(base_address, end_address - base_address))
+            .collect()
+    }
+
+    /// Gets the modules loaded and unloaded between two queries, sorted such that events are deterministic.
+    fn diff(
+        previous: &HashSet<NormalizedModule>,
+        current: &HashSet<NormalizedModule>,
+    ) -> (Vec<NormalizedModule>, Vec<NormalizedModule>) {
+        let mut added = current.difference(previous).cloned().collect::<Vec<_>>();
+        let mut removed = previous.difference(current).cloned().collect::<Vec<_>>();
+
+        added.sort_by_key(|module| (module.get_base_address(), module.get_region_size()));
+        removed.sort_by_key(|module| (module.get_base_address(), module.get_region_size()));
+
+        (added, removed)
+    }
+}
+
+impl Default for MemoryMapWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,279 @@
+use crate::module_symbols::module_symbol_cache::ModuleSymbolCache;
+use crate::os::PageRetrievalMode;
+use crate::os::engine_os_provider::{EngineOsProviders, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider};
+use crate::os::memory_middleware::memory_middleware_registry::MemoryMiddlewareRegistry;
+use crate::runtime_classes::runtime_class_cache::RuntimeClassCache;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::endian::Endian;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::memory::virtual_address_space::VirtualAddressSpace;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
+use std::sync::Arc;
+
+/// Shared state for the middleware providers, holding the unfiltered host providers that the middleware delegates to.
+#[derive(Clone)]
+struct MemoryMiddlewareContext {
+    host_providers: EngineOsProviders,
+    memory_middleware_registry: Arc<MemoryMiddlewareRegistry>,
+}
+
+impl MemoryMiddlewareContext {
+    fn get_address_spaces(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Option<Arc<Vec<VirtualAddressSpace>>> {
+        self.memory_middleware_registry.get_address_spaces(
+            process_info,
+            self.host_providers.memory_query.as_ref(),
+            self.host_providers.memory_read.as_ref(),
+        )
+    }
+
+    /// Translates a guest address range to a host address. Addresses pass through unchanged if no middleware is registered.
+    /// Otherwise, addresses outside of every resolved guest address space are rejected, since they would alias unrelated host memory.
+    fn guest_to_host(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        length: u64,
+    ) -> Option<u64> {
+        let Some(address_spaces) = self.get_address_spaces(process_info) else {
+            return Some(address);
+        };
+
+        address_spaces
+            .iter()
+            .find_map(|address_space| address_space.guest_to_host(address, length))
+    }
+}
+
+/// Wraps the host OS providers such that memory middlewares can expose guest address spaces as virtual regions and modules.
+pub fn create_memory_middleware_providers(
+    host_providers: EngineOsProviders,
+    memory_middleware_registry: Arc<MemoryMiddlewareRegistry>,
+) -> EngineOsProviders {
+    let context = MemoryMiddlewareContext {
+        host_providers: host_providers.clone(),
+        memory_middleware_registry,
+    };
+
+    let memory_read: Arc<dyn MemoryReadProvider> = Arc::new(MemoryMiddlewareReadProvider { context: context.clone() });
+
+    // Symbols and classes are cached separately from the host, since guest modules must be read through the middleware address translation.
+    EngineOsProviders::new(
+        host_providers.process_query,
+        Arc::new(MemoryMiddlewareQueryProvider { context: context.clone() }),
+        memory_read.clone(),
+        Arc::new(MemoryMiddlewareWriteProvider { context }),
+        host_providers.watchpoints,
+        Arc::new(ModuleSymbolCache::new(memory_read.clone())),
+        Arc::new(RuntimeClassCache::new(memory_read)),
+    )
+}
+
+struct MemoryMiddlewareQueryProvider {
+    context: MemoryMiddlewareContext,
+}
+
+impl MemoryQueryProvider for MemoryMiddlewareQueryProvider {
+    fn get_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule> {
+        let Some(address_spaces) = self.context.get_address_spaces(process_info) else {
+            return self
+                .context
+                .host_providers
+                .memory_query
+                .get_modules(process_info);
+        };
+
+        address_spaces
+            .iter()
+            .map(|address_space| {
+                NormalizedModule::new(
+                    address_space.get_module_name(),
+                    address_space.get_guest_base_address(),
+                    address_space.get_region_size(),
+                )
+            })
+            .collect()
+    }
+
+    fn address_to_module(
+        &self,
+        address: u64,
+        modules: &Vec<NormalizedModule>,
+    ) -> Option<(String, u64)> {
+        self.context
+            .host_providers
+            .memory_query
+            .address_to_module(address, modules)
+    }
+
+    fn resolve_module(
+        &self,
+        modules: &Vec<NormalizedModule>,
+        identifier: &str,
+    ) -> u64 {
+        self.context
+            .host_providers
+            .memory_query
+            .resolve_module(modules, identifier)
+    }
+
+    fn get_memory_page_bounds(
+        &self,
+        process_info: &OpenedProcessInfo,
+        page_retrieval_mode: PageRetrievalMode,
+    ) -> Vec<NormalizedRegion> {
+        let Some(address_spaces) = self.context.get_address_spaces(process_info) else {
+            return self
+                .context
+                .host_providers
+                .memory_query
+                .get_memory_page_bounds(process_info, page_retrieval_mode);
+        };
+
+        // Guest memory is entirely module memory, as each guest address space is exposed as a virtual module.
+        if page_retrieval_mode == PageRetrievalMode::FromNonModules {
+            return vec![];
+        }
+
+        address_spaces
+            .iter()
+            .map(|address_space| NormalizedRegion::new(address_space.get_guest_base_address(), address_space.get_region_size()))
+            .collect()
+    }
+}
+
+struct MemoryMiddlewareReadProvider {
+    context: MemoryMiddlewareContext,
+}
+
+impl MemoryReadProvider for MemoryMiddlewareReadProvider {
+    fn read(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        data_value: &mut DataValue,
+    ) -> bool {
+        match self
+            .context
+            .guest_to_host(process_info, address, data_value.get_size_in_bytes())
+        {
+            Some(host_address) => self
+                .context
+                .host_providers
+                .memory_read
+                .read(process_info, host_address, data_value),
+            None => false,
+        }
+    }
+
+    fn read_struct(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        valued_struct: &mut ValuedStruct,
+    ) -> bool {
+        match self
+            .context
+            .guest_to_host(process_info, address, valued_struct.get_size_in_bytes())
+        {
+            Some(host_address) => self
+                .context
+                .host_providers
+                .memory_read
+                .read_struct(process_info, host_address, valued_struct),
+            None => false,
+        }
+    }
+
+    fn read_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        match self
+            .context
+            .guest_to_host(process_info, address, values.len() as u64)
+        {
+            Some(host_address) => self
+                .context
+                .host_providers
+                .memory_read
+                .read_bytes(process_info, host_address, values),
+            None => false,
+        }
+    }
+
+    fn get_endian(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> Endian {
+        let Some(address_spaces) = self.context.get_address_spaces(process_info) else {
+            return self
+                .context
+                .host_providers
+                .memory_read
+                .get_endian(process_info, address);
+        };
+
+        address_spaces
+            .iter()
+            .find(|address_space| address_space.guest_to_host(address, 1).is_some())
+            .map(|address_space| address_space.get_endian().clone())
+            .unwrap_or_default()
+    }
+
+    fn get_bitness(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> Bitness {
+        let Some(address_spaces) = self.context.get_address_spaces(process_info) else {
+            return self
+                .context
+                .host_providers
+                .memory_read
+                .get_bitness(process_info, address);
+        };
+
+        address_spaces
+            .iter()
+            .find(|address_space| address_space.guest_to_host(address, 1).is_some())
+            .map(|address_space| address_space.get_guest_bitness())
+            .unwrap_or_else(|| process_info.get_bitness())
+    }
+}
+
+struct MemoryMiddlewareWriteProvider {
+    context: MemoryMiddlewareContext,
+}
+
+impl MemoryWriteProvider for MemoryMiddlewareWriteProvider {
+    fn write_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        match self
+            .context
+            .guest_to_host(process_info, address, values.len() as u64)
+        {
+            Some(host_address) => self
+                .context
+                .host_providers
+                .memory_write
+                .write_bytes(process_info, host_address, values),
+            None => false,
+        }
+    }
+}
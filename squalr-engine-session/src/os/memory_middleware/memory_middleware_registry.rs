@@ -0,0 +1,176 @@
+use crate::os::engine_os_provider::{MemoryQueryProvider, MemoryReadProvider};
+use crate::os::memory_middleware::memory_middleware_trait::MemoryMiddleware;
+use squalr_engine_api::structures::memory::virtual_address_space::VirtualAddressSpace;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// How long an incomplete resolve is reused before retrying, such that accesses while a signature is missing do not rescan host memory
+/// on every read.
+pub const RESOLVE_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The guest address spaces resolved for a specific opened process.
+struct ResolvedAddressSpaces {
+    process_id: u32,
+    handle: u64,
+    address_spaces: Arc<Vec<VirtualAddressSpace>>,
+    /// Whether every middleware resolved its address space. Incomplete resolves are retried rather than cached.
+    is_complete: bool,
+    resolved_at: Instant,
+}
+
+/// Tracks the active memory middlewares, and caches the guest address spaces they resolve for the opened process.
+pub struct MemoryMiddlewareRegistry {
+    middlewares: RwLock<Vec<Arc<dyn MemoryMiddleware>>>,
+    resolved_address_spaces: RwLock<Option<ResolvedAddressSpaces>>,
+}
+
+impl Default for MemoryMiddlewareRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryMiddlewareRegistry {
+    pub fn new() -> Self {
+        Self {
+            middlewares: RwLock::new(vec![]),
+            resolved_address_spaces: RwLock::new(None),
+        }
+    }
+
+    /// Registers a middleware, replacing any existing middleware with the same name.
+    pub fn register(
+        &self,
+        middleware: Arc<dyn MemoryMiddleware>,
+    ) {
+        match self.middlewares.write() {
+            Ok(mut middlewares) => {
+                middlewares.retain(|existing| existing.get_middleware_name() != middleware.get_middleware_name());
+                middlewares.push(middleware);
+            }
+            Err(error) => {
+                log::error!("Failed to acquire memory middleware write lock: {}", error);
+            }
+        }
+
+        self.invalidate();
+    }
+
+    /// Unregisters the middleware with the given name, returning whether one was removed.
+    pub fn unregister(
+        &self,
+        middleware_name: &str,
+    ) -> bool {
+        let removed = match self.middlewares.write() {
+            Ok(mut middlewares) => {
+                let middleware_count = middlewares.len();
+
+                middlewares.retain(|existing| existing.get_middleware_name() != middleware_name);
+                middlewares.len() != middleware_count
+            }
+            Err(error) => {
+                log::error!("Failed to acquire memory middleware write lock: {}", error);
+                false
+            }
+        };
+
+        if removed {
+            self.invalidate();
+        }
+
+        removed
+    }
+
+    pub fn get_middleware_names(&self) -> Vec<String> {
+        match self.middlewares.read() {
+            Ok(middlewares) => middlewares
+                .iter()
+                .map(|middleware| middleware.get_middleware_name().to_string())
+                .collect(),
+            Err(error) => {
+                log::error!("Failed to acquire memory middleware read lock: {}", error);
+                vec![]
+            }
+        }
+    }
+
+    /// Discards cached address spaces, such that they are resolved again on next access.
+    pub fn invalidate(&self) {
+        match self.resolved_address_spaces.write() {
+            Ok(mut resolved_address_spaces) => *resolved_address_spaces = None,
+            Err(error) => {
+                log::error!("Failed to acquire resolved address spaces write lock: {}", error);
+            }
+        }
+    }
+
+    /// Gets the guest address spaces for the given process, resolving them with the unfiltered host providers if not already cached.
+    /// Returns `None` if no middleware is registered, in which case host memory is used directly. Middlewares that have not resolved
+    /// their address space contribute none, such that their guest memory is inaccessible rather than aliasing host memory.
+    pub fn get_address_spaces(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_query: &dyn MemoryQueryProvider,
+        memory_read: &dyn MemoryReadProvider,
+    ) -> Option<Arc<Vec<VirtualAddressSpace>>> {
+        let middlewares = match self.middlewares.read() {
+            Ok(middlewares) if middlewares.is_empty() => return None,
+            Ok(middlewares) => middlewares.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire memory middleware read lock: {}", error);
+                return Some(Arc::new(vec![]));
+            }
+        };
+
+        let cached_address_spaces = match self.resolved_address_spaces.read() {
+            Ok(resolved_address_spaces) => resolved_address_spaces
+                .as_ref()
+                .filter(|resolved_address_spaces| {
+                    resolved_address_spaces.process_id == process_info.get_process_id()
+                        && resolved_address_spaces.handle == process_info.get_handle()
+                        && (resolved_address_spaces.is_complete || resolved_address_spaces.resolved_at.elapsed() < RESOLVE_RETRY_INTERVAL)
+                })
+                .map(|resolved_address_spaces| resolved_address_spaces.address_spaces.clone()),
+            Err(error) => {
+                log::error!("Failed to acquire resolved address spaces read lock: {}", error);
+                None
+            }
+        };
+
+        if let Some(cached_address_spaces) = cached_address_spaces {
+            return Some(cached_address_spaces);
+        }
+
+        let resolved_address_spaces_by_middleware: Vec<Vec<VirtualAddressSpace>> = middlewares
+            .iter()
+            .map(|middleware| middleware.resolve_address_spaces(process_info, memory_query, memory_read))
+            .collect();
+        let is_complete = resolved_address_spaces_by_middleware
+            .iter()
+            .all(|address_spaces| !address_spaces.is_empty());
+        let address_spaces = Arc::new(
+            resolved_address_spaces_by_middleware
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>(),
+        );
+
+        match self.resolved_address_spaces.write() {
+            Ok(mut resolved_address_spaces) => {
+                *resolved_address_spaces = Some(ResolvedAddressSpaces {
+                    process_id: process_info.get_process_id(),
+                    handle: process_info.get_handle(),
+                    address_spaces: address_spaces.clone(),
+                    is_complete,
+                    resolved_at: Instant::now(),
+                });
+            }
+            Err(error) => {
+                log::error!("Failed to acquire resolved address spaces write lock: {}", error);
+            }
+        }
+
+        Some(address_spaces)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::os::engine_os_provider::{MemoryQueryProvider, MemoryReadProvider};
+use squalr_engine_api::structures::memory::virtual_address_space::VirtualAddressSpace;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// A filter that sits between the OS memory providers and the scanners, exposing guest address spaces (ie emulated console RAM)
+/// in place of raw host memory.
+pub trait MemoryMiddleware: Send + Sync {
+    /// Gets the unique name of this middleware.
+    fn get_middleware_name(&self) -> &str;
+
+    /// Locates the guest address spaces within the host process, using the unfiltered host providers.
+    fn resolve_address_spaces(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_query: &dyn MemoryQueryProvider,
+        memory_read: &dyn MemoryReadProvider,
+    ) -> Vec<VirtualAddressSpace>;
+}
@@ -0,0 +1,4 @@
+pub mod memory_middleware_providers;
+pub mod memory_middleware_registry;
+pub mod memory_middleware_trait;
+pub mod signature_memory_middleware;
@@ -0,0 +1,107 @@
+use crate::os::PageRetrievalMode;
+use crate::os::engine_os_provider::{MemoryQueryProvider, MemoryReadProvider};
+use crate::os::memory_middleware::memory_middleware_trait::MemoryMiddleware;
+use squalr_engine_api::structures::memory::memory_middleware_definition::MemoryMiddlewareDefinition;
+use squalr_engine_api::structures::memory::virtual_address_space::VirtualAddressSpace;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// A memory middleware that finds a guest memory block by scanning host memory for a byte signature.
+pub struct SignatureMemoryMiddleware {
+    definition: MemoryMiddlewareDefinition,
+    pattern: Vec<Option<u8>>,
+}
+
+impl SignatureMemoryMiddleware {
+    /// The number of bytes read from the host at a time while searching for the signature.
+    const SEARCH_CHUNK_SIZE: u64 = 16 * 1024 * 1024;
+
+    /// Creates a signature middleware, returning `None` if the definition signature is malformed.
+    pub fn new(definition: MemoryMiddlewareDefinition) -> Option<Self> {
+        let pattern = definition.parse_signature()?;
+
+        Some(Self { definition, pattern })
+    }
+
+    pub fn get_definition(&self) -> &MemoryMiddlewareDefinition {
+        &self.definition
+    }
+
+    fn find_pattern(
+        &self,
+        bytes: &[u8],
+    ) -> Option<usize> {
+        bytes.windows(self.pattern.len()).position(|window| {
+            window
+                .iter()
+                .zip(self.pattern.iter())
+                .all(|(byte, pattern_byte)| pattern_byte.is_none_or(|pattern_byte| pattern_byte == *byte))
+        })
+    }
+
+    fn find_signature_address(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_query: &dyn MemoryQueryProvider,
+        memory_read: &dyn MemoryReadProvider,
+    ) -> Option<u64> {
+        let pattern_length = self.pattern.len() as u64;
+
+        for region in memory_query.get_memory_page_bounds(process_info, PageRetrievalMode::FromSettings) {
+            let mut chunk_address = region.get_base_address();
+
+            while chunk_address < region.get_end_address() {
+                // Overlap chunks by the pattern length so that matches spanning a chunk boundary are not missed.
+                let chunk_size = (region.get_end_address() - chunk_address).min(Self::SEARCH_CHUNK_SIZE + pattern_length);
+                let mut chunk = vec![0u8; chunk_size as usize];
+
+                let match_offset = if chunk_size >= pattern_length && memory_read.read_bytes(process_info, chunk_address, &mut chunk) {
+                    self.find_pattern(&chunk)
+                } else {
+                    None
+                };
+
+                if let Some(match_offset) = match_offset {
+                    return Some(chunk_address + match_offset as u64);
+                }
+
+                chunk_address = chunk_address.saturating_add(Self::SEARCH_CHUNK_SIZE);
+            }
+        }
+
+        None
+    }
+}
+
+impl MemoryMiddleware for SignatureMemoryMiddleware {
+    fn get_middleware_name(&self) -> &str {
+        self.definition.get_middleware_name()
+    }
+
+    fn resolve_address_spaces(
+        &self,
+        process_info: &OpenedProcessInfo,
+        memory_query: &dyn MemoryQueryProvider,
+        memory_read: &dyn MemoryReadProvider,
+    ) -> Vec<VirtualAddressSpace> {
+        let signature_address = match self.find_signature_address(process_info, memory_query, memory_read) {
+            Some(signature_address) => signature_address,
+            None => {
+                log::warn!("Memory middleware `{}` did not find its signature.", self.get_middleware_name());
+                return vec![];
+            }
+        };
+
+        match signature_address.checked_add_signed(self.definition.get_signature_offset()) {
+            Some(host_base_address) => vec![VirtualAddressSpace::new(
+                self.definition.get_middleware_name().to_string(),
+                self.definition.get_module_name().to_string(),
+                self.definition.get_guest_base_address(),
+                host_base_address,
+                self.definition.get_region_size(),
+                self.definition.get_endian().clone(),
+                self.definition.get_guest_bitness(),
+            )],
+            None => vec![],
+        }
+    }
+}
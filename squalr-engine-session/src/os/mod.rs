@@ -1,4 +1,8 @@
 pub mod engine_os_provider;
+pub mod memory_map_watcher;
+pub mod memory_middleware;
+pub mod process_address_expression_context;
+pub mod process_auto_attacher;
 
 pub use squalr_engine_operating_system::config::memory_settings_config::MemorySettingsConfig;
 pub use squalr_engine_operating_system::memory_queryer::page_retrieval_mode::PageRetrievalMode;
@@ -0,0 +1,149 @@
+use crate::module_symbols::module_symbol_resolver::ModuleSymbolResolver;
+use crate::os::engine_os_provider::EngineOsProviders;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::memory::address_expression_context::AddressExpressionContext;
+use squalr_engine_api::structures::memory::address_expression_error::AddressExpressionError;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::endian::Endian;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Evaluates address expressions against an opened process, resolving names to module bases or `module!symbol` addresses,
+/// and reading pointers using the bitness and byte order of the memory holding them.
+pub struct ProcessAddressExpressionContext<'a> {
+    os_providers: &'a EngineOsProviders,
+    process_info: &'a OpenedProcessInfo,
+    modules: Vec<NormalizedModule>,
+}
+
+impl<'a> ProcessAddressExpressionContext<'a> {
+    pub fn new(
+        os_providers: &'a EngineOsProviders,
+        process_info: &'a OpenedProcessInfo,
+    ) -> Self {
+        let modules = os_providers.memory_query.get_modules(process_info);
+
+        Self::new_with_modules(os_providers, process_info, modules)
+    }
+
+    /// Creates a context over an already queried module list, avoiding a module query for callers that resolve repeatedly.
+    pub fn new_with_modules(
+        os_providers: &'a EngineOsProviders,
+        process_info: &'a OpenedProcessInfo,
+        modules: Vec<NormalizedModule>,
+    ) -> Self {
+        Self {
+            os_providers,
+            process_info,
+            modules,
+        }
+    }
+
+    /// Consumes the context, returning its module list such that callers may cache it between resolves.
+    pub fn into_modules(self) -> Vec<NormalizedModule> {
+        self.modules
+    }
+
+    /// Parses and evaluates an address expression string to an absolute address.
+    pub fn resolve(
+        &self,
+        address_expression: &str,
+    ) -> Result<u64, AddressExpressionError> {
+        AddressExpression::parse(address_expression)?.evaluate(self)
+    }
+
+    /// Gets the base address of the named module, if it is loaded.
+    pub fn resolve_module(
+        &self,
+        module_name: &str,
+    ) -> Option<u64> {
+        let module_address = self
+            .os_providers
+            .memory_query
+            .resolve_module(&self.modules, module_name);
+
+        (module_address != 0).then_some(module_address)
+    }
+
+    /// Gets the module containing the given address and the offset into that module, if any.
+    pub fn address_to_module(
+        &self,
+        address: u64,
+    ) -> Option<(String, u64)> {
+        self.os_providers
+            .memory_query
+            .address_to_module(address, &self.modules)
+    }
+
+    /// Gets a `module!symbol+offset` label for the given address, if a symbol of the containing module covers it.
+    pub fn address_to_symbol_label(
+        &self,
+        address: u64,
+    ) -> Option<String> {
+        ModuleSymbolResolver::format_symbol_label(self.os_providers, self.process_info, &self.modules, address)
+    }
+}
+
+impl AddressExpressionContext for ProcessAddressExpressionContext<'_> {
+    fn resolve_symbol(
+        &self,
+        name: &str,
+    ) -> Option<u64> {
+        let module_address = self
+            .os_providers
+            .memory_query
+            .resolve_module(&self.modules, name);
+
+        if module_address != 0 {
+            Some(module_address)
+        } else {
+            ModuleSymbolResolver::resolve_symbol_address(self.os_providers, self.process_info, &self.modules, name)
+        }
+    }
+
+    fn read_pointer(
+        &self,
+        address: u64,
+    ) -> Option<u64> {
+        let is_big_endian = self
+            .os_providers
+            .memory_read
+            .get_endian(self.process_info, address)
+            == Endian::Big;
+
+        match self
+            .os_providers
+            .memory_read
+            .get_bitness(self.process_info, address)
+        {
+            Bitness::Bit32 => {
+                let mut pointer_bytes = [0u8; 4];
+
+                self.os_providers
+                    .memory_read
+                    .read_bytes(self.process_info, address, &mut pointer_bytes)
+                    .then(|| {
+                        if is_big_endian {
+                            u32::from_be_bytes(pointer_bytes) as u64
+                        } else {
+                            u32::from_le_bytes(pointer_bytes) as u64
+                        }
+                    })
+            }
+            Bitness::Bit64 => {
+                let mut pointer_bytes = [0u8; 8];
+
+                self.os_providers
+                    .memory_read
+                    .read_bytes(self.process_info, address, &mut pointer_bytes)
+                    .then(|| {
+                        if is_big_endian {
+                            u64::from_be_bytes(pointer_bytes)
+                        } else {
+                            u64::from_le_bytes(pointer_bytes)
+                        }
+                    })
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::os::engine_os_provider::EngineOsProviders;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_operating_system::process::process_manager::ProcessManager;
+use squalr_engine_operating_system::process_query::process_query_options::ProcessQueryOptions;
+use std::path::Path;
+use std::sync::{Mutex, RwLock};
+
+/// The executable that the engine should attach to whenever it is running and no other process is opened.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessAutoAttachTarget {
+    process_name: String,
+    match_case: bool,
+}
+
+impl ProcessAutoAttachTarget {
+    pub fn new(
+        process_name: String,
+        match_case: bool,
+    ) -> Self {
+        Self { process_name, match_case }
+    }
+
+    pub fn get_process_name(&self) -> &str {
+        &self.process_name
+    }
+
+    pub fn get_match_case(&self) -> bool {
+        self.match_case
+    }
+
+    /// Determines whether a process name refers to this target, either exactly or by its name without an extension.
+    pub fn matches(
+        &self,
+        process_name: &str,
+    ) -> bool {
+        let process_stem = Path::new(process_name)
+            .file_stem()
+            .and_then(|process_stem| process_stem.to_str())
+            .unwrap_or(process_name);
+
+        [process_name, process_stem].iter().any(|candidate_name| {
+            if self.match_case {
+                *candidate_name == self.process_name
+            } else {
+                candidate_name.eq_ignore_ascii_case(&self.process_name)
+            }
+        })
+    }
+}
+
+/// Attaches to a named target process when it launches, and re-attaches after it exits or crashes and is restarted.
+pub struct ProcessAutoAttacher {
+    target: RwLock<Option<ProcessAutoAttachTarget>>,
+    /// The last process that failed to open, such that a process we lack access to is not retried on every poll.
+    failed_process_id: Mutex<Option<u32>>,
+}
+
+impl ProcessAutoAttacher {
+    pub fn new() -> Self {
+        Self {
+            target: RwLock::new(None),
+            failed_process_id: Mutex::new(None),
+        }
+    }
+
+    /// Sets the process to automatically attach to, or disables auto-attach if no target is provided.
+    pub fn set_target(
+        &self,
+        target: Option<ProcessAutoAttachTarget>,
+    ) {
+        match self.target.write() {
+            Ok(mut target_guard) => {
+                match &target {
+                    Some(target) => log::info!("Auto-attach enabled for process: {}", target.get_process_name()),
+                    None if target_guard.is_some() => log::info!("Auto-attach disabled."),
+                    None => {}
+                }
+
+                *target_guard = target;
+            }
+            Err(error) => log::error!("Failed to acquire auto-attach target write lock: {}", error),
+        }
+
+        if let Ok(mut failed_process_id) = self.failed_process_id.lock() {
+            *failed_process_id = None;
+        }
+    }
+
+    /// Gets the process that will be automatically attached to, if any.
+    pub fn get_target(&self) -> Option<ProcessAutoAttachTarget> {
+        match self.target.read() {
+            Ok(target_guard) => target_guard.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire auto-attach target read lock: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Attaches to the target process if no process is currently opened and a matching process is running.
+    /// Returns the newly opened process, if an attach occurred.
+    pub fn try_attach(
+        &self,
+        os_providers: &EngineOsProviders,
+        process_manager: &ProcessManager,
+    ) -> Option<OpenedProcessInfo> {
+        let target = self.get_target()?;
+
+        if process_manager.get_opened_process().is_some() {
+            return None;
+        }
+
+        // Hold the failed process lock for the entire attach, such that concurrent polls cannot attach twice.
+        let mut failed_process_id = match self.failed_process_id.lock() {
+            Ok(failed_process_id) => failed_process_id,
+            Err(error) => {
+                log::error!("Failed to acquire auto-attach lock: {}", error);
+                return None;
+            }
+        };
+
+        if process_manager.get_opened_process().is_some() {
+            return None;
+        }
+
+        let processes = os_providers.process_query.get_processes(ProcessQueryOptions {
+            search_name: Some(target.get_process_name().to_string()),
+            required_process_id: None,
+            require_windowed: false,
+            match_case: target.get_match_case(),
+            fetch_icons: false,
+            limit: None,
+        });
+        let process_info = processes
+            .iter()
+            .find(|process_info| target.matches(process_info.get_name()))?;
+
+        if *failed_process_id == Some(process_info.get_process_id_raw()) {
+            return None;
+        }
+
+        match os_providers.process_query.open_process(process_info) {
+            Ok(opened_process_info) => {
+                *failed_process_id = None;
+                log::info!("Auto-attaching to process: {}", process_info.get_name());
+                process_manager.set_opened_process(opened_process_info.clone());
+
+                Some(opened_process_info)
+            }
+            Err(error) => {
+                *failed_process_id = Some(process_info.get_process_id_raw());
+                log::error!("Failed to auto-attach to process {}: {}", process_info.get_process_id_raw(), error);
+
+                None
+            }
+        }
+    }
+}
+
+impl Default for ProcessAutoAttacher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProcessAutoAttachTarget;
+
+    #[test]
+    fn target_matches_exact_name_and_name_without_extension() {
+        let target = ProcessAutoAttachTarget::new("game".to_string(), false);
+
+        assert!(target.matches("game"));
+        assert!(target.matches("Game.exe"));
+        assert!(!target.matches("game-launcher.exe"));
+    }
+
+    #[test]
+    fn target_respects_match_case() {
+        let target = ProcessAutoAttachTarget::new("Game.exe".to_string(), true);
+
+        assert!(target.matches("Game.exe"));
+        assert!(!target.matches("game.exe"));
+    }
+}
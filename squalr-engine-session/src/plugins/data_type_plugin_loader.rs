@@ -0,0 +1,198 @@
+use libloading::{Library, Symbol};
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type::DataType;
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin::DataTypePlugin;
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_abi::{
+    DATA_TYPE_PLUGIN_ABI_VERSION, DATA_TYPE_PLUGIN_ENTRY_SYMBOL, DataTypePluginEntryFn, DataTypePluginManifest,
+};
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_error::DataTypePluginError;
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_metadata::DataTypePluginMetadata;
+use std::any::Any;
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// Loads data type plugin libraries and registers the data types they export into symbol registries.
+/// Plugin libraries are never unloaded, since registered data types may still be referenced by snapshots and project items.
+pub struct DataTypePluginLoader {
+    loaded_data_types: RwLock<Vec<DataTypePluginMetadata>>,
+}
+
+impl DataTypePluginLoader {
+    pub fn new() -> Self {
+        Self {
+            loaded_data_types: RwLock::new(vec![]),
+        }
+    }
+
+    /// Gets the metadata for every data type registered by a plugin through this loader.
+    pub fn get_loaded_data_types(&self) -> Vec<DataTypePluginMetadata> {
+        match self.loaded_data_types.read() {
+            Ok(loaded_data_types) => loaded_data_types.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire loaded data type plugins read lock: {}", error);
+                vec![]
+            }
+        }
+    }
+
+    /// Loads the plugin library at the given path, registering each of its data types into every provided registry.
+    /// Registration is all or nothing: if any data type fails to register, all data types from this plugin are unregistered.
+    pub fn load_plugin(
+        &self,
+        plugin_path: &Path,
+        symbol_registries: &[&SymbolRegistry],
+    ) -> Result<Vec<DataTypePluginMetadata>, DataTypePluginError> {
+        let plugin_path_string = plugin_path.to_string_lossy().to_string();
+        let mut loaded_data_types = self
+            .loaded_data_types
+            .write()
+            .map_err(|error| DataTypePluginError::LibraryLoadFailed {
+                plugin_path: plugin_path_string.clone(),
+                error: error.to_string(),
+            })?;
+
+        if loaded_data_types
+            .iter()
+            .any(|metadata| metadata.get_plugin_path() == plugin_path)
+        {
+            return Err(DataTypePluginError::AlreadyLoaded {
+                plugin_path: plugin_path_string,
+            });
+        }
+
+        let (plugin_name, data_types) = Self::read_plugin_library(plugin_path)?;
+        let data_type_metadata = Self::register_data_types(plugin_path, &plugin_name, &data_types, symbol_registries)?;
+
+        log::info!(
+            "Loaded data type plugin `{}` from {} with {} data type(s).",
+            plugin_name,
+            plugin_path_string,
+            data_type_metadata.len()
+        );
+
+        loaded_data_types.extend(data_type_metadata.iter().cloned());
+
+        Ok(data_type_metadata)
+    }
+
+    /// Opens a plugin library and adapts each exported data type, without registering them.
+    pub fn read_plugin_library(plugin_path: &Path) -> Result<(String, Vec<Arc<DataTypePlugin>>), DataTypePluginError> {
+        let plugin_path_string = plugin_path.to_string_lossy().to_string();
+        let library = unsafe { Library::new(plugin_path) }.map_err(|error| DataTypePluginError::LibraryLoadFailed {
+            plugin_path: plugin_path_string.clone(),
+            error: error.to_string(),
+        })?;
+        let manifest = unsafe {
+            let entry_point: Symbol<DataTypePluginEntryFn> =
+                library
+                    .get(DATA_TYPE_PLUGIN_ENTRY_SYMBOL)
+                    .map_err(|error| DataTypePluginError::EntryPointMissing {
+                        plugin_path: plugin_path_string.clone(),
+                        error: error.to_string(),
+                    })?;
+
+            entry_point()
+        };
+        let library_handle: Arc<dyn Any + Send + Sync> = Arc::new(library);
+
+        unsafe { Self::read_plugin_manifest(&plugin_path_string, manifest, Some(library_handle)) }
+    }
+
+    /// Validates a plugin manifest and adapts each of its descriptors into a data type.
+    ///
+    /// # Safety
+    /// The manifest must be null or point to a valid manifest whose descriptors remain valid for as long as `library_handle` is alive.
+    pub unsafe fn read_plugin_manifest(
+        plugin_path: &str,
+        manifest: *const DataTypePluginManifest,
+        library_handle: Option<Arc<dyn Any + Send + Sync>>,
+    ) -> Result<(String, Vec<Arc<DataTypePlugin>>), DataTypePluginError> {
+        let manifest = match unsafe { manifest.as_ref() } {
+            Some(manifest) => manifest,
+            None => {
+                return Err(DataTypePluginError::NullManifest {
+                    plugin_path: plugin_path.to_string(),
+                });
+            }
+        };
+
+        if manifest.abi_version != DATA_TYPE_PLUGIN_ABI_VERSION {
+            return Err(DataTypePluginError::AbiVersionMismatch {
+                plugin_path: plugin_path.to_string(),
+                expected_abi_version: DATA_TYPE_PLUGIN_ABI_VERSION,
+                actual_abi_version: manifest.abi_version,
+            });
+        }
+
+        let plugin_name = if manifest.plugin_name.is_null() {
+            plugin_path.to_string()
+        } else {
+            unsafe { CStr::from_ptr(manifest.plugin_name) }
+                .to_string_lossy()
+                .to_string()
+        };
+        let descriptors = if manifest.data_type_count == 0 || manifest.data_types.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(manifest.data_types, manifest.data_type_count) }
+        };
+        let mut data_types = Vec::with_capacity(descriptors.len());
+
+        for (descriptor_index, descriptor) in descriptors.iter().enumerate() {
+            let data_type = unsafe { DataTypePlugin::from_descriptor(plugin_path, descriptor_index, descriptor, library_handle.clone()) }?;
+
+            data_types.push(Arc::new(data_type));
+        }
+
+        Ok((plugin_name, data_types))
+    }
+
+    fn register_data_types(
+        plugin_path: &Path,
+        plugin_name: &str,
+        data_types: &[Arc<DataTypePlugin>],
+        symbol_registries: &[&SymbolRegistry],
+    ) -> Result<Vec<DataTypePluginMetadata>, DataTypePluginError> {
+        let mut registered_data_types: Vec<(&SymbolRegistry, &str)> = vec![];
+
+        for symbol_registry in symbol_registries {
+            for data_type in data_types {
+                if let Err(error) = symbol_registry.register_data_type(data_type.clone()) {
+                    for (registered_symbol_registry, registered_data_type_id) in registered_data_types {
+                        if let Err(error) = registered_symbol_registry.unregister_data_type(registered_data_type_id) {
+                            log::error!("Failed to roll back data type plugin registration: {}", error);
+                        }
+                    }
+
+                    return Err(DataTypePluginError::RegistrationFailed {
+                        plugin_path: plugin_path.to_string_lossy().to_string(),
+                        source: error,
+                    });
+                }
+
+                registered_data_types.push((symbol_registry, data_type.get_data_type_id()));
+            }
+        }
+
+        Ok(data_types
+            .iter()
+            .map(|data_type| {
+                DataTypePluginMetadata::new(
+                    plugin_name.to_string(),
+                    PathBuf::from(plugin_path),
+                    data_type.get_data_type_id().to_string(),
+                    data_type.get_icon_id().to_string(),
+                    data_type.get_display_name().to_string(),
+                    data_type.get_unit_size_in_bytes(),
+                )
+            })
+            .collect())
+    }
+}
+
+impl Default for DataTypePluginLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
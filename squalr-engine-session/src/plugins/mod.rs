@@ -0,0 +1 @@
+pub mod data_type_plugin_loader;
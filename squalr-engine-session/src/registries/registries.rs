@@ -1,14 +1,19 @@
+use squalr_engine_api::registries::code_patches::code_patch_registry::CodePatchRegistry;
 use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
 use squalr_engine_api::registries::project_item_types::project_item_type_registry::ProjectItemTypeRegistry;
 use squalr_engine_api::registries::registry_context::RegistryContext;
 use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::registries::value_timelines::value_timeline_registry::ValueTimelineRegistry;
 use std::sync::{Arc, RwLock};
 
 pub struct Registries {
     // The list of frozen scan results.
     freeze_list_registry: Arc<RwLock<FreezeListRegistry>>,
 
+    /// The registry of code patches written to the opened process.
+    code_patch_registry: Arc<RwLock<CodePatchRegistry>>,
+
     /// The registry for project item types.
     project_item_type_registry: Arc<RwLock<ProjectItemTypeRegistry>>,
 
@@ -17,20 +22,27 @@ pub struct Registries {
 
     /// The registry for symbolic struct definitions.
     symbol_registry: Arc<RwLock<SymbolRegistry>>,
+
+    /// The registry of value timelines being recorded.
+    value_timeline_registry: Arc<RwLock<ValueTimelineRegistry>>,
 }
 
 impl Registries {
     pub fn new() -> Self {
         let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let code_patch_registry = Arc::new(RwLock::new(CodePatchRegistry::new()));
         let project_item_type_registry = Arc::new(RwLock::new(ProjectItemTypeRegistry::new()));
         let element_scan_rule_registry = Arc::new(RwLock::new(ElementScanRuleRegistry::new()));
         let symbol_registry = Arc::new(RwLock::new(SymbolRegistry::new()));
+        let value_timeline_registry = Arc::new(RwLock::new(ValueTimelineRegistry::new()));
 
         Self {
             freeze_list_registry,
+            code_patch_registry,
             project_item_type_registry,
             element_scan_rule_registry,
             symbol_registry,
+            value_timeline_registry,
         }
     }
 }
@@ -41,6 +53,11 @@ impl RegistryContext for Registries {
         self.freeze_list_registry.clone()
     }
 
+    /// Gets the registry of code patches written to the opened process.
+    fn get_code_patch_registry(&self) -> Arc<RwLock<CodePatchRegistry>> {
+        self.code_patch_registry.clone()
+    }
+
     /// Gets the registry for project item types.
     fn get_project_item_type_registry(&self) -> Arc<RwLock<ProjectItemTypeRegistry>> {
         self.project_item_type_registry.clone()
@@ -55,4 +72,9 @@ impl RegistryContext for Registries {
     fn get_symbol_registry(&self) -> Arc<RwLock<SymbolRegistry>> {
         self.symbol_registry.clone()
     }
+
+    /// Gets the registry of value timelines being recorded.
+    fn get_value_timeline_registry(&self) -> Arc<RwLock<ValueTimelineRegistry>> {
+        self.value_timeline_registry.clone()
+    }
 }
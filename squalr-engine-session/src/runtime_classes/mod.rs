@@ -0,0 +1,3 @@
+pub mod runtime_class_cache;
+pub mod runtime_class_resolver;
+pub mod runtime_class_scanner;
@@ -0,0 +1,131 @@
+use crate::module_symbols::module_file_parser::ModuleFileParser;
+use crate::os::engine_os_provider::{MemoryReadProvider, RuntimeClassProvider};
+use crate::runtime_classes::runtime_class_scanner::RuntimeClassScanner;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct RuntimeClassCacheKey {
+    process_id: u32,
+    module_name: String,
+    base_address: u64,
+    region_size: u64,
+}
+
+/// Discovers the classes of a module on first use and caches them per loaded module instance. Vtable lookups only consider
+/// modules that have already been discovered, since scanning every loaded module is too slow to do implicitly.
+pub struct RuntimeClassCache {
+    memory_read: Arc<dyn MemoryReadProvider>,
+    runtime_classes: RwLock<HashMap<RuntimeClassCacheKey, Arc<Vec<RuntimeClass>>>>,
+}
+
+impl RuntimeClassCache {
+    pub fn new(memory_read: Arc<dyn MemoryReadProvider>) -> Self {
+        Self {
+            memory_read,
+            runtime_classes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Gets the pointer size of a process in bytes.
+    pub fn get_pointer_size(process_info: &OpenedProcessInfo) -> u64 {
+        match process_info.get_bitness() {
+            Bitness::Bit32 => 4,
+            Bitness::Bit64 => 8,
+        }
+    }
+
+    /// Gets the address ranges of the segments of the module file, since some platforms only report the executable segment
+    /// as the module, whereas vtables reside in data segments. Returns no ranges if the module file cannot be parsed.
+    fn get_image_ranges(module: &NormalizedModule) -> Vec<Range<u64>> {
+        let module_path = module.get_module_path();
+
+        if module_path.is_empty() {
+            return Vec::new();
+        }
+
+        fs::read(module_path)
+            .ok()
+            .and_then(|file_bytes| ModuleFileParser::parse_image_ranges(&file_bytes, module.get_base_address()).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl RuntimeClassProvider for RuntimeClassCache {
+    fn get_runtime_classes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+        modules: &[NormalizedModule],
+    ) -> Arc<Vec<RuntimeClass>> {
+        let cache_key = RuntimeClassCacheKey {
+            process_id: process_info.get_process_id_raw(),
+            module_name: module.get_module_name().to_string(),
+            base_address: module.get_base_address(),
+            region_size: module.get_region_size(),
+        };
+
+        if let Some(runtime_classes) = self
+            .runtime_classes
+            .read()
+            .ok()
+            .and_then(|runtime_classes| runtime_classes.get(&cache_key).cloned())
+        {
+            return runtime_classes;
+        }
+
+        let module_range = module.get_base_address()
+            ..module
+                .get_base_address()
+                .saturating_add(module.get_region_size());
+        let image_ranges = Self::get_image_ranges(module);
+        let scan_ranges = if image_ranges.is_empty() {
+            std::slice::from_ref(&module_range)
+        } else {
+            image_ranges.as_slice()
+        };
+        let runtime_classes = Arc::new(RuntimeClassScanner::discover_classes(
+            module,
+            scan_ranges,
+            modules,
+            Self::get_pointer_size(process_info),
+            &|address, bytes| self.memory_read.read_bytes(process_info, address, bytes),
+        ));
+
+        log::debug!("Discovered {} classes in module '{}'.", runtime_classes.len(), module.get_module_name());
+
+        match self.runtime_classes.write() {
+            Ok(mut cached_runtime_classes) => {
+                cached_runtime_classes.insert(cache_key, runtime_classes.clone());
+            }
+            Err(error) => log::error!("Failed to acquire runtime class cache write lock: {}", error),
+        }
+
+        runtime_classes
+    }
+
+    fn find_runtime_class_by_vtable(
+        &self,
+        process_info: &OpenedProcessInfo,
+        vtable_address: u64,
+    ) -> Option<RuntimeClass> {
+        let process_id = process_info.get_process_id_raw();
+        let runtime_classes = self.runtime_classes.read().ok()?;
+
+        runtime_classes
+            .iter()
+            .filter(|(cache_key, _)| cache_key.process_id == process_id)
+            .find_map(|(_, runtime_classes)| {
+                runtime_classes
+                    .binary_search_by_key(&vtable_address, RuntimeClass::get_vtable_address)
+                    .ok()
+                    .map(|runtime_class_index| runtime_classes[runtime_class_index].clone())
+            })
+    }
+}
@@ -0,0 +1,58 @@
+use crate::os::engine_os_provider::EngineOsProviders;
+use crate::runtime_classes::runtime_class_cache::RuntimeClassCache;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
+use std::sync::Arc;
+
+/// Finds the classes of loaded modules, and labels objects by the class of the vtable that they point to.
+pub struct RuntimeClassResolver;
+
+impl RuntimeClassResolver {
+    /// Discovers the classes of a loaded module by name, or returns `None` if no such module is loaded.
+    pub fn get_module_runtime_classes(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        module_name: &str,
+    ) -> Option<Arc<Vec<RuntimeClass>>> {
+        let modules = os_providers.memory_query.get_modules(process_info);
+        let module = modules.iter().find(|module| {
+            module
+                .get_module_name()
+                .trim()
+                .eq_ignore_ascii_case(module_name.trim())
+        })?;
+
+        Some(
+            os_providers
+                .runtime_classes
+                .get_runtime_classes(process_info, module, &modules),
+        )
+    }
+
+    /// Gets the class of the object at an address, if its first pointer is the vtable of an already discovered class.
+    pub fn find_object_class(
+        os_providers: &EngineOsProviders,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+    ) -> Option<RuntimeClass> {
+        let pointer_size = RuntimeClassCache::get_pointer_size(process_info) as usize;
+        let mut vtable_address_bytes = [0u8; 8];
+
+        if !os_providers
+            .memory_read
+            .read_bytes(process_info, address, &mut vtable_address_bytes[..pointer_size])
+        {
+            return None;
+        }
+
+        let vtable_address = u64::from_le_bytes(vtable_address_bytes);
+
+        if vtable_address == 0 {
+            return None;
+        }
+
+        os_providers
+            .runtime_classes
+            .find_runtime_class_by_vtable(process_info, vtable_address)
+    }
+}
@@ -0,0 +1,374 @@
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
+use squalr_engine_api::structures::runtime_classes::runtime_class_abi::RuntimeClassAbi;
+use std::ops::Range;
+
+/// Discovers C++ classes by scanning module memory for vtables that are preceded by Itanium or MSVC run-time type information.
+pub struct RuntimeClassScanner;
+
+impl RuntimeClassScanner {
+    const PAGE_SIZE: u64 = 0x1000;
+    const MAX_TYPE_NAME_LENGTH: usize = 256;
+    const MSVC_COMPLETE_OBJECT_LOCATOR_SIZE: usize = 24;
+    const MSVC_SIGNATURE_X86: u32 = 0;
+    const MSVC_SIGNATURE_X64: u32 = 1;
+
+    /// Scans the given address ranges of a module for primary vtables, producing the classes that they belong to, sorted by
+    /// vtable address. Virtual function pointers must point into one of the loaded modules for a vtable to be accepted.
+    pub fn discover_classes(
+        module: &NormalizedModule,
+        scan_ranges: &[Range<u64>],
+        modules: &[NormalizedModule],
+        pointer_size: u64,
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+    ) -> Vec<RuntimeClass> {
+        let mut runtime_classes = Vec::new();
+
+        for scan_range in scan_ranges {
+            let mut page_address = scan_range.start & !(Self::PAGE_SIZE - 1);
+
+            while page_address < scan_range.end {
+                // Each page is read along with the neighbouring pointers needed to validate vtables at its edges.
+                let mut window_address = page_address.saturating_sub(pointer_size);
+                let mut window = vec![0u8; (Self::PAGE_SIZE + pointer_size * 3) as usize];
+
+                if !read_bytes(window_address, &mut window) {
+                    window_address = page_address;
+                    window = vec![0u8; Self::PAGE_SIZE as usize];
+
+                    if !read_bytes(window_address, &mut window) {
+                        page_address = page_address.saturating_add(Self::PAGE_SIZE);
+                        continue;
+                    }
+                }
+
+                let mut slot_address = page_address;
+
+                while slot_address < page_address + Self::PAGE_SIZE {
+                    if let Some(runtime_class) =
+                        Self::try_read_class_at_slot(module, scan_ranges, modules, pointer_size, read_bytes, &window, window_address, slot_address)
+                    {
+                        runtime_classes.push(runtime_class);
+                    }
+
+                    slot_address += pointer_size;
+                }
+
+                page_address = page_address.saturating_add(Self::PAGE_SIZE);
+            }
+        }
+
+        runtime_classes.sort_by_key(|runtime_class| runtime_class.get_vtable_address());
+        runtime_classes.dedup_by_key(|runtime_class| runtime_class.get_vtable_address());
+
+        runtime_classes
+    }
+
+    /// Checks whether the pointer at a slot is the run-time type information pointer that immediately precedes a vtable.
+    #[allow(clippy::too_many_arguments)]
+    fn try_read_class_at_slot(
+        module: &NormalizedModule,
+        scan_ranges: &[Range<u64>],
+        modules: &[NormalizedModule],
+        pointer_size: u64,
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        window: &[u8],
+        window_address: u64,
+        slot_address: u64,
+    ) -> Option<RuntimeClass> {
+        let slot_index = slot_address.checked_sub(window_address)? as usize;
+        let type_info_address = Self::read_pointer(window, slot_index, pointer_size)?;
+        let vtable_address = slot_address + pointer_size;
+        let first_virtual_function_address = Self::read_pointer(window, slot_index + pointer_size as usize, pointer_size)?;
+
+        if type_info_address == 0
+            || !Self::is_in_ranges(scan_ranges, type_info_address)
+            || !modules
+                .iter()
+                .any(|module| module.contains_address(first_virtual_function_address))
+        {
+            return None;
+        }
+
+        let (class_name, abi) = match Self::read_msvc_class_name(module, pointer_size, read_bytes, type_info_address) {
+            Some(class_name) => (class_name, RuntimeClassAbi::Msvc),
+            None => {
+                // Only primary vtables are kept, which are the ones with an offset-to-top of zero.
+                let offset_to_top = Self::read_pointer(window, slot_index.checked_sub(pointer_size as usize)?, pointer_size)?;
+
+                if offset_to_top != 0 {
+                    return None;
+                }
+
+                (
+                    Self::read_itanium_class_name(scan_ranges, pointer_size, read_bytes, type_info_address)?,
+                    RuntimeClassAbi::Itanium,
+                )
+            }
+        };
+
+        Some(RuntimeClass::new(class_name, module.get_module_name().to_string(), vtable_address, abi))
+    }
+
+    /// Reads the class name from an Itanium `type_info`, which holds its own vtable pointer followed by a pointer to its name.
+    fn read_itanium_class_name(
+        scan_ranges: &[Range<u64>],
+        pointer_size: u64,
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        type_info_address: u64,
+    ) -> Option<String> {
+        if !type_info_address.is_multiple_of(pointer_size) {
+            return None;
+        }
+
+        let mut type_info_bytes = vec![0u8; (pointer_size * 2) as usize];
+
+        if !read_bytes(type_info_address, &mut type_info_bytes) {
+            return None;
+        }
+
+        let type_info_vtable_address = Self::read_pointer(&type_info_bytes, 0, pointer_size)?;
+        let name_address = Self::read_pointer(&type_info_bytes, pointer_size as usize, pointer_size)?;
+
+        if type_info_vtable_address == 0 || !Self::is_in_ranges(scan_ranges, name_address) {
+            return None;
+        }
+
+        // Names with internal linkage are prefixed with an asterisk by GCC.
+        let mangled_name = Self::read_c_string(read_bytes, name_address)?;
+        let mangled_name = mangled_name.strip_prefix('*').unwrap_or(&mangled_name);
+
+        match RuntimeClassAbi::demangle_itanium_type_name(mangled_name) {
+            Some(class_name) => Some(class_name),
+            None if Self::is_plausible_itanium_name(mangled_name) => Some(mangled_name.to_string()),
+            None => None,
+        }
+    }
+
+    /// Reads the class name from an MSVC `RTTICompleteObjectLocator`, which refers to the `TypeDescriptor` holding the
+    /// decorated class name. 64-bit locators refer to other structures by image-relative offsets, and 32-bit locators by address.
+    fn read_msvc_class_name(
+        module: &NormalizedModule,
+        pointer_size: u64,
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        complete_object_locator_address: u64,
+    ) -> Option<String> {
+        if !complete_object_locator_address.is_multiple_of(4) || !module.contains_address(complete_object_locator_address) {
+            return None;
+        }
+
+        let mut locator_bytes = [0u8; Self::MSVC_COMPLETE_OBJECT_LOCATOR_SIZE];
+
+        if !read_bytes(complete_object_locator_address, &mut locator_bytes) {
+            return None;
+        }
+
+        let signature = Self::read_u32(&locator_bytes, 0)?;
+        let vtable_offset = Self::read_u32(&locator_bytes, 4)?;
+        let type_descriptor_reference = Self::read_u32(&locator_bytes, 12)? as u64;
+
+        // Only primary vftables are kept, which are the ones at offset zero within the complete object.
+        if vtable_offset != 0 {
+            return None;
+        }
+
+        let type_descriptor_address = match (pointer_size, signature) {
+            (8, Self::MSVC_SIGNATURE_X64) => {
+                let self_offset = Self::read_u32(&locator_bytes, 20)? as u64;
+
+                if complete_object_locator_address.checked_sub(self_offset)? != module.get_base_address() {
+                    return None;
+                }
+
+                module
+                    .get_base_address()
+                    .checked_add(type_descriptor_reference)?
+            }
+            (4, Self::MSVC_SIGNATURE_X86) => type_descriptor_reference,
+            _ => return None,
+        };
+
+        if !module.contains_address(type_descriptor_address) {
+            return None;
+        }
+
+        let decorated_name = Self::read_c_string(read_bytes, type_descriptor_address + pointer_size * 2)?;
+
+        if !decorated_name.starts_with(".?AV") && !decorated_name.starts_with(".?AU") {
+            return None;
+        }
+
+        Some(RuntimeClassAbi::demangle_msvc_type_name(&decorated_name).unwrap_or(decorated_name))
+    }
+
+    /// Reads a NUL-terminated ASCII string, without reading past the page that contains its terminator.
+    fn read_c_string(
+        read_bytes: &dyn Fn(u64, &mut [u8]) -> bool,
+        address: u64,
+    ) -> Option<String> {
+        let mut string_bytes = Vec::new();
+        let mut chunk_address = address;
+
+        while string_bytes.len() < Self::MAX_TYPE_NAME_LENGTH {
+            let bytes_to_page_end = (Self::PAGE_SIZE - (chunk_address % Self::PAGE_SIZE)) as usize;
+            let mut chunk = vec![0u8; bytes_to_page_end.min(Self::MAX_TYPE_NAME_LENGTH - string_bytes.len())];
+
+            if !read_bytes(chunk_address, &mut chunk) {
+                return None;
+            }
+
+            match chunk.iter().position(|byte| *byte == 0) {
+                Some(terminator_index) => {
+                    string_bytes.extend_from_slice(&chunk[..terminator_index]);
+
+                    if string_bytes.is_empty() || !string_bytes.iter().all(|byte| byte.is_ascii_graphic()) {
+                        return None;
+                    }
+
+                    return String::from_utf8(string_bytes).ok();
+                }
+                None => {
+                    chunk_address += chunk.len() as u64;
+                    string_bytes.extend_from_slice(&chunk);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Checks whether a name looks like an Itanium mangled type name that the demangler does not support, such as a template.
+    fn is_plausible_itanium_name(mangled_name: &str) -> bool {
+        (mangled_name.starts_with('N') || mangled_name.starts_with(|character: char| character.is_ascii_digit()))
+            && mangled_name
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+    }
+
+    fn is_in_ranges(
+        ranges: &[Range<u64>],
+        address: u64,
+    ) -> bool {
+        ranges.iter().any(|range| range.contains(&address))
+    }
+
+    fn read_pointer(
+        bytes: &[u8],
+        offset: usize,
+        pointer_size: u64,
+    ) -> Option<u64> {
+        let pointer_bytes = bytes.get(offset..offset.checked_add(pointer_size as usize)?)?;
+
+        match pointer_size {
+            4 => Some(u32::from_le_bytes(pointer_bytes.try_into().ok()?) as u64),
+            8 => Some(u64::from_le_bytes(pointer_bytes.try_into().ok()?)),
+            _ => None,
+        }
+    }
+
+    fn read_u32(
+        bytes: &[u8],
+        offset: usize,
+    ) -> Option<u32> {
+        Self::read_pointer(bytes, offset, 4).map(|value| value as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuntimeClassScanner;
+    use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+    use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
+    use squalr_engine_api::structures::runtime_classes::runtime_class_abi::RuntimeClassAbi;
+
+    const MODULE_BASE: u64 = 0x10000;
+
+    fn discover_classes(image: &[u8]) -> Vec<RuntimeClass> {
+        let module = NormalizedModule::new("game", MODULE_BASE, image.len() as u64);
+        let read_bytes = |address: u64, bytes: &mut [u8]| {
+            let Some(offset) = address.checked_sub(MODULE_BASE).map(|offset| offset as usize) else {
+                return false;
+            };
+
+            match image.get(offset..offset + bytes.len()) {
+                Some(image_bytes) => {
+                    bytes.copy_from_slice(image_bytes);
+                    true
+                }
+                None => false,
+            }
+        };
+
+        let scan_range = MODULE_BASE..MODULE_BASE + image.len() as u64;
+
+        RuntimeClassScanner::discover_classes(&module, std::slice::from_ref(&scan_range), std::slice::from_ref(&module), 8, &read_bytes)
+    }
+
+    fn write_u64(
+        image: &mut [u8],
+        offset: usize,
+        value: u64,
+    ) {
+        image[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn write_u32(
+        image: &mut [u8],
+        offset: usize,
+        value: u32,
+    ) {
+        image[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn discover_classes_reads_itanium_type_info_names() {
+        let mut image = vec![0u8; 0x3000];
+
+        // Vtable for game::Player: offset-to-top, type_info pointer, then a virtual function pointer.
+        write_u64(&mut image, 0x2000, 0);
+        write_u64(&mut image, 0x2008, MODULE_BASE + 0x2100);
+        write_u64(&mut image, 0x2010, MODULE_BASE + 0x1000);
+        // A secondary vtable with a non-zero offset-to-top is ignored.
+        write_u64(&mut image, 0x2040, (-16i64) as u64);
+        write_u64(&mut image, 0x2048, MODULE_BASE + 0x2100);
+        write_u64(&mut image, 0x2050, MODULE_BASE + 0x1000);
+        // The type_info holds its own vtable pointer, followed by a pointer to the mangled name.
+        write_u64(&mut image, 0x2100, 0x7FFF_0000_1000);
+        write_u64(&mut image, 0x2108, MODULE_BASE + 0x2200);
+        image[0x2200..0x2213].copy_from_slice(b"N4game6PlayerE\0\0\0\0\0");
+
+        assert_eq!(
+            discover_classes(&image),
+            vec![RuntimeClass::new(
+                "game::Player".to_string(),
+                "game".to_string(),
+                MODULE_BASE + 0x2010,
+                RuntimeClassAbi::Itanium
+            )]
+        );
+    }
+
+    #[test]
+    fn discover_classes_reads_msvc_complete_object_locators() {
+        let mut image = vec![0u8; 0x3000];
+
+        // The vftable is preceded by a pointer to its complete object locator.
+        write_u64(&mut image, 0x2008, MODULE_BASE + 0x2100);
+        write_u64(&mut image, 0x2010, MODULE_BASE + 0x1000);
+        write_u32(&mut image, 0x2100, 1);
+        write_u32(&mut image, 0x2104, 0);
+        write_u32(&mut image, 0x210C, 0x2200);
+        write_u32(&mut image, 0x2114, 0x2100);
+        image[0x2210..0x2222].copy_from_slice(b".?AVPlayer@game@@\0");
+
+        assert_eq!(
+            discover_classes(&image),
+            vec![RuntimeClass::new(
+                "game::Player".to_string(),
+                "game".to_string(),
+                MODULE_BASE + 0x2010,
+                RuntimeClassAbi::Msvc
+            )]
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use squalr_engine_api::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+use squalr_engine_api::structures::data_types::built_in_types::f64::data_type_f64::DataTypeF64;
+use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use squalr_engine_api::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use squalr_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
+use squalr_engine_api::structures::struct_inference::inferred_field_kind::InferredFieldKind;
+use squalr_engine_api::structures::struct_inference::inferred_struct_field::InferredStructField;
+use squalr_engine_api::structures::structs::symbolic_field_definition::SymbolicFieldDefinition;
+use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+
+/// Proposes a struct layout for unknown memory by classifying each aligned slot from its value and the process memory map.
+pub struct StructLayoutInferrer;
+
+impl StructLayoutInferrer {
+    const MIN_STRING_LENGTH: usize = 4;
+    const MIN_FLOAT_MAGNITUDE: f64 = 1.0e-3;
+    const MAX_FLOAT_MAGNITUDE: f64 = 1.0e6;
+    const MAX_SMALL_INTEGER_MAGNITUDE: i64 = 0x10000;
+
+    /// Classifies the bytes of an object into fields. Pointer-sized slots are checked for vtable, module and heap pointers and
+    /// doubles before falling back to 32-bit floats and integers, and strings may span any number of slots.
+    pub fn infer_fields(
+        bytes: &[u8],
+        pointer_size: u64,
+        modules: &[NormalizedModule],
+        regions: &[NormalizedRegion],
+        find_runtime_class: &dyn Fn(u64) -> Option<RuntimeClass>,
+    ) -> Vec<InferredStructField> {
+        let pointer_size = pointer_size as usize;
+        let mut inferred_fields = Vec::new();
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            let remaining_bytes = &bytes[offset..];
+
+            if let Some(string_length) = Self::get_string_length(remaining_bytes) {
+                // Include the null terminator, and pad the string to the next 32-bit slot.
+                let field_size = (string_length + 1)
+                    .next_multiple_of(4)
+                    .min(remaining_bytes.len());
+
+                inferred_fields.push(InferredStructField::new(
+                    offset as u64,
+                    InferredFieldKind::String,
+                    SymbolicFieldDefinition::new(
+                        DataTypeRef::new(DataTypeStringUtf8::get_data_type_id()),
+                        ContainerType::ArrayFixed(field_size as u64),
+                    ),
+                    format!("\"{}\"", String::from_utf8_lossy(&remaining_bytes[..string_length])),
+                ));
+                offset += field_size;
+                continue;
+            }
+
+            if offset.is_multiple_of(pointer_size) && remaining_bytes.len() >= pointer_size {
+                let mut value_bytes = [0u8; 8];
+                value_bytes[..pointer_size].copy_from_slice(&remaining_bytes[..pointer_size]);
+
+                let value = u64::from_le_bytes(value_bytes);
+                let inferred_field = Self::classify_pointer(offset as u64, value, pointer_size, modules, regions, find_runtime_class)
+                    .or_else(|| Self::classify_double(offset as u64, value, pointer_size));
+
+                if let Some(inferred_field) = inferred_field {
+                    inferred_fields.push(inferred_field);
+                    offset += pointer_size;
+                    continue;
+                }
+            }
+
+            if remaining_bytes.len() >= 4 {
+                let value = u32::from_le_bytes([
+                    remaining_bytes[0],
+                    remaining_bytes[1],
+                    remaining_bytes[2],
+                    remaining_bytes[3],
+                ]);
+
+                inferred_fields.push(Self::classify_u32(offset as u64, value));
+                offset += 4;
+                continue;
+            }
+
+            inferred_fields.push(InferredStructField::new(
+                offset as u64,
+                InferredFieldKind::Unknown,
+                SymbolicFieldDefinition::new(DataTypeRef::new(DataTypeU8::get_data_type_id()), ContainerType::None),
+                format!("0x{:02X}", remaining_bytes[0]),
+            ));
+            offset += 1;
+        }
+
+        inferred_fields
+    }
+
+    /// Creates a struct definition from inferred fields, which lays out to the same offsets as the analyzed bytes.
+    pub fn create_struct_definition(
+        struct_name: &str,
+        inferred_fields: &[InferredStructField],
+    ) -> SymbolicStructDefinition {
+        SymbolicStructDefinition::new(
+            struct_name.to_string(),
+            inferred_fields
+                .iter()
+                .map(|inferred_field| inferred_field.get_field_definition().clone())
+                .collect(),
+        )
+    }
+
+    fn classify_pointer(
+        offset: u64,
+        value: u64,
+        pointer_size: usize,
+        modules: &[NormalizedModule],
+        regions: &[NormalizedRegion],
+        find_runtime_class: &dyn Fn(u64) -> Option<RuntimeClass>,
+    ) -> Option<InferredStructField> {
+        if value == 0 {
+            return None;
+        }
+
+        let (pointer_container_type, pointer_sized_data_type_id) = if pointer_size == 4 {
+            (ContainerType::Pointer32, DataTypeU32::get_data_type_id())
+        } else {
+            (ContainerType::Pointer64, DataTypeU64::get_data_type_id())
+        };
+
+        if let Some(runtime_class) = find_runtime_class(value) {
+            return Some(InferredStructField::new(
+                offset,
+                InferredFieldKind::Vtable,
+                SymbolicFieldDefinition::new(DataTypeRef::new(pointer_sized_data_type_id), pointer_container_type),
+                runtime_class.get_class_name().to_string(),
+            ));
+        }
+
+        let byte_pointer_definition = SymbolicFieldDefinition::new(DataTypeRef::new(DataTypeU8::get_data_type_id()), pointer_container_type);
+
+        if let Some(module) = modules.iter().find(|module| module.contains_address(value)) {
+            return Some(InferredStructField::new(
+                offset,
+                InferredFieldKind::ModulePointer,
+                byte_pointer_definition,
+                AddressExpression::format_module_offset(module.get_module_name(), value - module.get_base_address()),
+            ));
+        }
+
+        if regions.iter().any(|region| region.contains_address(value)) {
+            return Some(InferredStructField::new(
+                offset,
+                InferredFieldKind::HeapPointer,
+                byte_pointer_definition,
+                AddressExpression::format_module_offset("", value),
+            ));
+        }
+
+        None
+    }
+
+    fn classify_double(
+        offset: u64,
+        value: u64,
+        pointer_size: usize,
+    ) -> Option<InferredStructField> {
+        if pointer_size != 8 {
+            return None;
+        }
+
+        // Two packed 32-bit values can also form a plausible double, so these are preferred when either half looks like one.
+        let double_value = f64::from_bits(value);
+        let low_value = value as u32;
+        let high_value = (value >> 32) as u32;
+
+        if !Self::is_plausible_float(double_value) || Self::is_plausible_float(f32::from_bits(low_value) as f64) || Self::is_small_integer(high_value) {
+            return None;
+        }
+
+        Some(InferredStructField::new(
+            offset,
+            InferredFieldKind::Double,
+            SymbolicFieldDefinition::new(DataTypeRef::new(DataTypeF64::get_data_type_id()), ContainerType::None),
+            double_value.to_string(),
+        ))
+    }
+
+    fn classify_u32(
+        offset: u64,
+        value: u32,
+    ) -> InferredStructField {
+        let (kind, data_type_id, description) = if value == 0 {
+            (InferredFieldKind::Zero, DataTypeU32::get_data_type_id(), "0".to_string())
+        } else if Self::is_small_integer(value) {
+            (InferredFieldKind::SmallInteger, DataTypeI32::get_data_type_id(), (value as i32).to_string())
+        } else if Self::is_plausible_float(f32::from_bits(value) as f64) {
+            (InferredFieldKind::Float, DataTypeF32::get_data_type_id(), f32::from_bits(value).to_string())
+        } else {
+            (InferredFieldKind::Unknown, DataTypeU32::get_data_type_id(), format!("0x{:08X}", value))
+        };
+
+        InferredStructField::new(
+            offset,
+            kind,
+            SymbolicFieldDefinition::new(DataTypeRef::new(data_type_id), ContainerType::None),
+            description,
+        )
+    }
+
+    fn is_plausible_float(value: f64) -> bool {
+        value.is_normal() && (Self::MIN_FLOAT_MAGNITUDE..=Self::MAX_FLOAT_MAGNITUDE).contains(&value.abs())
+    }
+
+    fn is_small_integer(value: u32) -> bool {
+        (value as i32 as i64).abs() <= Self::MAX_SMALL_INTEGER_MAGNITUDE
+    }
+
+    /// Gets the length of the null-terminated printable string at the start of the bytes, if it is long enough to be deliberate.
+    fn get_string_length(bytes: &[u8]) -> Option<usize> {
+        let string_length = bytes
+            .iter()
+            .take_while(|byte| byte.is_ascii_graphic() || **byte == b' ')
+            .count();
+
+        if string_length >= Self::MIN_STRING_LENGTH && bytes.get(string_length) == Some(&0) {
+            Some(string_length)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StructLayoutInferrer;
+    use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+    use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
+    use squalr_engine_api::structures::runtime_classes::runtime_class_abi::RuntimeClassAbi;
+    use squalr_engine_api::structures::struct_inference::inferred_field_kind::InferredFieldKind;
+
+    #[test]
+    fn infer_fields_classifies_pointers_numbers_and_strings() {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&0x12010u64.to_le_bytes());
+        bytes.extend_from_slice(&0x11500u64.to_le_bytes());
+        bytes.extend_from_slice(&0x40080u64.to_le_bytes());
+        bytes.extend_from_slice(&1.5f32.to_le_bytes());
+        bytes.extend_from_slice(&100i32.to_le_bytes());
+        bytes.extend_from_slice(&250.25f64.to_le_bytes());
+        bytes.extend_from_slice(b"Player\0\0");
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&0xDEADBEEFu32.to_le_bytes());
+
+        let modules = vec![NormalizedModule::new("game.exe", 0x10000, 0x3000)];
+        let regions = vec![NormalizedRegion::new(0x40000, 0x1000)];
+        let find_runtime_class = |vtable_address: u64| {
+            (vtable_address == 0x12010).then(|| RuntimeClass::new("game::Player".to_string(), "game.exe".to_string(), 0x12010, RuntimeClassAbi::Msvc))
+        };
+        let inferred_fields = StructLayoutInferrer::infer_fields(&bytes, 8, &modules, &regions, &find_runtime_class);
+        let summary = inferred_fields
+            .iter()
+            .map(|inferred_field| (inferred_field.get_offset(), inferred_field.get_kind(), inferred_field.get_description()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            summary,
+            vec![
+                (0x00, InferredFieldKind::Vtable, "game::Player"),
+                (0x08, InferredFieldKind::ModulePointer, "game.exe+0x1500"),
+                (0x10, InferredFieldKind::HeapPointer, "0x40080"),
+                (0x18, InferredFieldKind::Float, "1.5"),
+                (0x1C, InferredFieldKind::SmallInteger, "100"),
+                (0x20, InferredFieldKind::Double, "250.25"),
+                (0x28, InferredFieldKind::String, "\"Player\""),
+                (0x30, InferredFieldKind::Zero, "0"),
+                (0x34, InferredFieldKind::Unknown, "0xDEADBEEF"),
+            ]
+        );
+        assert_eq!(
+            StructLayoutInferrer::create_struct_definition("Player", &inferred_fields).to_string(),
+            "u64*(64);u8*(64);u8*(64);f32;i32;f64;string_utf8[8];u32;u32"
+        );
+    }
+
+    #[test]
+    fn infer_fields_uses_32_bit_pointers_and_keeps_trailing_bytes() {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&0x10020u32.to_le_bytes());
+        bytes.extend_from_slice(&[7, 8]);
+
+        let modules = vec![NormalizedModule::new("game.exe", 0x10000, 0x1000)];
+        let inferred_fields = StructLayoutInferrer::infer_fields(&bytes, 4, &modules, &[], &|_vtable_address| None);
+
+        assert_eq!(
+            StructLayoutInferrer::create_struct_definition("", &inferred_fields).to_string(),
+            "u8*(32);u8;u8"
+        );
+        assert_eq!(inferred_fields[0].get_description(), "game.exe+0x20");
+    }
+}
@@ -0,0 +1,95 @@
+use crate::os::engine_os_provider::EngineOsProviders;
+use crate::os::memory_map_watcher::MemoryMapWatcher;
+use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+const TASK_NAME: &str = "Memory Map Watcher";
+const POLL_INTERVAL_MS: u64 = 1000;
+
+pub struct MemoryMapWatchTask;
+
+/// Implementation of a task that emits events when modules or memory regions of the opened process change,
+/// marking any snapshot regions backed by unmapped memory as stale.
+impl MemoryMapWatchTask {
+    pub fn start_task(
+        process_info: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        snapshot: Arc<RwLock<Snapshot>>,
+        os_providers: EngineOsProviders,
+        event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            let mut memory_map_watcher = MemoryMapWatcher::new();
+
+            loop {
+                if task_clone.get_cancellation_token().load(Ordering::Acquire) {
+                    break;
+                }
+
+                Self::watch_memory_map(&mut memory_map_watcher, &process_info, &snapshot, &os_providers, &event_emitter);
+                thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+            }
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    fn watch_memory_map(
+        memory_map_watcher: &mut MemoryMapWatcher,
+        process_info: &Arc<RwLock<Option<OpenedProcessInfo>>>,
+        snapshot: &Arc<RwLock<Snapshot>>,
+        os_providers: &EngineOsProviders,
+        event_emitter: &Arc<dyn Fn(EngineEvent) + Send + Sync>,
+    ) {
+        // Clone the process such that the process lock is not held while querying the OS.
+        let process_info = match process_info.read() {
+            Ok(process_info_guard) => process_info_guard.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on process info for memory map watching: {}", error);
+
+                return;
+            }
+        };
+        let (modules_changed_event, regions_changed_event) = memory_map_watcher.poll(process_info.as_ref(), os_providers);
+
+        if let Some(modules_changed_event) = modules_changed_event {
+            for module in &modules_changed_event.loaded_modules {
+                log::debug!("Module loaded: {} at 0x{:X}", module.get_module_name(), module.get_base_address());
+            }
+
+            for module in &modules_changed_event.unloaded_modules {
+                log::debug!("Module unloaded: {}", module.get_module_name());
+            }
+
+            event_emitter(modules_changed_event.to_engine_event());
+        }
+
+        if let Some(regions_changed_event) = regions_changed_event {
+            if !regions_changed_event.unmapped_regions.is_empty() {
+                match snapshot.write() {
+                    Ok(mut snapshot) => {
+                        let stale_region_count = snapshot.mark_stale_regions(&regions_changed_event.unmapped_regions);
+
+                        if stale_region_count > 0 {
+                            log::info!("{} snapshot regions are stale due to unmapped memory.", stale_region_count);
+                        }
+                    }
+                    Err(error) => log::error!("Failed to acquire snapshot write lock to mark stale regions: {}", error),
+                }
+            }
+
+            event_emitter(regions_changed_event.to_engine_event());
+        }
+    }
+}
@@ -1,2 +1,5 @@
+pub mod memory_map_watch_task;
+pub mod project_hotkey_task;
 pub mod snapshot_scan_result_freeze_task;
 pub mod trackable_task_manager;
+pub mod value_timeline_record_task;
@@ -0,0 +1,69 @@
+use crate::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::commands::project_items::trigger_hotkey::project_items_trigger_hotkey_request::ProjectItemsTriggerHotkeyRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use squalr_engine_operating_system::hotkeys::hotkey_listener_trait::HotkeyListenerTrait;
+use squalr_engine_operating_system::hotkeys::key_chord_tracker::KeyChordTracker;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::Duration;
+
+const TASK_NAME: &str = "Project Hotkeys";
+
+/// How often key events are polled, which bounds the delay between pressing a hotkey and its actions being performed.
+const POLL_INTERVAL_MS: u64 = 10;
+
+pub struct ProjectHotkeyTask;
+
+/// Implementation of a task that listens for global key presses and triggers the project item actions bound to each completed chord.
+impl ProjectHotkeyTask {
+    /// Starts listening for hotkeys. Key events are still tracked while suspended, such as while typing into the application itself,
+    /// but no chords are triggered until the suspension is lifted.
+    pub fn start_task(
+        engine_unprivileged_state: Weak<EngineUnprivilegedState>,
+        mut hotkey_listener: Box<dyn HotkeyListenerTrait>,
+        is_suspended: Arc<AtomicBool>,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            let mut key_chord_tracker = KeyChordTracker::new();
+
+            loop {
+                if task_clone.get_cancellation_token().load(Ordering::Acquire) {
+                    break;
+                }
+
+                let Some(engine_unprivileged_state) = engine_unprivileged_state.upgrade() else {
+                    break;
+                };
+
+                // Sampled before polling, such that events are never judged against a suspension changed after they were read.
+                let is_poll_suspended = is_suspended.load(Ordering::Acquire);
+
+                for hotkey_key_event in hotkey_listener.poll_key_events() {
+                    let key_chord = key_chord_tracker.process_key_event(&hotkey_key_event);
+
+                    if is_poll_suspended {
+                        continue;
+                    }
+
+                    if let Some(key_chord) = key_chord {
+                        log::debug!("Hotkey pressed: {}", key_chord);
+
+                        ProjectItemsTriggerHotkeyRequest { key_chord }.send(&engine_unprivileged_state, |_project_items_trigger_hotkey_response| {});
+                    }
+                }
+
+                drop(engine_unprivileged_state);
+                thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+            }
+
+            task_clone.complete();
+        });
+
+        task
+    }
+}
@@ -1,36 +1,111 @@
 use crate::os::engine_os_provider::EngineOsProviders;
+use crate::os::process_address_expression_context::ProcessAddressExpressionContext;
+use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use squalr_engine_api::events::memory::freeze_failures::freeze_failures_event::FreezeFailuresEvent;
 use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::pointer::Pointer;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
 use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 use std::sync::RwLock;
 use std::sync::atomic::Ordering;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 const TASK_NAME: &str = "Scan Result Freezer";
 
+/// The longest the freezer sleeps, such that newly frozen addresses are written promptly.
+const IDLE_POLL_INTERVAL_MS: u64 = 50;
+
+/// The interval of low latency entries that do not specify one, which approximates writing once per frame or faster.
+const LOW_LATENCY_INTERVAL_MS: u64 = 1;
+
+/// How far ahead of a low latency deadline the freezer stops sleeping and begins busy-waiting, to absorb scheduler jitter.
+const LOW_LATENCY_SPIN_THRESHOLD: Duration = Duration::from_micros(1500);
+
+/// How often accumulated read and write failures are reported, such that unwritable addresses do not flood listeners.
+const FAILURE_REPORT_INTERVAL_MS: u64 = 1000;
+
+/// How often the cached module list is refreshed, such that module-relative freezes follow modules that load or move.
+const MODULE_REFRESH_INTERVAL_MS: u64 = 1000;
+
+/// The granularity at which reads and writes to frozen addresses are batched.
+const PAGE_SIZE: u64 = 0x1000;
+
+/// A contiguous span of process memory within a page, covering the frozen entries at the given indices.
+#[derive(Debug, PartialEq)]
+struct FreezeSpan {
+    address: u64,
+    bytes: Vec<u8>,
+    entry_indices: Vec<usize>,
+}
+
+/// A frozen entry that is due, resolved to an address in the opened process.
+struct DueFreezeEntry {
+    pointer: Pointer,
+    address: u64,
+    size_in_bytes: u64,
+    requires_current_value: bool,
+}
+
+/// State the freezer carries between iterations.
+struct FreezerState {
+    /// The process the freezer last observed, used to detect the opened process changing.
+    opened_process_id: Option<u32>,
+
+    /// The modules of the opened process, cached such that they are not queried on every freeze.
+    modules: Vec<NormalizedModule>,
+    modules_refresh_time: Instant,
+    next_freeze_times: HashMap<Pointer, Instant>,
+    failed_addresses: BTreeSet<u64>,
+}
+
+/// When the freezer should next wake, and whether an entry due at that time requires precise timing.
+struct FreezeSchedule {
+    next_freeze_time: Instant,
+    is_low_latency: bool,
+}
+
 pub struct SnapshotScanResultFreezeTask;
 
-/// Implementation of a task that freezes all scan results selected by the user.
+/// Implementation of a task that freezes all scan results selected by the user, each at its own interval. Reads and writes to the same
+/// page are batched, and failures are reported through task progress and events.
 impl SnapshotScanResultFreezeTask {
     pub fn start_task(
         process_info: Arc<RwLock<Option<OpenedProcessInfo>>>,
         freeze_list_registry: Arc<RwLock<FreezeListRegistry>>,
         os_providers: EngineOsProviders,
+        event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
     ) -> Arc<TrackableTask> {
         let task = TrackableTask::create(TASK_NAME.to_string(), None);
         let task_clone = task.clone();
 
         thread::spawn(move || {
+            let mut freezer_state = FreezerState {
+                opened_process_id: None,
+                modules: Vec::new(),
+                modules_refresh_time: Instant::now(),
+                next_freeze_times: HashMap::new(),
+                failed_addresses: BTreeSet::new(),
+            };
+            let mut next_failure_report_time = Instant::now();
+
             loop {
                 if task_clone.get_cancellation_token().load(Ordering::Acquire) {
                     break;
                 }
 
-                Self::freeze_values(&process_info, &freeze_list_registry, &os_providers);
-                thread::sleep(Duration::from_millis(ScanSettingsConfig::get_results_read_interval_ms()));
+                let freeze_schedule = Self::freeze_due_values(&process_info, &freeze_list_registry, &os_providers, &mut freezer_state);
+
+                if Instant::now() >= next_failure_report_time {
+                    Self::report_failures(&task_clone, &freeze_list_registry, &event_emitter, &mut freezer_state.failed_addresses);
+                    next_failure_report_time = Instant::now() + Duration::from_millis(FAILURE_REPORT_INTERVAL_MS);
+                }
+
+                Self::wait_until(&freeze_schedule);
             }
 
             task_clone.complete();
@@ -39,46 +114,443 @@ impl SnapshotScanResultFreezeTask {
         task
     }
 
-    fn freeze_values(
+    /// Freezes every entry that is due, returning when the next entry is due. The registry is only locked to copy out due entries and
+    /// to record results, never while querying modules or accessing process memory.
+    fn freeze_due_values(
         process_info: &Arc<RwLock<Option<OpenedProcessInfo>>>,
         freeze_list_registry: &Arc<RwLock<FreezeListRegistry>>,
         os_providers: &EngineOsProviders,
-    ) {
-        let process_info_guard = match process_info.read() {
-            Ok(process_info_guard) => process_info_guard,
+        freezer_state: &mut FreezerState,
+    ) -> FreezeSchedule {
+        let now = Instant::now();
+        let idle_schedule = FreezeSchedule {
+            next_freeze_time: now + Duration::from_millis(IDLE_POLL_INTERVAL_MS),
+            is_low_latency: false,
+        };
+
+        // Clone the process such that the process lock is not held while accessing memory.
+        let process_info = match process_info.read() {
+            Ok(process_info_guard) => process_info_guard.clone(),
             Err(error) => {
                 log::error!("Failed to acquire read lock on process info for result freezing: {}", error);
 
-                return;
+                return idle_schedule;
             }
         };
+        let process_id = process_info.as_ref().map(OpenedProcessInfo::get_process_id_raw);
+
+        if freezer_state.opened_process_id != process_id {
+            if freezer_state.opened_process_id.is_some() {
+                Self::clear_absolute_freezes(freeze_list_registry);
+            }
+
+            freezer_state.opened_process_id = process_id;
+            freezer_state.modules.clear();
+            freezer_state.modules_refresh_time = now;
+        }
 
-        let process_info = match process_info_guard.as_ref() {
-            Some(process_info) => process_info,
-            None => return,
+        let Some(process_info) = process_info else {
+            return idle_schedule;
         };
 
-        let freeze_list_registry_guard = match freeze_list_registry.write() {
+        let (due_pointers, freeze_schedule) = match freeze_list_registry.read() {
+            Ok(freeze_list_registry_guard) => Self::collect_due_pointers(&freeze_list_registry_guard, &mut freezer_state.next_freeze_times, now, idle_schedule),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on FreezeListRegistry: {}", error);
+
+                return idle_schedule;
+            }
+        };
+
+        if due_pointers.is_empty() {
+            return freeze_schedule;
+        }
+
+        if now >= freezer_state.modules_refresh_time {
+            freezer_state.modules = os_providers.memory_query.get_modules(&process_info);
+            freezer_state.modules_refresh_time = now + Duration::from_millis(MODULE_REFRESH_INTERVAL_MS);
+        }
+
+        let address_expression_context =
+            ProcessAddressExpressionContext::new_with_modules(os_providers, &process_info, std::mem::take(&mut freezer_state.modules));
+        let due_entries: Vec<DueFreezeEntry> = due_pointers
+            .into_iter()
+            .filter_map(|(pointer, size_in_bytes, requires_current_value)| {
+                Some(DueFreezeEntry {
+                    address: Self::resolve_pointer_address(&address_expression_context, &pointer)?,
+                    size_in_bytes,
+                    requires_current_value,
+                    pointer,
+                })
+            })
+            .collect();
+
+        freezer_state.modules = address_expression_context.into_modules();
+
+        // Read the current values of every entry that depends on them, one read per run of adjacent values.
+        let mut current_values: Vec<Option<Vec<u8>>> = vec![None; due_entries.len()];
+        let mut failed_read_count = 0;
+        let read_requests = due_entries
+            .iter()
+            .enumerate()
+            .filter(|(_entry_index, due_entry)| due_entry.requires_current_value)
+            .map(|(entry_index, due_entry)| (entry_index, due_entry.address, due_entry.size_in_bytes))
+            .collect();
+
+        for mut read_span in Self::coalesce_reads(read_requests) {
+            if !os_providers
+                .memory_read
+                .read_bytes(&process_info, read_span.address, &mut read_span.bytes)
+            {
+                failed_read_count += read_span.entry_indices.len() as u64;
+                freezer_state.failed_addresses.extend(
+                    read_span
+                        .entry_indices
+                        .iter()
+                        .map(|entry_index| due_entries[*entry_index].address),
+                );
+
+                continue;
+            }
+
+            for entry_index in read_span.entry_indices {
+                let due_entry = &due_entries[entry_index];
+                let offset = (due_entry.address - read_span.address) as usize;
+
+                current_values[entry_index] = Some(read_span.bytes[offset..offset + due_entry.size_in_bytes as usize].to_vec());
+            }
+        }
+
+        // Resolve the values to write, which may update entry state such as the values adopted by monotonic freezes.
+        let write_requests = match freeze_list_registry.write() {
+            Ok(mut freeze_list_registry_guard) => {
+                freeze_list_registry_guard
+                    .get_freeze_metrics_mut()
+                    .record_failed_read(failed_read_count);
+
+                let frozen_pointers = freeze_list_registry_guard.get_frozen_pointers_mut();
+
+                due_entries
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(entry_index, due_entry)| {
+                        let freeze_list_entry = frozen_pointers.get_mut(&due_entry.pointer)?;
+                        let value_bytes = freeze_list_entry.resolve_write_bytes(current_values[entry_index].as_deref())?;
+
+                        Some((entry_index, due_entry.address, value_bytes))
+                    })
+                    .collect()
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error);
+
+                return freeze_schedule;
+            }
+        };
+
+        // Write each contiguous run of values within a page at once.
+        let write_results: Vec<(FreezeSpan, bool)> = Self::coalesce_writes(write_requests)
+            .into_iter()
+            .map(|write_span| {
+                let success = os_providers
+                    .memory_write
+                    .write_bytes(&process_info, write_span.address, &write_span.bytes);
+
+                (write_span, success)
+            })
+            .collect();
+
+        let mut freeze_list_registry_guard = match freeze_list_registry.write() {
             Ok(freeze_list_registry_guard) => freeze_list_registry_guard,
             Err(error) => {
                 log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error);
 
+                return freeze_schedule;
+            }
+        };
+
+        for (write_span, success) in write_results {
+            freeze_list_registry_guard
+                .get_freeze_metrics_mut()
+                .record_write(write_span.entry_indices.len() as u64, success);
+
+            if success {
+                let frozen_pointers = freeze_list_registry_guard.get_frozen_pointers_mut();
+
+                for entry_index in &write_span.entry_indices {
+                    if let Some(freeze_list_entry) = frozen_pointers.get_mut(&due_entries[*entry_index].pointer) {
+                        freeze_list_entry.mark_applied();
+                    }
+                }
+            } else {
+                freezer_state.failed_addresses.extend(
+                    write_span
+                        .entry_indices
+                        .iter()
+                        .map(|entry_index| due_entries[*entry_index].address),
+                );
+            }
+        }
+
+        freeze_schedule
+    }
+
+    /// Collects `(pointer, size_in_bytes, requires_current_value)` for every entry that is due, advancing their deadlines, and
+    /// computes when the freezer should next wake.
+    fn collect_due_pointers(
+        freeze_list_registry: &FreezeListRegistry,
+        next_freeze_times: &mut HashMap<Pointer, Instant>,
+        now: Instant,
+        idle_schedule: FreezeSchedule,
+    ) -> (Vec<(Pointer, u64, bool)>, FreezeSchedule) {
+        let default_interval_ms = ScanSettingsConfig::get_freeze_interval_ms();
+        let frozen_pointers = freeze_list_registry.get_frozen_pointers();
+        let mut due_pointers = Vec::new();
+        let mut freeze_schedule = idle_schedule;
+
+        next_freeze_times.retain(|pointer, _next_freeze_time| frozen_pointers.contains_key(pointer));
+
+        for (pointer, freeze_list_entry) in frozen_pointers.iter() {
+            let interval_ms = match (freeze_list_entry.get_interval_ms(), freeze_list_entry.is_low_latency()) {
+                (Some(interval_ms), _) => interval_ms,
+                (None, true) => LOW_LATENCY_INTERVAL_MS,
+                (None, false) => default_interval_ms,
+            };
+            let next_freeze_time = next_freeze_times.entry(pointer.clone()).or_insert(now);
+
+            if *next_freeze_time <= now {
+                due_pointers.push((
+                    pointer.clone(),
+                    freeze_list_entry.get_size_in_bytes(),
+                    freeze_list_entry.get_freeze_mode().requires_current_value(),
+                ));
+
+                // Schedule from the previous deadline to avoid drift, unless the freezer has fallen more than an interval behind.
+                let interval = Duration::from_millis(interval_ms);
+                *next_freeze_time = (*next_freeze_time + interval).max(now);
+            }
+
+            if *next_freeze_time < freeze_schedule.next_freeze_time {
+                freeze_schedule.next_freeze_time = *next_freeze_time;
+                freeze_schedule.is_low_latency = freeze_list_entry.is_low_latency();
+            } else if *next_freeze_time == freeze_schedule.next_freeze_time {
+                freeze_schedule.is_low_latency |= freeze_list_entry.is_low_latency();
+            }
+        }
+
+        (due_pointers, freeze_schedule)
+    }
+
+    /// Drops absolute address freezes after the opened process changes or exits, such that they are not written into an unrelated process.
+    fn clear_absolute_freezes(freeze_list_registry: &Arc<RwLock<FreezeListRegistry>>) {
+        match freeze_list_registry.write() {
+            Ok(mut freeze_list_registry_guard) => freeze_list_registry_guard.clear_absolute_freezes(),
+            Err(error) => log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error),
+        }
+    }
+
+    /// Resolves the address of a frozen pointer, evaluating its address expression on every call such that pointer chains are followed.
+    /// Returns `None` while the pointer cannot be resolved, for example while a restarted process initializes its modules.
+    fn resolve_pointer_address(
+        address_expression_context: &ProcessAddressExpressionContext,
+        pointer: &Pointer,
+    ) -> Option<u64> {
+        if !pointer.get_address_expression().is_empty() {
+            return address_expression_context
+                .resolve(pointer.get_address_expression())
+                .ok();
+        }
+
+        if pointer.get_module_name().is_empty() {
+            return Some(pointer.get_address());
+        }
+
+        address_expression_context
+            .resolve_module(pointer.get_module_name())
+            .map(|module_address| module_address.saturating_add(pointer.get_address()))
+    }
+
+    /// Publishes the write success ratio as task progress, and emits an event if any reads or writes failed since the last report.
+    fn report_failures(
+        task: &Arc<TrackableTask>,
+        freeze_list_registry: &Arc<RwLock<FreezeListRegistry>>,
+        event_emitter: &Arc<dyn Fn(EngineEvent) + Send + Sync>,
+        failed_addresses: &mut BTreeSet<u64>,
+    ) {
+        let freeze_metrics = match freeze_list_registry.read() {
+            Ok(freeze_list_registry) => *freeze_list_registry.get_freeze_metrics(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on FreezeListRegistry: {}", error);
+
                 return;
             }
         };
+        let write_success_ratio = freeze_metrics.get_write_success_ratio();
 
-        let modules = os_providers.memory_query.get_modules(process_info);
+        // Progress updates are queued for subscribers, so only publish changes.
+        if task.get_progress() != write_success_ratio {
+            task.set_progress(write_success_ratio);
+        }
 
-        for pointer in freeze_list_registry_guard.get_frozen_pointers().keys() {
-            if let Some(value_bytes) = freeze_list_registry_guard.get_address_frozen_bytes(pointer) {
-                let module_address = os_providers
-                    .memory_query
-                    .resolve_module(&modules, pointer.get_module_name());
+        if failed_addresses.is_empty() {
+            return;
+        }
 
-                let _success = os_providers
-                    .memory_write
-                    .write_bytes(process_info, module_address.saturating_add(pointer.get_address()), value_bytes);
+        event_emitter(
+            FreezeFailuresEvent {
+                freeze_metrics,
+                failed_addresses: std::mem::take(failed_addresses).into_iter().collect(),
+            }
+            .to_engine_event(),
+        );
+    }
+
+    /// Waits until the next freeze is due. Low latency deadlines are approached by sleeping, then yielding until the remainder elapses, as
+    /// sleeps alone commonly overshoot by more than a frame. Yielding rather than spinning leaves the core to other runnable threads.
+    fn wait_until(freeze_schedule: &FreezeSchedule) {
+        let remaining_duration = freeze_schedule
+            .next_freeze_time
+            .saturating_duration_since(Instant::now());
+
+        if !freeze_schedule.is_low_latency {
+            thread::sleep(remaining_duration.max(Duration::from_millis(1)));
+
+            return;
+        }
+
+        if remaining_duration > LOW_LATENCY_SPIN_THRESHOLD {
+            thread::sleep(remaining_duration - LOW_LATENCY_SPIN_THRESHOLD);
+        }
+
+        while Instant::now() < freeze_schedule.next_freeze_time {
+            thread::yield_now();
+        }
+    }
+
+    /// Merges reads of `(entry_index, address, size_in_bytes)` that are contiguous or overlapping within the same page. Reads separated
+    /// by a gap are kept apart, such that unrelated memory between frozen values is never read.
+    fn coalesce_reads(mut read_requests: Vec<(usize, u64, u64)>) -> Vec<FreezeSpan> {
+        read_requests.sort_by_key(|(entry_index, address, _size_in_bytes)| (*address, *entry_index));
+
+        let mut read_spans: Vec<FreezeSpan> = Vec::new();
+
+        for (entry_index, address, size_in_bytes) in read_requests {
+            let end_address = address.saturating_add(size_in_bytes);
+
+            match read_spans.last_mut() {
+                Some(read_span) if read_span.address / PAGE_SIZE == address / PAGE_SIZE && address <= read_span.address + read_span.bytes.len() as u64 => {
+                    let span_length = (end_address - read_span.address) as usize;
+
+                    if span_length > read_span.bytes.len() {
+                        read_span.bytes.resize(span_length, 0);
+                    }
+
+                    read_span.entry_indices.push(entry_index);
+                }
+                _ => read_spans.push(FreezeSpan {
+                    address,
+                    bytes: vec![0u8; size_in_bytes as usize],
+                    entry_indices: vec![entry_index],
+                }),
+            }
+        }
+
+        read_spans
+    }
+
+    /// Merges writes of `(entry_index, address, bytes)` that are contiguous or overlapping within the same page. Writes separated by a
+    /// gap are kept apart, such that memory between frozen values is never overwritten.
+    fn coalesce_writes(mut write_requests: Vec<(usize, u64, Vec<u8>)>) -> Vec<FreezeSpan> {
+        write_requests.sort_by_key(|(entry_index, address, _bytes)| (*address, *entry_index));
+
+        let mut write_spans: Vec<FreezeSpan> = Vec::new();
+
+        for (entry_index, address, bytes) in write_requests {
+            match write_spans.last_mut() {
+                Some(write_span) if write_span.address / PAGE_SIZE == address / PAGE_SIZE && address <= write_span.address + write_span.bytes.len() as u64 => {
+                    let offset = (address - write_span.address) as usize;
+                    let span_length = offset + bytes.len();
+
+                    if span_length > write_span.bytes.len() {
+                        write_span.bytes.resize(span_length, 0);
+                    }
+
+                    write_span.bytes[offset..span_length].copy_from_slice(&bytes);
+                    write_span.entry_indices.push(entry_index);
+                }
+                _ => write_spans.push(FreezeSpan {
+                    address,
+                    bytes,
+                    entry_indices: vec![entry_index],
+                }),
             }
         }
+
+        write_spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FreezeSpan, SnapshotScanResultFreezeTask};
+
+    #[test]
+    fn coalesce_writes_merges_contiguous_values_within_a_page() {
+        let write_spans = SnapshotScanResultFreezeTask::coalesce_writes(vec![
+            (1, 0x1004, vec![3, 4]),
+            (0, 0x1000, vec![1, 2, 0, 0]),
+            (2, 0x1006, vec![5]),
+        ]);
+
+        assert_eq!(
+            write_spans,
+            vec![FreezeSpan {
+                address: 0x1000,
+                bytes: vec![1, 2, 0, 0, 3, 4, 5],
+                entry_indices: vec![0, 1, 2],
+            }]
+        );
+    }
+
+    #[test]
+    fn coalesce_writes_keeps_gaps_and_page_boundaries_apart() {
+        let write_spans = SnapshotScanResultFreezeTask::coalesce_writes(vec![
+            (0, 0x1000, vec![1]),
+            (1, 0x1002, vec![2]),
+            (2, 0x1FFF, vec![3]),
+            (3, 0x2000, vec![4]),
+        ]);
+        let span_addresses: Vec<u64> = write_spans
+            .iter()
+            .map(|write_span| write_span.address)
+            .collect();
+
+        assert_eq!(span_addresses, vec![0x1000, 0x1002, 0x1FFF, 0x2000]);
+    }
+
+    #[test]
+    fn coalesce_reads_merges_only_contiguous_or_overlapping_reads_within_a_page() {
+        let read_spans = SnapshotScanResultFreezeTask::coalesce_reads(vec![
+            (0, 0x1002, 4),
+            (1, 0x1000, 2),
+            (2, 0x1004, 1),
+            (3, 0x1010, 4),
+            (4, 0x1FFE, 4),
+            (5, 0x2000, 8),
+        ]);
+        let read_span_ranges: Vec<(u64, usize, Vec<usize>)> = read_spans
+            .iter()
+            .map(|read_span| (read_span.address, read_span.bytes.len(), read_span.entry_indices.clone()))
+            .collect();
+
+        assert_eq!(
+            read_span_ranges,
+            vec![
+                (0x1000, 6, vec![1, 0, 2]),
+                (0x1010, 4, vec![3]),
+                (0x1FFE, 4, vec![4]),
+                (0x2000, 8, vec![5]),
+            ]
+        );
     }
 }
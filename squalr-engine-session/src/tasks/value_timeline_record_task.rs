@@ -0,0 +1,176 @@
+use crate::os::engine_os_provider::EngineOsProviders;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::registries::value_timelines::value_timeline_registry::ValueTimelineRegistry;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use squalr_engine_api::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use squalr_engine_api::structures::value_timelines::value_timeline_target::ValueTimelineTarget;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const TASK_NAME: &str = "Value Timeline Recorder";
+
+/// The longest the recorder sleeps, such that newly added timelines begin recording promptly.
+const IDLE_POLL_INTERVAL_MS: u64 = 50;
+
+pub struct ValueTimelineRecordTask;
+
+/// Implementation of a task that samples the value of each recorded timeline target at that timeline's interval.
+impl ValueTimelineRecordTask {
+    pub fn start_task(
+        process_info: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        value_timeline_registry: Arc<RwLock<ValueTimelineRegistry>>,
+        os_providers: EngineOsProviders,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            let mut next_sample_times = HashMap::new();
+
+            loop {
+                if task_clone.get_cancellation_token().load(Ordering::Acquire) {
+                    break;
+                }
+
+                let sleep_duration = Self::record_due_samples(&process_info, &value_timeline_registry, &os_providers, &mut next_sample_times);
+
+                thread::sleep(sleep_duration);
+            }
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    /// Samples every timeline that is due, returning how long to wait until the next timeline is due.
+    fn record_due_samples(
+        process_info: &Arc<RwLock<Option<OpenedProcessInfo>>>,
+        value_timeline_registry: &Arc<RwLock<ValueTimelineRegistry>>,
+        os_providers: &EngineOsProviders,
+        next_sample_times: &mut HashMap<u32, Instant>,
+    ) -> Duration {
+        let idle_duration = Duration::from_millis(IDLE_POLL_INTERVAL_MS);
+
+        // Clone the process such that the process lock is not held while reading memory.
+        let process_info = match process_info.read() {
+            Ok(process_info_guard) => process_info_guard.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on process info for value timeline recording: {}", error);
+
+                return idle_duration;
+            }
+        };
+        let Some(process_info) = process_info else {
+            return idle_duration;
+        };
+
+        // Collect due targets up front, such that the registry is not locked while reading memory.
+        let now = Instant::now();
+        let due_timelines: Vec<(u32, ValueTimelineTarget)> = match value_timeline_registry.read() {
+            Ok(value_timeline_registry) => {
+                next_sample_times.retain(|timeline_id, _next_sample_time| value_timeline_registry.get_timeline(*timeline_id).is_some());
+
+                let due_timelines: Vec<(u32, ValueTimelineTarget)> = value_timeline_registry
+                    .get_timelines()
+                    .values()
+                    .filter(|value_timeline| {
+                        next_sample_times
+                            .get(&value_timeline.get_timeline_id())
+                            .is_none_or(|next_sample_time| *next_sample_time <= now)
+                    })
+                    .map(|value_timeline| (value_timeline.get_timeline_id(), value_timeline.get_target().clone()))
+                    .collect();
+
+                for (timeline_id, _target) in &due_timelines {
+                    if let Some(value_timeline) = value_timeline_registry.get_timeline(*timeline_id) {
+                        next_sample_times.insert(*timeline_id, now + Duration::from_millis(value_timeline.get_interval_ms()));
+                    }
+                }
+
+                due_timelines
+            }
+            Err(error) => {
+                log::error!("Failed to acquire read lock on ValueTimelineRegistry: {}", error);
+
+                return idle_duration;
+            }
+        };
+
+        if !due_timelines.is_empty() {
+            let modules = os_providers.memory_query.get_modules(&process_info);
+            let timestamp_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis() as u64)
+                .unwrap_or_default();
+            let samples: Vec<(u32, ValueTimelineSample)> = due_timelines
+                .into_iter()
+                .map(|(timeline_id, target)| {
+                    let value = Self::read_target_value(&process_info, &target, os_providers, &modules);
+
+                    (timeline_id, ValueTimelineSample::new(timestamp_ms, value))
+                })
+                .collect();
+
+            match value_timeline_registry.write() {
+                Ok(mut value_timeline_registry) => {
+                    for (timeline_id, sample) in samples {
+                        if let Some(value_timeline) = value_timeline_registry.get_timeline_mut(timeline_id) {
+                            value_timeline.push_sample(sample);
+                        }
+                    }
+                }
+                Err(error) => log::error!("Failed to acquire write lock on ValueTimelineRegistry: {}", error),
+            }
+        }
+
+        next_sample_times
+            .values()
+            .min()
+            .map(|next_sample_time| next_sample_time.saturating_duration_since(Instant::now()))
+            .unwrap_or(idle_duration)
+            .clamp(Duration::from_millis(1), idle_duration)
+    }
+
+    /// Reads the current value of the target as a decimal string, or `None` if it could not be read.
+    fn read_target_value(
+        process_info: &OpenedProcessInfo,
+        target: &ValueTimelineTarget,
+        os_providers: &EngineOsProviders,
+        modules: &Vec<NormalizedModule>,
+    ) -> Option<String> {
+        let module_address = os_providers
+            .memory_query
+            .resolve_module(modules, target.get_module_name());
+
+        // Skip module-relative targets until the module is loaded, for example while a restarted process initializes.
+        if module_address == 0 && !target.get_module_name().is_empty() {
+            return None;
+        }
+
+        let symbol_registry = SymbolRegistry::get_instance();
+        let data_type_ref = target.get_data_type_ref();
+        let mut value_bytes = vec![0u8; symbol_registry.get_unit_size_in_bytes(data_type_ref) as usize];
+
+        if value_bytes.is_empty()
+            || !os_providers
+                .memory_read
+                .read_bytes(process_info, module_address.saturating_add(target.get_address()), &mut value_bytes)
+        {
+            return None;
+        }
+
+        symbol_registry
+            .anonymize_value(&DataValue::new(data_type_ref.clone(), value_bytes), AnonymousValueStringFormat::Decimal)
+            .ok()
+            .map(|anonymous_value_string| anonymous_value_string.get_anonymous_value_string().to_string())
+    }
+}
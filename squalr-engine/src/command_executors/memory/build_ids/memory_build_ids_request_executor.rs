@@ -0,0 +1,112 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::build_ids::memory_build_ids_request::MemoryBuildIdsRequest;
+use squalr_engine_api::commands::memory::build_ids::memory_build_ids_response::MemoryBuildIdsResponse;
+use squalr_engine_api::events::memory::stale_module_builds::stale_module_builds_event::StaleModuleBuildsEvent;
+use squalr_engine_api::structures::memory::module_build_identity::ModuleBuildIdentity;
+use squalr_engine_api::structures::memory::module_build_mismatch::ModuleBuildMismatch;
+use squalr_engine_session::module_symbols::module_build_identity_resolver::ModuleBuildIdentityResolver;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryBuildIdsRequest {
+    type ResponseType = MemoryBuildIdsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::debug!("No opened process available to compute module build identities for.");
+                return MemoryBuildIdsResponse::default();
+            }
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let modules = os_providers.memory_query.get_modules(&process_info);
+        let mut module_build_identities: Vec<ModuleBuildIdentity> = Vec::new();
+        let requested_module_names = self.module_names.iter().map(String::as_str).chain(
+            self.expected_build_identities
+                .iter()
+                .map(|expected_build_identity| expected_build_identity.get_module_name()),
+        );
+
+        for module_name in requested_module_names {
+            let module_name = module_name.trim();
+
+            if module_name.is_empty()
+                || module_build_identities.iter().any(|module_build_identity| {
+                    module_build_identity
+                        .get_module_name()
+                        .eq_ignore_ascii_case(module_name)
+                })
+            {
+                continue;
+            }
+
+            // Modules that are not loaded yet are skipped, as their build cannot be compared until they load.
+            let Some(module) = modules.iter().find(|module| {
+                module
+                    .get_module_name()
+                    .trim()
+                    .eq_ignore_ascii_case(module_name)
+            }) else {
+                continue;
+            };
+
+            if let Some(module_build_identity) = ModuleBuildIdentityResolver::compute_build_identity(os_providers, &process_info, module) {
+                module_build_identities.push(module_build_identity);
+            }
+        }
+
+        let mut stale_module_builds: Vec<ModuleBuildMismatch> = Vec::new();
+
+        for expected_build_identity in &self.expected_build_identities {
+            let expected_build_id = expected_build_identity.get_build_id();
+            let Some(module_build_identity) = module_build_identities.iter().find(|module_build_identity| {
+                module_build_identity
+                    .get_module_name()
+                    .eq_ignore_ascii_case(expected_build_identity.get_module_name().trim())
+            }) else {
+                continue;
+            };
+
+            if expected_build_id.is_empty()
+                || module_build_identity.get_build_id() == expected_build_id
+                || stale_module_builds.iter().any(|stale_module_build| {
+                    stale_module_build.get_module_name() == module_build_identity.get_module_name()
+                        && stale_module_build.get_expected_build_id() == expected_build_id
+                })
+            {
+                continue;
+            }
+
+            log::warn!(
+                "Module '{}' is build {}, but addresses were recorded against build {}. These addresses may need to be re-found.",
+                module_build_identity.get_module_name(),
+                module_build_identity.get_build_id(),
+                expected_build_id
+            );
+
+            stale_module_builds.push(ModuleBuildMismatch::new(
+                module_build_identity.get_module_name().to_string(),
+                expected_build_id.to_string(),
+                module_build_identity.get_build_id().to_string(),
+            ));
+        }
+
+        if !stale_module_builds.is_empty() {
+            engine_privileged_state.emit_event(StaleModuleBuildsEvent {
+                stale_module_builds: stale_module_builds.clone(),
+            });
+        }
+
+        MemoryBuildIdsResponse {
+            module_build_identities,
+            stale_module_builds,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_build_ids_request_executor;
@@ -0,0 +1,51 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::classes::memory_classes_request::MemoryClassesRequest;
+use squalr_engine_api::commands::memory::classes::memory_classes_response::MemoryClassesResponse;
+use squalr_engine_session::runtime_classes::runtime_class_resolver::RuntimeClassResolver;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryClassesRequest {
+    type ResponseType = MemoryClassesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process available to discover classes in.");
+                return MemoryClassesResponse::default();
+            }
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let module_name = self.module_name.trim();
+        let runtime_classes = match RuntimeClassResolver::get_module_runtime_classes(os_providers, &process_info, module_name) {
+            Some(runtime_classes) => runtime_classes,
+            None => {
+                log::error!("Module '{}' is not loaded.", module_name);
+                return MemoryClassesResponse::default();
+            }
+        };
+        let filter = self.filter.as_ref().map(|filter| filter.to_ascii_lowercase());
+
+        MemoryClassesResponse {
+            runtime_classes: runtime_classes
+                .iter()
+                .filter(|runtime_class| match &filter {
+                    Some(filter) => runtime_class
+                        .get_class_name()
+                        .to_ascii_lowercase()
+                        .contains(filter),
+                    None => true,
+                })
+                .cloned()
+                .collect(),
+            success: true,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_classes_request_executor;
@@ -0,0 +1,71 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_request::MemoryDisassembleRequest;
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+use squalr_engine_session::disassembly::disassembler::Disassembler;
+use squalr_engine_session::os::process_address_expression_context::ProcessAddressExpressionContext;
+use std::sync::Arc;
+
+/// The maximum number of instructions that can be disassembled after the address in a single request.
+const DISASSEMBLE_MAX_INSTRUCTION_COUNT: u32 = 4096;
+
+/// The maximum number of instructions that can be disassembled before the address in a single request.
+const DISASSEMBLE_MAX_INSTRUCTIONS_BEFORE: u32 = 64;
+
+impl PrivilegedCommandRequestExecutor for MemoryDisassembleRequest {
+    type ResponseType = MemoryDisassembleResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => return MemoryDisassembleResponse::default(),
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let address = if !self.address_expression.is_empty() {
+            match ProcessAddressExpressionContext::new(os_providers, &process_info).resolve(&self.address_expression) {
+                Ok(address) => address,
+                Err(error) => {
+                    log::error!("Failed to resolve address expression '{}': {}", self.address_expression, error);
+                    return MemoryDisassembleResponse::default();
+                }
+            }
+        } else if self.module_name.is_empty() {
+            self.address
+        } else {
+            let modules = os_providers.memory_query.get_modules(&process_info);
+
+            os_providers
+                .memory_query
+                .resolve_module(&modules, &self.module_name)
+                .saturating_add(self.address)
+        };
+        let instruction_set = self
+            .instruction_set
+            .unwrap_or_else(|| InstructionSet::from_bitness(process_info.get_bitness()));
+        let instructions = Disassembler::disassemble(
+            os_providers,
+            &process_info,
+            address,
+            instruction_set,
+            self.instructions_before
+                .min(DISASSEMBLE_MAX_INSTRUCTIONS_BEFORE) as usize,
+            self.instruction_count.min(DISASSEMBLE_MAX_INSTRUCTION_COUNT) as usize,
+        );
+
+        MemoryDisassembleResponse {
+            address,
+            instruction_set: Some(instruction_set),
+            success: instructions
+                .iter()
+                .any(|instruction| instruction.get_address() >= address),
+            instructions,
+        }
+    }
+}
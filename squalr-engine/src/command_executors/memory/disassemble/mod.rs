@@ -0,0 +1 @@
+pub mod memory_disassemble_request_executor;
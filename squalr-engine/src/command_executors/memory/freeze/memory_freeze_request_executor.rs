@@ -2,7 +2,10 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::memory::freeze::memory_freeze_request::MemoryFreezeRequest;
 use squalr_engine_api::commands::memory::freeze::memory_freeze_response::MemoryFreezeResponse;
-use squalr_engine_api::structures::memory::pointer::Pointer;
+use squalr_engine_api::registries::freeze_list::freeze_list_entry::FreezeListEntry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_session::os::process_address_expression_context::ProcessAddressExpressionContext;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for MemoryFreezeRequest {
@@ -17,16 +20,13 @@ impl PrivilegedCommandRequestExecutor for MemoryFreezeRequest {
             Ok(freeze_list_registry_guard) => freeze_list_registry_guard,
             Err(error) => {
                 log::error!("Failed to acquire write lock on freeze registry for memory freeze request: {}", error);
-                return MemoryFreezeResponse {
-                    failed_freeze_target_count: self.freeze_targets.len() as u64,
-                };
+                return MemoryFreezeResponse::all_failed(self.freeze_targets.len());
             }
         };
 
         if !self.is_frozen {
             for freeze_target in &self.freeze_targets {
-                let pointer = Pointer::new(freeze_target.address, vec![], freeze_target.module_name.clone());
-                freeze_list_registry_guard.set_address_unfrozen(&pointer);
+                freeze_list_registry_guard.set_address_unfrozen(&freeze_target.to_pointer());
             }
 
             return MemoryFreezeResponse::default();
@@ -37,51 +37,69 @@ impl PrivilegedCommandRequestExecutor for MemoryFreezeRequest {
             .get_opened_process()
         else {
             log::warn!("Cannot freeze memory targets without an opened process.");
-            return MemoryFreezeResponse {
-                failed_freeze_target_count: self.freeze_targets.len() as u64,
-            };
+            return MemoryFreezeResponse::all_failed(self.freeze_targets.len());
         };
 
         let os_providers = engine_privileged_state.get_os_providers();
         let modules = os_providers.memory_query.get_modules(&opened_process_info);
+        let address_expression_context = ProcessAddressExpressionContext::new_with_modules(os_providers, &opened_process_info, modules.clone());
         let symbol_registry = engine_privileged_state.get_symbol_registry();
         let symbol_registry_guard = match symbol_registry.read() {
             Ok(symbol_registry_guard) => symbol_registry_guard,
             Err(error) => {
                 log::error!("Failed to acquire symbol registry read lock for memory freeze request: {}", error);
-                return MemoryFreezeResponse {
-                    failed_freeze_target_count: self.freeze_targets.len() as u64,
-                };
+                return MemoryFreezeResponse::all_failed(self.freeze_targets.len());
             }
         };
-        let mut failed_freeze_target_count = 0u64;
+        let mut failed_freeze_target_indices = Vec::new();
 
-        for freeze_target in &self.freeze_targets {
+        for (freeze_target_index, freeze_target) in self.freeze_targets.iter().enumerate() {
             let symbolic_struct_definition = match symbol_registry_guard.get(&freeze_target.data_type_id) {
                 Some(symbolic_struct_definition) => symbolic_struct_definition,
                 None => {
-                    failed_freeze_target_count = failed_freeze_target_count.saturating_add(1);
+                    failed_freeze_target_indices.push(freeze_target_index as u64);
                     continue;
                 }
             };
 
             let mut valued_struct = symbolic_struct_definition.get_default_valued_struct(&symbol_registry);
-            let module_base_address = os_providers
-                .memory_query
-                .resolve_module(&modules, &freeze_target.module_name);
-            let absolute_address = module_base_address.saturating_add(freeze_target.address);
+            let pointer = freeze_target.to_pointer();
+            let absolute_address = if pointer.get_address_expression().is_empty() {
+                os_providers
+                    .memory_query
+                    .resolve_module(&modules, pointer.get_module_name())
+                    .saturating_add(pointer.get_address())
+            } else {
+                match address_expression_context.resolve(pointer.get_address_expression()) {
+                    Ok(absolute_address) => absolute_address,
+                    Err(error) => {
+                        log::warn!("Failed to resolve freeze target '{}': {}", pointer.get_address_expression(), error);
+                        failed_freeze_target_indices.push(freeze_target_index as u64);
+                        continue;
+                    }
+                }
+            };
             if !os_providers
                 .memory_read
                 .read_struct(&opened_process_info, absolute_address, &mut valued_struct)
             {
-                failed_freeze_target_count = failed_freeze_target_count.saturating_add(1);
+                failed_freeze_target_indices.push(freeze_target_index as u64);
                 continue;
             }
 
-            let pointer = Pointer::new(freeze_target.address, vec![], freeze_target.module_name.clone());
-            freeze_list_registry_guard.set_address_frozen(pointer, valued_struct.get_bytes());
+            let frozen_value = DataValue::new(DataTypeRef::new(&freeze_target.data_type_id), valued_struct.get_bytes());
+            let freeze_list_entry = match FreezeListEntry::from_policy(frozen_value, &freeze_target.freeze_policy) {
+                Ok(freeze_list_entry) => freeze_list_entry,
+                Err(error) => {
+                    log::error!("Failed to freeze memory target: {}", error);
+                    failed_freeze_target_indices.push(freeze_target_index as u64);
+                    continue;
+                }
+            };
+
+            freeze_list_registry_guard.set_address_frozen(pointer, freeze_list_entry);
         }
 
-        MemoryFreezeResponse { failed_freeze_target_count }
+        MemoryFreezeResponse::from_failed_target_indices(failed_freeze_target_indices)
     }
 }
@@ -0,0 +1,69 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::infer_struct::memory_infer_struct_request::MemoryInferStructRequest;
+use squalr_engine_api::commands::memory::infer_struct::memory_infer_struct_response::MemoryInferStructResponse;
+use squalr_engine_session::os::PageRetrievalMode;
+use squalr_engine_session::runtime_classes::runtime_class_cache::RuntimeClassCache;
+use squalr_engine_session::struct_inference::struct_layout_inferrer::StructLayoutInferrer;
+use std::sync::Arc;
+
+/// The largest range that is analyzed at once, which comfortably covers the objects that are reversed by hand.
+const MAX_INFERENCE_SIZE: u64 = 0x1000;
+
+impl PrivilegedCommandRequestExecutor for MemoryInferStructRequest {
+    type ResponseType = MemoryInferStructResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process available to infer struct layouts in.");
+                return MemoryInferStructResponse::default();
+            }
+        };
+
+        if self.size == 0 || self.size > MAX_INFERENCE_SIZE {
+            log::error!("Struct inference size must be between 1 and 0x{:X} bytes.", MAX_INFERENCE_SIZE);
+            return MemoryInferStructResponse::default();
+        }
+
+        let os_providers = engine_privileged_state.get_os_providers();
+        let mut bytes = vec![0u8; self.size as usize];
+
+        if !os_providers
+            .memory_read
+            .read_bytes(&process_info, self.address, &mut bytes)
+        {
+            log::error!("Failed to read memory at 0x{:X} to infer a struct layout.", self.address);
+            return MemoryInferStructResponse::default();
+        }
+
+        let modules = os_providers.memory_query.get_modules(&process_info);
+        let regions = os_providers
+            .memory_query
+            .get_memory_page_bounds(&process_info, PageRetrievalMode::FromNonModules);
+        let inferred_fields = StructLayoutInferrer::infer_fields(
+            &bytes,
+            RuntimeClassCache::get_pointer_size(&process_info),
+            &modules,
+            &regions,
+            &|vtable_address| {
+                os_providers
+                    .runtime_classes
+                    .find_runtime_class_by_vtable(&process_info, vtable_address)
+            },
+        );
+        let symbolic_struct_definition = StructLayoutInferrer::create_struct_definition(self.struct_name.trim(), &inferred_fields);
+
+        MemoryInferStructResponse {
+            inferred_fields,
+            symbolic_struct_definition: Some(symbolic_struct_definition),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_infer_struct_request_executor;
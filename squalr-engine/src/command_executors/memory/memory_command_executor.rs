@@ -13,15 +13,39 @@ impl PrivilegedCommandExecutor for MemoryCommand {
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
         match self {
+            MemoryCommand::BuildIds { memory_build_ids_request } => memory_build_ids_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Classes { memory_classes_request } => memory_classes_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Disassemble { memory_disassemble_request } => memory_disassemble_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             MemoryCommand::Freeze { memory_freeze_request } => memory_freeze_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            MemoryCommand::InferStruct { memory_infer_struct_request } => memory_infer_struct_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Patch { memory_patch_request } => memory_patch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             MemoryCommand::Write { memory_write_request } => memory_write_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
             MemoryCommand::Read { memory_read_request } => memory_read_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            MemoryCommand::ReadRange { memory_read_range_request } => memory_read_range_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Resolve { memory_resolve_request } => memory_resolve_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Symbols { memory_symbols_request } => memory_symbols_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
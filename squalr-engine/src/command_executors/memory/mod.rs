@@ -1,4 +1,12 @@
+pub mod build_ids;
+pub mod classes;
+pub mod disassemble;
 pub mod freeze;
+pub mod infer_struct;
 pub mod memory_command_executor;
+pub mod patch;
 pub mod read;
+pub mod read_range;
+pub mod resolve;
+pub mod symbols;
 pub mod write;
@@ -0,0 +1,102 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::patch::memory_patch_request::MemoryPatchRequest;
+use squalr_engine_api::commands::memory::patch::memory_patch_response::MemoryPatchResponse;
+use squalr_engine_api::structures::code_patches::code_patch_error::CodePatchError;
+use squalr_engine_api::structures::memory::pointer::Pointer;
+use squalr_engine_session::code_patches::code_patcher::CodePatcher;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryPatchRequest {
+    type ResponseType = MemoryPatchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let code_patch_registry = engine_privileged_state.get_code_patch_registry();
+        let mut code_patch_registry_guard = match code_patch_registry.write() {
+            Ok(code_patch_registry_guard) => code_patch_registry_guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on code patch registry for memory patch request: {}", error);
+                return MemoryPatchResponse::all_failed(self.patch_targets.len());
+            }
+        };
+        let opened_process_info = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process();
+        let os_providers = engine_privileged_state.get_os_providers();
+        let mut failed_patch_target_indices = Vec::new();
+
+        if !self.is_patched {
+            for (patch_target_index, patch_target) in self.patch_targets.iter().enumerate() {
+                let pointer = Pointer::new(patch_target.address, vec![], patch_target.module_name.clone());
+                let Some(applied_code_patch) = code_patch_registry_guard.remove_applied_patch(&pointer) else {
+                    continue;
+                };
+
+                // Patches applied to a process that has since exited have nothing left to restore.
+                let Some(opened_process_info) = opened_process_info
+                    .as_ref()
+                    .filter(|opened_process_info| opened_process_info.get_process_id_raw() == applied_code_patch.get_process_id())
+                else {
+                    continue;
+                };
+
+                if let Err(error) = CodePatcher::restore(os_providers, opened_process_info, &applied_code_patch) {
+                    log::error!("Failed to restore code patch at 0x{:X}: {}", applied_code_patch.get_address(), error);
+                    failed_patch_target_indices.push(patch_target_index as u64);
+                }
+            }
+
+            return MemoryPatchResponse::from_failed_target_indices(failed_patch_target_indices);
+        }
+
+        let Some(opened_process_info) = opened_process_info else {
+            log::warn!("Cannot patch memory targets without an opened process.");
+            return MemoryPatchResponse::all_failed(self.patch_targets.len());
+        };
+        let modules = os_providers.memory_query.get_modules(&opened_process_info);
+
+        for (patch_target_index, patch_target) in self.patch_targets.iter().enumerate() {
+            let pointer = Pointer::new(patch_target.address, vec![], patch_target.module_name.clone());
+
+            if code_patch_registry_guard
+                .get_applied_patch(&pointer)
+                .is_some_and(|applied_code_patch| applied_code_patch.get_process_id() == opened_process_info.get_process_id_raw())
+            {
+                continue;
+            }
+
+            let module_base_address = os_providers
+                .memory_query
+                .resolve_module(&modules, &patch_target.module_name);
+            let patch_result = if !patch_target.module_name.is_empty() && module_base_address == 0 {
+                Err(CodePatchError::module_not_loaded(&patch_target.module_name))
+            } else {
+                CodePatcher::apply(
+                    os_providers,
+                    &opened_process_info,
+                    module_base_address.saturating_add(patch_target.address),
+                    &patch_target.original_bytes,
+                    &patch_target.replacement,
+                )
+            };
+
+            match patch_result {
+                Ok(applied_code_patch) => code_patch_registry_guard.set_patch_applied(pointer, applied_code_patch),
+                Err(error) => {
+                    log::error!(
+                        "Failed to apply code patch at {}+0x{:X}: {}",
+                        patch_target.module_name,
+                        patch_target.address,
+                        error
+                    );
+                    failed_patch_target_indices.push(patch_target_index as u64);
+                }
+            }
+        }
+
+        MemoryPatchResponse::from_failed_target_indices(failed_patch_target_indices)
+    }
+}
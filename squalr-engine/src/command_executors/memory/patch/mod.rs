@@ -0,0 +1 @@
+pub mod memory_patch_request_executor;
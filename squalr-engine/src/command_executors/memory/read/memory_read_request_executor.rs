@@ -5,6 +5,7 @@ use squalr_engine_api::commands::memory::read::memory_read_response::MemoryReadR
 use squalr_engine_api::registries::registry_context::RegistryContext;
 use squalr_engine_api::structures::structs::symbolic_struct_ref::SymbolicStructRef;
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
+use squalr_engine_session::os::process_address_expression_context::ProcessAddressExpressionContext;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for MemoryReadRequest {
@@ -21,7 +22,11 @@ impl PrivilegedCommandRequestExecutor for MemoryReadRequest {
             let os_providers = engine_privileged_state.get_os_providers();
 
             if !self.suppress_logging {
-                log::info!("Reading value from address {}", self.address);
+                if self.address_expression.is_empty() {
+                    log::info!("Reading value from address {}", self.address);
+                } else {
+                    log::info!("Reading value from address expression {}", self.address_expression);
+                }
             }
 
             let symbol_registry = engine_privileged_state.get_registries().get_symbol_registry();
@@ -29,7 +34,32 @@ impl PrivilegedCommandRequestExecutor for MemoryReadRequest {
                 .symbolic_struct_definition
                 .get_default_valued_struct(&symbol_registry);
 
-            if !self.module_name.is_empty() {
+            if !self.address_expression.is_empty() {
+                match ProcessAddressExpressionContext::new(os_providers, &process_info).resolve(&self.address_expression) {
+                    Ok(address) => {
+                        let success = os_providers
+                            .memory_read
+                            .read_struct(&process_info, address, &mut out_valued_struct);
+
+                        MemoryReadResponse {
+                            valued_struct: out_valued_struct,
+                            address,
+                            success,
+                        }
+                    }
+                    Err(error) => {
+                        if !self.suppress_logging {
+                            log::error!("Failed to resolve address expression '{}': {}", self.address_expression, error);
+                        }
+
+                        MemoryReadResponse {
+                            valued_struct: out_valued_struct,
+                            address: self.address,
+                            success: false,
+                        }
+                    }
+                }
+            } else if !self.module_name.is_empty() {
                 let modules = if let Some(opened_process_info) = engine_privileged_state
                     .get_process_manager()
                     .get_opened_process()
@@ -0,0 +1,81 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_request::MemoryReadRangeRequest;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+use squalr_engine_session::os::process_address_expression_context::ProcessAddressExpressionContext;
+use std::sync::Arc;
+
+/// The granularity at which ranges are read, such that a single unreadable page does not fail the entire range.
+const READ_RANGE_PAGE_SIZE: u64 = 0x1000;
+
+/// The maximum number of bytes that can be read in a single request.
+const READ_RANGE_MAX_LENGTH: u64 = 0x10_0000;
+
+impl PrivilegedCommandRequestExecutor for MemoryReadRangeRequest {
+    type ResponseType = MemoryReadRangeResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => return MemoryReadRangeResponse::default(),
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let base_address = if !self.address_expression.is_empty() {
+            match ProcessAddressExpressionContext::new(os_providers, &process_info).resolve(&self.address_expression) {
+                Ok(address) => address,
+                Err(error) => {
+                    log::error!("Failed to resolve address expression '{}': {}", self.address_expression, error);
+                    return MemoryReadRangeResponse::default();
+                }
+            }
+        } else if self.module_name.is_empty() {
+            self.address
+        } else {
+            let modules = os_providers.memory_query.get_modules(&process_info);
+
+            os_providers
+                .memory_query
+                .resolve_module(&modules, &self.module_name)
+                .saturating_add(self.address)
+        };
+        let length = self.length.min(READ_RANGE_MAX_LENGTH);
+        let end_address = base_address.saturating_add(length);
+        let mut bytes = vec![0u8; (end_address - base_address) as usize];
+        let mut readable = vec![false; bytes.len()];
+        let mut chunk_address = base_address;
+
+        while chunk_address < end_address {
+            let page_end_address = (chunk_address / READ_RANGE_PAGE_SIZE)
+                .saturating_add(1)
+                .saturating_mul(READ_RANGE_PAGE_SIZE);
+            let chunk_end_address = page_end_address.min(end_address);
+            let chunk_start_offset = (chunk_address - base_address) as usize;
+            let chunk_end_offset = (chunk_end_address - base_address) as usize;
+            let chunk = &mut bytes[chunk_start_offset..chunk_end_offset];
+
+            if os_providers
+                .memory_read
+                .read_bytes(&process_info, chunk_address, chunk)
+            {
+                readable[chunk_start_offset..chunk_end_offset].fill(true);
+            } else {
+                chunk.fill(0);
+            }
+
+            chunk_address = chunk_end_address;
+        }
+
+        MemoryReadRangeResponse {
+            address: base_address,
+            success: readable.iter().any(|is_readable| *is_readable),
+            bytes,
+            readable,
+        }
+    }
+}
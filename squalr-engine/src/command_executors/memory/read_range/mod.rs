@@ -0,0 +1 @@
+pub mod memory_read_range_request_executor;
@@ -0,0 +1,50 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::resolve::memory_resolve_request::MemoryResolveRequest;
+use squalr_engine_api::commands::memory::resolve::memory_resolve_response::MemoryResolveResponse;
+use squalr_engine_session::os::process_address_expression_context::ProcessAddressExpressionContext;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryResolveRequest {
+    type ResponseType = MemoryResolveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process available to resolve address expressions against.");
+                return MemoryResolveResponse::default();
+            }
+        };
+        let address_expression_context = ProcessAddressExpressionContext::new(engine_privileged_state.get_os_providers(), &process_info);
+
+        match address_expression_context.resolve(&self.address_expression) {
+            Ok(address) => {
+                let (module_name, module_offset) = address_expression_context
+                    .address_to_module(address)
+                    .unwrap_or((String::new(), address));
+                let symbol_label = address_expression_context
+                    .address_to_symbol_label(address)
+                    .unwrap_or_default();
+
+                MemoryResolveResponse {
+                    address,
+                    module_name,
+                    module_offset,
+                    symbol_label,
+                    success: true,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to resolve address expression '{}': {}", self.address_expression, error);
+                MemoryResolveResponse::default()
+            }
+        }
+    }
+}
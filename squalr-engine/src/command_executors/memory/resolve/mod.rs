@@ -0,0 +1 @@
+pub mod memory_resolve_request_executor;
@@ -0,0 +1,56 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::symbols::memory_symbols_request::MemorySymbolsRequest;
+use squalr_engine_api::commands::memory::symbols::memory_symbols_response::MemorySymbolsResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemorySymbolsRequest {
+    type ResponseType = MemorySymbolsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process available to look up module symbols in.");
+                return MemorySymbolsResponse::default();
+            }
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let module_name = self.module_name.trim();
+        let module = match os_providers
+            .memory_query
+            .get_modules(&process_info)
+            .into_iter()
+            .find(|module| {
+                module
+                    .get_module_name()
+                    .trim()
+                    .eq_ignore_ascii_case(module_name)
+            }) {
+            Some(module) => module,
+            None => {
+                log::error!("Module '{}' is not loaded.", module_name);
+                return MemorySymbolsResponse::default();
+            }
+        };
+        let module_symbol_table = os_providers
+            .module_symbols
+            .get_module_symbol_table(&process_info, &module)
+            .map(|module_symbol_table| match &self.filter {
+                Some(filter) => module_symbol_table.with_symbols(module_symbol_table.filter_symbols(filter)),
+                None => module_symbol_table.as_ref().clone(),
+            });
+
+        if module_symbol_table.is_none() {
+            log::error!("Failed to load symbols for module '{}'.", module_name);
+        }
+
+        MemorySymbolsResponse { module_symbol_table }
+    }
+}
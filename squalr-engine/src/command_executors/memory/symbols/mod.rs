@@ -0,0 +1 @@
+pub mod memory_symbols_request_executor;
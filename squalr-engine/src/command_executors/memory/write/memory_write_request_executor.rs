@@ -2,6 +2,7 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use squalr_engine_api::commands::memory::write::memory_write_response::MemoryWriteResponse;
+use squalr_engine_session::os::process_address_expression_context::ProcessAddressExpressionContext;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for MemoryWriteRequest {
@@ -17,7 +18,19 @@ impl PrivilegedCommandRequestExecutor for MemoryWriteRequest {
         {
             let os_providers = engine_privileged_state.get_os_providers();
 
-            if !self.module_name.is_empty() {
+            if !self.address_expression.is_empty() {
+                let success = match ProcessAddressExpressionContext::new(os_providers, &process_info).resolve(&self.address_expression) {
+                    Ok(address) => os_providers
+                        .memory_write
+                        .write_bytes(&process_info, address, &self.value),
+                    Err(error) => {
+                        log::error!("Failed to resolve address expression '{}': {}", self.address_expression, error);
+                        false
+                    }
+                };
+
+                MemoryWriteResponse { success }
+            } else if !self.module_name.is_empty() {
                 let modules = if let Some(opened_process_info) = engine_privileged_state
                     .get_process_manager()
                     .get_opened_process()
@@ -1,4 +1,5 @@
 pub mod memory;
+pub mod plugins;
 pub mod pointer_scan;
 pub mod privileged_command_executor;
 pub mod privileged_request_executor;
@@ -12,3 +13,5 @@ pub mod struct_scan;
 pub mod trackable_tasks;
 pub mod unprivileged_command_executor;
 pub mod unprivileged_request_executor;
+pub mod value_timeline;
+pub mod watchpoint;
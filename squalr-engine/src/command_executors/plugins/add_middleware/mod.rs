@@ -0,0 +1 @@
+pub mod plugins_add_middleware_request_executor;
@@ -0,0 +1,36 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::plugins::add_middleware::plugins_add_middleware_request::PluginsAddMiddlewareRequest;
+use squalr_engine_api::commands::plugins::add_middleware::plugins_add_middleware_response::PluginsAddMiddlewareResponse;
+use squalr_engine_session::os::memory_middleware::signature_memory_middleware::SignatureMemoryMiddleware;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PluginsAddMiddlewareRequest {
+    type ResponseType = PluginsAddMiddlewareResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        if self.region_size == 0 {
+            log::error!("Memory middleware `{}` must have a non-zero region size.", self.middleware_name);
+
+            return PluginsAddMiddlewareResponse::default();
+        }
+
+        match SignatureMemoryMiddleware::new(self.to_definition()) {
+            Some(signature_memory_middleware) => {
+                engine_privileged_state
+                    .get_memory_middleware_registry()
+                    .register(Arc::new(signature_memory_middleware));
+
+                PluginsAddMiddlewareResponse { success: true }
+            }
+            None => {
+                log::error!("Memory middleware `{}` has an invalid signature: {}", self.middleware_name, self.signature);
+
+                PluginsAddMiddlewareResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod plugins_list_request_executor;
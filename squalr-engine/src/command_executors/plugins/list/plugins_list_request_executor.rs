@@ -0,0 +1,20 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::plugins::list::plugins_list_request::PluginsListRequest;
+use squalr_engine_api::commands::plugins::list::plugins_list_response::PluginsListResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PluginsListRequest {
+    type ResponseType = PluginsListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        PluginsListResponse {
+            data_types: engine_privileged_state
+                .get_data_type_plugin_loader()
+                .get_loaded_data_types(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod plugins_list_middleware_request_executor;
@@ -0,0 +1,40 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::plugins::list_middleware::plugins_list_middleware_request::PluginsListMiddlewareRequest;
+use squalr_engine_api::commands::plugins::list_middleware::plugins_list_middleware_response::PluginsListMiddlewareResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PluginsListMiddlewareRequest {
+    type ResponseType = PluginsListMiddlewareResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let memory_middleware_registry = engine_privileged_state.get_memory_middleware_registry();
+
+        if self.refresh {
+            memory_middleware_registry.invalidate();
+        }
+
+        let address_spaces = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process) => {
+                let host_os_providers = engine_privileged_state.get_host_os_providers();
+
+                memory_middleware_registry
+                    .get_address_spaces(&opened_process, host_os_providers.memory_query.as_ref(), host_os_providers.memory_read.as_ref())
+                    .map(|address_spaces| address_spaces.as_ref().clone())
+                    .unwrap_or_default()
+            }
+            None => vec![],
+        };
+
+        PluginsListMiddlewareResponse {
+            middleware_names: memory_middleware_registry.get_middleware_names(),
+            address_spaces,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod plugins_load_request_executor;
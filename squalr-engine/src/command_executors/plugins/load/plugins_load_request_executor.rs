@@ -0,0 +1,35 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::plugins::load::plugins_load_request::PluginsLoadRequest;
+use squalr_engine_api::commands::plugins::load::plugins_load_response::PluginsLoadResponse;
+use squalr_engine_api::events::plugins::data_types_changed::data_types_changed_event::DataTypesChangedEvent;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PluginsLoadRequest {
+    type ResponseType = PluginsLoadResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state.load_data_type_plugin(&self.plugin_path) {
+            Ok(loaded_data_types) => {
+                engine_privileged_state.emit_event(DataTypesChangedEvent {
+                    data_types: engine_privileged_state
+                        .get_data_type_plugin_loader()
+                        .get_loaded_data_types(),
+                });
+
+                PluginsLoadResponse {
+                    success: true,
+                    loaded_data_types,
+                }
+            }
+            Err(error) => {
+                log::error!("{}", error);
+
+                PluginsLoadResponse::default()
+            }
+        }
+    }
+}
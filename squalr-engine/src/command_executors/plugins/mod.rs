@@ -0,0 +1,6 @@
+pub mod add_middleware;
+pub mod list;
+pub mod list_middleware;
+pub mod load;
+pub mod plugins_command_executor;
+pub mod remove_middleware;
@@ -0,0 +1,42 @@
+use crate::{
+    command_executors::{privileged_command_executor::PrivilegedCommandExecutor, privileged_request_executor::PrivilegedCommandRequestExecutor},
+    engine_privileged_state::EnginePrivilegedState,
+};
+use squalr_engine_api::commands::{
+    plugins::plugins_command::PluginsCommand,
+    privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse},
+};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for PluginsCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            PluginsCommand::Load { plugins_load_request } => plugins_load_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PluginsCommand::List { plugins_list_request } => plugins_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PluginsCommand::AddMiddleware {
+                plugins_add_middleware_request,
+            } => plugins_add_middleware_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PluginsCommand::RemoveMiddleware {
+                plugins_remove_middleware_request,
+            } => plugins_remove_middleware_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PluginsCommand::ListMiddleware {
+                plugins_list_middleware_request,
+            } => plugins_list_middleware_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod plugins_remove_middleware_request_executor;
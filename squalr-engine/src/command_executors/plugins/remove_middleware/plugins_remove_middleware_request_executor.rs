@@ -0,0 +1,20 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::plugins::remove_middleware::plugins_remove_middleware_request::PluginsRemoveMiddlewareRequest;
+use squalr_engine_api::commands::plugins::remove_middleware::plugins_remove_middleware_response::PluginsRemoveMiddlewareResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PluginsRemoveMiddlewareRequest {
+    type ResponseType = PluginsRemoveMiddlewareResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        PluginsRemoveMiddlewareResponse {
+            success: engine_privileged_state
+                .get_memory_middleware_registry()
+                .unregister(&self.middleware_name),
+        }
+    }
+}
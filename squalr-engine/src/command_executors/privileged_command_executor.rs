@@ -19,6 +19,7 @@ impl ExecutableCommandPrivileged for PrivilegedCommand {
     ) -> PrivilegedCommandResponse {
         match self {
             PrivilegedCommand::Memory(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Plugins(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Process(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Results(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Scan(command) => command.execute(engine_privileged_state),
@@ -26,6 +27,8 @@ impl ExecutableCommandPrivileged for PrivilegedCommand {
             PrivilegedCommand::StructScan(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Settings(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::TrackableTasks(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::ValueTimeline(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Watchpoint(command) => command.execute(engine_privileged_state),
         }
     }
 }
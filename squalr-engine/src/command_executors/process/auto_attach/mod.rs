@@ -0,0 +1 @@
+pub mod process_auto_attach_request_executor;
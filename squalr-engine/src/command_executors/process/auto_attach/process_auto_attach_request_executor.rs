@@ -0,0 +1,35 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::process::auto_attach::process_auto_attach_request::ProcessAutoAttachRequest;
+use squalr_engine_api::commands::process::auto_attach::process_auto_attach_response::ProcessAutoAttachResponse;
+use squalr_engine_session::os::process_auto_attacher::ProcessAutoAttachTarget;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ProcessAutoAttachRequest {
+    type ResponseType = ProcessAutoAttachResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_name = self
+            .process_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|process_name| !process_name.is_empty());
+        let target = process_name.map(|process_name| ProcessAutoAttachTarget::new(process_name.to_string(), self.match_case));
+        let is_enabled = target.is_some();
+
+        engine_privileged_state
+            .get_process_auto_attacher()
+            .set_target(target);
+
+        // Attach immediately if the target is already running, rather than waiting for the next poll.
+        let opened_process_info = if is_enabled { engine_privileged_state.poll_auto_attach() } else { None };
+
+        ProcessAutoAttachResponse {
+            is_enabled,
+            opened_process_info,
+        }
+    }
+}
@@ -9,6 +9,11 @@ impl PrivilegedCommandRequestExecutor for ProcessCloseRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        // Explicitly closing the process implies the user no longer wants to be re-attached to it.
+        engine_privileged_state
+            .get_process_auto_attacher()
+            .set_target(None);
+
         if let Some(process_info) = engine_privileged_state
             .get_process_manager()
             .get_opened_process()
@@ -19,6 +24,19 @@ impl PrivilegedCommandRequestExecutor for ProcessCloseRequest {
                 process_info.get_handle()
             );
 
+            // Restore any patched code while the process handle is still open.
+            engine_privileged_state.restore_code_patches();
+
+            // Detach any debugger, such that the process keeps running without watchpoints once it is no longer opened.
+            let watchpoints = &engine_privileged_state.get_os_providers().watchpoints;
+
+            if watchpoints.get_attached_process_id() == Some(process_info.get_process_id_raw()) {
+                match watchpoints.detach() {
+                    Ok(()) => {}
+                    Err(error) => log::error!("Failed to detach debugger: {}", error),
+                }
+            }
+
             match engine_privileged_state
                 .get_os_providers()
                 .process_query
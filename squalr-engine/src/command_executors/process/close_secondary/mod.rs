@@ -0,0 +1 @@
+pub mod process_close_secondary_request_executor;
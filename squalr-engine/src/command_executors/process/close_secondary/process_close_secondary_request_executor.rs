@@ -0,0 +1,22 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::process::close_secondary::process_close_secondary_request::ProcessCloseSecondaryRequest;
+use squalr_engine_api::commands::process::close_secondary::process_close_secondary_response::ProcessCloseSecondaryResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ProcessCloseSecondaryRequest {
+    type ResponseType = ProcessCloseSecondaryResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = engine_privileged_state.close_secondary_process();
+
+        if process_info.is_none() {
+            log::error!("No secondary process to close");
+        }
+
+        ProcessCloseSecondaryResponse { process_info }
+    }
+}
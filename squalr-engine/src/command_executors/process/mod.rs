@@ -1,4 +1,7 @@
+pub mod auto_attach;
 pub mod close;
+pub mod close_secondary;
 pub mod list;
 pub mod open;
+pub mod open_secondary;
 pub mod process_command_executor;
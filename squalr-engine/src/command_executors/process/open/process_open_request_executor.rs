@@ -2,6 +2,7 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
 use squalr_engine_api::commands::process::open::process_open_response::ProcessOpenResponse;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_session::os::Pid;
 use squalr_engine_session::os::ProcessQueryOptions;
 use std::sync::Arc;
@@ -13,44 +14,56 @@ impl PrivilegedCommandRequestExecutor for ProcessOpenRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
-        if self.process_id.is_none() && self.search_name.is_none() {
-            log::error!("Error: Neither PID nor search name provided. Cannot open process.");
-            return ProcessOpenResponse { opened_process_info: None };
+        let opened_process_info = find_and_open_process(engine_privileged_state, self.process_id, &self.search_name, self.match_case);
+
+        if let Some(opened_process_info) = &opened_process_info {
+            // Restore any patched code before switching, such that the previously opened process is not left modified.
+            engine_privileged_state.restore_code_patches();
+            engine_privileged_state
+                .get_process_manager()
+                .set_opened_process(opened_process_info.clone());
         }
 
-        log::info!("Opening process...");
-
-        let options = ProcessQueryOptions {
-            search_name: self.search_name.clone(),
-            required_process_id: self.process_id.map(Pid::from_u32),
-            require_windowed: false,
-            match_case: self.match_case,
-            fetch_icons: false,
-            limit: Some(1),
-        };
-
-        let os_providers = engine_privileged_state.get_os_providers();
-        let processes = os_providers.process_query.get_processes(options);
-
-        if let Some(process_info) = processes.first() {
-            match os_providers.process_query.open_process(&process_info) {
-                Ok(opened_process_info) => {
-                    engine_privileged_state
-                        .get_process_manager()
-                        .set_opened_process(opened_process_info.clone());
-
-                    return ProcessOpenResponse {
-                        opened_process_info: Some(opened_process_info),
-                    };
-                }
-                Err(error) => {
-                    log::info!("Failed to open process {}: {}", process_info.get_process_id_raw(), error);
-                }
+        ProcessOpenResponse { opened_process_info }
+    }
+}
+
+/// Opens the first process matching either the process id or search name, without attaching to it.
+pub(crate) fn find_and_open_process(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    process_id: Option<u32>,
+    search_name: &Option<String>,
+    match_case: bool,
+) -> Option<OpenedProcessInfo> {
+    if process_id.is_none() && search_name.is_none() {
+        log::error!("Error: Neither PID nor search name provided. Cannot open process.");
+        return None;
+    }
+
+    log::info!("Opening process...");
+
+    let options = ProcessQueryOptions {
+        search_name: search_name.clone(),
+        required_process_id: process_id.map(Pid::from_u32),
+        require_windowed: false,
+        match_case,
+        fetch_icons: false,
+        limit: Some(1),
+    };
+
+    let os_providers = engine_privileged_state.get_os_providers();
+    let processes = os_providers.process_query.get_processes(options);
+
+    if let Some(process_info) = processes.first() {
+        match os_providers.process_query.open_process(&process_info) {
+            Ok(opened_process_info) => return Some(opened_process_info),
+            Err(error) => {
+                log::info!("Failed to open process {}: {}", process_info.get_process_id_raw(), error);
             }
-        } else {
-            log::error!("No matching process found.");
         }
-
-        ProcessOpenResponse { opened_process_info: None }
+    } else {
+        log::error!("No matching process found.");
     }
+
+    None
 }
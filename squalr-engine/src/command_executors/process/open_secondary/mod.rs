@@ -0,0 +1 @@
+pub mod process_open_secondary_request_executor;
@@ -0,0 +1,37 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::process::open::process_open_request_executor::find_and_open_process;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::process::open_secondary::process_open_secondary_request::ProcessOpenSecondaryRequest;
+use squalr_engine_api::commands::process::open_secondary::process_open_secondary_response::ProcessOpenSecondaryResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ProcessOpenSecondaryRequest {
+    type ResponseType = ProcessOpenSecondaryResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(opened_process_info) = find_and_open_process(engine_privileged_state, self.process_id, &self.search_name, self.match_case) else {
+            return ProcessOpenSecondaryResponse::default();
+        };
+        let process_manager = engine_privileged_state.get_process_manager();
+
+        if process_manager
+            .get_opened_process()
+            .is_some_and(|primary_process_info| primary_process_info.get_process_id_raw() == opened_process_info.get_process_id_raw())
+        {
+            log::error!("The secondary process must differ from the opened process.");
+
+            return ProcessOpenSecondaryResponse::default();
+        }
+
+        // Only one secondary process is attached at a time, so any prior one is released along with its snapshot.
+        engine_privileged_state.close_secondary_process();
+        process_manager.set_secondary_process(opened_process_info.clone());
+
+        ProcessOpenSecondaryResponse {
+            opened_process_info: Some(opened_process_info),
+        }
+    }
+}
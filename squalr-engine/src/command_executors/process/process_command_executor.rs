@@ -22,6 +22,19 @@ impl PrivilegedCommandExecutor for ProcessCommand {
             ProcessCommand::Close { process_close_request } => process_close_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ProcessCommand::OpenSecondary {
+                process_open_secondary_request,
+            } => process_open_secondary_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::CloseSecondary {
+                process_close_secondary_request,
+            } => process_close_secondary_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ProcessCommand::AutoAttach { process_auto_attach_request } => process_auto_attach_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
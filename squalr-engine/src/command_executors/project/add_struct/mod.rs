@@ -0,0 +1 @@
+pub mod project_add_struct_request_executor;
@@ -0,0 +1,64 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project::add_struct::project_add_struct_request::ProjectAddStructRequest;
+use squalr_engine_api::commands::project::add_struct::project_add_struct_response::ProjectAddStructResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectAddStructRequest {
+    type ResponseType = ProjectAddStructResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let struct_name = self.struct_name.trim();
+
+        if struct_name.is_empty() || self.symbolic_struct_definition.get_fields().is_empty() {
+            log::error!("Cannot add a struct definition without a name and at least one field.");
+            return ProjectAddStructResponse { success: false };
+        }
+
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let opened_project = project_manager.get_opened_project();
+        let mut opened_project_guard = match opened_project.write() {
+            Ok(opened_project_guard) => opened_project_guard,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock for add struct command: {}", error);
+                return ProjectAddStructResponse { success: false };
+            }
+        };
+        let opened_project = match opened_project_guard.as_mut() {
+            Some(opened_project) => opened_project,
+            None => {
+                log::warn!("Cannot add a struct definition without an opened project.");
+                return ProjectAddStructResponse { success: false };
+            }
+        };
+        let project_directory_path = match opened_project.get_project_info().get_project_directory() {
+            Some(project_directory_path) => project_directory_path,
+            None => {
+                log::error!("Failed to resolve opened project directory for add struct operation.");
+                return ProjectAddStructResponse { success: false };
+            }
+        };
+
+        opened_project
+            .get_project_manifest_mut()
+            .set_struct_definition(SymbolicStructDefinition::new(
+                struct_name.to_string(),
+                self.symbolic_struct_definition.get_fields().to_vec(),
+            ));
+        opened_project
+            .get_project_info_mut()
+            .set_has_unsaved_changes(true);
+
+        if let Err(error) = opened_project.save_to_path(&project_directory_path, false) {
+            log::error!("Failed to persist project struct definitions: {}", error);
+            return ProjectAddStructResponse { success: false };
+        }
+
+        ProjectAddStructResponse { success: true }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::command_executors::project::open::project_open_request_executor::dispatch_project_auto_attach_request;
 use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
 use squalr_engine_api::commands::project::close::project_close_request::ProjectCloseRequest;
 use squalr_engine_api::commands::project::close::project_close_response::ProjectCloseResponse;
@@ -15,7 +16,20 @@ impl UnprivilegedCommandRequestExecutor for ProjectCloseRequest {
         let opened_project = project_manager.get_opened_project();
 
         if let Ok(mut opened_project) = opened_project.write() {
+            let had_auto_attach_process = opened_project.as_ref().is_some_and(|project| {
+                project
+                    .get_project_manifest()
+                    .get_auto_attach_process_name()
+                    .is_some()
+            });
+
             *opened_project = None;
+            drop(opened_project);
+
+            // Stop following the project's process once the project that requested it is closed.
+            if had_auto_attach_process {
+                dispatch_project_auto_attach_request(engine_unprivileged_state, None);
+            }
 
             ProjectCloseResponse { success: true }
         } else {
@@ -76,7 +76,17 @@ impl UnprivilegedCommandRequestExecutor for ProjectCreateRequest {
             };
         }
 
-        let project_info = ProjectInfo::new(project_directory_path.to_path_buf(), None, ProjectManifest::default());
+        let mut project_manifest = ProjectManifest::default();
+
+        project_manifest.set_auto_attach_process_name(
+            self.auto_attach_process_name
+                .as_deref()
+                .map(str::trim)
+                .filter(|process_name| !process_name.is_empty())
+                .map(str::to_string),
+        );
+
+        let project_info = ProjectInfo::new(project_directory_path.to_path_buf(), None, project_manifest);
         let project_root_ref = ProjectItemRef::new(project_directory_path.join(Project::PROJECT_DIR));
         let mut project_items = HashMap::new();
 
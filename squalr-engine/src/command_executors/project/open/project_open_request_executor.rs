@@ -1,11 +1,18 @@
 use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
 use rfd::FileDialog;
+use squalr_engine_api::commands::memory::build_ids::memory_build_ids_request::MemoryBuildIdsRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::process::auto_attach::process_auto_attach_request::ProcessAutoAttachRequest;
 use squalr_engine_api::commands::project::open::project_open_request::ProjectOpenRequest;
 use squalr_engine_api::commands::project::open::project_open_response::ProjectOpenResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::memory::module_build_identity::ModuleBuildIdentity;
 use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_code_patch::ProjectItemTypeCodePatch;
 use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
 use squalr_engine_projects::settings::project_settings_config::ProjectSettingsConfig;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -48,7 +55,20 @@ impl UnprivilegedCommandRequestExecutor for ProjectOpenRequest {
 
         match Project::load_from_path(&project_directory_path) {
             Ok(project) => {
+                let auto_attach_process_name = project
+                    .get_project_manifest()
+                    .get_auto_attach_process_name()
+                    .map(str::to_string);
+
                 *opened_project = Some(project);
+                drop(opened_project);
+
+                if auto_attach_process_name.is_some() {
+                    dispatch_project_auto_attach_request(engine_unprivileged_state, auto_attach_process_name);
+                }
+
+                verify_project_module_builds(engine_unprivileged_state, |_module_name| true);
+
                 ProjectOpenResponse { success: true }
             }
             Err(error) => {
@@ -58,3 +78,110 @@ impl UnprivilegedCommandRequestExecutor for ProjectOpenRequest {
         }
     }
 }
+
+/// Enables or disables auto-attach on the engine for the executable named by a project manifest.
+pub(crate) fn dispatch_project_auto_attach_request(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    process_name: Option<String>,
+) {
+    let process_auto_attach_request = ProcessAutoAttachRequest {
+        process_name,
+        match_case: false,
+    };
+
+    match engine_unprivileged_state.get_bindings().read() {
+        Ok(engine_bindings) => {
+            process_auto_attach_request.send_unprivileged(&*engine_bindings, |process_auto_attach_response| {
+                if let Some(process_info) = process_auto_attach_response.opened_process_info {
+                    log::info!("Auto-attached to project process: {}", process_info.get_name());
+                }
+            });
+        }
+        Err(error) => log::error!("Failed to acquire engine bindings lock for project auto-attach dispatch: {}", error),
+    }
+}
+
+/// Compares the module builds recorded on project items against the loaded modules whose name is accepted by the filter,
+/// warning about items that were recorded against a different build. The engine emits an event for any stale module builds.
+pub(crate) fn verify_project_module_builds(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    module_name_filter: impl Fn(&str) -> bool,
+) {
+    let mut expected_build_identities: Vec<ModuleBuildIdentity> = Vec::new();
+    let mut project_item_names_by_build: HashMap<(String, String), Vec<String>> = HashMap::new();
+
+    match engine_unprivileged_state
+        .get_project_manager()
+        .get_opened_project()
+        .write()
+    {
+        Ok(mut opened_project) => {
+            let Some(opened_project) = opened_project.as_mut() else {
+                return;
+            };
+
+            for project_item in opened_project.get_project_items_mut().values_mut() {
+                let (module_name, module_build_id) = match project_item.get_item_type().get_project_item_type_id() {
+                    ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID => (
+                        ProjectItemTypeAddress::get_field_module(project_item),
+                        ProjectItemTypeAddress::get_field_module_build_id(project_item),
+                    ),
+                    ProjectItemTypeCodePatch::PROJECT_ITEM_TYPE_ID => (
+                        ProjectItemTypeCodePatch::get_field_module(project_item),
+                        ProjectItemTypeCodePatch::get_field_module_build_id(project_item),
+                    ),
+                    _ => continue,
+                };
+
+                if module_name.is_empty() || module_build_id.is_empty() || !module_name_filter(&module_name) {
+                    continue;
+                }
+
+                let project_item_names = project_item_names_by_build
+                    .entry((module_name.to_ascii_lowercase(), module_build_id.clone()))
+                    .or_default();
+
+                if project_item_names.is_empty() {
+                    expected_build_identities.push(ModuleBuildIdentity::new(module_name, module_build_id));
+                }
+
+                project_item_names.push(project_item.get_field_name());
+            }
+        }
+        Err(error) => {
+            log::error!("Failed to acquire opened project lock for module build verification: {}", error);
+            return;
+        }
+    }
+
+    if expected_build_identities.is_empty() {
+        return;
+    }
+
+    let memory_build_ids_request = MemoryBuildIdsRequest {
+        module_names: Vec::new(),
+        expected_build_identities,
+    };
+
+    match engine_unprivileged_state.get_bindings().read() {
+        Ok(engine_bindings) => {
+            memory_build_ids_request.send_unprivileged(&*engine_bindings, move |memory_build_ids_response| {
+                for stale_module_build in &memory_build_ids_response.stale_module_builds {
+                    let build_key = (
+                        stale_module_build.get_module_name().to_ascii_lowercase(),
+                        stale_module_build.get_expected_build_id().to_string(),
+                    );
+
+                    if let Some(project_item_names) = project_item_names_by_build.get(&build_key) {
+                        log::warn!(
+                            "Project items recorded against a different build of '{}' may need to be re-found: {}",
+                            stale_module_build.get_module_name(),
+                            project_item_names.join(", ")
+                        );
+                    }
+                }
+            });
+        }
+        Err(error) => log::error!("Failed to acquire engine bindings lock for module build verification dispatch: {}", error),
+    }
+}
@@ -37,6 +37,9 @@ impl UnprivilegedCommandExecutor for ProjectCommand {
             ProjectCommand::Export { project_export_request } => project_export_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
+            ProjectCommand::AddStruct { project_add_struct_request } => project_add_struct_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
             ProjectCommand::List { project_list_request } => project_list_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
@@ -2,12 +2,17 @@ use crate::command_executors::unprivileged_request_executor::UnprivilegedCommand
 use squalr_engine_api::commands::memory::freeze::memory_freeze_request::MemoryFreezeRequest;
 use squalr_engine_api::commands::memory::freeze::memory_freeze_response::MemoryFreezeResponse;
 use squalr_engine_api::commands::memory::freeze::memory_freeze_target::MemoryFreezeTarget;
+use squalr_engine_api::commands::memory::patch::memory_patch_request::MemoryPatchRequest;
+use squalr_engine_api::commands::memory::patch::memory_patch_response::MemoryPatchResponse;
+use squalr_engine_api::commands::memory::patch::memory_patch_target::MemoryPatchTarget;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
 use squalr_engine_api::commands::project_items::activate::project_items_activate_request::ProjectItemsActivateRequest;
 use squalr_engine_api::commands::project_items::activate::project_items_activate_response::ProjectItemsActivateResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
 use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_code_patch::ProjectItemTypeCodePatch;
+use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
 use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -50,6 +55,9 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsActivateRequest {
         );
         let mut has_activation_changes = false;
         let mut freeze_targets = Vec::new();
+        let mut freeze_target_paths = Vec::new();
+        let mut patch_targets = Vec::new();
+        let mut patch_target_paths = Vec::new();
 
         for (project_item_ref, project_item) in opened_project.get_project_items_mut().iter_mut() {
             if !project_item_paths_for_activation.contains(project_item_ref.get_project_item_path()) {
@@ -61,6 +69,11 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsActivateRequest {
                 has_activation_changes = true;
                 if let Some(freeze_target) = create_memory_freeze_target(project_item) {
                     freeze_targets.push(freeze_target);
+                    freeze_target_paths.push(project_item_ref.get_project_item_path().clone());
+                }
+                if let Some(patch_target) = create_memory_patch_target(project_item) {
+                    patch_targets.push(patch_target);
+                    patch_target_paths.push(project_item_ref.get_project_item_path().clone());
                 }
             }
         }
@@ -68,10 +81,16 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsActivateRequest {
         drop(opened_project_guard);
 
         if has_activation_changes {
-            dispatch_memory_freeze_request(engine_unprivileged_state, &freeze_targets, self.is_activated);
-        }
-
-        if has_activation_changes {
+            let failed_freeze_target_indices = dispatch_memory_freeze_request(engine_unprivileged_state, &freeze_targets, self.is_activated);
+            let failed_patch_target_indices = dispatch_memory_patch_request(engine_unprivileged_state, &patch_targets, self.is_activated);
+            let failed_project_item_paths = collect_failed_project_item_paths(
+                &freeze_target_paths,
+                &failed_freeze_target_indices,
+                &patch_target_paths,
+                &failed_patch_target_indices,
+            );
+
+            revert_project_item_activations(engine_unprivileged_state, &failed_project_item_paths, self.is_activated);
             project_manager.notify_project_items_changed();
         }
 
@@ -79,7 +98,123 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsActivateRequest {
     }
 }
 
-fn collect_project_item_paths_for_activation(
+/// Re-sends freeze and patch requests for activated project items whose module is accepted by the filter, such that freezes
+/// and code patches target a newly attached process or a newly loaded module.
+pub(crate) fn reactivate_project_items(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    module_name_filter: impl Fn(&str) -> bool,
+) {
+    let project_manager = engine_unprivileged_state.get_project_manager();
+    let opened_project_lock = project_manager.get_opened_project();
+    let mut freeze_targets = Vec::new();
+    let mut freeze_target_paths = Vec::new();
+    let mut patch_targets = Vec::new();
+    let mut patch_target_paths = Vec::new();
+
+    match opened_project_lock.write() {
+        Ok(mut opened_project_guard) => {
+            let Some(opened_project) = opened_project_guard.as_mut() else {
+                return;
+            };
+
+            for (project_item_ref, project_item) in opened_project.get_project_items_mut().iter_mut() {
+                if !project_item.get_is_activated() {
+                    continue;
+                }
+
+                if let Some(freeze_target) = create_memory_freeze_target(project_item).filter(|freeze_target| module_name_filter(&freeze_target.module_name)) {
+                    freeze_targets.push(freeze_target);
+                    freeze_target_paths.push(project_item_ref.get_project_item_path().clone());
+                }
+
+                if let Some(patch_target) = create_memory_patch_target(project_item).filter(|patch_target| module_name_filter(&patch_target.module_name)) {
+                    patch_targets.push(patch_target);
+                    patch_target_paths.push(project_item_ref.get_project_item_path().clone());
+                }
+            }
+        }
+        Err(error) => {
+            log::error!("Failed to acquire opened project lock for project-item reactivation: {}", error);
+            return;
+        }
+    }
+
+    let failed_freeze_target_indices = dispatch_memory_freeze_request(engine_unprivileged_state, &freeze_targets, true);
+    let failed_patch_target_indices = dispatch_memory_patch_request(engine_unprivileged_state, &patch_targets, true);
+    let failed_project_item_paths = collect_failed_project_item_paths(
+        &freeze_target_paths,
+        &failed_freeze_target_indices,
+        &patch_target_paths,
+        &failed_patch_target_indices,
+    );
+
+    if revert_project_item_activations(engine_unprivileged_state, &failed_project_item_paths, true) {
+        project_manager.notify_project_items_changed();
+    }
+}
+
+/// Collects the paths of the project items whose freeze or patch targets failed.
+fn collect_failed_project_item_paths(
+    freeze_target_paths: &[PathBuf],
+    failed_freeze_target_indices: &[usize],
+    patch_target_paths: &[PathBuf],
+    failed_patch_target_indices: &[usize],
+) -> HashSet<PathBuf> {
+    failed_freeze_target_indices
+        .iter()
+        .filter_map(|failed_freeze_target_index| freeze_target_paths.get(*failed_freeze_target_index))
+        .chain(
+            failed_patch_target_indices
+                .iter()
+                .filter_map(|failed_patch_target_index| patch_target_paths.get(*failed_patch_target_index)),
+        )
+        .cloned()
+        .collect()
+}
+
+/// Reverts project items at the given paths that are still in the given activation state, such that items whose freeze or patch
+/// failed are never shown as applied. Returns whether any project item was reverted.
+fn revert_project_item_activations(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    project_item_paths: &HashSet<PathBuf>,
+    is_activated: bool,
+) -> bool {
+    if project_item_paths.is_empty() {
+        return false;
+    }
+
+    let project_manager = engine_unprivileged_state.get_project_manager();
+    let opened_project_lock = project_manager.get_opened_project();
+    let mut opened_project_guard = match opened_project_lock.write() {
+        Ok(opened_project_guard) => opened_project_guard,
+        Err(error) => {
+            log::error!("Failed to acquire opened project lock to revert project-item activation: {}", error);
+            return false;
+        }
+    };
+    let Some(opened_project) = opened_project_guard.as_mut() else {
+        return false;
+    };
+    let mut has_reverted_project_items = false;
+
+    for (project_item_ref, project_item) in opened_project.get_project_items_mut().iter_mut() {
+        if project_item.get_is_activated() != is_activated || !project_item_paths.contains(project_item_ref.get_project_item_path()) {
+            continue;
+        }
+
+        log::warn!(
+            "Reverting {} of project item '{}', as its memory could not be updated.",
+            if is_activated { "activation" } else { "deactivation" },
+            project_item.get_field_name()
+        );
+        project_item.toggle_activated();
+        has_reverted_project_items = true;
+    }
+
+    has_reverted_project_items
+}
+
+pub(crate) fn collect_project_item_paths_for_activation(
     all_project_item_paths: &[&PathBuf],
     requested_project_item_paths: &[String],
 ) -> HashSet<PathBuf> {
@@ -101,15 +236,14 @@ fn collect_project_item_paths_for_activation(
     project_item_paths_for_activation
 }
 
-fn create_memory_freeze_target(
-    project_item: &mut squalr_engine_api::structures::projects::project_items::project_item::ProjectItem
-) -> Option<MemoryFreezeTarget> {
+pub(crate) fn create_memory_freeze_target(project_item: &mut ProjectItem) -> Option<MemoryFreezeTarget> {
     if project_item.get_item_type().get_project_item_type_id() != ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID {
         return None;
     }
 
     let address = ProjectItemTypeAddress::get_field_address(project_item);
     let module_name = ProjectItemTypeAddress::get_field_module(project_item);
+    let address_expression = ProjectItemTypeAddress::get_field_address_expression(project_item);
     let data_type_id = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(project_item)?
         .get_symbolic_struct_namespace()
         .to_string();
@@ -121,72 +255,163 @@ fn create_memory_freeze_target(
     Some(MemoryFreezeTarget {
         address,
         module_name,
+        address_expression,
         data_type_id,
+        freeze_policy: ProjectItemTypeAddress::get_field_freeze_policy(project_item),
     })
 }
 
-fn dispatch_memory_freeze_request(
+fn create_memory_patch_target(project_item: &ProjectItem) -> Option<MemoryPatchTarget> {
+    if project_item.get_item_type().get_project_item_type_id() != ProjectItemTypeCodePatch::PROJECT_ITEM_TYPE_ID {
+        return None;
+    }
+
+    let replacement = match ProjectItemTypeCodePatch::get_field_replacement(project_item) {
+        Some(replacement) => replacement,
+        None => {
+            log::warn!(
+                "Skipping code patch project item '{}' with a malformed replacement.",
+                project_item.get_field_name()
+            );
+            return None;
+        }
+    };
+
+    Some(MemoryPatchTarget {
+        address: ProjectItemTypeCodePatch::get_field_address(project_item),
+        module_name: ProjectItemTypeCodePatch::get_field_module(project_item),
+        original_bytes: ProjectItemTypeCodePatch::get_field_original_bytes(project_item),
+        replacement,
+    })
+}
+
+/// Dispatches a freeze request, returning the indices of the targets that failed, which is every target if no response arrives.
+pub(crate) fn dispatch_memory_freeze_request(
     engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
     freeze_targets: &[MemoryFreezeTarget],
     is_frozen: bool,
-) {
+) -> Vec<usize> {
     if freeze_targets.is_empty() {
-        return;
+        return Vec::new();
     }
 
     let memory_freeze_request = MemoryFreezeRequest {
         freeze_targets: freeze_targets.to_vec(),
         is_frozen,
     };
-    let memory_freeze_command = memory_freeze_request.to_engine_command();
-    let (freeze_response_sender, freeze_response_receiver) = mpsc::channel();
+
+    let failed_freeze_target_indices =
+        match dispatch_and_wait::<MemoryFreezeRequest, MemoryFreezeResponse>(engine_unprivileged_state, &memory_freeze_request, "freeze") {
+            Some(memory_freeze_response) => to_target_indices(&memory_freeze_response.failed_freeze_target_indices, freeze_targets.len()),
+            None => (0..freeze_targets.len()).collect(),
+        };
+
+    if !failed_freeze_target_indices.is_empty() {
+        log::warn!(
+            "Project-item activation freeze request failed for {} targets.",
+            failed_freeze_target_indices.len()
+        );
+    }
+
+    failed_freeze_target_indices
+}
+
+/// Dispatches a patch request, returning the indices of the targets that failed, which is every target if no response arrives.
+fn dispatch_memory_patch_request(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    patch_targets: &[MemoryPatchTarget],
+    is_patched: bool,
+) -> Vec<usize> {
+    if patch_targets.is_empty() {
+        return Vec::new();
+    }
+
+    let memory_patch_request = MemoryPatchRequest {
+        patch_targets: patch_targets.to_vec(),
+        is_patched,
+    };
+
+    let failed_patch_target_indices =
+        match dispatch_and_wait::<MemoryPatchRequest, MemoryPatchResponse>(engine_unprivileged_state, &memory_patch_request, "patch") {
+            Some(memory_patch_response) => to_target_indices(&memory_patch_response.failed_patch_target_indices, patch_targets.len()),
+            None => (0..patch_targets.len()).collect(),
+        };
+
+    if !failed_patch_target_indices.is_empty() {
+        log::warn!(
+            "Project-item activation patch request failed for {} targets.",
+            failed_patch_target_indices.len()
+        );
+    }
+
+    failed_patch_target_indices
+}
+
+/// Converts target indices reported by a response, discarding any that do not refer to one of the requested targets.
+fn to_target_indices(
+    target_indices: &[u64],
+    target_count: usize,
+) -> Vec<usize> {
+    target_indices
+        .iter()
+        .filter_map(|target_index| usize::try_from(*target_index).ok())
+        .filter(|target_index| *target_index < target_count)
+        .collect()
+}
+
+/// Dispatches a privileged request and blocks until its response arrives, such that activation completes before returning.
+pub(crate) fn dispatch_and_wait<Request, Response>(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    request: &Request,
+    request_name: &str,
+) -> Option<Response>
+where
+    Request: PrivilegedCommandRequest<ResponseType = Response>,
+    Response: TypedPrivilegedCommandResponse + Send + 'static,
+{
+    let command = request.to_engine_command();
+    let (response_sender, response_receiver) = mpsc::channel();
 
     let dispatch_result = match engine_unprivileged_state.get_bindings().read() {
         Ok(engine_bindings) => engine_bindings.dispatch_privileged_command(
-            memory_freeze_command,
+            command,
             Box::new(move |engine_response| {
-                let conversion_result = match MemoryFreezeResponse::from_engine_response(engine_response) {
-                    Ok(memory_freeze_response) => Ok(memory_freeze_response),
-                    Err(unexpected_response) => Err(format!(
-                        "Unexpected response variant for project-items activation freeze request: {:?}",
-                        unexpected_response
-                    )),
-                };
-                let _ = freeze_response_sender.send(conversion_result);
+                let conversion_result = Response::from_engine_response(engine_response)
+                    .map_err(|unexpected_response| format!("Unexpected response variant: {:?}", unexpected_response));
+                let _ = response_sender.send(conversion_result);
             }),
         ),
         Err(error) => {
-            log::error!("Failed to acquire engine bindings lock for project-item activation freeze dispatch: {}", error);
-            return;
+            log::error!(
+                "Failed to acquire engine bindings lock for project-item activation {} dispatch: {}",
+                request_name,
+                error
+            );
+            return None;
         }
     };
 
     if let Err(error) = dispatch_result {
-        log::error!("Failed to dispatch project-item activation freeze request: {}", error);
-        return;
+        log::error!("Failed to dispatch project-item activation {} request: {}", request_name, error);
+        return None;
     }
 
-    match freeze_response_receiver.recv_timeout(Duration::from_secs(5)) {
-        Ok(Ok(memory_freeze_response)) => {
-            if memory_freeze_response.failed_freeze_target_count > 0 {
-                log::warn!(
-                    "Project-item activation freeze request failed for {} targets.",
-                    memory_freeze_response.failed_freeze_target_count
-                );
-            }
-        }
+    match response_receiver.recv_timeout(Duration::from_secs(5)) {
+        Ok(Ok(response)) => Some(response),
         Ok(Err(error)) => {
-            log::error!("Failed to convert project-item activation freeze response: {}", error);
+            log::error!("Failed to convert project-item activation {} response: {}", request_name, error);
+            None
         }
         Err(error) => {
-            log::error!("Timed out waiting for project-item activation freeze response: {}", error);
+            log::error!("Timed out waiting for project-item activation {} response: {}", request_name, error);
+            None
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{collect_project_item_paths_for_activation, create_memory_freeze_target};
+    use super::{collect_failed_project_item_paths, collect_project_item_paths_for_activation, create_memory_freeze_target, to_target_indices};
     use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
     use squalr_engine_api::structures::projects::project_items::built_in_types::{
         project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory,
@@ -230,6 +455,25 @@ mod tests {
         assert!(collected_paths.is_empty());
     }
 
+    #[test]
+    fn collect_failed_project_item_paths_maps_failed_freeze_and_patch_targets_to_their_items() {
+        let project_item_root_path = PathBuf::from(r"C:\Project\Items");
+        let health_path = project_item_root_path.join("Health.json");
+        let ammo_path = project_item_root_path.join("Ammo.json");
+        let god_mode_path = project_item_root_path.join("GodMode.json");
+        let failed_project_item_paths = collect_failed_project_item_paths(
+            &[health_path.clone(), ammo_path.clone()],
+            &to_target_indices(&[1, 7], 2),
+            std::slice::from_ref(&god_mode_path),
+            &to_target_indices(&[0], 1),
+        );
+
+        assert_eq!(failed_project_item_paths.len(), 2);
+        assert!(failed_project_item_paths.contains(&ammo_path));
+        assert!(failed_project_item_paths.contains(&god_mode_path));
+        assert!(!failed_project_item_paths.contains(&health_path));
+    }
+
     #[test]
     fn create_memory_freeze_target_uses_address_project_item_values() {
         let mut address_project_item = ProjectItemTypeAddress::new_project_item("Health", 0x579C, "winmine.exe", "", DataTypeU8::get_value_from_primitive(0));
@@ -241,6 +485,17 @@ mod tests {
         assert_eq!(freeze_target.data_type_id, "u8");
     }
 
+    #[test]
+    fn create_memory_freeze_target_carries_address_expression() {
+        let mut address_project_item = ProjectItemTypeAddress::new_project_item("Ammo", 0, "", "", DataTypeU8::get_value_from_primitive(0));
+        ProjectItemTypeAddress::set_field_address_expression(&mut address_project_item, "[game.exe+10]+4");
+
+        let freeze_target = create_memory_freeze_target(&mut address_project_item).expect("Expected address project item to produce a freeze target.");
+
+        assert_eq!(freeze_target.address_expression, "[game.exe+10]+4");
+        assert_eq!(freeze_target.to_pointer().get_address_expression(), "[game.exe+10]+4");
+    }
+
     #[test]
     fn create_memory_freeze_target_skips_non_address_project_items() {
         let directory_project_item_ref = ProjectItemRef::new(PathBuf::from(r"C:\Project\Items\Folder"));
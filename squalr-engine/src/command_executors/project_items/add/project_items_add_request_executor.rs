@@ -1,3 +1,4 @@
+use crate::command_executors::project_items::create::project_items_create_request_executor::request_module_build_identities;
 use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
@@ -7,6 +8,7 @@ use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_req
 use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_response::ScanResultsRefreshResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::memory::module_build_identity::ModuleBuildIdentity;
 use squalr_engine_api::structures::projects::project::Project;
 use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
 use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_directory::ProjectItemTypeDirectory;
@@ -44,6 +46,23 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsAddRequest {
             }
         };
 
+        let mut module_names: Vec<String> = Vec::new();
+
+        for scan_result in &scan_results {
+            if !scan_result.get_module().is_empty()
+                && !module_names
+                    .iter()
+                    .any(|module_name| module_name == scan_result.get_module())
+            {
+                module_names.push(scan_result.get_module().to_string());
+            }
+        }
+
+        let module_build_identities = if module_names.is_empty() {
+            Vec::new()
+        } else {
+            request_module_build_identities(engine_unprivileged_state, &module_names)
+        };
         let project_manager = engine_unprivileged_state.get_project_manager();
         let opened_project = project_manager.get_opened_project();
         let mut opened_project = match opened_project.write() {
@@ -80,7 +99,13 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsAddRequest {
             }
         };
 
-        let added_file_paths = add_scan_results_to_project(opened_project, &project_directory_path, &scan_results, &self.target_directory_path);
+        let added_file_paths = add_scan_results_to_project(
+            opened_project,
+            &project_directory_path,
+            &scan_results,
+            &module_build_identities,
+            &self.target_directory_path,
+        );
 
         if added_file_paths.is_empty() {
             return ProjectItemsAddResponse {
@@ -171,6 +196,7 @@ fn add_scan_results_to_project(
     opened_project: &mut Project,
     project_directory_path: &PathBuf,
     scan_results: &[ScanResult],
+    module_build_identities: &[ModuleBuildIdentity],
     target_directory_path: &Option<PathBuf>,
 ) -> Vec<PathBuf> {
     let symbol_registry = SymbolRegistry::get_instance();
@@ -204,7 +230,7 @@ fn add_scan_results_to_project(
         let project_item_ref = ProjectItemRef::new(project_item_absolute_path.clone());
 
         let project_item_name = build_project_item_name(scan_result);
-        let project_item = ProjectItemTypeAddress::new_project_item(
+        let mut project_item = ProjectItemTypeAddress::new_project_item(
             &project_item_name,
             scan_result.get_module_offset(),
             scan_result.get_module(),
@@ -212,6 +238,14 @@ fn add_scan_results_to_project(
             default_data_value,
         );
 
+        if let Some(module_build_identity) = module_build_identities.iter().find(|module_build_identity| {
+            module_build_identity
+                .get_module_name()
+                .eq_ignore_ascii_case(scan_result.get_module())
+        }) {
+            ProjectItemTypeAddress::set_field_module_build_id(&mut project_item, module_build_identity.get_build_id());
+        }
+
         project_items.insert(project_item_ref, project_item);
         added_file_paths.push(project_item_absolute_path);
     }
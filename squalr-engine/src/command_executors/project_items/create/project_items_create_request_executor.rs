@@ -1,13 +1,29 @@
 use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::memory::build_ids::memory_build_ids_request::MemoryBuildIdsRequest;
+use squalr_engine_api::commands::memory::build_ids::memory_build_ids_response::MemoryBuildIdsResponse;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
 use squalr_engine_api::commands::project_items::create::project_items_create_request::ProjectItemsCreateRequest;
 use squalr_engine_api::commands::project_items::create::project_items_create_response::ProjectItemsCreateResponse;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::memory::module_build_identity::ModuleBuildIdentity;
 use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_code_patch::ProjectItemTypeCodePatch;
 use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_directory::ProjectItemTypeDirectory;
+use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
 use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
-use std::fs;
+use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::mpsc;
+use std::time::Duration;
 
 impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateRequest {
     type ResponseType = ProjectItemsCreateResponse;
@@ -16,11 +32,16 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateRequest {
         &self,
         engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
     ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
-        if self.project_item_type != ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID {
+        if self.project_item_type != ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID
+            && self.project_item_type != ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID
+            && self.project_item_type != ProjectItemTypeCodePatch::PROJECT_ITEM_TYPE_ID
+        {
             log::error!(
-                "Unsupported project item type for create command: {}. Only '{}' is currently supported.",
+                "Unsupported project item type for create command: {}. Only '{}', '{}' and '{}' are currently supported.",
                 self.project_item_type,
-                ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID
+                ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID,
+                ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID,
+                ProjectItemTypeCodePatch::PROJECT_ITEM_TYPE_ID
             );
 
             return ProjectItemsCreateResponse {
@@ -29,6 +50,29 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateRequest {
             };
         }
 
+        // Items are created before taking the project lock, as recording the module build identity waits on the privileged engine.
+        let project_item = if self.project_item_type == ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID {
+            None
+        } else {
+            let project_item = if self.project_item_type == ProjectItemTypeCodePatch::PROJECT_ITEM_TYPE_ID {
+                create_code_patch_project_item(self)
+            } else {
+                create_address_project_item(self)
+            };
+
+            match project_item {
+                Some(mut project_item) => {
+                    record_module_build_id(engine_unprivileged_state, &mut project_item);
+                    Some(project_item)
+                }
+                None => {
+                    return ProjectItemsCreateResponse {
+                        success: false,
+                        created_project_item_path: PathBuf::new(),
+                    };
+                }
+            }
+        };
         let project_manager = engine_unprivileged_state.get_project_manager();
         let opened_project = project_manager.get_opened_project();
         let mut opened_project_guard = match opened_project.write() {
@@ -42,7 +86,7 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateRequest {
                 };
             }
         };
-        let opened_project = match opened_project_guard.as_ref() {
+        let opened_project = match opened_project_guard.as_mut() {
             Some(opened_project) => opened_project,
             None => {
                 log::warn!("Cannot create project items without an opened project.");
@@ -65,6 +109,26 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateRequest {
             }
         };
         let parent_directory_path = resolve_project_item_path(&project_directory_path, &self.parent_directory_path);
+
+        if let Some(project_item) = project_item {
+            let created_project_item_path = match insert_project_item(self, opened_project, &project_directory_path, &parent_directory_path, project_item) {
+                Some(created_project_item_path) => created_project_item_path,
+                None => {
+                    return ProjectItemsCreateResponse {
+                        success: false,
+                        created_project_item_path: PathBuf::new(),
+                    };
+                }
+            };
+
+            project_manager.notify_project_items_changed();
+
+            return ProjectItemsCreateResponse {
+                success: true,
+                created_project_item_path,
+            };
+        }
+
         let created_project_item_path = parent_directory_path.join(&self.project_item_name);
 
         if let Err(error) = fs::create_dir_all(&created_project_item_path) {
@@ -92,6 +156,236 @@ impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateRequest {
     }
 }
 
+fn create_address_project_item(project_items_create_request: &ProjectItemsCreateRequest) -> Option<ProjectItem> {
+    // Static expressions are stored as a plain module and offset, whereas pointer expressions are kept as-is and re-evaluated on every read.
+    let (address, module_name, address_expression) = match project_items_create_request.address_expression.as_deref() {
+        Some(address_expression) => match AddressExpression::parse(address_expression) {
+            Ok(address_expression) => match address_expression.to_module_offset() {
+                Some((module_name, address)) => (address, module_name, String::new()),
+                None => (0, String::new(), address_expression.to_string()),
+            },
+            Err(error) => {
+                log::error!("Cannot create an address project item from an invalid address expression: {}", error);
+                return None;
+            }
+        },
+        None => match project_items_create_request.address {
+            Some(address) => (
+                address,
+                project_items_create_request
+                    .module_name
+                    .clone()
+                    .unwrap_or_default(),
+                String::new(),
+            ),
+            None => {
+                log::error!("Cannot create an address project item without an address or address expression.");
+                return None;
+            }
+        },
+    };
+    let data_type_id = project_items_create_request
+        .data_type_id
+        .as_deref()
+        .unwrap_or(DataTypeU8::DATA_TYPE_ID);
+    let default_data_value = match SymbolRegistry::get_instance().get_default_value(&DataTypeRef::new(data_type_id)) {
+        Some(default_data_value) => default_data_value,
+        None => {
+            log::error!("Cannot create an address project item for unsupported data type: {}", data_type_id);
+            return None;
+        }
+    };
+    let mut project_item =
+        ProjectItemTypeAddress::new_project_item(&project_items_create_request.project_item_name, address, &module_name, "", default_data_value);
+
+    ProjectItemTypeAddress::set_field_address_expression(&mut project_item, &address_expression);
+
+    Some(project_item)
+}
+
+fn create_code_patch_project_item(project_items_create_request: &ProjectItemsCreateRequest) -> Option<ProjectItem> {
+    // Code is patched at a fixed location within a module, so pointer expressions are not supported.
+    let (address, module_name) = match project_items_create_request.address_expression.as_deref() {
+        Some(address_expression) => match AddressExpression::parse(address_expression).map(|address_expression| address_expression.to_module_offset()) {
+            Ok(Some((module_name, address))) => (address, module_name),
+            Ok(None) => {
+                log::error!("Cannot create a code patch project item from a pointer expression: {}", address_expression);
+                return None;
+            }
+            Err(error) => {
+                log::error!("Cannot create a code patch project item from an invalid address expression: {}", error);
+                return None;
+            }
+        },
+        None => match project_items_create_request.address {
+            Some(address) => (
+                address,
+                project_items_create_request
+                    .module_name
+                    .clone()
+                    .unwrap_or_default(),
+            ),
+            None => {
+                log::error!("Cannot create a code patch project item without an address or address expression.");
+                return None;
+            }
+        },
+    };
+    let original_bytes = match CodePatchReplacement::parse_bytes(
+        project_items_create_request
+            .original_bytes
+            .as_deref()
+            .unwrap_or_default(),
+    ) {
+        Ok(original_bytes) if !original_bytes.is_empty() => original_bytes,
+        Ok(_) => {
+            log::error!("Cannot create a code patch project item without the original bytes to replace.");
+            return None;
+        }
+        Err(error) => {
+            log::error!("Cannot create a code patch project item with invalid original bytes: {}", error);
+            return None;
+        }
+    };
+    let replacement = project_items_create_request
+        .replacement
+        .clone()
+        .unwrap_or(CodePatchReplacement::Nop);
+
+    Some(ProjectItemTypeCodePatch::new_project_item(
+        &project_items_create_request.project_item_name,
+        address,
+        &module_name,
+        "",
+        &original_bytes,
+        &replacement,
+    ))
+}
+
+/// Records the build identity of the module that an item is relative to, such that the item can be flagged as stale when the module changes.
+fn record_module_build_id(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    project_item: &mut ProjectItem,
+) {
+    let is_code_patch = project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeCodePatch::PROJECT_ITEM_TYPE_ID;
+    let module_name = if is_code_patch {
+        ProjectItemTypeCodePatch::get_field_module(project_item)
+    } else {
+        ProjectItemTypeAddress::get_field_module(project_item)
+    };
+
+    if module_name.trim().is_empty() {
+        return;
+    }
+
+    let Some(module_build_identity) = request_module_build_identities(engine_unprivileged_state, &[module_name])
+        .into_iter()
+        .next()
+    else {
+        return;
+    };
+
+    if is_code_patch {
+        ProjectItemTypeCodePatch::set_field_module_build_id(project_item, module_build_identity.get_build_id());
+    } else {
+        ProjectItemTypeAddress::set_field_module_build_id(project_item, module_build_identity.get_build_id());
+    }
+}
+
+/// Requests the build identities of the given modules from the privileged engine, omitting modules that are not loaded.
+pub(crate) fn request_module_build_identities(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    module_names: &[String],
+) -> Vec<ModuleBuildIdentity> {
+    let memory_build_ids_request = MemoryBuildIdsRequest {
+        module_names: module_names.to_vec(),
+        expected_build_identities: Vec::new(),
+    };
+    let (response_sender, response_receiver) = mpsc::channel();
+    let dispatch_result = match engine_unprivileged_state.get_bindings().read() {
+        Ok(engine_bindings) => engine_bindings.dispatch_privileged_command(
+            memory_build_ids_request.to_engine_command(),
+            Box::new(move |engine_response| {
+                let _ = response_sender.send(MemoryBuildIdsResponse::from_engine_response(engine_response));
+            }),
+        ),
+        Err(error) => {
+            log::error!("Failed to acquire engine bindings lock for module build id request: {}", error);
+            return Vec::new();
+        }
+    };
+
+    if let Err(error) = dispatch_result {
+        log::error!("Failed to dispatch module build id request: {}", error);
+        return Vec::new();
+    }
+
+    match response_receiver.recv_timeout(Duration::from_secs(5)) {
+        Ok(Ok(memory_build_ids_response)) => memory_build_ids_response.module_build_identities,
+        Ok(Err(unexpected_response)) => {
+            log::error!("Unexpected response variant for module build id request: {:?}", unexpected_response);
+            Vec::new()
+        }
+        Err(error) => {
+            log::error!("Timed out waiting for module build ids: {}", error);
+            Vec::new()
+        }
+    }
+}
+
+/// Inserts a project item and saves it to disk. The insert is rolled back if the item cannot be saved, such that the opened project
+/// never holds an item that is missing from disk.
+fn insert_project_item(
+    project_items_create_request: &ProjectItemsCreateRequest,
+    opened_project: &mut Project,
+    project_directory_path: &Path,
+    parent_directory_path: &Path,
+    project_item: ProjectItem,
+) -> Option<PathBuf> {
+    let created_project_item_path = parent_directory_path.join(format!("{}.json", project_items_create_request.project_item_name));
+    let project_item_ref = ProjectItemRef::new(created_project_item_path.clone());
+
+    if opened_project
+        .get_project_items_mut()
+        .contains_key(&project_item_ref)
+    {
+        log::error!("A project item already exists at {:?}.", created_project_item_path);
+        return None;
+    }
+
+    if let Err(error) = fs::create_dir_all(parent_directory_path) {
+        log::error!("Failed to create project item parent directory {:?}: {}", parent_directory_path, error);
+        return None;
+    }
+
+    if let Err(error) = File::create(&created_project_item_path) {
+        log::error!("Failed to create project item file {:?}: {}", created_project_item_path, error);
+        return None;
+    }
+
+    opened_project
+        .get_project_items_mut()
+        .insert(project_item_ref.clone(), project_item);
+
+    if let Err(error) = opened_project.save_to_path(project_directory_path, false) {
+        log::error!("Failed to save project after create operation: {}", error);
+
+        opened_project.get_project_items_mut().remove(&project_item_ref);
+
+        if let Err(error) = fs::remove_file(&created_project_item_path) {
+            log::error!(
+                "Failed to remove project item file {:?} after a failed save: {}",
+                created_project_item_path,
+                error
+            );
+        }
+
+        return None;
+    }
+
+    Some(created_project_item_path)
+}
+
 fn resolve_project_item_path(
     project_directory_path: &Path,
     project_item_path: &Path,
@@ -118,3 +412,61 @@ fn reload_opened_project(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{create_address_project_item, insert_project_item};
+    use squalr_engine_api::commands::project_items::create::project_items_create_request::ProjectItemsCreateRequest;
+    use squalr_engine_api::structures::projects::project::Project;
+    use squalr_engine_api::structures::projects::project_info::ProjectInfo;
+    use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_directory::ProjectItemTypeDirectory;
+    use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+    use squalr_engine_api::structures::projects::project_manifest::ProjectManifest;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn insert_project_item_leaves_project_unchanged_when_item_cannot_be_written() {
+        let project_directory = tempfile::tempdir().expect("temporary project directory should be created");
+        let project_directory_path = project_directory.path().to_path_buf();
+        let project_root_ref = ProjectItemRef::new(project_directory_path.join(Project::PROJECT_DIR));
+        let mut project_items = HashMap::new();
+
+        project_items.insert(project_root_ref.clone(), ProjectItemTypeDirectory::new_project_item(&project_root_ref));
+
+        let mut project = Project::new(
+            ProjectInfo::new(project_directory_path.clone(), None, ProjectManifest::default()),
+            project_items,
+            project_root_ref,
+        );
+        let blocking_file_path = project_directory_path.join("Addresses");
+
+        fs::write(&blocking_file_path, []).expect("blocking file should be written");
+
+        let project_items_create_request = ProjectItemsCreateRequest {
+            parent_directory_path: PathBuf::from("Addresses"),
+            project_item_name: "Health".to_string(),
+            project_item_type: "address".to_string(),
+            address: Some(0x10),
+            module_name: None,
+            address_expression: None,
+            data_type_id: None,
+            original_bytes: None,
+            replacement: None,
+        };
+        let project_item = create_address_project_item(&project_items_create_request).expect("address project item should be created");
+
+        assert!(
+            insert_project_item(
+                &project_items_create_request,
+                &mut project,
+                &project_directory_path,
+                &blocking_file_path,
+                project_item
+            )
+            .is_none()
+        );
+        assert_eq!(project.get_project_items().len(), 1);
+    }
+}
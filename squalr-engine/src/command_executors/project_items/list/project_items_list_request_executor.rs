@@ -67,6 +67,7 @@ fn refresh_project_item_display_values(
 
         let address = ProjectItemTypeAddress::get_field_address(project_item);
         let module_name = ProjectItemTypeAddress::get_field_module(project_item);
+        let address_expression = ProjectItemTypeAddress::get_field_address_expression(project_item);
         let Some(symbolic_struct_reference) = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(project_item) else {
             ProjectItemTypeAddress::set_field_freeze_data_value_interpreter(project_item, "");
             continue;
@@ -79,8 +80,13 @@ fn refresh_project_item_display_values(
             continue;
         };
 
-        let Some(memory_read_response) = dispatch_memory_read_request(engine_unprivileged_state, address, &module_name, symbolic_struct_definition.as_ref())
-        else {
+        let Some(memory_read_response) = dispatch_memory_read_request(
+            engine_unprivileged_state,
+            address,
+            &module_name,
+            &address_expression,
+            symbolic_struct_definition.as_ref(),
+        ) else {
             ProjectItemTypeAddress::set_field_freeze_data_value_interpreter(project_item, "");
             continue;
         };
@@ -113,11 +119,13 @@ fn dispatch_memory_read_request(
     engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
     address: u64,
     module_name: &str,
+    address_expression: &str,
     symbolic_struct_definition: &SymbolicStructDefinition,
 ) -> Option<MemoryReadResponse> {
     let memory_read_request = MemoryReadRequest {
         address,
         module_name: module_name.to_string(),
+        address_expression: address_expression.to_string(),
         symbolic_struct_definition: symbolic_struct_definition.clone(),
         suppress_logging: true,
     };
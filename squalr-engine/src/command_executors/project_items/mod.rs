@@ -7,3 +7,6 @@ pub mod move_item;
 pub mod project_items_executor;
 pub mod rename;
 pub mod reorder;
+pub mod set_freeze_policy;
+pub mod set_hotkeys;
+pub mod trigger_hotkey;
@@ -39,6 +39,21 @@ impl UnprivilegedCommandExecutor for ProjectItemsCommand {
             ProjectItemsCommand::Reorder { project_items_reorder_request } => project_items_reorder_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
+            ProjectItemsCommand::SetFreezePolicy {
+                project_items_set_freeze_policy_request,
+            } => project_items_set_freeze_policy_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
+            ProjectItemsCommand::SetHotkeys {
+                project_items_set_hotkeys_request,
+            } => project_items_set_hotkeys_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
+            ProjectItemsCommand::TriggerHotkey {
+                project_items_trigger_hotkey_request,
+            } => project_items_trigger_hotkey_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
         }
     }
 }
@@ -0,0 +1 @@
+pub mod project_items_set_freeze_policy_request_executor;
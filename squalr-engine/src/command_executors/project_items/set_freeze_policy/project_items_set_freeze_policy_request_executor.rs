@@ -0,0 +1,92 @@
+use crate::command_executors::project_items::activate::project_items_activate_request_executor::{
+    collect_project_item_paths_for_activation, create_memory_freeze_target, dispatch_memory_freeze_request,
+};
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_items::set_freeze_policy::project_items_set_freeze_policy_request::ProjectItemsSetFreezePolicyRequest;
+use squalr_engine_api::commands::project_items::set_freeze_policy::project_items_set_freeze_policy_response::ProjectItemsSetFreezePolicyResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsSetFreezePolicyRequest {
+    type ResponseType = ProjectItemsSetFreezePolicyResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let opened_project = project_manager.get_opened_project();
+        let mut opened_project_guard = match opened_project.write() {
+            Ok(opened_project_guard) => opened_project_guard,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock for freeze policy command: {}", error);
+
+                return ProjectItemsSetFreezePolicyResponse::default();
+            }
+        };
+        let opened_project = match opened_project_guard.as_mut() {
+            Some(opened_project) => opened_project,
+            None => {
+                log::warn!("Cannot set freeze policies without an opened project.");
+
+                return ProjectItemsSetFreezePolicyResponse::default();
+            }
+        };
+        let project_directory_path = match opened_project.get_project_info().get_project_directory() {
+            Some(project_directory_path) => project_directory_path,
+            None => {
+                log::error!("Failed to resolve opened project directory for freeze policy operation.");
+
+                return ProjectItemsSetFreezePolicyResponse::default();
+            }
+        };
+        let project_item_paths = collect_project_item_paths_for_activation(
+            opened_project
+                .get_project_items()
+                .keys()
+                .map(|project_item_ref| project_item_ref.get_project_item_path())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            &self.project_item_paths,
+        );
+        let mut updated_project_item_count = 0u64;
+        let mut freeze_targets = Vec::new();
+
+        for (project_item_ref, project_item) in opened_project.get_project_items_mut().iter_mut() {
+            if !project_item_paths.contains(project_item_ref.get_project_item_path())
+                || project_item.get_item_type().get_project_item_type_id() != ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID
+            {
+                continue;
+            }
+
+            ProjectItemTypeAddress::set_field_freeze_policy(project_item, &self.freeze_policy);
+            project_item.set_has_unsaved_changes(true);
+            updated_project_item_count += 1;
+
+            // Re-freeze activated items, such that the new policy takes effect immediately.
+            let is_activated = project_item.get_is_activated();
+
+            if let Some(freeze_target) = create_memory_freeze_target(project_item).filter(|_freeze_target| is_activated) {
+                freeze_targets.push(freeze_target);
+            }
+        }
+
+        if let Err(error) = opened_project.save_to_path(&project_directory_path, false) {
+            log::error!("Failed to persist project item freeze policies: {}", error);
+
+            return ProjectItemsSetFreezePolicyResponse::default();
+        }
+
+        drop(opened_project_guard);
+
+        dispatch_memory_freeze_request(engine_unprivileged_state, &freeze_targets, true);
+        project_manager.notify_project_items_changed();
+
+        ProjectItemsSetFreezePolicyResponse {
+            success: true,
+            updated_project_item_count,
+        }
+    }
+}
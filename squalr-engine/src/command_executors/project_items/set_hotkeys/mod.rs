@@ -0,0 +1 @@
+pub mod project_items_set_hotkeys_request_executor;
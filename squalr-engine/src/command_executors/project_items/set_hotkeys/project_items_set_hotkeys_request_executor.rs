@@ -0,0 +1,78 @@
+use crate::command_executors::project_items::activate::project_items_activate_request_executor::collect_project_item_paths_for_activation;
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_items::set_hotkeys::project_items_set_hotkeys_request::ProjectItemsSetHotkeysRequest;
+use squalr_engine_api::commands::project_items::set_hotkeys::project_items_set_hotkeys_response::ProjectItemsSetHotkeysResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_projects::project::serialization::serializable_project_file::SerializableProjectFile;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsSetHotkeysRequest {
+    type ResponseType = ProjectItemsSetHotkeysResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let opened_project = project_manager.get_opened_project();
+        let mut opened_project_guard = match opened_project.write() {
+            Ok(opened_project_guard) => opened_project_guard,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock for hotkey command: {}", error);
+
+                return ProjectItemsSetHotkeysResponse::default();
+            }
+        };
+        let opened_project = match opened_project_guard.as_mut() {
+            Some(opened_project) => opened_project,
+            None => {
+                log::warn!("Cannot set hotkeys without an opened project.");
+
+                return ProjectItemsSetHotkeysResponse::default();
+            }
+        };
+        let project_directory_path = match opened_project.get_project_info().get_project_directory() {
+            Some(project_directory_path) => project_directory_path,
+            None => {
+                log::error!("Failed to resolve opened project directory for hotkey operation.");
+
+                return ProjectItemsSetHotkeysResponse::default();
+            }
+        };
+        let project_item_paths = collect_project_item_paths_for_activation(
+            opened_project
+                .get_project_items()
+                .keys()
+                .map(|project_item_ref| project_item_ref.get_project_item_path())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            &self.project_item_paths,
+        );
+        let mut updated_project_item_count = 0u64;
+
+        for (project_item_ref, project_item) in opened_project.get_project_items_mut().iter_mut() {
+            if !project_item_paths.contains(project_item_ref.get_project_item_path()) {
+                continue;
+            }
+
+            project_item.set_field_hotkey_bindings(&self.hotkey_bindings);
+            project_item.set_has_unsaved_changes(true);
+            updated_project_item_count += 1;
+        }
+
+        if let Err(error) = opened_project.save_to_path(&project_directory_path, false) {
+            log::error!("Failed to persist project item hotkeys: {}", error);
+
+            return ProjectItemsSetHotkeysResponse::default();
+        }
+
+        drop(opened_project_guard);
+
+        project_manager.notify_project_items_changed();
+
+        ProjectItemsSetHotkeysResponse {
+            success: true,
+            updated_project_item_count,
+        }
+    }
+}
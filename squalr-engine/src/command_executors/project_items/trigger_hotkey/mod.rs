@@ -0,0 +1 @@
+pub mod project_items_trigger_hotkey_request_executor;
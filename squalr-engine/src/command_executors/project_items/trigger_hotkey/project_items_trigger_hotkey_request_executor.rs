@@ -0,0 +1,270 @@
+use crate::command_executors::project_items::activate::project_items_activate_request_executor::{
+    create_memory_freeze_target, dispatch_and_wait, dispatch_memory_freeze_request,
+};
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
+use squalr_engine_api::commands::memory::read::memory_read_response::MemoryReadResponse;
+use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use squalr_engine_api::commands::memory::write::memory_write_response::MemoryWriteResponse;
+use squalr_engine_api::commands::project_items::activate::project_items_activate_request::ProjectItemsActivateRequest;
+use squalr_engine_api::commands::project_items::trigger_hotkey::project_items_trigger_hotkey_request::ProjectItemsTriggerHotkeyRequest;
+use squalr_engine_api::commands::project_items::trigger_hotkey::project_items_trigger_hotkey_response::ProjectItemsTriggerHotkeyResponse;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::hotkeys::hotkey_action::HotkeyAction;
+use squalr_engine_api::structures::hotkeys::hotkey_binding::HotkeyBinding;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_code_patch::ProjectItemTypeCodePatch;
+use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use std::sync::Arc;
+
+/// A project item bound to the triggered key chord, captured such that actions run without holding the project lock.
+struct TriggeredHotkey {
+    project_item_path: String,
+    project_item: ProjectItem,
+    hotkey_binding: HotkeyBinding,
+}
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsTriggerHotkeyRequest {
+    type ResponseType = ProjectItemsTriggerHotkeyResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let triggered_hotkeys = match project_manager.get_opened_project().read() {
+            Ok(opened_project_guard) => match opened_project_guard.as_ref() {
+                Some(opened_project) => opened_project
+                    .get_project_items()
+                    .iter()
+                    .flat_map(|(project_item_ref, project_item)| {
+                        project_item
+                            .get_field_hotkey_bindings()
+                            .into_iter()
+                            .filter(|hotkey_binding| hotkey_binding.get_key_chord() == &self.key_chord)
+                            .map(|hotkey_binding| TriggeredHotkey {
+                                project_item_path: project_item_ref
+                                    .get_project_item_path()
+                                    .to_string_lossy()
+                                    .into_owned(),
+                                project_item: project_item.clone(),
+                                hotkey_binding,
+                            })
+                    })
+                    .collect::<Vec<_>>(),
+                None => return ProjectItemsTriggerHotkeyResponse::default(),
+            },
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock for hotkey trigger: {}", error);
+
+                return ProjectItemsTriggerHotkeyResponse::default();
+            }
+        };
+        let failed_action_count = triggered_hotkeys
+            .iter()
+            .filter(|triggered_hotkey| !perform_hotkey_action(engine_unprivileged_state, triggered_hotkey))
+            .count() as u64;
+
+        ProjectItemsTriggerHotkeyResponse {
+            success: true,
+            triggered_action_count: triggered_hotkeys.len() as u64,
+            failed_action_count,
+        }
+    }
+}
+
+/// Performs the bound action on a project item, returning whether it succeeded.
+fn perform_hotkey_action(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    triggered_hotkey: &TriggeredHotkey,
+) -> bool {
+    let project_item = &triggered_hotkey.project_item;
+    let project_item_type_id = project_item.get_item_type().get_project_item_type_id();
+
+    match triggered_hotkey.hotkey_binding.get_hotkey_action() {
+        HotkeyAction::ToggleActivation => {
+            ProjectItemsActivateRequest {
+                project_item_paths: vec![triggered_hotkey.project_item_path.clone()],
+                is_activated: !project_item.get_is_activated(),
+            }
+            .execute(engine_unprivileged_state);
+
+            true
+        }
+        HotkeyAction::RunScript if project_item_type_id == ProjectItemTypeCodePatch::PROJECT_ITEM_TYPE_ID => {
+            ProjectItemsActivateRequest {
+                project_item_paths: vec![triggered_hotkey.project_item_path.clone()],
+                is_activated: true,
+            }
+            .execute(engine_unprivileged_state);
+
+            true
+        }
+        HotkeyAction::SetValue | HotkeyAction::Increment | HotkeyAction::Decrement if project_item_type_id == ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID => {
+            write_hotkey_value(engine_unprivileged_state, triggered_hotkey)
+        }
+        hotkey_action => {
+            log::warn!(
+                "Hotkey action '{}' is not supported by project item '{}'.",
+                hotkey_action,
+                project_item.get_field_name()
+            );
+
+            false
+        }
+    }
+}
+
+/// Writes the value resolved by a set, increment or decrement hotkey to an address item. Activated items are re-frozen, such that
+/// the freezer holds the new value rather than restoring the previous one.
+fn write_hotkey_value(
+    engine_unprivileged_state: &Arc<dyn EngineExecutionContext>,
+    triggered_hotkey: &TriggeredHotkey,
+) -> bool {
+    let mut project_item = triggered_hotkey.project_item.clone();
+    let address = ProjectItemTypeAddress::get_field_address(&mut project_item);
+    let module_name = ProjectItemTypeAddress::get_field_module(&mut project_item);
+    let address_expression = ProjectItemTypeAddress::get_field_address_expression(&mut project_item);
+    let Some(data_type_id) = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(&mut project_item).map(|symbolic_struct_reference| {
+        symbolic_struct_reference
+            .get_symbolic_struct_namespace()
+            .to_string()
+    }) else {
+        return false;
+    };
+    let hotkey_binding = &triggered_hotkey.hotkey_binding;
+    let current_value = match hotkey_binding.get_hotkey_action() {
+        HotkeyAction::SetValue => None,
+        _ => {
+            let Some(symbolic_struct_definition) = SymbolRegistry::get_instance().get(&data_type_id) else {
+                return false;
+            };
+            let memory_read_request = MemoryReadRequest {
+                address,
+                module_name: module_name.clone(),
+                address_expression: address_expression.clone(),
+                symbolic_struct_definition: symbolic_struct_definition.as_ref().clone(),
+                suppress_logging: true,
+            };
+
+            match dispatch_and_wait::<MemoryReadRequest, MemoryReadResponse>(engine_unprivileged_state, &memory_read_request, "hotkey read")
+                .filter(|memory_read_response| memory_read_response.success)
+                .and_then(|memory_read_response| {
+                    memory_read_response
+                        .valued_struct
+                        .get_fields()
+                        .first()
+                        .and_then(|valued_struct_field| valued_struct_field.get_data_value())
+                        .cloned()
+                }) {
+                Some(current_value) => Some(current_value),
+                None => return false,
+            }
+        }
+    };
+    let Some(hotkey_value) = resolve_hotkey_value(&DataTypeRef::new(&data_type_id), hotkey_binding, current_value.as_ref()) else {
+        log::warn!(
+            "Hotkey '{}' could not produce a value for project item '{}'.",
+            hotkey_binding,
+            project_item.get_field_name()
+        );
+
+        return false;
+    };
+    let memory_write_request = MemoryWriteRequest {
+        address,
+        module_name,
+        address_expression,
+        value: hotkey_value.get_value_bytes().clone(),
+    };
+    let success = dispatch_and_wait::<MemoryWriteRequest, MemoryWriteResponse>(engine_unprivileged_state, &memory_write_request, "hotkey write")
+        .is_some_and(|memory_write_response| memory_write_response.success);
+
+    // Frozen items are re-frozen, such that the freeze holds the new value rather than restoring the old one.
+    let freeze_target = (success && project_item.get_is_activated())
+        .then(|| create_memory_freeze_target(&mut project_item))
+        .flatten();
+
+    if let Some(freeze_target) = freeze_target {
+        dispatch_memory_freeze_request(engine_unprivileged_state, &[freeze_target], true);
+    }
+
+    success
+}
+
+/// Resolves the value written by a set, increment or decrement binding, or `None` if the binding value is not valid for the data type
+/// or the result cannot be represented by it.
+fn resolve_hotkey_value(
+    data_type_ref: &DataTypeRef,
+    hotkey_binding: &HotkeyBinding,
+    current_value: Option<&DataValue>,
+) -> Option<DataValue> {
+    let symbol_registry = SymbolRegistry::get_instance();
+    let binding_value = hotkey_binding.get_value()?.trim();
+    let resolved_value = match (hotkey_binding.get_hotkey_action(), current_value) {
+        (HotkeyAction::SetValue, _) => binding_value.to_string(),
+        (HotkeyAction::Increment | HotkeyAction::Decrement, Some(current_value)) => {
+            let step_value = binding_value.parse::<f64>().ok()?;
+            let current_value = symbol_registry
+                .anonymize_value(current_value, AnonymousValueStringFormat::Decimal)
+                .ok()?
+                .get_anonymous_value_string()
+                .trim()
+                .parse::<f64>()
+                .ok()?;
+
+            match hotkey_binding.get_hotkey_action() {
+                HotkeyAction::Increment => current_value + step_value,
+                _ => current_value - step_value,
+            }
+            .to_string()
+        }
+        _ => return None,
+    };
+    let anonymous_value_string = AnonymousValueString::new(resolved_value, AnonymousValueStringFormat::Decimal, ContainerType::None);
+
+    symbol_registry
+        .deanonymize_value_string(data_type_ref, &anonymous_value_string)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_hotkey_value;
+    use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::hotkeys::hotkey_binding::HotkeyBinding;
+
+    fn resolve(
+        hotkey_binding: &str,
+        current_value: Option<u8>,
+    ) -> Option<Vec<u8>> {
+        let hotkey_binding = hotkey_binding
+            .parse::<HotkeyBinding>()
+            .expect("binding should parse");
+        let current_value = current_value.map(DataTypeU8::get_value_from_primitive);
+
+        resolve_hotkey_value(&DataTypeRef::new("u8"), &hotkey_binding, current_value.as_ref()).map(|data_value| data_value.get_value_bytes().clone())
+    }
+
+    #[test]
+    fn resolve_hotkey_value_sets_increments_and_decrements() {
+        assert_eq!(resolve("f1=set:42", None), Some(vec![42]));
+        assert_eq!(resolve("f2=increment:5", Some(10)), Some(vec![15]));
+        assert_eq!(resolve("f3=decrement:5", Some(10)), Some(vec![5]));
+    }
+
+    #[test]
+    fn resolve_hotkey_value_rejects_values_outside_the_data_type() {
+        assert_eq!(resolve("f1=set:300", None), None);
+        assert_eq!(resolve("f2=increment:10", Some(250)), None);
+        assert_eq!(resolve("f3=decrement:1", Some(0)), None);
+        assert_eq!(resolve("f2=increment:1", None), None);
+    }
+}
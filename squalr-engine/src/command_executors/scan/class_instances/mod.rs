@@ -0,0 +1 @@
+pub mod scan_class_instances_request_executor;
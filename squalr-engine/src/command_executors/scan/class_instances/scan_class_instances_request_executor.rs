@@ -0,0 +1,88 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::element_scan::element_scan_request_executor::execute_element_scan;
+use crate::command_executors::scan::scan_results_metadata_collector::collect_scan_results_metadata;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::class_instances::scan_class_instances_request::ScanClassInstancesRequest;
+use squalr_engine_api::commands::scan::class_instances::scan_class_instances_response::ScanClassInstancesResponse;
+use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
+use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use squalr_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint::ScanConstraint;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::ScanConstraintExpression;
+use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
+use squalr_engine_session::runtime_classes::runtime_class_resolver::RuntimeClassResolver;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanClassInstancesRequest {
+    type ResponseType = ScanClassInstancesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process available to scan for class instances in.");
+                return ScanClassInstancesResponse::default();
+            }
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let module_name = self.module_name.trim();
+        let class_name = self.class_name.trim();
+        let runtime_class = match RuntimeClassResolver::get_module_runtime_classes(os_providers, &process_info, module_name).and_then(|runtime_classes| {
+            runtime_classes
+                .iter()
+                .find(|runtime_class| runtime_class.get_class_name() == class_name)
+                .cloned()
+        }) {
+            Some(runtime_class) => runtime_class,
+            None => {
+                log::error!("No class '{}' was discovered in module '{}'.", class_name, module_name);
+                return ScanClassInstancesResponse::default();
+            }
+        };
+
+        // Instances are found by scanning fresh pointer-aligned memory for pointer-sized values equal to the class vtable.
+        let vtable_address = runtime_class.get_vtable_address();
+        let (data_type_ref, vtable_value, alignment) = match process_info.get_bitness() {
+            Bitness::Bit32 => (
+                DataTypeRef::new(DataTypeU32::get_data_type_id()),
+                DataTypeU32::get_value_from_primitive(vtable_address as u32),
+                MemoryAlignment::Alignment4,
+            ),
+            Bitness::Bit64 => (
+                DataTypeRef::new(DataTypeU64::get_data_type_id()),
+                DataTypeU64::get_value_from_primitive(vtable_address),
+                MemoryAlignment::Alignment8,
+            ),
+        };
+        let scan_constraint = ScanConstraint::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            vtable_value,
+            ScanSettingsConfig::get_floating_point_tolerance(),
+        );
+
+        ScanNewRequest {}.execute(engine_privileged_state);
+        execute_element_scan(
+            engine_privileged_state,
+            process_info,
+            HashMap::from([(data_type_ref, ScanConstraintExpression::Constraint(scan_constraint))]),
+            alignment,
+        );
+
+        ScanClassInstancesResponse {
+            scan_results_metadata: collect_scan_results_metadata(engine_privileged_state),
+            runtime_class: Some(runtime_class),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod scan_correlate_rank_request_executor;
@@ -0,0 +1,95 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::scan_result_elements::retain_scan_results;
+use crate::command_executors::scan::scan_results_metadata_collector::collect_scan_results_metadata;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::correlate_rank::scan_correlate_rank_request::ScanCorrelateRankRequest;
+use squalr_engine_api::commands::scan::correlate_rank::scan_correlate_rank_response::ScanCorrelateRankResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::scanning::correlation::scan_correlation::ScanCorrelation;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanCorrelateRankRequest {
+    type ResponseType = ScanCorrelateRankResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let mut snapshot = match snapshot.write() {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanCorrelateRankResponse::default();
+            }
+        };
+        let scan_correlation = engine_privileged_state.get_scan_correlation();
+        let mut scan_correlation = match scan_correlation.write() {
+            Ok(scan_correlation) => scan_correlation,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on scan correlation: {}", error);
+
+                return ScanCorrelateRankResponse::default();
+            }
+        };
+
+        if scan_correlation.get_sample_count() == 0 {
+            log::error!("Cannot rank correlation candidates, no observations have been sampled.");
+
+            return ScanCorrelateRankResponse::default();
+        }
+
+        let removed_candidate_count = scan_correlation.retain_minimum_score(self.correlation_mode, self.tolerance, self.minimum_score);
+
+        // Narrow the scan results to the remaining candidates, such that regular scans can continue from the correlation.
+        if removed_candidate_count > 0 {
+            narrow_to_correlation_candidates(&mut snapshot, &scan_correlation);
+        }
+
+        let mut ranked_candidates = scan_correlation.rank(self.correlation_mode, self.tolerance);
+        let sample_count = scan_correlation.get_sample_count() as u64;
+
+        ranked_candidates.truncate(self.limit as usize);
+
+        drop(scan_correlation);
+        drop(snapshot);
+
+        if removed_candidate_count > 0 {
+            engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+        }
+
+        log::info!(
+            "Ranked correlation candidates by {} correlation, discarding {} scoring below {}.",
+            self.correlation_mode,
+            removed_candidate_count,
+            self.minimum_score
+        );
+
+        ScanCorrelateRankResponse {
+            success: true,
+            sample_count,
+            ranked_candidates,
+            scan_results_metadata: collect_scan_results_metadata(engine_privileged_state),
+        }
+    }
+}
+
+/// Narrows the scan results to the remaining correlation candidates.
+fn narrow_to_correlation_candidates(
+    snapshot: &mut Snapshot,
+    scan_correlation: &ScanCorrelation,
+) {
+    let candidate_keys: HashSet<(u64, &DataTypeRef)> = scan_correlation
+        .get_candidates()
+        .iter()
+        .map(|candidate| (candidate.get_address(), candidate.get_data_type_ref()))
+        .collect();
+
+    retain_scan_results(snapshot, |_snapshot_region, address, data_type_ref| {
+        candidate_keys.contains(&(address, data_type_ref))
+    });
+}
@@ -0,0 +1 @@
+pub mod scan_correlate_sample_request_executor;
@@ -0,0 +1,131 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::scan_result_elements::for_each_scan_result;
+use crate::command_executors::scan_results::scan_results_query_evaluator::{NumericValue, read_element_numeric_value};
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::correlate_sample::scan_correlate_sample_request::ScanCorrelateSampleRequest;
+use squalr_engine_api::commands::scan::correlate_sample::scan_correlate_sample_response::ScanCorrelateSampleResponse;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::scanning::correlation::scan_correlation::ScanCorrelation;
+use squalr_engine_api::structures::scanning::correlation::scan_correlation_candidate::ScanCorrelationCandidate;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_scanning::scanners::scan_execution_context::ScanExecutionContext;
+use squalr_engine_scanning::scanners::value_collector_task::ValueCollector;
+use std::sync::Arc;
+
+/// The maximum number of scan results that a correlation can track. Larger result sets must first be narrowed by regular scans.
+const MAX_CORRELATION_CANDIDATES: u64 = 1_000_000;
+
+impl PrivilegedCommandRequestExecutor for ScanCorrelateSampleRequest {
+    type ResponseType = ScanCorrelateSampleResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let opened_process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => opened_process_info,
+            None => {
+                log::error!("Cannot sample correlation, no opened process.");
+
+                return ScanCorrelateSampleResponse::default();
+            }
+        };
+        let snapshot = engine_privileged_state.get_snapshot();
+
+        // Read the latest values of all scan results, such that they are sampled alongside the observation.
+        let memory_read_provider = engine_privileged_state.get_os_providers().memory_read.clone();
+        let scan_execution_context = ScanExecutionContext::new(
+            None,
+            None,
+            Some(Arc::new(move |opened_process_info, address, values| {
+                memory_read_provider.read_bytes(opened_process_info, address, values)
+            })),
+        );
+
+        ValueCollector::collect_values(opened_process_info, snapshot.clone(), false, &scan_execution_context);
+
+        let snapshot = match snapshot.read() {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+
+                return ScanCorrelateSampleResponse::default();
+            }
+        };
+        let scan_correlation = engine_privileged_state.get_scan_correlation();
+        let mut scan_correlation = match scan_correlation.write() {
+            Ok(scan_correlation) => scan_correlation,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on scan correlation: {}", error);
+
+                return ScanCorrelateSampleResponse::default();
+            }
+        };
+
+        if self.restart || scan_correlation.get_sample_count() == 0 {
+            let result_count = snapshot.get_number_of_results();
+
+            if result_count > MAX_CORRELATION_CANDIDATES {
+                log::error!(
+                    "Cannot correlate {} scan results, narrow the scan to at most {} results first.",
+                    result_count,
+                    MAX_CORRELATION_CANDIDATES
+                );
+
+                return ScanCorrelateSampleResponse::default();
+            }
+
+            scan_correlation.begin(collect_correlation_candidates(&snapshot));
+        }
+
+        sample_correlation_values(&snapshot, &mut scan_correlation, self.observation);
+
+        log::info!(
+            "Sampled {} correlation candidates against observation {} ({} samples).",
+            scan_correlation.get_candidate_count(),
+            self.observation,
+            scan_correlation.get_sample_count()
+        );
+
+        ScanCorrelateSampleResponse {
+            success: true,
+            sample_count: scan_correlation.get_sample_count() as u64,
+            candidate_count: scan_correlation.get_candidate_count() as u64,
+        }
+    }
+}
+
+/// Creates a correlation candidate for every scan result in the snapshot.
+fn collect_correlation_candidates(snapshot: &Snapshot) -> Vec<ScanCorrelationCandidate> {
+    let mut candidates = vec![];
+
+    for_each_scan_result(snapshot, |_snapshot_region, address, data_type_ref, memory_alignment| {
+        candidates.push(ScanCorrelationCandidate::new(address, data_type_ref.clone(), memory_alignment));
+    });
+
+    candidates
+}
+
+/// Records the observation, then samples the current value of each candidate that is still a scan result.
+fn sample_correlation_values(
+    snapshot: &Snapshot,
+    scan_correlation: &mut ScanCorrelation,
+    observation: f64,
+) {
+    scan_correlation.push_observation(observation);
+
+    for_each_scan_result(snapshot, |snapshot_region, address, data_type_ref, _memory_alignment| {
+        let value = read_element_numeric_value(
+            snapshot_region.get_current_values(),
+            address.saturating_sub(snapshot_region.get_base_address()),
+            SymbolRegistry::get_instance().get_unit_size_in_bytes(data_type_ref),
+            data_type_ref,
+        )
+        .map(NumericValue::to_f64);
+
+        scan_correlation.set_latest_value(address, data_type_ref, value);
+    });
+}
@@ -0,0 +1,265 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::scan_result_elements::{for_each_scan_result, retain_scan_results};
+use crate::command_executors::scan::scan_results_metadata_collector::collect_scan_results_metadata;
+use crate::command_executors::scan_results::import::scan_results_import_request_executor::create_imported_snapshot_regions;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::differential::scan_differential_request::ScanDifferentialRequest;
+use squalr_engine_api::commands::scan::differential::scan_differential_response::ScanDifferentialResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::scan_results::scan_results_export_record::ScanResultsExportRecord;
+use squalr_engine_api::structures::scan_results::scan_results_metadata::ScanResultsMetadata;
+use squalr_engine_api::structures::scanning::differential::scan_differential_compare_type::ScanDifferentialCompareType;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_scanning::scanners::scan_execution_context::ScanExecutionContext;
+use squalr_engine_scanning::scanners::value_collector_task::ValueCollector;
+use squalr_engine_session::os::PageRetrievalMode;
+use squalr_engine_session::os::engine_os_provider::MemoryQueryProvider;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+/// The maximum number of scan results that can be compared against a secondary process, bounding the memory used to pair their values.
+const MAX_DIFFERENTIAL_CANDIDATES: u64 = 1_000_000;
+
+impl PrivilegedCommandRequestExecutor for ScanDifferentialRequest {
+    type ResponseType = ScanDifferentialResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_manager = engine_privileged_state.get_process_manager();
+        let (Some(opened_process_info), Some(secondary_process_info)) = (process_manager.get_opened_process(), process_manager.get_secondary_process()) else {
+            log::error!("Cannot perform a differential scan, both an opened process and a secondary process are required.");
+
+            return ScanDifferentialResponse::default();
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let memory_query = os_providers.memory_query.as_ref();
+        let memory_read_provider = os_providers.memory_read.clone();
+        let scan_execution_context = ScanExecutionContext::new(
+            None,
+            None,
+            Some(Arc::new(move |opened_process_info, address, values| {
+                memory_read_provider.read_bytes(opened_process_info, address, values)
+            })),
+        );
+        let snapshot = engine_privileged_state.get_snapshot();
+        let secondary_snapshot = engine_privileged_state.get_process_snapshot(&secondary_process_info);
+
+        // Locate each module-relative scan result in the secondary process. Results outside of modules, or in modules that the
+        // secondary process has not loaded, cannot be compared.
+        let opened_process_modules = memory_query.get_modules(&opened_process_info);
+        let secondary_process_modules = memory_query.get_modules(&secondary_process_info);
+        let mut secondary_addresses = HashMap::new();
+        let mut scan_result_count = 0u64;
+        let opened_process_memory_pages = match snapshot.read() {
+            Ok(snapshot) => {
+                let result_count = snapshot.get_number_of_results();
+
+                if result_count > MAX_DIFFERENTIAL_CANDIDATES {
+                    log::error!(
+                        "Cannot compare {} scan results against a secondary process, narrow the scan to at most {} results first.",
+                        result_count,
+                        MAX_DIFFERENTIAL_CANDIDATES
+                    );
+
+                    return ScanDifferentialResponse::default();
+                }
+
+                for_each_scan_result(&snapshot, |_snapshot_region, address, data_type_ref, _memory_alignment| {
+                    scan_result_count += 1;
+
+                    let Some((module_name, module_offset)) = memory_query.address_to_module(address, &opened_process_modules) else {
+                        return;
+                    };
+
+                    match memory_query.resolve_module(&secondary_process_modules, &module_name) {
+                        0 => {}
+                        secondary_module_base_address => {
+                            secondary_addresses.insert((address, data_type_ref.clone()), secondary_module_base_address.saturating_add(module_offset));
+                        }
+                    }
+                });
+
+                snapshot
+                    .get_snapshot_regions()
+                    .iter()
+                    .map(|snapshot_region| NormalizedRegion::new(snapshot_region.get_base_address(), snapshot_region.get_region_size()))
+                    .collect::<Vec<NormalizedRegion>>()
+            }
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+
+                return ScanDifferentialResponse::default();
+            }
+        };
+
+        // Read the values of the opened process into a separate snapshot, such that the previous values of the scan results are kept.
+        let opened_process_records: Vec<ScanResultsExportRecord> = secondary_addresses
+            .keys()
+            .map(|(address, data_type_ref)| create_export_record(*address, data_type_ref))
+            .collect();
+        let Some(opened_process_values) = collect_result_values(
+            &opened_process_info,
+            &opened_process_records,
+            opened_process_memory_pages,
+            memory_query,
+            &Arc::new(RwLock::new(Snapshot::new())),
+            &scan_execution_context,
+        ) else {
+            return ScanDifferentialResponse::default();
+        };
+
+        // Mirror the results into the snapshot of the secondary process, then collect their values.
+        let secondary_process_records: Vec<ScanResultsExportRecord> = secondary_addresses
+            .iter()
+            .map(|((_address, data_type_ref), secondary_address)| create_export_record(*secondary_address, data_type_ref))
+            .collect();
+        let secondary_memory_pages = memory_query.get_memory_page_bounds(&secondary_process_info, PageRetrievalMode::FromSettings);
+        let Some(secondary_values) = collect_result_values(
+            &secondary_process_info,
+            &secondary_process_records,
+            secondary_memory_pages,
+            memory_query,
+            &secondary_snapshot,
+            &scan_execution_context,
+        ) else {
+            return ScanDifferentialResponse::default();
+        };
+
+        // Keep the results whose values compare as requested, narrowing both snapshots to the same module-relative results.
+        let mut retained_secondary_results: HashSet<(u64, DataTypeRef)> = HashSet::new();
+        let retained_result_count = match snapshot.write() {
+            Ok(mut snapshot) => retain_scan_results(&mut snapshot, |_snapshot_region, address, data_type_ref| {
+                let Some(secondary_address) = secondary_addresses.get(&(address, data_type_ref.clone())) else {
+                    return false;
+                };
+                let (Some(data_value), Some(secondary_data_value)) = (
+                    opened_process_values.get(&(address, data_type_ref.clone())),
+                    secondary_values.get(&(*secondary_address, data_type_ref.clone())),
+                ) else {
+                    return false;
+                };
+                let is_equal = data_value.get_value_bytes() == secondary_data_value.get_value_bytes();
+                let is_retained = match self.compare_type {
+                    ScanDifferentialCompareType::Different => !is_equal,
+                    ScanDifferentialCompareType::Equal => is_equal,
+                };
+
+                if is_retained {
+                    retained_secondary_results.insert((*secondary_address, data_type_ref.clone()));
+                }
+
+                is_retained
+            }),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanDifferentialResponse::default();
+            }
+        };
+        let secondary_scan_results_metadata = match secondary_snapshot.write() {
+            Ok(mut secondary_snapshot) => {
+                retain_scan_results(&mut secondary_snapshot, |_snapshot_region, address, data_type_ref| {
+                    retained_secondary_results.contains(&(address, data_type_ref.clone()))
+                });
+
+                ScanResultsMetadata {
+                    result_count: secondary_snapshot.get_number_of_results(),
+                    total_size_in_bytes: secondary_snapshot.get_byte_count(),
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on secondary snapshot: {}", error);
+
+                ScanResultsMetadata::default()
+            }
+        };
+        let unmatched_result_count = scan_result_count.saturating_sub(secondary_values.len() as u64);
+
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+
+        log::info!(
+            "Differential scan against process {} kept {} of {} results whose values are {}. {} results could not be located in the secondary process.",
+            secondary_process_info.get_process_id_raw(),
+            retained_result_count,
+            scan_result_count,
+            self.compare_type,
+            unmatched_result_count
+        );
+
+        ScanDifferentialResponse {
+            success: true,
+            scan_results_metadata: collect_scan_results_metadata(engine_privileged_state),
+            secondary_scan_results_metadata,
+            unmatched_result_count,
+        }
+    }
+}
+
+/// Creates a record placing a scan result of the given data type at an absolute address.
+fn create_export_record(
+    address: u64,
+    data_type_ref: &DataTypeRef,
+) -> ScanResultsExportRecord {
+    ScanResultsExportRecord {
+        address,
+        data_type_id: data_type_ref.get_data_type_id().to_string(),
+        ..ScanResultsExportRecord::default()
+    }
+}
+
+/// Places the given results into the snapshot over the given memory pages, then reads their current values from the process.
+fn collect_result_values(
+    process_info: &OpenedProcessInfo,
+    scan_results_export_records: &[ScanResultsExportRecord],
+    memory_pages: Vec<NormalizedRegion>,
+    memory_query: &dyn MemoryQueryProvider,
+    snapshot: &Arc<RwLock<Snapshot>>,
+    scan_execution_context: &ScanExecutionContext,
+) -> Option<HashMap<(u64, DataTypeRef), DataValue>> {
+    let (snapshot_regions, _placed_result_count) = create_imported_snapshot_regions(scan_results_export_records, &memory_pages, memory_query, &Vec::new());
+
+    match snapshot.write() {
+        Ok(mut snapshot) => {
+            snapshot.capture_memory_pages(memory_pages);
+            snapshot.set_snapshot_regions(snapshot_regions);
+        }
+        Err(error) => {
+            log::error!(
+                "Failed to acquire write lock on snapshot of process {}: {}",
+                process_info.get_process_id_raw(),
+                error
+            );
+
+            return None;
+        }
+    }
+
+    ValueCollector::collect_values(process_info.clone(), snapshot.clone(), false, scan_execution_context);
+
+    let mut values = HashMap::new();
+
+    match snapshot.read() {
+        Ok(snapshot) => for_each_scan_result(&snapshot, |snapshot_region, address, data_type_ref, _memory_alignment| {
+            if let Some(data_value) = snapshot_region.get_current_value(address, data_type_ref) {
+                values.insert((address, data_type_ref.clone()), data_value);
+            }
+        }),
+        Err(error) => {
+            log::error!(
+                "Failed to acquire read lock on snapshot of process {}: {}",
+                process_info.get_process_id_raw(),
+                error
+            );
+
+            return None;
+        }
+    }
+
+    Some(values)
+}
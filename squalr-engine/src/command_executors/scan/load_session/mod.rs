@@ -0,0 +1 @@
+pub mod scan_load_session_request_executor;
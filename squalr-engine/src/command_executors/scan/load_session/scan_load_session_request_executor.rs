@@ -0,0 +1,72 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::scan_results_metadata_collector::collect_scan_results_metadata;
+use crate::command_executors::scan::scan_session_file::read_scan_session_file;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::load_session::scan_load_session_request::ScanLoadSessionRequest;
+use squalr_engine_api::commands::scan::load_session::scan_load_session_response::ScanLoadSessionResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanLoadSessionRequest {
+    type ResponseType = ScanLoadSessionResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let snapshot_session = match read_scan_session_file(&self.file_path) {
+            Ok(snapshot_session) => snapshot_session,
+            Err(error) => {
+                log::error!("{}", error);
+
+                return ScanLoadSessionResponse::default();
+            }
+        };
+
+        // Sessions are restored from their saved values alone, so no process is required. A mismatch is only worth a warning,
+        // as the same executable may have been restarted, or the session may be reloaded against a different memory provider.
+        match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) if opened_process_info.get_name() != snapshot_session.process_name => log::warn!(
+                "Scan session was saved against process '{}', but '{}' is opened.",
+                snapshot_session.process_name,
+                opened_process_info.get_name()
+            ),
+            Some(opened_process_info) if opened_process_info.get_process_id() != snapshot_session.process_id => log::warn!(
+                "Scan session was saved against process id {}, but process id {} is opened.",
+                snapshot_session.process_id,
+                opened_process_info.get_process_id()
+            ),
+            _ => {}
+        }
+
+        let scan_settings = snapshot_session.scan_settings;
+
+        match engine_privileged_state.get_snapshot().write() {
+            Ok(mut snapshot) => {
+                if let Err(error) = snapshot_session.restore(&mut snapshot) {
+                    log::error!("{}", error);
+
+                    return ScanLoadSessionResponse::default();
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanLoadSessionResponse::default();
+            }
+        }
+
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+
+        log::info!("Loaded scan session from '{}'.", self.file_path.display());
+
+        ScanLoadSessionResponse {
+            success: true,
+            scan_results_metadata: collect_scan_results_metadata(engine_privileged_state),
+            scan_settings,
+        }
+    }
+}
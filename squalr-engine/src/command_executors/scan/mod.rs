@@ -1,8 +1,17 @@
+pub mod class_instances;
 pub mod collect_values;
+pub mod correlate_rank;
+pub mod correlate_sample;
+pub mod differential;
 pub mod element_scan;
+pub mod load_session;
 pub mod new;
 pub mod pointer_scan;
+pub mod refresh_regions;
 pub mod reset;
+pub mod save_session;
 pub mod scan_command_executor;
+pub mod scan_result_elements;
 pub mod scan_results_metadata_collector;
+pub mod scan_session_file;
 pub mod struct_scan;
@@ -0,0 +1 @@
+pub mod scan_refresh_regions_request_executor;
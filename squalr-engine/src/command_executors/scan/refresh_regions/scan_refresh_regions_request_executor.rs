@@ -0,0 +1,60 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::refresh_regions::scan_refresh_regions_request::ScanRefreshRegionsRequest;
+use squalr_engine_api::commands::scan::refresh_regions::scan_refresh_regions_response::ScanRefreshRegionsResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_session::os::PageRetrievalMode;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanRefreshRegionsRequest {
+    type ResponseType = ScanRefreshRegionsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let opened_process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => opened_process_info,
+            None => {
+                log::error!("Cannot refresh scan regions, no opened process.");
+
+                return ScanRefreshRegionsResponse::default();
+            }
+        };
+
+        // Query the pages before taking the snapshot lock, such that scans are not blocked on the OS.
+        let memory_pages = engine_privileged_state
+            .get_os_providers()
+            .memory_query
+            .get_memory_page_bounds(&opened_process_info, PageRetrievalMode::FromSettings);
+
+        let snapshot = engine_privileged_state.get_snapshot();
+        let refresh_result = match snapshot.write() {
+            Ok(mut snapshot) => snapshot.refresh_memory_pages(memory_pages, self.seed_new_regions),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanRefreshRegionsResponse::default();
+            }
+        };
+
+        log::info!(
+            "Refreshed scan regions: {} dropped, {} split, {} added.",
+            refresh_result.dropped_region_count,
+            refresh_result.split_region_count,
+            refresh_result.added_region_count
+        );
+
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+
+        ScanRefreshRegionsResponse {
+            success: true,
+            dropped_region_count: refresh_result.dropped_region_count,
+            split_region_count: refresh_result.split_region_count,
+            added_region_count: refresh_result.added_region_count,
+        }
+    }
+}
@@ -28,8 +28,13 @@ impl PrivilegedCommandRequestExecutor for ScanResetRequest {
                 // Clear the freeze list.
                 freeze_list_registry_guard.clear();
 
+                // Clear any in-progress correlation.
+                if let Ok(mut scan_correlation) = engine_privileged_state.get_scan_correlation().write() {
+                    scan_correlation.clear();
+                }
+
                 // Clears snapshot regions to reset the scan.
-                snapshot.set_snapshot_regions(vec![]);
+                snapshot.capture_memory_pages(vec![]);
                 engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
 
                 log::info!("Cleared scan data.");
@@ -0,0 +1 @@
+pub mod scan_save_session_request_executor;
@@ -0,0 +1,53 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::scan_session_file::write_scan_session_file;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::save_session::scan_save_session_request::ScanSaveSessionRequest;
+use squalr_engine_api::commands::scan::save_session::scan_save_session_response::ScanSaveSessionResponse;
+use squalr_engine_api::structures::settings::scan_settings::ScanSettings;
+use squalr_engine_api::structures::snapshots::snapshot_session::SnapshotSessionView;
+use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanSaveSessionRequest {
+    type ResponseType = ScanSaveSessionResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let (process_id, process_name) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .map(|opened_process_info| (opened_process_info.get_process_id(), opened_process_info.get_name().to_string()))
+            .unwrap_or_default();
+        let scan_settings = ScanSettingsConfig::get_full_config()
+            .read()
+            .map(|scan_settings| *scan_settings)
+            .unwrap_or_else(|_| ScanSettings::default());
+        let snapshot_lock = engine_privileged_state.get_snapshot();
+
+        // The snapshot is serialized in place, so its read lock is held while the session is written.
+        let snapshot = match snapshot_lock.read() {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+
+                return ScanSaveSessionResponse { success: false };
+            }
+        };
+        let snapshot_session_view = SnapshotSessionView::new(&snapshot, process_id, &process_name, &scan_settings);
+
+        match write_scan_session_file(&self.file_path, &snapshot_session_view) {
+            Ok(()) => {
+                log::info!("Saved scan session to '{}'.", self.file_path.display());
+
+                ScanSaveSessionResponse { success: true }
+            }
+            Err(error) => {
+                log::error!("{}", error);
+
+                ScanSaveSessionResponse { success: false }
+            }
+        }
+    }
+}
@@ -19,12 +19,33 @@ impl PrivilegedCommandExecutor for ScanCommand {
             ScanCommand::New { scan_new_request } => scan_new_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanCommand::RefreshRegions { scan_refresh_regions_request } => scan_refresh_regions_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             ScanCommand::CollectValues { scan_value_collector_request } => scan_value_collector_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
             ScanCommand::ElementScan { element_scan_request } => element_scan_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanCommand::ClassInstances { scan_class_instances_request } => scan_class_instances_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::CorrelateSample { scan_correlate_sample_request } => scan_correlate_sample_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::CorrelateRank { scan_correlate_rank_request } => scan_correlate_rank_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::Differential { scan_differential_request } => scan_differential_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::SaveSession { scan_save_session_request } => scan_save_session_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::LoadSession { scan_load_session_request } => scan_load_session_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
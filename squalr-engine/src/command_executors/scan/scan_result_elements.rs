@@ -0,0 +1,84 @@
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use std::cmp::max;
+
+/// Visits the address of every scan result in the snapshot, in the order that the snapshot indexes them.
+pub(crate) fn for_each_scan_result<Visit>(
+    snapshot: &Snapshot,
+    mut visit: Visit,
+) where
+    Visit: FnMut(&SnapshotRegion, u64, &DataTypeRef, MemoryAlignment),
+{
+    for snapshot_region in snapshot.get_snapshot_regions() {
+        for snapshot_region_filter_collection in snapshot_region.get_scan_results().get_filter_collections() {
+            let data_type_ref = snapshot_region_filter_collection.get_data_type_ref();
+            let memory_alignment = snapshot_region_filter_collection.get_memory_alignment();
+            let data_type_size = SymbolRegistry::get_instance().get_unit_size_in_bytes(data_type_ref);
+
+            for snapshot_region_filter in snapshot_region_filter_collection.iter() {
+                for element_index in 0..snapshot_region_filter.get_element_count(data_type_size, memory_alignment) {
+                    let address = snapshot_region_filter
+                        .get_base_address()
+                        .saturating_add(element_index * memory_alignment as u64);
+
+                    visit(snapshot_region, address, data_type_ref, memory_alignment);
+                }
+            }
+        }
+    }
+}
+
+/// Narrows the scan results of the snapshot to those accepted by the predicate, replacing the filters of every region
+/// with a single element filter for each kept result. Returns the number of results kept.
+pub(crate) fn retain_scan_results<Predicate>(
+    snapshot: &mut Snapshot,
+    mut predicate: Predicate,
+) -> u64
+where
+    Predicate: FnMut(&SnapshotRegion, u64, &DataTypeRef) -> bool,
+{
+    let mut retained_result_count = 0;
+
+    for snapshot_region in snapshot.get_snapshot_regions_mut() {
+        let mut snapshot_region_filter_collections = vec![];
+
+        for snapshot_region_filter_collection in snapshot_region.get_scan_results().get_filter_collections() {
+            let data_type_ref = snapshot_region_filter_collection.get_data_type_ref();
+            let memory_alignment = snapshot_region_filter_collection.get_memory_alignment();
+            let data_type_size = SymbolRegistry::get_instance().get_unit_size_in_bytes(data_type_ref);
+            let element_size = max(data_type_size, memory_alignment as u64);
+            let mut snapshot_region_filters = vec![];
+
+            for snapshot_region_filter in snapshot_region_filter_collection.iter() {
+                for element_index in 0..snapshot_region_filter.get_element_count(data_type_size, memory_alignment) {
+                    let address = snapshot_region_filter
+                        .get_base_address()
+                        .saturating_add(element_index * memory_alignment as u64);
+
+                    if predicate(snapshot_region, address, data_type_ref) {
+                        snapshot_region_filters.push(SnapshotRegionFilter::new(address, element_size));
+                    }
+                }
+            }
+
+            if !snapshot_region_filters.is_empty() {
+                retained_result_count += snapshot_region_filters.len() as u64;
+                snapshot_region_filter_collections.push(SnapshotRegionFilterCollection::new(
+                    vec![snapshot_region_filters],
+                    data_type_ref.clone(),
+                    memory_alignment,
+                ));
+            }
+        }
+
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(snapshot_region_filter_collections));
+    }
+
+    retained_result_count
+}
@@ -0,0 +1,178 @@
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use squalr_engine_api::structures::snapshots::snapshot_session::{SnapshotSession, SnapshotSessionView};
+use squalr_engine_api::structures::snapshots::snapshot_session_error::SnapshotSessionError;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// The bytes that begin every session file, identifying it before any decoding is attempted.
+const SESSION_FILE_MAGIC: &[u8; 8] = b"SQLRSESN";
+
+/// The largest decoded session accepted, such that a corrupt length cannot make the decoder allocate without bound. Well beyond the
+/// values of any realistic snapshot, which are held in memory once restored regardless.
+const MAX_SESSION_SIZE_BYTES: usize = if usize::BITS >= 64 { (1u64 << 36) as usize } else { usize::MAX };
+
+/// Writes a header of the file magic and session version, followed by the deflate compressed session, as snapshot values are often
+/// large and highly repetitive. The session is encoded directly into the compressed stream rather than buffered in memory first.
+pub fn write_scan_session_file(
+    file_path: &Path,
+    snapshot_session_view: &SnapshotSessionView,
+) -> Result<(), SnapshotSessionError> {
+    let display_path = file_path.display().to_string();
+    let file = File::create(file_path).map_err(|error| SnapshotSessionError::io(&display_path, error.to_string()))?;
+    let mut writer = BufWriter::new(file);
+
+    writer
+        .write_all(SESSION_FILE_MAGIC)
+        .and_then(|_| writer.write_all(&SnapshotSession::VERSION.to_le_bytes()))
+        .map_err(|error| SnapshotSessionError::io(&display_path, error.to_string()))?;
+
+    let mut deflate_encoder = DeflateEncoder::new(writer, Compression::default());
+
+    bincode::serde::encode_into_std_write(snapshot_session_view, &mut deflate_encoder, bincode::config::standard())
+        .map_err(|error| SnapshotSessionError::malformed(&display_path, error.to_string()))?;
+    deflate_encoder
+        .finish()
+        .and_then(|mut writer| writer.flush())
+        .map_err(|error| SnapshotSessionError::io(&display_path, error.to_string()))?;
+
+    Ok(())
+}
+
+/// Reads a session written by `write_scan_session_file`, rejecting other files and sessions of other versions before decoding.
+pub fn read_scan_session_file(file_path: &Path) -> Result<SnapshotSession, SnapshotSessionError> {
+    let display_path = file_path.display().to_string();
+    let file = File::open(file_path).map_err(|error| SnapshotSessionError::io(&display_path, error.to_string()))?;
+    let mut reader = BufReader::new(file);
+    let mut magic = [0u8; SESSION_FILE_MAGIC.len()];
+    let mut version = [0u8; size_of::<u32>()];
+
+    reader
+        .read_exact(&mut magic)
+        .and_then(|_| reader.read_exact(&mut version))
+        .map_err(|error| SnapshotSessionError::malformed(&display_path, error.to_string()))?;
+
+    if &magic != SESSION_FILE_MAGIC {
+        return Err(SnapshotSessionError::malformed(&display_path, "not a scan session file"));
+    }
+
+    let version = u32::from_le_bytes(version);
+
+    if version != SnapshotSession::VERSION {
+        return Err(SnapshotSessionError::UnsupportedVersion {
+            version,
+            expected_version: SnapshotSession::VERSION,
+        });
+    }
+
+    bincode::serde::decode_from_std_read::<SnapshotSession, _, _>(
+        &mut DeflateDecoder::new(reader),
+        bincode::config::standard().with_limit::<MAX_SESSION_SIZE_BYTES>(),
+    )
+    .map_err(|error| SnapshotSessionError::malformed(&display_path, error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SESSION_FILE_MAGIC, read_scan_session_file, write_scan_session_file};
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+    use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+    use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+    use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+    use squalr_engine_api::structures::settings::scan_settings::ScanSettings;
+    use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+    use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+    use squalr_engine_api::structures::snapshots::snapshot_session::{SnapshotSession, SnapshotSessionView};
+    use squalr_engine_api::structures::snapshots::snapshot_session_error::SnapshotSessionError;
+
+    #[test]
+    fn read_scan_session_file_checks_the_header_before_decoding() {
+        let temp_directory = tempfile::tempdir().expect("temp directory should be created");
+        let file_path = temp_directory.path().join("scan.session");
+        let snapshot = Snapshot::new();
+        let scan_settings = ScanSettings::default();
+
+        write_scan_session_file(&file_path, &SnapshotSessionView::new(&snapshot, 1234, "game.exe", &scan_settings)).expect("session should be written");
+
+        let mut file_contents = std::fs::read(&file_path).expect("session should be readable");
+
+        assert!(file_contents.starts_with(SESSION_FILE_MAGIC));
+        assert_eq!(
+            read_scan_session_file(&file_path)
+                .expect("session should be read")
+                .process_id,
+            1234
+        );
+
+        file_contents[SESSION_FILE_MAGIC.len()..SESSION_FILE_MAGIC.len() + 4].copy_from_slice(&(SnapshotSession::VERSION + 1).to_le_bytes());
+        std::fs::write(&file_path, &file_contents).expect("session should be written");
+
+        assert_eq!(
+            read_scan_session_file(&file_path).err(),
+            Some(SnapshotSessionError::UnsupportedVersion {
+                version: SnapshotSession::VERSION + 1,
+                expected_version: SnapshotSession::VERSION,
+            })
+        );
+
+        std::fs::write(&file_path, b"PK\x03\x04 not a session").expect("file should be written");
+
+        assert!(matches!(read_scan_session_file(&file_path), Err(SnapshotSessionError::Malformed { .. })));
+    }
+
+    #[test]
+    fn write_scan_session_file_round_trips_snapshot_values_and_filters() {
+        let temp_directory = tempfile::tempdir().expect("temp directory should be created");
+        let file_path = temp_directory.path().join("scan.session");
+        let scan_settings = ScanSettings::default();
+        let mut snapshot = Snapshot::new();
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, 0x2000), vec![0x2000]);
+
+        snapshot_region.current_values = (0..0x2000).map(|value_index| value_index as u8).collect();
+        snapshot_region.previous_values = vec![0x7F; 0x2000];
+        snapshot_region.page_boundary_tombstones.insert(0x2000);
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![
+                vec![SnapshotRegionFilter::new(0x1000, 4)],
+                vec![
+                    SnapshotRegionFilter::new(0x2000, 8),
+                    SnapshotRegionFilter::new(0x2FF0, 16),
+                ],
+            ],
+            DataTypeRef::new("u32"),
+            MemoryAlignment::Alignment4,
+        )]));
+        snapshot.capture_memory_pages(vec![NormalizedRegion::new(0x1000, 0x2000)]);
+        snapshot.set_snapshot_regions(vec![snapshot_region]);
+
+        write_scan_session_file(&file_path, &SnapshotSessionView::new(&snapshot, 1234, "game.exe", &scan_settings)).expect("session should be written");
+
+        let mut restored_snapshot = Snapshot::new();
+
+        read_scan_session_file(&file_path)
+            .expect("session should be read")
+            .restore(&mut restored_snapshot)
+            .expect("session should be restored");
+
+        let restored_snapshot_region = &restored_snapshot.get_snapshot_regions()[0];
+        let restored_filters: Vec<(u64, u64)> = restored_snapshot_region
+            .get_scan_results()
+            .get_filter_collections()[0]
+            .iter()
+            .map(|snapshot_region_filter| (snapshot_region_filter.get_base_address(), snapshot_region_filter.get_region_size()))
+            .collect();
+
+        assert_eq!(restored_snapshot_region.current_values, snapshot.get_snapshot_regions()[0].current_values);
+        assert_eq!(restored_snapshot_region.previous_values, vec![0x7F; 0x2000]);
+        assert!(
+            restored_snapshot_region
+                .page_boundary_tombstones
+                .contains(&0x2000)
+        );
+        assert_eq!(restored_filters, vec![(0x1000, 4), (0x2000, 8), (0x2FF0, 16)]);
+    }
+}
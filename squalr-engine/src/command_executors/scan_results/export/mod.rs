@@ -0,0 +1 @@
+pub mod scan_results_export_request_executor;
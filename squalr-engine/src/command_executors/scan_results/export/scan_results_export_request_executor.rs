@@ -0,0 +1,75 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan_results::scan_results_export_serializer::write_scan_results_export;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan_results::export::scan_results_export_request::ScanResultsExportRequest;
+use squalr_engine_api::commands::scan_results::export::scan_results_export_response::ScanResultsExportResponse;
+use squalr_engine_api::structures::scan_results::scan_results_export_error::ScanResultsExportError;
+use squalr_engine_api::structures::scan_results::scan_results_export_format::ScanResultsExportFormat;
+use std::fs::File;
+use std::io::BufWriter;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanResultsExportRequest {
+    type ResponseType = ScanResultsExportResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let format = match self
+            .format
+            .or_else(|| ScanResultsExportFormat::from_file_path(&self.file_path))
+        {
+            Some(format) => format,
+            None => {
+                log::error!("{}", ScanResultsExportError::unknown_format(self.file_path.display().to_string()));
+
+                return ScanResultsExportResponse::default();
+            }
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+
+        // Collect modules if possible so that results can be exported relative to their module, allowing them to be imported after a restart.
+        let modules = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => os_providers.memory_query.get_modules(&opened_process_info),
+            None => vec![],
+        };
+        let snapshot = engine_privileged_state.get_snapshot();
+        let snapshot = match snapshot.read() {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+
+                return ScanResultsExportResponse::default();
+            }
+        };
+        let file = match File::create(&self.file_path) {
+            Ok(file) => file,
+            Err(error) => {
+                log::error!("{}", ScanResultsExportError::io(self.file_path.display().to_string(), error.to_string()));
+
+                return ScanResultsExportResponse::default();
+            }
+        };
+        let mut writer = BufWriter::new(file);
+
+        match write_scan_results_export(&snapshot, format, os_providers.memory_query.as_ref(), &modules, &mut writer) {
+            Ok(exported_result_count) => {
+                log::info!("Exported {} scan results to '{}'.", exported_result_count, self.file_path.display());
+
+                ScanResultsExportResponse {
+                    success: true,
+                    exported_result_count,
+                }
+            }
+            Err(error) => {
+                log::error!("{}", ScanResultsExportError::io(self.file_path.display().to_string(), error.to_string()));
+
+                ScanResultsExportResponse::default()
+            }
+        }
+    }
+}
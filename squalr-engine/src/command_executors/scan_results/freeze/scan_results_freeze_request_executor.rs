@@ -2,6 +2,7 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
 use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_response::ScanResultsFreezeResponse;
+use squalr_engine_api::registries::freeze_list::freeze_list_entry::FreezeListEntry;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::structures::memory::pointer::Pointer;
 use std::sync::Arc;
@@ -77,8 +78,13 @@ impl PrivilegedCommandRequestExecutor for ScanResultsFreezeRequest {
                                 .memory_read
                                 .read(&opened_process_info, address, &mut data_value)
                             {
-                                freeze_list_registry_guard.set_address_frozen(pointer, data_value.get_value_bytes().to_vec());
-                                continue;
+                                match FreezeListEntry::from_policy(data_value, &self.freeze_policy) {
+                                    Ok(freeze_list_entry) => {
+                                        freeze_list_registry_guard.set_address_frozen(pointer, freeze_list_entry);
+                                        continue;
+                                    }
+                                    Err(error) => log::error!("Failed to freeze scan result: {}", error),
+                                }
                             }
                         }
                     }
@@ -0,0 +1 @@
+pub mod scan_results_import_request_executor;
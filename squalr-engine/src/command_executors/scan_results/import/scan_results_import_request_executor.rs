@@ -0,0 +1,234 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan_results::scan_results_export_serializer::read_scan_results_export;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan_results::import::scan_results_import_request::ScanResultsImportRequest;
+use squalr_engine_api::commands::scan_results::import::scan_results_import_response::ScanResultsImportResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use squalr_engine_api::structures::scan_results::scan_results_export_error::ScanResultsExportError;
+use squalr_engine_api::structures::scan_results::scan_results_export_format::ScanResultsExportFormat;
+use squalr_engine_api::structures::scan_results::scan_results_export_record::ScanResultsExportRecord;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use squalr_engine_scanning::scanners::scan_execution_context::ScanExecutionContext;
+use squalr_engine_scanning::scanners::value_collector_task::ValueCollector;
+use squalr_engine_session::os::PageRetrievalMode;
+use squalr_engine_session::os::engine_os_provider::MemoryQueryProvider;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanResultsImportRequest {
+    type ResponseType = ScanResultsImportResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let opened_process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => opened_process_info,
+            None => {
+                log::error!("Cannot import scan results, no opened process.");
+
+                return ScanResultsImportResponse::default();
+            }
+        };
+        let format = match self
+            .format
+            .or_else(|| ScanResultsExportFormat::from_file_path(&self.file_path))
+        {
+            Some(format) => format,
+            None => {
+                log::error!("{}", ScanResultsExportError::unknown_format(self.file_path.display().to_string()));
+
+                return ScanResultsImportResponse::default();
+            }
+        };
+        let scan_results_export_records = match File::open(&self.file_path)
+            .map_err(|error| ScanResultsExportError::io(self.file_path.display().to_string(), error.to_string()))
+            .and_then(|file| read_scan_results_export(format, BufReader::new(file)))
+        {
+            Ok(scan_results_export_records) => scan_results_export_records,
+            Err(error) => {
+                log::error!("{}", error);
+
+                return ScanResultsImportResponse::default();
+            }
+        };
+        let os_providers = engine_privileged_state.get_os_providers();
+        let modules = os_providers.memory_query.get_modules(&opened_process_info);
+        let memory_pages = os_providers
+            .memory_query
+            .get_memory_page_bounds(&opened_process_info, PageRetrievalMode::FromSettings);
+        let (snapshot_regions, imported_result_count) =
+            create_imported_snapshot_regions(&scan_results_export_records, &memory_pages, os_providers.memory_query.as_ref(), &modules);
+        let unresolved_result_count = scan_results_export_records.len() as u64 - imported_result_count;
+        let snapshot = engine_privileged_state.get_snapshot();
+
+        match snapshot.write() {
+            Ok(mut snapshot) => {
+                // The imported results behave as though all other memory was eliminated by a scan, so every page is still captured.
+                snapshot.capture_memory_pages(memory_pages);
+                snapshot.set_snapshot_regions(snapshot_regions);
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanResultsImportResponse::default();
+            }
+        }
+
+        // Read the current values of the imported results, such that narrowing can resume immediately.
+        let memory_read_provider = os_providers.memory_read.clone();
+        let scan_execution_context = ScanExecutionContext::new(
+            None,
+            None,
+            Some(Arc::new(move |opened_process_info, address, values| {
+                memory_read_provider.read_bytes(opened_process_info, address, values)
+            })),
+        );
+
+        ValueCollector::collect_values(opened_process_info, snapshot, true, &scan_execution_context);
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: true });
+
+        if unresolved_result_count > 0 {
+            log::warn!(
+                "Skipped {} imported scan results that were duplicates, had unknown data types, were in modules that are not loaded, or were outside of allocated memory.",
+                unresolved_result_count
+            );
+        }
+
+        log::info!("Imported {} scan results from '{}'.", imported_result_count, self.file_path.display());
+
+        ScanResultsImportResponse {
+            success: true,
+            imported_result_count,
+            unresolved_result_count,
+        }
+    }
+}
+
+/// Creates snapshot regions containing a filter for each imported result, returning these regions and the number of distinct results placed.
+/// Results with a module are placed relative to that module, and otherwise at their absolute address.
+/// Results of unknown data types, in modules that are not loaded, or that do not fall within allocated memory, are skipped.
+pub(crate) fn create_imported_snapshot_regions(
+    scan_results_export_records: &[ScanResultsExportRecord],
+    memory_pages: &[NormalizedRegion],
+    memory_query: &dyn MemoryQueryProvider,
+    modules: &Vec<NormalizedModule>,
+) -> (Vec<SnapshotRegion>, u64) {
+    let symbol_registry = SymbolRegistry::get_instance();
+    let mut memory_pages = memory_pages.to_vec();
+    let mut resolved_results = vec![];
+    let mut containing_memory_page_indices = BTreeSet::new();
+
+    memory_pages.sort_by_key(|memory_page| memory_page.get_base_address());
+
+    for scan_results_export_record in scan_results_export_records {
+        let data_type_ref = DataTypeRef::new(&scan_results_export_record.data_type_id);
+
+        if !symbol_registry.is_valid(&data_type_ref) {
+            continue;
+        }
+
+        // A result in a module that is not loaded cannot be located, as its recorded absolute address is likely stale.
+        let address = if scan_results_export_record.module.is_empty() {
+            scan_results_export_record.address
+        } else {
+            match memory_query.resolve_module(modules, &scan_results_export_record.module) {
+                0 => continue,
+                module_base_address => module_base_address.saturating_add(scan_results_export_record.module_offset),
+            }
+        };
+        let data_type_size = symbol_registry.get_unit_size_in_bytes(&data_type_ref);
+
+        if let Some(memory_page_index) = find_containing_region_index(&memory_pages, address, data_type_size, |memory_page| {
+            (memory_page.get_base_address(), memory_page.get_end_address())
+        }) {
+            containing_memory_page_indices.insert(memory_page_index);
+            resolved_results.push((address, data_type_ref, data_type_size));
+        }
+    }
+
+    // Only pages containing results are kept, mirroring a snapshot where all other memory was eliminated by scans.
+    let containing_memory_pages = containing_memory_page_indices
+        .into_iter()
+        .map(|memory_page_index| memory_pages[memory_page_index].clone())
+        .collect();
+    let mut snapshot_regions = Snapshot::create_snapshot_regions(containing_memory_pages);
+    let mut filters_by_region: Vec<Vec<(DataTypeRef, Vec<SnapshotRegionFilter>)>> = vec![vec![]; snapshot_regions.len()];
+    let mut imported_result_count = 0u64;
+
+    resolved_results.sort_by(|(left_address, left_data_type_ref, _), (right_address, right_data_type_ref, _)| {
+        left_address.cmp(right_address).then_with(|| {
+            left_data_type_ref
+                .get_data_type_id()
+                .cmp(right_data_type_ref.get_data_type_id())
+        })
+    });
+    resolved_results.dedup();
+
+    for (address, data_type_ref, data_type_size) in resolved_results {
+        let Some(region_index) = find_containing_region_index(&snapshot_regions, address, data_type_size, |snapshot_region| {
+            (snapshot_region.get_base_address(), snapshot_region.get_end_address())
+        }) else {
+            continue;
+        };
+        let region_filters = &mut filters_by_region[region_index];
+        let snapshot_region_filter = SnapshotRegionFilter::new(address, data_type_size);
+
+        imported_result_count += 1;
+
+        match region_filters
+            .iter_mut()
+            .find(|(filter_data_type_ref, _filters)| *filter_data_type_ref == data_type_ref)
+        {
+            Some((_data_type_ref, filters)) => filters.push(snapshot_region_filter),
+            None => region_filters.push((data_type_ref, vec![snapshot_region_filter])),
+        }
+    }
+
+    for (snapshot_region, region_filters) in snapshot_regions.iter_mut().zip(filters_by_region) {
+        let snapshot_region_filter_collections = region_filters
+            .into_iter()
+            .map(|(data_type_ref, filters)| SnapshotRegionFilterCollection::new(vec![filters], data_type_ref, MemoryAlignment::Alignment1))
+            .collect();
+
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(snapshot_region_filter_collections));
+    }
+
+    (snapshot_regions, imported_result_count)
+}
+
+/// Finds the region that fully contains the given value, assuming regions are sorted by base address and do not overlap.
+fn find_containing_region_index<Region, GetBounds>(
+    regions: &[Region],
+    address: u64,
+    size: u64,
+    get_bounds: GetBounds,
+) -> Option<usize>
+where
+    GetBounds: Fn(&Region) -> (u64, u64),
+{
+    let region_index = regions
+        .partition_point(|region| get_bounds(region).0 <= address)
+        .checked_sub(1)?;
+    let (_base_address, end_address) = get_bounds(&regions[region_index]);
+
+    if address.saturating_add(size) <= end_address {
+        Some(region_index)
+    } else {
+        None
+    }
+}
@@ -1,7 +1,11 @@
 pub mod delete;
+pub mod export;
 pub mod freeze;
+pub mod import;
 pub mod list;
 pub mod query;
 pub mod refresh;
 pub mod scan_results_command_executor;
+pub mod scan_results_export_serializer;
+pub mod scan_results_query_evaluator;
 pub mod set_property;
@@ -1,4 +1,5 @@
 use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan_results::scan_results_query_evaluator::collect_queried_scan_result_indices;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
 use squalr_engine_api::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
@@ -6,6 +7,8 @@ use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::structures::memory::pointer::Pointer;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
 use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
+use squalr_engine_session::module_symbols::module_symbol_resolver::ModuleSymbolResolver;
+use squalr_engine_session::runtime_classes::runtime_class_resolver::RuntimeClassResolver;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
@@ -34,7 +37,16 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
         };
 
         if let Ok(snapshot) = engine_privileged_state.get_snapshot().read() {
-            result_count = snapshot.get_number_of_results();
+            // Sort and filter all results here, such that only the requested page is sent back to the caller.
+            let queried_scan_result_indices = collect_queried_scan_result_indices(
+                &snapshot,
+                &self.query_options,
+                os_providers.memory_query.as_ref(),
+                &modules,
+                &engine_privileged_state.get_scan_results_query_cache(),
+            );
+
+            result_count = queried_scan_result_indices.len() as u64;
             last_page_index = result_count.saturating_sub(1) / results_page_size;
             total_size_in_bytes = snapshot.get_byte_count();
 
@@ -44,8 +56,8 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
                 .saturating_add(results_page_size)
                 .min(result_count);
 
-            for result_index in index_of_first_page_entry..index_of_last_page_entry {
-                let scan_result_base = match snapshot.get_scan_result(result_index) {
+            for result_index in &queried_scan_result_indices[index_of_first_page_entry as usize..index_of_last_page_entry as usize] {
+                let scan_result_base = match snapshot.get_scan_result(*result_index) {
                     None => break,
                     Some(scan_result_base) => scan_result_base,
                 };
@@ -81,6 +93,21 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
                     module_offset = address;
                 }
 
+                // Label this scan result by the module symbol containing it, if the module symbols could be loaded.
+                let symbol_label = engine_privileged_state
+                    .get_process_manager()
+                    .get_opened_process()
+                    .and_then(|opened_process_info| ModuleSymbolResolver::format_symbol_label(os_providers, &opened_process_info, &modules, address))
+                    .unwrap_or_default();
+
+                // Label objects whose vtable belongs to a class discovered by a previous class query.
+                let class_name = engine_privileged_state
+                    .get_process_manager()
+                    .get_opened_process()
+                    .and_then(|opened_process_info| RuntimeClassResolver::find_object_class(os_providers, &opened_process_info, address))
+                    .map(|runtime_class| runtime_class.get_class_name().to_string())
+                    .unwrap_or_default();
+
                 let pointer = Pointer::new(module_offset, vec![], module_name.clone());
                 let is_frozen = if let Ok(freeze_list_registry) = engine_privileged_state.get_freeze_list_registry().read() {
                     freeze_list_registry.is_address_frozen(&pointer)
@@ -97,14 +124,18 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
                     })
                     .unwrap_or_default();
 
-                scan_results_list.push(ScanResult::new(
+                let mut scan_result = ScanResult::new(
                     scan_result_base,
                     module_name,
                     module_offset,
                     recently_read_value,
                     recently_read_display_values,
                     is_frozen,
-                ));
+                );
+
+                scan_result.set_symbol_label(symbol_label);
+                scan_result.set_class_name(class_name);
+                scan_results_list.push(scan_result);
             }
         }
 
@@ -5,6 +5,8 @@ use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_res
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::structures::memory::pointer::Pointer;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+use squalr_engine_session::module_symbols::module_symbol_resolver::ModuleSymbolResolver;
+use squalr_engine_session::runtime_classes::runtime_class_resolver::RuntimeClassResolver;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for ScanResultsRefreshRequest {
@@ -72,6 +74,21 @@ impl PrivilegedCommandRequestExecutor for ScanResultsRefreshRequest {
                     module_offset = address;
                 }
 
+                // Label this scan result by the module symbol containing it, if the module symbols could be loaded.
+                let symbol_label = engine_privileged_state
+                    .get_process_manager()
+                    .get_opened_process()
+                    .and_then(|opened_process_info| ModuleSymbolResolver::format_symbol_label(os_providers, &opened_process_info, &modules, address))
+                    .unwrap_or_default();
+
+                // Label objects whose vtable belongs to a class discovered by a previous class query.
+                let class_name = engine_privileged_state
+                    .get_process_manager()
+                    .get_opened_process()
+                    .and_then(|opened_process_info| RuntimeClassResolver::find_object_class(os_providers, &opened_process_info, address))
+                    .map(|runtime_class| runtime_class.get_class_name().to_string())
+                    .unwrap_or_default();
+
                 let pointer = Pointer::new(module_offset, vec![], module_name.clone());
                 let is_frozen = if let Ok(freeze_list_registry) = engine_privileged_state.get_freeze_list_registry().read() {
                     freeze_list_registry.is_address_frozen(&pointer)
@@ -88,14 +105,18 @@ impl PrivilegedCommandRequestExecutor for ScanResultsRefreshRequest {
                     })
                     .unwrap_or_default();
 
-                scan_results_list.push(ScanResult::new(
+                let mut full_scan_result = ScanResult::new(
                     scan_result,
                     module_name,
                     module_offset,
                     recently_read_value,
                     recently_read_display_values,
                     is_frozen,
-                ));
+                );
+
+                full_scan_result.set_symbol_label(symbol_label);
+                full_scan_result.set_class_name(class_name);
+                scan_results_list.push(full_scan_result);
             }
         }
 
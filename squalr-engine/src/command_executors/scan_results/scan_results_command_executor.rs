@@ -31,6 +31,12 @@ impl PrivilegedCommandExecutor for ScanResultsCommand {
             ScanResultsCommand::Delete { results_delete_request } => results_delete_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanResultsCommand::Export { results_export_request } => results_export_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanResultsCommand::Import { results_import_request } => results_import_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
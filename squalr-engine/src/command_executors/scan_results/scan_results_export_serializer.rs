@@ -0,0 +1,114 @@
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::scan_results::scan_results_export_error::ScanResultsExportError;
+use squalr_engine_api::structures::scan_results::scan_results_export_format::ScanResultsExportFormat;
+use squalr_engine_api::structures::scan_results::scan_results_export_record::ScanResultsExportRecord;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_session::os::engine_os_provider::MemoryQueryProvider;
+use std::io::{self, BufRead, Write};
+
+/// Streams every scan result in the snapshot to the writer, returning the number of results written.
+/// Values are taken from the snapshot rather than read from process memory, matching what the results showed at export time.
+pub fn write_scan_results_export<W: Write>(
+    snapshot: &Snapshot,
+    format: ScanResultsExportFormat,
+    memory_query: &dyn MemoryQueryProvider,
+    modules: &Vec<NormalizedModule>,
+    writer: &mut W,
+) -> io::Result<u64> {
+    let symbol_registry = SymbolRegistry::get_instance();
+    let mut exported_result_count = 0u64;
+
+    match format {
+        ScanResultsExportFormat::Csv => writeln!(writer, "{}", ScanResultsExportRecord::CSV_HEADER)?,
+        ScanResultsExportFormat::Json => write!(writer, "[")?,
+    }
+
+    for snapshot_region in snapshot.get_snapshot_regions() {
+        for snapshot_region_filter_collection in snapshot_region.get_scan_results().get_filter_collections() {
+            let data_type_ref = snapshot_region_filter_collection.get_data_type_ref();
+            let memory_alignment = snapshot_region_filter_collection.get_memory_alignment();
+            let data_type_size = symbol_registry.get_unit_size_in_bytes(data_type_ref);
+
+            for snapshot_region_filter in snapshot_region_filter_collection.iter() {
+                let element_count = snapshot_region_filter.get_element_count(data_type_size, memory_alignment);
+
+                for element_index in 0..element_count {
+                    let address = snapshot_region_filter
+                        .get_base_address()
+                        .saturating_add(element_index * memory_alignment as u64);
+                    let (module, module_offset) = memory_query
+                        .address_to_module(address, modules)
+                        .unwrap_or_default();
+                    let scan_results_export_record = ScanResultsExportRecord {
+                        address,
+                        module,
+                        module_offset,
+                        data_type_id: data_type_ref.get_data_type_id().to_string(),
+                        current_value: format_value(snapshot_region.get_current_value(address, data_type_ref)),
+                        previous_value: format_value(snapshot_region.get_previous_value(address, data_type_ref)),
+                    };
+
+                    match format {
+                        ScanResultsExportFormat::Csv => writeln!(writer, "{}", scan_results_export_record.to_csv_row())?,
+                        ScanResultsExportFormat::Json => {
+                            if exported_result_count > 0 {
+                                write!(writer, ",")?;
+                            }
+
+                            writeln!(writer)?;
+                            serde_json::to_writer(&mut *writer, &scan_results_export_record)?;
+                        }
+                    }
+
+                    exported_result_count += 1;
+                }
+            }
+        }
+    }
+
+    if format == ScanResultsExportFormat::Json {
+        writeln!(writer, "\n]")?;
+    }
+
+    writer.flush()?;
+
+    Ok(exported_result_count)
+}
+
+/// Reads all records from an exported scan results file. The CSV header row is optional.
+pub fn read_scan_results_export<R: BufRead>(
+    format: ScanResultsExportFormat,
+    reader: R,
+) -> Result<Vec<ScanResultsExportRecord>, ScanResultsExportError> {
+    match format {
+        ScanResultsExportFormat::Json => serde_json::from_reader(reader).map_err(|error| ScanResultsExportError::malformed_json(error.to_string())),
+        ScanResultsExportFormat::Csv => {
+            let mut scan_results_export_records = vec![];
+
+            for (line_index, line) in reader.lines().enumerate() {
+                let line = line.map_err(|error| ScanResultsExportError::malformed_csv_row(line_index + 1, error.to_string()))?;
+
+                if line.trim().is_empty() || (line_index == 0 && line.trim_start().starts_with("address")) {
+                    continue;
+                }
+
+                scan_results_export_records.push(ScanResultsExportRecord::from_csv_row(&line, line_index + 1)?);
+            }
+
+            Ok(scan_results_export_records)
+        }
+    }
+}
+
+fn format_value(data_value: Option<DataValue>) -> Option<String> {
+    data_value
+        .and_then(|data_value| {
+            SymbolRegistry::get_instance()
+                .anonymize_value(&data_value, AnonymousValueStringFormat::Decimal)
+                .ok()
+        })
+        .map(|anonymous_value_string| anonymous_value_string.get_anonymous_value_string().to_string())
+}
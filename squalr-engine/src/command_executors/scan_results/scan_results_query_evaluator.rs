@@ -0,0 +1,443 @@
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::scan_results::scan_results_query_cache::ScanResultsQueryCache;
+use squalr_engine_api::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
+use squalr_engine_api::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
+use squalr_engine_api::structures::scanning::comparisons::scan_function_scalar::ScanFunctionScalar;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::ScanConstraintExpression;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
+use squalr_engine_session::os::engine_os_provider::MemoryQueryProvider;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// A scan result reduced to the fields needed to filter and sort it.
+struct QueriedScanResult {
+    scan_result_index: u64,
+    address: u64,
+    module: Option<(String, u64)>,
+    current_value: Option<NumericValue>,
+    delta: Option<NumericValue>,
+}
+
+/// A value interpreted as a number for sorting. Integers are kept exact, as 64-bit values are not all representable as floats.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum NumericValue {
+    Integer(i128),
+    Float(f64),
+}
+
+impl NumericValue {
+    /// Gets the value as a float, for uses such as statistics that do not require integers to be exact.
+    pub(crate) fn to_f64(self) -> f64 {
+        match self {
+            NumericValue::Integer(value) => value as f64,
+            NumericValue::Float(value) => value,
+        }
+    }
+
+    /// Subtracts another value, exactly if both are integers. Returns `None` if the difference overflows.
+    fn checked_sub(
+        self,
+        other: NumericValue,
+    ) -> Option<NumericValue> {
+        match (self, other) {
+            (NumericValue::Integer(left), NumericValue::Integer(right)) => left.checked_sub(right).map(NumericValue::Integer),
+            _ => Some(NumericValue::Float(self.to_f64() - other.to_f64())),
+        }
+    }
+
+    /// Orders values exactly if both are integers, and otherwise as floats, such as when results of several data types are sorted.
+    fn total_cmp(
+        &self,
+        other: &NumericValue,
+    ) -> Ordering {
+        match (self, other) {
+            (NumericValue::Integer(left), NumericValue::Integer(right)) => left.cmp(right),
+            _ => self.to_f64().total_cmp(&other.to_f64()),
+        }
+    }
+}
+
+/// The most scan results that a query will sort or filter. Larger result sets are listed in snapshot order, as evaluating them would
+/// stall every page request.
+const MAX_QUERY_CANDIDATES: u64 = 1_000_000;
+
+/// Evaluates query options over every scan result in the snapshot, returning the global indices of matching results in query order.
+/// Only values already captured by the snapshot are considered, such that queries never read process memory. Results are cached per
+/// snapshot generation, query and module list, such that paging through a query evaluates it once.
+pub fn collect_queried_scan_result_indices(
+    snapshot: &Snapshot,
+    query_options: &ScanResultsQueryOptions,
+    memory_query: &dyn MemoryQueryProvider,
+    modules: &Vec<NormalizedModule>,
+    query_cache: &RwLock<ScanResultsQueryCache>,
+) -> Arc<Vec<u64>> {
+    let result_count = snapshot.get_number_of_results();
+
+    if query_options.is_unfiltered_and_unsorted() {
+        return Arc::new((0..result_count).collect());
+    }
+
+    if result_count > MAX_QUERY_CANDIDATES {
+        log::warn!(
+            "Cannot sort or filter {} scan results, listing them in scan order. Narrow the scan to at most {} results first.",
+            result_count,
+            MAX_QUERY_CANDIDATES
+        );
+
+        return Arc::new((0..result_count).collect());
+    }
+
+    let snapshot_generation = snapshot.get_generation();
+    let modules_hash = ScanResultsQueryCache::hash_modules(modules);
+    let cached_scan_result_indices = match query_cache.read() {
+        Ok(query_cache) => query_cache.get(snapshot_generation, query_options, modules_hash),
+        Err(error) => {
+            log::error!("Failed to acquire read lock on scan results query cache: {}", error);
+
+            None
+        }
+    };
+
+    if let Some(cached_scan_result_indices) = cached_scan_result_indices {
+        return cached_scan_result_indices;
+    }
+
+    let scan_result_indices = Arc::new(evaluate_query(snapshot, query_options, memory_query, modules));
+
+    match query_cache.write() {
+        Ok(mut query_cache) => query_cache.set(snapshot_generation, query_options.clone(), modules_hash, scan_result_indices.clone()),
+        Err(error) => log::error!("Failed to acquire write lock on scan results query cache: {}", error),
+    }
+
+    scan_result_indices
+}
+
+/// Evaluates query options over every scan result in the snapshot. Modules and numeric values are only resolved when the query uses them.
+fn evaluate_query(
+    snapshot: &Snapshot,
+    query_options: &ScanResultsQueryOptions,
+    memory_query: &dyn MemoryQueryProvider,
+    modules: &Vec<NormalizedModule>,
+) -> Vec<u64> {
+    let module_filter = query_options
+        .module_filter
+        .as_ref()
+        .map(|module_filter| module_filter.trim().to_ascii_lowercase())
+        .filter(|module_filter| !module_filter.is_empty());
+    let address_search = query_options
+        .address_search
+        .as_ref()
+        .map(|address_search| normalize_address_search(address_search))
+        .filter(|address_search| !address_search.is_empty());
+    let requires_module = module_filter.is_some() || address_search.is_some() || query_options.sort_key == ScanResultsSortKey::Module;
+    let requires_numeric_values = matches!(query_options.sort_key, ScanResultsSortKey::CurrentValue | ScanResultsSortKey::Delta);
+    let mut value_filters_by_data_type: HashMap<DataTypeRef, Option<ScanConstraintExpression<ScanConstraintFinalized>>> = HashMap::new();
+    let mut queried_scan_results = vec![];
+    let mut scan_result_index = 0u64;
+
+    // Results are visited in the same order that they are indexed by the snapshot, ie by region, then by data type, then by filter.
+    for snapshot_region in snapshot.get_snapshot_regions() {
+        for snapshot_region_filter_collection in snapshot_region.get_scan_results().get_filter_collections() {
+            let data_type_ref = snapshot_region_filter_collection.get_data_type_ref();
+            let memory_alignment = snapshot_region_filter_collection.get_memory_alignment();
+            let data_type_size = SymbolRegistry::get_instance().get_unit_size_in_bytes(data_type_ref);
+            let value_filter = value_filters_by_data_type
+                .entry(data_type_ref.clone())
+                .or_insert_with(|| create_value_filter(query_options, data_type_ref));
+
+            for snapshot_region_filter in snapshot_region_filter_collection.iter() {
+                let element_count = snapshot_region_filter.get_element_count(data_type_size, memory_alignment);
+
+                for element_index in 0..element_count {
+                    let address = snapshot_region_filter
+                        .get_base_address()
+                        .saturating_add(element_index * memory_alignment as u64);
+                    let current_scan_result_index = scan_result_index;
+
+                    scan_result_index += 1;
+
+                    if query_options
+                        .region_start_address
+                        .is_some_and(|region_start_address| address < region_start_address)
+                        || query_options
+                            .region_end_address
+                            .is_some_and(|region_end_address| address >= region_end_address)
+                    {
+                        continue;
+                    }
+
+                    let module = if requires_module {
+                        memory_query.address_to_module(address, modules)
+                    } else {
+                        None
+                    };
+
+                    if let Some(module_filter) = &module_filter {
+                        match &module {
+                            Some((module_name, _module_offset)) if module_name.to_ascii_lowercase() == *module_filter => {}
+                            _ => continue,
+                        }
+                    }
+
+                    if address_search
+                        .as_ref()
+                        .is_some_and(|address_search| !matches_address_search(address, &module, address_search))
+                    {
+                        continue;
+                    }
+
+                    if let Some(value_filter) = value_filter {
+                        let current_value = snapshot_region.get_current_value(address, data_type_ref);
+                        let previous_value = snapshot_region.get_previous_value(address, data_type_ref);
+
+                        if !evaluate_value_filter(value_filter, &current_value, &previous_value) {
+                            continue;
+                        }
+                    } else if query_options.value_filter.is_some() {
+                        // The value filter cannot be applied to this data type, so none of its results can match.
+                        continue;
+                    }
+
+                    let byte_offset = address.saturating_sub(snapshot_region.get_base_address());
+                    let (current_numeric_value, previous_numeric_value) = if requires_numeric_values {
+                        (
+                            read_element_numeric_value(snapshot_region.get_current_values(), byte_offset, data_type_size, data_type_ref),
+                            read_element_numeric_value(snapshot_region.get_previous_values(), byte_offset, data_type_size, data_type_ref),
+                        )
+                    } else {
+                        (None, None)
+                    };
+
+                    queried_scan_results.push(QueriedScanResult {
+                        scan_result_index: current_scan_result_index,
+                        address,
+                        module,
+                        current_value: current_numeric_value,
+                        delta: current_numeric_value
+                            .zip(previous_numeric_value)
+                            .and_then(|(current_value, previous_value)| current_value.checked_sub(previous_value)),
+                    });
+                }
+            }
+        }
+    }
+
+    sort_queried_scan_results(&mut queried_scan_results, query_options.sort_key, query_options.sort_descending);
+
+    queried_scan_results
+        .into_iter()
+        .map(|queried_scan_result| queried_scan_result.scan_result_index)
+        .collect()
+}
+
+/// Deanonymizes the value filter against a data type. Returns `None` if there is no filter, or if it cannot be applied to this type.
+fn create_value_filter(
+    query_options: &ScanResultsQueryOptions,
+    data_type_ref: &DataTypeRef,
+) -> Option<ScanConstraintExpression<ScanConstraintFinalized>> {
+    let floating_point_tolerance = ScanSettingsConfig::get_floating_point_tolerance();
+
+    query_options
+        .value_filter
+        .clone()?
+        .map_constraints(&mut |anonymous_scan_constraint: AnonymousScanConstraint| {
+            anonymous_scan_constraint
+                .deanonymize_constraint(data_type_ref, floating_point_tolerance)
+                .map(ScanConstraintFinalized::new)
+        })
+}
+
+fn evaluate_value_filter(
+    value_filter: &ScanConstraintExpression<ScanConstraintFinalized>,
+    current_value: &Option<DataValue>,
+    previous_value: &Option<DataValue>,
+) -> bool {
+    match value_filter {
+        ScanConstraintExpression::Constraint(scan_constraint_finalized) => {
+            match (scan_constraint_finalized.get_scan_function_scalar(), current_value, previous_value) {
+                (Some(ScanFunctionScalar::Immediate(compare_func)), Some(current_value), _) => compare_func(current_value.as_ptr()),
+                (Some(ScanFunctionScalar::RelativeOrDelta(compare_func)), Some(current_value), Some(previous_value)) => {
+                    compare_func(current_value.as_ptr(), previous_value.as_ptr())
+                }
+                _ => false,
+            }
+        }
+        ScanConstraintExpression::And(value_filters) => value_filters
+            .iter()
+            .all(|value_filter| evaluate_value_filter(value_filter, current_value, previous_value)),
+        ScanConstraintExpression::Or(value_filters) => value_filters
+            .iter()
+            .any(|value_filter| evaluate_value_filter(value_filter, current_value, previous_value)),
+        ScanConstraintExpression::Not(value_filter) => !evaluate_value_filter(value_filter, current_value, previous_value),
+    }
+}
+
+/// Interprets the element at the given offset of a region's captured values as a number for sorting and correlation purposes.
+/// Returns `None` if the values were not captured.
+pub(crate) fn read_element_numeric_value(
+    region_values: &[u8],
+    byte_offset: u64,
+    data_type_size: u64,
+    data_type_ref: &DataTypeRef,
+) -> Option<NumericValue> {
+    let start = usize::try_from(byte_offset).ok()?;
+    let end = start.checked_add(usize::try_from(data_type_size).ok()?)?;
+
+    read_numeric_value(region_values.get(start..end)?, data_type_ref)
+}
+
+/// Interprets value bytes as a number. Built-in numeric types are decoded directly, and other types by way of their decimal representation.
+fn read_numeric_value(
+    value_bytes: &[u8],
+    data_type_ref: &DataTypeRef,
+) -> Option<NumericValue> {
+    match data_type_ref.get_data_type_id() {
+        "u8" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(u8::from_le_bytes(bytes).into())),
+        "i8" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(i8::from_le_bytes(bytes).into())),
+        "u16" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(u16::from_le_bytes(bytes).into())),
+        "i16" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(i16::from_le_bytes(bytes).into())),
+        "u32" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(u32::from_le_bytes(bytes).into())),
+        "i32" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(i32::from_le_bytes(bytes).into())),
+        "u64" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(u64::from_le_bytes(bytes).into())),
+        "i64" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(i64::from_le_bytes(bytes).into())),
+        "f32" => read_value_array(value_bytes).map(|bytes| NumericValue::Float(f32::from_le_bytes(bytes).into())),
+        "f64" => read_value_array(value_bytes).map(|bytes| NumericValue::Float(f64::from_le_bytes(bytes))),
+        "u16be" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(u16::from_be_bytes(bytes).into())),
+        "i16be" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(i16::from_be_bytes(bytes).into())),
+        "u32be" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(u32::from_be_bytes(bytes).into())),
+        "i32be" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(i32::from_be_bytes(bytes).into())),
+        "u64be" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(u64::from_be_bytes(bytes).into())),
+        "i64be" => read_value_array(value_bytes).map(|bytes| NumericValue::Integer(i64::from_be_bytes(bytes).into())),
+        "f32be" => read_value_array(value_bytes).map(|bytes| NumericValue::Float(f32::from_be_bytes(bytes).into())),
+        "f64be" => read_value_array(value_bytes).map(|bytes| NumericValue::Float(f64::from_be_bytes(bytes))),
+        _ => to_numeric_value(&DataValue::new(data_type_ref.clone(), value_bytes.to_vec())),
+    }
+}
+
+fn read_value_array<const SIZE: usize>(value_bytes: &[u8]) -> Option<[u8; SIZE]> {
+    value_bytes.get(..SIZE)?.try_into().ok()
+}
+
+/// Interprets a value as a number by way of its decimal representation, as an integer where possible.
+fn to_numeric_value(data_value: &DataValue) -> Option<NumericValue> {
+    let anonymous_value_string = SymbolRegistry::get_instance()
+        .anonymize_value(data_value, AnonymousValueStringFormat::Decimal)
+        .ok()?;
+    let value_string = anonymous_value_string.get_anonymous_value_string().trim();
+
+    match value_string.parse::<i128>() {
+        Ok(value) => Some(NumericValue::Integer(value)),
+        Err(_) => value_string.parse::<f64>().ok().map(NumericValue::Float),
+    }
+}
+
+fn normalize_address_search(address_search: &str) -> String {
+    let address_search = address_search.trim().to_ascii_lowercase();
+
+    address_search
+        .strip_prefix("0x")
+        .map(str::to_string)
+        .unwrap_or(address_search)
+}
+
+/// Matches the search text against the hex address, or the module relative address (ie `game.exe+1a0`).
+fn matches_address_search(
+    address: u64,
+    module: &Option<(String, u64)>,
+    address_search: &str,
+) -> bool {
+    if format!("{:x}", address).contains(address_search) {
+        return true;
+    }
+
+    match module {
+        Some((module_name, module_offset)) => format!("{}+{:x}", module_name.to_ascii_lowercase(), module_offset).contains(address_search),
+        None => false,
+    }
+}
+
+fn sort_queried_scan_results(
+    queried_scan_results: &mut [QueriedScanResult],
+    sort_key: ScanResultsSortKey,
+    sort_descending: bool,
+) {
+    let compare = |left: &QueriedScanResult, right: &QueriedScanResult| match sort_key {
+        ScanResultsSortKey::Index => left.scan_result_index.cmp(&right.scan_result_index),
+        ScanResultsSortKey::Address => left.address.cmp(&right.address),
+        ScanResultsSortKey::Module => match (&left.module, &right.module) {
+            (Some(left_module), Some(right_module)) => left_module.cmp(right_module),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => left.address.cmp(&right.address),
+        },
+        ScanResultsSortKey::CurrentValue => compare_numeric_values(left.current_value, right.current_value),
+        ScanResultsSortKey::Delta => compare_numeric_values(left.delta, right.delta),
+    };
+
+    // Stable sorting keeps ties in snapshot order. Descending order reverses the comparison rather than the results to preserve this.
+    if sort_descending {
+        queried_scan_results.sort_by(|left, right| compare(right, left));
+    } else {
+        queried_scan_results.sort_by(compare);
+    }
+}
+
+/// Compares numeric values, ordering results without a value last.
+fn compare_numeric_values(
+    left: Option<NumericValue>,
+    right: Option<NumericValue>,
+) -> Ordering {
+    match (left, right) {
+        (Some(left), Some(right)) => left.total_cmp(&right),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NumericValue, compare_numeric_values, read_element_numeric_value};
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn read_element_numeric_value_decodes_values_in_their_byte_order() {
+        let region_values = [0x00, 0x01, 0x02, 0x00, 0x00, 0x00];
+
+        assert_eq!(
+            read_element_numeric_value(&region_values, 1, 2, &DataTypeRef::new("u16")),
+            Some(NumericValue::Integer(513))
+        );
+        assert_eq!(
+            read_element_numeric_value(&region_values, 1, 2, &DataTypeRef::new("u16be")),
+            Some(NumericValue::Integer(258))
+        );
+        assert_eq!(read_element_numeric_value(&region_values, 4, 4, &DataTypeRef::new("u32")), None);
+    }
+
+    #[test]
+    fn numeric_values_keep_large_integers_exact() {
+        let region_values = [u64::MAX.to_le_bytes(), (u64::MAX - 1).to_le_bytes()].concat();
+        let largest_value = read_element_numeric_value(&region_values, 0, 8, &DataTypeRef::new("u64"));
+        let second_largest_value = read_element_numeric_value(&region_values, 8, 8, &DataTypeRef::new("u64"));
+
+        assert_eq!(compare_numeric_values(second_largest_value, largest_value), Ordering::Less);
+        assert_eq!(
+            NumericValue::Integer(i64::MIN.into()).checked_sub(NumericValue::Integer(i64::MAX.into())),
+            Some(NumericValue::Integer(i128::from(i64::MIN) - i128::from(i64::MAX)))
+        );
+        assert_eq!(
+            compare_numeric_values(Some(NumericValue::Float(1.5)), Some(NumericValue::Integer(2))),
+            Ordering::Less
+        );
+    }
+}
@@ -6,6 +6,7 @@ use squalr_engine_api::commands::scan_results::set_property::scan_results_set_pr
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::structures::data_types::built_in_types::bool32::data_type_bool32::DataTypeBool32;
 use squalr_engine_api::structures::data_types::data_type::DataType;
+use squalr_engine_api::structures::freezing::freeze_policy::FreezePolicy;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
 use std::sync::Arc;
 
@@ -57,6 +58,7 @@ impl PrivilegedCommandRequestExecutor for ScanResultsSetPropertyRequest {
                     let scan_results_freeze_request = ScanResultsFreezeRequest {
                         scan_result_refs: self.scan_result_refs.clone(),
                         is_frozen,
+                        freeze_policy: FreezePolicy::default(),
                     };
 
                     scan_results_freeze_request.execute(engine_privileged_state);
@@ -0,0 +1 @@
+pub mod value_timeline_export_request_executor;
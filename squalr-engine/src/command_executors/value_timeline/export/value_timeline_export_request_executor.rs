@@ -0,0 +1,79 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::value_timeline::export::value_timeline_export_request::ValueTimelineExportRequest;
+use squalr_engine_api::commands::value_timeline::export::value_timeline_export_response::ValueTimelineExportResponse;
+use squalr_engine_api::structures::value_timelines::value_timeline::ValueTimeline;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ValueTimelineExportRequest {
+    type ResponseType = ValueTimelineExportResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let value_timeline_registry = engine_privileged_state.get_value_timeline_registry();
+        let value_timeline_registry = match value_timeline_registry.read() {
+            Ok(value_timeline_registry) => value_timeline_registry,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on ValueTimelineRegistry: {}", error);
+
+                return ValueTimelineExportResponse::default();
+            }
+        };
+        let value_timelines: Vec<&ValueTimeline> = value_timeline_registry
+            .get_timelines()
+            .values()
+            .filter(|value_timeline| self.timeline_ids.is_empty() || self.timeline_ids.contains(&value_timeline.get_timeline_id()))
+            .collect();
+        let export_result = File::create(&self.file_path).and_then(|file| write_value_timelines_csv(&value_timelines, &mut BufWriter::new(file)));
+
+        match export_result {
+            Ok(exported_sample_count) => {
+                log::info!("Exported {} value timeline samples to '{}'.", exported_sample_count, self.file_path.display());
+
+                ValueTimelineExportResponse {
+                    success: true,
+                    exported_sample_count,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to export value timelines to '{}': {}", self.file_path.display(), error);
+
+                ValueTimelineExportResponse::default()
+            }
+        }
+    }
+}
+
+/// Writes the samples of all timelines as rows sorted by time, such that concurrent timelines can be correlated.
+fn write_value_timelines_csv<W: Write>(
+    value_timelines: &[&ValueTimeline],
+    writer: &mut W,
+) -> io::Result<u64> {
+    let mut rows: Vec<(u64, String)> = value_timelines
+        .iter()
+        .flat_map(|value_timeline| {
+            value_timeline
+                .get_samples()
+                .iter()
+                .map(|sample| sample.get_timestamp_ms())
+                .zip(value_timeline.to_csv_rows())
+        })
+        .collect();
+
+    // Stable sorting keeps samples taken at the same time in timeline order.
+    rows.sort_by_key(|(timestamp_ms, _row)| *timestamp_ms);
+
+    writeln!(writer, "{}", ValueTimeline::CSV_HEADER)?;
+
+    for (_timestamp_ms, row) in &rows {
+        writeln!(writer, "{}", row)?;
+    }
+
+    writer.flush()?;
+
+    Ok(rows.len() as u64)
+}
@@ -0,0 +1 @@
+pub mod value_timeline_list_request_executor;
@@ -0,0 +1,29 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::value_timeline::list::value_timeline_list_request::ValueTimelineListRequest;
+use squalr_engine_api::commands::value_timeline::list::value_timeline_list_response::ValueTimelineListResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ValueTimelineListRequest {
+    type ResponseType = ValueTimelineListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state.get_value_timeline_registry().read() {
+            Ok(value_timeline_registry) => ValueTimelineListResponse {
+                value_timelines: value_timeline_registry
+                    .get_timelines()
+                    .values()
+                    .map(|value_timeline| value_timeline.get_info())
+                    .collect(),
+            },
+            Err(error) => {
+                log::error!("Failed to acquire read lock on ValueTimelineRegistry: {}", error);
+
+                ValueTimelineListResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod export;
+pub mod list;
+pub mod query;
+pub mod record;
+pub mod remove;
+pub mod value_timeline_command_executor;
@@ -0,0 +1 @@
+pub mod value_timeline_query_request_executor;
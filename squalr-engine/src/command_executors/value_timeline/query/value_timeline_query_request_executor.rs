@@ -0,0 +1,36 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::value_timeline::query::value_timeline_query_request::ValueTimelineQueryRequest;
+use squalr_engine_api::commands::value_timeline::query::value_timeline_query_response::ValueTimelineQueryResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ValueTimelineQueryRequest {
+    type ResponseType = ValueTimelineQueryResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let value_timeline_registry = engine_privileged_state.get_value_timeline_registry();
+        let value_timeline_registry = match value_timeline_registry.read() {
+            Ok(value_timeline_registry) => value_timeline_registry,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on ValueTimelineRegistry: {}", error);
+
+                return ValueTimelineQueryResponse::default();
+            }
+        };
+        let Some(value_timeline) = value_timeline_registry.get_timeline(self.timeline_id) else {
+            return ValueTimelineQueryResponse::default();
+        };
+        let samples = match self.since_timestamp_ms {
+            Some(since_timestamp_ms) => value_timeline.get_samples_since(since_timestamp_ms),
+            None => value_timeline.get_samples().iter().cloned().collect(),
+        };
+
+        ValueTimelineQueryResponse {
+            value_timeline_info: Some(value_timeline.get_info()),
+            samples,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::value_timeline::record::value_timeline_record_request::ValueTimelineRecordRequest;
+use squalr_engine_api::commands::value_timeline::record::value_timeline_record_response::ValueTimelineRecordResponse;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::value_timelines::value_timeline_target::ValueTimelineTarget;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ValueTimelineRecordRequest {
+    type ResponseType = ValueTimelineRecordResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        if !SymbolRegistry::get_instance().is_valid(&self.data_type_ref) {
+            log::error!("Cannot record value timeline, unknown data type '{}'.", self.data_type_ref.get_data_type_id());
+
+            return ValueTimelineRecordResponse::default();
+        }
+
+        let target = ValueTimelineTarget::new(self.address, self.module_name.clone(), self.data_type_ref.clone(), self.label.clone());
+        let display_name = target.get_display_name();
+
+        match engine_privileged_state.get_value_timeline_registry().write() {
+            Ok(mut value_timeline_registry) => {
+                let timeline_id = value_timeline_registry.add_timeline(target, self.interval_ms, self.capacity);
+
+                log::info!(
+                    "Recording value timeline {} for {} every {} ms.",
+                    timeline_id,
+                    display_name,
+                    self.interval_ms.max(1)
+                );
+
+                ValueTimelineRecordResponse { success: true, timeline_id }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on ValueTimelineRegistry: {}", error);
+
+                ValueTimelineRecordResponse::default()
+            }
+        }
+    }
+}
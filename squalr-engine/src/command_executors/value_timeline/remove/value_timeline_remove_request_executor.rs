@@ -0,0 +1,27 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::value_timeline::remove::value_timeline_remove_request::ValueTimelineRemoveRequest;
+use squalr_engine_api::commands::value_timeline::remove::value_timeline_remove_response::ValueTimelineRemoveResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ValueTimelineRemoveRequest {
+    type ResponseType = ValueTimelineRemoveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state.get_value_timeline_registry().write() {
+            Ok(mut value_timeline_registry) => ValueTimelineRemoveResponse {
+                success: value_timeline_registry
+                    .remove_timeline(self.timeline_id)
+                    .is_some(),
+            },
+            Err(error) => {
+                log::error!("Failed to acquire write lock on ValueTimelineRegistry: {}", error);
+
+                ValueTimelineRemoveResponse { success: false }
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::command_executors::privileged_command_executor::PrivilegedCommandExecutor;
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse};
+use squalr_engine_api::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for ValueTimelineCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            ValueTimelineCommand::Record { value_timeline_record_request } => value_timeline_record_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ValueTimelineCommand::Remove { value_timeline_remove_request } => value_timeline_remove_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ValueTimelineCommand::List { value_timeline_list_request } => value_timeline_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ValueTimelineCommand::Query { value_timeline_query_request } => value_timeline_query_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ValueTimelineCommand::Export { value_timeline_export_request } => value_timeline_export_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod watchpoint_attach_request_executor;
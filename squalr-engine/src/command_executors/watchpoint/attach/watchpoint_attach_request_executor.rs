@@ -0,0 +1,40 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::watchpoint::attach::watchpoint_attach_request::WatchpointAttachRequest;
+use squalr_engine_api::commands::watchpoint::attach::watchpoint_attach_response::WatchpointAttachResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for WatchpointAttachRequest {
+    type ResponseType = WatchpointAttachResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let opened_process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => opened_process_info,
+            None => {
+                log::error!("Cannot attach debugger, no opened process.");
+
+                return WatchpointAttachResponse { success: false };
+            }
+        };
+        let watchpoints = &engine_privileged_state.get_os_providers().watchpoints;
+
+        if watchpoints.get_attached_process_id() == Some(opened_process_info.get_process_id_raw()) {
+            return WatchpointAttachResponse { success: true };
+        }
+
+        match watchpoints.attach(&opened_process_info) {
+            Ok(()) => WatchpointAttachResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to attach debugger: {}", error);
+
+                WatchpointAttachResponse { success: false }
+            }
+        }
+    }
+}
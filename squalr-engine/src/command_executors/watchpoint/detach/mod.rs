@@ -0,0 +1 @@
+pub mod watchpoint_detach_request_executor;
@@ -0,0 +1,23 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::watchpoint::detach::watchpoint_detach_request::WatchpointDetachRequest;
+use squalr_engine_api::commands::watchpoint::detach::watchpoint_detach_response::WatchpointDetachResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for WatchpointDetachRequest {
+    type ResponseType = WatchpointDetachResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state.get_os_providers().watchpoints.detach() {
+            Ok(()) => WatchpointDetachResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to detach debugger: {}", error);
+
+                WatchpointDetachResponse { success: false }
+            }
+        }
+    }
+}
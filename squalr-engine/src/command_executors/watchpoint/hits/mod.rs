@@ -0,0 +1 @@
+pub mod watchpoint_hits_request_executor;
@@ -0,0 +1,21 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::watchpoint::hits::watchpoint_hits_request::WatchpointHitsRequest;
+use squalr_engine_api::commands::watchpoint::hits::watchpoint_hits_response::WatchpointHitsResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for WatchpointHitsRequest {
+    type ResponseType = WatchpointHitsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let watchpoint_hits = engine_privileged_state
+            .get_os_providers()
+            .watchpoints
+            .get_watchpoint_hits(self.clear);
+
+        WatchpointHitsResponse { watchpoint_hits }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod attach;
+pub mod detach;
+pub mod hits;
+pub mod remove;
+pub mod set;
+pub mod watchpoint_command_executor;
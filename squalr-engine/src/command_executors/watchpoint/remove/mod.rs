@@ -0,0 +1 @@
+pub mod watchpoint_remove_request_executor;
@@ -0,0 +1,27 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::watchpoint::remove::watchpoint_remove_request::WatchpointRemoveRequest;
+use squalr_engine_api::commands::watchpoint::remove::watchpoint_remove_response::WatchpointRemoveResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for WatchpointRemoveRequest {
+    type ResponseType = WatchpointRemoveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state
+            .get_os_providers()
+            .watchpoints
+            .remove_watchpoint(self.watchpoint_id)
+        {
+            Ok(()) => WatchpointRemoveResponse { success: true },
+            Err(error) => {
+                log::error!("Failed to remove watchpoint: {}", error);
+
+                WatchpointRemoveResponse { success: false }
+            }
+        }
+    }
+}
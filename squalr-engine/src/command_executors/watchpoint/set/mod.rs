@@ -0,0 +1 @@
+pub mod watchpoint_set_request_executor;
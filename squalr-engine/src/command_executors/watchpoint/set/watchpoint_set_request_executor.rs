@@ -0,0 +1,39 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::watchpoint::set::watchpoint_set_request::WatchpointSetRequest;
+use squalr_engine_api::commands::watchpoint::set::watchpoint_set_response::WatchpointSetResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for WatchpointSetRequest {
+    type ResponseType = WatchpointSetResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state
+            .get_os_providers()
+            .watchpoints
+            .set_watchpoint(self.address, self.size, self.watchpoint_kind)
+        {
+            Ok(watchpoint_id) => {
+                log::info!(
+                    "Set {} watchpoint {} at 0x{:X} ({} bytes).",
+                    self.watchpoint_kind,
+                    watchpoint_id,
+                    self.address,
+                    self.size
+                );
+
+                WatchpointSetResponse {
+                    watchpoint_id: Some(watchpoint_id),
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to set watchpoint: {}", error);
+
+                WatchpointSetResponse { watchpoint_id: None }
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::command_executors::privileged_command_executor::PrivilegedCommandExecutor;
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse};
+use squalr_engine_api::commands::watchpoint::watchpoint_command::WatchpointCommand;
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for WatchpointCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            WatchpointCommand::Attach { watchpoint_attach_request } => watchpoint_attach_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            WatchpointCommand::Set { watchpoint_set_request } => watchpoint_set_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            WatchpointCommand::Remove { watchpoint_remove_request } => watchpoint_remove_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            WatchpointCommand::Hits { watchpoint_hits_request } => watchpoint_hits_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            WatchpointCommand::Detach { watchpoint_detach_request } => watchpoint_detach_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
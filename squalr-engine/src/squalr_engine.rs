@@ -1,4 +1,6 @@
 use crate::app_provisioner::updater::app_updater::AppUpdater;
+use crate::command_executors::project::open::project_open_request_executor::verify_project_module_builds;
+use crate::command_executors::project_items::activate::project_items_activate_request_executor::reactivate_project_items;
 use crate::engine_bindings::standalone::standalone_engine_api_unprivileged_bindings::StandaloneEngineApiUnprivilegedBindings;
 use crate::engine_mode::EngineMode;
 use crate::engine_privileged_state::{EnginePrivilegedState, create_engine_privileged_state};
@@ -9,7 +11,13 @@ use crate::{
 };
 use squalr_engine_api::dependency_injection::dependency_container::DependencyContainer;
 use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
+use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::events::memory::modules_changed::modules_changed_event::ModulesChangedEvent;
+use squalr_engine_api::events::process::changed::process_changed_event::ProcessChangedEvent;
+use squalr_engine_operating_system::hotkeys::HotkeyListener;
 use squalr_engine_session::engine_unprivileged_state::{EngineUnprivilegedState, EngineUnprivilegedStateOptions};
+use squalr_engine_session::tasks::project_hotkey_task::ProjectHotkeyTask;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 
 /// Orchestrates commands and responses to and from the engine.
@@ -22,17 +30,25 @@ pub struct SqualrEngine {
 
     /// Dependency injection manager.
     dependency_container: DependencyContainer,
+
+    /// Whether global key presses trigger the actions bound to project items.
+    enable_project_hotkeys: bool,
+
+    /// Whether project hotkeys are temporarily ignored, such as while the application's own window has focus.
+    project_hotkeys_suspended: Arc<AtomicBool>,
 }
 
 #[derive(Clone, Copy)]
 pub struct SqualrEngineOptions {
     pub enable_unprivileged_console_logging: bool,
+    pub enable_project_hotkeys: bool,
 }
 
 impl Default for SqualrEngineOptions {
     fn default() -> Self {
         Self {
             enable_unprivileged_console_logging: true,
+            enable_project_hotkeys: false,
         }
     }
 }
@@ -85,6 +101,8 @@ impl SqualrEngine {
             engine_privileged_state,
             engine_unprivileged_state,
             dependency_container: DependencyContainer::new(),
+            enable_project_hotkeys: options.enable_project_hotkeys,
+            project_hotkeys_suspended: Arc::new(AtomicBool::new(false)),
         };
 
         log::info!("Squalr started");
@@ -102,11 +120,84 @@ impl SqualrEngine {
         // Initialize unprivileged engine capabilities if we own them.
         if let Some(engine_unprivileged_state) = &self.engine_unprivileged_state {
             engine_unprivileged_state.initialize();
+            Self::listen_for_process_attach(engine_unprivileged_state);
+            Self::listen_for_module_loads(engine_unprivileged_state);
+
+            if self.enable_project_hotkeys {
+                Self::start_project_hotkeys(engine_unprivileged_state, &self.project_hotkeys_suspended);
+            }
         }
 
         AppUpdater::run_update(ProgressTracker::new());
     }
 
+    /// Re-activates project items whenever a process is attached, such that freezes resolve against the new process modules.
+    /// Module builds recorded on project items are also verified, such that stale addresses are reported.
+    fn listen_for_process_attach(engine_unprivileged_state: &Arc<EngineUnprivilegedState>) {
+        let engine_unprivileged_state_weak = Arc::downgrade(engine_unprivileged_state);
+
+        engine_unprivileged_state.listen_for_engine_event::<ProcessChangedEvent>(move |process_changed_event| {
+            if process_changed_event.process_info.is_none() {
+                return;
+            }
+
+            if let Some(engine_unprivileged_state) = engine_unprivileged_state_weak.upgrade() {
+                let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state;
+
+                reactivate_project_items(&engine_execution_context, |_module_name| true);
+                verify_project_module_builds(&engine_execution_context, |_module_name| true);
+            }
+        });
+    }
+
+    /// Re-activates and verifies project items bound to newly loaded modules, such that items for late-loaded libraries resolve automatically.
+    fn listen_for_module_loads(engine_unprivileged_state: &Arc<EngineUnprivilegedState>) {
+        let engine_unprivileged_state_weak = Arc::downgrade(engine_unprivileged_state);
+
+        engine_unprivileged_state.listen_for_engine_event::<ModulesChangedEvent>(move |modules_changed_event| {
+            if modules_changed_event.loaded_modules.is_empty() {
+                return;
+            }
+
+            if let Some(engine_unprivileged_state) = engine_unprivileged_state_weak.upgrade() {
+                let engine_execution_context: Arc<dyn EngineExecutionContext> = engine_unprivileged_state;
+
+                let is_loaded_module = |module_name: &str| {
+                    modules_changed_event
+                        .loaded_modules
+                        .iter()
+                        .any(|module| module.get_module_name().eq_ignore_ascii_case(module_name))
+                };
+
+                reactivate_project_items(&engine_execution_context, is_loaded_module);
+                verify_project_module_builds(&engine_execution_context, is_loaded_module);
+            }
+        });
+    }
+
+    /// Listens for global key presses, such that hotkeys bound to project items work while another application has focus.
+    fn start_project_hotkeys(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        project_hotkeys_suspended: &Arc<AtomicBool>,
+    ) {
+        match HotkeyListener::create() {
+            Ok(hotkey_listener) => {
+                ProjectHotkeyTask::start_task(Arc::downgrade(engine_unprivileged_state), hotkey_listener, project_hotkeys_suspended.clone());
+            }
+            Err(error) => log::warn!("Project hotkeys are unavailable: {}", error),
+        }
+    }
+
+    /// Suspends or resumes project hotkeys. Frontends that read key presses themselves suspend hotkeys while they have focus, such
+    /// that typing into them does not also trigger project item actions.
+    pub fn set_project_hotkeys_suspended(
+        &self,
+        is_suspended: bool,
+    ) {
+        self.project_hotkeys_suspended
+            .store(is_suspended, Ordering::Release);
+    }
+
     /// Gets the engine execution context to allow for API access to the engine privileged state.
     pub fn get_engine_unprivileged_state(&self) -> &Option<Arc<EngineUnprivilegedState>> {
         &self.engine_unprivileged_state
@@ -122,3 +213,12 @@ impl SqualrEngine {
         &self.dependency_container
     }
 }
+
+impl Drop for SqualrEngine {
+    /// Restores any applied code patches, such that the target process is left unmodified when the engine shuts down.
+    fn drop(&mut self) {
+        if let Some(engine_privileged_state) = &self.engine_privileged_state {
+            engine_privileged_state.restore_code_patches();
+        }
+    }
+}
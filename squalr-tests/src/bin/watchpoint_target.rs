@@ -0,0 +1,23 @@
+//! A small helper process for watchpoint tests. Prints the address of a value, then writes to it until killed.
+
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Duration;
+
+static WATCHED_VALUE: AtomicU64 = AtomicU64::new(0);
+
+fn main() {
+    println!("{:X}", &WATCHED_VALUE as *const AtomicU64 as u64);
+
+    if std::io::stdout().flush().is_err() {
+        return;
+    }
+
+    loop {
+        let value = WATCHED_VALUE.load(Ordering::Relaxed);
+
+        WATCHED_VALUE.store(value.wrapping_add(1), Ordering::Relaxed);
+        thread::sleep(Duration::from_millis(1));
+    }
+}
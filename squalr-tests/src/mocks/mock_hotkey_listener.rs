@@ -0,0 +1,57 @@
+use squalr_engine_operating_system::hotkeys::hotkey_key_event::HotkeyKeyEvent;
+use squalr_engine_operating_system::hotkeys::hotkey_listener_trait::HotkeyListenerTrait;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Hotkey listener stub that reports key events queued by a test instead of reading the keyboard.
+#[derive(Clone, Default)]
+pub struct MockHotkeyListener {
+    queued_key_events: Arc<Mutex<VecDeque<HotkeyKeyEvent>>>,
+}
+
+impl MockHotkeyListener {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a press and release of the given keys, in order, with every key held until the last is pressed.
+    pub fn press_keys(
+        &self,
+        key_names: &[&str],
+    ) {
+        let mut queued_key_events = self
+            .queued_key_events
+            .lock()
+            .expect("hotkey event queue lock should be available");
+
+        queued_key_events.extend(
+            key_names
+                .iter()
+                .map(|key_name| HotkeyKeyEvent::new(key_name, true)),
+        );
+        queued_key_events.extend(
+            key_names
+                .iter()
+                .rev()
+                .map(|key_name| HotkeyKeyEvent::new(key_name, false)),
+        );
+    }
+
+    /// Gets the number of queued key events that have not yet been polled.
+    pub fn get_queued_key_event_count(&self) -> usize {
+        self.queued_key_events
+            .lock()
+            .expect("hotkey event queue lock should be available")
+            .len()
+    }
+}
+
+impl HotkeyListenerTrait for MockHotkeyListener {
+    fn poll_key_events(&mut self) -> Vec<HotkeyKeyEvent> {
+        self.queued_key_events
+            .lock()
+            .expect("hotkey event queue lock should be available")
+            .drain(..)
+            .collect()
+    }
+}
@@ -4,10 +4,18 @@ use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
+use squalr_engine_api::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
 use squalr_engine_operating_system::memory_queryer::page_retrieval_mode::PageRetrievalMode;
 use squalr_engine_operating_system::process_query::process_query_error::ProcessQueryError;
 use squalr_engine_operating_system::process_query::process_query_options::ProcessQueryOptions;
-use squalr_engine_session::os::engine_os_provider::{EngineOsProviders, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, ProcessQueryProvider};
+use squalr_engine_operating_system::watchpoints::watchpoint_error::WatchpointError;
+use squalr_engine_session::module_symbols::module_symbol_cache::ModuleSymbolCache;
+use squalr_engine_session::os::engine_os_provider::{
+    EngineOsProviders, MemoryQueryProvider, MemoryReadProvider, MemoryWriteProvider, ProcessQueryProvider, WatchpointProvider,
+};
+use squalr_engine_session::runtime_classes::runtime_class_cache::RuntimeClassCache;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 #[derive(Clone)]
@@ -28,12 +36,19 @@ pub struct MockOsState {
     pub memory_read_addresses: Vec<u64>,
     pub memory_struct_read_addresses: Vec<u64>,
     pub memory_write_requests: Vec<(u64, Vec<u8>)>,
+    pub module_query_count: u64,
     pub processes: Vec<ProcessInfo>,
     pub opened_process_result: Option<OpenedProcessInfo>,
     pub modules: Vec<NormalizedModule>,
     pub memory_pages: Vec<NormalizedRegion>,
+    pub memory_contents: Vec<(u64, Vec<u8>)>,
+    pub process_modules: HashMap<u32, Vec<NormalizedModule>>,
+    pub process_memory_contents: HashMap<u32, Vec<(u64, Vec<u8>)>>,
     pub write_success: bool,
     pub read_success: bool,
+    pub watchpoint_attached_process_id: Option<u32>,
+    pub watchpoint_set_requests: Vec<(u64, u64, WatchpointKind)>,
+    pub watchpoint_hits: Vec<WatchpointHit>,
 }
 
 #[derive(Clone)]
@@ -92,6 +107,39 @@ impl MockEngineOs {
         }
     }
 
+    pub fn set_memory_contents(
+        &self,
+        memory_contents: Vec<(u64, Vec<u8>)>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.memory_contents = memory_contents;
+        }
+    }
+
+    /// Sets the modules of a specific process, overriding the modules shared by all processes.
+    pub fn set_process_modules(
+        &self,
+        process_id: u32,
+        modules: Vec<NormalizedModule>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.process_modules.insert(process_id, modules);
+        }
+    }
+
+    /// Sets the memory contents of a specific process, overriding the memory contents shared by all processes.
+    pub fn set_process_memory_contents(
+        &self,
+        process_id: u32,
+        memory_contents: Vec<(u64, Vec<u8>)>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard
+                .process_memory_contents
+                .insert(process_id, memory_contents);
+        }
+    }
+
     pub fn set_write_success(
         &self,
         write_success: bool,
@@ -110,13 +158,33 @@ impl MockEngineOs {
         }
     }
 
+    pub fn set_watchpoint_hits(
+        &self,
+        watchpoint_hits: Vec<WatchpointHit>,
+    ) {
+        if let Ok(mut state_guard) = self.state.lock() {
+            state_guard.watchpoint_hits = watchpoint_hits;
+        }
+    }
+
     pub fn create_providers(&self) -> EngineOsProviders {
         let process_provider = Arc::new(MockProcessQueryProvider { state: self.state.clone() });
         let memory_query_provider = Arc::new(MockMemoryQueryProvider { state: self.state.clone() });
         let memory_read_provider = Arc::new(MockMemoryReadProvider { state: self.state.clone() });
         let memory_write_provider = Arc::new(MockMemoryWriteProvider { state: self.state.clone() });
-
-        EngineOsProviders::new(process_provider, memory_query_provider, memory_read_provider, memory_write_provider)
+        let watchpoint_provider = Arc::new(MockWatchpointProvider { state: self.state.clone() });
+        let module_symbol_provider = Arc::new(ModuleSymbolCache::new(memory_read_provider.clone()));
+        let runtime_class_provider = Arc::new(RuntimeClassCache::new(memory_read_provider.clone()));
+
+        EngineOsProviders::new(
+            process_provider,
+            memory_query_provider,
+            memory_read_provider,
+            memory_write_provider,
+            watchpoint_provider,
+            module_symbol_provider,
+            runtime_class_provider,
+        )
     }
 }
 
@@ -200,14 +268,19 @@ struct MockMemoryQueryProvider {
 impl MemoryQueryProvider for MockMemoryQueryProvider {
     fn get_modules(
         &self,
-        _process_info: &OpenedProcessInfo,
+        process_info: &OpenedProcessInfo,
     ) -> Vec<NormalizedModule> {
         match self.state.lock() {
-            Ok(state_guard) => state_guard
-                .modules
-                .iter()
-                .map(|module| NormalizedModule::new(module.get_module_name(), module.get_base_address(), module.get_region_size()))
-                .collect(),
+            Ok(mut state_guard) => {
+                state_guard.module_query_count += 1;
+                state_guard
+                    .process_modules
+                    .get(&process_info.get_process_id_raw())
+                    .unwrap_or(&state_guard.modules)
+                    .iter()
+                    .map(|module| NormalizedModule::new(module.get_module_name(), module.get_base_address(), module.get_region_size()))
+                    .collect()
+            }
             Err(_error) => Vec::new(),
         }
     }
@@ -289,12 +362,35 @@ impl MemoryReadProvider for MockMemoryReadProvider {
 
     fn read_bytes(
         &self,
-        _process_info: &OpenedProcessInfo,
-        _address: u64,
-        _values: &mut [u8],
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &mut [u8],
     ) -> bool {
         match self.state.lock() {
-            Ok(state_guard) => state_guard.read_success,
+            Ok(state_guard) => {
+                let memory_contents = state_guard
+                    .process_memory_contents
+                    .get(&process_info.get_process_id_raw())
+                    .unwrap_or(&state_guard.memory_contents);
+
+                // Copy any overlap between the requested range and the seeded memory contents.
+                for (base_address, bytes) in memory_contents {
+                    let start_address = address.max(*base_address);
+                    let end_address = address
+                        .saturating_add(values.len() as u64)
+                        .min(base_address.saturating_add(bytes.len() as u64));
+
+                    if start_address < end_address {
+                        let destination_offset = (start_address - address) as usize;
+                        let source_offset = (start_address - base_address) as usize;
+                        let length = (end_address - start_address) as usize;
+
+                        values[destination_offset..destination_offset + length].copy_from_slice(&bytes[source_offset..source_offset + length]);
+                    }
+                }
+
+                state_guard.read_success
+            }
             Err(_error) => false,
         }
     }
@@ -322,3 +418,92 @@ impl MemoryWriteProvider for MockMemoryWriteProvider {
         }
     }
 }
+
+struct MockWatchpointProvider {
+    state: Arc<Mutex<MockOsState>>,
+}
+
+impl MockWatchpointProvider {
+    fn lock_state(
+        &self,
+        operation: &'static str,
+    ) -> Result<std::sync::MutexGuard<'_, MockOsState>, WatchpointError> {
+        self.state
+            .lock()
+            .map_err(|error| WatchpointError::internal(operation, format!("Failed to lock mock watchpoint provider: {}", error)))
+    }
+}
+
+impl WatchpointProvider for MockWatchpointProvider {
+    fn attach(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<(), WatchpointError> {
+        let mut state_guard = self.lock_state("attach")?;
+
+        if let Some(process_id) = state_guard.watchpoint_attached_process_id {
+            return Err(WatchpointError::AlreadyAttached { process_id });
+        }
+
+        state_guard.watchpoint_attached_process_id = Some(process_info.get_process_id_raw());
+
+        Ok(())
+    }
+
+    fn detach(&self) -> Result<(), WatchpointError> {
+        let mut state_guard = self.lock_state("detach")?;
+
+        state_guard
+            .watchpoint_attached_process_id
+            .take()
+            .map(|_| ())
+            .ok_or(WatchpointError::NotAttached)
+    }
+
+    fn get_attached_process_id(&self) -> Option<u32> {
+        self.state.lock().ok()?.watchpoint_attached_process_id
+    }
+
+    fn set_watchpoint(
+        &self,
+        address: u64,
+        size: u64,
+        watchpoint_kind: WatchpointKind,
+    ) -> Result<u32, WatchpointError> {
+        let mut state_guard = self.lock_state("set_watchpoint")?;
+
+        if state_guard.watchpoint_attached_process_id.is_none() {
+            return Err(WatchpointError::NotAttached);
+        }
+
+        state_guard
+            .watchpoint_set_requests
+            .push((address, size, watchpoint_kind));
+
+        Ok(state_guard.watchpoint_set_requests.len() as u32 - 1)
+    }
+
+    fn remove_watchpoint(
+        &self,
+        watchpoint_id: u32,
+    ) -> Result<(), WatchpointError> {
+        let state_guard = self.lock_state("remove_watchpoint")?;
+
+        if (watchpoint_id as usize) < state_guard.watchpoint_set_requests.len() {
+            Ok(())
+        } else {
+            Err(WatchpointError::UnknownWatchpoint { watchpoint_id })
+        }
+    }
+
+    fn get_watchpoint_hits(
+        &self,
+        clear: bool,
+    ) -> Vec<WatchpointHit> {
+        match self.state.lock() {
+            Ok(mut state_guard) if clear => std::mem::take(&mut state_guard.watchpoint_hits),
+            Ok(state_guard) => state_guard.watchpoint_hits.clone(),
+            Err(_error) => Vec::new(),
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod mock_engine_bindings;
+pub mod mock_hotkey_listener;
 pub mod mock_os;
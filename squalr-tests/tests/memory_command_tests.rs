@@ -1,6 +1,12 @@
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_request::MemoryDisassembleRequest;
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
 use squalr_engine_api::commands::memory::memory_command::MemoryCommand;
 use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
 use squalr_engine_api::commands::memory::read::memory_read_response::MemoryReadResponse;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_request::MemoryReadRangeRequest;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+use squalr_engine_api::commands::memory::resolve::memory_resolve_request::MemoryResolveRequest;
+use squalr_engine_api::commands::memory::resolve::memory_resolve_response::MemoryResolveResponse;
 use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use squalr_engine_api::commands::memory::write::memory_write_response::MemoryWriteResponse;
 use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
@@ -8,6 +14,8 @@ use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRe
 use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
 use squalr_engine_api::commands::project::list::project_list_response::ProjectListResponse;
 use squalr_engine_api::commands::unprivileged_command_response::TypedUnprivilegedCommandResponse;
+use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
 use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -26,6 +34,7 @@ fn memory_write_request_dispatches_write_command_and_invokes_typed_callback() {
     let memory_write_request = MemoryWriteRequest {
         address: 0x40,
         module_name: String::new(),
+        address_expression: String::new(),
         value: vec![1, 2, 3],
     };
 
@@ -70,6 +79,7 @@ fn memory_write_request_does_not_invoke_callback_when_response_variant_is_wrong(
     let memory_write_request = MemoryWriteRequest {
         address: 0x88,
         module_name: "game.exe".to_string(),
+        address_expression: String::new(),
         value: vec![9, 8, 7, 6],
     };
 
@@ -115,6 +125,7 @@ fn memory_read_request_dispatches_read_command_and_invokes_typed_callback() {
     let memory_read_request = MemoryReadRequest {
         address: 0x1234,
         module_name: "kernel32.dll".to_string(),
+        address_expression: String::new(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
     };
@@ -161,6 +172,7 @@ fn memory_read_request_does_not_invoke_callback_when_response_variant_is_wrong()
     let memory_read_request = MemoryReadRequest {
         address: 0x5678,
         module_name: "game.exe".to_string(),
+        address_expression: String::new(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
     };
@@ -282,3 +294,342 @@ fn privileged_command_parser_rejects_memory_write_when_required_value_is_missing
     assert!(parse_result.is_ok());
     assert!(parse_result.expect("parser should not panic").is_err());
 }
+
+#[test]
+fn memory_read_range_request_dispatches_read_range_command_and_invokes_typed_callback() {
+    let bindings = MockEngineBindings::new(
+        MemoryReadRangeResponse {
+            address: 0x1000,
+            bytes: vec![0xAA, 0x00],
+            readable: vec![true, false],
+            success: true,
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+
+    let memory_read_range_request = MemoryReadRangeRequest {
+        address: 0x1000,
+        module_name: String::new(),
+        address_expression: String::new(),
+        length: 2,
+    };
+
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    memory_read_range_request.send_unprivileged(&bindings, move |memory_read_range_response| {
+        callback_invoked_clone.store(
+            memory_read_range_response.is_readable(0) && !memory_read_range_response.is_readable(1),
+            Ordering::SeqCst,
+        );
+    });
+
+    assert!(callback_invoked.load(Ordering::SeqCst));
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert_eq!(dispatched_commands_guard.len(), 1);
+
+    match &dispatched_commands_guard[0] {
+        PrivilegedCommand::Memory(MemoryCommand::ReadRange {
+            memory_read_range_request: captured_memory_read_range_request,
+        }) => {
+            assert_eq!(captured_memory_read_range_request.address, 0x1000);
+            assert_eq!(captured_memory_read_range_request.length, 2);
+        }
+        dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_memory_read_range_with_short_flags() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "memory",
+            "read-range",
+            "-a",
+            "0x10",
+            "-m",
+            "game.exe",
+            "-l",
+            "0x20",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Memory(MemoryCommand::ReadRange { memory_read_range_request }) => {
+            assert_eq!(memory_read_range_request.address, 0x10);
+            assert_eq!(memory_read_range_request.module_name, "game.exe".to_string());
+            assert_eq!(memory_read_range_request.length, 0x20);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_memory_symbols_with_filter() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "memory",
+            "symbols",
+            "-m",
+            "libc.so.6",
+            "-f",
+            "malloc",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Memory(MemoryCommand::Symbols { memory_symbols_request }) => {
+            assert_eq!(memory_symbols_request.module_name, "libc.so.6".to_string());
+            assert_eq!(memory_symbols_request.filter, Some("malloc".to_string()));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_memory_build_ids_with_modules() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "memory",
+            "build-ids",
+            "-m",
+            "game.exe",
+            "-m",
+            "libc.so.6",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Memory(MemoryCommand::BuildIds { memory_build_ids_request }) => {
+            assert_eq!(memory_build_ids_request.module_names, vec!["game.exe".to_string(), "libc.so.6".to_string()]);
+            assert!(memory_build_ids_request.expected_build_identities.is_empty());
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn memory_resolve_request_dispatches_resolve_command_and_invokes_typed_callback() {
+    let bindings = MockEngineBindings::new(
+        MemoryResolveResponse {
+            address: 0x7204,
+            module_name: "game.exe".to_string(),
+            module_offset: 0x204,
+            symbol_label: String::new(),
+            success: true,
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+
+    let memory_resolve_request = MemoryResolveRequest {
+        address_expression: "[[game.exe+10]+8]+4".to_string(),
+    };
+
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    memory_resolve_request.send_unprivileged(&bindings, move |memory_resolve_response| {
+        callback_invoked_clone.store(
+            memory_resolve_response.success && memory_resolve_response.module_offset == 0x204,
+            Ordering::SeqCst,
+        );
+    });
+
+    assert!(callback_invoked.load(Ordering::SeqCst));
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert_eq!(dispatched_commands_guard.len(), 1);
+
+    match &dispatched_commands_guard[0] {
+        PrivilegedCommand::Memory(MemoryCommand::Resolve {
+            memory_resolve_request: captured_memory_resolve_request,
+        }) => {
+            assert_eq!(captured_memory_resolve_request.address_expression, "[[game.exe+10]+8]+4");
+        }
+        dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_memory_resolve_with_short_flags() {
+    let parse_result = std::panic::catch_unwind(|| PrivilegedCommand::from_iter_safe(["squalr-cli", "memory", "resolve", "-e", "[game.exe+10]+4"]));
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Memory(MemoryCommand::Resolve { memory_resolve_request }) => {
+            assert_eq!(memory_resolve_request.address_expression, "[game.exe+10]+4");
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_memory_read_with_address_expression_and_no_address() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "memory",
+            "read",
+            "--address-expression",
+            "[game.exe+10]+4",
+            "-v",
+            "u32",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Memory(MemoryCommand::Read { memory_read_request }) => {
+            assert_eq!(memory_read_request.address, 0);
+            assert_eq!(memory_read_request.module_name, "");
+            assert_eq!(memory_read_request.address_expression, "[game.exe+10]+4");
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn memory_disassemble_request_dispatches_disassemble_command_and_invokes_typed_callback() {
+    let bindings = MockEngineBindings::new(
+        MemoryDisassembleResponse {
+            address: 0x1000,
+            instruction_set: Some(InstructionSet::X64),
+            instructions: vec![DisassembledInstruction::new(
+                0x1000,
+                vec![0xC3],
+                "ret".to_string(),
+                String::new(),
+                true,
+                None,
+            )],
+            success: true,
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+
+    let memory_disassemble_request = MemoryDisassembleRequest {
+        address: 0x1000,
+        module_name: String::new(),
+        address_expression: String::new(),
+        instruction_count: 1,
+        instructions_before: 0,
+        instruction_set: Some(InstructionSet::X64),
+    };
+
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    memory_disassemble_request.send_unprivileged(&bindings, move |memory_disassemble_response| {
+        callback_invoked_clone.store(
+            memory_disassemble_response.success && memory_disassemble_response.instructions[0].get_text() == "ret",
+            Ordering::SeqCst,
+        );
+    });
+
+    assert!(callback_invoked.load(Ordering::SeqCst));
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert_eq!(dispatched_commands_guard.len(), 1);
+
+    match &dispatched_commands_guard[0] {
+        PrivilegedCommand::Memory(MemoryCommand::Disassemble {
+            memory_disassemble_request: captured_memory_disassemble_request,
+        }) => {
+            assert_eq!(captured_memory_disassemble_request.address, 0x1000);
+            assert_eq!(captured_memory_disassemble_request.instruction_count, 1);
+            assert_eq!(captured_memory_disassemble_request.instruction_set, Some(InstructionSet::X64));
+        }
+        dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_memory_disassemble_with_short_flags() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "memory",
+            "disassemble",
+            "-e",
+            "game.exe+10",
+            "-c",
+            "8",
+            "-b",
+            "2",
+            "-i",
+            "arm64",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Memory(MemoryCommand::Disassemble { memory_disassemble_request }) => {
+            assert_eq!(memory_disassemble_request.address_expression, "game.exe+10".to_string());
+            assert_eq!(memory_disassemble_request.instruction_count, 8);
+            assert_eq!(memory_disassemble_request.instructions_before, 2);
+            assert_eq!(memory_disassemble_request.instruction_set, Some(InstructionSet::AArch64));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_rejects_memory_disassemble_with_unknown_instruction_set() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "memory",
+            "disassemble",
+            "-a",
+            "0x10",
+            "-i",
+            "mips",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+    assert!(parse_result.expect("parser should not panic").is_err());
+}
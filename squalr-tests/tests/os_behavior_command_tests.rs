@@ -1,36 +1,89 @@
 use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
 use squalr_engine::engine_mode::EngineMode;
 use squalr_engine::engine_privileged_state::{EnginePrivilegedState, create_engine_privileged_state_with_os_providers};
+use squalr_engine_api::commands::memory::build_ids::memory_build_ids_request::MemoryBuildIdsRequest;
+use squalr_engine_api::commands::memory::classes::memory_classes_request::MemoryClassesRequest;
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_request::MemoryDisassembleRequest;
+use squalr_engine_api::commands::memory::freeze::memory_freeze_request::MemoryFreezeRequest;
+use squalr_engine_api::commands::memory::freeze::memory_freeze_target::MemoryFreezeTarget;
+use squalr_engine_api::commands::memory::infer_struct::memory_infer_struct_request::MemoryInferStructRequest;
+use squalr_engine_api::commands::memory::patch::memory_patch_request::MemoryPatchRequest;
+use squalr_engine_api::commands::memory::patch::memory_patch_target::MemoryPatchTarget;
 use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_request::MemoryReadRangeRequest;
+use squalr_engine_api::commands::memory::resolve::memory_resolve_request::MemoryResolveRequest;
+use squalr_engine_api::commands::memory::symbols::memory_symbols_request::MemorySymbolsRequest;
 use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use squalr_engine_api::commands::process::auto_attach::process_auto_attach_request::ProcessAutoAttachRequest;
 use squalr_engine_api::commands::process::close::process_close_request::ProcessCloseRequest;
+use squalr_engine_api::commands::process::close_secondary::process_close_secondary_request::ProcessCloseSecondaryRequest;
 use squalr_engine_api::commands::process::list::process_list_request::ProcessListRequest;
 use squalr_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
+use squalr_engine_api::commands::process::open_secondary::process_open_secondary_request::ProcessOpenSecondaryRequest;
+use squalr_engine_api::commands::scan::class_instances::scan_class_instances_request::ScanClassInstancesRequest;
+use squalr_engine_api::commands::scan::correlate_rank::scan_correlate_rank_request::ScanCorrelateRankRequest;
+use squalr_engine_api::commands::scan::correlate_sample::scan_correlate_sample_request::ScanCorrelateSampleRequest;
+use squalr_engine_api::commands::scan::differential::scan_differential_request::ScanDifferentialRequest;
+use squalr_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use squalr_engine_api::commands::scan::load_session::scan_load_session_request::ScanLoadSessionRequest;
 use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
+use squalr_engine_api::commands::scan::refresh_regions::scan_refresh_regions_request::ScanRefreshRegionsRequest;
+use squalr_engine_api::commands::scan::save_session::scan_save_session_request::ScanSaveSessionRequest;
+use squalr_engine_api::commands::scan_results::export::scan_results_export_request::ScanResultsExportRequest;
 use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
+use squalr_engine_api::commands::scan_results::import::scan_results_import_request::ScanResultsImportRequest;
 use squalr_engine_api::commands::scan_results::list::scan_results_list_request::ScanResultsListRequest;
 use squalr_engine_api::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
 use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
 use squalr_engine_api::commands::scan_results::set_property::scan_results_set_property_request::ScanResultsSetPropertyRequest;
+use squalr_engine_api::commands::watchpoint::attach::watchpoint_attach_request::WatchpointAttachRequest;
+use squalr_engine_api::commands::watchpoint::hits::watchpoint_hits_request::WatchpointHitsRequest;
+use squalr_engine_api::commands::watchpoint::set::watchpoint_set_request::WatchpointSetRequest;
+use squalr_engine_api::registries::freeze_list::freeze_list_entry::FreezeListEntry;
+use squalr_engine_api::structures::code_patches::code_patch_replacement::CodePatchReplacement;
 use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+use squalr_engine_api::structures::freezing::freeze_mode::FreezeMode;
+use squalr_engine_api::structures::freezing::freeze_policy::FreezePolicy;
 use squalr_engine_api::structures::memory::bitness::Bitness;
 use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::memory::module_build_identity::ModuleBuildIdentity;
+use squalr_engine_api::structures::memory::module_build_mismatch::ModuleBuildMismatch;
+use squalr_engine_api::structures::memory::module_symbol::ModuleSymbol;
 use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::memory::pointer::Pointer;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
 use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use squalr_engine_api::structures::runtime_classes::runtime_class::RuntimeClass;
+use squalr_engine_api::structures::runtime_classes::runtime_class_abi::RuntimeClassAbi;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
 use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use squalr_engine_api::structures::scan_results::scan_results_export_record::ScanResultsExportRecord;
+use squalr_engine_api::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
+use squalr_engine_api::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::AnonymousScanConstraintExpression;
+use squalr_engine_api::structures::scanning::correlation::scan_correlation_mode::ScanCorrelationMode;
+use squalr_engine_api::structures::scanning::differential::scan_differential_compare_type::ScanDifferentialCompareType;
 use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
 use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
 use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use squalr_engine_api::structures::struct_inference::inferred_field_kind::InferredFieldKind;
 use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use squalr_engine_api::structures::watchpoints::register_snapshot::RegisterSnapshot;
+use squalr_engine_api::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+use squalr_engine_session::os::memory_map_watcher::MemoryMapWatcher;
 use squalr_tests::mocks::mock_os::MockEngineOs;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
 
 fn create_test_state() -> (MockEngineOs, std::sync::Arc<EnginePrivilegedState>) {
     let mock_engine_os = MockEngineOs::new();
@@ -95,6 +148,7 @@ fn memory_write_executor_uses_injected_module_resolution_and_writer() {
     let memory_write_request = MemoryWriteRequest {
         address: 0x20,
         module_name: "game.exe".to_string(),
+        address_expression: String::new(),
         value: vec![1, 2, 3, 4],
     };
 
@@ -122,6 +176,7 @@ fn memory_read_executor_uses_injected_module_resolution_and_reader() {
     let memory_read_request = MemoryReadRequest {
         address: 0x10,
         module_name: "game.exe".to_string(),
+        address_expression: String::new(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
     };
@@ -138,6 +193,1169 @@ fn memory_read_executor_uses_injected_module_resolution_and_reader() {
     assert_eq!(state_guard.memory_struct_read_addresses[0], 0x7010);
 }
 
+#[test]
+fn memory_read_range_executor_resolves_module_and_returns_seeded_bytes() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x7000, 0x1000)]);
+    mock_engine_os.set_memory_contents(vec![(0x7010, vec![0xDE, 0xAD, 0xBE, 0xEF])]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_read_range_request = MemoryReadRangeRequest {
+        address: 0x10,
+        module_name: "game.exe".to_string(),
+        address_expression: String::new(),
+        length: 4,
+    };
+
+    let memory_read_range_response = memory_read_range_request.execute(&engine_privileged_state);
+    assert!(memory_read_range_response.success);
+    assert_eq!(memory_read_range_response.address, 0x7010);
+    assert_eq!(memory_read_range_response.bytes, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+    assert_eq!(memory_read_range_response.readable, vec![true; 4]);
+}
+
+#[test]
+fn memory_read_range_executor_marks_bytes_unreadable_when_reader_fails() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_read_success(false);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_read_range_request = MemoryReadRangeRequest {
+        address: 0x1FF8,
+        module_name: String::new(),
+        address_expression: String::new(),
+        length: 0x10,
+    };
+
+    let memory_read_range_response = memory_read_range_request.execute(&engine_privileged_state);
+    assert!(!memory_read_range_response.success);
+    assert_eq!(memory_read_range_response.bytes, vec![0u8; 0x10]);
+    assert!(!memory_read_range_response.is_readable(0));
+    assert!(!memory_read_range_response.is_readable(0xF));
+}
+
+#[test]
+fn memory_disassemble_executor_decodes_around_address_and_annotates_module_offsets() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x7000, 0x1000)]);
+    mock_engine_os.set_memory_contents(vec![(0x700F, vec![0x90, 0x55, 0x48, 0x89, 0xE5, 0xE8, 0x05, 0x00, 0x00, 0x00])]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_disassemble_request = MemoryDisassembleRequest {
+        address: 0,
+        module_name: String::new(),
+        address_expression: "game.exe+10".to_string(),
+        instruction_count: 3,
+        instructions_before: 1,
+        instruction_set: Some(InstructionSet::X64),
+    };
+
+    let memory_disassemble_response = memory_disassemble_request.execute(&engine_privileged_state);
+    let instructions = &memory_disassemble_response.instructions;
+    assert!(memory_disassemble_response.success);
+    assert_eq!(memory_disassemble_response.address, 0x7010);
+    assert_eq!(memory_disassemble_response.instruction_set, Some(InstructionSet::X64));
+    assert_eq!(
+        instructions
+            .iter()
+            .map(|instruction| instruction.get_text())
+            .collect::<Vec<_>>(),
+        vec!["nop", "push rbp", "mov rbp, rsp", "call 0x701E"]
+    );
+    assert_eq!(instructions[0].get_address(), 0x700F);
+    assert_eq!(instructions[1].get_location(), Some("game.exe+0x10"));
+    assert_eq!(instructions[3].get_target_address(), Some(0x701E));
+    assert_eq!(instructions[3].get_target_location(), Some("game.exe+0x1E"));
+}
+
+#[test]
+fn memory_disassemble_executor_fails_when_code_is_unreadable() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_read_success(false);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_disassemble_request = MemoryDisassembleRequest {
+        address: 0x1000,
+        module_name: String::new(),
+        address_expression: String::new(),
+        instruction_count: 4,
+        instructions_before: 0,
+        instruction_set: None,
+    };
+
+    let memory_disassemble_response = memory_disassemble_request.execute(&engine_privileged_state);
+    assert!(!memory_disassemble_response.success);
+    assert!(memory_disassemble_response.instructions.is_empty());
+}
+
+fn create_patch_request(is_patched: bool) -> MemoryPatchRequest {
+    MemoryPatchRequest {
+        patch_targets: vec![MemoryPatchTarget {
+            address: 0x20,
+            module_name: "game.exe".to_string(),
+            original_bytes: vec![0x0F, 0x84, 0x10, 0x00, 0x00, 0x00],
+            replacement: CodePatchReplacement::Assembly("jmp 0x7036".to_string()),
+        }],
+        is_patched,
+    }
+}
+
+#[test]
+fn memory_patch_executor_verifies_original_bytes_and_restores_them_when_unpatched() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let patch_bytes = vec![0xE9, 0x11, 0x00, 0x00, 0x00, 0x90];
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x7000, 0x1000)]);
+    mock_engine_os.set_memory_contents(vec![(0x7020, vec![0x0F, 0x84, 0x10, 0x00, 0x00, 0x00])]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_patch_response = create_patch_request(true).execute(&engine_privileged_state);
+    assert_eq!(memory_patch_response.failed_patch_target_count, 0);
+
+    // Re-applying an active patch must not write again or overwrite the recorded original bytes.
+    let memory_patch_response = create_patch_request(true).execute(&engine_privileged_state);
+    assert_eq!(memory_patch_response.failed_patch_target_count, 0);
+
+    mock_engine_os.set_memory_contents(vec![(0x7020, patch_bytes.clone())]);
+
+    let memory_patch_response = create_patch_request(false).execute(&engine_privileged_state);
+    assert_eq!(memory_patch_response.failed_patch_target_count, 0);
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(
+        state_guard.memory_write_requests,
+        vec![
+            (0x7020, patch_bytes),
+            (0x7020, vec![0x0F, 0x84, 0x10, 0x00, 0x00, 0x00])
+        ]
+    );
+
+    let code_patch_registry = engine_privileged_state.get_code_patch_registry();
+    let code_patch_registry_guard = match code_patch_registry.read() {
+        Ok(code_patch_registry_guard) => code_patch_registry_guard,
+        Err(error) => panic!("failed to lock code patch registry: {}", error),
+    };
+    assert!(code_patch_registry_guard.get_applied_patches().is_empty());
+}
+
+#[test]
+fn process_close_executor_restores_applied_code_patches() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let patch_bytes = vec![0xE9, 0x11, 0x00, 0x00, 0x00, 0x90];
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x7000, 0x1000)]);
+    mock_engine_os.set_memory_contents(vec![(0x7020, vec![0x0F, 0x84, 0x10, 0x00, 0x00, 0x00])]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_patch_response = create_patch_request(true).execute(&engine_privileged_state);
+    assert_eq!(memory_patch_response.failed_patch_target_count, 0);
+
+    mock_engine_os.set_memory_contents(vec![(0x7020, patch_bytes.clone())]);
+
+    let process_close_response = ProcessCloseRequest {}.execute(&engine_privileged_state);
+    assert!(process_close_response.process_info.is_some());
+
+    match mock_engine_os.get_state().lock() {
+        Ok(state_guard) => assert_eq!(
+            state_guard.memory_write_requests,
+            vec![
+                (0x7020, patch_bytes),
+                (0x7020, vec![0x0F, 0x84, 0x10, 0x00, 0x00, 0x00])
+            ]
+        ),
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    }
+
+    match engine_privileged_state.get_code_patch_registry().read() {
+        Ok(code_patch_registry_guard) => assert!(code_patch_registry_guard.get_applied_patches().is_empty()),
+        Err(error) => panic!("failed to lock code patch registry: {}", error),
+    }
+}
+
+#[test]
+fn memory_patch_executor_does_not_write_when_original_bytes_do_not_match() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x7000, 0x1000)]);
+    mock_engine_os.set_memory_contents(vec![(0x7020, vec![0x0F, 0x85, 0x10, 0x00, 0x00, 0x00])]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_patch_response = create_patch_request(true).execute(&engine_privileged_state);
+    assert_eq!(memory_patch_response.failed_patch_target_count, 1);
+    assert_eq!(memory_patch_response.failed_patch_target_indices, vec![0]);
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert!(state_guard.memory_write_requests.is_empty());
+
+    let code_patch_registry = engine_privileged_state.get_code_patch_registry();
+    let code_patch_registry_guard = match code_patch_registry.read() {
+        Ok(code_patch_registry_guard) => code_patch_registry_guard,
+        Err(error) => panic!("failed to lock code patch registry: {}", error),
+    };
+    assert!(code_patch_registry_guard.get_applied_patches().is_empty());
+}
+
+fn seed_pointer_chain(mock_engine_os: &MockEngineOs) {
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x7000, 0x1000)]);
+    mock_engine_os.set_memory_contents(vec![
+        (0x7010, 0x7100u64.to_le_bytes().to_vec()),
+        (0x7108, 0x7200u64.to_le_bytes().to_vec()),
+    ]);
+}
+
+#[test]
+fn memory_resolve_executor_follows_pointer_chain_and_reports_module_offset() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_pointer_chain(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_resolve_response = MemoryResolveRequest {
+        address_expression: "[[game.exe+10]+8]+4".to_string(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(memory_resolve_response.success);
+    assert_eq!(memory_resolve_response.address, 0x7204);
+    assert_eq!(memory_resolve_response.module_name, "game.exe");
+    assert_eq!(memory_resolve_response.module_offset, 0x204);
+}
+
+#[test]
+fn memory_resolve_executor_fails_for_unknown_module_and_malformed_expression() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_pointer_chain(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    for address_expression in ["missing.dll+10", "[game.exe+10"] {
+        let memory_resolve_response = MemoryResolveRequest {
+            address_expression: address_expression.to_string(),
+        }
+        .execute(&engine_privileged_state);
+
+        assert!(!memory_resolve_response.success);
+    }
+}
+
+/// Seeds a loaded PE image for `game.exe` at 0x10000 that exports `update_player` at offset 0x1500.
+fn seed_pe_module_with_export(mock_engine_os: &MockEngineOs) {
+    let mut image = vec![0u8; 0x1100];
+    let mut write_u32 = |offset: usize, value: u32| image[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+
+    write_u32(0x3C, 0x80);
+    write_u32(0x80, 0x4550);
+    write_u32(0x98, 0x020B);
+    write_u32(0x108, 0x1000);
+    write_u32(0x10C, 0x80);
+    write_u32(0x1014, 1);
+    write_u32(0x1018, 1);
+    write_u32(0x101C, 0x1040);
+    write_u32(0x1020, 0x1044);
+    write_u32(0x1024, 0x1048);
+    write_u32(0x1040, 0x1500);
+    write_u32(0x1044, 0x1050);
+    image[0..2].copy_from_slice(b"MZ");
+    image[0x1050..0x105E].copy_from_slice(b"update_player\0");
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x10000, 0x2000)]);
+    mock_engine_os.set_memory_contents(vec![(0x10000, image)]);
+}
+
+#[test]
+fn memory_resolve_executor_resolves_module_symbols_and_reports_symbol_labels() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_pe_module_with_export(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_resolve_response = MemoryResolveRequest {
+        address_expression: "game.exe!update_player+4".to_string(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(memory_resolve_response.success);
+    assert_eq!(memory_resolve_response.address, 0x11504);
+    assert_eq!(memory_resolve_response.module_offset, 0x1504);
+    assert_eq!(memory_resolve_response.symbol_label, "game.exe!update_player+0x4");
+
+    let missing_symbol_response = MemoryResolveRequest {
+        address_expression: "game.exe!missing".to_string(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(!missing_symbol_response.success);
+}
+
+#[test]
+fn memory_symbols_executor_lists_filtered_module_symbols() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_pe_module_with_export(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let create_request = |filter: &str| MemorySymbolsRequest {
+        module_name: "GAME.EXE".to_string(),
+        filter: Some(filter.to_string()),
+    };
+    let module_symbol_table = create_request("PLAYER")
+        .execute(&engine_privileged_state)
+        .module_symbol_table
+        .expect("module symbols should load from the loaded image");
+
+    assert_eq!(
+        module_symbol_table.get_symbols(),
+        &vec![ModuleSymbol::new("update_player".to_string(), 0x1500, 0)]
+    );
+    assert!(
+        create_request("render")
+            .execute(&engine_privileged_state)
+            .module_symbol_table
+            .is_some_and(|module_symbol_table| module_symbol_table.get_symbols().is_empty())
+    );
+    assert!(
+        MemorySymbolsRequest {
+            module_name: "missing.dll".to_string(),
+            filter: None,
+        }
+        .execute(&engine_privileged_state)
+        .module_symbol_table
+        .is_none()
+    );
+}
+
+#[test]
+fn memory_build_ids_executor_reports_stale_module_builds() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_pe_module_with_export(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_build_ids_response = MemoryBuildIdsRequest {
+        module_names: vec!["game.exe".to_string(), "missing.dll".to_string()],
+        expected_build_identities: vec![],
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(
+        memory_build_ids_response.module_build_identities,
+        vec![ModuleBuildIdentity::new(
+            "game.exe".to_string(),
+            "pe:00000000-00000000".to_string()
+        )]
+    );
+    assert!(memory_build_ids_response.stale_module_builds.is_empty());
+
+    let memory_build_ids_response = MemoryBuildIdsRequest {
+        module_names: vec![],
+        expected_build_identities: vec![
+            ModuleBuildIdentity::new("GAME.EXE".to_string(), "pe:5f3a1b2c-0001a2b3".to_string()),
+            ModuleBuildIdentity::new("game.exe".to_string(), "pe:00000000-00000000".to_string()),
+            ModuleBuildIdentity::new("missing.dll".to_string(), "pe:5f3a1b2c-0001a2b3".to_string()),
+        ],
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(
+        memory_build_ids_response.stale_module_builds,
+        vec![ModuleBuildMismatch::new(
+            "game.exe".to_string(),
+            "pe:5f3a1b2c-0001a2b3".to_string(),
+            "pe:00000000-00000000".to_string()
+        )]
+    );
+}
+
+fn seed_msvc_module_with_class_instance(mock_engine_os: &MockEngineOs) {
+    let mut image = vec![0u8; 0x3000];
+    let mut write_bytes = |offset: usize, bytes: &[u8]| image[offset..offset + bytes.len()].copy_from_slice(bytes);
+
+    // The vftable of game::Player at 0x12010 is preceded by its complete object locator at 0x12100.
+    write_bytes(0x2008, &0x12100u64.to_le_bytes());
+    write_bytes(0x2010, &0x11000u64.to_le_bytes());
+    write_bytes(0x2100, &1u32.to_le_bytes());
+    write_bytes(0x210C, &0x2200u32.to_le_bytes());
+    write_bytes(0x2114, &0x2100u32.to_le_bytes());
+    write_bytes(0x2210, b".?AVPlayer@game@@\0");
+
+    let mut heap_object = vec![0u8; 0x100];
+    heap_object[0x10..0x18].copy_from_slice(&0x12010u64.to_le_bytes());
+    heap_object[0x18..0x1C].copy_from_slice(&1.5f32.to_le_bytes());
+    heap_object[0x1C..0x20].copy_from_slice(&3i32.to_le_bytes());
+    heap_object[0x20..0x28].copy_from_slice(&0x40080u64.to_le_bytes());
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x10000, 0x3000)]);
+    mock_engine_os.set_memory_contents(vec![(0x10000, image), (0x40000, heap_object)]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x40000, 0x100)]);
+}
+
+#[test]
+fn memory_classes_executor_discovers_rtti_classes_and_labels_scan_results() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_msvc_module_with_class_instance(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_classes_response = MemoryClassesRequest {
+        module_name: "GAME.EXE".to_string(),
+        filter: Some("player".to_string()),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(memory_classes_response.success);
+    assert_eq!(
+        memory_classes_response.runtime_classes,
+        vec![RuntimeClass::new(
+            "game::Player".to_string(),
+            "game.exe".to_string(),
+            0x12010,
+            RuntimeClassAbi::Msvc
+        )]
+    );
+
+    seed_snapshot_with_single_scan_result(&engine_privileged_state, 0x40010);
+
+    let scan_results_list_response = ScanResultsListRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(scan_results_list_response.scan_results[0].get_class_name(), "game::Player");
+    assert!(
+        !MemoryClassesRequest {
+            module_name: "missing.dll".to_string(),
+            filter: None,
+        }
+        .execute(&engine_privileged_state)
+        .success
+    );
+}
+
+#[test]
+fn memory_infer_struct_executor_proposes_draft_struct_definition() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_msvc_module_with_class_instance(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    MemoryClassesRequest {
+        module_name: "game.exe".to_string(),
+        filter: None,
+    }
+    .execute(&engine_privileged_state);
+
+    let memory_infer_struct_response = MemoryInferStructRequest {
+        address: 0x40010,
+        size: 0x18,
+        struct_name: "Player".to_string(),
+    }
+    .execute(&engine_privileged_state);
+    let symbolic_struct_definition = memory_infer_struct_response
+        .symbolic_struct_definition
+        .expect("the object should be readable");
+    let inferred_field_kinds = memory_infer_struct_response
+        .inferred_fields
+        .iter()
+        .map(|inferred_field| inferred_field.get_kind())
+        .collect::<Vec<_>>();
+
+    assert_eq!(symbolic_struct_definition.get_symbol_namespace(), "Player");
+    assert_eq!(symbolic_struct_definition.to_string(), "u64*(64);f32;i32;u8*(64)");
+    assert_eq!(
+        inferred_field_kinds,
+        vec![
+            InferredFieldKind::Vtable,
+            InferredFieldKind::Float,
+            InferredFieldKind::SmallInteger,
+            InferredFieldKind::HeapPointer
+        ]
+    );
+    assert!(
+        MemoryInferStructRequest {
+            address: 0x40010,
+            size: 0,
+            struct_name: String::new(),
+        }
+        .execute(&engine_privileged_state)
+        .symbolic_struct_definition
+        .is_none()
+    );
+}
+
+#[test]
+fn scan_class_instances_executor_finds_objects_pointing_at_class_vtable() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_msvc_module_with_class_instance(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let scan_class_instances_response = ScanClassInstancesRequest {
+        class_name: "game::Player".to_string(),
+        module_name: "game.exe".to_string(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(
+        scan_class_instances_response
+            .runtime_class
+            .map(|runtime_class| runtime_class.get_vtable_address()),
+        Some(0x12010)
+    );
+    assert_eq!(scan_class_instances_response.scan_results_metadata.result_count, 1);
+    assert!(
+        ScanClassInstancesRequest {
+            class_name: "game::Enemy".to_string(),
+            module_name: "game.exe".to_string(),
+        }
+        .execute(&engine_privileged_state)
+        .runtime_class
+        .is_none()
+    );
+}
+
+#[test]
+fn element_scan_executor_combines_or_and_not_constraint_expressions() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let mut memory = vec![0xFFu8; 0x18];
+    memory[0x00..0x04].copy_from_slice(&150u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&0u32.to_le_bytes());
+    memory[0x10..0x14].copy_from_slice(&250u32.to_le_bytes());
+    mock_engine_os.set_memory_contents(vec![(0x50000, memory)]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x50000, 0x18)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let execute_element_scan = |scan_constraint_expression: &str| {
+        ScanNewRequest {}.execute(&engine_privileged_state);
+        ElementScanRequest {
+            scan_constraints: vec![AnonymousScanConstraintExpression::from_str(scan_constraint_expression).expect("constraint expression should parse")],
+            data_type_refs: vec![DataTypeRef::new("u32")],
+        }
+        .execute(&engine_privileged_state)
+        .scan_results_metadata
+        .result_count
+    };
+
+    // Matches 150 and 0, but not 250 nor any of the unaligned values spanning the 0xFF filler bytes.
+    assert_eq!(execute_element_scan("(> 100 && < 200) || == 0"), 2);
+
+    // Matches 0, 250, and the unaligned value 0x000000FF, but excludes 150.
+    assert_eq!(execute_element_scan("!(> 100 && < 200) && < 1000"), 3);
+}
+
+#[test]
+fn scan_correlation_ranks_results_by_how_well_they_track_observations() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let set_values = |values: [u32; 4]| {
+        let memory = values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<u8>>();
+
+        mock_engine_os.set_memory_contents(vec![(0x50000, memory)]);
+    };
+    let rank = |correlation_mode: ScanCorrelationMode, minimum_score: f64| {
+        ScanCorrelateRankRequest {
+            correlation_mode,
+            minimum_score,
+            tolerance: 0.0,
+            limit: 10,
+        }
+        .execute(&engine_privileged_state)
+    };
+
+    // Tracks health exactly, tracks health at double scale, moves loosely with health, and never changes.
+    set_values([100, 200, 100, 7]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x50000, 0x10)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    let element_scan_response = ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraintExpression::from_str("< 1000").expect("constraint expression should parse")],
+        data_type_refs: vec![DataTypeRef::new("u32")],
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(element_scan_response.scan_results_metadata.result_count, 4);
+
+    for (observation, values) in [
+        (100.0, [100, 200, 100, 7]),
+        (90.0, [90, 180, 60, 7]),
+        (90.0, [90, 180, 99, 7]),
+        (45.0, [45, 90, 50, 7]),
+    ] {
+        set_values(values);
+
+        let scan_correlate_sample_response = ScanCorrelateSampleRequest { observation, restart: false }.execute(&engine_privileged_state);
+
+        assert!(scan_correlate_sample_response.success);
+        assert_eq!(scan_correlate_sample_response.candidate_count, 4);
+    }
+
+    let monotonic_rank_response = rank(ScanCorrelationMode::Monotonic, 0.0);
+    let monotonic_scores: Vec<(u64, f64)> = monotonic_rank_response
+        .ranked_candidates
+        .iter()
+        .map(|ranked_candidate| (ranked_candidate.address, ranked_candidate.score))
+        .collect();
+
+    assert!(monotonic_rank_response.success);
+    assert_eq!(monotonic_rank_response.sample_count, 4);
+    assert_eq!(monotonic_scores[0..2], [(0x50000, 1.0), (0x50004, 1.0)]);
+    assert_eq!(monotonic_scores[2].0, 0x50008);
+    assert!((monotonic_scores[2].1 - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(monotonic_rank_response.scan_results_metadata.result_count, 4);
+
+    // Proportional correlation keeps the scaled value, and narrows away the others.
+    let proportional_rank_response = rank(ScanCorrelationMode::Proportional, 0.99);
+
+    assert_eq!(proportional_rank_response.scan_results_metadata.result_count, 2);
+    assert_eq!(
+        proportional_rank_response
+            .ranked_candidates
+            .iter()
+            .map(|ranked_candidate| ranked_candidate.address)
+            .collect::<Vec<_>>(),
+        vec![0x50000, 0x50004]
+    );
+
+    // Exact correlation narrows to the value that was observed directly.
+    let exact_rank_response = rank(ScanCorrelationMode::Exact, 1.0);
+
+    assert_eq!(exact_rank_response.scan_results_metadata.result_count, 1);
+    assert_eq!(exact_rank_response.ranked_candidates[0].address, 0x50000);
+    assert_eq!(exact_rank_response.ranked_candidates[0].latest_value, Some(45.0));
+
+    // Starting a new scan discards the correlation.
+    ScanNewRequest {}.execute(&engine_privileged_state);
+
+    assert!(!rank(ScanCorrelationMode::Exact, 0.0).success);
+}
+
+#[test]
+fn differential_scan_keeps_module_relative_results_that_differ_or_match_between_processes() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let secondary_process_id = 4242;
+    let to_memory = |values: [u32; 4]| {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<u8>>()
+    };
+    let get_result_addresses = || {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let snapshot = snapshot.read().expect("snapshot should be readable");
+
+        snapshot
+            .get_snapshot_regions()
+            .iter()
+            .flat_map(|snapshot_region| {
+                snapshot_region
+                    .get_scan_results()
+                    .get_filter_collections()
+                    .iter()
+            })
+            .flat_map(|snapshot_region_filter_collection| snapshot_region_filter_collection.iter())
+            .map(|snapshot_region_filter| snapshot_region_filter.get_base_address())
+            .collect::<Vec<u64>>()
+    };
+
+    // The same module is loaded at a different base address in each process.
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x50000, 0x10)]);
+    mock_engine_os.set_memory_contents(vec![(0x50000, to_memory([150, 500, 250, 75]))]);
+    mock_engine_os.set_process_modules(secondary_process_id, vec![NormalizedModule::new("game.exe", 0x80000, 0x10)]);
+    mock_engine_os.set_process_memory_contents(secondary_process_id, vec![(0x80000, to_memory([150, 999, 250, 1]))]);
+    mock_engine_os.set_processes(vec![ProcessInfo::new(
+        secondary_process_id,
+        "game.exe".to_string(),
+        true,
+        None,
+    )]);
+    mock_engine_os.set_opened_process_result(Some(OpenedProcessInfo::new(
+        secondary_process_id,
+        "game.exe".to_string(),
+        0xBEEF,
+        Bitness::Bit64,
+        None,
+    )));
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    assert!(
+        !ScanDifferentialRequest {
+            compare_type: ScanDifferentialCompareType::Different,
+        }
+        .execute(&engine_privileged_state)
+        .success
+    );
+
+    let secondary_process_info = ProcessOpenSecondaryRequest {
+        process_id: Some(secondary_process_id),
+        search_name: None,
+        match_case: false,
+    }
+    .execute(&engine_privileged_state)
+    .opened_process_info
+    .expect("secondary process should open");
+
+    let execute_differential_scan = |compare_type: ScanDifferentialCompareType| {
+        mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x50000, 0x10)]);
+        ScanNewRequest {}.execute(&engine_privileged_state);
+        ElementScanRequest {
+            scan_constraints: vec![AnonymousScanConstraintExpression::from_str("< 1000").expect("constraint expression should parse")],
+            data_type_refs: vec![DataTypeRef::new("u32")],
+        }
+        .execute(&engine_privileged_state);
+
+        // The mock shares page bounds between processes, so expose the pages of the secondary module for the comparison.
+        mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x80000, 0x10)]);
+
+        ScanDifferentialRequest { compare_type }.execute(&engine_privileged_state)
+    };
+
+    let scan_differential_response = execute_differential_scan(ScanDifferentialCompareType::Different);
+
+    assert!(scan_differential_response.success);
+    assert_eq!(scan_differential_response.scan_results_metadata.result_count, 2);
+    assert_eq!(
+        scan_differential_response
+            .secondary_scan_results_metadata
+            .result_count,
+        2
+    );
+    assert_eq!(scan_differential_response.unmatched_result_count, 0);
+    assert_eq!(get_result_addresses(), vec![0x50004, 0x5000C]);
+    assert_eq!(
+        engine_privileged_state
+            .get_process_snapshot(&secondary_process_info)
+            .read()
+            .expect("secondary snapshot should be readable")
+            .get_number_of_results(),
+        2
+    );
+
+    let scan_differential_response = execute_differential_scan(ScanDifferentialCompareType::Equal);
+
+    assert_eq!(scan_differential_response.scan_results_metadata.result_count, 2);
+    assert_eq!(get_result_addresses(), vec![0x50000, 0x50008]);
+
+    // Comparing reads the opened process separately, leaving the values of the scan results as they were when last scanned.
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x50000, 0x10)]);
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraintExpression::from_str("< 1000").expect("constraint expression should parse")],
+        data_type_refs: vec![DataTypeRef::new("u32")],
+    }
+    .execute(&engine_privileged_state);
+    mock_engine_os.set_memory_contents(vec![(0x50000, to_memory([150, 501, 250, 75]))]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x80000, 0x10)]);
+
+    let scan_differential_response = ScanDifferentialRequest {
+        compare_type: ScanDifferentialCompareType::Different,
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(scan_differential_response.scan_results_metadata.result_count, 2);
+
+    {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let snapshot = snapshot.read().expect("snapshot should be readable");
+        let snapshot_region = &snapshot.get_snapshot_regions()[0];
+        let value_offset = (0x50004 - snapshot_region.get_base_address()) as usize;
+
+        assert_eq!(snapshot_region.get_current_values()[value_offset..value_offset + 4], 500u32.to_le_bytes());
+    }
+
+    let process_close_secondary_response = ProcessCloseSecondaryRequest {}.execute(&engine_privileged_state);
+
+    assert_eq!(
+        process_close_secondary_response
+            .process_info
+            .map(|process_info| process_info.get_process_id_raw()),
+        Some(secondary_process_id)
+    );
+    assert!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_secondary_process()
+            .is_none()
+    );
+    assert!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .is_some()
+    );
+
+    // A secondary process that exits is detached, discarding its snapshot.
+    let secondary_process_info = ProcessOpenSecondaryRequest {
+        process_id: Some(secondary_process_id),
+        search_name: None,
+        match_case: false,
+    }
+    .execute(&engine_privileged_state)
+    .opened_process_info
+    .expect("secondary process should reopen");
+
+    execute_differential_scan(ScanDifferentialCompareType::Different);
+    mock_engine_os.set_processes(vec![]);
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    while engine_privileged_state
+        .get_process_manager()
+        .get_secondary_process()
+        .is_some()
+        && Instant::now() < deadline
+    {
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_secondary_process()
+            .is_none()
+    );
+    assert_eq!(
+        engine_privileged_state
+            .get_process_snapshot(&secondary_process_info)
+            .read()
+            .expect("secondary snapshot should be readable")
+            .get_number_of_results(),
+        0
+    );
+}
+
+#[test]
+fn scan_results_query_executor_sorts_and_filters_results_in_engine() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let mut memory = vec![0u8; 0x10];
+    memory[0x00..0x04].copy_from_slice(&150u32.to_le_bytes());
+    memory[0x04..0x08].copy_from_slice(&500u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&250u32.to_le_bytes());
+    memory[0x0C..0x10].copy_from_slice(&75u32.to_le_bytes());
+    mock_engine_os.set_memory_contents(vec![(0x50000, memory)]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x50000, 0x10)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    let element_scan_response = ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraintExpression::from_str("in [75, 500]").expect("constraint expression should parse")],
+        data_type_refs: vec![DataTypeRef::new("u32")],
+    }
+    .execute(&engine_privileged_state);
+    assert_eq!(element_scan_response.scan_results_metadata.result_count, 4);
+
+    let query_scan_results = |query_options: ScanResultsQueryOptions| {
+        let scan_results_query_response = ScanResultsQueryRequest { page_index: 0, query_options }.execute(&engine_privileged_state);
+        let addresses: Vec<u64> = scan_results_query_response
+            .scan_results
+            .iter()
+            .map(|scan_result| scan_result.get_address())
+            .collect();
+
+        (scan_results_query_response.result_count, addresses)
+    };
+
+    assert_eq!(
+        query_scan_results(ScanResultsQueryOptions {
+            sort_key: ScanResultsSortKey::CurrentValue,
+            sort_descending: true,
+            ..ScanResultsQueryOptions::default()
+        }),
+        (4, vec![0x50004, 0x50008, 0x50000, 0x5000C])
+    );
+    assert_eq!(
+        query_scan_results(ScanResultsQueryOptions {
+            sort_key: ScanResultsSortKey::CurrentValue,
+            value_filter: Some(AnonymousScanConstraintExpression::from_str("> 100 && != 500").expect("value filter should parse")),
+            ..ScanResultsQueryOptions::default()
+        }),
+        (2, vec![0x50000, 0x50008])
+    );
+    assert_eq!(
+        query_scan_results(ScanResultsQueryOptions {
+            region_start_address: Some(0x50008),
+            ..ScanResultsQueryOptions::default()
+        }),
+        (2, vec![0x50008, 0x5000C])
+    );
+    assert_eq!(
+        query_scan_results(ScanResultsQueryOptions {
+            address_search: Some("0x5000c".to_string()),
+            ..ScanResultsQueryOptions::default()
+        }),
+        (1, vec![0x5000C])
+    );
+    assert_eq!(
+        query_scan_results(ScanResultsQueryOptions {
+            module_filter: Some("game.exe".to_string()),
+            ..ScanResultsQueryOptions::default()
+        }),
+        (0, vec![])
+    );
+
+    // Collecting new values must invalidate the cached order of a repeated query.
+    let mut memory = vec![0u8; 0x10];
+    memory[0x00..0x04].copy_from_slice(&900u32.to_le_bytes());
+    memory[0x04..0x08].copy_from_slice(&500u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&250u32.to_le_bytes());
+    memory[0x0C..0x10].copy_from_slice(&75u32.to_le_bytes());
+    mock_engine_os.set_memory_contents(vec![(0x50000, memory)]);
+    ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraintExpression::from_str("in [75, 900]").expect("constraint expression should parse")],
+        data_type_refs: vec![DataTypeRef::new("u32")],
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(
+        query_scan_results(ScanResultsQueryOptions {
+            sort_key: ScanResultsSortKey::CurrentValue,
+            sort_descending: true,
+            ..ScanResultsQueryOptions::default()
+        }),
+        (4, vec![0x50000, 0x50004, 0x50008, 0x5000C])
+    );
+}
+
+#[test]
+fn scan_results_export_and_import_restore_results_relative_to_relocated_modules() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let mut memory = vec![0u8; 0x10];
+    memory[0x00..0x04].copy_from_slice(&150u32.to_le_bytes());
+    memory[0x04..0x08].copy_from_slice(&500u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&250u32.to_le_bytes());
+    memory[0x0C..0x10].copy_from_slice(&75u32.to_le_bytes());
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x50000, 0x10)]);
+    mock_engine_os.set_memory_contents(vec![(0x50000, memory.clone())]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x50000, 0x10)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraintExpression::from_str("in [75, 500]").expect("constraint expression should parse")],
+        data_type_refs: vec![DataTypeRef::new("u32")],
+    }
+    .execute(&engine_privileged_state);
+
+    let export_directory = std::env::temp_dir().join(format!("squalr_scan_results_export_{}", std::process::id()));
+    std::fs::create_dir_all(&export_directory).expect("export directory should be created");
+    let csv_file_path = export_directory.join("results.csv");
+    let json_file_path = export_directory.join("results.json");
+
+    for file_path in [&csv_file_path, &json_file_path] {
+        let scan_results_export_response = ScanResultsExportRequest {
+            file_path: file_path.clone(),
+            format: None,
+        }
+        .execute(&engine_privileged_state);
+
+        assert!(scan_results_export_response.success);
+        assert_eq!(scan_results_export_response.exported_result_count, 4);
+    }
+
+    let csv_contents = std::fs::read_to_string(&csv_file_path).expect("exported csv should be readable");
+    assert!(csv_contents.starts_with(ScanResultsExportRecord::CSV_HEADER));
+    assert!(csv_contents.contains("0x50004,game.exe,0x4,u32,500,"));
+
+    // Simulate restarting the process, such that the module is loaded at a different base address.
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x60000, 0x10)]);
+    mock_engine_os.set_memory_contents(vec![(0x60000, memory)]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x60000, 0x10)]);
+
+    for file_path in [&csv_file_path, &json_file_path] {
+        ScanNewRequest {}.execute(&engine_privileged_state);
+
+        let scan_results_import_response = ScanResultsImportRequest {
+            file_path: file_path.clone(),
+            format: None,
+        }
+        .execute(&engine_privileged_state);
+
+        assert!(scan_results_import_response.success);
+        assert_eq!(scan_results_import_response.imported_result_count, 4);
+        assert_eq!(scan_results_import_response.unresolved_result_count, 0);
+
+        let scan_results_query_response = ScanResultsQueryRequest {
+            page_index: 0,
+            query_options: ScanResultsQueryOptions {
+                sort_key: ScanResultsSortKey::CurrentValue,
+                ..ScanResultsQueryOptions::default()
+            },
+        }
+        .execute(&engine_privileged_state);
+        let addresses: Vec<u64> = scan_results_query_response
+            .scan_results
+            .iter()
+            .map(|scan_result| scan_result.get_address())
+            .collect();
+
+        assert_eq!(scan_results_query_response.result_count, 4);
+        assert_eq!(addresses, vec![0x6000C, 0x60000, 0x60008, 0x60004]);
+    }
+
+    // Duplicates are imported once, and results in modules that are not loaded are not placed at their stale absolute address.
+    std::fs::write(
+        &csv_file_path,
+        format!(
+            "{}\n0x50004,game.exe,0x4,u32,,\n0x50004,game.exe,0x4,u32,,\n0x60008,missing.dll,0x8,u32,,\n",
+            ScanResultsExportRecord::CSV_HEADER
+        ),
+    )
+    .expect("csv should be written");
+    ScanNewRequest {}.execute(&engine_privileged_state);
+
+    let scan_results_import_response = ScanResultsImportRequest {
+        file_path: csv_file_path.clone(),
+        format: None,
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(scan_results_import_response.success);
+    assert_eq!(scan_results_import_response.imported_result_count, 1);
+    assert_eq!(scan_results_import_response.unresolved_result_count, 2);
+
+    let _ = std::fs::remove_dir_all(&export_directory);
+}
+
+#[test]
+fn scan_session_save_and_load_restores_results_and_values_for_continued_narrowing() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let mut memory = vec![0u8; 0x10];
+    memory[0x00..0x04].copy_from_slice(&150u32.to_le_bytes());
+    memory[0x04..0x08].copy_from_slice(&500u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&250u32.to_le_bytes());
+    memory[0x0C..0x10].copy_from_slice(&75u32.to_le_bytes());
+    mock_engine_os.set_memory_contents(vec![(0x50000, memory.clone())]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x50000, 0x10)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraintExpression::from_str("in {75, 500}").expect("constraint expression should parse")],
+        data_type_refs: vec![DataTypeRef::new("u32")],
+    }
+    .execute(&engine_privileged_state);
+
+    let session_file_path = std::env::temp_dir().join(format!("squalr_scan_session_{}.session", std::process::id()));
+    let scan_save_session_response = ScanSaveSessionRequest {
+        file_path: session_file_path.clone(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(scan_save_session_response.success);
+
+    // Discard the scan, then change one of the results such that continued narrowing can be observed.
+    ScanNewRequest {}.execute(&engine_privileged_state);
+    memory[0x04..0x08].copy_from_slice(&501u32.to_le_bytes());
+    mock_engine_os.set_memory_contents(vec![(0x50000, memory)]);
+
+    let scan_load_session_response = ScanLoadSessionRequest {
+        file_path: session_file_path.clone(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(scan_load_session_response.success);
+    assert_eq!(scan_load_session_response.scan_results_metadata.result_count, 2);
+
+    let scan_results_query_response = ScanResultsQueryRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
+    let addresses: Vec<u64> = scan_results_query_response
+        .scan_results
+        .iter()
+        .map(|scan_result| scan_result.get_address())
+        .collect();
+
+    assert_eq!(addresses, vec![0x50004, 0x5000C]);
+
+    ElementScanRequest {
+        scan_constraints: vec![AnonymousScanConstraintExpression::from_str("!=").expect("constraint expression should parse")],
+        data_type_refs: vec![DataTypeRef::new("u32")],
+    }
+    .execute(&engine_privileged_state);
+
+    let scan_results_query_response = ScanResultsQueryRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(scan_results_query_response.result_count, 1);
+    assert_eq!(scan_results_query_response.scan_results[0].get_address(), 0x50004);
+
+    let _ = std::fs::remove_file(&session_file_path);
+}
+
+#[test]
+fn memory_read_executor_prefers_address_expression_over_address_and_module() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_pointer_chain(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let memory_read_request = MemoryReadRequest {
+        address: 0x44,
+        module_name: "game.exe".to_string(),
+        address_expression: "[game.exe+10]+8".to_string(),
+        symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
+        suppress_logging: false,
+    };
+
+    let memory_read_response = memory_read_request.execute(&engine_privileged_state);
+    assert!(memory_read_response.success);
+    assert_eq!(memory_read_response.address, 0x7108);
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(state_guard.memory_struct_read_addresses, vec![0x7108]);
+}
+
 #[test]
 fn memory_read_executor_returns_failure_without_mutating_write_state_when_reader_fails() {
     let (mock_engine_os, engine_privileged_state) = create_test_state();
@@ -150,6 +1368,7 @@ fn memory_read_executor_returns_failure_without_mutating_write_state_when_reader
     let memory_read_request = MemoryReadRequest {
         address: 0x44,
         module_name: "game.exe".to_string(),
+        address_expression: String::new(),
         symbolic_struct_definition: SymbolicStructDefinition::new(String::new(), vec![]),
         suppress_logging: false,
     };
@@ -193,70 +1412,484 @@ fn process_executors_use_injected_process_provider() {
     assert_eq!(process_list_response.processes.len(), 1);
     assert_eq!(process_list_response.processes[0].get_name(), "calc.exe");
 
-    let process_open_request = ProcessOpenRequest {
-        process_id: Some(process_identifier),
-        search_name: Some("calc".to_string()),
-        match_case: true,
-    };
-    let process_open_response = process_open_request.execute(&engine_privileged_state);
-    assert!(process_open_response.opened_process_info.is_some());
+    let process_open_request = ProcessOpenRequest {
+        process_id: Some(process_identifier),
+        search_name: Some("calc".to_string()),
+        match_case: true,
+    };
+    let process_open_response = process_open_request.execute(&engine_privileged_state);
+    assert!(process_open_response.opened_process_info.is_some());
+
+    let process_close_response = ProcessCloseRequest {}.execute(&engine_privileged_state);
+    assert!(process_close_response.process_info.is_some());
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(state_guard.process_query_requests.len(), 2);
+    assert_eq!(state_guard.process_query_requests[0].search_name, Some("calc".to_string()));
+    assert!(state_guard.process_query_requests[0].require_windowed);
+    assert!(state_guard.process_query_requests[0].match_case);
+    assert_eq!(state_guard.process_query_requests[0].limit, Some(5));
+    assert!(!state_guard.process_query_requests[0].fetch_icons);
+    assert_eq!(state_guard.process_query_requests[1].required_process_id, Some(process_identifier));
+    assert_eq!(state_guard.open_process_requests, vec![process_identifier]);
+    assert_eq!(state_guard.close_process_handles, vec![0xBEEF]);
+}
+
+#[test]
+fn process_open_executor_returns_none_when_process_handle_resolution_fails() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let process_identifier = std::process::id();
+    let process_info = ProcessInfo::new(process_identifier, "calc.exe".to_string(), true, None);
+
+    mock_engine_os.set_processes(vec![process_info]);
+    mock_engine_os.set_opened_process_result(None);
+
+    let process_open_request = ProcessOpenRequest {
+        process_id: Some(process_identifier),
+        search_name: Some("calc".to_string()),
+        match_case: true,
+    };
+    let process_open_response = process_open_request.execute(&engine_privileged_state);
+    assert!(process_open_response.opened_process_info.is_none());
+    assert!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .is_none()
+    );
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(state_guard.open_process_requests, vec![process_identifier]);
+}
+
+#[test]
+fn process_auto_attach_executor_attaches_and_reattaches_after_process_restart() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let process_identifier = std::process::id();
+
+    mock_engine_os.set_processes(vec![
+        ProcessInfo::new(process_identifier, "game-launcher.exe".to_string(), true, None),
+        ProcessInfo::new(process_identifier, "Game.exe".to_string(), true, None),
+    ]);
+    mock_engine_os.set_opened_process_result(Some(OpenedProcessInfo::new(
+        process_identifier,
+        "Game.exe".to_string(),
+        0xBEEF,
+        Bitness::Bit64,
+        None,
+    )));
+
+    let process_auto_attach_request = ProcessAutoAttachRequest {
+        process_name: Some("game".to_string()),
+        match_case: false,
+    };
+    let process_auto_attach_response = process_auto_attach_request.execute(&engine_privileged_state);
+
+    assert!(process_auto_attach_response.is_enabled);
+    assert_eq!(
+        process_auto_attach_response
+            .opened_process_info
+            .map(|opened_process_info| opened_process_info.get_name().to_string()),
+        Some("Game.exe".to_string())
+    );
+
+    // Simulate the target dying, after which the next poll should re-attach to the restarted process.
+    engine_privileged_state
+        .get_process_manager()
+        .clear_opened_process();
+    engine_privileged_state.poll_auto_attach();
+
+    assert!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .is_some()
+    );
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(state_guard.open_process_requests, vec![process_identifier, process_identifier]);
+    assert_eq!(state_guard.process_query_requests[0].search_name, Some("game".to_string()));
+}
+
+#[test]
+fn process_auto_attach_executor_does_not_retry_process_that_failed_to_open() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let process_identifier = std::process::id();
+
+    mock_engine_os.set_processes(vec![ProcessInfo::new(
+        process_identifier,
+        "game.exe".to_string(),
+        true,
+        None,
+    )]);
+    mock_engine_os.set_opened_process_result(None);
+
+    let process_auto_attach_request = ProcessAutoAttachRequest {
+        process_name: Some("game.exe".to_string()),
+        match_case: true,
+    };
+    let process_auto_attach_response = process_auto_attach_request.execute(&engine_privileged_state);
+
+    assert!(process_auto_attach_response.is_enabled);
+    assert!(process_auto_attach_response.opened_process_info.is_none());
+    assert!(engine_privileged_state.poll_auto_attach().is_none());
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = match mock_os_state.lock() {
+        Ok(state_guard) => state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    assert_eq!(state_guard.open_process_requests, vec![process_identifier]);
+}
+
+#[test]
+fn process_close_executor_disables_auto_attach() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    let process_identifier = std::process::id();
+
+    mock_engine_os.set_processes(vec![ProcessInfo::new(
+        process_identifier,
+        "game.exe".to_string(),
+        true,
+        None,
+    )]);
+    mock_engine_os.set_opened_process_result(Some(OpenedProcessInfo::new(
+        process_identifier,
+        "game.exe".to_string(),
+        0xBEEF,
+        Bitness::Bit64,
+        None,
+    )));
+
+    let process_auto_attach_request = ProcessAutoAttachRequest {
+        process_name: Some("game.exe".to_string()),
+        match_case: false,
+    };
+    assert!(
+        process_auto_attach_request
+            .execute(&engine_privileged_state)
+            .opened_process_info
+            .is_some()
+    );
+
+    ProcessCloseRequest {}.execute(&engine_privileged_state);
+
+    assert!(
+        engine_privileged_state
+            .get_process_auto_attacher()
+            .get_target()
+            .is_none()
+    );
+    assert!(engine_privileged_state.poll_auto_attach().is_none());
+    assert!(
+        engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .is_none()
+    );
+}
+
+#[test]
+fn watchpoint_executors_use_injected_watchpoint_provider_for_opened_process() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    assert!(
+        !WatchpointAttachRequest {}
+            .execute(&engine_privileged_state)
+            .success
+    );
+
+    let opened_process_info = create_opened_process_info();
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(opened_process_info.clone());
+
+    assert!(
+        WatchpointAttachRequest {}
+            .execute(&engine_privileged_state)
+            .success
+    );
+    assert!(
+        WatchpointAttachRequest {}
+            .execute(&engine_privileged_state)
+            .success
+    );
+
+    let watchpoint_set_response = WatchpointSetRequest {
+        address: 0x4000,
+        size: 2,
+        watchpoint_kind: WatchpointKind::ReadWrite,
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(watchpoint_set_response.watchpoint_id, Some(0));
+
+    mock_engine_os.set_watchpoint_hits(vec![WatchpointHit::new(0, 77, 0x401000, RegisterSnapshot::default())]);
+
+    assert_eq!(
+        WatchpointHitsRequest { clear: true }
+            .execute(&engine_privileged_state)
+            .watchpoint_hits
+            .len(),
+        1
+    );
+    assert!(
+        WatchpointHitsRequest { clear: false }
+            .execute(&engine_privileged_state)
+            .watchpoint_hits
+            .is_empty()
+    );
+
+    let _process_close_response = ProcessCloseRequest {}.execute(&engine_privileged_state);
+
+    let state = mock_engine_os.get_state();
+    let state_guard = state.lock().expect("mock state lock should be available");
+
+    assert_eq!(state_guard.watchpoint_set_requests, vec![(0x4000, 2, WatchpointKind::ReadWrite)]);
+    assert_eq!(state_guard.watchpoint_attached_process_id, None);
+}
+
+#[test]
+fn memory_map_watcher_reports_module_and_region_changes_after_baseline() {
+    let mock_engine_os = MockEngineOs::new();
+    let os_providers = mock_engine_os.create_providers();
+    let opened_process_info = create_opened_process_info();
+    let mut memory_map_watcher = MemoryMapWatcher::new();
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x400000, 0x1000)]);
+    mock_engine_os.set_memory_pages(vec![
+        NormalizedRegion::new(0x10000, 0x1000),
+        NormalizedRegion::new(0x20000, 0x1000),
+    ]);
+
+    let (modules_changed_event, regions_changed_event) = memory_map_watcher.poll(Some(&opened_process_info), &os_providers);
+    assert!(modules_changed_event.is_none());
+    assert!(regions_changed_event.is_none());
+
+    mock_engine_os.set_modules(vec![
+        NormalizedModule::new("game.exe", 0x400000, 0x1000),
+        NormalizedModule::new("dlc.dll", 0x7FF00000, 0x2000),
+    ]);
+    mock_engine_os.set_memory_pages(vec![
+        NormalizedRegion::new(0x10000, 0x1000),
+        NormalizedRegion::new(0x30000, 0x2000),
+    ]);
+
+    let (modules_changed_event, regions_changed_event) = memory_map_watcher.poll(Some(&opened_process_info), &os_providers);
+    let modules_changed_event = modules_changed_event.expect("Expected a module load to be reported.");
+    let regions_changed_event = regions_changed_event.expect("Expected region changes to be reported.");
+
+    assert_eq!(modules_changed_event.loaded_modules.len(), 1);
+    assert_eq!(modules_changed_event.loaded_modules[0].get_module_name(), "dlc.dll");
+    assert!(modules_changed_event.unloaded_modules.is_empty());
+    assert_eq!(regions_changed_event.mapped_regions, vec![NormalizedRegion::new(0x30000, 0x2000)]);
+    assert_eq!(regions_changed_event.unmapped_regions, vec![NormalizedRegion::new(0x20000, 0x1000)]);
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x400000, 0x1000)]);
+
+    let (modules_changed_event, regions_changed_event) = memory_map_watcher.poll(Some(&opened_process_info), &os_providers);
+    let modules_changed_event = modules_changed_event.expect("Expected a module unload to be reported.");
+
+    assert!(modules_changed_event.loaded_modules.is_empty());
+    assert_eq!(modules_changed_event.unloaded_modules[0].get_module_name(), "dlc.dll");
+    assert!(regions_changed_event.is_none());
+}
+
+#[test]
+fn memory_map_watcher_only_reports_addresses_that_are_mapped_or_unmapped() {
+    let mock_engine_os = MockEngineOs::new();
+    let os_providers = mock_engine_os.create_providers();
+    let opened_process_info = create_opened_process_info();
+    let mut memory_map_watcher = MemoryMapWatcher::new();
+
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x10000, 0x3000)]);
+    memory_map_watcher.poll(Some(&opened_process_info), &os_providers);
+
+    // Splitting a region, for example by reprotecting part of it, does not change which addresses are mapped.
+    mock_engine_os.set_memory_pages(vec![
+        NormalizedRegion::new(0x10000, 0x1000),
+        NormalizedRegion::new(0x11000, 0x2000),
+    ]);
+
+    let (_modules_changed_event, regions_changed_event) = memory_map_watcher.poll(Some(&opened_process_info), &os_providers);
+    assert!(regions_changed_event.is_none());
+
+    // Shrinking a region and growing it elsewhere only reports the addresses that actually changed.
+    mock_engine_os.set_memory_pages(vec![
+        NormalizedRegion::new(0x10000, 0x1000),
+        NormalizedRegion::new(0x11000, 0x1000),
+        NormalizedRegion::new(0x13000, 0x1000),
+    ]);
+
+    let (_modules_changed_event, regions_changed_event) = memory_map_watcher.poll(Some(&opened_process_info), &os_providers);
+    let regions_changed_event = regions_changed_event.expect("Expected region changes to be reported.");
+
+    assert_eq!(regions_changed_event.mapped_regions, vec![NormalizedRegion::new(0x13000, 0x1000)]);
+    assert_eq!(regions_changed_event.unmapped_regions, vec![NormalizedRegion::new(0x12000, 0x1000)]);
+}
+
+#[test]
+fn memory_map_watcher_rebaselines_when_process_changes() {
+    let mock_engine_os = MockEngineOs::new();
+    let os_providers = mock_engine_os.create_providers();
+    let mut memory_map_watcher = MemoryMapWatcher::new();
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("game.exe", 0x400000, 0x1000)]);
+    memory_map_watcher.poll(Some(&create_opened_process_info()), &os_providers);
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("other.exe", 0x400000, 0x1000)]);
+
+    let restarted_process_info = OpenedProcessInfo::new(std::process::id() + 1, "other.exe".to_string(), 0xABC1, Bitness::Bit64, None);
+    let (modules_changed_event, regions_changed_event) = memory_map_watcher.poll(Some(&restarted_process_info), &os_providers);
+
+    assert!(modules_changed_event.is_none());
+    assert!(regions_changed_event.is_none());
+}
+
+#[test]
+fn snapshot_marks_regions_overlapping_unmapped_memory_as_stale() {
+    let mut snapshot = Snapshot::new();
+
+    snapshot.set_snapshot_regions(vec![
+        SnapshotRegion::new(NormalizedRegion::new(0x10000, 0x1000), Vec::new()),
+        SnapshotRegion::new(NormalizedRegion::new(0x20000, 0x1000), Vec::new()),
+    ]);
+
+    assert_eq!(snapshot.mark_stale_regions(&[NormalizedRegion::new(0x20800, 0x1000)]), 1);
+    assert_eq!(snapshot.mark_stale_regions(&[NormalizedRegion::new(0x20800, 0x1000)]), 0);
+    assert_eq!(snapshot.mark_stale_regions(&[NormalizedRegion::new(0x11000, 0x1000)]), 0);
+    assert_eq!(snapshot.get_stale_region_count(), 1);
+    assert!(!snapshot.get_snapshot_regions()[0].get_is_stale());
+    assert!(snapshot.get_snapshot_regions()[1].get_is_stale());
+}
+
+#[test]
+fn scan_new_executor_uses_injected_memory_page_bounds() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_memory_pages(vec![
+        NormalizedRegion::new(0x1000, 0x1000),
+        NormalizedRegion::new(0x2000, 0x1000),
+        NormalizedRegion::new(0x5000, 0x1000),
+    ]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let _scan_new_response = ScanNewRequest {}.execute(&engine_privileged_state);
+
+    let snapshot_ref = engine_privileged_state.get_snapshot();
+    let snapshot_guard = match snapshot_ref.read() {
+        Ok(snapshot_guard) => snapshot_guard,
+        Err(error) => panic!("failed to lock snapshot for read: {}", error),
+    };
+    let snapshot_regions = snapshot_guard.get_snapshot_regions();
+
+    assert_eq!(snapshot_regions.len(), 2);
+    assert_eq!(snapshot_regions[0].get_base_address(), 0x1000);
+    assert_eq!(snapshot_regions[0].get_region_size(), 0x2000);
+    assert_eq!(snapshot_regions[0].page_boundaries, vec![0x2000]);
+    assert_eq!(snapshot_regions[1].get_base_address(), 0x5000);
+    assert_eq!(snapshot_regions[1].get_region_size(), 0x1000);
+}
+
+#[test]
+fn snapshot_refresh_drops_unmapped_regions_and_clips_partially_unmapped_regions() {
+    let mut snapshot = Snapshot::new();
 
-    let process_close_response = ProcessCloseRequest {}.execute(&engine_privileged_state);
-    assert!(process_close_response.process_info.is_some());
+    snapshot.capture_memory_pages(vec![
+        NormalizedRegion::new(0x1000, 0x1000),
+        NormalizedRegion::new(0x2000, 0x1000),
+        NormalizedRegion::new(0x5000, 0x1000),
+    ]);
+    snapshot.mark_stale_regions(&[NormalizedRegion::new(0x2000, 0x1000)]);
 
-    let mock_os_state = mock_engine_os.get_state();
-    let state_guard = match mock_os_state.lock() {
-        Ok(state_guard) => state_guard,
-        Err(error) => panic!("failed to lock mock state: {}", error),
-    };
-    assert_eq!(state_guard.process_query_requests.len(), 2);
-    assert_eq!(state_guard.process_query_requests[0].search_name, Some("calc".to_string()));
-    assert!(state_guard.process_query_requests[0].require_windowed);
-    assert!(state_guard.process_query_requests[0].match_case);
-    assert_eq!(state_guard.process_query_requests[0].limit, Some(5));
-    assert!(!state_guard.process_query_requests[0].fetch_icons);
-    assert_eq!(state_guard.process_query_requests[1].required_process_id, Some(process_identifier));
-    assert_eq!(state_guard.open_process_requests, vec![process_identifier]);
-    assert_eq!(state_guard.close_process_handles, vec![0xBEEF]);
+    let refresh_result = snapshot.refresh_memory_pages(vec![NormalizedRegion::new(0x1000, 0x800)], false);
+    let snapshot_regions = snapshot.get_snapshot_regions();
+
+    assert_eq!(refresh_result.dropped_region_count, 1);
+    assert_eq!(refresh_result.split_region_count, 1);
+    assert_eq!(refresh_result.added_region_count, 0);
+    assert_eq!(snapshot_regions.len(), 1);
+    assert_eq!(snapshot_regions[0].get_base_address(), 0x1000);
+    assert_eq!(snapshot_regions[0].get_region_size(), 0x800);
+    assert!(snapshot_regions[0].page_boundaries.is_empty());
+    assert!(!snapshot_regions[0].get_is_stale());
 }
 
 #[test]
-fn process_open_executor_returns_none_when_process_handle_resolution_fails() {
-    let (mock_engine_os, engine_privileged_state) = create_test_state();
-    let process_identifier = std::process::id();
-    let process_info = ProcessInfo::new(process_identifier, "calc.exe".to_string(), true, None);
+fn snapshot_refresh_preserves_filters_and_seeds_new_regions_on_request() {
+    let mut snapshot = Snapshot::new();
+    let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, 0x1000), Vec::new());
+    let snapshot_filter_collection = SnapshotRegionFilterCollection::new(
+        vec![vec![
+            SnapshotRegionFilter::new(0x1010, 4),
+            SnapshotRegionFilter::new(0x1800, 4),
+        ]],
+        DataTypeRef::new("u32"),
+        MemoryAlignment::Alignment4,
+    );
 
-    mock_engine_os.set_processes(vec![process_info]);
-    mock_engine_os.set_opened_process_result(None);
+    snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![snapshot_filter_collection]));
+    snapshot.capture_memory_pages(vec![NormalizedRegion::new(0x1000, 0x1000)]);
+    snapshot.set_snapshot_regions(vec![snapshot_region]);
+
+    // New memory is not added to an in-progress scan unless explicitly requested.
+    let refresh_result = snapshot.refresh_memory_pages(
+        vec![
+            NormalizedRegion::new(0x1000, 0x1000),
+            NormalizedRegion::new(0x8000, 0x100),
+        ],
+        false,
+    );
 
-    let process_open_request = ProcessOpenRequest {
-        process_id: Some(process_identifier),
-        search_name: Some("calc".to_string()),
-        match_case: true,
-    };
-    let process_open_response = process_open_request.execute(&engine_privileged_state);
-    assert!(process_open_response.opened_process_info.is_none());
-    assert!(
-        engine_privileged_state
-            .get_process_manager()
-            .get_opened_process()
-            .is_none()
+    assert_eq!(refresh_result.added_region_count, 0);
+    assert_eq!(snapshot.get_snapshot_regions().len(), 1);
+    assert_eq!(snapshot.get_number_of_results(), 2);
+
+    let refresh_result = snapshot.refresh_memory_pages(
+        vec![
+            NormalizedRegion::new(0x1000, 0x400),
+            NormalizedRegion::new(0x8000, 0x100),
+        ],
+        true,
     );
+    let snapshot_regions = snapshot.get_snapshot_regions();
 
-    let mock_os_state = mock_engine_os.get_state();
-    let state_guard = match mock_os_state.lock() {
-        Ok(state_guard) => state_guard,
-        Err(error) => panic!("failed to lock mock state: {}", error),
-    };
-    assert_eq!(state_guard.open_process_requests, vec![process_identifier]);
+    assert_eq!(refresh_result.split_region_count, 1);
+    assert_eq!(refresh_result.added_region_count, 1);
+    assert_eq!(snapshot_regions.len(), 2);
+    assert_eq!(snapshot_regions[1].get_base_address(), 0x8000);
+    assert_eq!(snapshot.get_number_of_results(), 1 + 0x100 / 4);
+
+    // Once seeded, the new memory is part of the scan and is not added again.
+    let refresh_result = snapshot.refresh_memory_pages(
+        vec![
+            NormalizedRegion::new(0x1000, 0x400),
+            NormalizedRegion::new(0x8000, 0x100),
+        ],
+        true,
+    );
+
+    assert_eq!(refresh_result, Default::default());
 }
 
 #[test]
-fn scan_new_executor_uses_injected_memory_page_bounds() {
+fn scan_refresh_regions_executor_reconciles_snapshot_with_injected_memory_page_bounds() {
     let (mock_engine_os, engine_privileged_state) = create_test_state();
     mock_engine_os.set_memory_pages(vec![
         NormalizedRegion::new(0x1000, 0x1000),
-        NormalizedRegion::new(0x2000, 0x1000),
         NormalizedRegion::new(0x5000, 0x1000),
     ]);
     engine_privileged_state
@@ -265,6 +1898,18 @@ fn scan_new_executor_uses_injected_memory_page_bounds() {
 
     let _scan_new_response = ScanNewRequest {}.execute(&engine_privileged_state);
 
+    mock_engine_os.set_memory_pages(vec![
+        NormalizedRegion::new(0x1000, 0x1000),
+        NormalizedRegion::new(0x9000, 0x1000),
+    ]);
+
+    let scan_refresh_regions_response = ScanRefreshRegionsRequest { seed_new_regions: false }.execute(&engine_privileged_state);
+
+    assert!(scan_refresh_regions_response.success);
+    assert_eq!(scan_refresh_regions_response.dropped_region_count, 1);
+    assert_eq!(scan_refresh_regions_response.split_region_count, 0);
+    assert_eq!(scan_refresh_regions_response.added_region_count, 1);
+
     let snapshot_ref = engine_privileged_state.get_snapshot();
     let snapshot_guard = match snapshot_ref.read() {
         Ok(snapshot_guard) => snapshot_guard,
@@ -274,10 +1919,16 @@ fn scan_new_executor_uses_injected_memory_page_bounds() {
 
     assert_eq!(snapshot_regions.len(), 2);
     assert_eq!(snapshot_regions[0].get_base_address(), 0x1000);
-    assert_eq!(snapshot_regions[0].get_region_size(), 0x2000);
-    assert_eq!(snapshot_regions[0].page_boundaries, vec![0x2000]);
-    assert_eq!(snapshot_regions[1].get_base_address(), 0x5000);
-    assert_eq!(snapshot_regions[1].get_region_size(), 0x1000);
+    assert_eq!(snapshot_regions[1].get_base_address(), 0x9000);
+}
+
+#[test]
+fn scan_refresh_regions_executor_fails_without_opened_process() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+
+    let scan_refresh_regions_response = ScanRefreshRegionsRequest { seed_new_regions: true }.execute(&engine_privileged_state);
+
+    assert!(!scan_refresh_regions_response.success);
 }
 
 #[test]
@@ -289,7 +1940,11 @@ fn scan_results_list_executor_uses_injected_providers() {
         .set_opened_process(create_opened_process_info());
     seed_snapshot_with_single_scan_result(&engine_privileged_state, 0x1010);
 
-    let scan_results_list_response = ScanResultsListRequest { page_index: 0 }.execute(&engine_privileged_state);
+    let scan_results_list_response = ScanResultsListRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
 
     assert_eq!(scan_results_list_response.scan_results.len(), 1);
     assert_eq!(scan_results_list_response.scan_results[0].get_module(), "game.exe");
@@ -313,7 +1968,11 @@ fn scan_results_list_executor_handles_read_failure_without_incorrect_value_mutat
         .set_opened_process(create_opened_process_info());
     seed_snapshot_with_single_scan_result(&engine_privileged_state, 0x1010);
 
-    let scan_results_list_response = ScanResultsListRequest { page_index: 0 }.execute(&engine_privileged_state);
+    let scan_results_list_response = ScanResultsListRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
 
     assert_eq!(scan_results_list_response.scan_results.len(), 1);
     assert_eq!(scan_results_list_response.scan_results[0].get_module(), "game.exe");
@@ -346,7 +2005,11 @@ fn scan_results_query_executor_uses_injected_providers() {
         .set_opened_process(create_opened_process_info());
     seed_snapshot_with_single_scan_result(&engine_privileged_state, 0x4014);
 
-    let scan_results_query_response = ScanResultsQueryRequest { page_index: 0 }.execute(&engine_privileged_state);
+    let scan_results_query_response = ScanResultsQueryRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
 
     assert_eq!(scan_results_query_response.scan_results.len(), 1);
     assert_eq!(scan_results_query_response.scan_results[0].get_module(), "engine.dll");
@@ -370,7 +2033,11 @@ fn scan_results_query_executor_handles_read_failure_without_incorrect_value_muta
         .set_opened_process(create_opened_process_info());
     seed_snapshot_with_single_scan_result(&engine_privileged_state, 0x4014);
 
-    let scan_results_query_response = ScanResultsQueryRequest { page_index: 0 }.execute(&engine_privileged_state);
+    let scan_results_query_response = ScanResultsQueryRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
 
     assert_eq!(scan_results_query_response.scan_results.len(), 1);
     assert_eq!(scan_results_query_response.scan_results[0].get_module(), "engine.dll");
@@ -403,7 +2070,11 @@ fn scan_results_query_executor_reads_recent_values_when_scan_current_value_is_mi
         .set_opened_process(create_opened_process_info());
     seed_snapshot_with_single_scan_result_missing_current_value(&engine_privileged_state, 0x4014);
 
-    let scan_results_query_response = ScanResultsQueryRequest { page_index: 0 }.execute(&engine_privileged_state);
+    let scan_results_query_response = ScanResultsQueryRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .execute(&engine_privileged_state);
 
     assert_eq!(scan_results_query_response.scan_results.len(), 1);
     assert!(
@@ -524,6 +2195,7 @@ fn scan_results_freeze_executor_uses_injected_providers() {
     let scan_results_freeze_response = ScanResultsFreezeRequest {
         scan_result_refs: vec![ScanResultRef::new(0)],
         is_frozen: true,
+        freeze_policy: FreezePolicy::default(),
     }
     .execute(&engine_privileged_state);
 
@@ -549,6 +2221,372 @@ fn scan_results_freeze_executor_uses_injected_providers() {
     assert_eq!(state_guard.memory_read_addresses, vec![0x8018]);
 }
 
+#[test]
+fn scan_results_freeze_executor_applies_freeze_policy_and_rejects_invalid_policies() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("freeze.exe", 0x8000, 0x1000)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    seed_snapshot_with_single_scan_result(&engine_privileged_state, 0x8018);
+
+    let invalid_freeze_response = ScanResultsFreezeRequest {
+        scan_result_refs: vec![ScanResultRef::new(0)],
+        is_frozen: true,
+        freeze_policy: FreezePolicy {
+            freeze_mode: FreezeMode::Increment,
+            ..FreezePolicy::default()
+        },
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(
+        invalid_freeze_response
+            .failed_freeze_toggle_scan_result_refs
+            .len(),
+        1
+    );
+
+    let scan_results_freeze_response = ScanResultsFreezeRequest {
+        scan_result_refs: vec![ScanResultRef::new(0)],
+        is_frozen: true,
+        freeze_policy: FreezePolicy {
+            freeze_mode: FreezeMode::Clamp,
+            freeze_minimum: Some("1".to_string()),
+            freeze_interval_ms: Some(16),
+            freeze_low_latency: true,
+            ..FreezePolicy::default()
+        },
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(
+        scan_results_freeze_response
+            .failed_freeze_toggle_scan_result_refs
+            .is_empty()
+    );
+
+    let frozen_pointer = Pointer::new(0x18, Vec::new(), "freeze.exe".to_string());
+    let freeze_list_registry = engine_privileged_state.get_freeze_list_registry();
+    let freeze_list_registry_guard = match freeze_list_registry.read() {
+        Ok(freeze_list_registry_guard) => freeze_list_registry_guard,
+        Err(error) => panic!("failed to lock freeze list registry: {}", error),
+    };
+    let freeze_list_entry = freeze_list_registry_guard
+        .get_address_freeze_entry(&frozen_pointer)
+        .expect("scan result should be frozen");
+
+    assert_eq!(freeze_list_entry.get_freeze_mode(), FreezeMode::Clamp);
+    assert_eq!(freeze_list_entry.get_interval_ms(), Some(16));
+    assert!(freeze_list_entry.is_low_latency());
+}
+
+#[test]
+fn scan_result_freezer_coalesces_adjacent_writes_and_records_failures() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("freeze.exe", 0x8000, 0x1000)]);
+    mock_engine_os.set_write_success(false);
+
+    {
+        let freeze_list_registry = engine_privileged_state.get_freeze_list_registry();
+        let mut freeze_list_registry_guard = match freeze_list_registry.write() {
+            Ok(freeze_list_registry_guard) => freeze_list_registry_guard,
+            Err(error) => panic!("failed to lock freeze list registry: {}", error),
+        };
+
+        freeze_list_registry_guard.set_address_frozen(
+            Pointer::new(0x18, Vec::new(), "freeze.exe".to_string()),
+            FreezeListEntry::new(DataValue::new(DataTypeRef::new("u16"), vec![0x11, 0x22])),
+        );
+        freeze_list_registry_guard.set_address_frozen(
+            Pointer::new(0x1A, Vec::new(), "freeze.exe".to_string()),
+            FreezeListEntry::new(DataValue::new(DataTypeRef::new("u16"), vec![0x33, 0x44])),
+        );
+    }
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    loop {
+        let has_coalesced_write = match mock_engine_os.get_state().lock() {
+            Ok(state_guard) => state_guard
+                .memory_write_requests
+                .iter()
+                .any(|(address, values)| *address == 0x8018 && values == &vec![0x11, 0x22, 0x33, 0x44]),
+            Err(error) => panic!("failed to lock mock state: {}", error),
+        };
+        let failed_write_count = match engine_privileged_state.get_freeze_list_registry().read() {
+            Ok(freeze_list_registry_guard) => freeze_list_registry_guard
+                .get_freeze_metrics()
+                .get_failed_write_count(),
+            Err(error) => panic!("failed to lock freeze list registry: {}", error),
+        };
+
+        if has_coalesced_write && failed_write_count >= 2 {
+            break;
+        }
+
+        assert!(Instant::now() < deadline, "timed out waiting for the freezer to write");
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn memory_freeze_executor_re_resolves_address_expression_targets_on_each_freeze() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    seed_pointer_chain(&mock_engine_os);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let freeze_target = MemoryFreezeTarget {
+        address_expression: "[game.exe+10]+4".to_string(),
+        data_type_id: "u8".to_string(),
+        ..MemoryFreezeTarget::default()
+    };
+    let memory_freeze_response = MemoryFreezeRequest {
+        freeze_targets: vec![freeze_target.clone()],
+        is_frozen: true,
+    }
+    .execute(&engine_privileged_state);
+
+    assert_eq!(memory_freeze_response.failed_freeze_target_count, 0);
+
+    // Repoint the chain, such that the freezer must follow the new pointer rather than a cached address.
+    mock_engine_os.set_memory_contents(vec![(0x7010, 0x7300u64.to_le_bytes().to_vec())]);
+
+    wait_for_freeze_write(&mock_engine_os, 0x7304);
+
+    match mock_engine_os.get_state().lock() {
+        Ok(state_guard) => assert!(
+            state_guard
+                .memory_write_requests
+                .iter()
+                .all(|(address, _values)| *address != 0),
+            "pointer targets must never be frozen at address zero"
+        ),
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    }
+
+    MemoryFreezeRequest {
+        freeze_targets: vec![freeze_target],
+        is_frozen: false,
+    }
+    .execute(&engine_privileged_state);
+
+    match engine_privileged_state.get_freeze_list_registry().read() {
+        Ok(freeze_list_registry_guard) => assert!(freeze_list_registry_guard.get_frozen_pointers().is_empty()),
+        Err(error) => panic!("failed to lock freeze list registry: {}", error),
+    }
+}
+
+fn wait_for_freeze_write(
+    mock_engine_os: &MockEngineOs,
+    address: u64,
+) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    loop {
+        let has_write = match mock_engine_os.get_state().lock() {
+            Ok(state_guard) => state_guard
+                .memory_write_requests
+                .iter()
+                .any(|(write_address, _values)| *write_address == address),
+            Err(error) => panic!("failed to lock mock state: {}", error),
+        };
+
+        if has_write {
+            return;
+        }
+
+        assert!(Instant::now() < deadline, "timed out waiting for the freezer to write {:#X}", address);
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn scan_result_freezer_drops_absolute_freezes_and_rebases_module_freezes_when_process_changes() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("freeze.exe", 0x8000, 0x1000)]);
+
+    {
+        let freeze_list_registry = engine_privileged_state.get_freeze_list_registry();
+        let mut freeze_list_registry_guard = match freeze_list_registry.write() {
+            Ok(freeze_list_registry_guard) => freeze_list_registry_guard,
+            Err(error) => panic!("failed to lock freeze list registry: {}", error),
+        };
+
+        freeze_list_registry_guard.set_address_frozen(
+            Pointer::new(0x5000, Vec::new(), String::new()),
+            FreezeListEntry::new(DataValue::new(DataTypeRef::new("u8"), vec![0x11])),
+        );
+        freeze_list_registry_guard.set_address_frozen(
+            Pointer::new(0x18, Vec::new(), "freeze.exe".to_string()),
+            FreezeListEntry::new(DataValue::new(DataTypeRef::new("u8"), vec![0x22])),
+        );
+    }
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    wait_for_freeze_write(&mock_engine_os, 0x5000);
+    wait_for_freeze_write(&mock_engine_os, 0x8018);
+
+    engine_privileged_state
+        .get_process_manager()
+        .clear_opened_process();
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    loop {
+        let frozen_pointer_count = match engine_privileged_state.get_freeze_list_registry().read() {
+            Ok(freeze_list_registry_guard) => freeze_list_registry_guard.get_frozen_pointers().len(),
+            Err(error) => panic!("failed to lock freeze list registry: {}", error),
+        };
+
+        if frozen_pointer_count == 1 {
+            break;
+        }
+
+        assert!(Instant::now() < deadline, "timed out waiting for absolute freezes to be dropped");
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    // Reattach to a process that loaded the module elsewhere.
+    mock_engine_os.set_modules(vec![NormalizedModule::new("freeze.exe", 0x9000, 0x1000)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    wait_for_freeze_write(&mock_engine_os, 0x9018);
+
+    match engine_privileged_state.get_freeze_list_registry().read() {
+        Ok(freeze_list_registry_guard) => assert!(freeze_list_registry_guard.is_address_frozen(&Pointer::new(0x18, Vec::new(), "freeze.exe".to_string()))),
+        Err(error) => panic!("failed to lock freeze list registry: {}", error),
+    }
+}
+
+#[test]
+fn scan_result_freezer_caches_modules_and_releases_registry_during_memory_access() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("freeze.exe", 0x8000, 0x1000)]);
+
+    let freeze_policy = FreezePolicy {
+        freeze_low_latency: true,
+        ..FreezePolicy::default()
+    };
+    let freeze_list_entry = match FreezeListEntry::from_policy(DataValue::new(DataTypeRef::new("u8"), vec![0x11]), &freeze_policy) {
+        Ok(freeze_list_entry) => freeze_list_entry,
+        Err(error) => panic!("failed to create freeze list entry: {}", error),
+    };
+
+    match engine_privileged_state.get_freeze_list_registry().write() {
+        Ok(mut freeze_list_registry_guard) => {
+            freeze_list_registry_guard.set_address_frozen(Pointer::new(0x18, Vec::new(), "freeze.exe".to_string()), freeze_list_entry)
+        }
+        Err(error) => panic!("failed to lock freeze list registry: {}", error),
+    }
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+    wait_for_freeze_write(&mock_engine_os, 0x8018);
+
+    let module_query_count_before = match mock_engine_os.get_state().lock() {
+        Ok(state_guard) => state_guard.module_query_count,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+
+    thread::sleep(Duration::from_millis(300));
+
+    let mock_state = mock_engine_os.get_state();
+    let mock_state_guard = match mock_state.lock() {
+        Ok(mock_state_guard) => mock_state_guard,
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+
+    // Low latency freezes run every millisecond, so querying modules on each freeze would far exceed this.
+    assert!(mock_state_guard.module_query_count - module_query_count_before < 50);
+
+    // Holding the mock state stalls the freezer inside a memory access, which must not block registry commands.
+    let deadline = Instant::now() + Duration::from_millis(500);
+
+    loop {
+        if engine_privileged_state
+            .get_freeze_list_registry()
+            .try_write()
+            .is_ok()
+        {
+            break;
+        }
+
+        assert!(Instant::now() < deadline, "the freezer held the registry lock while accessing memory");
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    drop(mock_state_guard);
+}
+
+#[test]
+fn scan_result_freezer_retries_set_once_values_until_written() {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+    mock_engine_os.set_modules(vec![NormalizedModule::new("freeze.exe", 0x8000, 0x1000)]);
+    mock_engine_os.set_write_success(false);
+
+    let freeze_policy = FreezePolicy {
+        freeze_mode: FreezeMode::SetOnce,
+        freeze_interval_ms: Some(5),
+        ..FreezePolicy::default()
+    };
+    let freeze_list_entry = match FreezeListEntry::from_policy(DataValue::new(DataTypeRef::new("u8"), vec![0x11]), &freeze_policy) {
+        Ok(freeze_list_entry) => freeze_list_entry,
+        Err(error) => panic!("failed to create freeze list entry: {}", error),
+    };
+
+    match engine_privileged_state.get_freeze_list_registry().write() {
+        Ok(mut freeze_list_registry_guard) => {
+            freeze_list_registry_guard.set_address_frozen(Pointer::new(0x18, Vec::new(), "freeze.exe".to_string()), freeze_list_entry)
+        }
+        Err(error) => panic!("failed to lock freeze list registry: {}", error),
+    }
+
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(create_opened_process_info());
+
+    let count_writes = || match mock_engine_os.get_state().lock() {
+        Ok(state_guard) => state_guard
+            .memory_write_requests
+            .iter()
+            .filter(|(address, _values)| *address == 0x8018)
+            .count(),
+        Err(error) => panic!("failed to lock mock state: {}", error),
+    };
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    while count_writes() < 2 {
+        assert!(Instant::now() < deadline, "timed out waiting for the failed set-once write to be retried");
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    mock_engine_os.set_write_success(true);
+
+    let failed_write_count = count_writes();
+
+    while count_writes() == failed_write_count {
+        assert!(Instant::now() < deadline, "timed out waiting for the set-once write");
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    let applied_write_count = count_writes();
+
+    thread::sleep(Duration::from_millis(100));
+
+    assert_eq!(count_writes(), applied_write_count);
+}
+
 #[test]
 fn scan_results_freeze_executor_reports_failed_refs_when_memory_read_fails() {
     let (mock_engine_os, engine_privileged_state) = create_test_state();
@@ -563,6 +2601,7 @@ fn scan_results_freeze_executor_reports_failed_refs_when_memory_read_fails() {
     let scan_results_freeze_response = ScanResultsFreezeRequest {
         scan_result_refs: vec![scan_result_ref.clone()],
         is_frozen: true,
+        freeze_policy: FreezePolicy::default(),
     }
     .execute(&engine_privileged_state);
 
@@ -623,6 +2662,7 @@ fn memory_write_executor_returns_failure_without_freeze_mutation_when_writer_fai
     let memory_write_request = MemoryWriteRequest {
         address: 0x20,
         module_name: "game.exe".to_string(),
+        address_expression: String::new(),
         value: vec![1, 2, 3, 4],
     };
 
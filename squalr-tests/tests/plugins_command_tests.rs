@@ -0,0 +1,567 @@
+use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use squalr_engine::engine_mode::EngineMode;
+use squalr_engine::engine_privileged_state::{EnginePrivilegedState, create_engine_privileged_state_with_os_providers};
+use squalr_engine_api::commands::memory::resolve::memory_resolve_request::MemoryResolveRequest;
+use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use squalr_engine_api::commands::plugins::add_middleware::plugins_add_middleware_request::PluginsAddMiddlewareRequest;
+use squalr_engine_api::commands::plugins::list::plugins_list_request::PluginsListRequest;
+use squalr_engine_api::commands::plugins::list::plugins_list_response::PluginsListResponse;
+use squalr_engine_api::commands::plugins::list_middleware::plugins_list_middleware_request::PluginsListMiddlewareRequest;
+use squalr_engine_api::commands::plugins::load::plugins_load_request::PluginsLoadRequest;
+use squalr_engine_api::commands::plugins::load::plugins_load_response::PluginsLoadResponse;
+use squalr_engine_api::commands::plugins::plugins_command::PluginsCommand;
+use squalr_engine_api::commands::plugins::remove_middleware::plugins_remove_middleware_request::PluginsRemoveMiddlewareRequest;
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use squalr_engine_api::commands::project::list::project_list_response::ProjectListResponse;
+use squalr_engine_api::commands::unprivileged_command_response::TypedUnprivilegedCommandResponse;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_abi::{
+    DATA_TYPE_PLUGIN_ABI_VERSION, DATA_TYPE_PLUGIN_STATUS_OK, DataTypePluginCompareOperation, DataTypePluginDescriptor, DataTypePluginManifest,
+    DataTypePluginValueFormat,
+};
+use squalr_engine_api::structures::data_types::plugin_types::data_type_plugin_error::DataTypePluginError;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::endian::Endian;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_session::os::PageRetrievalMode;
+use squalr_engine_session::os::memory_middleware::memory_middleware_registry::RESOLVE_RETRY_INTERVAL;
+use squalr_engine_session::plugins::data_type_plugin_loader::DataTypePluginLoader;
+use squalr_tests::mocks::mock_engine_bindings::MockEngineBindings;
+use squalr_tests::mocks::mock_os::MockEngineOs;
+use std::path::PathBuf;
+use std::ptr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use structopt::StructOpt;
+
+unsafe extern "C" fn parse_byte(
+    value_string: *const u8,
+    value_string_length: usize,
+    _value_format: u32,
+    out_value_bytes: *mut u8,
+) -> i32 {
+    let value_string = unsafe { std::slice::from_raw_parts(value_string, value_string_length) };
+
+    match std::str::from_utf8(value_string)
+        .ok()
+        .and_then(|value| value.parse::<u8>().ok())
+    {
+        Some(value) => {
+            unsafe { *out_value_bytes = value };
+            DATA_TYPE_PLUGIN_STATUS_OK
+        }
+        None => 1,
+    }
+}
+
+unsafe extern "C" fn format_byte(
+    value_bytes: *const u8,
+    _value_format: u32,
+    out_string: *mut u8,
+    out_capacity: usize,
+    out_length: *mut usize,
+) -> i32 {
+    let value_string = unsafe { *value_bytes }.to_string();
+
+    unsafe { *out_length = value_string.len() };
+
+    if value_string.len() > out_capacity {
+        return 2;
+    }
+
+    unsafe { ptr::copy_nonoverlapping(value_string.as_ptr(), out_string, value_string.len()) };
+    DATA_TYPE_PLUGIN_STATUS_OK
+}
+
+unsafe extern "C" fn compare_byte(
+    _compare_operation: u32,
+    current: *const u8,
+    _previous: *const u8,
+    operand: *const u8,
+) -> u8 {
+    unsafe { (*current == *operand) as u8 }
+}
+
+struct ManifestPointers(DataTypePluginManifest);
+
+unsafe impl Sync for ManifestPointers {}
+
+struct DescriptorPointers(DataTypePluginDescriptor);
+
+unsafe impl Sync for DescriptorPointers {}
+
+static TEST_PLUGIN_DESCRIPTOR: DescriptorPointers = DescriptorPointers(DataTypePluginDescriptor {
+    data_type_id: c"test_plugin_byte".as_ptr(),
+    icon_id: ptr::null(),
+    display_name: c"Test Byte".as_ptr(),
+    unit_size_in_bytes: 1,
+    flags: 0,
+    supported_value_formats: 1 << (DataTypePluginValueFormat::Decimal as u32),
+    default_value_format: DataTypePluginValueFormat::Decimal as u32,
+    supported_compare_operations: 1 << (DataTypePluginCompareOperation::Equal as u32),
+    parse_value: parse_byte,
+    format_value: format_byte,
+    compare: compare_byte,
+});
+
+fn create_test_manifest(abi_version: u32) -> ManifestPointers {
+    ManifestPointers(DataTypePluginManifest {
+        abi_version,
+        plugin_name: c"test plugin".as_ptr(),
+        data_types: &TEST_PLUGIN_DESCRIPTOR.0,
+        data_type_count: 1,
+    })
+}
+
+fn create_test_state() -> (MockEngineOs, Arc<EnginePrivilegedState>) {
+    let mock_engine_os = MockEngineOs::new();
+    let engine_os_providers = mock_engine_os.create_providers();
+    let engine_privileged_state = match create_engine_privileged_state_with_os_providers(EngineMode::Standalone, engine_os_providers) {
+        Ok(engine_privileged_state) => engine_privileged_state,
+        Err(error) => panic!("failed to create engine privileged state in test: {}", error),
+    };
+
+    (mock_engine_os, engine_privileged_state)
+}
+
+#[test]
+fn plugins_load_request_dispatches_load_command_and_invokes_typed_callback() {
+    let bindings = MockEngineBindings::new(
+        PluginsLoadResponse {
+            success: true,
+            loaded_data_types: vec![],
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+    let plugins_load_request = PluginsLoadRequest {
+        plugin_path: PathBuf::from("plugins/fixed_point.so"),
+    };
+
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    plugins_load_request.send_unprivileged(&bindings, move |plugins_load_response| {
+        assert!(plugins_load_response.success);
+        callback_invoked_clone.store(true, Ordering::SeqCst);
+    });
+
+    assert!(callback_invoked.load(Ordering::SeqCst));
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert_eq!(dispatched_commands_guard.len(), 1);
+
+    match &dispatched_commands_guard[0] {
+        PrivilegedCommand::Plugins(PluginsCommand::Load { plugins_load_request }) => {
+            assert_eq!(plugins_load_request.plugin_path, PathBuf::from("plugins/fixed_point.so"));
+        }
+        dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+    }
+}
+
+#[test]
+fn plugins_list_request_does_not_invoke_callback_when_response_variant_is_wrong() {
+    let bindings = MockEngineBindings::new(
+        PluginsLoadResponse::default().to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    PluginsListRequest {}.send_unprivileged(&bindings, move |_plugins_list_response: PluginsListResponse| {
+        callback_invoked_clone.store(true, Ordering::SeqCst);
+    });
+
+    assert!(!callback_invoked.load(Ordering::SeqCst));
+}
+
+#[test]
+fn privileged_command_parser_accepts_plugins_load_with_long_flags() {
+    let parsed_command =
+        PrivilegedCommand::from_iter_safe(["squalr-cli", "plugins", "load", "--plugin-path", "libfixed.so"]).expect("command should parse successfully");
+
+    match parsed_command {
+        PrivilegedCommand::Plugins(PluginsCommand::Load { plugins_load_request }) => {
+            assert_eq!(plugins_load_request.plugin_path, PathBuf::from("libfixed.so"));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn plugins_load_executor_reports_failure_for_missing_library() {
+    let (_mock_engine_os, engine_privileged_state) = create_test_state();
+    let plugins_load_response = PluginsLoadRequest {
+        plugin_path: PathBuf::from("/nonexistent/libsqualr_missing_plugin.so"),
+    }
+    .execute(&engine_privileged_state);
+    let plugins_list_response = PluginsListRequest {}.execute(&engine_privileged_state);
+
+    assert!(!plugins_load_response.success);
+    assert!(plugins_load_response.loaded_data_types.is_empty());
+    assert!(plugins_list_response.data_types.is_empty());
+}
+
+#[test]
+fn data_type_plugin_loader_rejects_mismatched_abi_version() {
+    let manifest = create_test_manifest(DATA_TYPE_PLUGIN_ABI_VERSION + 1);
+    let result = unsafe { DataTypePluginLoader::read_plugin_manifest("test_plugin", &manifest.0, None) };
+
+    match result {
+        Err(DataTypePluginError::AbiVersionMismatch { actual_abi_version, .. }) => {
+            assert_eq!(actual_abi_version, DATA_TYPE_PLUGIN_ABI_VERSION + 1);
+        }
+        result => panic!("unexpected result: {:?}", result.map(|(plugin_name, _)| plugin_name)),
+    }
+}
+
+#[test]
+fn data_type_plugin_manifest_data_types_register_into_symbol_registry() {
+    let manifest = create_test_manifest(DATA_TYPE_PLUGIN_ABI_VERSION);
+    let (plugin_name, data_types) = unsafe { DataTypePluginLoader::read_plugin_manifest("test_plugin", &manifest.0, None) }.expect("manifest should be valid");
+    let symbol_registry = SymbolRegistry::new();
+    let data_type_ref = DataTypeRef::new("test_plugin_byte");
+
+    assert_eq!(plugin_name, "test plugin");
+    assert_eq!(data_types.len(), 1);
+
+    symbol_registry
+        .register_data_type(data_types[0].clone())
+        .expect("plugin data type should register");
+
+    assert!(symbol_registry.is_valid(&data_type_ref));
+    assert!(symbol_registry.get(data_type_ref.get_data_type_id()).is_some());
+    assert!(!symbol_registry.supports_vector_comparisons(&data_type_ref));
+    assert!(
+        symbol_registry
+            .register_data_type(data_types[0].clone())
+            .is_err()
+    );
+
+    let data_value = symbol_registry
+        .deanonymize_value_string(
+            &data_type_ref,
+            &AnonymousValueString::new("42".to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+        )
+        .expect("plugin value should parse");
+
+    assert_eq!(data_value.get_value_bytes(), &vec![42u8]);
+}
+
+fn create_emulator_middleware_request() -> PluginsAddMiddlewareRequest {
+    PluginsAddMiddlewareRequest {
+        middleware_name: "test emulator".to_string(),
+        module_name: "guest_ram".to_string(),
+        signature: "DE AD ?? EF".to_string(),
+        signature_offset: -0x100,
+        guest_base_address: 0x8000_0000,
+        region_size: 0x1000,
+        big_endian: true,
+        guest_64_bit: false,
+    }
+}
+
+fn create_emulator_test_state() -> (MockEngineOs, Arc<EnginePrivilegedState>) {
+    let (mock_engine_os, engine_privileged_state) = create_test_state();
+
+    mock_engine_os.set_modules(vec![NormalizedModule::new("emulator.exe", 0x1000, 0x1000)]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x4000, 0x2000)]);
+    mock_engine_os.set_memory_contents(vec![(0x4100, vec![0xDE, 0xAD, 0xBE, 0xEF])]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(1234, "emulator.exe".to_string(), 0xABC0, Bitness::Bit64, None));
+
+    (mock_engine_os, engine_privileged_state)
+}
+
+#[test]
+fn memory_middleware_exposes_guest_address_space_as_virtual_module_and_region() {
+    let (_mock_engine_os, engine_privileged_state) = create_emulator_test_state();
+
+    assert!(
+        create_emulator_middleware_request()
+            .execute(&engine_privileged_state)
+            .success
+    );
+
+    let opened_process = engine_privileged_state
+        .get_process_manager()
+        .get_opened_process()
+        .expect("process should be opened");
+    let os_providers = engine_privileged_state.get_os_providers();
+    let modules = os_providers.memory_query.get_modules(&opened_process);
+    let regions = os_providers
+        .memory_query
+        .get_memory_page_bounds(&opened_process, PageRetrievalMode::FromSettings);
+
+    assert_eq!(modules.len(), 1);
+    assert_eq!(modules[0].get_module_name(), "guest_ram");
+    assert_eq!(modules[0].get_base_address(), 0x8000_0000);
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].get_base_address(), 0x8000_0000);
+    assert_eq!(regions[0].get_region_size(), 0x1000);
+
+    let plugins_list_middleware_response = PluginsListMiddlewareRequest { refresh: false }.execute(&engine_privileged_state);
+
+    assert_eq!(plugins_list_middleware_response.middleware_names, vec!["test emulator".to_string()]);
+    assert_eq!(plugins_list_middleware_response.address_spaces.len(), 1);
+    assert_eq!(plugins_list_middleware_response.address_spaces[0].get_host_base_address(), 0x4000);
+    assert_eq!(plugins_list_middleware_response.address_spaces[0].get_endian(), &Endian::Big);
+}
+
+#[test]
+fn memory_middleware_translates_guest_writes_to_host_addresses_and_rejects_unmapped_addresses() {
+    let (mock_engine_os, engine_privileged_state) = create_emulator_test_state();
+
+    assert!(
+        create_emulator_middleware_request()
+            .execute(&engine_privileged_state)
+            .success
+    );
+
+    let guest_write_response = MemoryWriteRequest {
+        address: 0x20,
+        module_name: "guest_ram".to_string(),
+        address_expression: String::new(),
+        value: vec![1, 2, 3, 4],
+    }
+    .execute(&engine_privileged_state);
+    let out_of_bounds_write_response = MemoryWriteRequest {
+        address: 0xFFE,
+        module_name: "guest_ram".to_string(),
+        address_expression: String::new(),
+        value: vec![1, 2, 3, 4],
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(guest_write_response.success);
+    assert!(!out_of_bounds_write_response.success);
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = mock_os_state
+        .lock()
+        .expect("mock state lock should be available");
+
+    assert_eq!(state_guard.memory_write_requests.len(), 1);
+    assert_eq!(state_guard.memory_write_requests[0].0, 0x4020);
+}
+
+#[test]
+fn memory_middleware_rejects_access_until_signature_resolves_and_then_retries() {
+    let (mock_engine_os, engine_privileged_state) = create_emulator_test_state();
+    let guest_write_request = MemoryWriteRequest {
+        address: 0x20,
+        module_name: "guest_ram".to_string(),
+        address_expression: String::new(),
+        value: vec![1, 2, 3, 4],
+    };
+
+    // The process is re-opened as this test process, such that it is not detached as dead while waiting to retry.
+    mock_engine_os.set_memory_contents(vec![]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(
+            std::process::id(),
+            "emulator.exe".to_string(),
+            0xABC0,
+            Bitness::Bit64,
+            None,
+        ));
+
+    assert!(
+        create_emulator_middleware_request()
+            .execute(&engine_privileged_state)
+            .success
+    );
+
+    let unresolved_write_response = guest_write_request.execute(&engine_privileged_state);
+    let unresolved_host_write_response = MemoryWriteRequest {
+        address: 0x4020,
+        module_name: String::new(),
+        address_expression: String::new(),
+        value: vec![1],
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(!unresolved_write_response.success);
+    assert!(!unresolved_host_write_response.success);
+
+    mock_engine_os.set_memory_contents(vec![(0x4100, vec![0xDE, 0xAD, 0xBE, 0xEF])]);
+    thread::sleep(RESOLVE_RETRY_INTERVAL);
+
+    let resolved_write_response = guest_write_request.execute(&engine_privileged_state);
+
+    assert!(resolved_write_response.success);
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = mock_os_state
+        .lock()
+        .expect("mock state lock should be available");
+
+    assert_eq!(state_guard.memory_write_requests.len(), 1);
+    assert_eq!(state_guard.memory_write_requests[0].0, 0x4020);
+}
+
+#[test]
+fn memory_middleware_reads_guest_pointers_in_guest_byte_order_and_width() {
+    let (mock_engine_os, engine_privileged_state) = create_emulator_test_state();
+    let resolve_guest_pointer = || {
+        MemoryResolveRequest {
+            address_expression: "[guest_ram+10]+4".to_string(),
+        }
+        .execute(&engine_privileged_state)
+    };
+
+    // A 32-bit guest inside a 64-bit emulator stores 4 byte pointers, so the bytes that follow are not part of the pointer.
+    mock_engine_os.set_memory_contents(vec![
+        (0x4010, vec![0x80, 0x00, 0x00, 0x40, 0xFF, 0xFF, 0xFF, 0xFF]),
+        (0x4100, vec![0xDE, 0xAD, 0xBE, 0xEF]),
+    ]);
+
+    assert!(
+        create_emulator_middleware_request()
+            .execute(&engine_privileged_state)
+            .success
+    );
+
+    let memory_resolve_response = resolve_guest_pointer();
+
+    assert!(memory_resolve_response.success);
+    assert_eq!(memory_resolve_response.address, 0x8000_0044);
+    assert_eq!(memory_resolve_response.module_name, "guest_ram");
+    assert_eq!(memory_resolve_response.module_offset, 0x44);
+
+    // A 64-bit guest reads the full 8 byte pointer.
+    mock_engine_os.set_memory_contents(vec![
+        (0x4010, vec![0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x40]),
+        (0x4100, vec![0xDE, 0xAD, 0xBE, 0xEF]),
+    ]);
+
+    assert!(
+        PluginsRemoveMiddlewareRequest {
+            middleware_name: "test emulator".to_string(),
+        }
+        .execute(&engine_privileged_state)
+        .success
+    );
+    assert!(
+        PluginsAddMiddlewareRequest {
+            guest_64_bit: true,
+            ..create_emulator_middleware_request()
+        }
+        .execute(&engine_privileged_state)
+        .success
+    );
+
+    let memory_resolve_response = resolve_guest_pointer();
+
+    assert!(memory_resolve_response.success);
+    assert_eq!(memory_resolve_response.address, 0x8000_0044);
+}
+
+#[test]
+fn memory_middleware_removal_restores_host_address_space() {
+    let (mock_engine_os, engine_privileged_state) = create_emulator_test_state();
+
+    assert!(
+        create_emulator_middleware_request()
+            .execute(&engine_privileged_state)
+            .success
+    );
+    assert!(
+        PluginsRemoveMiddlewareRequest {
+            middleware_name: "test emulator".to_string(),
+        }
+        .execute(&engine_privileged_state)
+        .success
+    );
+
+    let memory_write_response = MemoryWriteRequest {
+        address: 0x20,
+        module_name: "emulator.exe".to_string(),
+        address_expression: String::new(),
+        value: vec![1],
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(memory_write_response.success);
+    assert!(
+        PluginsListMiddlewareRequest { refresh: true }
+            .execute(&engine_privileged_state)
+            .middleware_names
+            .is_empty()
+    );
+
+    let mock_os_state = mock_engine_os.get_state();
+    let state_guard = mock_os_state
+        .lock()
+        .expect("mock state lock should be available");
+
+    assert_eq!(state_guard.memory_write_requests[0].0, 0x1020);
+}
+
+#[test]
+fn memory_middleware_add_rejects_malformed_signature() {
+    let (_mock_engine_os, engine_privileged_state) = create_emulator_test_state();
+    let plugins_add_middleware_response = PluginsAddMiddlewareRequest {
+        signature: "DE AD XYZ".to_string(),
+        ..create_emulator_middleware_request()
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(!plugins_add_middleware_response.success);
+    assert!(
+        engine_privileged_state
+            .get_memory_middleware_registry()
+            .get_middleware_names()
+            .is_empty()
+    );
+}
+
+#[test]
+fn privileged_command_parser_accepts_plugins_add_middleware_with_hex_and_negative_offset() {
+    let parsed_command = PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "plugins",
+        "add-middleware",
+        "--middleware-name",
+        "dolphin",
+        "--module-name",
+        "mem1",
+        "--signature",
+        "DE AD ?? EF",
+        "--signature-offset",
+        "-16",
+        "--guest-base-address",
+        "0x80000000",
+        "--region-size",
+        "0x1800000",
+        "--big-endian",
+    ])
+    .expect("command should parse successfully");
+
+    match parsed_command {
+        PrivilegedCommand::Plugins(PluginsCommand::AddMiddleware {
+            plugins_add_middleware_request,
+        }) => {
+            assert_eq!(plugins_add_middleware_request.signature_offset, -16);
+            assert_eq!(plugins_add_middleware_request.guest_base_address, 0x8000_0000);
+            assert_eq!(plugins_add_middleware_request.region_size, 0x180_0000);
+            assert!(plugins_add_middleware_request.big_endian);
+            assert!(!plugins_add_middleware_request.guest_64_bit);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
@@ -2,6 +2,8 @@ use squalr_engine_api::commands::memory::write::memory_write_response::MemoryWri
 use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use squalr_engine_api::commands::process::auto_attach::process_auto_attach_request::ProcessAutoAttachRequest;
+use squalr_engine_api::commands::process::auto_attach::process_auto_attach_response::ProcessAutoAttachResponse;
 use squalr_engine_api::commands::process::close::process_close_request::ProcessCloseRequest;
 use squalr_engine_api::commands::process::close::process_close_response::ProcessCloseResponse;
 use squalr_engine_api::commands::process::list::process_list_request::ProcessListRequest;
@@ -367,6 +369,107 @@ fn privileged_command_parser_accepts_process_close_subcommand() {
     }
 }
 
+#[test]
+fn process_auto_attach_request_dispatches_auto_attach_command_and_invokes_typed_callback() {
+    let bindings = MockEngineBindings::new(
+        ProcessAutoAttachResponse {
+            is_enabled: true,
+            opened_process_info: None,
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+
+    let process_auto_attach_request = ProcessAutoAttachRequest {
+        process_name: Some("game.exe".to_string()),
+        match_case: true,
+    };
+
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    process_auto_attach_request.send_unprivileged(&bindings, move |process_auto_attach_response| {
+        callback_invoked_clone.store(
+            process_auto_attach_response.is_enabled && process_auto_attach_response.opened_process_info.is_none(),
+            Ordering::SeqCst,
+        );
+    });
+
+    assert!(callback_invoked.load(Ordering::SeqCst));
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert_eq!(dispatched_commands_guard.len(), 1);
+
+    match &dispatched_commands_guard[0] {
+        PrivilegedCommand::Process(ProcessCommand::AutoAttach {
+            process_auto_attach_request: captured_process_auto_attach_request,
+        }) => {
+            assert_eq!(captured_process_auto_attach_request.process_name, Some("game.exe".to_string()));
+            assert!(captured_process_auto_attach_request.match_case);
+        }
+        dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_process_auto_attach_with_and_without_name() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "process",
+            "auto-attach",
+            "--process-name",
+            "game.exe",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    match parse_result
+        .expect("parser should not panic")
+        .expect("command should parse successfully")
+    {
+        PrivilegedCommand::Process(ProcessCommand::AutoAttach { process_auto_attach_request }) => {
+            assert_eq!(process_auto_attach_request.process_name, Some("game.exe".to_string()));
+            assert!(!process_auto_attach_request.match_case);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    let parse_result = std::panic::catch_unwind(|| PrivilegedCommand::from_iter_safe(["squalr-cli", "process", "auto-attach"]));
+
+    match parse_result
+        .expect("parser should not panic")
+        .expect("command should parse successfully")
+    {
+        PrivilegedCommand::Process(ProcessCommand::AutoAttach { process_auto_attach_request }) => {
+            assert!(process_auto_attach_request.process_name.is_none());
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_process_open_and_close_secondary() {
+    match PrivilegedCommand::from_iter_safe(["squalr-cli", "process", "open-secondary", "-n", "game"]).expect("command should parse successfully") {
+        PrivilegedCommand::Process(ProcessCommand::OpenSecondary {
+            process_open_secondary_request,
+        }) => {
+            assert_eq!(process_open_secondary_request.process_id, None);
+            assert_eq!(process_open_secondary_request.search_name.as_deref(), Some("game"));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    match PrivilegedCommand::from_iter_safe(["squalr-cli", "process", "close-secondary"]).expect("command should parse successfully") {
+        PrivilegedCommand::Process(ProcessCommand::CloseSecondary { .. }) => {}
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
 #[test]
 fn privileged_command_parser_rejects_process_open_with_invalid_process_id() {
     let parse_result = std::panic::catch_unwind(|| PrivilegedCommand::from_iter_safe(["squalr-cli", "process", "open", "--process-id", "not-a-number"]));
@@ -123,6 +123,7 @@ fn project_create_request_dispatches_unprivileged_command_and_invokes_typed_call
     let project_create_request = ProjectCreateRequest {
         project_directory_path: Some(PathBuf::from("C:\\Projects")),
         project_name: Some("ContractCreateProject".to_string()),
+        auto_attach_process_name: Some("game.exe".to_string()),
     };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
@@ -152,6 +153,7 @@ fn project_create_request_dispatches_unprivileged_command_and_invokes_typed_call
                 Some("C:\\Projects".to_string())
             );
             assert_eq!(captured_project_create_request.project_name, Some("ContractCreateProject".to_string()));
+            assert_eq!(captured_project_create_request.auto_attach_process_name, Some("game.exe".to_string()));
         }
         dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
     }
@@ -460,6 +462,39 @@ fn unprivileged_command_parser_accepts_project_rename_with_long_flags() {
     }
 }
 
+#[test]
+fn unprivileged_command_parser_accepts_project_add_struct_with_draft_definition() {
+    let parse_result = std::panic::catch_unwind(|| {
+        UnprivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "project",
+            "add-struct",
+            "--struct-name",
+            "Player",
+            "--symbolic-struct-definition",
+            "u64*(64);f32;string_utf8[8]",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        UnprivilegedCommand::Project(ProjectCommand::AddStruct { project_add_struct_request }) => {
+            assert_eq!(project_add_struct_request.struct_name, "Player".to_string());
+            assert_eq!(
+                project_add_struct_request
+                    .symbolic_struct_definition
+                    .to_string(),
+                "u64*(64);f32;string_utf8[8]".to_string()
+            );
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
 #[test]
 fn unprivileged_command_parser_accepts_project_open_with_long_flags() {
     let parse_result = std::panic::catch_unwind(|| {
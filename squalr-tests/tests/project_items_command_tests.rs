@@ -15,15 +15,26 @@ use squalr_engine_api::commands::project_items::project_items_command::ProjectIt
 use squalr_engine_api::commands::project_items::rename::project_items_rename_response::ProjectItemsRenameResponse;
 use squalr_engine_api::commands::project_items::reorder::project_items_reorder_request::ProjectItemsReorderRequest;
 use squalr_engine_api::commands::project_items::reorder::project_items_reorder_response::ProjectItemsReorderResponse;
+use squalr_engine_api::commands::project_items::set_hotkeys::project_items_set_hotkeys_response::ProjectItemsSetHotkeysResponse;
+use squalr_engine_api::commands::project_items::trigger_hotkey::project_items_trigger_hotkey_response::ProjectItemsTriggerHotkeyResponse;
 use squalr_engine_api::commands::unprivileged_command::UnprivilegedCommand;
 use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
 use squalr_engine_api::commands::unprivileged_command_response::TypedUnprivilegedCommandResponse;
+use squalr_engine_api::structures::code_patches::code_patch_replacement::CodePatchReplacement;
+use squalr_engine_api::structures::freezing::freeze_mode::FreezeMode;
+use squalr_engine_api::structures::hotkeys::hotkey_action::HotkeyAction;
+use squalr_engine_api::structures::hotkeys::key_chord::KeyChord;
 use squalr_engine_api::structures::projects::project::Project;
 use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use squalr_engine_session::engine_unprivileged_state::{EngineUnprivilegedState, EngineUnprivilegedStateOptions};
+use squalr_engine_session::tasks::project_hotkey_task::ProjectHotkeyTask;
+use squalr_tests::mocks::mock_hotkey_listener::MockHotkeyListener;
 use squalr_tests::shared_execution_context;
 use std::path::PathBuf;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
 use structopt::StructOpt;
 
 use squalr_tests::mocks::mock_engine_bindings::MockEngineBindings;
@@ -371,6 +382,12 @@ fn project_items_create_request_dispatches_unprivileged_command_and_invokes_type
         parent_directory_path: PathBuf::from("Addresses"),
         project_item_name: "New Folder".to_string(),
         project_item_type: "directory".to_string(),
+        address: None,
+        module_name: None,
+        address_expression: None,
+        data_type_id: None,
+        original_bytes: None,
+        replacement: None,
     };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
@@ -489,6 +506,235 @@ fn unprivileged_command_parser_accepts_project_items_rename_with_long_flags() {
     }
 }
 
+#[test]
+fn unprivileged_command_parser_accepts_project_items_set_freeze_policy_with_long_flags() {
+    match UnprivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "project-items",
+        "set-freeze-policy",
+        "--project-item-paths",
+        "Addresses/health.json",
+        "--freeze-mode",
+        "increment",
+        "--freeze-step",
+        "2",
+    ])
+    .expect("command should parse successfully")
+    {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::SetFreezePolicy {
+            project_items_set_freeze_policy_request,
+        }) => {
+            assert_eq!(
+                project_items_set_freeze_policy_request.project_item_paths,
+                vec!["Addresses/health.json".to_string()]
+            );
+            assert_eq!(
+                project_items_set_freeze_policy_request
+                    .freeze_policy
+                    .freeze_mode,
+                FreezeMode::Increment
+            );
+            assert_eq!(
+                project_items_set_freeze_policy_request
+                    .freeze_policy
+                    .freeze_step
+                    .as_deref(),
+                Some("2")
+            );
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn unprivileged_command_parser_accepts_project_items_set_hotkeys_with_long_flags() {
+    match UnprivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "project-items",
+        "set-hotkeys",
+        "--project-item-paths",
+        "Addresses/health.json",
+        "--hotkey-bindings",
+        "ctrl+f1=toggle",
+        "--hotkey-bindings",
+        "numpadadd=increment:10",
+    ])
+    .expect("command should parse successfully")
+    {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::SetHotkeys {
+            project_items_set_hotkeys_request,
+        }) => {
+            let hotkey_bindings = &project_items_set_hotkeys_request.hotkey_bindings;
+
+            assert_eq!(project_items_set_hotkeys_request.project_item_paths, vec!["Addresses/health.json".to_string()]);
+            assert_eq!(hotkey_bindings.len(), 2);
+            assert_eq!(hotkey_bindings[0].get_key_chord(), &KeyChord::new("f1", true, false, false, false));
+            assert_eq!(hotkey_bindings[0].get_hotkey_action(), HotkeyAction::ToggleActivation);
+            assert_eq!(hotkey_bindings[1].get_hotkey_action(), HotkeyAction::Increment);
+            assert_eq!(hotkey_bindings[1].get_value(), Some("10"));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn unprivileged_command_parser_rejects_project_items_set_hotkeys_when_value_is_missing() {
+    let parse_result = UnprivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "project-items",
+        "set-hotkeys",
+        "--project-item-paths",
+        "Addresses/health.json",
+        "--hotkey-bindings",
+        "f2=set",
+    ]);
+
+    assert!(parse_result.is_err());
+}
+
+#[test]
+fn unprivileged_command_parser_accepts_project_items_trigger_hotkey_with_long_flags() {
+    match UnprivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "project-items",
+        "trigger-hotkey",
+        "--key-chord",
+        "Shift+Ctrl+F5",
+    ])
+    .expect("command should parse successfully")
+    {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::TriggerHotkey {
+            project_items_trigger_hotkey_request,
+        }) => {
+            assert_eq!(project_items_trigger_hotkey_request.key_chord, KeyChord::new("f5", true, false, true, false));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn project_hotkey_task_triggers_hotkey_for_each_completed_key_chord() {
+    let bindings = MockEngineBindings::new(
+        MemoryWriteResponse { success: true }.to_engine_response(),
+        ProjectItemsTriggerHotkeyResponse {
+            success: true,
+            triggered_action_count: 1,
+            failed_action_count: 0,
+        }
+        .to_engine_response(),
+    );
+    let dispatched_unprivileged_commands = bindings.get_dispatched_unprivileged_commands();
+    let engine_unprivileged_state = EngineUnprivilegedState::new_with_options(
+        Arc::new(RwLock::new(bindings)),
+        EngineUnprivilegedStateOptions { enable_console_logging: false },
+    );
+    let mock_hotkey_listener = MockHotkeyListener::new();
+
+    mock_hotkey_listener.press_keys(&["ctrl", "f1"]);
+    mock_hotkey_listener.press_keys(&["shift"]);
+    mock_hotkey_listener.press_keys(&["numpadadd"]);
+
+    let task = ProjectHotkeyTask::start_task(
+        Arc::downgrade(&engine_unprivileged_state),
+        Box::new(mock_hotkey_listener),
+        Arc::new(AtomicBool::new(false)),
+    );
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    while dispatched_unprivileged_commands
+        .lock()
+        .expect("command capture lock should be available")
+        .len()
+        < 2
+        && Instant::now() < deadline
+    {
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    task.cancel();
+
+    let dispatched_key_chords: Vec<KeyChord> = dispatched_unprivileged_commands
+        .lock()
+        .expect("command capture lock should be available")
+        .iter()
+        .map(|dispatched_command| match dispatched_command {
+            UnprivilegedCommand::ProjectItems(ProjectItemsCommand::TriggerHotkey {
+                project_items_trigger_hotkey_request,
+            }) => project_items_trigger_hotkey_request.key_chord.clone(),
+            dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+        })
+        .collect();
+
+    assert_eq!(
+        dispatched_key_chords,
+        vec![
+            KeyChord::new("f1", true, false, false, false),
+            KeyChord::new("numpadadd", false, false, false, false)
+        ]
+    );
+}
+
+#[test]
+fn project_hotkey_task_ignores_key_chords_while_suspended() {
+    let bindings = MockEngineBindings::new(
+        MemoryWriteResponse { success: true }.to_engine_response(),
+        ProjectItemsTriggerHotkeyResponse {
+            success: true,
+            triggered_action_count: 1,
+            failed_action_count: 0,
+        }
+        .to_engine_response(),
+    );
+    let dispatched_unprivileged_commands = bindings.get_dispatched_unprivileged_commands();
+    let engine_unprivileged_state = EngineUnprivilegedState::new_with_options(
+        Arc::new(RwLock::new(bindings)),
+        EngineUnprivilegedStateOptions { enable_console_logging: false },
+    );
+    let mock_hotkey_listener = MockHotkeyListener::new();
+    let is_suspended = Arc::new(AtomicBool::new(true));
+
+    mock_hotkey_listener.press_keys(&["f1"]);
+
+    let task = ProjectHotkeyTask::start_task(
+        Arc::downgrade(&engine_unprivileged_state),
+        Box::new(mock_hotkey_listener.clone()),
+        is_suspended.clone(),
+    );
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    while mock_hotkey_listener.get_queued_key_event_count() > 0 && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    is_suspended.store(false, Ordering::Release);
+    mock_hotkey_listener.press_keys(&["f2"]);
+
+    while dispatched_unprivileged_commands
+        .lock()
+        .expect("command capture lock should be available")
+        .is_empty()
+        && Instant::now() < deadline
+    {
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    task.cancel();
+
+    let dispatched_key_chords: Vec<KeyChord> = dispatched_unprivileged_commands
+        .lock()
+        .expect("command capture lock should be available")
+        .iter()
+        .map(|dispatched_command| match dispatched_command {
+            UnprivilegedCommand::ProjectItems(ProjectItemsCommand::TriggerHotkey {
+                project_items_trigger_hotkey_request,
+            }) => project_items_trigger_hotkey_request.key_chord.clone(),
+            dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+        })
+        .collect();
+
+    assert_eq!(dispatched_key_chords, vec![KeyChord::new("f2", false, false, false, false)]);
+}
+
 #[test]
 fn unprivileged_command_parser_accepts_project_items_move_with_long_flags() {
     let parse_result = std::panic::catch_unwind(|| {
@@ -643,6 +889,15 @@ fn project_items_new_response_types_round_trip_through_engine_response() {
         success: true,
         reordered_project_item_count: 3,
     };
+    let set_hotkeys_response = ProjectItemsSetHotkeysResponse {
+        success: true,
+        updated_project_item_count: 2,
+    };
+    let trigger_hotkey_response = ProjectItemsTriggerHotkeyResponse {
+        success: false,
+        triggered_action_count: 1,
+        failed_action_count: 1,
+    };
 
     let delete_round_trip = ProjectItemsDeleteResponse::from_engine_response(delete_response.to_engine_response())
         .expect("delete response should deserialize from command response");
@@ -652,6 +907,10 @@ fn project_items_new_response_types_round_trip_through_engine_response() {
         ProjectItemsMoveResponse::from_engine_response(move_response.to_engine_response()).expect("move response should deserialize from command response");
     let reorder_round_trip = ProjectItemsReorderResponse::from_engine_response(reorder_response.to_engine_response())
         .expect("reorder response should deserialize from command response");
+    let set_hotkeys_round_trip = ProjectItemsSetHotkeysResponse::from_engine_response(set_hotkeys_response.to_engine_response())
+        .expect("set hotkeys response should deserialize from command response");
+    let trigger_hotkey_round_trip = ProjectItemsTriggerHotkeyResponse::from_engine_response(trigger_hotkey_response.to_engine_response())
+        .expect("trigger hotkey response should deserialize from command response");
 
     assert!(delete_round_trip.success);
     assert_eq!(delete_round_trip.deleted_project_item_count, 2);
@@ -661,4 +920,85 @@ fn project_items_new_response_types_round_trip_through_engine_response() {
     assert_eq!(move_round_trip.moved_project_item_count, 2);
     assert!(reorder_round_trip.success);
     assert_eq!(reorder_round_trip.reordered_project_item_count, 3);
+    assert!(set_hotkeys_round_trip.success);
+    assert_eq!(set_hotkeys_round_trip.updated_project_item_count, 2);
+    assert!(!trigger_hotkey_round_trip.success);
+    assert_eq!(trigger_hotkey_round_trip.triggered_action_count, 1);
+    assert_eq!(trigger_hotkey_round_trip.failed_action_count, 1);
+}
+
+#[test]
+fn unprivileged_command_parser_accepts_project_items_create_address_with_expression() {
+    let parse_result = std::panic::catch_unwind(|| {
+        UnprivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "project-items",
+            "create",
+            "-p",
+            "project_items",
+            "-n",
+            "health",
+            "-t",
+            "address",
+            "-e",
+            "[[game.exe+10]+8]+4",
+            "-d",
+            "u32",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::Create { project_items_create_request }) => {
+            assert_eq!(project_items_create_request.project_item_type, "address");
+            assert_eq!(project_items_create_request.address, None);
+            assert_eq!(project_items_create_request.address_expression, Some("[[game.exe+10]+8]+4".to_string()));
+            assert_eq!(project_items_create_request.data_type_id, Some("u32".to_string()));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn unprivileged_command_parser_accepts_project_items_create_code_patch() {
+    let parse_result = std::panic::catch_unwind(|| {
+        UnprivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "project-items",
+            "create",
+            "-p",
+            "project_items",
+            "-n",
+            "skip_damage",
+            "-t",
+            "code_patch",
+            "-e",
+            "game.exe+20",
+            "-o",
+            "0F 84 10 00 00 00",
+            "-r",
+            "asm:jmp 0x7036",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::Create { project_items_create_request }) => {
+            assert_eq!(project_items_create_request.project_item_type, "code_patch");
+            assert_eq!(project_items_create_request.original_bytes, Some("0F 84 10 00 00 00".to_string()));
+            assert_eq!(
+                project_items_create_request.replacement,
+                Some(CodePatchReplacement::Assembly("jmp 0x7036".to_string()))
+            );
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
 }
@@ -11,6 +11,8 @@ use squalr_engine_api::commands::scan::element_scan::element_scan_request::Eleme
 use squalr_engine_api::commands::scan::element_scan::element_scan_response::ElementScanResponse;
 use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
 use squalr_engine_api::commands::scan::new::scan_new_response::ScanNewResponse;
+use squalr_engine_api::commands::scan::refresh_regions::scan_refresh_regions_request::ScanRefreshRegionsRequest;
+use squalr_engine_api::commands::scan::refresh_regions::scan_refresh_regions_response::ScanRefreshRegionsResponse;
 use squalr_engine_api::commands::scan::reset::scan_reset_request::ScanResetRequest;
 use squalr_engine_api::commands::scan::reset::scan_reset_response::ScanResetResponse;
 use squalr_engine_api::commands::scan::scan_command::ScanCommand;
@@ -23,6 +25,9 @@ use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::Sca
 use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
 use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
 use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::{AnonymousScanConstraintExpression, ScanConstraintExpression};
+use squalr_engine_api::structures::scanning::correlation::scan_correlation_mode::ScanCorrelationMode;
+use squalr_engine_api::structures::scanning::differential::scan_differential_compare_type::ScanDifferentialCompareType;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -161,6 +166,45 @@ fn scan_reset_request_does_not_invoke_callback_when_response_variant_is_wrong()
     }
 }
 
+#[test]
+fn scan_refresh_regions_request_dispatches_refresh_regions_command_and_invokes_typed_callback() {
+    let bindings = MockEngineBindings::new(
+        ScanRefreshRegionsResponse {
+            success: true,
+            added_region_count: 2,
+            ..ScanRefreshRegionsResponse::default()
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+    let scan_refresh_regions_request = ScanRefreshRegionsRequest { seed_new_regions: true };
+
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    scan_refresh_regions_request.send_unprivileged(&bindings, move |scan_refresh_regions_response| {
+        callback_invoked_clone.store(
+            scan_refresh_regions_response.success && scan_refresh_regions_response.added_region_count == 2,
+            Ordering::SeqCst,
+        );
+    });
+
+    assert!(callback_invoked.load(Ordering::SeqCst));
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert_eq!(dispatched_commands_guard.len(), 1);
+
+    match &dispatched_commands_guard[0] {
+        PrivilegedCommand::Scan(ScanCommand::RefreshRegions { scan_refresh_regions_request }) => {
+            assert!(scan_refresh_regions_request.seed_new_regions);
+        }
+        dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+    }
+}
+
 #[test]
 fn scan_collect_values_request_dispatches_collect_values_command_and_invokes_typed_callback() {
     let bindings = MockEngineBindings::new(
@@ -232,8 +276,12 @@ fn element_scan_request_dispatches_element_scan_command_and_invokes_typed_callba
     let dispatched_commands = bindings.get_dispatched_commands();
     let element_scan_request = ElementScanRequest {
         scan_constraints: vec![
-            AnonymousScanConstraint::from_str(">=5;dec;").expect("scan constraint should parse"),
-            AnonymousScanConstraint::from_str("==").expect("scan constraint should parse"),
+            AnonymousScanConstraint::from_str(">=5;dec;")
+                .expect("scan constraint should parse")
+                .into(),
+            AnonymousScanConstraint::from_str("==")
+                .expect("scan constraint should parse")
+                .into(),
         ],
         data_type_refs: vec![DataTypeRef::new("i32"), DataTypeRef::new("f32")],
     };
@@ -272,7 +320,11 @@ fn element_scan_request_does_not_invoke_callback_when_response_variant_is_wrong(
     );
     let dispatched_commands = bindings.get_dispatched_commands();
     let element_scan_request = ElementScanRequest {
-        scan_constraints: vec![AnonymousScanConstraint::from_str("==").expect("scan constraint should parse")],
+        scan_constraints: vec![
+            AnonymousScanConstraint::from_str("==")
+                .expect("scan constraint should parse")
+                .into(),
+        ],
         data_type_refs: vec![DataTypeRef::new("i32")],
     };
     let callback_invoked = Arc::new(AtomicBool::new(false));
@@ -526,7 +578,9 @@ fn privileged_command_parser_accepts_element_scan_with_long_flags() {
             assert_eq!(element_scan_request.scan_constraints.len(), 2);
             assert_eq!(element_scan_request.data_type_refs.len(), 2);
 
-            let first_constraint = &element_scan_request.scan_constraints[0];
+            let first_constraint = element_scan_request.scan_constraints[0]
+                .get_first_constraint()
+                .expect("first constraint expression should contain a constraint");
             assert_eq!(
                 first_constraint.get_scan_compare_type(),
                 ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThanOrEqual)
@@ -539,7 +593,9 @@ fn privileged_command_parser_accepts_element_scan_with_long_flags() {
                 Some("5")
             );
 
-            let second_constraint = &element_scan_request.scan_constraints[1];
+            let second_constraint = element_scan_request.scan_constraints[1]
+                .get_first_constraint()
+                .expect("second constraint expression should contain a constraint");
             assert_eq!(
                 second_constraint.get_scan_compare_type(),
                 ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged)
@@ -553,6 +609,75 @@ fn privileged_command_parser_accepts_element_scan_with_long_flags() {
     }
 }
 
+#[test]
+fn privileged_command_parser_accepts_element_scan_with_boolean_constraint_expression() {
+    let parsed_command_result = PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "scan",
+        "element-scan",
+        "--scan-constraints",
+        "(> 100 && < 200) || == 0",
+        "--scan-constraints",
+        "!= previous && !in {1, 2, 4, 8}",
+        "--data-type-refs",
+        "u32",
+    ]);
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Scan(ScanCommand::ElementScan { element_scan_request }) => {
+            assert_eq!(element_scan_request.scan_constraints.len(), 2);
+
+            match &element_scan_request.scan_constraints[0] {
+                ScanConstraintExpression::Or(or_operands) => {
+                    assert_eq!(or_operands.len(), 2);
+                    assert!(matches!(&or_operands[0], ScanConstraintExpression::And(and_operands) if and_operands.len() == 2));
+
+                    let equal_constraint = or_operands[1]
+                        .get_first_constraint()
+                        .expect("equality operand should contain a constraint");
+                    assert_eq!(
+                        equal_constraint.get_scan_compare_type(),
+                        ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal)
+                    );
+                    assert_eq!(
+                        equal_constraint
+                            .get_anonymous_value_string()
+                            .as_ref()
+                            .map(|anonymous_value_string| anonymous_value_string.get_anonymous_value_string()),
+                        Some("0")
+                    );
+                }
+                scan_constraint_expression => panic!("unexpected constraint expression: {scan_constraint_expression:?}"),
+            }
+
+            match &element_scan_request.scan_constraints[1] {
+                ScanConstraintExpression::And(and_operands) => {
+                    assert_eq!(and_operands.len(), 2);
+                    assert!(matches!(
+                        &and_operands[0],
+                        ScanConstraintExpression::Constraint(anonymous_scan_constraint)
+                            if anonymous_scan_constraint.get_scan_compare_type() == ScanCompareType::Relative(ScanCompareTypeRelative::Changed)
+                    ));
+                    assert!(matches!(
+                        &and_operands[1],
+                        ScanConstraintExpression::Not(negated_expression)
+                            if matches!(negated_expression.as_ref(), ScanConstraintExpression::Or(set_operands) if set_operands.len() == 4)
+                    ));
+                }
+                scan_constraint_expression => panic!("unexpected constraint expression: {scan_constraint_expression:?}"),
+            }
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn scan_constraint_expression_parser_rejects_unbalanced_expressions() {
+    assert!(AnonymousScanConstraintExpression::from_str("(> 100 && < 200").is_err());
+    assert!(AnonymousScanConstraintExpression::from_str("> 100 ||").is_err());
+    assert!(AnonymousScanConstraintExpression::from_str("in [1, 2, 3]").is_err());
+}
+
 #[test]
 fn privileged_command_parser_accepts_scan_new_subcommand() {
     let parse_result = std::panic::catch_unwind(|| PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "new"]));
@@ -583,6 +708,139 @@ fn privileged_command_parser_accepts_scan_reset_subcommand() {
     }
 }
 
+#[test]
+fn privileged_command_parser_accepts_scan_refresh_regions_subcommand() {
+    let parse_result = std::panic::catch_unwind(|| PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "refresh-regions", "--seed-new-regions"]));
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Scan(ScanCommand::RefreshRegions { scan_refresh_regions_request }) => {
+            assert!(scan_refresh_regions_request.seed_new_regions);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_scan_class_instances_subcommand() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "scan",
+            "class-instances",
+            "--class-name",
+            "game::Player",
+            "-m",
+            "game.exe",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Scan(ScanCommand::ClassInstances { scan_class_instances_request }) => {
+            assert_eq!(scan_class_instances_request.class_name, "game::Player");
+            assert_eq!(scan_class_instances_request.module_name, "game.exe");
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_scan_save_and_load_session() {
+    match PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "save-session", "-f", "scan.session"]).expect("command should parse successfully") {
+        PrivilegedCommand::Scan(ScanCommand::SaveSession { scan_save_session_request }) => {
+            assert_eq!(scan_save_session_request.file_path, std::path::PathBuf::from("scan.session"));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    match PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "scan",
+        "load-session",
+        "--file-path",
+        "scan.session",
+    ])
+    .expect("command should parse successfully")
+    {
+        PrivilegedCommand::Scan(ScanCommand::LoadSession { scan_load_session_request }) => {
+            assert_eq!(scan_load_session_request.file_path, std::path::PathBuf::from("scan.session"));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_scan_correlate_sample_and_rank() {
+    match PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "scan",
+        "correlate-sample",
+        "-o",
+        "-45.5",
+        "--restart",
+    ])
+    .expect("command should parse successfully")
+    {
+        PrivilegedCommand::Scan(ScanCommand::CorrelateSample { scan_correlate_sample_request }) => {
+            assert_eq!(scan_correlate_sample_request.observation, -45.5);
+            assert!(scan_correlate_sample_request.restart);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    match PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "scan",
+        "correlate-rank",
+        "-m",
+        "proportional",
+        "-s",
+        "0.9",
+        "-l",
+        "5",
+    ])
+    .expect("command should parse successfully")
+    {
+        PrivilegedCommand::Scan(ScanCommand::CorrelateRank { scan_correlate_rank_request }) => {
+            assert_eq!(scan_correlate_rank_request.correlation_mode, ScanCorrelationMode::Proportional);
+            assert_eq!(scan_correlate_rank_request.minimum_score, 0.9);
+            assert_eq!(scan_correlate_rank_request.tolerance, 0.0);
+            assert_eq!(scan_correlate_rank_request.limit, 5);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    assert!(PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "correlate-rank", "-m", "strict"]).is_err());
+}
+
+#[test]
+fn privileged_command_parser_accepts_scan_differential_compare_types() {
+    match PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "differential"]).expect("command should parse successfully") {
+        PrivilegedCommand::Scan(ScanCommand::Differential { scan_differential_request }) => {
+            assert_eq!(scan_differential_request.compare_type, ScanDifferentialCompareType::Different);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    match PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "differential", "--compare-type", "match"]).expect("command should parse successfully") {
+        PrivilegedCommand::Scan(ScanCommand::Differential { scan_differential_request }) => {
+            assert_eq!(scan_differential_request.compare_type, ScanDifferentialCompareType::Equal);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    assert!(PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "differential", "-c", "bigger"]).is_err());
+}
+
 #[test]
 fn privileged_command_parser_accepts_scan_collect_values_subcommand() {
     let parse_result = std::panic::catch_unwind(|| PrivilegedCommand::from_iter_safe(["squalr-cli", "scan", "collect-values"]));
@@ -22,8 +22,16 @@ use squalr_engine_api::engine::engine_binding_error::EngineBindingError;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
 use squalr_engine_api::events::engine_event::EngineEvent;
 use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::freezing::freeze_mode::FreezeMode;
+use squalr_engine_api::structures::freezing::freeze_policy::FreezePolicy;
 use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use squalr_engine_api::structures::scan_results::scan_results_export_error::ScanResultsExportError;
+use squalr_engine_api::structures::scan_results::scan_results_export_format::ScanResultsExportFormat;
+use squalr_engine_api::structures::scan_results::scan_results_export_record::ScanResultsExportRecord;
+use squalr_engine_api::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
+use squalr_engine_api::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
 use squalr_engine_api::{commands::unprivileged_command::UnprivilegedCommand, commands::unprivileged_command_response::UnprivilegedCommandResponse};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
@@ -73,7 +81,10 @@ fn scan_results_list_request_dispatches_list_command_and_invokes_typed_callback(
     );
     let dispatched_commands = bindings.get_dispatched_commands();
 
-    let scan_results_list_request = ScanResultsListRequest { page_index: 4 };
+    let scan_results_list_request = ScanResultsListRequest {
+        page_index: 4,
+        query_options: ScanResultsQueryOptions::default(),
+    };
     let callback_page_index = Arc::new(RwLock::new(None::<u64>));
     let callback_page_index_clone = callback_page_index.clone();
 
@@ -109,7 +120,11 @@ fn scan_results_query_request_send_unprivileged_returns_false_when_dispatch_fail
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
 
-    let did_dispatch = ScanResultsQueryRequest { page_index: 0 }.send_unprivileged(&bindings, move |_scan_results_query_response| {
+    let did_dispatch = ScanResultsQueryRequest {
+        page_index: 0,
+        query_options: ScanResultsQueryOptions::default(),
+    }
+    .send_unprivileged(&bindings, move |_scan_results_query_response| {
         callback_invoked_clone.store(true, Ordering::SeqCst);
     });
 
@@ -125,7 +140,10 @@ fn scan_results_list_request_does_not_invoke_callback_when_response_variant_is_w
     );
     let dispatched_commands = bindings.get_dispatched_commands();
 
-    let scan_results_list_request = ScanResultsListRequest { page_index: 9 };
+    let scan_results_list_request = ScanResultsListRequest {
+        page_index: 9,
+        query_options: ScanResultsQueryOptions::default(),
+    };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
 
@@ -166,7 +184,10 @@ fn scan_results_query_request_dispatches_query_command_and_invokes_typed_callbac
     );
     let dispatched_commands = bindings.get_dispatched_commands();
 
-    let scan_results_query_request = ScanResultsQueryRequest { page_index: 3 };
+    let scan_results_query_request = ScanResultsQueryRequest {
+        page_index: 3,
+        query_options: ScanResultsQueryOptions::default(),
+    };
     let callback_page_size = Arc::new(RwLock::new(None::<u64>));
     let callback_page_size_clone = callback_page_size.clone();
 
@@ -204,7 +225,10 @@ fn scan_results_query_request_does_not_invoke_callback_when_response_variant_is_
     );
     let dispatched_commands = bindings.get_dispatched_commands();
 
-    let scan_results_query_request = ScanResultsQueryRequest { page_index: 7 };
+    let scan_results_query_request = ScanResultsQueryRequest {
+        page_index: 7,
+        query_options: ScanResultsQueryOptions::default(),
+    };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
 
@@ -243,6 +267,7 @@ fn scan_results_freeze_request_dispatches_freeze_command_and_invokes_typed_callb
     let scan_results_freeze_request = ScanResultsFreezeRequest {
         scan_result_refs: vec![ScanResultRef::new(8), ScanResultRef::new(13)],
         is_frozen: true,
+        freeze_policy: FreezePolicy::default(),
     };
     let callback_failed_ref_count = Arc::new(RwLock::new(None::<usize>));
     let callback_failed_ref_count_clone = callback_failed_ref_count.clone();
@@ -291,6 +316,7 @@ fn scan_results_freeze_request_does_not_invoke_callback_when_response_variant_is
     let scan_results_freeze_request = ScanResultsFreezeRequest {
         scan_result_refs: vec![ScanResultRef::new(77)],
         is_frozen: false,
+        freeze_policy: FreezePolicy::default(),
     };
     let callback_invoked = Arc::new(AtomicBool::new(false));
     let callback_invoked_clone = callback_invoked.clone();
@@ -607,6 +633,124 @@ fn privileged_command_parser_accepts_scan_results_query_with_long_flags() {
     }
 }
 
+#[test]
+fn privileged_command_parser_accepts_scan_results_list_with_query_options() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "results",
+            "list",
+            "--page-index",
+            "1",
+            "--sort-key",
+            "value",
+            "--sort-descending",
+            "--value-filter",
+            "> 1 && != previous",
+            "--module-filter",
+            "game.exe",
+            "--region-start-address",
+            "0x1000",
+            "--address-search",
+            "1a0",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Results(ScanResultsCommand::List { results_list_request }) => {
+            let query_options = results_list_request.query_options;
+
+            assert_eq!(results_list_request.page_index, 1);
+            assert_eq!(query_options.sort_key, ScanResultsSortKey::CurrentValue);
+            assert!(query_options.sort_descending);
+            assert!(query_options.value_filter.is_some());
+            assert_eq!(query_options.module_filter.as_deref(), Some("game.exe"));
+            assert_eq!(query_options.region_start_address, Some(0x1000));
+            assert_eq!(query_options.region_end_address, None);
+            assert_eq!(query_options.address_search.as_deref(), Some("1a0"));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn scan_results_query_options_default_to_unsorted_and_unfiltered() {
+    let query_options = ScanResultsQueryOptions::default();
+
+    assert!(query_options.is_unfiltered_and_unsorted());
+    assert!(
+        !ScanResultsQueryOptions {
+            sort_key: ScanResultsSortKey::Delta,
+            ..ScanResultsQueryOptions::default()
+        }
+        .is_unfiltered_and_unsorted()
+    );
+}
+
+#[test]
+fn privileged_command_parser_accepts_scan_results_export_and_import() {
+    match PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "results",
+        "export",
+        "--file-path",
+        "results.dat",
+        "--format",
+        "json",
+    ]) {
+        Ok(PrivilegedCommand::Results(ScanResultsCommand::Export { results_export_request })) => {
+            assert_eq!(results_export_request.file_path, PathBuf::from("results.dat"));
+            assert_eq!(results_export_request.format, Some(ScanResultsExportFormat::Json));
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    match PrivilegedCommand::from_iter_safe(["squalr-cli", "results", "import", "-f", "results.csv"]) {
+        Ok(PrivilegedCommand::Results(ScanResultsCommand::Import { results_import_request })) => {
+            assert_eq!(results_import_request.file_path, PathBuf::from("results.csv"));
+            assert_eq!(results_import_request.format, None);
+            assert_eq!(
+                ScanResultsExportFormat::from_file_path(&results_import_request.file_path),
+                Some(ScanResultsExportFormat::Csv)
+            );
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn scan_results_export_record_round_trips_quoted_csv_fields() {
+    let scan_results_export_record = ScanResultsExportRecord {
+        address: 0x1A0,
+        module: "game, \"retail\".exe".to_string(),
+        module_offset: 0x20,
+        data_type_id: "u8[]".to_string(),
+        current_value: Some("1, 2, 3".to_string()),
+        previous_value: None,
+    };
+    let csv_row = scan_results_export_record.to_csv_row();
+
+    assert_eq!(csv_row, "0x1A0,\"game, \"\"retail\"\".exe\",0x20,u8[],\"1, 2, 3\",");
+    assert_eq!(ScanResultsExportRecord::from_csv_row(&csv_row, 2), Ok(scan_results_export_record));
+    assert_eq!(
+        ScanResultsExportRecord::from_csv_row("416,,,u32", 3),
+        Ok(ScanResultsExportRecord {
+            address: 416,
+            data_type_id: "u32".to_string(),
+            ..ScanResultsExportRecord::default()
+        })
+    );
+    assert!(matches!(
+        ScanResultsExportRecord::from_csv_row("0x10,\"unterminated,0x0,u32", 4),
+        Err(ScanResultsExportError::MalformedCsvRow { line_number: 4, .. })
+    ));
+}
+
 #[test]
 fn privileged_command_parser_accepts_scan_results_refresh_with_long_flags() {
     let parse_result = std::panic::catch_unwind(|| {
@@ -736,6 +880,60 @@ fn privileged_command_parser_accepts_scan_results_freeze_with_long_flags() {
     }
 }
 
+#[test]
+fn privileged_command_parser_accepts_scan_results_freeze_with_freeze_policy() {
+    match PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "results",
+        "freeze",
+        "--scan-result-refs",
+        "3",
+        "--is-frozen",
+        "--freeze-mode",
+        "clamp",
+        "--freeze-minimum",
+        "-5",
+        "--freeze-maximum",
+        "100",
+    ])
+    .expect("command should parse successfully")
+    {
+        PrivilegedCommand::Results(ScanResultsCommand::Freeze { results_freeze_request }) => {
+            assert_eq!(results_freeze_request.freeze_policy.freeze_mode, FreezeMode::Clamp);
+            assert_eq!(results_freeze_request.freeze_policy.freeze_minimum.as_deref(), Some("-5"));
+            assert_eq!(results_freeze_request.freeze_policy.freeze_maximum.as_deref(), Some("100"));
+            assert!(results_freeze_request.freeze_policy.freeze_step.is_none());
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+
+    assert!(PrivilegedCommand::from_iter_safe(["squalr-cli", "results", "freeze", "--freeze-mode", "sometimes"]).is_err());
+}
+
+#[test]
+fn privileged_command_parser_accepts_scan_results_freeze_with_interval_and_low_latency() {
+    match PrivilegedCommand::from_iter_safe([
+        "squalr-cli",
+        "results",
+        "freeze",
+        "--scan-result-refs",
+        "3",
+        "--is-frozen",
+        "--freeze-interval-ms",
+        "16",
+        "--freeze-low-latency",
+    ])
+    .expect("command should parse successfully")
+    {
+        PrivilegedCommand::Results(ScanResultsCommand::Freeze { results_freeze_request }) => {
+            assert_eq!(results_freeze_request.freeze_policy.freeze_mode, FreezeMode::Constant);
+            assert_eq!(results_freeze_request.freeze_policy.freeze_interval_ms, Some(16));
+            assert!(results_freeze_request.freeze_policy.freeze_low_latency);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
 #[test]
 fn privileged_command_parser_rejects_scan_results_set_property_with_invalid_anonymous_value_string() {
     let parse_result = std::panic::catch_unwind(|| {
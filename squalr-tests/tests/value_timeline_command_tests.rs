@@ -0,0 +1,207 @@
+use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use squalr_engine::engine_mode::EngineMode;
+use squalr_engine::engine_privileged_state::create_engine_privileged_state_with_os_providers;
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::value_timeline::export::value_timeline_export_request::ValueTimelineExportRequest;
+use squalr_engine_api::commands::value_timeline::list::value_timeline_list_request::ValueTimelineListRequest;
+use squalr_engine_api::commands::value_timeline::query::value_timeline_query_request::ValueTimelineQueryRequest;
+use squalr_engine_api::commands::value_timeline::record::value_timeline_record_request::ValueTimelineRecordRequest;
+use squalr_engine_api::commands::value_timeline::remove::value_timeline_remove_request::ValueTimelineRemoveRequest;
+use squalr_engine_api::commands::value_timeline::value_timeline_command::ValueTimelineCommand;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::value_timelines::value_timeline::ValueTimeline;
+use squalr_engine_api::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use squalr_engine_api::structures::value_timelines::value_timeline_target::ValueTimelineTarget;
+use squalr_tests::mocks::mock_os::MockEngineOs;
+use std::thread;
+use std::time::{Duration, Instant};
+use structopt::StructOpt;
+
+#[test]
+fn privileged_command_parser_accepts_value_timeline_record_with_label_and_interval() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "vt",
+            "record",
+            "-a",
+            "0x1234",
+            "-m",
+            "game.exe",
+            "-d",
+            "u32",
+            "-l",
+            "health",
+            "-i",
+            "50",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::ValueTimeline(ValueTimelineCommand::Record { value_timeline_record_request }) => {
+            assert_eq!(value_timeline_record_request.address, 0x1234);
+            assert_eq!(value_timeline_record_request.module_name, "game.exe");
+            assert_eq!(value_timeline_record_request.data_type_ref, DataTypeRef::new("u32"));
+            assert_eq!(value_timeline_record_request.label, "health");
+            assert_eq!(value_timeline_record_request.interval_ms, 50);
+            assert_eq!(value_timeline_record_request.capacity, 10000);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_accepts_value_timeline_export_with_multiple_timelines() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "timeline",
+            "export",
+            "-f",
+            "timeline.csv",
+            "-t",
+            "1",
+            "3",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::ValueTimeline(ValueTimelineCommand::Export { value_timeline_export_request }) => {
+            assert_eq!(value_timeline_export_request.file_path.to_string_lossy(), "timeline.csv");
+            assert_eq!(value_timeline_export_request.timeline_ids, vec![1, 3]);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn value_timeline_ring_buffer_discards_oldest_samples_and_queries_by_timestamp() {
+    let mut value_timeline = ValueTimeline::new(1, ValueTimelineTarget::new(0x10, String::new(), DataTypeRef::new("u32"), String::new()), 10, 3);
+
+    for timestamp_ms in 0..5 {
+        value_timeline.push_sample(ValueTimelineSample::new(timestamp_ms, Some(timestamp_ms.to_string())));
+    }
+
+    let timestamps: Vec<u64> = value_timeline
+        .get_samples_since(2)
+        .iter()
+        .map(|sample| sample.get_timestamp_ms())
+        .collect();
+
+    assert_eq!(value_timeline.get_info().sample_count, 3);
+    assert_eq!(timestamps, vec![3, 4]);
+}
+
+#[test]
+fn value_timeline_recorder_samples_changing_values_and_exports_csv() {
+    let mock_engine_os = MockEngineOs::new();
+    let engine_privileged_state = create_engine_privileged_state_with_os_providers(EngineMode::Standalone, mock_engine_os.create_providers())
+        .unwrap_or_else(|error| panic!("failed to create engine privileged state in test: {}", error));
+    mock_engine_os.set_memory_contents(vec![(0x60000, 7u32.to_le_bytes().to_vec())]);
+    mock_engine_os.set_memory_pages(vec![NormalizedRegion::new(0x60000, 0x4)]);
+    engine_privileged_state
+        .get_process_manager()
+        .set_opened_process(OpenedProcessInfo::new(
+            std::process::id(),
+            "test-process.exe".to_string(),
+            0xABC0,
+            Bitness::Bit64,
+            None,
+        ));
+
+    let value_timeline_record_response = ValueTimelineRecordRequest {
+        address: 0x60000,
+        module_name: String::new(),
+        data_type_ref: DataTypeRef::new("u32"),
+        label: "health".to_string(),
+        interval_ms: 5,
+        capacity: 1000,
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(value_timeline_record_response.success);
+
+    let timeline_id = value_timeline_record_response.timeline_id;
+    let wait_for_value = |expected_value: &str| {
+        let deadline = Instant::now() + Duration::from_secs(5);
+
+        loop {
+            let value_timeline_query_response = ValueTimelineQueryRequest {
+                timeline_id,
+                since_timestamp_ms: None,
+            }
+            .execute(&engine_privileged_state);
+
+            if value_timeline_query_response
+                .samples
+                .iter()
+                .any(|sample| sample.get_value() == Some(expected_value))
+            {
+                return value_timeline_query_response;
+            }
+
+            assert!(Instant::now() < deadline, "timed out waiting for a sample of {}", expected_value);
+            thread::sleep(Duration::from_millis(10));
+        }
+    };
+
+    wait_for_value("7");
+    mock_engine_os.set_memory_contents(vec![(0x60000, 42u32.to_le_bytes().to_vec())]);
+
+    let value_timeline_query_response = wait_for_value("42");
+    let value_timeline_info = value_timeline_query_response
+        .value_timeline_info
+        .expect("queried timeline should exist");
+
+    assert_eq!(value_timeline_info.target.get_display_name(), "health");
+    assert_eq!(
+        ValueTimelineListRequest {}
+            .execute(&engine_privileged_state)
+            .value_timelines
+            .len(),
+        1
+    );
+
+    let export_file_path = std::env::temp_dir().join(format!("squalr_value_timeline_{}.csv", std::process::id()));
+    let value_timeline_export_response = ValueTimelineExportRequest {
+        file_path: export_file_path.clone(),
+        timeline_ids: vec![timeline_id],
+    }
+    .execute(&engine_privileged_state);
+
+    assert!(value_timeline_export_response.success);
+    assert!(value_timeline_export_response.exported_sample_count >= 2);
+
+    let csv_contents = std::fs::read_to_string(&export_file_path).expect("exported CSV should be readable");
+    let mut csv_lines = csv_contents.lines();
+
+    assert_eq!(csv_lines.next(), Some(ValueTimeline::CSV_HEADER));
+    assert!(csv_lines.any(|csv_line| csv_line.ends_with(",health,,0x60000,u32,42")));
+
+    assert!(
+        ValueTimelineRemoveRequest { timeline_id }
+            .execute(&engine_privileged_state)
+            .success
+    );
+    assert!(
+        ValueTimelineListRequest {}
+            .execute(&engine_privileged_state)
+            .value_timelines
+            .is_empty()
+    );
+
+    let _ = std::fs::remove_file(&export_file_path);
+}
@@ -0,0 +1,272 @@
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use squalr_engine_api::commands::project::list::project_list_response::ProjectListResponse;
+use squalr_engine_api::commands::unprivileged_command_response::TypedUnprivilegedCommandResponse;
+use squalr_engine_api::commands::watchpoint::attach::watchpoint_attach_request::WatchpointAttachRequest;
+use squalr_engine_api::commands::watchpoint::attach::watchpoint_attach_response::WatchpointAttachResponse;
+use squalr_engine_api::commands::watchpoint::hits::watchpoint_hits_request::WatchpointHitsRequest;
+use squalr_engine_api::commands::watchpoint::hits::watchpoint_hits_response::WatchpointHitsResponse;
+use squalr_engine_api::commands::watchpoint::set::watchpoint_set_request::WatchpointSetRequest;
+use squalr_engine_api::commands::watchpoint::set::watchpoint_set_response::WatchpointSetResponse;
+use squalr_engine_api::commands::watchpoint::watchpoint_command::WatchpointCommand;
+use squalr_engine_api::structures::watchpoints::register_snapshot::RegisterSnapshot;
+use squalr_engine_api::structures::watchpoints::watchpoint_hit::WatchpointHit;
+use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use structopt::StructOpt;
+
+use squalr_tests::mocks::mock_engine_bindings::MockEngineBindings;
+
+#[test]
+fn watchpoint_set_request_dispatches_set_command_and_invokes_typed_callback() {
+    let bindings = MockEngineBindings::new(
+        WatchpointSetResponse { watchpoint_id: Some(2) }.to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let dispatched_commands = bindings.get_dispatched_commands();
+    let watchpoint_set_request = WatchpointSetRequest {
+        address: 0x7FFF_0000,
+        size: 4,
+        watchpoint_kind: WatchpointKind::ReadWrite,
+    };
+    let captured_watchpoint_id = Arc::new(Mutex::new(None));
+    let captured_watchpoint_id_clone = captured_watchpoint_id.clone();
+
+    watchpoint_set_request.send_unprivileged(&bindings, move |watchpoint_set_response| {
+        if let Ok(mut captured_watchpoint_id) = captured_watchpoint_id_clone.lock() {
+            *captured_watchpoint_id = watchpoint_set_response.watchpoint_id;
+        }
+    });
+
+    assert_eq!(
+        *captured_watchpoint_id
+            .lock()
+            .expect("capture lock should be available"),
+        Some(2)
+    );
+
+    let dispatched_commands_guard = dispatched_commands
+        .lock()
+        .expect("command capture lock should be available");
+    assert_eq!(dispatched_commands_guard.len(), 1);
+
+    match &dispatched_commands_guard[0] {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Set { watchpoint_set_request }) => {
+            assert_eq!(watchpoint_set_request.address, 0x7FFF_0000);
+            assert_eq!(watchpoint_set_request.watchpoint_kind, WatchpointKind::ReadWrite);
+        }
+        dispatched_command => panic!("unexpected dispatched command: {dispatched_command:?}"),
+    }
+}
+
+#[test]
+fn watchpoint_hits_request_invokes_typed_callback_with_hits() {
+    let watchpoint_hit = WatchpointHit::new(0, 1234, 0x401000, RegisterSnapshot::new(vec![("rip".to_string(), 0x401000)]));
+    let bindings = MockEngineBindings::new(
+        WatchpointHitsResponse {
+            watchpoint_hits: vec![watchpoint_hit.clone()],
+        }
+        .to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let captured_watchpoint_hits = Arc::new(Mutex::new(vec![]));
+    let captured_watchpoint_hits_clone = captured_watchpoint_hits.clone();
+
+    WatchpointHitsRequest { clear: true }.send_unprivileged(&bindings, move |watchpoint_hits_response| {
+        if let Ok(mut captured_watchpoint_hits) = captured_watchpoint_hits_clone.lock() {
+            *captured_watchpoint_hits = watchpoint_hits_response.watchpoint_hits;
+        }
+    });
+
+    assert_eq!(
+        *captured_watchpoint_hits
+            .lock()
+            .expect("capture lock should be available"),
+        vec![watchpoint_hit]
+    );
+}
+
+#[test]
+fn watchpoint_attach_request_does_not_invoke_callback_when_response_variant_is_wrong() {
+    let bindings = MockEngineBindings::new(
+        WatchpointSetResponse::default().to_engine_response(),
+        ProjectListResponse::default().to_engine_response(),
+    );
+    let callback_invoked = Arc::new(AtomicBool::new(false));
+    let callback_invoked_clone = callback_invoked.clone();
+
+    WatchpointAttachRequest {}.send_unprivileged(&bindings, move |_watchpoint_attach_response: WatchpointAttachResponse| {
+        callback_invoked_clone.store(true, Ordering::SeqCst);
+    });
+
+    assert!(!callback_invoked.load(Ordering::SeqCst));
+}
+
+#[test]
+fn privileged_command_parser_accepts_watchpoint_set_with_hex_address_size_and_kind() {
+    let parse_result = std::panic::catch_unwind(|| {
+        PrivilegedCommand::from_iter_safe([
+            "squalr-cli",
+            "watchpoint",
+            "set",
+            "--address",
+            "0x1000",
+            "--size",
+            "8",
+            "--watchpoint-kind",
+            "rw",
+        ])
+    });
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Set { watchpoint_set_request }) => {
+            assert_eq!(watchpoint_set_request.address, 0x1000);
+            assert_eq!(watchpoint_set_request.size, 8);
+            assert_eq!(watchpoint_set_request.watchpoint_kind, WatchpointKind::ReadWrite);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[test]
+fn privileged_command_parser_defaults_watchpoint_set_to_four_byte_write() {
+    let parse_result = std::panic::catch_unwind(|| PrivilegedCommand::from_iter_safe(["squalr-cli", "wp", "set", "-a", "4096"]));
+
+    assert!(parse_result.is_ok());
+
+    let parsed_command_result = parse_result.expect("parser should not panic");
+    assert!(parsed_command_result.is_ok());
+
+    match parsed_command_result.expect("command should parse successfully") {
+        PrivilegedCommand::Watchpoint(WatchpointCommand::Set { watchpoint_set_request }) => {
+            assert_eq!(watchpoint_set_request.address, 4096);
+            assert_eq!(watchpoint_set_request.size, 4);
+            assert_eq!(watchpoint_set_request.watchpoint_kind, WatchpointKind::Write);
+        }
+        parsed_command => panic!("unexpected parsed command: {parsed_command:?}"),
+    }
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod linux_watchpoint_tests {
+    use squalr_engine::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+    use squalr_engine::engine_mode::EngineMode;
+    use squalr_engine::engine_privileged_state::create_engine_privileged_state_with_os_providers;
+    use squalr_engine_api::commands::watchpoint::attach::watchpoint_attach_request::WatchpointAttachRequest;
+    use squalr_engine_api::commands::watchpoint::detach::watchpoint_detach_request::WatchpointDetachRequest;
+    use squalr_engine_api::commands::watchpoint::hits::watchpoint_hits_request::WatchpointHitsRequest;
+    use squalr_engine_api::commands::watchpoint::remove::watchpoint_remove_request::WatchpointRemoveRequest;
+    use squalr_engine_api::commands::watchpoint::set::watchpoint_set_request::WatchpointSetRequest;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+    use squalr_engine_api::structures::watchpoints::watchpoint_kind::WatchpointKind;
+    use squalr_engine_session::os::engine_os_provider::EngineOsProviders;
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    /// Kills the helper process even if an assertion fails, such that no traced process outlives the test.
+    struct ChildProcessGuard(Child);
+
+    impl Drop for ChildProcessGuard {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+            let _ = self.0.wait();
+        }
+    }
+
+    #[test]
+    fn watchpoint_executors_collect_hits_from_helper_process_and_detach_cleanly() {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_watchpoint_target"))
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("helper process should launch");
+        let child_stdout = child.stdout.take().expect("helper stdout should be piped");
+        let mut child = ChildProcessGuard(child);
+        let mut address_line = String::new();
+
+        BufReader::new(child_stdout)
+            .read_line(&mut address_line)
+            .expect("helper should print the watched address");
+
+        let watched_address = u64::from_str_radix(address_line.trim(), 16).expect("watched address should be hexadecimal");
+        let engine_privileged_state = create_engine_privileged_state_with_os_providers(EngineMode::Standalone, EngineOsProviders::default())
+            .expect("engine privileged state should be created");
+
+        engine_privileged_state
+            .get_process_manager()
+            .set_opened_process(OpenedProcessInfo::new(child.0.id(), "watchpoint_target".to_string(), 0, Bitness::Bit64, None));
+
+        assert!(
+            WatchpointAttachRequest {}
+                .execute(&engine_privileged_state)
+                .success
+        );
+
+        let watchpoint_set_response = WatchpointSetRequest {
+            address: watched_address,
+            size: 8,
+            watchpoint_kind: WatchpointKind::Write,
+        }
+        .execute(&engine_privileged_state);
+
+        assert_eq!(watchpoint_set_response.watchpoint_id, Some(0));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut watchpoint_hits = vec![];
+
+        while watchpoint_hits.is_empty() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(20));
+            watchpoint_hits = WatchpointHitsRequest { clear: false }
+                .execute(&engine_privileged_state)
+                .watchpoint_hits;
+        }
+
+        assert!(!watchpoint_hits.is_empty(), "helper writes should trigger the watchpoint");
+
+        for watchpoint_hit in &watchpoint_hits {
+            assert_eq!(watchpoint_hit.get_watchpoint_id(), 0);
+            assert!(watchpoint_hit.get_hit_count() >= 1);
+            assert_eq!(
+                watchpoint_hit.get_registers().get_register("rip"),
+                Some(watchpoint_hit.get_instruction_pointer())
+            );
+        }
+
+        assert!(
+            WatchpointRemoveRequest { watchpoint_id: 0 }
+                .execute(&engine_privileged_state)
+                .success
+        );
+        assert!(
+            !WatchpointRemoveRequest { watchpoint_id: 0 }
+                .execute(&engine_privileged_state)
+                .success
+        );
+        assert!(
+            WatchpointDetachRequest {}
+                .execute(&engine_privileged_state)
+                .success
+        );
+        assert!(
+            !WatchpointDetachRequest {}
+                .execute(&engine_privileged_state)
+                .success
+        );
+
+        // The helper must keep running normally once the debugger is gone.
+        thread::sleep(Duration::from_millis(50));
+
+        let child_status = child.0.try_wait();
+
+        assert!(matches!(child_status, Ok(None)), "helper should still be running: {:?}", child_status);
+    }
+}
@@ -17,6 +17,9 @@ impl AppShell {
             TuiWorkspacePage::ProjectWorkspace => self.draw_project_workspace_layout(frame, body_area),
             TuiWorkspacePage::ScannerWorkspace => self.draw_scanner_workspace_layout(frame, body_area),
             TuiWorkspacePage::SettingsWorkspace => self.draw_settings_workspace_layout(frame, body_area),
+            TuiWorkspacePage::MemoryWorkspace => self.draw_memory_workspace_layout(frame, body_area),
+            TuiWorkspacePage::DisassemblyWorkspace => self.draw_disassembly_workspace_layout(frame, body_area),
+            TuiWorkspacePage::ValueTimelineWorkspace => self.draw_value_timeline_workspace_layout(frame, body_area),
         }
     }
 
@@ -75,6 +78,48 @@ impl AppShell {
         self.draw_single_pane(frame, rows[1], TuiPane::Output);
     }
 
+    fn draw_memory_workspace_layout(
+        &self,
+        frame: &mut ratatui::Frame<'_>,
+        body_area: Rect,
+    ) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+            .split(body_area);
+
+        self.draw_single_pane(frame, rows[0], TuiPane::MemoryViewer);
+        self.draw_single_pane(frame, rows[1], TuiPane::Output);
+    }
+
+    fn draw_disassembly_workspace_layout(
+        &self,
+        frame: &mut ratatui::Frame<'_>,
+        body_area: Rect,
+    ) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+            .split(body_area);
+
+        self.draw_single_pane(frame, rows[0], TuiPane::Disassembly);
+        self.draw_single_pane(frame, rows[1], TuiPane::Output);
+    }
+
+    fn draw_value_timeline_workspace_layout(
+        &self,
+        frame: &mut ratatui::Frame<'_>,
+        body_area: Rect,
+    ) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+            .split(body_area);
+
+        self.draw_single_pane(frame, rows[0], TuiPane::ValueTimeline);
+        self.draw_single_pane(frame, rows[1], TuiPane::Output);
+    }
+
     fn draw_single_pane(
         &self,
         frame: &mut ratatui::Frame<'_>,
@@ -291,7 +336,10 @@ impl AppShell {
     }
 
     fn is_entry_heavy_pane(pane: TuiPane) -> bool {
-        matches!(pane, TuiPane::ProcessSelector | TuiPane::ScanResults | TuiPane::ProjectExplorer)
+        matches!(
+            pane,
+            TuiPane::ProcessSelector | TuiPane::ScanResults | TuiPane::ProjectExplorer | TuiPane::MemoryViewer | TuiPane::Disassembly | TuiPane::ValueTimeline
+        )
     }
 
     fn minimum_entry_row_count_for_pane(pane: TuiPane) -> usize {
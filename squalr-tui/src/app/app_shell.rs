@@ -2,7 +2,7 @@ use crate::state::TuiAppState;
 use crate::state::workspace_page::TuiWorkspacePage;
 use crate::theme::TuiTheme;
 use anyhow::{Context, Result, bail};
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, DisableFocusChange, EnableFocusChange, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode};
 use crossterm::{cursor, execute};
 use ratatui::Terminal;
@@ -34,7 +34,7 @@ impl TerminalGuard {
         let mut stdout = io::stdout();
 
         enable_raw_mode().context("Failed to enable terminal raw mode.")?;
-        execute!(stdout, EnterAlternateScreen, cursor::Hide).context("Failed to switch to alternate screen.")?;
+        execute!(stdout, EnterAlternateScreen, EnableFocusChange, cursor::Hide).context("Failed to switch to alternate screen.")?;
 
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend).context("Failed to initialize terminal backend.")?;
@@ -46,7 +46,7 @@ impl TerminalGuard {
 impl Drop for TerminalGuard {
     fn drop(&mut self) {
         let _ = disable_raw_mode();
-        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen, cursor::Show);
+        let _ = execute!(self.terminal.backend_mut(), DisableFocusChange, LeaveAlternateScreen, cursor::Show);
         let _ = self.terminal.show_cursor();
     }
 }
@@ -68,6 +68,8 @@ pub struct AppShell {
     pub last_project_list_auto_refresh_attempt_time: Option<Instant>,
     pub last_project_items_auto_refresh_attempt_time: Option<Instant>,
     pub last_settings_auto_refresh_attempt_time: Option<Instant>,
+    pub last_memory_viewer_refresh_time: Option<Instant>,
+    pub last_value_timeline_refresh_time: Option<Instant>,
     pub has_auto_seeked_project_explorer_once: bool,
 }
 
@@ -78,6 +80,8 @@ impl AppShell {
     pub(super) const MAX_PROJECT_ITEMS_REFRESH_INTERVAL_MS: u64 = 5_000;
     pub(super) const MIN_PROCESS_AND_PROJECT_AUTO_REFRESH_INTERVAL_MS: u64 = 1_000;
     pub(super) const MIN_SETTINGS_AUTO_REFRESH_INTERVAL_MS: u64 = 1_000;
+    pub(super) const MEMORY_VIEWER_REFRESH_INTERVAL_MS: u64 = 250;
+    pub(super) const VALUE_TIMELINE_REFRESH_INTERVAL_MS: u64 = 250;
 
     pub fn new(tick_rate: Duration) -> Self {
         Self {
@@ -97,6 +101,8 @@ impl AppShell {
             last_project_list_auto_refresh_attempt_time: None,
             last_project_items_auto_refresh_attempt_time: None,
             last_settings_auto_refresh_attempt_time: None,
+            last_memory_viewer_refresh_time: None,
+            last_value_timeline_refresh_time: None,
             has_auto_seeked_project_explorer_once: false,
         }
     }
@@ -163,7 +169,7 @@ impl AppShell {
     }
 
     fn footer_navigation_controls_line() -> &'static str {
-        "[NAV] F1 Project | F2 Scanner | F3 Settings | F4 Process | Tab/Shift+Tab focus | Ctrl+Q/C exit."
+        "[NAV] F1 Project | F2 Scanner | F3 Settings | F4 Process | F5 Memory | F6 Disassembly | F7 Timeline | Tab/Shift+Tab focus | Ctrl+Q/C exit."
     }
 
     fn session_opened_process_metadata_line(&self) -> String {
@@ -250,14 +256,20 @@ impl AppShell {
         incoming_event: Event,
         squalr_engine: &mut SqualrEngine,
     ) {
-        if let Event::Key(key_event) = incoming_event {
-            if key_event.kind != KeyEventKind::Press {
-                return;
-            }
+        match incoming_event {
+            Event::Key(key_event) => {
+                if key_event.kind != KeyEventKind::Press {
+                    return;
+                }
 
-            if !self.handle_global_key_event(key_event) {
-                self.handle_focused_pane_event(key_event, squalr_engine);
+                if !self.handle_global_key_event(key_event) {
+                    self.handle_focused_pane_event(key_event, squalr_engine);
+                }
             }
+            // Project hotkeys only fire while another window, such as the game, has focus.
+            Event::FocusGained => squalr_engine.set_project_hotkeys_suspended(true),
+            Event::FocusLost => squalr_engine.set_project_hotkeys_suspended(false),
+            _ => {}
         }
     }
 
@@ -1,4 +1,5 @@
 use super::app_shell::AppShell;
+use crate::state::pane::TuiPane;
 use squalr_engine::squalr_engine::SqualrEngine;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
 use squalr_engine_api::events::process::changed::process_changed_event::ProcessChangedEvent;
@@ -41,6 +42,14 @@ impl AppShell {
         }
 
         self.refresh_settings_on_tick_if_eligible(squalr_engine);
+
+        if self.should_refresh_memory_viewer_on_tick(current_tick_time) {
+            self.refresh_memory_viewer_with_feedback(squalr_engine, false);
+        }
+
+        if self.should_refresh_value_timelines_on_tick(current_tick_time) {
+            self.refresh_value_timelines_with_feedback(squalr_engine, false);
+        }
     }
 
     pub(super) fn synchronize_active_project_from_engine_state(
@@ -240,6 +249,38 @@ impl AppShell {
         }
     }
 
+    pub(super) fn should_refresh_memory_viewer_on_tick(
+        &self,
+        current_tick_time: Instant,
+    ) -> bool {
+        if !self.app_state.is_pane_visible(TuiPane::MemoryViewer) || !self.app_state.memory_viewer_pane_state.has_target {
+            return false;
+        }
+
+        match self.last_memory_viewer_refresh_time {
+            Some(last_memory_viewer_refresh_time) => {
+                current_tick_time.duration_since(last_memory_viewer_refresh_time) >= Duration::from_millis(Self::MEMORY_VIEWER_REFRESH_INTERVAL_MS)
+            }
+            None => true,
+        }
+    }
+
+    pub(super) fn should_refresh_value_timelines_on_tick(
+        &self,
+        current_tick_time: Instant,
+    ) -> bool {
+        if !self.app_state.is_pane_visible(TuiPane::ValueTimeline) {
+            return false;
+        }
+
+        match self.last_value_timeline_refresh_time {
+            Some(last_value_timeline_refresh_time) => {
+                current_tick_time.duration_since(last_value_timeline_refresh_time) >= Duration::from_millis(Self::VALUE_TIMELINE_REFRESH_INTERVAL_MS)
+            }
+            None => true,
+        }
+    }
+
     pub(super) fn should_refresh_process_list_on_tick(
         &self,
         current_tick_time: Instant,
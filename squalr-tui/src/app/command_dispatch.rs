@@ -13,6 +13,7 @@ use squalr_engine_api::commands::settings::scan::list::scan_settings_list_reques
 use squalr_engine_api::commands::settings::scan::set::scan_settings_set_request::ScanSettingsSetRequest;
 use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
 use squalr_engine_api::engine::engine_execution_context::EngineExecutionContext;
+use squalr_engine_api::structures::freezing::freeze_policy::FreezePolicy;
 use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
 use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
@@ -386,6 +387,7 @@ impl AppShell {
             let scan_results_freeze_request = ScanResultsFreezeRequest {
                 scan_result_refs: selected_scan_result_refs,
                 is_frozen: target_frozen_state,
+                freeze_policy: FreezePolicy::default(),
             };
             let (response_sender, response_receiver) = mpsc::sync_channel(1);
             let request_dispatched = scan_results_freeze_request.send(engine_unprivileged_state, move |scan_results_freeze_response| {
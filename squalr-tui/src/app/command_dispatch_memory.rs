@@ -0,0 +1,317 @@
+use super::app_shell::AppShell;
+use crate::views::disassembly::pane_state::DisassemblyPaneState;
+use crate::views::memory_viewer::pane_state::MemoryViewerPaneState;
+use squalr_engine::squalr_engine::SqualrEngine;
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_request::MemoryDisassembleRequest;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_request::MemoryReadRangeRequest;
+use squalr_engine_api::commands::memory::resolve::memory_resolve_request::MemoryResolveRequest;
+use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::project_items::create::project_items_create_request::ProjectItemsCreateRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+impl AppShell {
+    /// Moves the memory viewer to an address expression. Static expressions stay module-relative, while pointer
+    /// expressions are resolved by the engine once.
+    pub(super) fn go_to_memory_viewer_address(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+        address_expression: AddressExpression,
+    ) {
+        if let Some((module_name, address)) = address_expression.to_module_offset() {
+            self.app_state
+                .memory_viewer_pane_state
+                .go_to_module_offset(module_name, address);
+            self.refresh_memory_viewer_with_feedback(squalr_engine, true);
+            return;
+        }
+
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                self.app_state.memory_viewer_pane_state.status_message = "No unprivileged engine state is available for address resolution.".to_string();
+                return;
+            }
+        };
+        let memory_resolve_request = MemoryResolveRequest {
+            address_expression: address_expression.to_string(),
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = memory_resolve_request.send(engine_unprivileged_state, move |memory_resolve_response| {
+            let _ = response_sender.send(memory_resolve_response);
+        });
+
+        if !request_dispatched {
+            self.app_state.memory_viewer_pane_state.status_message = "Failed to dispatch address resolve request.".to_string();
+            return;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(memory_resolve_response) if memory_resolve_response.success => {
+                self.app_state
+                    .memory_viewer_pane_state
+                    .go_to_module_offset(memory_resolve_response.module_name, memory_resolve_response.module_offset);
+                self.refresh_memory_viewer_with_feedback(squalr_engine, true);
+            }
+            Ok(_) => {
+                self.app_state.memory_viewer_pane_state.status_message = format!("Failed to resolve '{}'.", address_expression);
+            }
+            Err(receive_error) => {
+                self.app_state.memory_viewer_pane_state.status_message = format!("Timed out waiting for address resolve response: {}", receive_error);
+            }
+        }
+    }
+
+    pub(super) fn refresh_memory_viewer_with_feedback(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+        should_update_status_message: bool,
+    ) -> bool {
+        if !self.app_state.memory_viewer_pane_state.has_target || self.app_state.memory_viewer_pane_state.is_reading {
+            return false;
+        }
+
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                if should_update_status_message {
+                    self.app_state.memory_viewer_pane_state.status_message = "No unprivileged engine state is available for memory reads.".to_string();
+                }
+                return false;
+            }
+        };
+
+        self.app_state.memory_viewer_pane_state.is_reading = true;
+
+        let memory_read_range_request = MemoryReadRangeRequest {
+            address: self.app_state.memory_viewer_pane_state.base_address,
+            module_name: self.app_state.memory_viewer_pane_state.module_name.clone(),
+            address_expression: String::new(),
+            length: MemoryViewerPaneState::view_length() as u64,
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = memory_read_range_request.send(engine_unprivileged_state, move |memory_read_range_response| {
+            let _ = response_sender.send(memory_read_range_response);
+        });
+
+        if !request_dispatched {
+            self.app_state.memory_viewer_pane_state.is_reading = false;
+            if should_update_status_message {
+                self.app_state.memory_viewer_pane_state.status_message = "Failed to dispatch memory read request.".to_string();
+            }
+            return false;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(memory_read_range_response) => {
+                let was_readable = memory_read_range_response.success;
+                self.app_state
+                    .memory_viewer_pane_state
+                    .apply_read_range_response(memory_read_range_response);
+                if should_update_status_message {
+                    self.app_state.memory_viewer_pane_state.status_message = if was_readable {
+                        format!("Read {} bytes.", MemoryViewerPaneState::view_length())
+                    } else {
+                        "Memory at this address is not readable.".to_string()
+                    };
+                }
+                self.last_memory_viewer_refresh_time = Some(Instant::now());
+            }
+            Err(receive_error) => {
+                if should_update_status_message {
+                    self.app_state.memory_viewer_pane_state.status_message = format!("Timed out waiting for memory read response: {}", receive_error);
+                }
+            }
+        }
+
+        self.app_state.memory_viewer_pane_state.is_reading = false;
+        true
+    }
+
+    pub(super) fn commit_memory_viewer_edit(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        let Some(value) = self.app_state.memory_viewer_pane_state.pending_edit_bytes() else {
+            self.app_state.memory_viewer_pane_state.status_message = "Type an even number of hex digits to write.".to_string();
+            return;
+        };
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                self.app_state.memory_viewer_pane_state.status_message = "No unprivileged engine state is available for memory writes.".to_string();
+                return;
+            }
+        };
+        let memory_write_request = MemoryWriteRequest {
+            address: self
+                .app_state
+                .memory_viewer_pane_state
+                .address_at(self.app_state.memory_viewer_pane_state.cursor_offset),
+            module_name: self.app_state.memory_viewer_pane_state.module_name.clone(),
+            address_expression: String::new(),
+            value,
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = memory_write_request.send(engine_unprivileged_state, move |memory_write_response| {
+            let _ = response_sender.send(memory_write_response);
+        });
+
+        if !request_dispatched {
+            self.app_state.memory_viewer_pane_state.status_message = "Failed to dispatch memory write request.".to_string();
+            return;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(memory_write_response) => {
+                self.app_state.memory_viewer_pane_state.status_message = if memory_write_response.success {
+                    format!(
+                        "Wrote {} at {}.",
+                        self.app_state.memory_viewer_pane_state.pending_edit_text,
+                        self.app_state
+                            .memory_viewer_pane_state
+                            .format_address(self.app_state.memory_viewer_pane_state.cursor_offset)
+                    )
+                } else {
+                    "Memory write failed.".to_string()
+                };
+                self.app_state
+                    .memory_viewer_pane_state
+                    .pending_edit_text
+                    .clear();
+                self.refresh_memory_viewer_with_feedback(squalr_engine, false);
+            }
+            Err(receive_error) => {
+                self.app_state.memory_viewer_pane_state.status_message = format!("Timed out waiting for memory write response: {}", receive_error);
+            }
+        }
+    }
+
+    pub(super) fn add_memory_viewer_selection_to_project(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                self.app_state.memory_viewer_pane_state.status_message = "No unprivileged engine state is available for project item creation.".to_string();
+                return;
+            }
+        };
+        let memory_viewer_pane_state = &self.app_state.memory_viewer_pane_state;
+        let (selection_start, _selection_end) = memory_viewer_pane_state.selection_range();
+        let address = memory_viewer_pane_state.address_at(selection_start);
+        let project_item_name = if memory_viewer_pane_state.module_name.is_empty() {
+            format!("address_0x{:X}", address)
+        } else {
+            format!("{}_0x{:X}", memory_viewer_pane_state.module_name, address)
+        };
+        let project_items_create_request = ProjectItemsCreateRequest {
+            parent_directory_path: PathBuf::from(Project::PROJECT_DIR),
+            project_item_name: project_item_name.clone(),
+            project_item_type: ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID.to_string(),
+            address: Some(address),
+            module_name: Some(memory_viewer_pane_state.module_name.clone()),
+            address_expression: None,
+            data_type_id: Some(
+                memory_viewer_pane_state
+                    .selected_data_type_ref()
+                    .get_data_type_id()
+                    .to_string(),
+            ),
+            original_bytes: None,
+            replacement: None,
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        project_items_create_request.send(engine_unprivileged_state, move |project_items_create_response| {
+            let _ = response_sender.send(project_items_create_response);
+        });
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(project_items_create_response) => {
+                self.app_state.memory_viewer_pane_state.status_message = if project_items_create_response.success {
+                    format!("Added '{}' to the project.", project_item_name)
+                } else {
+                    "Failed to add the selection to the project. Is a project open?".to_string()
+                };
+            }
+            Err(receive_error) => {
+                self.app_state.memory_viewer_pane_state.status_message = format!("Timed out waiting for project item create response: {}", receive_error);
+            }
+        }
+    }
+
+    pub(super) fn refresh_disassembly_with_feedback(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+        should_update_status_message: bool,
+    ) -> bool {
+        if !self.app_state.disassembly_pane_state.has_target || self.app_state.disassembly_pane_state.is_disassembling {
+            return false;
+        }
+
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                if should_update_status_message {
+                    self.app_state.disassembly_pane_state.status_message = "No unprivileged engine state is available for disassembly.".to_string();
+                }
+                return false;
+            }
+        };
+
+        self.app_state.disassembly_pane_state.is_disassembling = true;
+
+        let memory_disassemble_request = MemoryDisassembleRequest {
+            address: 0,
+            module_name: String::new(),
+            address_expression: self.app_state.disassembly_pane_state.target_expression.clone(),
+            instruction_count: DisassemblyPaneState::INSTRUCTION_COUNT,
+            instructions_before: DisassemblyPaneState::INSTRUCTIONS_BEFORE,
+            instruction_set: None,
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = memory_disassemble_request.send(engine_unprivileged_state, move |memory_disassemble_response| {
+            let _ = response_sender.send(memory_disassemble_response);
+        });
+
+        if !request_dispatched {
+            self.app_state.disassembly_pane_state.is_disassembling = false;
+            if should_update_status_message {
+                self.app_state.disassembly_pane_state.status_message = "Failed to dispatch disassemble request.".to_string();
+            }
+            return false;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(memory_disassemble_response) => {
+                let was_successful = memory_disassemble_response.success;
+                let instruction_count = memory_disassemble_response.instructions.len();
+                self.app_state
+                    .disassembly_pane_state
+                    .apply_disassemble_response(memory_disassemble_response);
+                if should_update_status_message {
+                    self.app_state.disassembly_pane_state.status_message = if was_successful {
+                        format!("Decoded {} instructions.", instruction_count)
+                    } else {
+                        "Code at this address is not readable.".to_string()
+                    };
+                }
+            }
+            Err(receive_error) => {
+                if should_update_status_message {
+                    self.app_state.disassembly_pane_state.status_message = format!("Timed out waiting for disassemble response: {}", receive_error);
+                }
+            }
+        }
+
+        self.app_state.disassembly_pane_state.is_disassembling = false;
+        true
+    }
+}
@@ -218,6 +218,7 @@ impl AppShell {
         let project_create_request = ProjectCreateRequest {
             project_directory_path: None,
             project_name: Some(new_project_name.clone()),
+            auto_attach_process_name: None,
         };
         let (response_sender, response_receiver) = mpsc::sync_channel(1);
         project_create_request.send(engine_unprivileged_state, move |project_create_response| {
@@ -307,6 +308,12 @@ impl AppShell {
             parent_directory_path,
             project_item_name: project_item_name.clone(),
             project_item_type: "directory".to_string(),
+            address: None,
+            module_name: None,
+            address_expression: None,
+            data_type_id: None,
+            original_bytes: None,
+            replacement: None,
         };
         let (response_sender, response_receiver) = mpsc::sync_channel(1);
         project_items_create_request.send(engine_unprivileged_state, move |project_items_create_response| {
@@ -971,10 +978,12 @@ impl AppShell {
         let edited_data_value = edited_field.get_data_value()?;
         let address = ProjectItemTypeAddress::get_field_address(project_item);
         let module_name = ProjectItemTypeAddress::get_field_module(project_item);
+        let address_expression = ProjectItemTypeAddress::get_field_address_expression(project_item);
 
         Some(MemoryWriteRequest {
             address,
             module_name,
+            address_expression,
             value: edited_data_value.get_value_bytes().clone(),
         })
     }
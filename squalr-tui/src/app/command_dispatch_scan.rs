@@ -19,6 +19,7 @@ use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedComma
 use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::freezing::freeze_policy::FreezePolicy;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
@@ -297,7 +298,10 @@ impl AppShell {
 
         let page_index = self.app_state.scan_results_pane_state.current_page_index;
         self.sync_scan_results_type_filters_from_element_scanner();
-        let scan_results_query_request = ScanResultsQueryRequest { page_index };
+        let scan_results_query_request = ScanResultsQueryRequest {
+            page_index,
+            query_options: self.app_state.scan_results_pane_state.query_options.clone(),
+        };
         let (response_sender, response_receiver) = mpsc::sync_channel(1);
         let request_dispatched = scan_results_query_request.send(engine_unprivileged_state, move |scan_results_query_response| {
             let _ = response_sender.send(scan_results_query_response);
@@ -342,6 +346,37 @@ impl AppShell {
         }
     }
 
+    pub(super) fn cycle_scan_results_sort_key(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        self.app_state.scan_results_pane_state.cycle_sort_key();
+        self.query_scan_results_current_page(squalr_engine);
+    }
+
+    pub(super) fn toggle_scan_results_sort_descending(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        self.app_state.scan_results_pane_state.toggle_sort_descending();
+        self.query_scan_results_current_page(squalr_engine);
+    }
+
+    pub(super) fn toggle_scan_results_value_filter(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        if self
+            .app_state
+            .scan_results_pane_state
+            .toggle_value_filter_from_pending_edit()
+        {
+            self.query_scan_results_current_page(squalr_engine);
+        } else {
+            self.app_state.scan_results_pane_state.status_message = "Pending edit value is not a valid value filter.".to_string();
+        }
+    }
+
     pub(super) fn query_previous_scan_results_page(
         &mut self,
         squalr_engine: &mut SqualrEngine,
@@ -495,6 +530,7 @@ impl AppShell {
         let scan_results_freeze_request = ScanResultsFreezeRequest {
             scan_result_refs: selected_scan_result_refs,
             is_frozen: target_frozen_state,
+            freeze_policy: FreezePolicy::default(),
         };
         let (response_sender, response_receiver) = mpsc::sync_channel(1);
         let request_dispatched = scan_results_freeze_request.send(engine_unprivileged_state, move |scan_results_freeze_response| {
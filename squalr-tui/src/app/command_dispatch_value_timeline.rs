@@ -0,0 +1,239 @@
+use super::app_shell::AppShell;
+use squalr_engine::squalr_engine::SqualrEngine;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::value_timeline::export::value_timeline_export_request::ValueTimelineExportRequest;
+use squalr_engine_api::commands::value_timeline::list::value_timeline_list_request::ValueTimelineListRequest;
+use squalr_engine_api::commands::value_timeline::query::value_timeline_query_request::ValueTimelineQueryRequest;
+use squalr_engine_api::commands::value_timeline::record::value_timeline_record_request::ValueTimelineRecordRequest;
+use squalr_engine_api::commands::value_timeline::remove::value_timeline_remove_request::ValueTimelineRemoveRequest;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+impl AppShell {
+    /// Lists recorded timelines, then queries each timeline for samples newer than those already retained.
+    pub(super) fn refresh_value_timelines_with_feedback(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+        should_update_status_message: bool,
+    ) -> bool {
+        if self
+            .app_state
+            .value_timeline_pane_state
+            .is_refreshing_value_timelines
+        {
+            return false;
+        }
+
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                if should_update_status_message {
+                    self.app_state.value_timeline_pane_state.status_message = "No unprivileged engine state is available for value timelines.".to_string();
+                }
+                return false;
+            }
+        };
+
+        self.app_state
+            .value_timeline_pane_state
+            .is_refreshing_value_timelines = true;
+        self.last_value_timeline_refresh_time = Some(Instant::now());
+
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = ValueTimelineListRequest {}.send(engine_unprivileged_state, move |value_timeline_list_response| {
+            let _ = response_sender.send(value_timeline_list_response);
+        });
+
+        if !request_dispatched {
+            self.app_state
+                .value_timeline_pane_state
+                .is_refreshing_value_timelines = false;
+            if should_update_status_message {
+                self.app_state.value_timeline_pane_state.status_message = "Failed to dispatch value timeline list request.".to_string();
+            }
+            return false;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(value_timeline_list_response) => {
+                self.app_state
+                    .value_timeline_pane_state
+                    .apply_value_timelines(value_timeline_list_response.value_timelines);
+            }
+            Err(receive_error) => {
+                self.app_state
+                    .value_timeline_pane_state
+                    .is_refreshing_value_timelines = false;
+                if should_update_status_message {
+                    self.app_state.value_timeline_pane_state.status_message = format!("Timed out waiting for value timeline list response: {}", receive_error);
+                }
+                return false;
+            }
+        }
+
+        let timeline_ids = self
+            .app_state
+            .value_timeline_pane_state
+            .value_timelines
+            .iter()
+            .map(|value_timeline_info| value_timeline_info.timeline_id)
+            .collect::<Vec<_>>();
+
+        for timeline_id in timeline_ids {
+            let value_timeline_query_request = ValueTimelineQueryRequest {
+                timeline_id,
+                since_timestamp_ms: self
+                    .app_state
+                    .value_timeline_pane_state
+                    .latest_timestamp_ms(timeline_id),
+            };
+            let (response_sender, response_receiver) = mpsc::sync_channel(1);
+            let request_dispatched = value_timeline_query_request.send(engine_unprivileged_state, move |value_timeline_query_response| {
+                let _ = response_sender.send(value_timeline_query_response);
+            });
+
+            if !request_dispatched {
+                continue;
+            }
+
+            if let Ok(value_timeline_query_response) = response_receiver.recv_timeout(Duration::from_secs(3)) {
+                self.app_state
+                    .value_timeline_pane_state
+                    .append_samples(timeline_id, value_timeline_query_response.samples);
+            }
+        }
+
+        if should_update_status_message {
+            self.app_state.value_timeline_pane_state.status_message =
+                format!("Loaded {} value timelines.", self.app_state.value_timeline_pane_state.value_timelines.len());
+        }
+
+        self.app_state
+            .value_timeline_pane_state
+            .is_refreshing_value_timelines = false;
+        true
+    }
+
+    pub(super) fn record_value_timeline(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+        value_timeline_record_request: ValueTimelineRecordRequest,
+    ) {
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                self.app_state.value_timeline_pane_state.status_message = "No unprivileged engine state is available for value timelines.".to_string();
+                return;
+            }
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = value_timeline_record_request.send(engine_unprivileged_state, move |value_timeline_record_response| {
+            let _ = response_sender.send(value_timeline_record_response);
+        });
+
+        if !request_dispatched {
+            self.app_state.value_timeline_pane_state.status_message = "Failed to dispatch value timeline record request.".to_string();
+            return;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(value_timeline_record_response) if value_timeline_record_response.success => {
+                self.app_state.value_timeline_pane_state.status_message = format!("Recording timeline #{}.", value_timeline_record_response.timeline_id);
+                self.refresh_value_timelines_with_feedback(squalr_engine, false);
+            }
+            Ok(_) => {
+                self.app_state.value_timeline_pane_state.status_message = "Failed to record the address. Is the data type valid?".to_string();
+            }
+            Err(receive_error) => {
+                self.app_state.value_timeline_pane_state.status_message = format!("Timed out waiting for value timeline record response: {}", receive_error);
+            }
+        }
+    }
+
+    pub(super) fn remove_selected_value_timeline(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        let Some(timeline_id) = self
+            .app_state
+            .value_timeline_pane_state
+            .selected_value_timeline()
+            .map(|value_timeline_info| value_timeline_info.timeline_id)
+        else {
+            self.app_state.value_timeline_pane_state.status_message = "No value timeline is selected.".to_string();
+            return;
+        };
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                self.app_state.value_timeline_pane_state.status_message = "No unprivileged engine state is available for value timelines.".to_string();
+                return;
+            }
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = ValueTimelineRemoveRequest { timeline_id }.send(engine_unprivileged_state, move |value_timeline_remove_response| {
+            let _ = response_sender.send(value_timeline_remove_response);
+        });
+
+        if !request_dispatched {
+            self.app_state.value_timeline_pane_state.status_message = "Failed to dispatch value timeline remove request.".to_string();
+            return;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(3)) {
+            Ok(value_timeline_remove_response) => {
+                self.app_state.value_timeline_pane_state.status_message = if value_timeline_remove_response.success {
+                    format!("Removed timeline #{}.", timeline_id)
+                } else {
+                    format!("Timeline #{} no longer exists.", timeline_id)
+                };
+                self.refresh_value_timelines_with_feedback(squalr_engine, false);
+            }
+            Err(receive_error) => {
+                self.app_state.value_timeline_pane_state.status_message = format!("Timed out waiting for value timeline remove response: {}", receive_error);
+            }
+        }
+    }
+
+    /// Exports every recorded timeline to a CSV file.
+    pub(super) fn export_value_timelines(
+        &mut self,
+        squalr_engine: &mut SqualrEngine,
+        file_path: String,
+    ) {
+        let engine_unprivileged_state = match squalr_engine.get_engine_unprivileged_state().as_ref() {
+            Some(engine_unprivileged_state) => engine_unprivileged_state,
+            None => {
+                self.app_state.value_timeline_pane_state.status_message = "No unprivileged engine state is available for value timelines.".to_string();
+                return;
+            }
+        };
+        let value_timeline_export_request = ValueTimelineExportRequest {
+            file_path: PathBuf::from(&file_path),
+            timeline_ids: Vec::new(),
+        };
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        let request_dispatched = value_timeline_export_request.send(engine_unprivileged_state, move |value_timeline_export_response| {
+            let _ = response_sender.send(value_timeline_export_response);
+        });
+
+        if !request_dispatched {
+            self.app_state.value_timeline_pane_state.status_message = "Failed to dispatch value timeline export request.".to_string();
+            return;
+        }
+
+        match response_receiver.recv_timeout(Duration::from_secs(10)) {
+            Ok(value_timeline_export_response) if value_timeline_export_response.success => {
+                self.app_state.value_timeline_pane_state.status_message =
+                    format!("Exported {} samples to {}.", value_timeline_export_response.exported_sample_count, file_path);
+            }
+            Ok(_) => {
+                self.app_state.value_timeline_pane_state.status_message = format!("Failed to export value timelines to {}.", file_path);
+            }
+            Err(receive_error) => {
+                self.app_state.value_timeline_pane_state.status_message = format!("Timed out waiting for value timeline export response: {}", receive_error);
+            }
+        }
+    }
+}
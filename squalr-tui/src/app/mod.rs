@@ -2,8 +2,10 @@ mod app_render;
 mod app_shell;
 mod app_tick;
 mod command_dispatch;
+mod command_dispatch_memory;
 mod command_dispatch_project;
 mod command_dispatch_scan;
+mod command_dispatch_value_timeline;
 mod pane_key_handlers;
 
 pub use app_shell::{AppShell, TerminalGuard};
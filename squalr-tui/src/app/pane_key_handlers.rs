@@ -3,6 +3,7 @@ use crate::state::pane::TuiPane;
 use crate::views::element_scanner::pane_state::ElementScannerFocusTarget;
 use crate::views::process_selector::pane_state::ProcessSelectorInputMode;
 use crate::views::project_explorer::pane_state::{ProjectExplorerFocusTarget, ProjectSelectorInputMode};
+use crate::views::value_timeline::pane_state::ValueTimelineInputMode;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use squalr_engine::squalr_engine::SqualrEngine;
 
@@ -18,6 +19,9 @@ impl AppShell {
             TuiPane::ScanResults => self.handle_scan_results_key_event(key_event, squalr_engine),
             TuiPane::ProjectExplorer => self.handle_project_explorer_key_event(key_event, squalr_engine),
             TuiPane::StructViewer => self.handle_struct_viewer_key_event(key_event, squalr_engine),
+            TuiPane::MemoryViewer => self.handle_memory_viewer_key_event(key_event, squalr_engine),
+            TuiPane::Disassembly => self.handle_disassembly_key_event(key_event, squalr_engine),
+            TuiPane::ValueTimeline => self.handle_value_timeline_key_event(key_event, squalr_engine),
             TuiPane::Output => self.handle_output_key_event(key_event.code, squalr_engine),
             TuiPane::Settings => self.handle_settings_key_event(key_event, squalr_engine),
         }
@@ -323,6 +327,9 @@ impl AppShell {
             KeyCode::Char('R') => self.refresh_scan_results_page(squalr_engine),
             KeyCode::Char(']') => self.query_next_scan_results_page(squalr_engine),
             KeyCode::Char('[') => self.query_previous_scan_results_page(squalr_engine),
+            KeyCode::Char('s') => self.cycle_scan_results_sort_key(squalr_engine),
+            KeyCode::Char('S') => self.toggle_scan_results_sort_descending(squalr_engine),
+            KeyCode::Char('v') => self.toggle_scan_results_value_filter(squalr_engine),
             KeyCode::Down => {
                 if is_range_extend_modifier_active {
                     self.app_state
@@ -662,4 +669,199 @@ impl AppShell {
             _ => {}
         }
     }
+
+    pub(super) fn handle_memory_viewer_key_event(
+        &mut self,
+        key_event: KeyEvent,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        if self.app_state.memory_viewer_pane_state.is_editing_address {
+            match key_event.code {
+                KeyCode::Enter => {
+                    if let Some(address_expression) = self.app_state.memory_viewer_pane_state.commit_address_input() {
+                        self.go_to_memory_viewer_address(squalr_engine, address_expression);
+                    }
+                }
+                KeyCode::Esc => self.app_state.memory_viewer_pane_state.cancel_address_input(),
+                KeyCode::Backspace => self
+                    .app_state
+                    .memory_viewer_pane_state
+                    .backspace_address_input(),
+                KeyCode::Char(address_character) => self
+                    .app_state
+                    .memory_viewer_pane_state
+                    .append_address_input_character(address_character),
+                _ => {}
+            }
+            return;
+        }
+
+        let row_length = crate::views::memory_viewer::pane_state::MemoryViewerPaneState::BYTES_PER_ROW as i64;
+        let extend_selection = key_event.modifiers.contains(KeyModifiers::SHIFT);
+        match key_event.code {
+            KeyCode::Char('g') => self.app_state.memory_viewer_pane_state.begin_address_input(),
+            KeyCode::Char('r') => {
+                self.refresh_memory_viewer_with_feedback(squalr_engine, true);
+            }
+            KeyCode::Char('p') => self.add_memory_viewer_selection_to_project(squalr_engine),
+            KeyCode::Char('[') => self
+                .app_state
+                .memory_viewer_pane_state
+                .cycle_data_type_backward(),
+            KeyCode::Char(']') => self
+                .app_state
+                .memory_viewer_pane_state
+                .cycle_data_type_forward(),
+            KeyCode::Left => self
+                .app_state
+                .memory_viewer_pane_state
+                .move_cursor(-1, extend_selection),
+            KeyCode::Right => self
+                .app_state
+                .memory_viewer_pane_state
+                .move_cursor(1, extend_selection),
+            KeyCode::Up => self
+                .app_state
+                .memory_viewer_pane_state
+                .move_cursor(-row_length, extend_selection),
+            KeyCode::Down => self
+                .app_state
+                .memory_viewer_pane_state
+                .move_cursor(row_length, extend_selection),
+            KeyCode::PageUp => {
+                self.app_state.memory_viewer_pane_state.scroll_rows(-8);
+                self.refresh_memory_viewer_with_feedback(squalr_engine, false);
+            }
+            KeyCode::PageDown => {
+                self.app_state.memory_viewer_pane_state.scroll_rows(8);
+                self.refresh_memory_viewer_with_feedback(squalr_engine, false);
+            }
+            KeyCode::Enter => self.commit_memory_viewer_edit(squalr_engine),
+            KeyCode::Backspace => self.app_state.memory_viewer_pane_state.backspace_pending_edit(),
+            KeyCode::Esc => {
+                self.app_state
+                    .memory_viewer_pane_state
+                    .pending_edit_text
+                    .clear();
+                self.app_state.memory_viewer_pane_state.selection_anchor = None;
+            }
+            KeyCode::Char(edit_character) => self
+                .app_state
+                .memory_viewer_pane_state
+                .append_pending_edit_character(edit_character),
+            _ => {}
+        }
+    }
+
+    pub(super) fn handle_disassembly_key_event(
+        &mut self,
+        key_event: KeyEvent,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        if self.app_state.disassembly_pane_state.is_editing_address {
+            match key_event.code {
+                KeyCode::Enter => {
+                    if let Some(address_expression) = self.app_state.disassembly_pane_state.commit_address_input() {
+                        self.app_state
+                            .disassembly_pane_state
+                            .go_to(address_expression.to_string());
+                        self.refresh_disassembly_with_feedback(squalr_engine, true);
+                    }
+                }
+                KeyCode::Esc => self.app_state.disassembly_pane_state.cancel_address_input(),
+                KeyCode::Backspace => self.app_state.disassembly_pane_state.backspace_address_input(),
+                KeyCode::Char(address_character) => self
+                    .app_state
+                    .disassembly_pane_state
+                    .append_address_input_character(address_character),
+                _ => {}
+            }
+            return;
+        }
+
+        match key_event.code {
+            KeyCode::Char('g') => self.app_state.disassembly_pane_state.begin_address_input(),
+            KeyCode::Char('r') => {
+                self.refresh_disassembly_with_feedback(squalr_engine, true);
+            }
+            KeyCode::Up => self.app_state.disassembly_pane_state.move_selection(-1),
+            KeyCode::Down => self.app_state.disassembly_pane_state.move_selection(1),
+            KeyCode::PageUp => {
+                if self.app_state.disassembly_pane_state.page(false) {
+                    self.refresh_disassembly_with_feedback(squalr_engine, false);
+                }
+            }
+            KeyCode::PageDown => {
+                if self.app_state.disassembly_pane_state.page(true) {
+                    self.refresh_disassembly_with_feedback(squalr_engine, false);
+                }
+            }
+            KeyCode::Enter => {
+                if self.app_state.disassembly_pane_state.follow_selected_target() {
+                    self.refresh_disassembly_with_feedback(squalr_engine, true);
+                }
+            }
+            KeyCode::Backspace => {
+                let has_previous_target = self.app_state.disassembly_pane_state.go_back();
+
+                if has_previous_target {
+                    self.refresh_disassembly_with_feedback(squalr_engine, true);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn handle_value_timeline_key_event(
+        &mut self,
+        key_event: KeyEvent,
+        squalr_engine: &mut SqualrEngine,
+    ) {
+        match self.app_state.value_timeline_pane_state.input_mode {
+            ValueTimelineInputMode::None => {}
+            input_mode => {
+                match key_event.code {
+                    KeyCode::Enter => match input_mode {
+                        ValueTimelineInputMode::Record => {
+                            if let Some(value_timeline_record_request) = self.app_state.value_timeline_pane_state.commit_record_input() {
+                                self.record_value_timeline(squalr_engine, value_timeline_record_request);
+                            }
+                        }
+                        ValueTimelineInputMode::Export => {
+                            if let Some(file_path) = self.app_state.value_timeline_pane_state.commit_export_input() {
+                                self.export_value_timelines(squalr_engine, file_path);
+                            }
+                        }
+                        ValueTimelineInputMode::None => {}
+                    },
+                    KeyCode::Esc => self.app_state.value_timeline_pane_state.cancel_input(),
+                    KeyCode::Backspace => self.app_state.value_timeline_pane_state.backspace_input(),
+                    KeyCode::Char(input_character) => self
+                        .app_state
+                        .value_timeline_pane_state
+                        .append_input_character(input_character),
+                    _ => {}
+                }
+                return;
+            }
+        }
+
+        match key_event.code {
+            KeyCode::Char('a') => self
+                .app_state
+                .value_timeline_pane_state
+                .begin_input(ValueTimelineInputMode::Record),
+            KeyCode::Char('e') => self
+                .app_state
+                .value_timeline_pane_state
+                .begin_input(ValueTimelineInputMode::Export),
+            KeyCode::Char('x') | KeyCode::Delete => self.remove_selected_value_timeline(squalr_engine),
+            KeyCode::Char('r') => {
+                self.refresh_value_timelines_with_feedback(squalr_engine, true);
+            }
+            KeyCode::Up => self.app_state.value_timeline_pane_state.move_selection(-1),
+            KeyCode::Down => self.app_state.value_timeline_pane_state.move_selection(1),
+            _ => {}
+        }
+    }
 }
@@ -17,13 +17,17 @@ fn main() -> Result<()> {
         EngineMode::Standalone
     };
 
+    // Hotkeys are listened for, but suspended while the terminal has focus, as keys typed into the TUI are also seen globally.
+    // The terminal is assumed to have focus on launch, and reports later focus changes.
     let mut squalr_engine = SqualrEngine::new_with_options(
         engine_mode,
         SqualrEngineOptions {
             enable_unprivileged_console_logging: false,
+            enable_project_hotkeys: true,
         },
     )
     .context("Fatal error initializing Squalr engine.")?;
+    squalr_engine.set_project_hotkeys_suspended(true);
     squalr_engine.initialize();
 
     if engine_mode == EngineMode::Standalone {
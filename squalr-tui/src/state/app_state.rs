@@ -2,7 +2,9 @@ use crate::state::pane::TuiPane;
 use crate::state::pane_entry_row::PaneEntryRow;
 use crate::state::pane_layout_state::PaneLayoutState;
 use crate::state::workspace_page::TuiWorkspacePage;
+use crate::views::disassembly::pane_state::DisassemblyPaneState;
 use crate::views::element_scanner::pane_state::ElementScannerPaneState;
+use crate::views::memory_viewer::pane_state::MemoryViewerPaneState;
 use crate::views::output::pane_state::OutputPaneState;
 use crate::views::output::summary::OUTPUT_FIXED_SUMMARY_LINE_COUNT;
 use crate::views::process_selector::pane_state::ProcessSelectorPaneState;
@@ -11,6 +13,7 @@ use crate::views::scan_results::pane_state::ScanResultsPaneState;
 use crate::views::settings::pane_state::SettingsPaneState;
 use crate::views::struct_viewer::pane_state::StructViewerPaneState;
 use crate::views::struct_viewer::summary::STRUCT_VIEWER_FIXED_SUMMARY_LINE_COUNT;
+use crate::views::value_timeline::pane_state::ValueTimelinePaneState;
 
 /// Root state container for TUI panes.
 #[derive(Clone, Debug, Default)]
@@ -21,6 +24,9 @@ pub struct TuiAppState {
     pub scan_results_pane_state: ScanResultsPaneState,
     pub project_explorer_pane_state: ProjectExplorerPaneState,
     pub struct_viewer_pane_state: StructViewerPaneState,
+    pub memory_viewer_pane_state: MemoryViewerPaneState,
+    pub disassembly_pane_state: DisassemblyPaneState,
+    pub value_timeline_pane_state: ValueTimelinePaneState,
     pub output_pane_state: OutputPaneState,
     pub settings_pane_state: SettingsPaneState,
 }
@@ -100,6 +106,9 @@ impl TuiAppState {
             TuiPane::StructViewer => self
                 .struct_viewer_pane_state
                 .summary_lines(pane_content_height.saturating_sub(STRUCT_VIEWER_FIXED_SUMMARY_LINE_COUNT)),
+            TuiPane::MemoryViewer => self.memory_viewer_pane_state.summary_lines(),
+            TuiPane::Disassembly => self.disassembly_pane_state.summary_lines(),
+            TuiPane::ValueTimeline => self.value_timeline_pane_state.summary_lines(),
             TuiPane::Output => self
                 .output_pane_state
                 .summary_lines(pane_content_height.saturating_sub(OUTPUT_FIXED_SUMMARY_LINE_COUNT)),
@@ -121,6 +130,15 @@ impl TuiAppState {
             TuiPane::ScanResults => self
                 .scan_results_pane_state
                 .visible_scan_result_rows(pane_entry_row_capacity),
+            TuiPane::MemoryViewer => self
+                .memory_viewer_pane_state
+                .visible_memory_rows(pane_entry_row_capacity),
+            TuiPane::Disassembly => self
+                .disassembly_pane_state
+                .visible_disassembly_rows(pane_entry_row_capacity),
+            TuiPane::ValueTimeline => self
+                .value_timeline_pane_state
+                .visible_value_timeline_rows(pane_entry_row_capacity),
             TuiPane::ProjectExplorer => {
                 let (project_entry_row_capacity, project_item_entry_row_capacity) = self.project_explorer_entry_row_capacities(pane_entry_row_capacity);
                 let mut entry_rows = self
@@ -6,6 +6,9 @@ pub enum TuiPane {
     ScanResults,
     ProjectExplorer,
     StructViewer,
+    MemoryViewer,
+    Disassembly,
+    ValueTimeline,
     Output,
     Settings,
 }
@@ -18,6 +21,9 @@ impl TuiPane {
             TuiPane::ScanResults => "Scan Results",
             TuiPane::ProjectExplorer => "Project Explorer",
             TuiPane::StructViewer => "Struct Viewer",
+            TuiPane::MemoryViewer => "Memory Viewer",
+            TuiPane::Disassembly => "Disassembly",
+            TuiPane::ValueTimeline => "Value Timeline",
             TuiPane::Output => "Output",
             TuiPane::Settings => "Settings",
         }
@@ -32,6 +38,9 @@ impl TuiPane {
             TuiPane::StructViewer => 4,
             TuiPane::Output => 5,
             TuiPane::Settings => 6,
+            TuiPane::MemoryViewer => 7,
+            TuiPane::Disassembly => 8,
+            TuiPane::ValueTimeline => 9,
         }
     }
 }
@@ -6,6 +6,9 @@ pub enum TuiWorkspacePage {
     ProjectWorkspace,
     ScannerWorkspace,
     SettingsWorkspace,
+    MemoryWorkspace,
+    DisassemblyWorkspace,
+    ValueTimelineWorkspace,
 }
 
 impl TuiWorkspacePage {
@@ -14,6 +17,9 @@ impl TuiWorkspacePage {
             2 => Some(Self::ScannerWorkspace),
             3 => Some(Self::SettingsWorkspace),
             4 => Some(Self::ProjectWorkspace),
+            5 => Some(Self::MemoryWorkspace),
+            6 => Some(Self::DisassemblyWorkspace),
+            7 => Some(Self::ValueTimelineWorkspace),
             _ => None,
         }
     }
@@ -23,6 +29,9 @@ impl TuiWorkspacePage {
             Self::ProjectWorkspace => "Project Workspace",
             Self::ScannerWorkspace => "Scanner Workspace",
             Self::SettingsWorkspace => "Settings Workspace",
+            Self::MemoryWorkspace => "Memory Workspace",
+            Self::DisassemblyWorkspace => "Disassembly Workspace",
+            Self::ValueTimelineWorkspace => "Value Timeline Workspace",
         }
     }
 
@@ -31,6 +40,9 @@ impl TuiWorkspacePage {
             Self::ProjectWorkspace => "Process Selector -> Project Explorer -> Output",
             Self::ScannerWorkspace => "Element Scanner -> Scan Results -> Output",
             Self::SettingsWorkspace => "Settings -> Output",
+            Self::MemoryWorkspace => "Memory Viewer -> Output",
+            Self::DisassemblyWorkspace => "Disassembly -> Output",
+            Self::ValueTimelineWorkspace => "Value Timeline -> Output",
         }
     }
 
@@ -43,6 +55,9 @@ impl TuiWorkspacePage {
             ],
             Self::ScannerWorkspace => &[TuiPane::ElementScanner, TuiPane::ScanResults, TuiPane::Output],
             Self::SettingsWorkspace => &[TuiPane::Settings, TuiPane::Output],
+            Self::MemoryWorkspace => &[TuiPane::MemoryViewer, TuiPane::Output],
+            Self::DisassemblyWorkspace => &[TuiPane::Disassembly, TuiPane::Output],
+            Self::ValueTimelineWorkspace => &[TuiPane::ValueTimeline, TuiPane::Output],
         }
     }
 }
@@ -64,6 +79,9 @@ mod tests {
         assert_eq!(TuiWorkspacePage::from_function_key(2), Some(TuiWorkspacePage::ScannerWorkspace));
         assert_eq!(TuiWorkspacePage::from_function_key(3), Some(TuiWorkspacePage::SettingsWorkspace));
         assert_eq!(TuiWorkspacePage::from_function_key(4), Some(TuiWorkspacePage::ProjectWorkspace));
+        assert_eq!(TuiWorkspacePage::from_function_key(5), Some(TuiWorkspacePage::MemoryWorkspace));
+        assert_eq!(TuiWorkspacePage::from_function_key(6), Some(TuiWorkspacePage::DisassemblyWorkspace));
+        assert_eq!(TuiWorkspacePage::from_function_key(7), Some(TuiWorkspacePage::ValueTimelineWorkspace));
     }
 
     #[test]
@@ -81,5 +99,11 @@ mod tests {
             &[TuiPane::ElementScanner, TuiPane::ScanResults, TuiPane::Output]
         );
         assert_eq!(TuiWorkspacePage::SettingsWorkspace.visible_panes(), &[TuiPane::Settings, TuiPane::Output]);
+        assert_eq!(TuiWorkspacePage::MemoryWorkspace.visible_panes(), &[TuiPane::MemoryViewer, TuiPane::Output]);
+        assert_eq!(TuiWorkspacePage::DisassemblyWorkspace.visible_panes(), &[TuiPane::Disassembly, TuiPane::Output]);
+        assert_eq!(
+            TuiWorkspacePage::ValueTimelineWorkspace.visible_panes(),
+            &[TuiPane::ValueTimeline, TuiPane::Output]
+        );
     }
 }
@@ -0,0 +1,48 @@
+use crate::state::pane_entry_row::PaneEntryRow;
+use crate::views::disassembly::pane_state::DisassemblyPaneState;
+use crate::views::entry_row_viewport::build_selection_relative_viewport_range;
+
+pub fn build_visible_disassembly_rows(
+    disassembly_pane_state: &DisassemblyPaneState,
+    viewport_capacity: usize,
+) -> Vec<PaneEntryRow> {
+    let visible_instruction_range = build_selection_relative_viewport_range(
+        disassembly_pane_state.instructions.len(),
+        Some(disassembly_pane_state.selected_instruction_index),
+        viewport_capacity,
+    );
+    let mut entry_rows = Vec::with_capacity(visible_instruction_range.len());
+
+    for instruction_index in visible_instruction_range {
+        let instruction = &disassembly_pane_state.instructions[instruction_index];
+        let is_selected = instruction_index == disassembly_pane_state.selected_instruction_index;
+        let marker_text = format!(
+            "{}{}",
+            if is_selected { ">" } else { " " },
+            if instruction.get_address() == disassembly_pane_state.resolved_address {
+                "*"
+            } else {
+                " "
+            }
+        );
+        let primary_text = format!(
+            "{:<24} {:<24} {}",
+            DisassemblyPaneState::format_instruction_address(instruction),
+            DisassemblyPaneState::format_instruction_bytes(instruction),
+            instruction.get_text()
+        );
+        let secondary_text = instruction
+            .get_target_location()
+            .map(|target_location| format!("; {}", target_location));
+
+        if is_selected {
+            entry_rows.push(PaneEntryRow::selected(marker_text, primary_text, secondary_text));
+        } else if !instruction.is_valid() {
+            entry_rows.push(PaneEntryRow::disabled(marker_text, primary_text, secondary_text));
+        } else {
+            entry_rows.push(PaneEntryRow::normal(marker_text, primary_text, secondary_text));
+        }
+    }
+
+    entry_rows
+}
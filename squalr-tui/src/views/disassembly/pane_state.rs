@@ -0,0 +1,258 @@
+use crate::state::pane_entry_row::PaneEntryRow;
+use crate::views::disassembly::entry_rows::build_visible_disassembly_rows;
+use crate::views::disassembly::summary::build_disassembly_summary_lines;
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
+use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+
+/// Stores state for browsing an instruction listing around an address of the opened process.
+#[derive(Clone, Debug)]
+pub struct DisassemblyPaneState {
+    pub address_input: String,
+    pub is_editing_address: bool,
+    pub target_expression: String,
+    pub target_history: Vec<String>,
+    pub resolved_address: u64,
+    pub instruction_set: Option<InstructionSet>,
+    pub instructions: Vec<DisassembledInstruction>,
+    pub selected_instruction_index: usize,
+    pub has_target: bool,
+    pub is_disassembling: bool,
+    pub status_message: String,
+}
+
+impl DisassemblyPaneState {
+    pub const INSTRUCTIONS_BEFORE: u32 = 8;
+    pub const INSTRUCTION_COUNT: u32 = 48;
+    const MAX_HISTORY_LENGTH: usize = 64;
+
+    pub fn begin_address_input(&mut self) {
+        self.is_editing_address = true;
+        self.address_input.clear();
+    }
+
+    pub fn cancel_address_input(&mut self) {
+        self.is_editing_address = false;
+    }
+
+    pub fn append_address_input_character(
+        &mut self,
+        address_character: char,
+    ) {
+        self.address_input.push(address_character);
+    }
+
+    pub fn backspace_address_input(&mut self) {
+        self.address_input.pop();
+    }
+
+    /// Parses the typed go-to address expression, returning it if it was valid.
+    pub fn commit_address_input(&mut self) -> Option<AddressExpression> {
+        self.is_editing_address = false;
+
+        match AddressExpression::parse(&self.address_input) {
+            Ok(address_expression) => Some(address_expression),
+            Err(error) => {
+                self.status_message = format!("Invalid address '{}': {}", self.address_input, error);
+                None
+            }
+        }
+    }
+
+    /// Moves the listing to an address expression, remembering the previous target such that it can be returned to.
+    pub fn go_to(
+        &mut self,
+        target_expression: String,
+    ) {
+        if self.has_target && self.target_expression != target_expression {
+            if self.target_history.len() >= Self::MAX_HISTORY_LENGTH {
+                self.target_history.remove(0);
+            }
+
+            self.target_history.push(self.target_expression.clone());
+        }
+
+        self.set_target(target_expression);
+    }
+
+    /// Returns to the previous target, if any.
+    pub fn go_back(&mut self) -> bool {
+        match self.target_history.pop() {
+            Some(target_expression) => {
+                self.set_target(target_expression);
+                true
+            }
+            None => {
+                self.status_message = "No previous address.".to_string();
+                false
+            }
+        }
+    }
+
+    /// Moves the listing to the branch target or memory address referenced by the selected instruction.
+    pub fn follow_selected_target(&mut self) -> bool {
+        match self
+            .selected_instruction()
+            .and_then(|instruction| instruction.get_target_address())
+        {
+            Some(target_address) => {
+                self.go_to(format!("0x{:X}", target_address));
+                true
+            }
+            None => {
+                self.status_message = "The selected instruction has no target.".to_string();
+                false
+            }
+        }
+    }
+
+    pub fn move_selection(
+        &mut self,
+        instruction_delta: i64,
+    ) {
+        if self.instructions.is_empty() {
+            return;
+        }
+
+        self.selected_instruction_index = self
+            .selected_instruction_index
+            .saturating_add_signed(instruction_delta as isize)
+            .min(self.instructions.len() - 1);
+    }
+
+    /// Re-targets the listing at the first or last decoded instruction, such that paging continues past the current listing.
+    pub fn page(
+        &mut self,
+        is_forward: bool,
+    ) -> bool {
+        let page_instruction = if is_forward { self.instructions.last() } else { self.instructions.first() };
+
+        match page_instruction {
+            Some(page_instruction) => {
+                let page_address = page_instruction.get_address();
+
+                self.set_target(format!("0x{:X}", page_address));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn selected_instruction(&self) -> Option<&DisassembledInstruction> {
+        self.instructions.get(self.selected_instruction_index)
+    }
+
+    pub fn apply_disassemble_response(
+        &mut self,
+        memory_disassemble_response: MemoryDisassembleResponse,
+    ) {
+        self.resolved_address = memory_disassemble_response.address;
+        self.instruction_set = memory_disassemble_response.instruction_set;
+        self.instructions = memory_disassemble_response.instructions;
+        self.selected_instruction_index = self
+            .instructions
+            .iter()
+            .position(|instruction| instruction.get_address() == self.resolved_address)
+            .unwrap_or(0);
+    }
+
+    /// Formats the address of an instruction, preferring its module and offset.
+    pub fn format_instruction_address(instruction: &DisassembledInstruction) -> String {
+        match instruction.get_location() {
+            Some(location) => location.to_string(),
+            None => format!("0x{:X}", instruction.get_address()),
+        }
+    }
+
+    pub fn format_instruction_bytes(instruction: &DisassembledInstruction) -> String {
+        instruction
+            .get_bytes()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn summary_lines(&self) -> Vec<String> {
+        build_disassembly_summary_lines(self)
+    }
+
+    pub fn visible_disassembly_rows(
+        &self,
+        viewport_capacity: usize,
+    ) -> Vec<PaneEntryRow> {
+        build_visible_disassembly_rows(self, viewport_capacity)
+    }
+
+    fn set_target(
+        &mut self,
+        target_expression: String,
+    ) {
+        self.status_message = format!("Disassembling {}.", target_expression);
+        self.target_expression = target_expression;
+        self.has_target = true;
+        self.instructions.clear();
+        self.selected_instruction_index = 0;
+    }
+}
+
+impl Default for DisassemblyPaneState {
+    fn default() -> Self {
+        Self {
+            address_input: String::new(),
+            is_editing_address: false,
+            target_expression: String::new(),
+            target_history: Vec::new(),
+            resolved_address: 0,
+            instruction_set: None,
+            instructions: Vec::new(),
+            selected_instruction_index: 0,
+            has_target: false,
+            is_disassembling: false,
+            status_message: "Press g to go to an address.".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisassemblyPaneState;
+    use squalr_engine_api::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
+    use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+
+    fn create_response() -> MemoryDisassembleResponse {
+        MemoryDisassembleResponse {
+            address: 0x1001,
+            instruction_set: None,
+            instructions: vec![
+                DisassembledInstruction::new(0x1000, vec![0x90], "nop".to_string(), String::new(), true, None),
+                DisassembledInstruction::new(0x1001, vec![0xEB, 0x10], "jmp".to_string(), "0x1013".to_string(), true, Some(0x1013)),
+            ],
+            success: true,
+        }
+    }
+
+    #[test]
+    fn apply_disassemble_response_selects_the_target_instruction() {
+        let mut disassembly_pane_state = DisassemblyPaneState::default();
+
+        disassembly_pane_state.apply_disassemble_response(create_response());
+
+        assert_eq!(disassembly_pane_state.selected_instruction_index, 1);
+    }
+
+    #[test]
+    fn following_targets_can_be_undone() {
+        let mut disassembly_pane_state = DisassemblyPaneState::default();
+
+        disassembly_pane_state.go_to("game.exe+1000".to_string());
+        disassembly_pane_state.apply_disassemble_response(create_response());
+
+        assert!(disassembly_pane_state.follow_selected_target());
+        assert_eq!(disassembly_pane_state.target_expression, "0x1013");
+        assert!(disassembly_pane_state.go_back());
+        assert_eq!(disassembly_pane_state.target_expression, "game.exe+1000");
+        assert!(!disassembly_pane_state.go_back());
+    }
+}
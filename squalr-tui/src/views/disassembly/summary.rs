@@ -0,0 +1,28 @@
+use crate::views::disassembly::pane_state::DisassemblyPaneState;
+
+pub fn build_disassembly_summary_lines(disassembly_pane_state: &DisassemblyPaneState) -> Vec<String> {
+    let address_line = if disassembly_pane_state.is_editing_address {
+        format!("[GOTO] {}_ (Enter apply | Esc cancel).", disassembly_pane_state.address_input)
+    } else {
+        format!(
+            "[ADDR] target={} | resolved=0x{:X} | isa={}.",
+            if disassembly_pane_state.has_target {
+                disassembly_pane_state.target_expression.as_str()
+            } else {
+                "none"
+            },
+            disassembly_pane_state.resolved_address,
+            disassembly_pane_state
+                .instruction_set
+                .map(|instruction_set| instruction_set.to_string())
+                .unwrap_or_else(|| "auto".to_string())
+        )
+    };
+
+    vec![
+        "[ACT] g goto | r refresh | Enter follow target | Backspace back.".to_string(),
+        "[NAV] Up/Down select | PgUp/PgDn page.".to_string(),
+        address_line,
+        format!("[STAT] {}.", disassembly_pane_state.status_message),
+    ]
+}
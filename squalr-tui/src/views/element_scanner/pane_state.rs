@@ -8,6 +8,7 @@ use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_delt
 use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
 use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
 use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::AnonymousScanConstraintExpression;
 use std::collections::BTreeSet;
 
 /// Stores one editable scanner constraint row.
@@ -273,7 +274,7 @@ impl ElementScannerPaneState {
         self.constraint_rows[self.selected_constraint_row_index].scan_value_text = "0".to_string();
     }
 
-    pub fn build_anonymous_scan_constraints(&self) -> Vec<AnonymousScanConstraint> {
+    pub fn build_anonymous_scan_constraints(&self) -> Vec<AnonymousScanConstraintExpression> {
         self.constraint_rows
             .iter()
             .map(|constraint_row| {
@@ -288,7 +289,7 @@ impl ElementScannerPaneState {
                     None
                 };
 
-                AnonymousScanConstraint::new(constraint_row.scan_compare_type, anonymous_value_string)
+                AnonymousScanConstraint::new(constraint_row.scan_compare_type, anonymous_value_string).into()
             })
             .collect()
     }
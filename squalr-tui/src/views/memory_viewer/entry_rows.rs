@@ -0,0 +1,49 @@
+use crate::state::pane_entry_row::PaneEntryRow;
+use crate::views::entry_row_viewport::build_selection_relative_viewport_range;
+use crate::views::memory_viewer::pane_state::MemoryViewerPaneState;
+
+pub fn build_visible_memory_rows(
+    memory_viewer_pane_state: &MemoryViewerPaneState,
+    viewport_capacity: usize,
+) -> Vec<PaneEntryRow> {
+    if !memory_viewer_pane_state.has_target {
+        return Vec::new();
+    }
+
+    let cursor_row_index = memory_viewer_pane_state.cursor_offset / MemoryViewerPaneState::BYTES_PER_ROW;
+    let (selection_start, selection_end) = memory_viewer_pane_state.selection_range();
+    let visible_row_range = build_selection_relative_viewport_range(MemoryViewerPaneState::ROW_COUNT, Some(cursor_row_index), viewport_capacity);
+    let mut entry_rows = Vec::with_capacity(visible_row_range.len());
+
+    for row_index in visible_row_range {
+        let row_start = row_index * MemoryViewerPaneState::BYTES_PER_ROW;
+        let row_end = row_start + MemoryViewerPaneState::BYTES_PER_ROW - 1;
+        let is_in_selection = row_start <= selection_end && row_end >= selection_start;
+        let marker_text = format!(
+            "{}{}",
+            if row_index == cursor_row_index { ">" } else { " " },
+            if is_in_selection { "*" } else { " " }
+        );
+        let primary_text = format!(
+            "{}  {}",
+            memory_viewer_pane_state.format_address(row_start),
+            memory_viewer_pane_state.format_row_hex(row_index)
+        );
+        let secondary_text = Some(memory_viewer_pane_state.decode_row(row_index));
+        let is_row_readable = memory_viewer_pane_state
+            .readable
+            .get(row_start..=row_end)
+            .map(|readable| readable.iter().any(|is_readable| *is_readable))
+            .unwrap_or(false);
+
+        if row_index == cursor_row_index {
+            entry_rows.push(PaneEntryRow::selected(marker_text, primary_text, secondary_text));
+        } else if !is_row_readable {
+            entry_rows.push(PaneEntryRow::disabled(marker_text, primary_text, secondary_text));
+        } else {
+            entry_rows.push(PaneEntryRow::normal(marker_text, primary_text, secondary_text));
+        }
+    }
+
+    entry_rows
+}
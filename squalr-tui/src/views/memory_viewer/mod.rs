@@ -0,0 +1,3 @@
+pub mod entry_rows;
+pub mod pane_state;
+pub mod summary;
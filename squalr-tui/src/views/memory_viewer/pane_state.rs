@@ -0,0 +1,374 @@
+use crate::state::pane_entry_row::PaneEntryRow;
+use crate::views::memory_viewer::entry_rows::build_visible_memory_rows;
+use crate::views::memory_viewer::summary::build_memory_viewer_summary_lines;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+
+/// Stores state for browsing, editing, and decoding a window of process memory.
+#[derive(Clone, Debug)]
+pub struct MemoryViewerPaneState {
+    pub address_input: String,
+    pub is_editing_address: bool,
+    pub module_name: String,
+    pub base_address: u64,
+    pub resolved_address: u64,
+    pub bytes: Vec<u8>,
+    pub readable: Vec<bool>,
+    pub changed: Vec<bool>,
+    pub cursor_offset: usize,
+    pub selection_anchor: Option<usize>,
+    pub pending_edit_text: String,
+    pub selected_data_type_index: usize,
+    pub has_target: bool,
+    pub is_reading: bool,
+    pub status_message: String,
+}
+
+impl MemoryViewerPaneState {
+    pub const BYTES_PER_ROW: usize = 16;
+    pub const ROW_COUNT: usize = 32;
+    const SUPPORTED_DATA_TYPE_IDS: [&'static str; 10] = [
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "f32", "f64",
+    ];
+
+    pub fn view_length() -> usize {
+        Self::BYTES_PER_ROW * Self::ROW_COUNT
+    }
+
+    pub fn selected_data_type_ref(&self) -> DataTypeRef {
+        DataTypeRef::new(Self::SUPPORTED_DATA_TYPE_IDS[self.selected_data_type_index])
+    }
+
+    pub fn cycle_data_type_forward(&mut self) {
+        self.selected_data_type_index = (self.selected_data_type_index + 1) % Self::SUPPORTED_DATA_TYPE_IDS.len();
+    }
+
+    pub fn cycle_data_type_backward(&mut self) {
+        self.selected_data_type_index = if self.selected_data_type_index == 0 {
+            Self::SUPPORTED_DATA_TYPE_IDS.len() - 1
+        } else {
+            self.selected_data_type_index - 1
+        };
+    }
+
+    pub fn begin_address_input(&mut self) {
+        self.is_editing_address = true;
+        self.address_input.clear();
+    }
+
+    pub fn cancel_address_input(&mut self) {
+        self.is_editing_address = false;
+    }
+
+    pub fn append_address_input_character(
+        &mut self,
+        address_character: char,
+    ) {
+        self.address_input.push(address_character);
+    }
+
+    pub fn backspace_address_input(&mut self) {
+        self.address_input.pop();
+    }
+
+    /// Parses the typed go-to address expression, returning it if it was valid.
+    pub fn commit_address_input(&mut self) -> Option<AddressExpression> {
+        self.is_editing_address = false;
+
+        match AddressExpression::parse(&self.address_input) {
+            Ok(address_expression) => Some(address_expression),
+            Err(error) => {
+                self.status_message = format!("Invalid address '{}': {}", self.address_input, error);
+                None
+            }
+        }
+    }
+
+    /// Moves the view to an address, relative to the given module if it is non-empty.
+    pub fn go_to_module_offset(
+        &mut self,
+        module_name: String,
+        address: u64,
+    ) {
+        self.module_name = module_name;
+        self.base_address = address;
+        self.has_target = true;
+        self.cursor_offset = 0;
+        self.selection_anchor = None;
+        self.clear_view();
+        self.status_message = format!("Viewing {}.", self.format_address(0));
+    }
+
+    pub fn move_cursor(
+        &mut self,
+        byte_delta: i64,
+        extend_selection: bool,
+    ) {
+        if extend_selection && self.selection_anchor.is_none() {
+            self.selection_anchor = Some(self.cursor_offset);
+        } else if !extend_selection {
+            self.selection_anchor = None;
+        }
+
+        let target_offset = self.cursor_offset as i64 + byte_delta;
+        let view_length = Self::view_length() as i64;
+
+        // Scroll the view when the cursor leaves the window, keeping the cursor on the same address.
+        if target_offset < 0 || target_offset >= view_length {
+            let row_length = Self::BYTES_PER_ROW as i64;
+            let scroll_rows = if target_offset < 0 {
+                (target_offset - row_length + 1) / row_length
+            } else {
+                (target_offset - view_length) / row_length + 1
+            };
+
+            self.scroll_rows(scroll_rows);
+            self.cursor_offset = (target_offset - scroll_rows * row_length).clamp(0, view_length - 1) as usize;
+        } else {
+            self.cursor_offset = target_offset as usize;
+        }
+
+        self.pending_edit_text.clear();
+    }
+
+    pub fn scroll_rows(
+        &mut self,
+        row_delta: i64,
+    ) {
+        let byte_delta = row_delta.saturating_mul(Self::BYTES_PER_ROW as i64);
+
+        self.base_address = self.base_address.saturating_add_signed(byte_delta);
+        self.selection_anchor = None;
+        self.clear_view();
+    }
+
+    pub fn append_pending_edit_character(
+        &mut self,
+        edit_character: char,
+    ) {
+        if edit_character.is_ascii_hexdigit() {
+            self.pending_edit_text.push(edit_character.to_ascii_uppercase());
+        }
+    }
+
+    pub fn backspace_pending_edit(&mut self) {
+        self.pending_edit_text.pop();
+    }
+
+    /// Parses the pending hex edit text into bytes, two hex digits per byte.
+    pub fn pending_edit_bytes(&self) -> Option<Vec<u8>> {
+        if self.pending_edit_text.is_empty() || !self.pending_edit_text.len().is_multiple_of(2) {
+            return None;
+        }
+
+        (0..self.pending_edit_text.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&self.pending_edit_text[index..index + 2], 16).ok())
+            .collect()
+    }
+
+    /// Gets the inclusive selected byte range, ordered from low to high.
+    pub fn selection_range(&self) -> (usize, usize) {
+        let selection_anchor = self.selection_anchor.unwrap_or(self.cursor_offset);
+
+        (selection_anchor.min(self.cursor_offset), selection_anchor.max(self.cursor_offset))
+    }
+
+    pub fn address_at(
+        &self,
+        offset: usize,
+    ) -> u64 {
+        self.base_address.saturating_add(offset as u64)
+    }
+
+    pub fn format_address(
+        &self,
+        offset: usize,
+    ) -> String {
+        AddressExpression::format_module_offset(&self.module_name, self.address_at(offset))
+    }
+
+    pub fn apply_read_range_response(
+        &mut self,
+        memory_read_range_response: MemoryReadRangeResponse,
+    ) {
+        let is_same_range = self.resolved_address == memory_read_range_response.address && self.bytes.len() == memory_read_range_response.bytes.len();
+
+        self.changed = (0..memory_read_range_response.bytes.len())
+            .map(|offset| {
+                is_same_range
+                    && memory_read_range_response.is_readable(offset)
+                    && self.readable[offset]
+                    && self.bytes[offset] != memory_read_range_response.bytes[offset]
+            })
+            .collect();
+        self.resolved_address = memory_read_range_response.address;
+        self.bytes = memory_read_range_response.bytes;
+        self.readable = memory_read_range_response.readable;
+    }
+
+    /// Formats a row of bytes as hex, marking unreadable bytes with `??` and changed bytes with a trailing `*`.
+    pub fn format_row_hex(
+        &self,
+        row_index: usize,
+    ) -> String {
+        let row_start = row_index * Self::BYTES_PER_ROW;
+        let mut row_text = String::with_capacity(Self::BYTES_PER_ROW * 3);
+
+        for offset in row_start..row_start + Self::BYTES_PER_ROW {
+            match (
+                self.readable.get(offset).copied().unwrap_or(false),
+                self.changed.get(offset).copied().unwrap_or(false),
+            ) {
+                (false, _) => row_text.push_str("?? "),
+                (true, true) => row_text.push_str(&format!("{:02X}*", self.bytes[offset])),
+                (true, false) => row_text.push_str(&format!("{:02X} ", self.bytes[offset])),
+            }
+        }
+
+        row_text.trim_end().to_string()
+    }
+
+    /// Decodes a row of bytes into values of the selected data type.
+    pub fn decode_row(
+        &self,
+        row_index: usize,
+    ) -> String {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let data_type_ref = self.selected_data_type_ref();
+        let unit_size = (symbol_registry.get_unit_size_in_bytes(&data_type_ref) as usize).max(1);
+        let default_format = symbol_registry.get_default_anonymous_value_string_format(&data_type_ref);
+        let row_start = row_index * Self::BYTES_PER_ROW;
+        let row_end = (row_start + Self::BYTES_PER_ROW).min(self.bytes.len());
+        let mut decoded_values = Vec::new();
+        let mut value_start = row_start;
+
+        while value_start + unit_size <= row_end {
+            let value_end = value_start + unit_size;
+
+            if self.readable[value_start..value_end]
+                .iter()
+                .all(|is_readable| *is_readable)
+            {
+                let data_value = DataValue::new(data_type_ref.clone(), self.bytes[value_start..value_end].to_vec());
+
+                decoded_values.push(
+                    symbol_registry
+                        .anonymize_value(&data_value, default_format)
+                        .map(|anonymous_value_string| anonymous_value_string.get_anonymous_value_string().to_string())
+                        .unwrap_or_else(|_| "??".to_string()),
+                );
+            } else {
+                decoded_values.push("??".to_string());
+            }
+
+            value_start = value_end;
+        }
+
+        decoded_values.join(" ")
+    }
+
+    pub fn summary_lines(&self) -> Vec<String> {
+        build_memory_viewer_summary_lines(self)
+    }
+
+    pub fn visible_memory_rows(
+        &self,
+        viewport_capacity: usize,
+    ) -> Vec<PaneEntryRow> {
+        build_visible_memory_rows(self, viewport_capacity)
+    }
+
+    fn clear_view(&mut self) {
+        self.bytes.clear();
+        self.readable.clear();
+        self.changed.clear();
+        self.pending_edit_text.clear();
+    }
+}
+
+impl Default for MemoryViewerPaneState {
+    fn default() -> Self {
+        Self {
+            address_input: String::new(),
+            is_editing_address: false,
+            module_name: String::new(),
+            base_address: 0,
+            resolved_address: 0,
+            bytes: Vec::new(),
+            readable: Vec::new(),
+            changed: Vec::new(),
+            cursor_offset: 0,
+            selection_anchor: None,
+            pending_edit_text: String::new(),
+            selected_data_type_index: 0,
+            has_target: false,
+            is_reading: false,
+            status_message: "Press g to go to an address.".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryViewerPaneState;
+    use squalr_engine_api::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+
+    #[test]
+    fn commit_address_input_parses_address_expressions() {
+        let mut memory_viewer_pane_state = MemoryViewerPaneState {
+            address_input: "game.exe+1F0".to_string(),
+            ..MemoryViewerPaneState::default()
+        };
+
+        let address_expression = memory_viewer_pane_state
+            .commit_address_input()
+            .expect("address expression should parse");
+
+        assert_eq!(address_expression.to_module_offset(), Some(("game.exe".to_string(), 0x1F0)));
+        assert!(!memory_viewer_pane_state.is_editing_address);
+    }
+
+    #[test]
+    fn moving_cursor_past_window_scrolls_by_rows() {
+        let mut memory_viewer_pane_state = MemoryViewerPaneState {
+            base_address: 0x1000,
+            ..MemoryViewerPaneState::default()
+        };
+
+        memory_viewer_pane_state.move_cursor(-1, false);
+
+        assert_eq!(memory_viewer_pane_state.base_address, 0x0FF0);
+        assert_eq!(memory_viewer_pane_state.cursor_offset, 15);
+    }
+
+    #[test]
+    fn format_row_hex_marks_unreadable_and_changed_bytes() {
+        let mut memory_viewer_pane_state = MemoryViewerPaneState::default();
+        let readable = (0..16).map(|offset| offset != 1).collect::<Vec<_>>();
+
+        memory_viewer_pane_state.apply_read_range_response(MemoryReadRangeResponse {
+            address: 0x1000,
+            bytes: vec![0xAA; 16],
+            readable: readable.clone(),
+            success: true,
+        });
+        let mut changed_bytes = vec![0xAA; 16];
+        changed_bytes[2] = 0xBB;
+        memory_viewer_pane_state.apply_read_range_response(MemoryReadRangeResponse {
+            address: 0x1000,
+            bytes: changed_bytes,
+            readable,
+            success: true,
+        });
+
+        assert!(
+            memory_viewer_pane_state
+                .format_row_hex(0)
+                .starts_with("AA ?? BB*AA")
+        );
+    }
+}
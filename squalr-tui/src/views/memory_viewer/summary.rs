@@ -0,0 +1,38 @@
+use crate::views::memory_viewer::pane_state::MemoryViewerPaneState;
+
+pub fn build_memory_viewer_summary_lines(memory_viewer_pane_state: &MemoryViewerPaneState) -> Vec<String> {
+    let (selection_start, selection_end) = memory_viewer_pane_state.selection_range();
+    let address_line = if memory_viewer_pane_state.is_editing_address {
+        format!("[GOTO] {}_ (Enter apply | Esc cancel).", memory_viewer_pane_state.address_input)
+    } else {
+        format!(
+            "[ADDR] base={} | cursor={} | resolved=0x{:X}.",
+            memory_viewer_pane_state.format_address(0),
+            memory_viewer_pane_state.format_address(memory_viewer_pane_state.cursor_offset),
+            memory_viewer_pane_state
+                .resolved_address
+                .saturating_add(memory_viewer_pane_state.cursor_offset as u64)
+        )
+    };
+
+    vec![
+        "[ACT] g goto | r refresh | Enter write | p add-to-project | [/] data type.".to_string(),
+        "[NAV] Arrows move | Shift+Arrows select | PgUp/PgDn scroll | 0-9 A-F edit.".to_string(),
+        address_line,
+        format!(
+            "[SEL] {}..{} ({} bytes) | type={} | edit={}.",
+            memory_viewer_pane_state.format_address(selection_start),
+            memory_viewer_pane_state.format_address(selection_end),
+            selection_end - selection_start + 1,
+            memory_viewer_pane_state
+                .selected_data_type_ref()
+                .get_data_type_id(),
+            if memory_viewer_pane_state.pending_edit_text.is_empty() {
+                "none"
+            } else {
+                memory_viewer_pane_state.pending_edit_text.as_str()
+            }
+        ),
+        format!("[STAT] {}.", memory_viewer_pane_state.status_message),
+    ]
+}
@@ -1,8 +1,11 @@
+pub mod disassembly;
 pub mod element_scanner;
 pub mod entry_row_viewport;
+pub mod memory_viewer;
 pub mod output;
 pub mod process_selector;
 pub mod project_explorer;
 pub mod scan_results;
 pub mod settings;
 pub mod struct_viewer;
+pub mod value_timeline;
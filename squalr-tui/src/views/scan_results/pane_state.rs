@@ -5,8 +5,12 @@ use squalr_engine_api::commands::scan_results::query::scan_results_query_respons
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
 use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use squalr_engine_api::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
+use squalr_engine_api::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::AnonymousScanConstraintExpression;
 use std::collections::BTreeSet;
 use std::ops::RangeInclusive;
+use std::str::FromStr;
 
 /// Stores pagination and selection state for scan results.
 #[derive(Clone, Debug)]
@@ -30,6 +34,7 @@ pub struct ScanResultsPaneState {
     pub is_deleting_scan_results: bool,
     pub is_adding_scan_results_to_project: bool,
     pub is_committing_value_edit: bool,
+    pub query_options: ScanResultsQueryOptions,
     pub status_message: String,
 }
 
@@ -153,6 +158,44 @@ impl ScanResultsPaneState {
         true
     }
 
+    /// Advances to the next server-side sort key, returning to the first page.
+    pub fn cycle_sort_key(&mut self) {
+        let sort_keys = [
+            ScanResultsSortKey::Index,
+            ScanResultsSortKey::Address,
+            ScanResultsSortKey::Module,
+            ScanResultsSortKey::CurrentValue,
+            ScanResultsSortKey::Delta,
+        ];
+        let sort_key_position = sort_keys
+            .iter()
+            .position(|sort_key| *sort_key == self.query_options.sort_key)
+            .unwrap_or(0);
+
+        self.query_options.sort_key = sort_keys[(sort_key_position + 1) % sort_keys.len()];
+        self.reset_query_page();
+    }
+
+    pub fn toggle_sort_descending(&mut self) {
+        self.query_options.sort_descending = !self.query_options.sort_descending;
+        self.reset_query_page();
+    }
+
+    /// Filters results to those equal to the pending edit value, or clears the value filter if one is already active.
+    pub fn toggle_value_filter_from_pending_edit(&mut self) -> bool {
+        if self.query_options.value_filter.is_some() {
+            self.query_options.value_filter = None;
+        } else {
+            match AnonymousScanConstraintExpression::from_str(&format!("== {}", self.pending_value_edit_text)) {
+                Ok(value_filter) => self.query_options.value_filter = Some(value_filter),
+                Err(_) => return false,
+            }
+        }
+
+        self.reset_query_page();
+        true
+    }
+
     pub fn set_selected_range_end_to_current(&mut self) {
         if self.selected_result_index.is_some() {
             self.selected_range_end_index = self.selected_result_index;
@@ -367,6 +410,12 @@ impl ScanResultsPaneState {
         Some(range_low_index..=range_high_index)
     }
 
+    fn reset_query_page(&mut self) {
+        self.current_page_index = 0;
+        self.selected_result_index = None;
+        self.selected_range_end_index = None;
+    }
+
     fn clamp_selection_to_bounds(&mut self) {
         if self.scan_results.is_empty() {
             self.selected_result_index = None;
@@ -436,6 +485,7 @@ impl Default for ScanResultsPaneState {
             is_deleting_scan_results: false,
             is_adding_scan_results_to_project: false,
             is_committing_value_edit: false,
+            query_options: ScanResultsQueryOptions::default(),
             status_message: "Ready.".to_string(),
         }
     }
@@ -453,4 +503,20 @@ mod tests {
         assert!(scan_results_pane_state.scan_results.is_empty());
         assert_eq!(scan_results_pane_state.filtered_data_type_ids.len(), 2);
     }
+
+    #[test]
+    fn toggling_value_filter_uses_pending_edit_and_resets_page() {
+        let mut scan_results_pane_state = ScanResultsPaneState {
+            current_page_index: 3,
+            pending_value_edit_text: "42".to_string(),
+            ..ScanResultsPaneState::default()
+        };
+
+        assert!(scan_results_pane_state.toggle_value_filter_from_pending_edit());
+        assert!(scan_results_pane_state.query_options.value_filter.is_some());
+        assert_eq!(scan_results_pane_state.current_page_index, 0);
+
+        assert!(scan_results_pane_state.toggle_value_filter_from_pending_edit());
+        assert!(scan_results_pane_state.query_options.value_filter.is_none());
+    }
 }
@@ -20,6 +20,20 @@ pub fn build_scan_results_summary_lines(scan_results_pane_state: &ScanResultsPan
         "[ACT] r query | R refresh-page | [/] page | f freeze | a add | x delete.".to_string(),
         "[NAV] Up/Down move | Shift+Up/Down range | Home/End.".to_string(),
         "[EDIT] y pull | type value | Enter commit.".to_string(),
+        format!(
+            "[SORT] s key={} | S order={} | v value-filter={}.",
+            scan_results_pane_state.query_options.sort_key,
+            if scan_results_pane_state.query_options.sort_descending {
+                "desc"
+            } else {
+                "asc"
+            },
+            if scan_results_pane_state.query_options.value_filter.is_some() {
+                "on"
+            } else {
+                "off"
+            }
+        ),
         format!("[TYPE] active={} | available={}.", selected_type_filters, available_types),
         format!(
             "[PAGE] {}/{} | size={} | results={}.",
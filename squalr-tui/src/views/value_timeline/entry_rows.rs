@@ -0,0 +1,51 @@
+use crate::state::pane_entry_row::PaneEntryRow;
+use crate::views::entry_row_viewport::build_selection_relative_viewport_range;
+use crate::views::value_timeline::pane_state::ValueTimelinePaneState;
+
+pub fn build_visible_value_timeline_rows(
+    value_timeline_pane_state: &ValueTimelinePaneState,
+    viewport_capacity: usize,
+) -> Vec<PaneEntryRow> {
+    let visible_timeline_range = build_selection_relative_viewport_range(
+        value_timeline_pane_state.value_timelines.len(),
+        value_timeline_pane_state.selected_timeline_index,
+        viewport_capacity,
+    );
+    let mut entry_rows = Vec::with_capacity(visible_timeline_range.len());
+
+    for timeline_index in visible_timeline_range {
+        let value_timeline_info = &value_timeline_pane_state.value_timelines[timeline_index];
+        let is_selected = value_timeline_pane_state.selected_timeline_index == Some(timeline_index);
+        let marker_text = if is_selected { ">".to_string() } else { " ".to_string() };
+        let latest_value = value_timeline_info
+            .latest_sample
+            .as_ref()
+            .and_then(|sample| sample.get_value())
+            .unwrap_or("??");
+        let primary_text = format!(
+            "#{:<3} {:<28} {:<8} {}",
+            value_timeline_info.timeline_id,
+            value_timeline_info.target.get_display_name(),
+            value_timeline_info
+                .target
+                .get_data_type_ref()
+                .get_data_type_id(),
+            latest_value
+        );
+        let sparkline = ValueTimelinePaneState::build_sparkline(
+            value_timeline_pane_state.samples_for(value_timeline_info.timeline_id),
+            ValueTimelinePaneState::SPARKLINE_WIDTH,
+        );
+        let secondary_text = (!sparkline.is_empty()).then_some(sparkline);
+
+        if is_selected {
+            entry_rows.push(PaneEntryRow::selected(marker_text, primary_text, secondary_text));
+        } else if value_timeline_info.latest_sample.is_none() {
+            entry_rows.push(PaneEntryRow::disabled(marker_text, primary_text, secondary_text));
+        } else {
+            entry_rows.push(PaneEntryRow::normal(marker_text, primary_text, secondary_text));
+        }
+    }
+
+    entry_rows
+}
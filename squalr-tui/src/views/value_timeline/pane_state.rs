@@ -0,0 +1,317 @@
+use crate::state::pane_entry_row::PaneEntryRow;
+use crate::views::value_timeline::entry_rows::build_visible_value_timeline_rows;
+use crate::views::value_timeline::summary::build_value_timeline_summary_lines;
+use squalr_engine_api::commands::value_timeline::record::value_timeline_record_request::ValueTimelineRecordRequest;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::value_timelines::value_timeline_info::ValueTimelineInfo;
+use squalr_engine_api::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use std::collections::HashMap;
+
+/// Describes which text prompt, if any, is active in the value timeline pane.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValueTimelineInputMode {
+    None,
+    Record,
+    Export,
+}
+
+/// Stores state for recording and browsing value timelines of addresses in the opened process.
+#[derive(Clone, Debug)]
+pub struct ValueTimelinePaneState {
+    pub value_timelines: Vec<ValueTimelineInfo>,
+    pub samples_by_timeline_id: HashMap<u32, Vec<ValueTimelineSample>>,
+    pub selected_timeline_index: Option<usize>,
+    pub input_mode: ValueTimelineInputMode,
+    pub input_text: String,
+    pub is_refreshing_value_timelines: bool,
+    pub status_message: String,
+}
+
+impl ValueTimelinePaneState {
+    pub const SPARKLINE_WIDTH: usize = 48;
+    pub const DEFAULT_INTERVAL_MS: u64 = 100;
+    pub const DEFAULT_CAPACITY: u64 = 10_000;
+    const MAX_RETAINED_SAMPLES: usize = 256;
+    const SPARKLINE_CHARACTERS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    pub fn begin_input(
+        &mut self,
+        input_mode: ValueTimelineInputMode,
+    ) {
+        self.input_mode = input_mode;
+        self.input_text.clear();
+    }
+
+    pub fn cancel_input(&mut self) {
+        self.input_mode = ValueTimelineInputMode::None;
+    }
+
+    pub fn append_input_character(
+        &mut self,
+        input_character: char,
+    ) {
+        self.input_text.push(input_character);
+    }
+
+    pub fn backspace_input(&mut self) {
+        self.input_text.pop();
+    }
+
+    /// Parses the typed `<address> <data type> [interval ms] [label]` text into a record request.
+    pub fn commit_record_input(&mut self) -> Option<ValueTimelineRecordRequest> {
+        self.input_mode = ValueTimelineInputMode::None;
+
+        let mut input_parts = self.input_text.split_whitespace();
+        let (Some(address_text), Some(data_type_id)) = (input_parts.next(), input_parts.next()) else {
+            self.status_message = "Expected an address followed by a data type".to_string();
+            return None;
+        };
+        let address_expression = match AddressExpression::parse(address_text) {
+            Ok(address_expression) => address_expression,
+            Err(error) => {
+                self.status_message = format!("Invalid address '{}': {}", address_text, error);
+                return None;
+            }
+        };
+        let Some((module_name, address)) = address_expression.to_module_offset() else {
+            self.status_message = "Only static addresses can be recorded".to_string();
+            return None;
+        };
+        let mut remaining_parts = input_parts.peekable();
+        let interval_ms = match remaining_parts
+            .peek()
+            .and_then(|interval_text| interval_text.parse::<u64>().ok())
+        {
+            Some(interval_ms) => {
+                remaining_parts.next();
+                interval_ms
+            }
+            None => Self::DEFAULT_INTERVAL_MS,
+        };
+
+        Some(ValueTimelineRecordRequest {
+            address,
+            module_name,
+            data_type_ref: DataTypeRef::new(data_type_id),
+            label: remaining_parts.collect::<Vec<_>>().join(" "),
+            interval_ms,
+            capacity: Self::DEFAULT_CAPACITY,
+        })
+    }
+
+    /// Takes the typed export file path, returning it if it was not empty.
+    pub fn commit_export_input(&mut self) -> Option<String> {
+        self.input_mode = ValueTimelineInputMode::None;
+
+        let file_path = self.input_text.trim();
+
+        if file_path.is_empty() {
+            self.status_message = "No export file path was provided".to_string();
+            None
+        } else {
+            Some(file_path.to_string())
+        }
+    }
+
+    pub fn move_selection(
+        &mut self,
+        timeline_delta: i64,
+    ) {
+        if self.value_timelines.is_empty() {
+            self.selected_timeline_index = None;
+            return;
+        }
+
+        let selected_timeline_index = self.selected_timeline_index.unwrap_or(0);
+
+        self.selected_timeline_index = Some(
+            selected_timeline_index
+                .saturating_add_signed(timeline_delta as isize)
+                .min(self.value_timelines.len() - 1),
+        );
+    }
+
+    pub fn selected_value_timeline(&self) -> Option<&ValueTimelineInfo> {
+        self.selected_timeline_index
+            .and_then(|selected_timeline_index| self.value_timelines.get(selected_timeline_index))
+    }
+
+    pub fn samples_for(
+        &self,
+        timeline_id: u32,
+    ) -> &[ValueTimelineSample] {
+        self.samples_by_timeline_id
+            .get(&timeline_id)
+            .map(|samples| samples.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Gets the timestamp of the newest retained sample for a timeline, such that only newer samples are queried.
+    pub fn latest_timestamp_ms(
+        &self,
+        timeline_id: u32,
+    ) -> Option<u64> {
+        self.samples_for(timeline_id)
+            .last()
+            .map(|sample| sample.get_timestamp_ms())
+    }
+
+    /// Replaces the listed timelines, preserving the selection and dropping samples of removed timelines.
+    pub fn apply_value_timelines(
+        &mut self,
+        value_timelines: Vec<ValueTimelineInfo>,
+    ) {
+        let selected_timeline_id = self
+            .selected_value_timeline()
+            .map(|value_timeline_info| value_timeline_info.timeline_id);
+
+        self.samples_by_timeline_id.retain(|timeline_id, _| {
+            value_timelines
+                .iter()
+                .any(|value_timeline_info| value_timeline_info.timeline_id == *timeline_id)
+        });
+        self.value_timelines = value_timelines;
+        self.selected_timeline_index = match selected_timeline_id {
+            Some(selected_timeline_id) => self
+                .value_timelines
+                .iter()
+                .position(|value_timeline_info| value_timeline_info.timeline_id == selected_timeline_id)
+                .or_else(|| (!self.value_timelines.is_empty()).then_some(0)),
+            None => (!self.value_timelines.is_empty()).then_some(0),
+        };
+    }
+
+    /// Appends newly queried samples to a timeline, retaining only the most recent samples.
+    pub fn append_samples(
+        &mut self,
+        timeline_id: u32,
+        samples: Vec<ValueTimelineSample>,
+    ) {
+        let retained_samples = self.samples_by_timeline_id.entry(timeline_id).or_default();
+        let latest_timestamp_ms = retained_samples.last().map(|sample| sample.get_timestamp_ms());
+
+        retained_samples.extend(
+            samples
+                .into_iter()
+                .filter(|sample| latest_timestamp_ms.is_none_or(|latest_timestamp_ms| sample.get_timestamp_ms() > latest_timestamp_ms)),
+        );
+
+        if retained_samples.len() > Self::MAX_RETAINED_SAMPLES {
+            retained_samples.drain(..retained_samples.len() - Self::MAX_RETAINED_SAMPLES);
+        }
+    }
+
+    /// Renders the most recent numeric samples as a row of block characters scaled between their minimum and maximum.
+    pub fn build_sparkline(
+        samples: &[ValueTimelineSample],
+        width: usize,
+    ) -> String {
+        let first_sample_index = samples.len().saturating_sub(width);
+        let numeric_values = samples[first_sample_index..]
+            .iter()
+            .map(|sample| sample.get_numeric_value())
+            .collect::<Vec<_>>();
+        let min_value = numeric_values
+            .iter()
+            .flatten()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        let max_value = numeric_values
+            .iter()
+            .flatten()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let value_range = max_value - min_value;
+        let max_level = Self::SPARKLINE_CHARACTERS.len() - 1;
+
+        numeric_values
+            .iter()
+            .map(|numeric_value| match numeric_value {
+                Some(numeric_value) if value_range > 0.0 => {
+                    let level = ((numeric_value - min_value) / value_range * max_level as f64).round() as usize;
+
+                    Self::SPARKLINE_CHARACTERS[level.min(max_level)]
+                }
+                Some(_) => Self::SPARKLINE_CHARACTERS[0],
+                None => ' ',
+            })
+            .collect()
+    }
+
+    pub fn summary_lines(&self) -> Vec<String> {
+        build_value_timeline_summary_lines(self)
+    }
+
+    pub fn visible_value_timeline_rows(
+        &self,
+        viewport_capacity: usize,
+    ) -> Vec<PaneEntryRow> {
+        build_visible_value_timeline_rows(self, viewport_capacity)
+    }
+}
+
+impl Default for ValueTimelinePaneState {
+    fn default() -> Self {
+        Self {
+            value_timelines: Vec::new(),
+            samples_by_timeline_id: HashMap::new(),
+            selected_timeline_index: None,
+            input_mode: ValueTimelineInputMode::None,
+            input_text: String::new(),
+            is_refreshing_value_timelines: false,
+            status_message: "Press a to record an address.".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ValueTimelineInputMode, ValueTimelinePaneState};
+    use squalr_engine_api::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+
+    fn create_samples(values: &[&str]) -> Vec<ValueTimelineSample> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(sample_index, value)| ValueTimelineSample::new(sample_index as u64, Some(value.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn record_input_parses_address_data_type_interval_and_label() {
+        let mut value_timeline_pane_state = ValueTimelinePaneState::default();
+
+        value_timeline_pane_state.begin_input(ValueTimelineInputMode::Record);
+        value_timeline_pane_state.input_text = "game.exe+1234 u32 50 player health".to_string();
+
+        let value_timeline_record_request = value_timeline_pane_state
+            .commit_record_input()
+            .expect("Expected the record input to parse.");
+
+        assert_eq!(value_timeline_record_request.module_name, "game.exe");
+        assert_eq!(value_timeline_record_request.address, 0x1234);
+        assert_eq!(value_timeline_record_request.data_type_ref.get_data_type_id(), "u32");
+        assert_eq!(value_timeline_record_request.interval_ms, 50);
+        assert_eq!(value_timeline_record_request.label, "player health");
+    }
+
+    #[test]
+    fn sparkline_scales_between_minimum_and_maximum() {
+        let samples = create_samples(&["0", "7", "14", "?"]);
+
+        assert_eq!(ValueTimelinePaneState::build_sparkline(&samples, 8), "▁▅█ ");
+        assert_eq!(ValueTimelinePaneState::build_sparkline(&samples, 2), "▁ ");
+    }
+
+    #[test]
+    fn appended_samples_skip_duplicates() {
+        let mut value_timeline_pane_state = ValueTimelinePaneState::default();
+
+        value_timeline_pane_state.append_samples(3, create_samples(&["1", "2"]));
+        value_timeline_pane_state.append_samples(3, create_samples(&["1", "2", "3"]));
+
+        assert_eq!(value_timeline_pane_state.samples_for(3).len(), 3);
+        assert_eq!(value_timeline_pane_state.latest_timestamp_ms(3), Some(2));
+    }
+}
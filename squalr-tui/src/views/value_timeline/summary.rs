@@ -0,0 +1,52 @@
+use crate::views::value_timeline::pane_state::{ValueTimelineInputMode, ValueTimelinePaneState};
+
+pub fn build_value_timeline_summary_lines(value_timeline_pane_state: &ValueTimelinePaneState) -> Vec<String> {
+    let input_line = match value_timeline_pane_state.input_mode {
+        ValueTimelineInputMode::None => "[INPUT] a record | e export.".to_string(),
+        ValueTimelineInputMode::Record => format!(
+            "[REC] {}_ (<address> <data type> [interval ms] [label] | Enter apply | Esc cancel).",
+            value_timeline_pane_state.input_text
+        ),
+        ValueTimelineInputMode::Export => format!("[CSV] {}_ (file path | Enter apply | Esc cancel).", value_timeline_pane_state.input_text),
+    };
+    let selected_line = match value_timeline_pane_state.selected_value_timeline() {
+        Some(value_timeline_info) => {
+            let samples = value_timeline_pane_state.samples_for(value_timeline_info.timeline_id);
+            let numeric_values = samples
+                .iter()
+                .filter_map(|sample| sample.get_numeric_value())
+                .collect::<Vec<_>>();
+            let min_value = numeric_values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max_value = numeric_values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+            if numeric_values.is_empty() {
+                format!(
+                    "[SEL] {} | samples={}/{} | every {}ms.",
+                    value_timeline_info.target.get_display_name(),
+                    value_timeline_info.sample_count,
+                    value_timeline_info.capacity,
+                    value_timeline_info.interval_ms
+                )
+            } else {
+                format!(
+                    "[SEL] {} | samples={}/{} | every {}ms | min={} | max={}.",
+                    value_timeline_info.target.get_display_name(),
+                    value_timeline_info.sample_count,
+                    value_timeline_info.capacity,
+                    value_timeline_info.interval_ms,
+                    min_value,
+                    max_value
+                )
+            }
+        }
+        None => "[SEL] none.".to_string(),
+    };
+
+    vec![
+        "[ACT] a record | x remove | e export csv | r refresh.".to_string(),
+        "[NAV] Up/Down select.".to_string(),
+        input_line,
+        selected_line,
+        format!("[STAT] {}.", value_timeline_pane_state.status_message),
+    ]
+}
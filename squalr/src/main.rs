@@ -11,14 +11,21 @@ use app::App;
 use eframe::NativeOptions;
 use eframe::egui::{IconData, ViewportBuilder};
 use squalr_engine::engine_mode::EngineMode;
-use squalr_engine::squalr_engine::SqualrEngine;
+use squalr_engine::squalr_engine::{SqualrEngine, SqualrEngineOptions};
 
 static ICON_APP: &[u8] = include_bytes!("../images/app/app_icon.png");
 static APP_NAME: &str = "Squalr";
 
 pub fn main() -> Result<()> {
-    // Create a standalone engine (same process for gui and engine).
-    let mut squalr_engine = SqualrEngine::new(EngineMode::Standalone).context("Fatal error initializing Squalr engine.")?;
+    // Create a standalone engine (same process for gui and engine). Hotkeys are listened for, as the GUI does not read console input.
+    let mut squalr_engine = SqualrEngine::new_with_options(
+        EngineMode::Standalone,
+        SqualrEngineOptions {
+            enable_project_hotkeys: true,
+            ..SqualrEngineOptions::default()
+        },
+    )
+    .context("Fatal error initializing Squalr engine.")?;
 
     let icon = image::load_from_memory(ICON_APP)
         .unwrap_or_default()
@@ -1,9 +1,11 @@
 use crate::models::docking::builder::dock_builder::DockBuilder;
 use crate::models::docking::hierarchy::dock_node::DockNode;
 use crate::models::docking::hierarchy::types::dock_split_direction::DockSplitDirection;
+use crate::views::disassembly::disassembly_view::DisassemblyView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
 use crate::views::element_scanner::scanner::element_scanner_view::ElementScannerView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
 use crate::views::output::output_view::OutputView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
 use crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView;
@@ -14,6 +16,7 @@ use crate::views::project_explorer::project_explorer_view::ProjectExplorerView;
 use crate::views::settings::settings_view::SettingsView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
 use crate::views::struct_viewer::struct_viewer_view::StructViewerView;
+use crate::views::value_timeline::value_timeline_view::ValueTimelineView;
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 use std::fs;
@@ -53,7 +56,14 @@ impl DockSettingsConfig {
                             )
                             .push_child(0.5, DockBuilder::window(StructViewerView::WINDOW_ID)),
                     )
-                    .push_child(0.5, DockBuilder::window(OutputView::WINDOW_ID)),
+                    .push_child(
+                        0.5,
+                        DockBuilder::tab_node(OutputView::WINDOW_ID)
+                            .push_tab(DockBuilder::window(OutputView::WINDOW_ID))
+                            .push_tab(DockBuilder::window(MemoryViewerView::WINDOW_ID))
+                            .push_tab(DockBuilder::window(DisassemblyView::WINDOW_ID))
+                            .push_tab(DockBuilder::window(ValueTimelineView::WINDOW_ID)),
+                    ),
             )
             .push_child(
                 0.4,
@@ -83,7 +93,14 @@ impl DockSettingsConfig {
                     ),
             )
             .push_child(0.25, DockBuilder::window(StructViewerView::WINDOW_ID))
-            .push_child(0.2, DockBuilder::window(OutputView::WINDOW_ID))
+            .push_child(
+                0.2,
+                DockBuilder::tab_node(OutputView::WINDOW_ID)
+                    .push_tab(DockBuilder::window(OutputView::WINDOW_ID))
+                    .push_tab(DockBuilder::window(MemoryViewerView::WINDOW_ID))
+                    .push_tab(DockBuilder::window(DisassemblyView::WINDOW_ID))
+                    .push_tab(DockBuilder::window(ValueTimelineView::WINDOW_ID)),
+            )
             .build();
 
         default_layout
@@ -79,10 +79,21 @@ impl<'lifetime> Widget for DataTypeSelectorView<'lifetime> {
         let element_width = 104.0;
         let data_type_id = self.active_data_type.get_data_type_id();
         let icon = DataTypeToIconConverter::convert_data_type_to_icon(data_type_id, icon_library);
+        let plugin_data_types = self
+            .app_context
+            .engine_unprivileged_state
+            .get_plugin_data_types();
+        let data_type_label = match plugin_data_types
+            .iter()
+            .find(|plugin_data_type| plugin_data_type.get_data_type_id() == data_type_id)
+        {
+            Some(plugin_data_type) => plugin_data_type.get_display_name(),
+            None => DataTypeToStringConverter::convert_data_type_to_string(data_type_id),
+        };
 
         let combo_box = ComboBoxView::new(
             self.app_context.clone(),
-            DataTypeToStringConverter::convert_data_type_to_string(data_type_id),
+            data_type_label,
             self.menu_id,
             Some(icon),
             |popup_user_interface: &mut Ui, should_close: &mut bool| {
@@ -387,6 +398,26 @@ impl<'lifetime> Widget for DataTypeSelectorView<'lifetime> {
                         };
                     });
 
+                    // Plugin-defined data types, mirrored from the engine as plugins are loaded.
+                    for plugin_data_type_row in plugin_data_types.chunks(2) {
+                        user_interface.horizontal(|user_interface| {
+                            for plugin_data_type in plugin_data_type_row {
+                                if user_interface
+                                    .add(DataTypeItemView::new(
+                                        self.app_context.clone(),
+                                        plugin_data_type.get_display_name(),
+                                        Some(DataTypeToIconConverter::convert_data_type_to_icon(plugin_data_type.get_icon_id(), icon_library)),
+                                        element_width,
+                                    ))
+                                    .clicked()
+                                {
+                                    *self.active_data_type = DataTypeRef::new(plugin_data_type.get_data_type_id());
+                                    *should_close = true;
+                                };
+                            }
+                        });
+                    }
+
                     user_interface.horizontal(|user_interface| {
                         if user_interface
                             .add(DataTypeItemView::new(
@@ -0,0 +1,232 @@
+use crate::app_context::AppContext;
+use crate::ui::draw::icon_draw::IconDraw;
+use crate::ui::widgets::controls::button::Button;
+use crate::views::disassembly::view_data::{disassembly_frame_action::DisassemblyFrameAction, disassembly_view_data::DisassemblyViewData};
+use eframe::egui::{Align, Align2, Key, Layout, Response, Sense, TextEdit, Ui, Widget};
+use epaint::{Color32, CornerRadius, Rect, pos2, vec2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DisassemblyView {
+    app_context: Arc<AppContext>,
+    disassembly_view_data: Dependency<DisassemblyViewData>,
+}
+
+impl DisassemblyView {
+    pub const WINDOW_ID: &'static str = "window_disassembly";
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let disassembly_view_data = app_context
+            .dependency_container
+            .register(DisassemblyViewData::new());
+
+        Self {
+            app_context,
+            disassembly_view_data,
+        }
+    }
+}
+
+impl Widget for DisassemblyView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        const TOOLBAR_HEIGHT: f32 = 32.0;
+        const ROW_HEIGHT: f32 = 20.0;
+        const ADDRESS_COLUMN_WIDTH: f32 = 220.0;
+        const BYTES_COLUMN_WIDTH: f32 = 200.0;
+        const INSTRUCTION_COLUMN_WIDTH: f32 = 320.0;
+        const TEXT_PADDING: f32 = 8.0;
+
+        let theme = &self.app_context.theme;
+        let font_id = theme.font_library.font_ubuntu_mono_bold.font_normal.clone();
+        let mut frame_action = DisassemblyFrameAction::None;
+
+        let response = user_interface
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                let mut disassembly_view_data = match self.disassembly_view_data.write("Disassembly view") {
+                    Some(disassembly_view_data) => disassembly_view_data,
+                    None => return,
+                };
+
+                // Draw the toolbar.
+                user_interface.allocate_ui_with_layout(
+                    vec2(user_interface.available_width(), TOOLBAR_HEIGHT),
+                    Layout::left_to_right(Align::Center),
+                    |user_interface| {
+                        let button_size = vec2(36.0, 28.0);
+
+                        user_interface.add_space(4.0);
+
+                        let button_back = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Go back to the previous address."),
+                        );
+                        IconDraw::draw(user_interface, button_back.rect, &theme.icon_library.icon_handle_navigation_left_arrow);
+
+                        if button_back.clicked() {
+                            frame_action = DisassemblyFrameAction::GoBack;
+                        }
+
+                        let address_response = user_interface.add_sized(
+                            vec2(240.0, 24.0),
+                            TextEdit::singleline(&mut disassembly_view_data.address_text)
+                                .hint_text("module+offset or address")
+                                .vertical_align(Align::Center)
+                                .font(font_id.clone())
+                                .background_color(theme.background_primary)
+                                .text_color(theme.foreground),
+                        );
+
+                        if address_response.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                            frame_action = DisassemblyFrameAction::GoToAddress;
+                        }
+
+                        let button_go = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Disassemble at address."),
+                        );
+                        IconDraw::draw(user_interface, button_go.rect, &theme.icon_library.icon_handle_navigation_right_arrow);
+
+                        if button_go.clicked() {
+                            frame_action = DisassemblyFrameAction::GoToAddress;
+                        }
+
+                        let button_refresh = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Refresh disassembly."),
+                        );
+                        IconDraw::draw(user_interface, button_refresh.rect, &theme.icon_library.icon_handle_navigation_refresh);
+
+                        if button_refresh.clicked() {
+                            frame_action = DisassemblyFrameAction::Refresh;
+                        }
+
+                        if let Some(instruction_set) = disassembly_view_data.instruction_set {
+                            user_interface.add_space(8.0);
+                            user_interface.label(instruction_set.to_string());
+                        }
+                    },
+                );
+
+                // Draw the instruction rows.
+                let content_rect = user_interface.available_rect_before_wrap();
+                let content_response = user_interface.interact(content_rect, user_interface.id().with("disassembly_content"), Sense::hover());
+                let bytes_column_x = content_rect.min.x + ADDRESS_COLUMN_WIDTH;
+                let instruction_column_x = bytes_column_x + BYTES_COLUMN_WIDTH;
+                let target_column_x = instruction_column_x + INSTRUCTION_COLUMN_WIDTH;
+
+                if content_response.hovered() {
+                    let scroll_delta = user_interface.input(|input| input.raw_scroll_delta.y);
+
+                    if scroll_delta != 0.0 {
+                        frame_action = DisassemblyFrameAction::Page(scroll_delta < 0.0);
+                    }
+                }
+
+                let painter = user_interface.painter().clone();
+
+                for (instruction_index, instruction) in disassembly_view_data.instructions.iter().enumerate() {
+                    let row_min_y = content_rect.min.y + instruction_index as f32 * ROW_HEIGHT;
+
+                    if row_min_y + ROW_HEIGHT > content_rect.max.y {
+                        break;
+                    }
+
+                    let row_rect = Rect::from_min_size(pos2(content_rect.min.x, row_min_y), vec2(content_rect.width(), ROW_HEIGHT));
+                    let row_center_y = row_rect.center().y;
+                    let row_response = user_interface.interact(row_rect, user_interface.id().with(("disassembly_row", instruction_index)), Sense::click());
+
+                    if disassembly_view_data.selected_instruction_index == Some(instruction_index) {
+                        painter.rect_filled(row_rect, CornerRadius::ZERO, theme.selected_background);
+                    }
+
+                    let address_color = if instruction.get_address() == disassembly_view_data.resolved_address {
+                        theme.background_control_warning
+                    } else {
+                        theme.foreground_preview
+                    };
+                    let instruction_color = if instruction.is_valid() { theme.foreground } else { theme.foreground_preview };
+
+                    painter.text(
+                        pos2(row_rect.min.x + TEXT_PADDING, row_center_y),
+                        Align2::LEFT_CENTER,
+                        DisassemblyViewData::format_instruction_address(instruction),
+                        font_id.clone(),
+                        address_color,
+                    );
+                    painter.text(
+                        pos2(bytes_column_x, row_center_y),
+                        Align2::LEFT_CENTER,
+                        DisassemblyViewData::format_instruction_bytes(instruction),
+                        font_id.clone(),
+                        theme.foreground_preview,
+                    );
+                    painter.text(
+                        pos2(instruction_column_x, row_center_y),
+                        Align2::LEFT_CENTER,
+                        instruction.get_text(),
+                        font_id.clone(),
+                        instruction_color,
+                    );
+
+                    if let Some(target_location) = instruction.get_target_location() {
+                        painter.text(
+                            pos2(target_column_x, row_center_y),
+                            Align2::LEFT_CENTER,
+                            format!("; {}", target_location),
+                            font_id.clone(),
+                            theme.hexadecimal_green,
+                        );
+                    }
+
+                    if row_response.double_clicked() {
+                        frame_action = DisassemblyFrameAction::FollowTarget(instruction_index);
+                    } else if row_response.clicked() {
+                        frame_action = DisassemblyFrameAction::SelectInstruction(instruction_index);
+                    }
+                }
+            })
+            .response;
+
+        match frame_action {
+            DisassemblyFrameAction::None => {}
+            DisassemblyFrameAction::GoToAddress => {
+                DisassemblyViewData::go_to_address(self.disassembly_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            DisassemblyFrameAction::GoBack => {
+                DisassemblyViewData::go_back(self.disassembly_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            DisassemblyFrameAction::Refresh => {
+                DisassemblyViewData::refresh_disassembly(self.disassembly_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            DisassemblyFrameAction::Page(is_forward) => {
+                DisassemblyViewData::page(
+                    self.disassembly_view_data.clone(),
+                    self.app_context.engine_unprivileged_state.clone(),
+                    is_forward,
+                );
+            }
+            DisassemblyFrameAction::SelectInstruction(instruction_index) => {
+                DisassemblyViewData::select_instruction(self.disassembly_view_data.clone(), instruction_index);
+            }
+            DisassemblyFrameAction::FollowTarget(instruction_index) => {
+                DisassemblyViewData::follow_target(
+                    self.disassembly_view_data.clone(),
+                    self.app_context.engine_unprivileged_state.clone(),
+                    instruction_index,
+                );
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,2 @@
+pub mod disassembly_view;
+pub mod view_data;
@@ -0,0 +1,10 @@
+#[derive(Clone, PartialEq)]
+pub enum DisassemblyFrameAction {
+    None,
+    GoToAddress,
+    GoBack,
+    Refresh,
+    Page(bool),
+    SelectInstruction(usize),
+    FollowTarget(usize),
+}
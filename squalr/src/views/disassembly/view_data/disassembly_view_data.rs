@@ -0,0 +1,265 @@
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_request::MemoryDisassembleRequest;
+use squalr_engine_api::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+use squalr_engine_api::structures::disassembly::instruction_set::InstructionSet;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_session::engine_unprivileged_state::EngineUnprivilegedState;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DisassemblyViewData {
+    pub address_text: String,
+    pub target_expression: String,
+    pub target_history: Vec<String>,
+    pub resolved_address: u64,
+    pub instruction_set: Option<InstructionSet>,
+    pub instructions: Vec<DisassembledInstruction>,
+    pub selected_instruction_index: Option<usize>,
+    pub is_disassembling: bool,
+}
+
+impl DisassemblyViewData {
+    pub const INSTRUCTIONS_BEFORE: u32 = 16;
+    pub const INSTRUCTION_COUNT: u32 = 64;
+    const MAX_HISTORY_LENGTH: usize = 64;
+
+    pub fn new() -> Self {
+        Self {
+            address_text: String::new(),
+            target_expression: String::new(),
+            target_history: Vec::new(),
+            resolved_address: 0,
+            instruction_set: None,
+            instructions: Vec::new(),
+            selected_instruction_index: None,
+            is_disassembling: false,
+        }
+    }
+
+    pub fn refresh_disassembly(
+        disassembly_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let memory_disassemble_request = match disassembly_view_data.write("Disassembly refresh") {
+            Some(mut disassembly_view_data) => {
+                if disassembly_view_data.is_disassembling || disassembly_view_data.target_expression.is_empty() {
+                    return;
+                }
+
+                disassembly_view_data.is_disassembling = true;
+
+                MemoryDisassembleRequest {
+                    address: 0,
+                    module_name: String::new(),
+                    address_expression: disassembly_view_data.target_expression.clone(),
+                    instruction_count: Self::INSTRUCTION_COUNT,
+                    instructions_before: Self::INSTRUCTIONS_BEFORE,
+                    instruction_set: None,
+                }
+            }
+            None => return,
+        };
+        let disassembly_view_data_clone = disassembly_view_data.clone();
+
+        let did_send = memory_disassemble_request.send(&engine_unprivileged_state, move |memory_disassemble_response| {
+            if let Some(mut disassembly_view_data) = disassembly_view_data_clone.write("Disassembly refresh response") {
+                disassembly_view_data.is_disassembling = false;
+                disassembly_view_data.apply_disassemble_response(memory_disassemble_response);
+            }
+        });
+
+        if did_send {
+            return;
+        }
+
+        if let Some(mut disassembly_view_data) = disassembly_view_data.write("Disassembly refresh send failure") {
+            disassembly_view_data.is_disassembling = false;
+        }
+    }
+
+    /// Parses the go-to address expression and disassembles around the resulting address.
+    pub fn go_to_address(
+        disassembly_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        match disassembly_view_data.write("Disassembly go to address") {
+            Some(mut disassembly_view_data) => match AddressExpression::parse(&disassembly_view_data.address_text) {
+                Ok(address_expression) => disassembly_view_data.push_target(address_expression.to_string()),
+                Err(error) => {
+                    log::warn!("Invalid disassembly address '{}': {}", disassembly_view_data.address_text, error);
+                    return;
+                }
+            },
+            None => return,
+        }
+
+        Self::refresh_disassembly(disassembly_view_data, engine_unprivileged_state);
+    }
+
+    /// Disassembles around the branch target or memory address referenced by an instruction.
+    pub fn follow_target(
+        disassembly_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        instruction_index: usize,
+    ) {
+        match disassembly_view_data.write("Disassembly follow target") {
+            Some(mut disassembly_view_data) => {
+                let target_address = match disassembly_view_data
+                    .instructions
+                    .get(instruction_index)
+                    .and_then(|instruction| instruction.get_target_address())
+                {
+                    Some(target_address) => target_address,
+                    None => return,
+                };
+
+                disassembly_view_data.push_target(format!("0x{:X}", target_address));
+            }
+            None => return,
+        }
+
+        Self::refresh_disassembly(disassembly_view_data, engine_unprivileged_state);
+    }
+
+    /// Returns to the previously viewed target, if any.
+    pub fn go_back(
+        disassembly_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        match disassembly_view_data.write("Disassembly go back") {
+            Some(mut disassembly_view_data) => match disassembly_view_data.target_history.pop() {
+                Some(target_expression) => disassembly_view_data.set_target(target_expression),
+                None => return,
+            },
+            None => return,
+        }
+
+        Self::refresh_disassembly(disassembly_view_data, engine_unprivileged_state);
+    }
+
+    /// Re-targets the listing at its first or last instruction, such that scrolling continues past the current listing.
+    pub fn page(
+        disassembly_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        is_forward: bool,
+    ) {
+        match disassembly_view_data.write("Disassembly page") {
+            Some(mut disassembly_view_data) => {
+                let page_instruction = if is_forward {
+                    disassembly_view_data.instructions.last()
+                } else {
+                    disassembly_view_data.instructions.first()
+                };
+                let page_address = match page_instruction {
+                    Some(page_instruction) => page_instruction.get_address(),
+                    None => return,
+                };
+
+                disassembly_view_data.set_target(format!("0x{:X}", page_address));
+            }
+            None => return,
+        }
+
+        Self::refresh_disassembly(disassembly_view_data, engine_unprivileged_state);
+    }
+
+    pub fn select_instruction(
+        disassembly_view_data: Dependency<Self>,
+        instruction_index: usize,
+    ) {
+        if let Some(mut disassembly_view_data) = disassembly_view_data.write("Disassembly select instruction") {
+            disassembly_view_data.selected_instruction_index = Some(instruction_index);
+        }
+    }
+
+    /// Formats the address of an instruction, preferring its module and offset.
+    pub fn format_instruction_address(instruction: &DisassembledInstruction) -> String {
+        match instruction.get_location() {
+            Some(location) => location.to_string(),
+            None => format!("{:016X}", instruction.get_address()),
+        }
+    }
+
+    pub fn format_instruction_bytes(instruction: &DisassembledInstruction) -> String {
+        instruction
+            .get_bytes()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn apply_disassemble_response(
+        &mut self,
+        memory_disassemble_response: MemoryDisassembleResponse,
+    ) {
+        self.resolved_address = memory_disassemble_response.address;
+        self.instruction_set = memory_disassemble_response.instruction_set;
+        self.instructions = memory_disassemble_response.instructions;
+        self.selected_instruction_index = self
+            .instructions
+            .iter()
+            .position(|instruction| instruction.get_address() == self.resolved_address);
+    }
+
+    fn push_target(
+        &mut self,
+        target_expression: String,
+    ) {
+        if !self.target_expression.is_empty() && self.target_expression != target_expression {
+            if self.target_history.len() >= Self::MAX_HISTORY_LENGTH {
+                self.target_history.remove(0);
+            }
+
+            self.target_history.push(self.target_expression.clone());
+        }
+
+        self.set_target(target_expression);
+    }
+
+    fn set_target(
+        &mut self,
+        target_expression: String,
+    ) {
+        self.address_text = target_expression.clone();
+        self.target_expression = target_expression;
+        self.instructions.clear();
+        self.selected_instruction_index = None;
+    }
+}
+
+impl Default for DisassemblyViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisassemblyViewData;
+    use squalr_engine_api::commands::memory::disassemble::memory_disassemble_response::MemoryDisassembleResponse;
+    use squalr_engine_api::structures::disassembly::disassembled_instruction::DisassembledInstruction;
+
+    #[test]
+    fn apply_disassemble_response_selects_the_target_instruction_and_targets_are_remembered() {
+        let mut disassembly_view_data = DisassemblyViewData::new();
+
+        disassembly_view_data.push_target("game.exe+10".to_string());
+        disassembly_view_data.push_target("0x2000".to_string());
+        disassembly_view_data.apply_disassemble_response(MemoryDisassembleResponse {
+            address: 0x2001,
+            instruction_set: None,
+            instructions: vec![
+                DisassembledInstruction::new(0x2000, vec![0x90], "nop".to_string(), String::new(), true, None),
+                DisassembledInstruction::new(0x2001, vec![0xC3], "ret".to_string(), String::new(), true, None),
+            ],
+            success: true,
+        });
+
+        assert_eq!(disassembly_view_data.selected_instruction_index, Some(1));
+        assert_eq!(disassembly_view_data.target_history, vec!["game.exe+10".to_string()]);
+        assert_eq!(disassembly_view_data.address_text, "0x2000");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod disassembly_frame_action;
+pub mod disassembly_view_data;
@@ -147,7 +147,9 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
         let icon_pos = pos2(self.address_splitter_position_x + text_left_padding, row_center_y - icon_size.y * 0.5);
         let address_text_position = pos2(icon_pos.x + icon_size.x + 6.0, row_center_y);
         let address = self.scan_result.get_address();
-        let address_string = if self.scan_result.is_module() {
+        let address_string = if !self.scan_result.get_symbol_label().is_empty() {
+            self.scan_result.get_symbol_label().to_string()
+        } else if self.scan_result.is_module() {
             format!("{}+{:X}", self.scan_result.get_module(), self.scan_result.get_module_offset())
         } else if address <= u32::MAX as u64 {
             format!("{:08X}", address)
@@ -16,9 +16,12 @@ use crate::{
         struct_viewer::view_data::struct_viewer_view_data::StructViewerViewData,
     },
 };
-use eframe::egui::{Align, Align2, CursorIcon, Direction, Layout, Response, ScrollArea, Sense, Spinner, Ui, Widget};
+use eframe::egui::{Align, Align2, CursorIcon, Direction, Key, Layout, Response, ScrollArea, Sense, Spinner, TextEdit, Ui, Widget};
 use epaint::{Margin, Rect, Vec2, pos2, vec2};
-use squalr_engine_api::{dependency_injection::dependency::Dependency, structures::scan_results::scan_result::ScanResult};
+use squalr_engine_api::{
+    dependency_injection::dependency::Dependency,
+    structures::scan_results::{scan_result::ScanResult, scan_results_sort_key::ScanResultsSortKey},
+};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -172,40 +175,107 @@ impl Widget for ElementScannerResultsView {
                     &self.app_context.theme.icon_library.icon_handle_results_freeze,
                 );
 
-                // Address column header.
+                // Column headers sort the results when clicked, showing the direction of the active sort key.
                 let text_left_padding = 8.0;
-                let address_header_x = faux_address_splitter_position_x + text_left_padding;
-                let address_header_position = pos2(address_header_x, header_rectangle.center().y);
-
-                user_interface.painter().text(
-                    address_header_position,
-                    Align2::LEFT_CENTER,
-                    "Address",
-                    theme.font_library.font_noto_sans.font_header.clone(),
-                    theme.foreground,
-                );
-
-                // Value column header.
-                let value_label_position = pos2(value_splitter_position_x + text_left_padding, header_rectangle.center().y);
+                let (active_sort_key, is_sort_descending) = match self
+                    .element_scanner_results_view_data
+                    .read("Element scanner results view sort key")
+                {
+                    Some(element_scanner_results_view_data) => (
+                        element_scanner_results_view_data.query_options.sort_key,
+                        element_scanner_results_view_data.query_options.sort_descending,
+                    ),
+                    None => return,
+                };
+                let column_headers = [
+                    (
+                        "Address",
+                        ScanResultsSortKey::Address,
+                        faux_address_splitter_position_x,
+                        value_splitter_position_x,
+                    ),
+                    (
+                        "Value",
+                        ScanResultsSortKey::CurrentValue,
+                        value_splitter_position_x,
+                        previous_value_splitter_position_x,
+                    ),
+                    (
+                        "Previous Value",
+                        ScanResultsSortKey::Delta,
+                        previous_value_splitter_position_x,
+                        header_rectangle.max.x,
+                    ),
+                ];
+                let mut address_header_text_max_x = faux_address_splitter_position_x;
+
+                for (header_text, sort_key, column_min_x, column_max_x) in column_headers {
+                    let header_text = if sort_key != active_sort_key {
+                        header_text.to_string()
+                    } else if is_sort_descending {
+                        format!("{} ▼", header_text)
+                    } else {
+                        format!("{} ▲", header_text)
+                    };
+                    let header_column_rectangle = Rect::from_min_max(pos2(column_min_x, header_rectangle.min.y), pos2(column_max_x, header_rectangle.max.y));
+                    let header_text_rectangle = user_interface.painter().text(
+                        pos2(column_min_x + text_left_padding, header_rectangle.center().y),
+                        Align2::LEFT_CENTER,
+                        header_text,
+                        theme.font_library.font_noto_sans.font_header.clone(),
+                        theme.foreground,
+                    );
 
-                user_interface.painter().text(
-                    value_label_position,
-                    Align2::LEFT_CENTER,
-                    "Value",
-                    theme.font_library.font_noto_sans.font_header.clone(),
-                    theme.foreground,
-                );
+                    if sort_key == ScanResultsSortKey::Address {
+                        address_header_text_max_x = header_text_rectangle.max.x;
+                    }
+
+                    let header_text_response = user_interface
+                        .interact(
+                            header_text_rectangle.intersect(header_column_rectangle),
+                            user_interface
+                                .id()
+                                .with(("element_scanner_results_sort", sort_key)),
+                            Sense::click(),
+                        )
+                        .on_hover_cursor(CursorIcon::PointingHand)
+                        .on_hover_text("Sort results. Click again to reverse the order.");
+
+                    if header_text_response.clicked() {
+                        element_sanner_result_frame_action = ElementScannerResultFrameAction::SortBy(sort_key);
+                    }
+                }
 
-                // Previous value column header.
-                let previous_value_label_position = pos2(previous_value_splitter_position_x + text_left_padding, header_rectangle.center().y);
+                // Query filter, which sits in the remaining space of the address column header.
+                let query_filter_min_x = address_header_text_max_x + text_left_padding;
+                let query_filter_max_x = value_splitter_position_x - text_left_padding;
+
+                let query_filter_view_data = (query_filter_max_x - query_filter_min_x >= MINIMUM_SPLITTER_PIXEL_GAP)
+                    .then(|| {
+                        self.element_scanner_results_view_data
+                            .write("Element scanner results view query filter")
+                    })
+                    .flatten();
+
+                if let Some(mut element_scanner_results_view_data) = query_filter_view_data {
+                    let query_filter_rectangle = Rect::from_center_size(
+                        pos2((query_filter_min_x + query_filter_max_x) * 0.5, header_rectangle.center().y),
+                        vec2(query_filter_max_x - query_filter_min_x, 24.0),
+                    );
+                    let query_filter_response = user_interface.put(
+                        query_filter_rectangle,
+                        TextEdit::singleline(&mut element_scanner_results_view_data.query_filter_text)
+                            .hint_text("Filter, ie > 100 or game.exe+1a0")
+                            .vertical_align(Align::Center)
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .background_color(theme.background_primary)
+                            .text_color(theme.foreground),
+                    );
 
-                user_interface.painter().text(
-                    previous_value_label_position,
-                    Align2::LEFT_CENTER,
-                    "Previous Value",
-                    theme.font_library.font_noto_sans.font_header.clone(),
-                    theme.foreground,
-                );
+                    if query_filter_response.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                        element_sanner_result_frame_action = ElementScannerResultFrameAction::ApplyQueryFilter;
+                    }
+                }
 
                 // Assume all false.
                 let mut selection_freeze_checkstate = CheckState::False;
@@ -447,6 +517,19 @@ impl Widget for ElementScannerResultsView {
                         edit_value,
                     );
                 }
+                ElementScannerResultFrameAction::SortBy(sort_key) => {
+                    ElementScannerResultsViewData::sort_scan_results_by(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
+                        sort_key,
+                    );
+                }
+                ElementScannerResultFrameAction::ApplyQueryFilter => {
+                    ElementScannerResultsViewData::apply_query_filter_text(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
+                    );
+                }
             }
         }
 
@@ -1,4 +1,5 @@
 use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
 
 #[derive(Clone, PartialEq)]
 pub enum ElementScannerResultFrameAction {
@@ -11,4 +12,6 @@ pub enum ElementScannerResultFrameAction {
     AddScanResult(i32),
     DeleteSelection,
     CommitValueToSelection(AnonymousValueString),
+    SortBy(ScanResultsSortKey),
+    ApplyQueryFilter,
 }
@@ -9,8 +9,12 @@ use squalr_engine_api::dependency_injection::write_guard::WriteGuard;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::freezing::freeze_policy::FreezePolicy;
 use squalr_engine_api::structures::scan_results::scan_result_base::ScanResultBase;
 use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use squalr_engine_api::structures::scan_results::scan_results_query_options::ScanResultsQueryOptions;
+use squalr_engine_api::structures::scan_results::scan_results_sort_key::ScanResultsSortKey;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_expression::AnonymousScanConstraintExpression;
 use squalr_engine_api::{
     commands::{
         privileged_command_request::PrivilegedCommandRequest, scan_results::query::scan_results_query_request::ScanResultsQueryRequest,
@@ -24,6 +28,7 @@ use squalr_engine_api::{
 use squalr_engine_session::engine_unprivileged_state::EngineUnprivilegedState;
 use std::ops::RangeInclusive;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::{
     thread,
@@ -50,6 +55,8 @@ pub struct ElementScannerResultsViewData {
     pub results_read_interval_ms: u64,
     pub is_querying_scan_settings: bool,
     pub last_scan_settings_sync_timestamp: Option<Instant>,
+    pub query_options: ScanResultsQueryOptions,
+    pub query_filter_text: String,
 }
 
 impl ElementScannerResultsViewData {
@@ -78,6 +85,8 @@ impl ElementScannerResultsViewData {
             results_read_interval_ms: ScanSettings::default().results_read_interval_ms,
             is_querying_scan_settings: false,
             last_scan_settings_sync_timestamp: None,
+            query_options: ScanResultsQueryOptions::default(),
+            query_filter_text: String::new(),
         }
     }
 
@@ -253,7 +262,10 @@ impl ElementScannerResultsViewData {
             None => return,
         };
         let page_index = Self::load_current_page_index_write(&element_scanner_results_view_data);
-        let scan_results_query_request = ScanResultsQueryRequest { page_index };
+        let scan_results_query_request = ScanResultsQueryRequest {
+            page_index,
+            query_options: element_scanner_results_view_data.query_options.clone(),
+        };
 
         element_scanner_results_view_data.is_querying_scan_results = true;
 
@@ -428,6 +440,79 @@ impl ElementScannerResultsViewData {
         Self::query_scan_results(element_scanner_results_view_data_clone, engine_unprivileged_state, false);
     }
 
+    /// Sorts results by the given key. Sorting by the active key again flips the sort direction.
+    pub fn sort_scan_results_by(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        sort_key: ScanResultsSortKey,
+    ) {
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let mut element_scanner_results_view_data = match element_scanner_results_view_data.write("Sort scan results") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
+        };
+
+        if element_scanner_results_view_data.query_options.sort_key == sort_key {
+            element_scanner_results_view_data.query_options.sort_descending = !element_scanner_results_view_data.query_options.sort_descending;
+        } else {
+            element_scanner_results_view_data.query_options.sort_key = sort_key;
+            element_scanner_results_view_data.query_options.sort_descending = false;
+        }
+
+        Self::reset_query_page(&mut element_scanner_results_view_data);
+
+        // Drop to commit the write.
+        drop(element_scanner_results_view_data);
+
+        Self::query_scan_results(element_scanner_results_view_data_clone, engine_unprivileged_state, false);
+    }
+
+    /// Applies the query filter text to the results. Text forming a constraint expression (ie `> 100 && != previous`) filters values,
+    /// and any other text is searched for in result addresses and module offsets.
+    pub fn apply_query_filter_text(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let mut element_scanner_results_view_data = match element_scanner_results_view_data.write("Apply scan results query filter") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
+        };
+        let query_filter_text = element_scanner_results_view_data
+            .query_filter_text
+            .trim()
+            .to_string();
+
+        if query_filter_text.is_empty() {
+            element_scanner_results_view_data.query_options.value_filter = None;
+            element_scanner_results_view_data.query_options.address_search = None;
+        } else {
+            match AnonymousScanConstraintExpression::from_str(&query_filter_text) {
+                Ok(value_filter) => {
+                    element_scanner_results_view_data.query_options.value_filter = Some(value_filter);
+                    element_scanner_results_view_data.query_options.address_search = None;
+                }
+                Err(_) => {
+                    element_scanner_results_view_data.query_options.value_filter = None;
+                    element_scanner_results_view_data.query_options.address_search = Some(query_filter_text);
+                }
+            }
+        }
+
+        Self::reset_query_page(&mut element_scanner_results_view_data);
+
+        // Drop to commit the write.
+        drop(element_scanner_results_view_data);
+
+        Self::query_scan_results(element_scanner_results_view_data_clone, engine_unprivileged_state, false);
+    }
+
+    fn reset_query_page(element_scanner_results_view_data: &mut WriteGuard<'_, ElementScannerResultsViewData>) {
+        element_scanner_results_view_data.current_page_index = 0;
+        element_scanner_results_view_data.selection_index_start = None;
+        element_scanner_results_view_data.selection_index_end = None;
+    }
+
     pub fn set_page_index_string(
         element_scanner_results_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
@@ -624,7 +709,11 @@ impl ElementScannerResultsViewData {
 
         if !scan_result_refs.is_empty() {
             let engine_unprivileged_state = &engine_unprivileged_state;
-            let scan_results_freeze_request = ScanResultsFreezeRequest { scan_result_refs, is_frozen };
+            let scan_results_freeze_request = ScanResultsFreezeRequest {
+                scan_result_refs,
+                is_frozen,
+                freeze_policy: FreezePolicy::default(),
+            };
 
             scan_results_freeze_request.send(engine_unprivileged_state, move |scan_results_freeze_response| {
                 let mut element_scanner_results_view_data =
@@ -682,7 +771,11 @@ impl ElementScannerResultsViewData {
 
         if !scan_result_refs.is_empty() {
             let engine_unprivileged_state = &engine_unprivileged_state;
-            let scan_results_freeze_request = ScanResultsFreezeRequest { scan_result_refs, is_frozen };
+            let scan_results_freeze_request = ScanResultsFreezeRequest {
+                scan_result_refs,
+                is_frozen,
+                freeze_policy: FreezePolicy::default(),
+            };
 
             scan_results_freeze_request.send(engine_unprivileged_state, move |scan_results_freeze_response| {
                 let mut element_scanner_results_view_data =
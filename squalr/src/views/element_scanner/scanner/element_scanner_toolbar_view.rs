@@ -9,7 +9,7 @@ use crate::{
     },
     views::element_scanner::scanner::view_data::element_scanner_view_data::ElementScannerViewData,
 };
-use eframe::egui::{Align, Layout, Response, Sense, Ui, UiBuilder, Widget};
+use eframe::egui::{Align, Align2, Layout, Response, Sense, Ui, UiBuilder, Widget};
 use epaint::{Color32, CornerRadius, vec2};
 use squalr_engine_api::{dependency_injection::dependency::Dependency, structures::scanning::comparisons::scan_compare_type::ScanCompareType};
 use std::sync::Arc;
@@ -153,6 +153,56 @@ impl Widget for ElementScannerToolbarView {
 
             toolbar_user_interface.allocate_ui(vec2(toolbar_user_interface.available_width(), constraint_row_height), |user_interface| {
                 user_interface.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
+                    let operator_font_id = theme.font_library.font_noto_sans.font_normal.clone();
+
+                    // Boolean operator joining this constraint to the previous constraint. AND binds tighter than OR.
+                    user_interface.add_space(8.0);
+
+                    if index == 0 {
+                        user_interface.add_space(button_size.x + user_interface.spacing().item_spacing.x);
+                    } else {
+                        let button_join_operator = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Toggle between AND / OR with the previous constraint."),
+                        );
+                        let join_operator_text = if scan_values_and_constraint.is_or_with_previous { "OR" } else { "AND" };
+
+                        user_interface.painter().text(
+                            button_join_operator.rect.center(),
+                            Align2::CENTER_CENTER,
+                            join_operator_text,
+                            operator_font_id.clone(),
+                            theme.foreground,
+                        );
+
+                        if button_join_operator.clicked() {
+                            scan_values_and_constraint.is_or_with_previous = !scan_values_and_constraint.is_or_with_previous;
+                        }
+                    }
+
+                    // Negation of this constraint.
+                    let button_negate = user_interface.add_sized(
+                        button_size,
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .with_tooltip_text("Toggle NOT for this constraint."),
+                    );
+                    let negate_color = if scan_values_and_constraint.is_negated {
+                        theme.foreground
+                    } else {
+                        theme.foreground_preview
+                    };
+
+                    user_interface
+                        .painter()
+                        .text(button_negate.rect.center(), Align2::CENTER_CENTER, "NOT", operator_font_id, negate_color);
+
+                    if button_negate.clicked() {
+                        scan_values_and_constraint.is_negated = !scan_values_and_constraint.is_negated;
+                    }
+
                     // Scan compare type selector.
                     user_interface.add_space(8.0);
                     user_interface.add(ScanCompareTypeSelectorView::new(
@@ -7,6 +7,8 @@ use squalr_engine_api::structures::{
 pub struct ElementScannerValueViewData {
     pub selected_scan_compare_type: ScanCompareType,
     pub current_scan_value: AnonymousValueString,
+    pub is_negated: bool,
+    pub is_or_with_previous: bool,
     pub menu_id: String,
 }
 
@@ -15,6 +17,8 @@ impl ElementScannerValueViewData {
         Self {
             selected_scan_compare_type: ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
             current_scan_value: AnonymousValueString::new(String::new(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            is_negated: false,
+            is_or_with_previous: false,
             menu_id,
         }
     }
@@ -15,7 +15,10 @@ use squalr_engine_api::{
         data_values::anonymous_value_string_format::AnonymousValueStringFormat,
         scanning::{
             comparisons::{scan_compare_type::ScanCompareType, scan_compare_type_immediate::ScanCompareTypeImmediate},
-            constraints::anonymous_scan_constraint::AnonymousScanConstraint,
+            constraints::{
+                anonymous_scan_constraint::AnonymousScanConstraint,
+                scan_constraint_expression::{AnonymousScanConstraintExpression, ScanConstraintExpression},
+            },
         },
     },
 };
@@ -131,14 +134,8 @@ impl ElementScannerViewData {
         };
         let data_type_refs = vec![element_scanner_view_data.selected_data_type.clone()];
         let scan_constraints = element_scanner_view_data
-            .scan_values_and_constraints
-            .iter()
-            .map(|scan_value_and_constraint| {
-                AnonymousScanConstraint::new(
-                    scan_value_and_constraint.selected_scan_compare_type,
-                    Some(scan_value_and_constraint.current_scan_value.clone()),
-                )
-            })
+            .build_scan_constraint_expression()
+            .into_iter()
             .collect();
         let element_scan_request = ElementScanRequest {
             scan_constraints,
@@ -207,6 +204,36 @@ impl ElementScannerViewData {
             .remove(index);
     }
 
+    /// Builds a scan constraint expression from the constraint rows. Rows are joined with AND unless marked as OR with the previous row,
+    /// and AND binds tighter than OR, such that rows `a`, `b`, `|| c` scan for `(a && b) || c`.
+    fn build_scan_constraint_expression(&self) -> Option<AnonymousScanConstraintExpression> {
+        let mut or_operands: Vec<Vec<AnonymousScanConstraintExpression>> = vec![];
+
+        for (index, scan_value_and_constraint) in self.scan_values_and_constraints.iter().enumerate() {
+            let anonymous_scan_constraint = AnonymousScanConstraint::new(
+                scan_value_and_constraint.selected_scan_compare_type,
+                Some(scan_value_and_constraint.current_scan_value.clone()),
+            );
+            let scan_constraint_expression = if scan_value_and_constraint.is_negated {
+                ScanConstraintExpression::Not(Box::new(anonymous_scan_constraint.into()))
+            } else {
+                anonymous_scan_constraint.into()
+            };
+
+            match or_operands.last_mut() {
+                Some(and_operands) if index == 0 || !scan_value_and_constraint.is_or_with_previous => and_operands.push(scan_constraint_expression),
+                _ => or_operands.push(vec![scan_constraint_expression]),
+            }
+        }
+
+        ScanConstraintExpression::any(
+            or_operands
+                .into_iter()
+                .filter_map(ScanConstraintExpression::all)
+                .collect(),
+        )
+    }
+
     fn create_menu_id(index: usize) -> String {
         format!("element_scanner_data_type_selector_{}", index)
     }
@@ -2,13 +2,16 @@ use crate::models::toolbar::toolbar_data::ToolbarData;
 use crate::models::toolbar::toolbar_header_item_data::ToolbarHeaderItemData;
 use crate::models::toolbar::toolbar_menu_item_data::ToolbarMenuItemData;
 use crate::ui::widgets::controls::toolbar_menu::toolbar_view::ToolbarView;
+use crate::views::disassembly::disassembly_view::DisassemblyView;
 use crate::views::element_scanner::scanner::element_scanner_view::ElementScannerView;
+use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
 use crate::views::output::output_view::OutputView;
 use crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView;
 use crate::views::process_selector::process_selector_view::ProcessSelectorView;
 use crate::views::project_explorer::project_explorer_view::ProjectExplorerView;
 use crate::views::settings::settings_view::SettingsView;
 use crate::views::struct_viewer::struct_viewer_view::StructViewerView;
+use crate::views::value_timeline::value_timeline_view::ValueTimelineView;
 use crate::{app_context::AppContext, models::docking::settings::dockable_window_settings::DockSettingsConfig};
 use eframe::egui::viewport::ViewportCommand;
 use eframe::egui::{Response, Ui, Widget};
@@ -30,6 +33,9 @@ impl MainToolbarView {
         let docking_manager_for_process_selector = app_context.docking_manager.clone();
         let docking_manager_for_project_explorer = app_context.docking_manager.clone();
         let docking_manager_for_struct_viewer = app_context.docking_manager.clone();
+        let docking_manager_for_memory_viewer = app_context.docking_manager.clone();
+        let docking_manager_for_disassembly = app_context.docking_manager.clone();
+        let docking_manager_for_value_timeline = app_context.docking_manager.clone();
         let docking_manager_for_output = app_context.docking_manager.clone();
         let docking_manager_for_pointer_scanner = app_context.docking_manager.clone();
         let docking_manager_for_element_scanner = app_context.docking_manager.clone();
@@ -96,7 +102,41 @@ impl MainToolbarView {
                             None
                         })),
                     ),
-                    // ToolbarMenuItemData::new(MemoryViewerView::WINDOW_ID, "Memory Viewer", Some(move || false)),
+                    ToolbarMenuItemData::new(
+                        MemoryViewerView::WINDOW_ID,
+                        "Memory Viewer",
+                        Some(Box::new(move || {
+                            if let Ok(docking_manager) = docking_manager_for_memory_viewer.read() {
+                                if let Some(docked_node) = docking_manager.get_node_by_id(MemoryViewerView::WINDOW_ID) {
+                                    return Some(docked_node.is_visible());
+                                }
+                            }
+
+                            None
+                        })),
+                    ),
+                    ToolbarMenuItemData::new(
+                        DisassemblyView::WINDOW_ID,
+                        "Disassembly",
+                        Some(Box::new(move || {
+                            let docking_manager = docking_manager_for_disassembly.read().ok()?;
+
+                            docking_manager
+                                .get_node_by_id(DisassemblyView::WINDOW_ID)
+                                .map(|docked_node| docked_node.is_visible())
+                        })),
+                    ),
+                    ToolbarMenuItemData::new(
+                        ValueTimelineView::WINDOW_ID,
+                        "Value Timeline",
+                        Some(Box::new(move || {
+                            let docking_manager = docking_manager_for_value_timeline.read().ok()?;
+
+                            docking_manager
+                                .get_node_by_id(ValueTimelineView::WINDOW_ID)
+                                .map(|docked_node| docked_node.is_visible())
+                        })),
+                    ),
                     ToolbarMenuItemData::new(
                         OutputView::WINDOW_ID,
                         "Output",
@@ -165,7 +205,6 @@ impl MainToolbarView {
             ToolbarHeaderItemData {
                 header: "Debugger".into(),
                 items: vec![
-                    ToolbarMenuItemData::new("window_disassembly", "Disassembly", None),
                     ToolbarMenuItemData::new("window_code_tracer", "Code Tracer", None),
                 ]
                 .into(),
@@ -198,13 +237,14 @@ impl Widget for MainToolbarView {
             ProcessSelectorView::WINDOW_ID
             | ProjectExplorerView::WINDOW_ID
             | StructViewerView::WINDOW_ID
-            // | "window_memory_viewer"
+            | MemoryViewerView::WINDOW_ID
             | OutputView::WINDOW_ID
             | PointerScannerView::WINDOW_ID
             | ElementScannerView::WINDOW_ID
             | SettingsView::WINDOW_ID
             | PointerScannerView::WINDOW_ID
-            // | "window_disassembly"
+            | DisassemblyView::WINDOW_ID
+            | ValueTimelineView::WINDOW_ID
             // | "window_code_tracer"
             => {
                 let docking_manager = &app_context.docking_manager;
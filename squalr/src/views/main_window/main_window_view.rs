@@ -2,17 +2,20 @@ use crate::app_context::AppContext;
 use crate::ui::widgets::docking::dock_root_view::DockRootView;
 use crate::ui::widgets::docking::dock_root_view_data::DockRootViewData;
 use crate::ui::widgets::docking::docked_window_view::DockedWindowView;
+use crate::views::disassembly::disassembly_view::DisassemblyView;
 use crate::views::element_scanner::scanner::element_scanner_view::ElementScannerView;
 use crate::views::main_window::main_footer_view::MainFooterView;
 use crate::views::main_window::main_shortcut_bar_view::MainShortcutBarView;
 use crate::views::main_window::main_title_bar_view::MainTitleBarView;
 use crate::views::main_window::main_toolbar_view::MainToolbarView;
+use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
 use crate::views::output::output_view::OutputView;
 use crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView;
 use crate::views::process_selector::process_selector_view::ProcessSelectorView;
 use crate::views::project_explorer::project_explorer_view::ProjectExplorerView;
 use crate::views::settings::settings_view::SettingsView;
 use crate::views::struct_viewer::struct_viewer_view::StructViewerView;
+use crate::views::value_timeline::value_timeline_view::ValueTimelineView;
 use eframe::egui::{Align, Context, Id, Layout, ResizeDirection, Response, Sense, Ui, ViewportCommand, Widget};
 use epaint::CornerRadius;
 use epaint::{Rect, pos2};
@@ -68,6 +71,33 @@ impl MainWindowView {
             Rc::new("window_struct_viewer".to_string()),
         );
 
+        let app_context_for_memory_viewer = app_context.clone();
+        let memory_viewer_view = DockedWindowView::new(
+            app_context_for_memory_viewer.clone(),
+            dock_view_data.clone(),
+            MemoryViewerView::new(app_context_for_memory_viewer.clone()),
+            Rc::new("Memory Viewer".to_string()),
+            Rc::new("window_memory_viewer".to_string()),
+        );
+
+        let app_context_for_disassembly = app_context.clone();
+        let disassembly_view = DockedWindowView::new(
+            app_context_for_disassembly.clone(),
+            dock_view_data.clone(),
+            DisassemblyView::new(app_context_for_disassembly.clone()),
+            Rc::new("Disassembly".to_string()),
+            Rc::new("window_disassembly".to_string()),
+        );
+
+        let app_context_for_value_timeline = app_context.clone();
+        let value_timeline_view = DockedWindowView::new(
+            app_context_for_value_timeline.clone(),
+            dock_view_data.clone(),
+            ValueTimelineView::new(app_context_for_value_timeline.clone()),
+            Rc::new("Value Timeline".to_string()),
+            Rc::new("window_value_timeline".to_string()),
+        );
+
         let app_context_for_project_explorer = app_context.clone();
         let project_explorer_view = DockedWindowView::new(
             app_context_for_project_explorer.clone(),
@@ -108,6 +138,9 @@ impl MainWindowView {
             Box::new(output_view),
             Box::new(settings_view),
             Box::new(struct_viewer_view),
+            Box::new(memory_viewer_view),
+            Box::new(disassembly_view),
+            Box::new(value_timeline_view),
             Box::new(project_explorer_view),
             Box::new(process_selector_view),
             Box::new(element_scanner_view),
@@ -0,0 +1,251 @@
+use crate::app_context::AppContext;
+use crate::ui::draw::icon_draw::IconDraw;
+use crate::ui::widgets::controls::{button::Button, data_type_selector::data_type_selector_view::DataTypeSelectorView};
+use crate::views::memory_viewer::view_data::{memory_viewer_frame_action::MemoryViewerFrameAction, memory_viewer_view_data::MemoryViewerViewData};
+use eframe::egui::{Align, Align2, Key, Layout, Response, Sense, TextEdit, Ui, Widget};
+use epaint::{Color32, CornerRadius, Rect, pos2, vec2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct MemoryViewerView {
+    app_context: Arc<AppContext>,
+    memory_viewer_view_data: Dependency<MemoryViewerViewData>,
+}
+
+impl MemoryViewerView {
+    pub const WINDOW_ID: &'static str = "window_memory_viewer";
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let memory_viewer_view_data = app_context
+            .dependency_container
+            .register(MemoryViewerViewData::new());
+
+        MemoryViewerViewData::poll_memory(memory_viewer_view_data.clone(), app_context.engine_unprivileged_state.clone());
+
+        Self {
+            app_context,
+            memory_viewer_view_data,
+        }
+    }
+}
+
+impl Widget for MemoryViewerView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        user_interface
+            .ctx()
+            .request_repaint_after(MemoryViewerViewData::get_refresh_interval());
+
+        const TOOLBAR_HEIGHT: f32 = 32.0;
+        const ROW_HEIGHT: f32 = 20.0;
+        const ADDRESS_COLUMN_WIDTH: f32 = 180.0;
+        const BYTE_COLUMN_WIDTH: f32 = 24.0;
+        const DECODED_COLUMN_PADDING: f32 = 16.0;
+        const TEXT_PADDING: f32 = 8.0;
+
+        let theme = &self.app_context.theme;
+        let font_id = theme.font_library.font_ubuntu_mono_bold.font_normal.clone();
+        let mut frame_action = MemoryViewerFrameAction::None;
+
+        let response = user_interface
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                let mut memory_viewer_view_data = match self.memory_viewer_view_data.write("Memory viewer view") {
+                    Some(memory_viewer_view_data) => memory_viewer_view_data,
+                    None => return,
+                };
+
+                // Draw the toolbar.
+                user_interface.allocate_ui_with_layout(
+                    vec2(user_interface.available_width(), TOOLBAR_HEIGHT),
+                    Layout::left_to_right(Align::Center),
+                    |user_interface| {
+                        let button_size = vec2(36.0, 28.0);
+
+                        user_interface.add_space(4.0);
+
+                        let address_response = user_interface.add_sized(
+                            vec2(240.0, 24.0),
+                            TextEdit::singleline(&mut memory_viewer_view_data.address_text)
+                                .hint_text("module+offset or address")
+                                .vertical_align(Align::Center)
+                                .font(font_id.clone())
+                                .background_color(theme.background_primary)
+                                .text_color(theme.foreground),
+                        );
+
+                        if address_response.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                            frame_action = MemoryViewerFrameAction::GoToAddress;
+                        }
+
+                        let button_go = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Go to address."),
+                        );
+                        IconDraw::draw(user_interface, button_go.rect, &theme.icon_library.icon_handle_navigation_right_arrow);
+
+                        if button_go.clicked() {
+                            frame_action = MemoryViewerFrameAction::GoToAddress;
+                        }
+
+                        user_interface.add_space(8.0);
+                        user_interface.add(DataTypeSelectorView::new(
+                            self.app_context.clone(),
+                            &mut memory_viewer_view_data.data_type_ref,
+                            "memory_viewer_data_type_selector",
+                        ));
+                        user_interface.add_space(8.0);
+
+                        let button_add_to_project = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Add selection to project."),
+                        );
+                        IconDraw::draw(user_interface, button_add_to_project.rect, &theme.icon_library.icon_handle_common_add);
+
+                        if button_add_to_project.clicked() {
+                            frame_action = MemoryViewerFrameAction::AddSelectionToProject;
+                        }
+                    },
+                );
+
+                // Draw the rows.
+                let content_rect = user_interface.available_rect_before_wrap();
+                let content_response = user_interface.interact(content_rect, user_interface.id().with("memory_viewer_content"), Sense::hover());
+                let hex_column_x = content_rect.min.x + ADDRESS_COLUMN_WIDTH;
+                let decoded_column_x = hex_column_x + BYTE_COLUMN_WIDTH * MemoryViewerViewData::BYTES_PER_ROW as f32 + DECODED_COLUMN_PADDING;
+
+                if content_response.hovered() {
+                    let scroll_delta = user_interface.input(|input| input.raw_scroll_delta.y);
+
+                    if scroll_delta != 0.0 {
+                        frame_action = MemoryViewerFrameAction::ScrollRows(if scroll_delta > 0.0 { -1 } else { 1 });
+                    }
+                }
+
+                let painter = user_interface.painter().clone();
+                let edit_offset = memory_viewer_view_data.edit_offset;
+
+                for row_index in 0..MemoryViewerViewData::ROW_COUNT {
+                    let row_min_y = content_rect.min.y + row_index as f32 * ROW_HEIGHT;
+
+                    if row_min_y + ROW_HEIGHT > content_rect.max.y {
+                        break;
+                    }
+
+                    let row_center_y = row_min_y + ROW_HEIGHT * 0.5;
+
+                    painter.text(
+                        pos2(content_rect.min.x + TEXT_PADDING, row_center_y),
+                        Align2::LEFT_CENTER,
+                        memory_viewer_view_data.format_row_address(row_index),
+                        font_id.clone(),
+                        theme.foreground_preview,
+                    );
+
+                    for column_index in 0..MemoryViewerViewData::BYTES_PER_ROW {
+                        let offset = row_index * MemoryViewerViewData::BYTES_PER_ROW + column_index;
+                        let byte_rect = Rect::from_min_size(
+                            pos2(hex_column_x + column_index as f32 * BYTE_COLUMN_WIDTH, row_min_y),
+                            vec2(BYTE_COLUMN_WIDTH, ROW_HEIGHT),
+                        );
+
+                        if edit_offset == Some(offset) {
+                            let edit_response = user_interface.put(
+                                Rect::from_min_size(byte_rect.min, vec2(BYTE_COLUMN_WIDTH * 4.0, ROW_HEIGHT)),
+                                TextEdit::singleline(&mut memory_viewer_view_data.edit_text)
+                                    .vertical_align(Align::Center)
+                                    .font(font_id.clone())
+                                    .background_color(theme.background_primary)
+                                    .text_color(theme.foreground),
+                            );
+
+                            edit_response.request_focus();
+
+                            if user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                                frame_action = MemoryViewerFrameAction::CommitEdit;
+                            } else if user_interface.input(|input| input.key_pressed(Key::Escape)) {
+                                frame_action = MemoryViewerFrameAction::CancelEdit;
+                            }
+
+                            continue;
+                        }
+
+                        let byte_response = user_interface.interact(byte_rect, user_interface.id().with(("memory_viewer_byte", offset)), Sense::click());
+
+                        if memory_viewer_view_data.is_selected(offset) {
+                            painter.rect_filled(byte_rect, CornerRadius::ZERO, theme.selected_background);
+                        }
+
+                        let is_readable = memory_viewer_view_data
+                            .readable
+                            .get(offset)
+                            .copied()
+                            .unwrap_or(false);
+                        let is_changed = memory_viewer_view_data
+                            .changed
+                            .get(offset)
+                            .copied()
+                            .unwrap_or(false);
+                        let (byte_text, byte_color) = match (is_readable, is_changed) {
+                            (false, _) => ("??".to_string(), theme.foreground_preview),
+                            (true, true) => (format!("{:02X}", memory_viewer_view_data.bytes[offset]), theme.background_control_danger),
+                            (true, false) => (format!("{:02X}", memory_viewer_view_data.bytes[offset]), theme.foreground),
+                        };
+
+                        painter.text(byte_rect.center(), Align2::CENTER_CENTER, byte_text, font_id.clone(), byte_color);
+
+                        if byte_response.double_clicked() {
+                            frame_action = MemoryViewerFrameAction::BeginEdit(offset);
+                        } else if byte_response.clicked() {
+                            let extend_selection = user_interface.input(|input| input.modifiers.shift);
+
+                            frame_action = MemoryViewerFrameAction::SelectByte(offset, extend_selection);
+                        }
+                    }
+
+                    painter.text(
+                        pos2(decoded_column_x, row_center_y),
+                        Align2::LEFT_CENTER,
+                        memory_viewer_view_data.decode_row(row_index),
+                        font_id.clone(),
+                        theme.hexadecimal_green,
+                    );
+                }
+            })
+            .response;
+
+        match frame_action {
+            MemoryViewerFrameAction::None => {}
+            MemoryViewerFrameAction::GoToAddress => {
+                MemoryViewerViewData::go_to_address(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            MemoryViewerFrameAction::ScrollRows(row_delta) => {
+                MemoryViewerViewData::scroll_rows(self.memory_viewer_view_data.clone(), row_delta);
+                MemoryViewerViewData::refresh_memory(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            MemoryViewerFrameAction::SelectByte(offset, extend_selection) => {
+                MemoryViewerViewData::select_byte(self.memory_viewer_view_data.clone(), offset, extend_selection);
+            }
+            MemoryViewerFrameAction::BeginEdit(offset) => {
+                MemoryViewerViewData::begin_edit(self.memory_viewer_view_data.clone(), offset);
+            }
+            MemoryViewerFrameAction::CommitEdit => {
+                MemoryViewerViewData::commit_edit(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            MemoryViewerFrameAction::CancelEdit => {
+                MemoryViewerViewData::cancel_edit(self.memory_viewer_view_data.clone());
+            }
+            MemoryViewerFrameAction::AddSelectionToProject => {
+                MemoryViewerViewData::add_selection_to_project(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,11 @@
+#[derive(Clone, PartialEq)]
+pub enum MemoryViewerFrameAction {
+    None,
+    GoToAddress,
+    ScrollRows(i64),
+    SelectByte(usize, bool),
+    BeginEdit(usize),
+    CommitEdit,
+    CancelEdit,
+    AddSelectionToProject,
+}
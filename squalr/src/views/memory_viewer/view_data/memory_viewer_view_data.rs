@@ -0,0 +1,480 @@
+use squalr_engine_api::commands::memory::read_range::memory_read_range_request::MemoryReadRangeRequest;
+use squalr_engine_api::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+use squalr_engine_api::commands::memory::resolve::memory_resolve_request::MemoryResolveRequest;
+use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::project_items::create::project_items_create_request::ProjectItemsCreateRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_session::engine_unprivileged_state::EngineUnprivilegedState;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct MemoryViewerViewData {
+    pub address_text: String,
+    pub module_name: String,
+    pub base_address: u64,
+    pub resolved_address: u64,
+    pub bytes: Vec<u8>,
+    pub readable: Vec<bool>,
+    pub changed: Vec<bool>,
+    pub data_type_ref: DataTypeRef,
+    pub selection_anchor: Option<usize>,
+    pub selection_end: Option<usize>,
+    pub edit_offset: Option<usize>,
+    pub edit_text: String,
+    pub is_reading: bool,
+}
+
+impl MemoryViewerViewData {
+    pub const BYTES_PER_ROW: usize = 16;
+    pub const ROW_COUNT: usize = 32;
+    pub const REFRESH_INTERVAL_MS: u64 = 250;
+
+    pub fn new() -> Self {
+        Self {
+            address_text: String::new(),
+            module_name: String::new(),
+            base_address: 0,
+            resolved_address: 0,
+            bytes: Vec::new(),
+            readable: Vec::new(),
+            changed: Vec::new(),
+            data_type_ref: DataTypeRef::new(DataTypeU8::DATA_TYPE_ID),
+            selection_anchor: None,
+            selection_end: None,
+            edit_offset: None,
+            edit_text: String::new(),
+            is_reading: false,
+        }
+    }
+
+    pub fn get_view_length() -> usize {
+        Self::BYTES_PER_ROW * Self::ROW_COUNT
+    }
+
+    pub fn get_refresh_interval() -> Duration {
+        Duration::from_millis(Self::REFRESH_INTERVAL_MS)
+    }
+
+    pub fn poll_memory(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        // Re-read the visible range on a loop so that changed bytes can be highlighted.
+        thread::spawn(move || {
+            loop {
+                Self::refresh_memory(memory_viewer_view_data.clone(), engine_unprivileged_state.clone());
+
+                thread::sleep(Self::get_refresh_interval());
+            }
+        });
+    }
+
+    pub fn refresh_memory(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let memory_read_range_request = match memory_viewer_view_data.write("Memory viewer refresh memory") {
+            Some(mut memory_viewer_view_data) => {
+                if memory_viewer_view_data.is_reading || memory_viewer_view_data.address_text.trim().is_empty() {
+                    return;
+                }
+
+                memory_viewer_view_data.is_reading = true;
+
+                MemoryReadRangeRequest {
+                    address: memory_viewer_view_data.base_address,
+                    module_name: memory_viewer_view_data.module_name.clone(),
+                    address_expression: String::new(),
+                    length: Self::get_view_length() as u64,
+                }
+            }
+            None => return,
+        };
+        let memory_viewer_view_data_clone = memory_viewer_view_data.clone();
+
+        let did_send = memory_read_range_request.send(&engine_unprivileged_state, move |memory_read_range_response| {
+            if let Some(mut memory_viewer_view_data) = memory_viewer_view_data_clone.write("Memory viewer refresh memory response") {
+                memory_viewer_view_data.is_reading = false;
+                memory_viewer_view_data.apply_read_range_response(memory_read_range_response);
+            }
+        });
+
+        if did_send {
+            return;
+        }
+
+        if let Some(mut memory_viewer_view_data) = memory_viewer_view_data.write("Memory viewer refresh memory send failure") {
+            memory_viewer_view_data.is_reading = false;
+        }
+    }
+
+    /// Parses the go-to address expression and moves the view to the resulting address.
+    /// Static expressions (such as `game.exe+1234`) stay module-relative, while pointer expressions are resolved by the engine once.
+    pub fn go_to_address(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let address_expression = match memory_viewer_view_data.write("Memory viewer go to address") {
+            Some(mut memory_viewer_view_data) => {
+                let address_expression = match AddressExpression::parse(&memory_viewer_view_data.address_text) {
+                    Ok(address_expression) => address_expression,
+                    Err(error) => {
+                        log::warn!("Invalid memory viewer address '{}': {}", memory_viewer_view_data.address_text, error);
+                        return;
+                    }
+                };
+
+                match address_expression.to_module_offset() {
+                    Some((module_name, address)) => {
+                        memory_viewer_view_data.module_name = module_name;
+                        memory_viewer_view_data.base_address = address;
+                        memory_viewer_view_data.clear_view();
+                        None
+                    }
+                    None => Some(address_expression),
+                }
+            }
+            None => return,
+        };
+
+        let Some(address_expression) = address_expression else {
+            Self::refresh_memory(memory_viewer_view_data, engine_unprivileged_state);
+            return;
+        };
+        let memory_resolve_request = MemoryResolveRequest {
+            address_expression: address_expression.to_string(),
+        };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        memory_resolve_request.send(&engine_unprivileged_state, move |memory_resolve_response| {
+            if !memory_resolve_response.success {
+                log::warn!("Failed to resolve memory viewer address expression: {}", address_expression);
+                return;
+            }
+
+            if let Some(mut memory_viewer_view_data) = memory_viewer_view_data.write("Memory viewer go to resolved address") {
+                memory_viewer_view_data.module_name = memory_resolve_response.module_name;
+                memory_viewer_view_data.base_address = memory_resolve_response.module_offset;
+                memory_viewer_view_data.clear_view();
+            }
+
+            Self::refresh_memory(memory_viewer_view_data, engine_unprivileged_state_clone);
+        });
+    }
+
+    /// Scrolls the view by the given number of rows, updating the address text to match.
+    pub fn scroll_rows(
+        memory_viewer_view_data: Dependency<Self>,
+        row_delta: i64,
+    ) {
+        let mut memory_viewer_view_data = match memory_viewer_view_data.write("Memory viewer scroll rows") {
+            Some(memory_viewer_view_data) => memory_viewer_view_data,
+            None => return,
+        };
+        let byte_delta = row_delta.saturating_mul(Self::BYTES_PER_ROW as i64);
+
+        memory_viewer_view_data.base_address = memory_viewer_view_data
+            .base_address
+            .saturating_add_signed(byte_delta);
+        memory_viewer_view_data.address_text = memory_viewer_view_data.format_base_address();
+        memory_viewer_view_data.clear_view();
+    }
+
+    pub fn select_byte(
+        memory_viewer_view_data: Dependency<Self>,
+        offset: usize,
+        extend_selection: bool,
+    ) {
+        let mut memory_viewer_view_data = match memory_viewer_view_data.write("Memory viewer select byte") {
+            Some(memory_viewer_view_data) => memory_viewer_view_data,
+            None => return,
+        };
+
+        if !extend_selection || memory_viewer_view_data.selection_anchor.is_none() {
+            memory_viewer_view_data.selection_anchor = Some(offset);
+        }
+
+        memory_viewer_view_data.selection_end = Some(offset);
+        memory_viewer_view_data.edit_offset = None;
+    }
+
+    pub fn begin_edit(
+        memory_viewer_view_data: Dependency<Self>,
+        offset: usize,
+    ) {
+        let mut memory_viewer_view_data = match memory_viewer_view_data.write("Memory viewer begin edit") {
+            Some(memory_viewer_view_data) => memory_viewer_view_data,
+            None => return,
+        };
+
+        if !memory_viewer_view_data
+            .readable
+            .get(offset)
+            .copied()
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        memory_viewer_view_data.edit_text = format!("{:02X}", memory_viewer_view_data.bytes[offset]);
+        memory_viewer_view_data.edit_offset = Some(offset);
+    }
+
+    pub fn cancel_edit(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut memory_viewer_view_data) = memory_viewer_view_data.write("Memory viewer cancel edit") {
+            memory_viewer_view_data.edit_offset = None;
+        }
+    }
+
+    /// Writes the edited hex bytes (space separated) starting at the byte being edited.
+    pub fn commit_edit(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let memory_write_request = match memory_viewer_view_data.write("Memory viewer commit edit") {
+            Some(mut memory_viewer_view_data) => {
+                let Some(edit_offset) = memory_viewer_view_data.edit_offset.take() else {
+                    return;
+                };
+                let Some(value) = Self::parse_edit_bytes(&memory_viewer_view_data.edit_text) else {
+                    log::warn!("Invalid memory viewer edit value: {}", memory_viewer_view_data.edit_text);
+                    return;
+                };
+
+                MemoryWriteRequest {
+                    address: memory_viewer_view_data
+                        .base_address
+                        .saturating_add(edit_offset as u64),
+                    module_name: memory_viewer_view_data.module_name.clone(),
+                    address_expression: String::new(),
+                    value,
+                }
+            }
+            None => return,
+        };
+        let memory_viewer_view_data_clone = memory_viewer_view_data.clone();
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        memory_write_request.send(&engine_unprivileged_state, move |memory_write_response| {
+            if !memory_write_response.success {
+                log::error!("Failed to write memory from the memory viewer.");
+            }
+
+            Self::refresh_memory(memory_viewer_view_data_clone, engine_unprivileged_state_clone);
+        });
+    }
+
+    /// Adds the first selected address to the opened project as an address item of the decode data type.
+    pub fn add_selection_to_project(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let project_items_create_request = match memory_viewer_view_data.read("Memory viewer add selection to project") {
+            Some(memory_viewer_view_data) => {
+                let Some((selection_start, _selection_end)) = memory_viewer_view_data.get_selection_range() else {
+                    return;
+                };
+                let address = memory_viewer_view_data
+                    .base_address
+                    .saturating_add(selection_start as u64);
+                let project_item_name = if memory_viewer_view_data.module_name.is_empty() {
+                    format!("address_0x{:X}", address)
+                } else {
+                    format!("{}_0x{:X}", memory_viewer_view_data.module_name, address)
+                };
+
+                ProjectItemsCreateRequest {
+                    parent_directory_path: PathBuf::from(Project::PROJECT_DIR),
+                    project_item_name,
+                    project_item_type: ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID.to_string(),
+                    address: Some(address),
+                    module_name: Some(memory_viewer_view_data.module_name.clone()),
+                    address_expression: None,
+                    data_type_id: Some(
+                        memory_viewer_view_data
+                            .data_type_ref
+                            .get_data_type_id()
+                            .to_string(),
+                    ),
+                    original_bytes: None,
+                    replacement: None,
+                }
+            }
+            None => return,
+        };
+
+        project_items_create_request.send(&engine_unprivileged_state, |project_items_create_response| {
+            if !project_items_create_response.success {
+                log::error!("Failed to add memory viewer selection to the project.");
+            }
+        });
+    }
+
+    /// Gets the inclusive selected byte range, ordered from low to high.
+    pub fn get_selection_range(&self) -> Option<(usize, usize)> {
+        let selection_anchor = self.selection_anchor?;
+        let selection_end = self.selection_end.unwrap_or(selection_anchor);
+
+        Some((selection_anchor.min(selection_end), selection_anchor.max(selection_end)))
+    }
+
+    pub fn is_selected(
+        &self,
+        offset: usize,
+    ) -> bool {
+        self.get_selection_range()
+            .map(|(selection_start, selection_end)| offset >= selection_start && offset <= selection_end)
+            .unwrap_or(false)
+    }
+
+    /// Gets the address text shown for a row, either absolute or relative to the viewed module.
+    pub fn format_row_address(
+        &self,
+        row_index: usize,
+    ) -> String {
+        let row_address = self
+            .base_address
+            .saturating_add((row_index * Self::BYTES_PER_ROW) as u64);
+
+        if self.module_name.is_empty() {
+            format!("{:016X}", row_address)
+        } else {
+            format!("{}+{:X}", self.module_name, row_address)
+        }
+    }
+
+    /// Decodes a row of bytes into values of the selected data type. Values that span unreadable bytes are shown as `??`.
+    pub fn decode_row(
+        &self,
+        row_index: usize,
+    ) -> String {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let unit_size = (symbol_registry.get_unit_size_in_bytes(&self.data_type_ref) as usize).max(1);
+        let default_format = symbol_registry.get_default_anonymous_value_string_format(&self.data_type_ref);
+        let row_start = row_index * Self::BYTES_PER_ROW;
+        let row_end = (row_start + Self::BYTES_PER_ROW).min(self.bytes.len());
+        let mut decoded_values = Vec::new();
+        let mut value_start = row_start;
+
+        while value_start + unit_size <= row_end {
+            let value_end = value_start + unit_size;
+            let is_readable = self.readable[value_start..value_end]
+                .iter()
+                .all(|is_readable| *is_readable);
+
+            if is_readable {
+                let data_value = DataValue::new(self.data_type_ref.clone(), self.bytes[value_start..value_end].to_vec());
+                let decoded_value = symbol_registry
+                    .anonymize_value(&data_value, default_format)
+                    .map(|anonymous_value_string| anonymous_value_string.get_anonymous_value_string().to_string())
+                    .unwrap_or_else(|_| "??".to_string());
+
+                decoded_values.push(decoded_value);
+            } else {
+                decoded_values.push("??".to_string());
+            }
+
+            value_start = value_end;
+        }
+
+        decoded_values.join(" ")
+    }
+
+    fn apply_read_range_response(
+        &mut self,
+        memory_read_range_response: MemoryReadRangeResponse,
+    ) {
+        let is_same_range = self.resolved_address == memory_read_range_response.address && self.bytes.len() == memory_read_range_response.bytes.len();
+
+        self.changed = (0..memory_read_range_response.bytes.len())
+            .map(|offset| {
+                is_same_range
+                    && memory_read_range_response.is_readable(offset)
+                    && self.readable[offset]
+                    && self.bytes[offset] != memory_read_range_response.bytes[offset]
+            })
+            .collect();
+        self.resolved_address = memory_read_range_response.address;
+        self.bytes = memory_read_range_response.bytes;
+        self.readable = memory_read_range_response.readable;
+    }
+
+    fn clear_view(&mut self) {
+        self.bytes.clear();
+        self.readable.clear();
+        self.changed.clear();
+        self.selection_anchor = None;
+        self.selection_end = None;
+        self.edit_offset = None;
+    }
+
+    fn format_base_address(&self) -> String {
+        AddressExpression::format_module_offset(&self.module_name, self.base_address)
+    }
+
+    fn parse_edit_bytes(edit_text: &str) -> Option<Vec<u8>> {
+        let bytes = edit_text
+            .split_whitespace()
+            .map(|byte_text| u8::from_str_radix(byte_text, 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+
+        if bytes.is_empty() { None } else { Some(bytes) }
+    }
+}
+
+impl Default for MemoryViewerViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryViewerViewData;
+    use squalr_engine_api::commands::memory::read_range::memory_read_range_response::MemoryReadRangeResponse;
+
+    #[test]
+    fn apply_read_range_response_marks_changed_readable_bytes() {
+        let mut memory_viewer_view_data = MemoryViewerViewData::new();
+
+        memory_viewer_view_data.apply_read_range_response(MemoryReadRangeResponse {
+            address: 0x1000,
+            bytes: vec![1, 2, 0, 4],
+            readable: vec![true, true, false, true],
+            success: true,
+        });
+        assert!(
+            memory_viewer_view_data
+                .changed
+                .iter()
+                .all(|is_changed| !is_changed)
+        );
+
+        memory_viewer_view_data.apply_read_range_response(MemoryReadRangeResponse {
+            address: 0x1000,
+            bytes: vec![1, 9, 7, 4],
+            readable: vec![true, true, true, true],
+            success: true,
+        });
+        assert_eq!(memory_viewer_view_data.changed, vec![false, true, false, false]);
+    }
+
+    #[test]
+    fn parse_edit_bytes_accepts_space_separated_hex() {
+        assert_eq!(MemoryViewerViewData::parse_edit_bytes("DE ad 0"), Some(vec![0xDE, 0xAD, 0x00]));
+        assert_eq!(MemoryViewerViewData::parse_edit_bytes("zz"), None);
+        assert_eq!(MemoryViewerViewData::parse_edit_bytes(""), None);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_viewer_frame_action;
+pub mod memory_viewer_view_data;
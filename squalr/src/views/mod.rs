@@ -1,8 +1,11 @@
+pub mod disassembly;
 pub mod element_scanner;
 pub mod main_window;
+pub mod memory_viewer;
 pub mod output;
 pub mod pointer_scanner;
 pub mod process_selector;
 pub mod project_explorer;
 pub mod settings;
 pub mod struct_viewer;
+pub mod value_timeline;
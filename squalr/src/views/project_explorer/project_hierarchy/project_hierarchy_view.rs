@@ -752,10 +752,12 @@ impl ProjectHierarchyView {
         let edited_data_value = edited_field.get_data_value()?;
         let address = ProjectItemTypeAddress::get_field_address(project_item);
         let module_name = ProjectItemTypeAddress::get_field_module(project_item);
+        let address_expression = ProjectItemTypeAddress::get_field_address_expression(project_item);
 
         Some(MemoryWriteRequest {
             address,
             module_name,
+            address_expression,
             value: edited_data_value.get_value_bytes().clone(),
         })
     }
@@ -466,6 +466,12 @@ impl ProjectHierarchyViewData {
             parent_directory_path,
             project_item_name: directory_name,
             project_item_type: ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID.to_string(),
+            address: None,
+            module_name: None,
+            address_expression: None,
+            data_type_id: None,
+            original_bytes: None,
+            replacement: None,
         };
         let app_context_clone = app_context.clone();
         let project_hierarchy_view_data_clone = project_hierarchy_view_data.clone();
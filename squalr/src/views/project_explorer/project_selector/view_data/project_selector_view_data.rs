@@ -85,6 +85,7 @@ impl ProjectSelectorViewData {
         let project_create_request = ProjectCreateRequest {
             project_directory_path: None,
             project_name: None,
+            auto_attach_process_name: None,
         };
 
         project_create_request.send(&app_context.engine_unprivileged_state, move |project_create_response| {
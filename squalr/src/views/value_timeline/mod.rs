@@ -0,0 +1,2 @@
+pub mod value_timeline_view;
+pub mod view_data;
@@ -0,0 +1,293 @@
+use crate::app_context::AppContext;
+use crate::ui::draw::icon_draw::IconDraw;
+use crate::ui::widgets::controls::{button::Button, data_type_selector::data_type_selector_view::DataTypeSelectorView};
+use crate::views::value_timeline::view_data::{value_timeline_frame_action::ValueTimelineFrameAction, value_timeline_view_data::ValueTimelineViewData};
+use eframe::egui::{Align, Align2, Key, Layout, Painter, Response, Sense, TextEdit, Ui, Widget};
+use epaint::{Color32, CornerRadius, Rect, Stroke, StrokeKind, pos2, vec2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ValueTimelineView {
+    app_context: Arc<AppContext>,
+    value_timeline_view_data: Dependency<ValueTimelineViewData>,
+}
+
+impl ValueTimelineView {
+    pub const WINDOW_ID: &'static str = "window_value_timeline";
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let value_timeline_view_data = app_context
+            .dependency_container
+            .register(ValueTimelineViewData::new());
+
+        ValueTimelineViewData::poll_value_timelines(value_timeline_view_data.clone(), app_context.engine_unprivileged_state.clone());
+
+        Self {
+            app_context,
+            value_timeline_view_data,
+        }
+    }
+
+    /// Draws the numeric samples as a line scaled to fill the given rectangle. Unreadable samples break the line.
+    fn draw_plot(
+        painter: &Painter,
+        plot_rect: Rect,
+        samples: &[ValueTimelineSample],
+        color: Color32,
+    ) {
+        let numeric_values = samples
+            .iter()
+            .filter_map(|sample| sample.get_numeric_value())
+            .collect::<Vec<_>>();
+        let min_value = numeric_values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max_value = numeric_values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let value_range = max_value - min_value;
+        let sample_step = plot_rect.width() / samples.len().saturating_sub(1).max(1) as f32;
+        let to_point = |sample_index: usize, numeric_value: f64| {
+            let normalized_value = if value_range > 0.0 { (numeric_value - min_value) / value_range } else { 0.5 };
+
+            pos2(
+                plot_rect.min.x + sample_index as f32 * sample_step,
+                plot_rect.max.y - normalized_value as f32 * plot_rect.height(),
+            )
+        };
+        let mut previous_point = None;
+
+        for (sample_index, sample) in samples.iter().enumerate() {
+            let point = sample
+                .get_numeric_value()
+                .map(|numeric_value| to_point(sample_index, numeric_value));
+
+            if let (Some(previous_point), Some(point)) = (previous_point, point) {
+                painter.line_segment([previous_point, point], Stroke::new(1.5_f32, color));
+            }
+
+            previous_point = point;
+        }
+    }
+}
+
+impl Widget for ValueTimelineView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        const TOOLBAR_HEIGHT: f32 = 32.0;
+        const ROW_HEIGHT: f32 = 24.0;
+        const NAME_COLUMN_WIDTH: f32 = 240.0;
+        const DATA_TYPE_COLUMN_WIDTH: f32 = 80.0;
+        const VALUE_COLUMN_WIDTH: f32 = 160.0;
+        const SPARKLINE_WIDTH: f32 = 200.0;
+        const TEXT_PADDING: f32 = 8.0;
+        const PLOT_PADDING: f32 = 12.0;
+
+        let theme = &self.app_context.theme;
+        let font_id = theme.font_library.font_ubuntu_mono_bold.font_normal.clone();
+        let mut frame_action = ValueTimelineFrameAction::None;
+
+        let response = user_interface
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                let mut value_timeline_view_data = match self.value_timeline_view_data.write("Value timeline view") {
+                    Some(value_timeline_view_data) => value_timeline_view_data,
+                    None => return,
+                };
+                let button_size = vec2(36.0, 28.0);
+
+                // Draw the record toolbar.
+                user_interface.allocate_ui_with_layout(
+                    vec2(user_interface.available_width(), TOOLBAR_HEIGHT),
+                    Layout::left_to_right(Align::Center),
+                    |user_interface| {
+                        user_interface.add_space(4.0);
+
+                        let address_response = user_interface.add_sized(
+                            vec2(200.0, 24.0),
+                            TextEdit::singleline(&mut value_timeline_view_data.address_text)
+                                .hint_text("module+offset or address")
+                                .vertical_align(Align::Center)
+                                .font(font_id.clone())
+                                .background_color(theme.background_primary)
+                                .text_color(theme.foreground),
+                        );
+
+                        if address_response.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                            frame_action = ValueTimelineFrameAction::Record;
+                        }
+
+                        user_interface.add_space(8.0);
+                        user_interface.add(DataTypeSelectorView::new(
+                            self.app_context.clone(),
+                            &mut value_timeline_view_data.data_type_ref,
+                            "value_timeline_data_type_selector",
+                        ));
+                        user_interface.add_space(8.0);
+                        user_interface.add_sized(
+                            vec2(64.0, 24.0),
+                            TextEdit::singleline(&mut value_timeline_view_data.interval_text)
+                                .hint_text("ms")
+                                .vertical_align(Align::Center)
+                                .font(font_id.clone())
+                                .background_color(theme.background_primary)
+                                .text_color(theme.foreground),
+                        );
+                        user_interface.add_sized(
+                            vec2(160.0, 24.0),
+                            TextEdit::singleline(&mut value_timeline_view_data.label_text)
+                                .hint_text("label")
+                                .vertical_align(Align::Center)
+                                .font(font_id.clone())
+                                .background_color(theme.background_primary)
+                                .text_color(theme.foreground),
+                        );
+
+                        let button_record = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Record the value of this address over time."),
+                        );
+                        IconDraw::draw(user_interface, button_record.rect, &theme.icon_library.icon_handle_common_add);
+
+                        if button_record.clicked() {
+                            frame_action = ValueTimelineFrameAction::Record;
+                        }
+
+                        user_interface.add_space(16.0);
+                        user_interface.add_sized(
+                            vec2(200.0, 24.0),
+                            TextEdit::singleline(&mut value_timeline_view_data.export_file_path_text)
+                                .hint_text("export.csv")
+                                .vertical_align(Align::Center)
+                                .font(font_id.clone())
+                                .background_color(theme.background_primary)
+                                .text_color(theme.foreground),
+                        );
+
+                        let button_export = user_interface.add_sized(
+                            button_size,
+                            Button::new_from_theme(theme)
+                                .background_color(Color32::TRANSPARENT)
+                                .with_tooltip_text("Export all timelines to CSV."),
+                        );
+                        IconDraw::draw(user_interface, button_export.rect, &theme.icon_library.icon_handle_file_system_save);
+
+                        if button_export.clicked() {
+                            frame_action = ValueTimelineFrameAction::Export;
+                        }
+                    },
+                );
+
+                // Draw one row per timeline, followed by a plot of the selected timeline.
+                let content_rect = user_interface.available_rect_before_wrap();
+                let data_type_column_x = content_rect.min.x + NAME_COLUMN_WIDTH;
+                let value_column_x = data_type_column_x + DATA_TYPE_COLUMN_WIDTH;
+                let sparkline_column_x = value_column_x + VALUE_COLUMN_WIDTH;
+                let painter = user_interface.painter().clone();
+                let mut rows_max_y = content_rect.min.y;
+
+                for (timeline_index, value_timeline_info) in value_timeline_view_data.value_timelines.iter().enumerate() {
+                    let row_min_y = content_rect.min.y + timeline_index as f32 * ROW_HEIGHT;
+
+                    if row_min_y + ROW_HEIGHT > content_rect.max.y {
+                        break;
+                    }
+
+                    let timeline_id = value_timeline_info.timeline_id;
+                    let row_rect = Rect::from_min_size(pos2(content_rect.min.x, row_min_y), vec2(content_rect.width(), ROW_HEIGHT));
+                    let row_center_y = row_rect.center().y;
+                    let remove_rect = Rect::from_min_size(pos2(row_rect.max.x - ROW_HEIGHT, row_rect.min.y), vec2(ROW_HEIGHT, ROW_HEIGHT));
+                    let row_response = user_interface.interact(row_rect, user_interface.id().with(("value_timeline_row", timeline_id)), Sense::click());
+                    let remove_response =
+                        user_interface.interact(remove_rect, user_interface.id().with(("value_timeline_remove", timeline_id)), Sense::click());
+
+                    rows_max_y = row_rect.max.y;
+
+                    if value_timeline_view_data.selected_timeline_id == Some(timeline_id) {
+                        painter.rect_filled(row_rect, CornerRadius::ZERO, theme.selected_background);
+                    }
+
+                    painter.text(
+                        pos2(row_rect.min.x + TEXT_PADDING, row_center_y),
+                        Align2::LEFT_CENTER,
+                        value_timeline_info.target.get_display_name(),
+                        font_id.clone(),
+                        theme.foreground,
+                    );
+                    painter.text(
+                        pos2(data_type_column_x, row_center_y),
+                        Align2::LEFT_CENTER,
+                        value_timeline_info
+                            .target
+                            .get_data_type_ref()
+                            .get_data_type_id(),
+                        font_id.clone(),
+                        theme.foreground_preview,
+                    );
+                    painter.text(
+                        pos2(value_column_x, row_center_y),
+                        Align2::LEFT_CENTER,
+                        value_timeline_info
+                            .latest_sample
+                            .as_ref()
+                            .and_then(|sample| sample.get_value())
+                            .unwrap_or("??"),
+                        font_id.clone(),
+                        theme.hexadecimal_green,
+                    );
+                    Self::draw_plot(
+                        &painter,
+                        Rect::from_min_size(pos2(sparkline_column_x, row_rect.min.y + 4.0), vec2(SPARKLINE_WIDTH, ROW_HEIGHT - 8.0)),
+                        value_timeline_view_data.samples_for(timeline_id),
+                        theme.background_control_info,
+                    );
+                    IconDraw::draw(user_interface, remove_rect, &theme.icon_library.icon_handle_common_delete);
+
+                    if remove_response.clicked() {
+                        frame_action = ValueTimelineFrameAction::Remove(timeline_id);
+                    } else if row_response.clicked() {
+                        frame_action = ValueTimelineFrameAction::SelectTimeline(timeline_id);
+                    }
+                }
+
+                let plot_rect = Rect::from_min_max(pos2(content_rect.min.x, rows_max_y), content_rect.max).shrink(PLOT_PADDING);
+
+                match value_timeline_view_data.selected_timeline_id {
+                    Some(selected_timeline_id) if plot_rect.height() > ROW_HEIGHT => {
+                        painter.rect_stroke(plot_rect, CornerRadius::ZERO, Stroke::new(1.0_f32, theme.submenu_border), StrokeKind::Inside);
+                        Self::draw_plot(
+                            &painter,
+                            plot_rect.shrink(PLOT_PADDING),
+                            value_timeline_view_data.samples_for(selected_timeline_id),
+                            theme.hexadecimal_green,
+                        );
+                    }
+                    _ => {}
+                }
+            })
+            .response;
+
+        match frame_action {
+            ValueTimelineFrameAction::None => {}
+            ValueTimelineFrameAction::Record => {
+                ValueTimelineViewData::record(self.value_timeline_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            ValueTimelineFrameAction::Remove(timeline_id) => {
+                ValueTimelineViewData::remove(
+                    self.value_timeline_view_data.clone(),
+                    self.app_context.engine_unprivileged_state.clone(),
+                    timeline_id,
+                );
+            }
+            ValueTimelineFrameAction::Export => {
+                ValueTimelineViewData::export(self.value_timeline_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            ValueTimelineFrameAction::SelectTimeline(timeline_id) => {
+                ValueTimelineViewData::select_timeline(self.value_timeline_view_data.clone(), timeline_id);
+            }
+        }
+
+        response
+    }
+}
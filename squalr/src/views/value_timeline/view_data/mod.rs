@@ -0,0 +1,2 @@
+pub mod value_timeline_frame_action;
+pub mod value_timeline_view_data;
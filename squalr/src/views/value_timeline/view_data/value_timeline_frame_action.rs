@@ -0,0 +1,8 @@
+#[derive(Clone, PartialEq)]
+pub enum ValueTimelineFrameAction {
+    None,
+    Record,
+    Remove(u32),
+    Export,
+    SelectTimeline(u32),
+}
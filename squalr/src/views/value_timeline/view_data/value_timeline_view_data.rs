@@ -0,0 +1,341 @@
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::value_timeline::export::value_timeline_export_request::ValueTimelineExportRequest;
+use squalr_engine_api::commands::value_timeline::list::value_timeline_list_request::ValueTimelineListRequest;
+use squalr_engine_api::commands::value_timeline::query::value_timeline_query_request::ValueTimelineQueryRequest;
+use squalr_engine_api::commands::value_timeline::record::value_timeline_record_request::ValueTimelineRecordRequest;
+use squalr_engine_api::commands::value_timeline::remove::value_timeline_remove_request::ValueTimelineRemoveRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::address_expression::AddressExpression;
+use squalr_engine_api::structures::value_timelines::value_timeline_info::ValueTimelineInfo;
+use squalr_engine_api::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+use squalr_engine_session::engine_unprivileged_state::EngineUnprivilegedState;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct ValueTimelineViewData {
+    pub address_text: String,
+    pub label_text: String,
+    pub interval_text: String,
+    pub data_type_ref: DataTypeRef,
+    pub export_file_path_text: String,
+    pub value_timelines: Vec<ValueTimelineInfo>,
+    pub samples_by_timeline_id: HashMap<u32, Vec<ValueTimelineSample>>,
+    pub selected_timeline_id: Option<u32>,
+    pub is_refreshing: bool,
+}
+
+impl ValueTimelineViewData {
+    pub const REFRESH_INTERVAL_MS: u64 = 250;
+    pub const DEFAULT_INTERVAL_MS: u64 = 100;
+    pub const DEFAULT_CAPACITY: u64 = 10_000;
+    const MAX_RETAINED_SAMPLES: usize = 512;
+
+    pub fn new() -> Self {
+        Self {
+            address_text: String::new(),
+            label_text: String::new(),
+            interval_text: Self::DEFAULT_INTERVAL_MS.to_string(),
+            data_type_ref: DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            export_file_path_text: String::new(),
+            value_timelines: Vec::new(),
+            samples_by_timeline_id: HashMap::new(),
+            selected_timeline_id: None,
+            is_refreshing: false,
+        }
+    }
+
+    pub fn get_refresh_interval() -> Duration {
+        Duration::from_millis(Self::REFRESH_INTERVAL_MS)
+    }
+
+    pub fn poll_value_timelines(
+        value_timeline_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        // Fetch new samples on a loop so that the plots scroll as the recorder task samples values.
+        thread::spawn(move || {
+            loop {
+                Self::refresh_value_timelines(value_timeline_view_data.clone(), engine_unprivileged_state.clone());
+
+                thread::sleep(Self::get_refresh_interval());
+            }
+        });
+    }
+
+    /// Lists recorded timelines, then queries each timeline for samples newer than those already retained.
+    pub fn refresh_value_timelines(
+        value_timeline_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        match value_timeline_view_data.write("Value timeline refresh") {
+            Some(mut value_timeline_view_data) => {
+                if value_timeline_view_data.is_refreshing {
+                    return;
+                }
+
+                value_timeline_view_data.is_refreshing = true;
+            }
+            None => return,
+        }
+
+        let value_timeline_view_data_clone = value_timeline_view_data.clone();
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        let did_send = ValueTimelineListRequest {}.send(&engine_unprivileged_state, move |value_timeline_list_response| {
+            let value_timeline_query_requests = match value_timeline_view_data_clone.write("Value timeline refresh response") {
+                Some(mut value_timeline_view_data) => {
+                    value_timeline_view_data.is_refreshing = false;
+                    value_timeline_view_data.apply_value_timelines(value_timeline_list_response.value_timelines);
+                    value_timeline_view_data
+                        .value_timelines
+                        .iter()
+                        .map(|value_timeline_info| ValueTimelineQueryRequest {
+                            timeline_id: value_timeline_info.timeline_id,
+                            since_timestamp_ms: value_timeline_view_data.latest_timestamp_ms(value_timeline_info.timeline_id),
+                        })
+                        .collect::<Vec<_>>()
+                }
+                None => return,
+            };
+
+            for value_timeline_query_request in value_timeline_query_requests {
+                let timeline_id = value_timeline_query_request.timeline_id;
+                let value_timeline_view_data = value_timeline_view_data_clone.clone();
+
+                value_timeline_query_request.send(&engine_unprivileged_state_clone, move |value_timeline_query_response| {
+                    if let Some(mut value_timeline_view_data) = value_timeline_view_data.write("Value timeline query response") {
+                        value_timeline_view_data.append_samples(timeline_id, value_timeline_query_response.samples);
+                    }
+                });
+            }
+        });
+
+        if did_send {
+            return;
+        }
+
+        if let Some(mut value_timeline_view_data) = value_timeline_view_data.write("Value timeline refresh send failure") {
+            value_timeline_view_data.is_refreshing = false;
+        }
+    }
+
+    /// Starts recording the address in the address box. Only static addresses (such as `game.exe+1234`) can be recorded.
+    pub fn record(
+        value_timeline_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let value_timeline_record_request = match value_timeline_view_data.read("Value timeline record") {
+            Some(value_timeline_view_data) => {
+                let address_expression = match AddressExpression::parse(&value_timeline_view_data.address_text) {
+                    Ok(address_expression) => address_expression,
+                    Err(error) => {
+                        log::warn!("Invalid value timeline address '{}': {}", value_timeline_view_data.address_text, error);
+                        return;
+                    }
+                };
+                let Some((module_name, address)) = address_expression.to_module_offset() else {
+                    log::warn!("Only static addresses can be recorded: {}", address_expression);
+                    return;
+                };
+
+                ValueTimelineRecordRequest {
+                    address,
+                    module_name,
+                    data_type_ref: value_timeline_view_data.data_type_ref.clone(),
+                    label: value_timeline_view_data.label_text.trim().to_string(),
+                    interval_ms: value_timeline_view_data
+                        .interval_text
+                        .trim()
+                        .parse()
+                        .unwrap_or(Self::DEFAULT_INTERVAL_MS),
+                    capacity: Self::DEFAULT_CAPACITY,
+                }
+            }
+            None => return,
+        };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        value_timeline_record_request.send(&engine_unprivileged_state, move |value_timeline_record_response| {
+            if !value_timeline_record_response.success {
+                log::warn!("Failed to record value timeline.");
+                return;
+            }
+
+            if let Some(mut value_timeline_view_data) = value_timeline_view_data.write("Value timeline record response") {
+                value_timeline_view_data.selected_timeline_id = Some(value_timeline_record_response.timeline_id);
+            }
+
+            Self::refresh_value_timelines(value_timeline_view_data, engine_unprivileged_state_clone);
+        });
+    }
+
+    pub fn remove(
+        value_timeline_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        timeline_id: u32,
+    ) {
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        ValueTimelineRemoveRequest { timeline_id }.send(&engine_unprivileged_state, move |_value_timeline_remove_response| {
+            Self::refresh_value_timelines(value_timeline_view_data, engine_unprivileged_state_clone);
+        });
+    }
+
+    /// Exports every recorded timeline to the CSV file in the export path box.
+    pub fn export(
+        value_timeline_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let file_path = match value_timeline_view_data.read("Value timeline export") {
+            Some(value_timeline_view_data) => value_timeline_view_data
+                .export_file_path_text
+                .trim()
+                .to_string(),
+            None => return,
+        };
+
+        if file_path.is_empty() {
+            log::warn!("No value timeline export file path was provided.");
+            return;
+        }
+
+        let value_timeline_export_request = ValueTimelineExportRequest {
+            file_path: PathBuf::from(&file_path),
+            timeline_ids: Vec::new(),
+        };
+
+        value_timeline_export_request.send(&engine_unprivileged_state, move |value_timeline_export_response| {
+            if value_timeline_export_response.success {
+                log::info!(
+                    "Exported {} value timeline samples to {}.",
+                    value_timeline_export_response.exported_sample_count,
+                    file_path
+                );
+            } else {
+                log::error!("Failed to export value timelines to {}.", file_path);
+            }
+        });
+    }
+
+    pub fn select_timeline(
+        value_timeline_view_data: Dependency<Self>,
+        timeline_id: u32,
+    ) {
+        if let Some(mut value_timeline_view_data) = value_timeline_view_data.write("Value timeline select timeline") {
+            value_timeline_view_data.selected_timeline_id = Some(timeline_id);
+        }
+    }
+
+    pub fn samples_for(
+        &self,
+        timeline_id: u32,
+    ) -> &[ValueTimelineSample] {
+        self.samples_by_timeline_id
+            .get(&timeline_id)
+            .map(|samples| samples.as_slice())
+            .unwrap_or(&[])
+    }
+
+    fn latest_timestamp_ms(
+        &self,
+        timeline_id: u32,
+    ) -> Option<u64> {
+        self.samples_for(timeline_id)
+            .last()
+            .map(|sample| sample.get_timestamp_ms())
+    }
+
+    fn apply_value_timelines(
+        &mut self,
+        value_timelines: Vec<ValueTimelineInfo>,
+    ) {
+        self.samples_by_timeline_id.retain(|timeline_id, _| {
+            value_timelines
+                .iter()
+                .any(|value_timeline_info| value_timeline_info.timeline_id == *timeline_id)
+        });
+        self.value_timelines = value_timelines;
+
+        let is_selection_valid = self.selected_timeline_id.is_some_and(|selected_timeline_id| {
+            self.value_timelines
+                .iter()
+                .any(|value_timeline_info| value_timeline_info.timeline_id == selected_timeline_id)
+        });
+
+        if !is_selection_valid {
+            self.selected_timeline_id = self
+                .value_timelines
+                .first()
+                .map(|value_timeline_info| value_timeline_info.timeline_id);
+        }
+    }
+
+    fn append_samples(
+        &mut self,
+        timeline_id: u32,
+        samples: Vec<ValueTimelineSample>,
+    ) {
+        let retained_samples = self.samples_by_timeline_id.entry(timeline_id).or_default();
+        let latest_timestamp_ms = retained_samples.last().map(|sample| sample.get_timestamp_ms());
+
+        retained_samples.extend(
+            samples
+                .into_iter()
+                .filter(|sample| latest_timestamp_ms.is_none_or(|latest_timestamp_ms| sample.get_timestamp_ms() > latest_timestamp_ms)),
+        );
+
+        if retained_samples.len() > Self::MAX_RETAINED_SAMPLES {
+            retained_samples.drain(..retained_samples.len() - Self::MAX_RETAINED_SAMPLES);
+        }
+    }
+}
+
+impl Default for ValueTimelineViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueTimelineViewData;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::value_timelines::value_timeline_info::ValueTimelineInfo;
+    use squalr_engine_api::structures::value_timelines::value_timeline_sample::ValueTimelineSample;
+    use squalr_engine_api::structures::value_timelines::value_timeline_target::ValueTimelineTarget;
+
+    fn create_value_timeline_info(timeline_id: u32) -> ValueTimelineInfo {
+        ValueTimelineInfo {
+            timeline_id,
+            target: ValueTimelineTarget::new(0x10, "game.exe".to_string(), DataTypeRef::new("u32"), String::new()),
+            interval_ms: 100,
+            capacity: 16,
+            sample_count: 0,
+            latest_sample: None,
+        }
+    }
+
+    #[test]
+    fn removed_timelines_drop_their_samples_and_selection() {
+        let mut value_timeline_view_data = ValueTimelineViewData::new();
+
+        value_timeline_view_data.apply_value_timelines(vec![create_value_timeline_info(1), create_value_timeline_info(2)]);
+        value_timeline_view_data.selected_timeline_id = Some(2);
+        value_timeline_view_data.append_samples(2, vec![ValueTimelineSample::new(5, Some("7".to_string()))]);
+        value_timeline_view_data.append_samples(2, vec![ValueTimelineSample::new(5, Some("7".to_string()))]);
+
+        assert_eq!(value_timeline_view_data.samples_for(2).len(), 1);
+        assert_eq!(value_timeline_view_data.latest_timestamp_ms(2), Some(5));
+
+        value_timeline_view_data.apply_value_timelines(vec![create_value_timeline_info(1)]);
+
+        assert_eq!(value_timeline_view_data.selected_timeline_id, Some(1));
+        assert!(value_timeline_view_data.samples_for(2).is_empty());
+    }
+}